@@ -18,6 +18,7 @@
 //! Command within CLI
 
 use crate::cli_context::CliSessionContext;
+use crate::copy::CopyArgs;
 use crate::exec::{exec_and_print, exec_from_lines};
 use crate::functions::{display_all_functions, Function};
 use crate::print_format::PrintFormat;
@@ -46,6 +47,7 @@ pub enum Command {
     SearchFunctions(String),
     QuietMode(Option<bool>),
     OutputFormat(Option<String>),
+    Copy(Option<String>),
 }
 
 pub enum OutputFormat {
@@ -112,6 +114,23 @@ impl Command {
                     exec_err!("{function} is not a supported function")
                 }
             }
+            Self::Copy(args) => {
+                let Some(args) = args else {
+                    return exec_err!("Required source and destination are missing");
+                };
+                let Ok(args) = args.parse::<CopyArgs>() else {
+                    return exec_err!(
+                        "Invalid arguments '{args}', \
+                         expected SOURCE TO DESTINATION [FORMAT]"
+                    );
+                };
+                let now = Instant::now();
+                let batches = args.execute(ctx).await?;
+                if let Some(batch) = batches.first() {
+                    print_options.print_batches(batch.schema(), &batches, now)?;
+                }
+                Ok(())
+            }
             Self::OutputFormat(_) => exec_err!(
                 "Unexpected change output format, this should be handled outside"
             ),
@@ -133,11 +152,15 @@ impl Command {
             Self::OutputFormat(_) => {
                 ("\\pset [NAME [VALUE]]", "set table output option\n(format)")
             }
+            Self::Copy(_) => (
+                "\\copy source TO destination [format]",
+                "copy a local file to a table, path or URL",
+            ),
         }
     }
 }
 
-const ALL_COMMANDS: [Command; 9] = [
+const ALL_COMMANDS: [Command; 10] = [
     Command::ListTables,
     Command::DescribeTableStmt(String::new()),
     Command::Quit,
@@ -147,6 +170,7 @@ const ALL_COMMANDS: [Command; 9] = [
     Command::SearchFunctions(String::new()),
     Command::QuietMode(None),
     Command::OutputFormat(None),
+    Command::Copy(None),
 ];
 
 fn all_commands_info() -> RecordBatch {
@@ -197,6 +221,7 @@ impl FromStr for Command {
                 Self::OutputFormat(Some(subcommand.to_string()))
             }
             ("pset", None) => Self::OutputFormat(None),
+            ("copy", args) => Self::Copy(args.map(|a| a.to_string())),
             _ => return Err(()),
         })
     }
@@ -0,0 +1,246 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Implementation of the `\copy` command, which loads local files into a
+//! table or an object store
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::cli_context::CliSessionContext;
+use crate::exec::{
+    config_file_type_from_str, register_object_store_and_config_extensions,
+};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::common::{exec_err, plan_err};
+use datafusion::config::ConfigFileType;
+use datafusion::dataframe::{DataFrame, DataFrameWriteOptions};
+use datafusion::error::Result;
+use datafusion::prelude::{
+    ArrowReadOptions, CsvReadOptions, NdJsonReadOptions, ParquetReadOptions,
+    SessionContext,
+};
+
+/// The arguments of `\copy SOURCE TO DESTINATION [FORMAT]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyArgs {
+    /// The local file or directory to read
+    pub source: String,
+    /// The name of a registered table, or the path or URL to write to
+    pub destination: String,
+    /// The format to write when the destination is not a table, inferred
+    /// from the extension of the destination if not specified
+    pub format: Option<String>,
+}
+
+impl FromStr for CopyArgs {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split(' ').collect::<Vec<_>>().as_slice() {
+            [source, to, destination] if to.eq_ignore_ascii_case("to") => Ok(Self {
+                source: source.to_string(),
+                destination: destination.to_string(),
+                format: None,
+            }),
+            [source, to, destination, format] if to.eq_ignore_ascii_case("to") => {
+                Ok(Self {
+                    source: source.to_string(),
+                    destination: destination.to_string(),
+                    format: Some(format.to_lowercase()),
+                })
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+impl CopyArgs {
+    /// Reads the source and writes it to the destination with the write path
+    /// of the engine, returning a batch with the number of rows written
+    pub async fn execute(&self, ctx: &dyn CliSessionContext) -> Result<Vec<RecordBatch>> {
+        // the context shares the catalogs and object stores of the session
+        let session_ctx = SessionContext::new_with_state(ctx.session_state());
+        let df = read_local(&session_ctx, &self.source).await?;
+
+        if self.format.is_none()
+            && session_ctx
+                .table_exist(self.destination.as_str())
+                .unwrap_or(false)
+        {
+            return df
+                .write_table(&self.destination, DataFrameWriteOptions::new())
+                .await;
+        }
+
+        let format = match &self.format {
+            Some(format) => format.clone(),
+            None => extension(&self.destination).unwrap_or_else(|| "parquet".into()),
+        };
+        let Some(file_type) = config_file_type_from_str(&format) else {
+            return plan_err!(
+                "Unsupported format '{format}' for \\copy, expected csv, json or parquet"
+            );
+        };
+        register_object_store_and_config_extensions(
+            ctx,
+            &self.destination,
+            &HashMap::new(),
+            Some(file_type.clone()),
+        )
+        .await?;
+
+        let options = DataFrameWriteOptions::new();
+        match file_type {
+            ConfigFileType::CSV => df.write_csv(&self.destination, options, None).await,
+            ConfigFileType::JSON => df.write_json(&self.destination, options, None).await,
+            ConfigFileType::PARQUET => {
+                df.write_parquet(&self.destination, options, None).await
+            }
+        }
+    }
+}
+
+/// Reads a local file or directory, with the format given by its extension
+async fn read_local(ctx: &SessionContext, source: &str) -> Result<DataFrame> {
+    let Some(ext) = extension(source) else {
+        return exec_err!(
+            "Cannot infer the format of '{source}' for \\copy, \
+             expected a .csv, .tsv, .json, .parquet or .arrow file"
+        );
+    };
+    let file_extension = format!(".{ext}");
+    match ext.as_str() {
+        "csv" => {
+            let options = CsvReadOptions::new().file_extension(&file_extension);
+            ctx.read_csv(source, options).await
+        }
+        "tsv" => {
+            let options = CsvReadOptions::new()
+                .delimiter(b'\t')
+                .file_extension(&file_extension);
+            ctx.read_csv(source, options).await
+        }
+        "json" | "ndjson" => {
+            let options = NdJsonReadOptions::default().file_extension(&file_extension);
+            ctx.read_json(source, options).await
+        }
+        "parquet" => {
+            let options = ParquetReadOptions {
+                file_extension: &file_extension,
+                ..Default::default()
+            };
+            ctx.read_parquet(source, options).await
+        }
+        "arrow" => {
+            let options = ArrowReadOptions {
+                file_extension: &file_extension,
+                ..Default::default()
+            };
+            ctx.read_arrow(source, options).await
+        }
+        _ => exec_err!("Unsupported file extension '{ext}' for \\copy"),
+    }
+}
+
+fn extension(path: &str) -> Option<String> {
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use datafusion::assert_batches_eq;
+
+    #[test]
+    fn parse_copy_args() {
+        assert_eq!(
+            "data.csv TO s3://bucket/data/ parquet".parse::<CopyArgs>(),
+            Ok(CopyArgs {
+                source: "data.csv".into(),
+                destination: "s3://bucket/data/".into(),
+                format: Some("parquet".into()),
+            })
+        );
+        assert_eq!(
+            "data.csv to t".parse::<CopyArgs>(),
+            Ok(CopyArgs {
+                source: "data.csv".into(),
+                destination: "t".into(),
+                format: None,
+            })
+        );
+        assert!("data.csv t".parse::<CopyArgs>().is_err());
+    }
+
+    #[tokio::test]
+    async fn copy_to_table_and_path() -> Result<()> {
+        let dir =
+            std::env::temp_dir().join(format!("df_cli_copy_{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let source = dir.join("source.csv");
+        std::fs::write(&source, "a,b\n1,x\n2,y\n3,z\n")?;
+        let source = source.to_str().unwrap().to_string();
+
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t (a BIGINT, b VARCHAR)")
+            .await?
+            .collect()
+            .await?;
+
+        // into a registered table
+        let args = format!("{source} TO t").parse::<CopyArgs>().unwrap();
+        args.execute(&ctx).await?;
+        let batches = ctx
+            .sql("SELECT * FROM t ORDER BY a")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(
+            [
+                "+---+---+",
+                "| a | b |",
+                "+---+---+",
+                "| 1 | x |",
+                "| 2 | y |",
+                "| 3 | z |",
+                "+---+---+",
+            ],
+            &batches
+        );
+
+        // into a path, in the format of its extension
+        let destination = dir.join("destination.parquet");
+        let destination = destination.to_str().unwrap().to_string();
+        let args = format!("{source} TO {destination}")
+            .parse::<CopyArgs>()
+            .unwrap();
+        args.execute(&ctx).await?;
+        let df = ctx
+            .read_parquet(destination.as_str(), ParquetReadOptions::default())
+            .await?;
+        assert_eq!(df.count().await?, 3);
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -290,7 +290,7 @@ impl AdjustedPrintOptions {
     }
 }
 
-fn config_file_type_from_str(ext: &str) -> Option<ConfigFileType> {
+pub(crate) fn config_file_type_from_str(ext: &str) -> Option<ConfigFileType> {
     match ext.to_lowercase().as_str() {
         "csv" => Some(ConfigFileType::CSV),
         "json" => Some(ConfigFileType::JSON),
@@ -21,11 +21,14 @@ pub const DATAFUSION_CLI_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub mod catalog;
 pub mod cli_context;
 pub mod command;
+pub mod copy;
 pub mod exec;
 pub mod functions;
 pub mod helper;
 pub mod highlighter;
 pub mod object_storage;
+#[cfg(feature = "pg-server")]
+pub mod pg_server;
 pub mod pool_type;
 pub mod print_format;
 pub mod print_options;
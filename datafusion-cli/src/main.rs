@@ -123,6 +123,14 @@ struct Args {
 
     #[clap(long, help = "Enables console syntax highlighting")]
     color: bool,
+
+    #[cfg(feature = "pg-server")]
+    #[clap(
+        long,
+        help = "Serve queries over the PostgreSQL wire protocol on the given address (e.g. '127.0.0.1:5432') instead of starting the REPL",
+        conflicts_with_all = &["command", "file"]
+    )]
+    pg_listen: Option<String>,
 }
 
 #[tokio::main]
@@ -214,6 +222,16 @@ async fn main_inner() -> Result<()> {
         if !rc.is_empty() {
             exec::exec_from_files(&mut ctx, rc, &print_options).await?;
         }
+        #[cfg(feature = "pg-server")]
+        if let Some(addr) = args.pg_listen {
+            let listener = tokio::net::TcpListener::bind(&addr).await?;
+            if !print_options.quiet {
+                println!("Listening for PostgreSQL clients on {addr}");
+            }
+            return datafusion_cli::pg_server::PgServer::new(ctx)
+                .serve(listener)
+                .await;
+        }
         // TODO maybe we can have thiserror for cli but for now let's keep it simple
         return exec::exec_from_repl(&mut ctx, &mut print_options)
             .await
@@ -0,0 +1,858 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Serves a [`SessionContext`] over the PostgreSQL wire protocol (version 3.0)
+//! so that standard Postgres clients such as `psql`, JDBC / ODBC drivers,
+//! DBeaver or Grafana can connect to it.
+//!
+//! Both the simple query protocol and the extended query protocol
+//! (Parse / Bind / Describe / Execute / Sync) are supported. Values are
+//! always exchanged in text format and no authentication is performed.
+//!
+//! This module requires the `pg-server` feature. `datafusion-cli` uses it to
+//! serve its session with `--pg-listen`:
+//!
+//! ```shell
+//! datafusion-cli --pg-listen 127.0.0.1:5432
+//! psql -h 127.0.0.1 -p 5432 -c "select * from 'data.parquet'"
+//! ```
+
+use std::collections::HashMap;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use datafusion::arrow::array::{Array, AsArray, RecordBatch};
+use datafusion::arrow::datatypes::{DataType, SchemaRef};
+use datafusion::arrow::util::display::{ArrayFormatter, FormatOptions};
+use datafusion::common::{exec_datafusion_err, exec_err, ScalarValue};
+use datafusion::error::Result;
+use datafusion::logical_expr::{LogicalPlan, Statement};
+use datafusion::prelude::SessionContext;
+use datafusion::sql::parser::{DFParser, Statement as DFStatement};
+use datafusion::sql::sqlparser::dialect::PostgreSqlDialect;
+use futures::TryStreamExt;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Request codes sent in place of a protocol version in the startup packet
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+const PROTOCOL_VERSION_3: i32 = 196608;
+
+/// The maximum size of a startup packet, as enforced by Postgres itself
+const MAX_STARTUP_PACKET_SIZE: usize = 10_000;
+
+/// The default maximum size of any other message sent by a client
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+/// Serves a [`SessionContext`] to Postgres clients
+///
+/// Every connection runs its statements in its own copy of the session
+/// state, so settings changed with `SET` only apply to that connection,
+/// while tables created by one connection are visible to all of them.
+pub struct PgServer {
+    ctx: SessionContext,
+    max_message_size: usize,
+}
+
+impl PgServer {
+    /// Create a new server for `ctx`
+    pub fn new(ctx: SessionContext) -> Self {
+        Self {
+            ctx,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+
+    /// Set the maximum size in bytes of a message sent by a client, defaults
+    /// to [`DEFAULT_MAX_MESSAGE_SIZE`]. Connections sending larger messages
+    /// are closed, as their length is otherwise only bounded by `i32::MAX`.
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Accepts and serves connections from `listener` until accepting
+    /// fails. Connections are served concurrently.
+    pub async fn serve(&self, listener: TcpListener) -> Result<()> {
+        loop {
+            let (socket, peer) = listener.accept().await?;
+            let connection = self.connection(socket);
+            tokio::spawn(async move {
+                if let Err(e) = connection.run().await {
+                    eprintln!("Connection from {peer} failed: {e}");
+                }
+            });
+        }
+    }
+
+    /// Serves a single client connection until the client terminates it
+    pub async fn run_connection<S>(&self, socket: S) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        self.connection(socket).run().await
+    }
+
+    fn connection<S>(&self, socket: S) -> Connection<S> {
+        let ctx = SessionContext::new_with_state(self.ctx.state());
+        Connection::new(socket, ctx, self.max_message_size)
+    }
+}
+
+/// A statement created by a `Parse` message
+struct PreparedStatement {
+    /// The planned statement, or `None` for an empty query string
+    plan: Option<LogicalPlan>,
+    /// The types of the `$n` placeholders, in order
+    param_types: Vec<DataType>,
+}
+
+/// A statement bound to its parameter values by a `Bind` message
+struct Portal {
+    plan: Option<LogicalPlan>,
+}
+
+/// The state of a single client connection
+struct Connection<S> {
+    socket: S,
+    ctx: SessionContext,
+    /// The maximum size of a message sent by the client
+    max_message_size: usize,
+    /// Messages waiting to be sent to the client
+    out: BytesMut,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Connection<S> {
+    fn new(socket: S, ctx: SessionContext, max_message_size: usize) -> Self {
+        Self {
+            socket,
+            ctx,
+            max_message_size,
+            out: BytesMut::new(),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+        }
+    }
+
+    async fn run(mut self) -> Result<()> {
+        if !self.startup().await? {
+            return Ok(());
+        }
+
+        // Set when an error occurs while processing an extended query
+        // message: all messages are then discarded until the next `Sync`
+        let mut skip_until_sync = false;
+        loop {
+            let tag = match self.socket.read_u8().await {
+                Ok(tag) => tag,
+                // the client went away without sending `Terminate`
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e.into()),
+            };
+            let mut body = self.read_body(self.max_message_size).await?;
+
+            match tag {
+                b'Q' => {
+                    let sql = get_cstr(&mut body)?;
+                    if let Err(e) = self.simple_query(&sql).await {
+                        self.error_response(&e.to_string());
+                    }
+                    self.ready_for_query();
+                }
+                b'S' => {
+                    skip_until_sync = false;
+                    self.ready_for_query();
+                }
+                b'H' => {}
+                b'X' => break,
+                _ if skip_until_sync => {}
+                b'P' | b'B' | b'D' | b'E' | b'C' => {
+                    if let Err(e) = self.extended_query(tag, body).await {
+                        self.error_response(&e.to_string());
+                        skip_until_sync = true;
+                    }
+                }
+                _ => {
+                    self.error_response(&format!(
+                        "unsupported message type '{}'",
+                        tag as char
+                    ));
+                    self.ready_for_query();
+                }
+            }
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Performs the startup handshake, returning `false` if the
+    /// connection should be closed
+    async fn startup(&mut self) -> Result<bool> {
+        // Startup parameters such as the user and database name are ignored
+        loop {
+            let mut body = self.read_body(MAX_STARTUP_PACKET_SIZE).await?;
+            match get_i32(&mut body)? {
+                SSL_REQUEST_CODE | GSSENC_REQUEST_CODE => {
+                    // encryption is not supported, the client may
+                    // continue unencrypted
+                    self.socket.write_all(b"N").await?;
+                }
+                // query cancellation is not supported
+                CANCEL_REQUEST_CODE => return Ok(false),
+                PROTOCOL_VERSION_3 => break,
+                version => {
+                    self.error_response(&format!(
+                        "unsupported protocol version {version}"
+                    ));
+                    self.flush().await?;
+                    return Ok(false);
+                }
+            }
+        }
+
+        // AuthenticationOk
+        message(&mut self.out, b'R', |buf| buf.put_i32(0));
+        for (name, value) in [
+            ("server_version", "14.0"),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+        ] {
+            message(&mut self.out, b'S', |buf| {
+                put_cstr(buf, name);
+                put_cstr(buf, value);
+            });
+        }
+        // BackendKeyData, unused as cancellation is not supported
+        message(&mut self.out, b'K', |buf| {
+            buf.put_i32(std::process::id() as i32);
+            buf.put_i32(0);
+        });
+        self.ready_for_query();
+        self.flush().await?;
+        Ok(true)
+    }
+
+    /// Runs all statements of a `Query` message
+    async fn simple_query(&mut self, sql: &str) -> Result<()> {
+        let statements = DFParser::parse_sql_with_dialect(sql, &PostgreSqlDialect {})?;
+        if statements.is_empty() {
+            message(&mut self.out, b'I', |_| {});
+            return Ok(());
+        }
+
+        for statement in statements {
+            let plan = self.ctx.state().statement_to_plan(statement).await?;
+            let schema = plan.schema().inner().clone();
+            if !schema.fields().is_empty() {
+                self.row_description(&schema);
+            }
+            self.execute(plan).await?;
+        }
+        Ok(())
+    }
+
+    /// Handles a single extended query protocol message
+    async fn extended_query(&mut self, tag: u8, mut body: Bytes) -> Result<()> {
+        match tag {
+            // Parse
+            b'P' => {
+                let name = get_cstr(&mut body)?;
+                let sql = get_cstr(&mut body)?;
+                let statement = self.parse(&sql).await?;
+                self.statements.insert(name, statement);
+                message(&mut self.out, b'1', |_| {});
+            }
+            // Bind
+            b'B' => {
+                let portal = get_cstr(&mut body)?;
+                let name = get_cstr(&mut body)?;
+                let statement = self.statements.get(&name).ok_or_else(|| {
+                    exec_datafusion_err!("prepared statement \"{name}\" does not exist")
+                })?;
+
+                let num_formats = get_i16(&mut body)?;
+                for _ in 0..num_formats {
+                    if get_i16(&mut body)? != 0 {
+                        return exec_err!("binary parameter format is not supported");
+                    }
+                }
+
+                let num_params = get_i16(&mut body)? as usize;
+                if num_params != statement.param_types.len() {
+                    return exec_err!(
+                        "bind message supplies {num_params} parameters, but prepared statement \"{name}\" requires {}",
+                        statement.param_types.len()
+                    );
+                }
+                let mut values = Vec::with_capacity(num_params);
+                for data_type in &statement.param_types {
+                    let value = match get_i32(&mut body)? {
+                        -1 => ScalarValue::try_from(data_type)?,
+                        len => {
+                            let bytes = get_bytes(&mut body, len as usize)?;
+                            let text =
+                                String::from_utf8(bytes.to_vec()).map_err(|e| {
+                                    exec_datafusion_err!("invalid parameter value: {e}")
+                                })?;
+                            ScalarValue::try_from_string(text, data_type)?
+                        }
+                    };
+                    values.push(value);
+                }
+
+                let num_result_formats = get_i16(&mut body)?;
+                for _ in 0..num_result_formats {
+                    if get_i16(&mut body)? != 0 {
+                        return exec_err!("binary result format is not supported");
+                    }
+                }
+
+                let plan = match &statement.plan {
+                    Some(plan) => Some(plan.clone().with_param_values(values)?),
+                    None => None,
+                };
+                self.portals.insert(portal, Portal { plan });
+                message(&mut self.out, b'2', |_| {});
+            }
+            // Describe
+            b'D' => {
+                let kind = get_u8(&mut body)?;
+                let name = get_cstr(&mut body)?;
+                if kind == b'S' {
+                    let statement = self.statements.get(&name).ok_or_else(|| {
+                        exec_datafusion_err!(
+                            "prepared statement \"{name}\" does not exist"
+                        )
+                    })?;
+                    let oids: Vec<i32> =
+                        statement.param_types.iter().map(type_oid).collect();
+                    let schema = statement
+                        .plan
+                        .as_ref()
+                        .map(|plan| plan.schema().inner().clone());
+                    message(&mut self.out, b't', |buf| {
+                        buf.put_i16(oids.len() as i16);
+                        oids.iter().for_each(|oid| buf.put_i32(*oid));
+                    });
+                    self.describe_rows(schema);
+                } else {
+                    let portal = self.portals.get(&name).ok_or_else(|| {
+                        exec_datafusion_err!("portal \"{name}\" does not exist")
+                    })?;
+                    let schema = portal
+                        .plan
+                        .as_ref()
+                        .map(|plan| plan.schema().inner().clone());
+                    self.describe_rows(schema);
+                }
+            }
+            // Execute. The maximum number of rows to return is ignored and
+            // the portal is always run to completion.
+            b'E' => {
+                let name = get_cstr(&mut body)?;
+                let portal = self.portals.get(&name).ok_or_else(|| {
+                    exec_datafusion_err!("portal \"{name}\" does not exist")
+                })?;
+                match portal.plan.clone() {
+                    Some(plan) => self.execute(plan).await?,
+                    None => message(&mut self.out, b'I', |_| {}),
+                }
+            }
+            // Close
+            b'C' => {
+                let kind = get_u8(&mut body)?;
+                let name = get_cstr(&mut body)?;
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                message(&mut self.out, b'3', |_| {});
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    /// Plans the statement of a `Parse` message
+    async fn parse(&self, sql: &str) -> Result<PreparedStatement> {
+        let mut statements =
+            DFParser::parse_sql_with_dialect(sql, &PostgreSqlDialect {})?;
+        let statement: DFStatement = match statements.len() {
+            0 => {
+                return Ok(PreparedStatement {
+                    plan: None,
+                    param_types: vec![],
+                })
+            }
+            1 => statements.pop_front().unwrap(),
+            _ => {
+                return exec_err!(
+                    "cannot insert multiple commands into a prepared statement"
+                )
+            }
+        };
+
+        let plan = self.ctx.state().statement_to_plan(statement).await?;
+        let mut types = plan.get_parameter_types()?;
+        // Parameters whose type can not be inferred are passed as strings
+        let param_types = (1..=types.len())
+            .map(|i| {
+                types
+                    .remove(&format!("${i}"))
+                    .flatten()
+                    .unwrap_or(DataType::Utf8)
+            })
+            .collect();
+        Ok(PreparedStatement {
+            plan: Some(plan),
+            param_types,
+        })
+    }
+
+    /// Runs a plan, sending its rows (if any) followed by `CommandComplete`
+    async fn execute(&mut self, plan: LogicalPlan) -> Result<()> {
+        let tag = match &plan {
+            // Session settings and transactions sent by drivers on connect
+            // are accepted but have no effect
+            LogicalPlan::Statement(Statement::SetVariable(set))
+                if !set.variable.starts_with("datafusion.") =>
+            {
+                Some("SET")
+            }
+            LogicalPlan::Statement(Statement::TransactionStart(_)) => Some("BEGIN"),
+            LogicalPlan::Statement(Statement::TransactionEnd(_)) => Some("COMMIT"),
+            _ => None,
+        };
+        if let Some(tag) = tag {
+            message(&mut self.out, b'C', |buf| put_cstr(buf, tag));
+            return Ok(());
+        }
+
+        let is_query = !plan.schema().fields().is_empty();
+        let mut stream = self
+            .ctx
+            .execute_logical_plan(plan)
+            .await?
+            .execute_stream()
+            .await?;
+        let mut num_rows = 0;
+        // Rows are sent as each batch is produced, so that only one batch and
+        // its encoded rows are held in memory
+        while let Some(batch) = stream.try_next().await? {
+            if is_query {
+                self.data_rows(&batch)?;
+                num_rows += batch.num_rows();
+                self.flush().await?;
+            }
+        }
+
+        let tag = if is_query {
+            format!("SELECT {num_rows}")
+        } else {
+            "OK".to_string()
+        };
+        message(&mut self.out, b'C', |buf| put_cstr(buf, &tag));
+        Ok(())
+    }
+
+    /// Sends `RowDescription` for statements returning rows and `NoData`
+    /// otherwise
+    fn describe_rows(&mut self, schema: Option<SchemaRef>) {
+        match schema {
+            Some(schema) if !schema.fields().is_empty() => self.row_description(&schema),
+            _ => message(&mut self.out, b'n', |_| {}),
+        }
+    }
+
+    fn row_description(&mut self, schema: &SchemaRef) {
+        message(&mut self.out, b'T', |buf| {
+            buf.put_i16(schema.fields().len() as i16);
+            for field in schema.fields() {
+                put_cstr(buf, field.name());
+                // table OID and column attribute number
+                buf.put_i32(0);
+                buf.put_i16(0);
+                buf.put_i32(type_oid(field.data_type()));
+                // type size and modifier
+                buf.put_i16(-1);
+                buf.put_i32(-1);
+                // text format
+                buf.put_i16(0);
+            }
+        });
+    }
+
+    fn data_rows(&mut self, batch: &RecordBatch) -> Result<()> {
+        let options = FormatOptions::default()
+            .with_timestamp_format(Some("%Y-%m-%d %H:%M:%S%.f"))
+            .with_timestamp_tz_format(Some("%Y-%m-%d %H:%M:%S%.f%:z"));
+        let formatters = batch
+            .columns()
+            .iter()
+            .map(|array| ArrayFormatter::try_new(array.as_ref(), &options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for row in 0..batch.num_rows() {
+            message(&mut self.out, b'D', |buf| {
+                buf.put_i16(batch.num_columns() as i16);
+                for (array, formatter) in batch.columns().iter().zip(&formatters) {
+                    if array.is_null(row) {
+                        buf.put_i32(-1);
+                        continue;
+                    }
+                    let value = match array.data_type() {
+                        // Postgres spells booleans `t` and `f`
+                        DataType::Boolean => {
+                            let value = array.as_boolean().value(row);
+                            (if value { "t" } else { "f" }).to_string()
+                        }
+                        _ => formatter.value(row).to_string(),
+                    };
+                    buf.put_i32(value.len() as i32);
+                    buf.put_slice(value.as_bytes());
+                }
+            });
+        }
+        Ok(())
+    }
+
+    fn error_response(&mut self, msg: &str) {
+        message(&mut self.out, b'E', |buf| {
+            for (field, value) in [
+                (b'S', "ERROR"),
+                (b'V', "ERROR"),
+                // internal_error
+                (b'C', "XX000"),
+                (b'M', msg),
+            ] {
+                buf.put_u8(field);
+                put_cstr(buf, value);
+            }
+            buf.put_u8(0);
+        });
+    }
+
+    fn ready_for_query(&mut self) {
+        // always idle, as transactions are not supported
+        message(&mut self.out, b'Z', |buf| buf.put_u8(b'I'));
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        self.socket.write_all(&self.out).await?;
+        self.out.clear();
+        Ok(())
+    }
+
+    /// Reads the length prefixed body of a message of at most `max_size`
+    /// bytes.
+    ///
+    /// The rest of the stream can not be interpreted after an invalid
+    /// length, so the client is sent an error before the error is returned
+    /// and the connection closed.
+    async fn read_body(&mut self, max_size: usize) -> Result<Bytes> {
+        let len = self.socket.read_i32().await?;
+        let error = match usize::try_from(len) {
+            Ok(len) if len > max_size => Some(format!(
+                "message length {len} exceeds the maximum of {max_size}"
+            )),
+            Ok(len) if len >= 4 => None,
+            _ => Some(format!("invalid message length {len}")),
+        };
+        if let Some(msg) = error {
+            self.error_response(&msg);
+            self.flush().await?;
+            return exec_err!("{msg}");
+        }
+        let mut body = vec![0; len as usize - 4];
+        self.socket.read_exact(&mut body).await?;
+        Ok(body.into())
+    }
+}
+
+/// Appends a message with the given type `tag` to `out`, filling in its
+/// length once `body` has been written
+fn message(out: &mut BytesMut, tag: u8, body: impl FnOnce(&mut BytesMut)) {
+    out.put_u8(tag);
+    let start = out.len();
+    out.put_i32(0);
+    body(out);
+    let len = (out.len() - start) as i32;
+    out[start..start + 4].copy_from_slice(&len.to_be_bytes());
+}
+
+fn put_cstr(buf: &mut BytesMut, s: &str) {
+    buf.put_slice(s.as_bytes());
+    buf.put_u8(0);
+}
+
+fn get_cstr(buf: &mut Bytes) -> Result<String> {
+    let end = buf
+        .iter()
+        .position(|b| *b == 0)
+        .ok_or_else(|| exec_datafusion_err!("unterminated string in message"))?;
+    let s = String::from_utf8(buf.split_to(end).to_vec())
+        .map_err(|e| exec_datafusion_err!("invalid string in message: {e}"))?;
+    buf.advance(1);
+    Ok(s)
+}
+
+fn get_bytes(buf: &mut Bytes, len: usize) -> Result<Bytes> {
+    if buf.remaining() < len {
+        return exec_err!("unexpected end of message");
+    }
+    Ok(buf.split_to(len))
+}
+
+fn get_u8(buf: &mut Bytes) -> Result<u8> {
+    Ok(get_bytes(buf, 1)?.get_u8())
+}
+
+fn get_i16(buf: &mut Bytes) -> Result<i16> {
+    Ok(get_bytes(buf, 2)?.get_i16())
+}
+
+fn get_i32(buf: &mut Bytes) -> Result<i32> {
+    Ok(get_bytes(buf, 4)?.get_i32())
+}
+
+/// Returns the OID of the Postgres type used to describe `data_type`.
+/// Types without a Postgres equivalent are described as `text`.
+fn type_oid(data_type: &DataType) -> i32 {
+    match data_type {
+        DataType::Boolean => 16,
+        DataType::Int64 | DataType::UInt32 => 20,
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => 21,
+        DataType::Int32 | DataType::UInt16 => 23,
+        DataType::Float16 | DataType::Float32 => 700,
+        DataType::Float64 => 701,
+        DataType::Date32 | DataType::Date64 => 1082,
+        DataType::Timestamp(_, None) => 1114,
+        DataType::Timestamp(_, Some(_)) => 1184,
+        // numeric, as int8 can not hold the values above i64::MAX
+        DataType::UInt64 | DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            1700
+        }
+        _ => 25,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::arrow::array::UInt64Array;
+    use datafusion::arrow::datatypes::{Field, Schema};
+    use datafusion::datasource::MemTable;
+    use std::sync::Arc;
+    use tokio::io::{duplex, DuplexStream};
+    use tokio::task::JoinHandle;
+
+    /// Starts serving `server` over an in memory stream and performs the
+    /// startup handshake, returning the client end of the stream
+    async fn connect(server: PgServer) -> Result<(DuplexStream, JoinHandle<Result<()>>)> {
+        let (mut client, socket) = duplex(64 * 1024);
+        let handle = tokio::spawn(async move { server.run_connection(socket).await });
+
+        let mut startup = BytesMut::new();
+        startup.put_i32(0);
+        startup.put_i32(PROTOCOL_VERSION_3);
+        put_cstr(&mut startup, "user");
+        put_cstr(&mut startup, "test");
+        startup.put_u8(0);
+        let len = startup.len() as i32;
+        startup[0..4].copy_from_slice(&len.to_be_bytes());
+        client.write_all(&startup).await?;
+
+        let messages = read_until_ready(&mut client).await?;
+        assert_eq!(messages.first().map(|(tag, _)| *tag), Some(b'R'));
+        Ok((client, handle))
+    }
+
+    async fn read_message(client: &mut DuplexStream) -> Result<(u8, Bytes)> {
+        let tag = client.read_u8().await?;
+        let len = client.read_i32().await?;
+        let mut body = vec![0; len as usize - 4];
+        client.read_exact(&mut body).await?;
+        Ok((tag, body.into()))
+    }
+
+    /// Reads messages up to and including `ReadyForQuery`
+    async fn read_until_ready(client: &mut DuplexStream) -> Result<Vec<(u8, Bytes)>> {
+        let mut messages = vec![];
+        loop {
+            let message = read_message(client).await?;
+            let tag = message.0;
+            messages.push(message);
+            if tag == b'Z' {
+                return Ok(messages);
+            }
+        }
+    }
+
+    async fn simple_query(
+        client: &mut DuplexStream,
+        sql: &str,
+    ) -> Result<Vec<(u8, Bytes)>> {
+        let mut out = BytesMut::new();
+        message(&mut out, b'Q', |buf| put_cstr(buf, sql));
+        client.write_all(&out).await?;
+        read_until_ready(client).await
+    }
+
+    fn column_names(mut body: Bytes) -> Result<Vec<String>> {
+        let num_fields = get_i16(&mut body)?;
+        (0..num_fields)
+            .map(|_| {
+                let name = get_cstr(&mut body)?;
+                // table OID, attribute number, type OID, size, modifier, format
+                get_bytes(&mut body, 18)?;
+                Ok(name)
+            })
+            .collect()
+    }
+
+    fn type_oids(mut body: Bytes) -> Result<Vec<i32>> {
+        let num_fields = get_i16(&mut body)?;
+        (0..num_fields)
+            .map(|_| {
+                get_cstr(&mut body)?;
+                // table OID and attribute number
+                get_bytes(&mut body, 6)?;
+                let oid = get_i32(&mut body)?;
+                // size, modifier and format
+                get_bytes(&mut body, 8)?;
+                Ok(oid)
+            })
+            .collect()
+    }
+
+    fn row_values(mut body: Bytes) -> Result<Vec<Option<String>>> {
+        let num_values = get_i16(&mut body)?;
+        (0..num_values)
+            .map(|_| match get_i32(&mut body)? {
+                -1 => Ok(None),
+                len => {
+                    let value = get_bytes(&mut body, len as usize)?;
+                    Ok(Some(String::from_utf8(value.to_vec()).unwrap()))
+                }
+            })
+            .collect()
+    }
+
+    fn tags(messages: &[(u8, Bytes)]) -> String {
+        messages.iter().map(|(tag, _)| *tag as char).collect()
+    }
+
+    #[tokio::test]
+    async fn simple_query_flow() -> Result<()> {
+        let (mut client, handle) = connect(PgServer::new(SessionContext::new())).await?;
+
+        let messages = simple_query(
+            &mut client,
+            "select 1 as a, 'x' as b, null as c; select true",
+        )
+        .await?;
+        assert_eq!(tags(&messages), "TDCTDCZ");
+        assert_eq!(column_names(messages[0].1.clone())?, ["a", "b", "c"]);
+        assert_eq!(
+            row_values(messages[1].1.clone())?,
+            [Some("1".to_string()), Some("x".to_string()), None]
+        );
+        assert_eq!(get_cstr(&mut messages[2].1.clone())?, "SELECT 1");
+        assert_eq!(row_values(messages[4].1.clone())?, [Some("t".to_string())]);
+
+        // statements without results complete without a row description
+        let messages = simple_query(&mut client, "create table t(i int)").await?;
+        assert_eq!(tags(&messages), "CZ");
+
+        // an empty query string
+        let messages = simple_query(&mut client, "").await?;
+        assert_eq!(tags(&messages), "IZ");
+
+        // an error leaves the connection ready for the next query
+        let messages = simple_query(&mut client, "select * from missing").await?;
+        assert_eq!(tags(&messages), "EZ");
+        let messages = simple_query(&mut client, "select count(*) from t").await?;
+        assert_eq!(tags(&messages), "TDCZ");
+        assert_eq!(row_values(messages[1].1.clone())?, [Some("0".to_string())]);
+
+        let mut out = BytesMut::new();
+        message(&mut out, b'X', |_| {});
+        client.write_all(&out).await?;
+        handle.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn streamed_results() -> Result<()> {
+        // 3 batches of 1000 values close to u64::MAX
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("v", DataType::UInt64, false)]));
+        let batches = (0..3)
+            .map(|i| {
+                let values = (0..1000).map(|j| u64::MAX - i * 1000 - j);
+                RecordBatch::try_new(
+                    Arc::clone(&schema),
+                    vec![Arc::new(UInt64Array::from_iter_values(values))],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let ctx = SessionContext::new();
+        ctx.register_table("t", Arc::new(MemTable::try_new(schema, vec![batches])?))?;
+        let (mut client, handle) = connect(PgServer::new(ctx)).await?;
+
+        let messages = simple_query(&mut client, "select v from t").await?;
+        assert_eq!(messages.len(), 3003);
+        // unsigned 64 bit integers are sent as numeric, as they may not fit
+        // in an int8
+        assert_eq!(type_oids(messages[0].1.clone())?, [1700]);
+        assert_eq!(
+            row_values(messages[1].1.clone())?,
+            [Some(u64::MAX.to_string())]
+        );
+        assert_eq!(get_cstr(&mut messages[3001].1.clone())?, "SELECT 3000");
+
+        let mut out = BytesMut::new();
+        message(&mut out, b'X', |_| {});
+        client.write_all(&out).await?;
+        handle.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn oversized_message() -> Result<()> {
+        let server = PgServer::new(SessionContext::new()).with_max_message_size(1024);
+        let (mut client, handle) = connect(server).await?;
+
+        // only the length is sent, the server must not wait for the body
+        client.write_u8(b'Q').await?;
+        client.write_i32(1_000_000).await?;
+
+        let (tag, _) = read_message(&mut client).await?;
+        assert_eq!(tag, b'E');
+        let err = handle.await.unwrap().unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: message length 1000000 exceeds the maximum of 1024"
+        );
+        Ok(())
+    }
+}
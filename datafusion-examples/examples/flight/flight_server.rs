@@ -19,6 +19,7 @@ use arrow::ipc::writer::{DictionaryTracker, IpcDataGenerator};
 use std::sync::Arc;
 
 use arrow_flight::{PollInfo, SchemaAsIpc};
+use datafusion::arrow::datatypes::Schema;
 use datafusion::arrow::error::ArrowError;
 use datafusion::datasource::file_format::parquet::ParquetFormat;
 use datafusion::datasource::listing::{ListingOptions, ListingTableUrl};
@@ -30,8 +31,8 @@ use datafusion::prelude::*;
 
 use arrow_flight::{
     flight_service_server::FlightService, flight_service_server::FlightServiceServer,
-    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
-    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint,
+    FlightInfo, HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
 };
 
 #[derive(Clone)]
@@ -80,19 +81,7 @@ impl FlightService for FlightServiceImpl {
             Ok(sql) => {
                 println!("do_get: {sql}");
 
-                // create local execution context
-                let ctx = SessionContext::new();
-
-                let testdata = datafusion::test_util::parquet_test_data();
-
-                // register parquet file with the execution context
-                ctx.register_parquet(
-                    "alltypes_plain",
-                    &format!("{testdata}/alltypes_plain.parquet"),
-                    ParquetReadOptions::default(),
-                )
-                .await
-                .map_err(to_tonic_err)?;
+                let ctx = create_context().await?;
 
                 // create the DataFrame
                 let df = ctx.sql(sql).await.map_err(to_tonic_err)?;
@@ -100,9 +89,6 @@ impl FlightService for FlightServiceImpl {
                 // execute the query
                 let schema = df.schema().clone().into();
                 let results = df.collect().await.map_err(to_tonic_err)?;
-                if results.is_empty() {
-                    return Err(Status::internal("There were no results from ticket"));
-                }
 
                 // add an initial FlightData message that sends schema
                 let options = datafusion::arrow::ipc::writer::IpcWriteOptions::default();
@@ -145,9 +131,27 @@ impl FlightService for FlightServiceImpl {
 
     async fn get_flight_info(
         &self,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented("Not yet implemented"))
+        let descriptor = request.into_inner();
+        let sql = std::str::from_utf8(&descriptor.cmd)
+            .map_err(|e| Status::invalid_argument(format!("Invalid command: {e:?}")))?;
+        println!("get_flight_info: {sql}");
+
+        // plan the query to find out its schema, it is executed by `do_get`
+        let ctx = create_context().await?;
+        let df = ctx.sql(sql).await.map_err(to_tonic_err)?;
+        let schema: Schema = df.schema().into();
+
+        // the results are served by a single endpoint, whose ticket is the query
+        let endpoint = FlightEndpoint::new().with_ticket(Ticket::new(sql.to_string()));
+        let info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .map_err(|e| Status::internal(e.to_string()))?
+            .with_endpoint(endpoint)
+            .with_descriptor(descriptor);
+
+        Ok(Response::new(info))
     }
 
     async fn do_put(
@@ -186,13 +190,31 @@ impl FlightService for FlightServiceImpl {
     }
 }
 
+/// Creates a context with the `alltypes_plain` table registered
+async fn create_context() -> Result<SessionContext, Status> {
+    let ctx = SessionContext::new();
+
+    let testdata = datafusion::test_util::parquet_test_data();
+
+    // register parquet file with the execution context
+    ctx.register_parquet(
+        "alltypes_plain",
+        &format!("{testdata}/alltypes_plain.parquet"),
+        ParquetReadOptions::default(),
+    )
+    .await
+    .map_err(to_tonic_err)?;
+
+    Ok(ctx)
+}
+
 fn to_tonic_err(e: datafusion::error::DataFusionError) -> Status {
     Status::internal(format!("{e:?}"))
 }
 
 /// This example shows how to wrap DataFusion with `FlightService` to support looking up schema information for
 /// Parquet files and executing SQL queries against them on a remote server.
-/// This example is run along-side the example `flight_client`.
+/// This example is run along-side the examples `flight_client` and `flight_table`.
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let addr = "0.0.0.0:50051".parse()?;
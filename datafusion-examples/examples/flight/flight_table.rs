@@ -0,0 +1,389 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use arrow_flight::{FlightClient, FlightDescriptor, FlightEndpoint, FlightInfo};
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::{Schema, SchemaRef};
+use datafusion::arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use datafusion::arrow::util::pretty;
+use datafusion::common::stats::Precision;
+use datafusion::common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion::common::{exec_datafusion_err, Column, Statistics};
+use datafusion::datasource::{TableProvider, TableType};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::context::{SessionState, TaskContext};
+use datafusion::logical_expr::TableProviderFilterPushDown;
+use datafusion::physical_plan::stream::{
+    EmptyRecordBatchStream, RecordBatchStreamAdapter,
+};
+use datafusion::physical_plan::{
+    project_schema, DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan,
+    Partitioning, PlanProperties, SendableRecordBatchStream,
+};
+use datafusion::prelude::*;
+use datafusion_physical_expr::EquivalenceProperties;
+use datafusion_sql::sqlparser::ast::Ident;
+use datafusion_sql::unparser::expr_to_sql;
+use futures::{StreamExt, TryStreamExt};
+use tonic::transport::Channel;
+
+/// This example shows how to query a dataset served over Arrow Flight as if it
+/// was a local table, by implementing a [`TableProvider`] on top of a
+/// [`FlightClient`].
+///
+/// Projections, filters and limits are pushed down to the remote service by
+/// sending them as a SQL command in the Flight descriptor.
+///
+/// This example is run along-side the example `flight_server`.
+#[tokio::main]
+async fn main() -> Result<()> {
+    let ctx = SessionContext::new();
+
+    // register the remote table under a local name
+    let table = FlightTable::try_new("http://localhost:50051", "alltypes_plain").await?;
+    ctx.register_table("remote_alltypes", Arc::new(table))?;
+
+    let df = ctx
+        .sql(
+            "SELECT id, bool_col, string_col FROM remote_alltypes \
+             WHERE id > 2 AND bool_col ORDER BY id",
+        )
+        .await?;
+
+    // the projection and the filters are part of the query sent to the server
+    df.clone().explain(false, false)?.show().await?;
+
+    let results = df.collect().await?;
+    pretty::print_batches(&results)?;
+
+    Ok(())
+}
+
+/// A table that is read from a remote service speaking Arrow Flight.
+///
+/// The schema and statistics of the table are read with `GetFlightInfo` for a
+/// command selecting all rows of the table. Scans issue a new `GetFlightInfo`
+/// request with the projection, filters and limit of the scan, and stream each
+/// of the returned endpoints with `DoGet` as a separate partition.
+#[derive(Debug)]
+pub struct FlightTable {
+    /// URL of the Flight service, e.g. `http://localhost:50051`
+    url: String,
+    /// Name of the table on the remote service
+    remote_table: String,
+    schema: SchemaRef,
+    statistics: Statistics,
+}
+
+impl FlightTable {
+    /// Creates a table for `remote_table` on the Flight service at `url`,
+    /// fetching its schema from the service.
+    pub async fn try_new(
+        url: impl Into<String>,
+        remote_table: impl Into<String>,
+    ) -> Result<Self> {
+        let url = url.into();
+        let remote_table = remote_table.into();
+
+        let query = format!("SELECT * FROM {}", quote_ident(&remote_table));
+        let info = get_flight_info(&url, query).await?;
+        let schema = Arc::new(decode_schema(&info)?);
+        let statistics = info_statistics(&info, &schema);
+
+        Ok(Self {
+            url,
+            remote_table,
+            schema,
+            statistics,
+        })
+    }
+
+    /// Returns the SQL query sent to the service for a scan
+    fn scan_query(
+        &self,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<String> {
+        let columns = match projection {
+            Some(projection) if projection.is_empty() => "1".to_string(),
+            Some(projection) => projection
+                .iter()
+                .map(|i| quote_ident(self.schema.field(*i).name()))
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => "*".to_string(),
+        };
+
+        let mut query =
+            format!("SELECT {columns} FROM {}", quote_ident(&self.remote_table));
+        if !filters.is_empty() {
+            let predicates = filters
+                .iter()
+                .map(|filter| Ok(format!("({})", filter_to_sql(filter)?)))
+                .collect::<Result<Vec<_>>>()?;
+            query.push_str(" WHERE ");
+            query.push_str(&predicates.join(" AND "));
+        }
+        if let Some(limit) = limit {
+            query.push_str(&format!(" LIMIT {limit}"));
+        }
+        Ok(query)
+    }
+}
+
+#[async_trait]
+impl TableProvider for FlightTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let query = self.scan_query(projection, filters, limit)?;
+        let info = get_flight_info(&self.url, query.clone()).await?;
+
+        let schema = project_schema(&self.schema, projection)?;
+        let statistics = info_statistics(&info, &schema);
+        Ok(Arc::new(FlightExec::new(
+            self.url.clone(),
+            query,
+            info.endpoint,
+            schema,
+            statistics,
+        )))
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        // the filters are still evaluated locally, so that the results do not
+        // depend on the remote service applying them exactly like DataFusion
+        Ok(filters
+            .iter()
+            .map(|filter| match filter_to_sql(filter) {
+                Ok(_) => TableProviderFilterPushDown::Inexact,
+                Err(_) => TableProviderFilterPushDown::Unsupported,
+            })
+            .collect())
+    }
+
+    fn statistics(&self) -> Option<Statistics> {
+        Some(self.statistics.clone())
+    }
+}
+
+/// Execution plan reading the endpoints of a [`FlightInfo`], one partition per
+/// endpoint.
+#[derive(Debug)]
+struct FlightExec {
+    /// URL of the service to use for endpoints that have no location
+    url: String,
+    /// The query the endpoints were planned for, for display only
+    query: String,
+    endpoints: Vec<FlightEndpoint>,
+    schema: SchemaRef,
+    statistics: Statistics,
+    cache: PlanProperties,
+}
+
+impl FlightExec {
+    fn new(
+        url: String,
+        query: String,
+        endpoints: Vec<FlightEndpoint>,
+        schema: SchemaRef,
+        statistics: Statistics,
+    ) -> Self {
+        let cache = PlanProperties::new(
+            EquivalenceProperties::new(schema.clone()),
+            Partitioning::UnknownPartitioning(endpoints.len().max(1)),
+            ExecutionMode::Bounded,
+        );
+        Self {
+            url,
+            query,
+            endpoints,
+            schema,
+            statistics,
+            cache,
+        }
+    }
+}
+
+impl DisplayAs for FlightExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "FlightExec: url={}, endpoints={}, query={}",
+            self.url,
+            self.endpoints.len(),
+            self.query
+        )
+    }
+}
+
+impl ExecutionPlan for FlightExec {
+    fn name(&self) -> &'static str {
+        "FlightExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let schema = self.schema.clone();
+        let Some(endpoint) = self.endpoints.get(partition) else {
+            // the service returned no endpoints at all
+            return Ok(Box::pin(EmptyRecordBatchStream::new(schema)));
+        };
+
+        let ticket = endpoint.ticket.clone().ok_or_else(|| {
+            exec_datafusion_err!("Flight endpoint {partition} has no ticket")
+        })?;
+        let url = endpoint
+            .location
+            .first()
+            .map(|location| location.uri.clone())
+            .unwrap_or_else(|| self.url.clone());
+
+        let batches = futures::stream::once(async move {
+            let mut client = connect(&url).await?;
+            let stream = client.do_get(ticket).await.map_err(flight_err)?;
+            Ok::<_, DataFusionError>(stream.map_err(flight_err))
+        })
+        .try_flatten();
+
+        let output_schema = schema.clone();
+        let batches = batches.map(move |batch| {
+            let batch = batch?;
+            if output_schema.fields().is_empty() {
+                // only the number of rows was requested
+                let options =
+                    RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+                return Ok(RecordBatch::try_new_with_options(
+                    output_schema.clone(),
+                    vec![],
+                    &options,
+                )?);
+            }
+            Ok(batch)
+        });
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(schema, batches)))
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        Ok(self.statistics.clone())
+    }
+}
+
+async fn connect(url: &str) -> Result<FlightClient> {
+    let channel = Channel::from_shared(url.to_string())
+        .map_err(|e| DataFusionError::External(Box::new(e)))?
+        .connect()
+        .await
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    Ok(FlightClient::new(channel))
+}
+
+async fn get_flight_info(url: &str, query: String) -> Result<FlightInfo> {
+    let mut client = connect(url).await?;
+    client
+        .get_flight_info(FlightDescriptor::new_cmd(query))
+        .await
+        .map_err(flight_err)
+}
+
+fn decode_schema(info: &FlightInfo) -> Result<Schema> {
+    Ok(info.clone().try_decode_schema()?)
+}
+
+/// Statistics of a table or scan, from the totals reported by the service.
+/// Negative totals mean that the service does not know them.
+fn info_statistics(info: &FlightInfo, schema: &Schema) -> Statistics {
+    let total = |value: i64| match usize::try_from(value) {
+        Ok(value) => Precision::Exact(value),
+        Err(_) => Precision::Absent,
+    };
+    Statistics {
+        num_rows: total(info.total_records),
+        total_byte_size: total(info.total_bytes),
+        column_statistics: Statistics::unknown_column(schema),
+    }
+}
+
+/// Returns the SQL for a filter, with the columns unqualified, as the table is
+/// known under a different name on the remote service
+fn filter_to_sql(filter: &Expr) -> Result<String> {
+    let filter = filter
+        .clone()
+        .transform(|expr| match expr {
+            Expr::Column(column) => Ok(Transformed::yes(Expr::Column(
+                Column::new_unqualified(column.name),
+            ))),
+            _ => Ok(Transformed::no(expr)),
+        })
+        .data()?;
+    Ok(expr_to_sql(&filter)?.to_string())
+}
+
+fn quote_ident(name: &str) -> String {
+    Ident::with_quote('"', name).to_string()
+}
+
+fn flight_err(e: arrow_flight::error::FlightError) -> DataFusionError {
+    DataFusionError::External(Box::new(e))
+}
@@ -242,7 +242,10 @@ config_namespace! {
         /// The default time zone
         ///
         /// Some functions, e.g. `EXTRACT(HOUR from SOME_TIME)`, shift the underlying datetime
-        /// according to this time zone, and then extract the hour
+        /// according to this time zone, and then extract the hour.
+        ///
+        /// Timestamps without a time zone are interpreted in this time zone when they
+        /// are combined with timestamps with a time zone
         pub time_zone: Option<String>, default = Some("+00:00".into())
 
         /// Parquet options
@@ -311,6 +314,88 @@ config_namespace! {
 
         /// Should Datafusion keep the columns used for partition_by in the output RecordBatches
         pub keep_partition_by_columns: bool, default = false
+
+        /// If set, the maximum wall-clock time in milliseconds a query may run
+        /// before it is aborted with an `ExecutionTimeout` error
+        pub query_timeout_ms: Option<u64>, default = None
+
+        /// If set, the maximum memory in bytes the operators of a single query
+        /// may reserve. This limit applies in addition to the limit of the
+        /// memory pool of the runtime, which is shared by all queries
+        pub query_memory_limit: Option<usize>, default = None
+
+        /// How the memory of `query_memory_limit` is shared by the operators of
+        /// a query. `spill` limits each operator that can spill to a fair share
+        /// of the memory so they spill before the limit is reached, while
+        /// `fail` grants memory on a first come first served basis, failing the
+        /// query once the limit is reached
+        pub query_memory_policy: QueryMemoryPolicy, default = QueryMemoryPolicy::Spill
+
+        /// If set, the maximum disk space in bytes the temporary files spilled
+        /// by a single query may use. This limit applies in addition to the
+        /// limit of the disk manager of the runtime, which is shared by all queries
+        pub query_spill_limit: Option<u64>, default = None
+
+        /// Should the temporary files spilled to disk by sorts and aggregations
+        /// include a CRC32 checksum for each batch, verified when the batches
+        /// are read back. A corrupted file is then reported with a
+        /// `SpillFileCorrupted` error instead of failing to decode, at the
+        /// cost of computing the checksums
+        pub spill_checksums: bool, default = false
+
+        /// Should DataFusion create a `tracing` span for each partition of each
+        /// operator it executes, recording the operator name, the partition
+        /// and its metrics. Useful to export query traces with OpenTelemetry.
+        /// Requires the `tracing` feature
+        pub enable_tracing: bool, default = false
+
+        /// Should the tables created with `CREATE TABLE` compute the minimum
+        /// and maximum values of the columns of each batch when data is
+        /// inserted, so that their scans skip the batches that can not match
+        /// the filters of a query. Filters on these tables are then also
+        /// pushed down to the table scan
+        pub memory_table_zone_maps: bool, default = false
+
+        /// Should `sum` and `avg` of `Decimal128` values with a precision
+        /// above 28 compute and return `Decimal256` values. Otherwise their
+        /// result is a `Decimal128`, whose 38 digits may overflow
+        pub widen_decimal_aggregates: bool, default = false
+
+        /// Time only one in this many of the batches processed by each
+        /// partition of an operator for its `elapsed_compute` metric, counting
+        /// the others as taking the same time. Values above 1 lower the
+        /// overhead of reading the clock, to keep the metrics of production
+        /// queries at the cost of approximate compute times. 1 times every batch
+        pub metrics_sampling_interval: usize, default = 1
+
+        /// What integer `+`, `-`, `*` and `sum` do when their result does not
+        /// fit in its type: `wrap` silently wraps around, `error` fails with
+        /// an arithmetic overflow error as required by ANSI SQL, and
+        /// `saturate` clamps the result to the minimum or maximum of the type.
+        /// The deprecated `datafusion.execution.arithmetic_overflow_check`
+        /// option sets it to `error` when true and to `wrap` when false
+        pub arithmetic_overflow_mode: ArithmeticOverflowMode, default = ArithmeticOverflowMode::Wrap
+
+        /// Should aggregations grouping by several columns intern the distinct
+        /// values of their string and binary group keys, storing a fixed size
+        /// id for each group instead of a copy of the value. This reduces the
+        /// memory used when key values repeat across many groups and speeds
+        /// up comparing group keys, at the cost of an extra hash table lookup
+        pub intern_string_group_keys: bool, default = false
+
+        /// Should transaction control statements, such as `BEGIN`, `COMMIT`,
+        /// `ROLLBACK` and `SET TRANSACTION`, be accepted and ignored with a
+        /// warning. DataFusion does not support transactions, but tools such as
+        /// ORMs and connection pools issue these statements. Otherwise executing
+        /// them fails
+        pub ignore_transaction_statements: bool, default = false
+
+        /// Should hash joins find the build side matches of each distinct join
+        /// key of a probe batch once, and stream the combinations of its probe
+        /// and build rows in batches of `batch_size` rows. This speeds up joins
+        /// on keys with many duplicates on both sides, at the cost of an extra
+        /// hash table lookup per probe row
+        pub hash_join_group_duplicate_keys: bool, default = false
     }
 }
 
@@ -351,6 +436,12 @@ config_namespace! {
         /// the filters are applied in the same order as written in the query
         pub reorder_filters: bool, default = false
 
+        /// If true, and no sort order was specified for the table, the sort
+        /// order of the scanned files is inferred from the `sorting_columns`
+        /// in their metadata, provided all files declare the same order.
+        /// This requires reading the metadata of every file when planning
+        pub infer_sort_order: bool, default = false
+
         // The following map to parquet::file::properties::WriterProperties
 
         /// Sets best effort maximum size of data page in bytes
@@ -591,6 +682,12 @@ config_namespace! {
 
         /// When set to true, the optimizer will not attempt to convert Union to Interleave
         pub prefer_existing_union: bool, default = false
+
+        /// When set to true, an aggregate or window whose input is already hash
+        /// partitioned on a subset of its partition keys keeps that partitioning
+        /// instead of repartitioning on all of the keys. This saves a shuffle, but
+        /// can cause severe partition skew if the subset has few distinct values
+        pub prefer_existing_subset_partitioning: bool, default = false
     }
 }
 
@@ -656,6 +753,18 @@ impl ConfigField for ConfigOptions {
         let (key, rem) = key.split_once('.').unwrap_or((key, ""));
         match key {
             "catalog" => self.catalog.set(rem, value),
+            // `execution.arithmetic_overflow_check` is the deprecated boolean
+            // form of `execution.arithmetic_overflow_mode`
+            "execution" if rem == "arithmetic_overflow_check" => {
+                let mut check = false;
+                check.set(rem, value)?;
+                self.execution.arithmetic_overflow_mode = if check {
+                    ArithmeticOverflowMode::Error
+                } else {
+                    ArithmeticOverflowMode::Wrap
+                };
+                Ok(())
+            }
             "execution" => self.execution.set(rem, value),
             "optimizer" => self.optimizer.set(rem, value),
             "explain" => self.explain.set(rem, value),
@@ -983,6 +1092,101 @@ impl ConfigField for u8 {
     }
 }
 
+/// How the memory of a query is shared by its operators, see
+/// [`ExecutionOptions::query_memory_limit`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryMemoryPolicy {
+    /// Grant memory on a first come first served basis, failing the query once
+    /// the limit is reached
+    Fail,
+    /// Limit each operator that can spill to a fair share of the memory
+    /// available to spilling operators
+    Spill,
+}
+
+impl FromStr for QueryMemoryPolicy {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "fail" => Ok(Self::Fail),
+            "spill" => Ok(Self::Spill),
+            _ => _config_err!(
+                "Invalid query memory policy '{s}', expected one of: fail, spill"
+            ),
+        }
+    }
+}
+
+impl Display for QueryMemoryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fail => write!(f, "fail"),
+            Self::Spill => write!(f, "spill"),
+        }
+    }
+}
+
+impl ConfigField for QueryMemoryPolicy {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = value.parse()?;
+        Ok(())
+    }
+}
+
+/// What integer arithmetic does on overflow, see
+/// [`ExecutionOptions::arithmetic_overflow_mode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ArithmeticOverflowMode {
+    /// Wrap around, keeping the low order bits of the result
+    #[default]
+    Wrap,
+    /// Fail with an arithmetic overflow error
+    Error,
+    /// Clamp the result to the minimum or maximum value of its type
+    Saturate,
+}
+
+impl FromStr for ArithmeticOverflowMode {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "wrap" => Ok(Self::Wrap),
+            "error" => Ok(Self::Error),
+            "saturate" => Ok(Self::Saturate),
+            _ => _config_err!(
+                "Invalid arithmetic overflow mode '{s}', expected one of: wrap, error, saturate"
+            ),
+        }
+    }
+}
+
+impl Display for ArithmeticOverflowMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Wrap => write!(f, "wrap"),
+            Self::Error => write!(f, "error"),
+            Self::Saturate => write!(f, "saturate"),
+        }
+    }
+}
+
+impl ConfigField for ArithmeticOverflowMode {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = value.parse()?;
+        Ok(())
+    }
+}
+
 impl ConfigField for CompressionTypeVariant {
     fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
         v.some(key, self, description)
@@ -1727,8 +1931,8 @@ mod tests {
     use std::collections::HashMap;
 
     use crate::config::{
-        ConfigEntry, ConfigExtension, ConfigFileType, ExtensionOptions, Extensions,
-        TableOptions,
+        ArithmeticOverflowMode, ConfigEntry, ConfigExtension, ConfigFileType,
+        ConfigOptions, ExtensionOptions, Extensions, TableOptions,
     };
 
     #[derive(Default, Debug, Clone)]
@@ -1874,4 +2078,22 @@ mod tests {
         let parsed_metadata = table_config.parquet.key_value_metadata;
         assert_eq!(parsed_metadata.get("key_dupe"), Some(&Some("B".into())));
     }
+
+    #[test]
+    fn deprecated_arithmetic_overflow_check() {
+        let mut config = ConfigOptions::new();
+        let key = "datafusion.execution.arithmetic_overflow_check";
+
+        config.set(key, "true").unwrap();
+        assert_eq!(
+            config.execution.arithmetic_overflow_mode,
+            ArithmeticOverflowMode::Error
+        );
+        config.set(key, "false").unwrap();
+        assert_eq!(
+            config.execution.arithmetic_overflow_mode,
+            ArithmeticOverflowMode::Wrap
+        );
+        assert!(config.set(key, "maybe").is_err());
+    }
 }
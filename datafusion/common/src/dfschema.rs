@@ -1048,6 +1048,57 @@ impl SchemaExt for Schema {
     }
 }
 
+/// Describes, one line per column, how the fields of `actual` differ from
+/// the fields of `expected`.
+///
+/// Only the columns whose name or (logical) type differ are listed, with
+/// their 1-based position, so that mismatches between wide schemas remain
+/// readable. When the types differ but `actual` can be cast to the expected
+/// type, an `arrow_cast` is suggested.
+pub fn schema_mismatch_details(expected: &Schema, actual: &Schema) -> String {
+    let mut details = vec![];
+    if expected.fields().len() != actual.fields().len() {
+        details.push(format!(
+            "expected {} columns, found {}",
+            expected.fields().len(),
+            actual.fields().len()
+        ));
+    }
+
+    for (i, (expected, actual)) in expected
+        .fields()
+        .iter()
+        .zip(actual.fields().iter())
+        .enumerate()
+    {
+        let same_type = DFSchema::datatype_is_logically_equal(
+            expected.data_type(),
+            actual.data_type(),
+        );
+        if same_type && expected.name() == actual.name() {
+            continue;
+        }
+
+        let mut detail = format!(
+            "column {}: expected {} (type: {}), found {} (type: {})",
+            i + 1,
+            expected.name(),
+            expected.data_type(),
+            actual.name(),
+            actual.data_type()
+        );
+        if !same_type && can_cast_types(actual.data_type(), expected.data_type()) {
+            detail.push_str(&format!(
+                ", consider arrow_cast({}, '{}')",
+                actual.name(),
+                expected.data_type()
+            ));
+        }
+        details.push(detail);
+    }
+    details.join("\n")
+}
+
 pub fn qualified_name(qualifier: Option<&TableReference>, name: &str) -> String {
     match qualifier {
         Some(q) => format!("{}.{}", q, name),
@@ -1363,6 +1414,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_schema_mismatch_details() {
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Boolean, true),
+            Field::new("d", DataType::Int32, true),
+        ]);
+        let actual = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new(
+                "b",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                true,
+            ),
+            Field::new("c", DataType::Utf8, true),
+            Field::new("x", DataType::Int32, true),
+        ]);
+        assert_eq!(
+            schema_mismatch_details(&expected, &actual),
+            "column 3: expected c (type: Boolean), found c (type: Utf8), consider arrow_cast(c, 'Boolean')\n\
+             column 4: expected d (type: Int32), found x (type: Int32)"
+        );
+
+        let actual = Schema::new(vec![Field::new(
+            "a",
+            DataType::Struct(Fields::empty()),
+            true,
+        )]);
+        assert_eq!(
+            schema_mismatch_details(&expected, &actual),
+            "expected 4 columns, found 1\n\
+             column 1: expected a (type: Int64), found a (type: Struct([]))"
+        );
+
+        assert_eq!(schema_mismatch_details(&expected, &expected), "");
+    }
+
     fn test_schema_2() -> Schema {
         Schema::new(vec![
             Field::new("c100", DataType::Boolean, true),
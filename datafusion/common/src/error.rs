@@ -23,8 +23,10 @@ use std::borrow::Cow;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::io;
+use std::path::PathBuf;
 use std::result;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::utils::quote_identifier;
 use crate::{Column, DFSchema, TableReference};
@@ -117,6 +119,18 @@ pub enum DataFusionError {
     /// This error is thrown when a consumer cannot acquire additional memory
     /// or other resources needed to execute the query from the Memory Manager.
     ResourcesExhausted(String),
+    /// Error when the execution of a query takes longer than the configured
+    /// timeout.
+    ///
+    /// 1st argument is the timeout, 2nd argument is the plan annotated with
+    /// the metrics collected until the query was aborted.
+    ExecutionTimeout(Duration, String),
+    /// Error when a temporary file spilled to disk is corrupted, as detected
+    /// by its checksums.
+    ///
+    /// 1st argument is the path of the file, 2nd argument describes the
+    /// corruption.
+    SpillFileCorrupted(PathBuf, String),
     /// Errors originating from outside DataFusion's core codebase.
     ///
     /// For example, a custom S3Error from the crate datafusion-objectstore-s3
@@ -307,6 +321,8 @@ impl Error for DataFusionError {
             DataFusionError::SchemaError(e, _) => Some(e),
             DataFusionError::Execution(_) => None,
             DataFusionError::ResourcesExhausted(_) => None,
+            DataFusionError::ExecutionTimeout(_, _) => None,
+            DataFusionError::SpillFileCorrupted(_, _) => None,
             DataFusionError::External(e) => Some(e.as_ref()),
             DataFusionError::Context(_, e) => Some(e.as_ref()),
             DataFusionError::Substrait(_) => None,
@@ -418,6 +434,8 @@ impl DataFusionError {
             DataFusionError::SchemaError(_, _) => "Schema error: ",
             DataFusionError::Execution(_) => "Execution error: ",
             DataFusionError::ResourcesExhausted(_) => "Resources exhausted: ",
+            DataFusionError::ExecutionTimeout(_, _) => "Execution timeout: ",
+            DataFusionError::SpillFileCorrupted(_, _) => "Spill file corrupted: ",
             DataFusionError::External(_) => "External error: ",
             DataFusionError::Context(_, _) => "",
             DataFusionError::Substrait(_) => "Substrait error: ",
@@ -453,6 +471,14 @@ impl DataFusionError {
             }
             DataFusionError::Execution(ref desc) => Cow::Owned(desc.to_string()),
             DataFusionError::ResourcesExhausted(ref desc) => Cow::Owned(desc.to_string()),
+            DataFusionError::ExecutionTimeout(ref timeout, ref metrics) => {
+                Cow::Owned(format!(
+                    "query did not complete within {timeout:?}\npartial metrics:\n{metrics}"
+                ))
+            }
+            DataFusionError::SpillFileCorrupted(ref path, ref desc) => {
+                Cow::Owned(format!("{}: {desc}", path.display()))
+            }
             DataFusionError::External(ref desc) => Cow::Owned(desc.to_string()),
             #[cfg(feature = "object_store")]
             DataFusionError::ObjectStore(ref desc) => Cow::Owned(desc.to_string()),
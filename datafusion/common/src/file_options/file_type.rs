@@ -28,6 +28,8 @@ pub const DEFAULT_AVRO_EXTENSION: &str = ".avro";
 pub const DEFAULT_CSV_EXTENSION: &str = ".csv";
 /// The default file extension of json files
 pub const DEFAULT_JSON_EXTENSION: &str = ".json";
+/// The default file extension of orc files
+pub const DEFAULT_ORC_EXTENSION: &str = ".orc";
 /// The default file extension of parquet files
 pub const DEFAULT_PARQUET_EXTENSION: &str = ".parquet";
 
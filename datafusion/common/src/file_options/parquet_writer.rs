@@ -77,6 +77,7 @@ impl TryFrom<&TableParquetOptions> for ParquetWriterOptions {
             metadata_size_hint: _,
             pushdown_filters: _,
             reorder_filters: _,
+            infer_sort_order: _,
             allow_single_file_parallelism: _,
             maximum_parallel_row_group_writers: _,
             maximum_buffered_record_batches_per_stream: _,
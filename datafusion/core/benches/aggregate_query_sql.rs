@@ -144,6 +144,42 @@ fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    c.bench_function("aggregate_query_group_by_variance_u64_narrow", |b| {
+        b.iter(|| {
+            query(
+                ctx.clone(),
+                "SELECT u64_narrow, VAR(f64), VAR_POP(f64), STDDEV(f64) \
+                 FROM t GROUP BY u64_narrow",
+            )
+        })
+    });
+
+    c.bench_function("aggregate_query_group_by_variance_u64_wide", |b| {
+        b.iter(|| {
+            query(
+                ctx.clone(),
+                "SELECT u64_wide, VAR(f64), VAR_POP(f64), STDDEV(f64) \
+                 FROM t GROUP BY u64_wide",
+            )
+        })
+    });
+
+    let interned_ctx = create_context(partitions_len, array_len, batch_size).unwrap();
+    query(
+        interned_ctx.clone(),
+        "SET datafusion.execution.intern_string_group_keys = true",
+    );
+
+    c.bench_function("aggregate_query_group_by_u64_multiple_keys_interned", |b| {
+        b.iter(|| {
+            query(
+                interned_ctx.clone(),
+                "SELECT u64_wide, utf8, MIN(f64), AVG(f64), COUNT(f64) \
+                 FROM t GROUP BY u64_wide, utf8",
+            )
+        })
+    });
+
     c.bench_function("aggregate_query_approx_percentile_cont_on_u64", |b| {
         b.iter(|| {
             query(
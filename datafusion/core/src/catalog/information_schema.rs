@@ -25,7 +25,7 @@ use std::{any::Any, sync::Arc};
 
 use arrow::{
     array::{StringBuilder, UInt64Builder},
-    datatypes::{DataType, Field, Schema, SchemaRef},
+    datatypes::{DataType, Field, IntervalUnit, Schema, SchemaRef, TimeUnit},
     record_batch::RecordBatch,
 };
 
@@ -178,6 +178,7 @@ impl InformationSchemaConfig {
                     if let Some(schema) = catalog.schema(&schema_name) {
                         for table_name in schema.table_names() {
                             if let Some(table) = schema.table(&table_name).await? {
+                                let partition_cols = table.partition_columns();
                                 for (field_position, field) in
                                     table.schema().fields().iter().enumerate()
                                 {
@@ -187,6 +188,7 @@ impl InformationSchemaConfig {
                                         &table_name,
                                         field_position,
                                         field,
+                                        partition_cols.contains(field.name()),
                                     )
                                 }
                             }
@@ -449,6 +451,7 @@ impl InformationSchemaColumns {
             Field::new("numeric_scale", DataType::UInt64, true),
             Field::new("datetime_precision", DataType::UInt64, true),
             Field::new("interval_type", DataType::Utf8, true),
+            Field::new("is_partition", DataType::Utf8, false),
         ]));
 
         Self { schema, config }
@@ -476,6 +479,7 @@ impl InformationSchemaColumns {
             numeric_scales: UInt64Builder::with_capacity(default_capacity),
             datetime_precisions: UInt64Builder::with_capacity(default_capacity),
             interval_types: StringBuilder::new(),
+            is_partitions: StringBuilder::new(),
             schema: self.schema.clone(),
         }
     }
@@ -503,6 +507,8 @@ impl PartitionStream for InformationSchemaColumns {
 /// Builds the `information_schema.COLUMNS` table row by row
 ///
 /// Columns are based on <https://www.postgresql.org/docs/current/infoschema-columns.html>
+/// followed by the DataFusion specific `is_partition` column, as also
+/// reported by `DESCRIBE`
 struct InformationSchemaColumnsBuilder {
     schema: SchemaRef,
     catalog_names: StringBuilder,
@@ -520,6 +526,7 @@ struct InformationSchemaColumnsBuilder {
     numeric_scales: UInt64Builder,
     datetime_precisions: UInt64Builder,
     interval_types: StringBuilder,
+    is_partitions: StringBuilder,
 }
 
 impl InformationSchemaColumnsBuilder {
@@ -530,6 +537,7 @@ impl InformationSchemaColumnsBuilder {
         table_name: &str,
         field_position: usize,
         field: &Field,
+        is_partition: bool,
     ) {
         use DataType::*;
 
@@ -613,8 +621,34 @@ impl InformationSchemaColumnsBuilder {
         self.numeric_precision_radixes.append_option(numeric_radix);
         self.numeric_scales.append_option(numeric_scale);
 
-        self.datetime_precisions.append_option(None);
-        self.interval_types.append_null();
+        // datetime_precision: "If data_type identifies a date, time,
+        // timestamp, or interval type, this column contains the (declared or
+        // implicit) fractional seconds precision of the type for this column,
+        // that is, the number of decimal digits maintained following the
+        // decimal point in the seconds value. For all other data types, this
+        // column is null."
+        //
+        // interval_type: "If data_type identifies an interval type, this
+        // column contains the specification which fields the intervals include
+        // for this column, e.g., YEAR TO MONTH, DAY TO SECOND, etc. If no field
+        // restrictions were specified (that is, the interval accepts all
+        // fields), and for all other data types, this field is null."
+        let (datetime_precision, interval_type) = match field.data_type() {
+            Date32 | Date64 => (Some(0), None),
+            Time32(unit) | Time64(unit) | Timestamp(unit, _) | Duration(unit) => {
+                (Some(fractional_seconds_digits(unit)), None)
+            }
+            Interval(IntervalUnit::YearMonth) => (Some(0), Some("YEAR TO MONTH")),
+            Interval(IntervalUnit::DayTime) => (Some(3), Some("DAY TO SECOND")),
+            Interval(IntervalUnit::MonthDayNano) => (Some(9), None),
+            _ => (None, None),
+        };
+        self.datetime_precisions.append_option(datetime_precision);
+        self.interval_types.append_option(interval_type);
+
+        // "YES if the table is partitioned by the column, NO otherwise"
+        let partition_str = if is_partition { "YES" } else { "NO" };
+        self.is_partitions.append_value(partition_str);
     }
 
     fn finish(&mut self) -> RecordBatch {
@@ -636,12 +670,23 @@ impl InformationSchemaColumnsBuilder {
                 Arc::new(self.numeric_scales.finish()),
                 Arc::new(self.datetime_precisions.finish()),
                 Arc::new(self.interval_types.finish()),
+                Arc::new(self.is_partitions.finish()),
             ],
         )
         .unwrap()
     }
 }
 
+/// Returns the number of decimal digits of the fractional seconds of `unit`
+fn fractional_seconds_digits(unit: &TimeUnit) -> u64 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 3,
+        TimeUnit::Microsecond => 6,
+        TimeUnit::Nanosecond => 9,
+    }
+}
+
 struct InformationSchemata {
     schema: SchemaRef,
     config: InformationSchemaConfig,
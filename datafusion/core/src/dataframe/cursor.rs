@@ -0,0 +1,304 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`DataFrameCursor`]: pull based access to the results of a [`DataFrame`]
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use super::{DataFrame, RecordBatch};
+use crate::error::Result;
+use crate::physical_plan::stream::RecordBatchReceiverStream;
+use crate::physical_plan::{
+    execute_stream_partitioned, RecordBatchStream, SendableRecordBatchStream,
+};
+
+use arrow_schema::SchemaRef;
+use datafusion_common::{exec_datafusion_err, plan_err};
+use futures::stream::{AbortHandle, Abortable};
+use futures::{Stream, StreamExt};
+
+impl DataFrame {
+    /// Executes this DataFrame and returns a [`DataFrameCursor`] to pull its
+    /// results from, one [`RecordBatch`] at a time.
+    ///
+    /// All output partitions are executed concurrently, but at most
+    /// `max_outstanding_batches` batches are buffered ahead of the consumer:
+    /// once the buffer is full, execution pauses until the consumer catches
+    /// up. This makes the cursor suitable for client drivers (such as ODBC or
+    /// ADBC drivers) that fetch results at their own pace.
+    ///
+    /// The order of the batches is only preserved if the plan has a single
+    /// output partition.
+    ///
+    /// Like [`Self::execute_stream`], the execution is aborted when the
+    /// [`CancellationToken`] of the DataFrame is cancelled or when it runs
+    /// for longer than the configured query timeout.
+    ///
+    /// [`CancellationToken`]: crate::execution::CancellationToken
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use futures::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let mut cursor = df.execute_cursor(2).await?;
+    /// // the schema is known before any batch is fetched
+    /// assert_eq!(cursor.schema().fields().len(), 3);
+    /// while let Some(batch) = cursor.next().await {
+    ///     let batch = batch?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_cursor(
+        self,
+        max_outstanding_batches: usize,
+    ) -> Result<DataFrameCursor> {
+        if max_outstanding_batches == 0 {
+            return plan_err!("max_outstanding_batches must be greater than 0");
+        }
+        let task_ctx = Arc::new(self.task_ctx());
+        let plan = self.create_physical_plan().await?;
+        let schema = plan.schema();
+
+        // the partition streams stop when the query is cancelled or times out
+        let mut streams = execute_stream_partitioned(plan, task_ctx)?;
+        let stream = match streams.len() {
+            1 => streams.remove(0),
+            _ => {
+                let mut builder = RecordBatchReceiverStream::builder(
+                    Arc::clone(&schema),
+                    max_outstanding_batches,
+                );
+                for mut stream in streams {
+                    let output = builder.tx();
+                    builder.spawn(async move {
+                        while let Some(item) = stream.next().await {
+                            let is_err = item.is_err();
+                            // stop if the cursor is gone or after the first error
+                            if output.send(item).await.is_err() || is_err {
+                                break;
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+                builder.build()
+            }
+        };
+
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        Ok(DataFrameCursor {
+            schema,
+            stream: Some(Abortable::new(stream, registration)),
+            abort_handle,
+        })
+    }
+}
+
+/// A cursor over the results of a [`DataFrame`], created by
+/// [`DataFrame::execute_cursor`].
+///
+/// The cursor is a [`RecordBatchStream`], so batches are pulled with
+/// [`StreamExt::next`]. Execution can be cancelled from another task or
+/// thread using the handle returned by [`Self::cancel_handle`].
+///
+/// Dropping the cursor also cancels the execution and frees any allocated
+/// resources.
+pub struct DataFrameCursor {
+    schema: SchemaRef,
+    /// The results, or `None` once execution has been cancelled
+    stream: Option<Abortable<SendableRecordBatchStream>>,
+    abort_handle: AbortHandle,
+}
+
+impl DataFrameCursor {
+    /// Returns a handle that cancels the execution of this cursor.
+    ///
+    /// After [`AbortHandle::abort`] is called, a pending or subsequent fetch
+    /// returns an error, the execution is stopped and its resources are
+    /// released.
+    pub fn cancel_handle(&self) -> AbortHandle {
+        self.abort_handle.clone()
+    }
+
+    /// Returns `true` if this cursor has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.abort_handle.is_aborted()
+    }
+}
+
+impl Stream for DataFrameCursor {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let Some(stream) = self.stream.as_mut() else {
+            return Poll::Ready(None);
+        };
+        match stream.poll_next_unpin(cx) {
+            Poll::Ready(None) if stream.is_aborted() => {
+                // release the resources of the execution right away
+                self.stream = None;
+                Poll::Ready(Some(Err(exec_datafusion_err!(
+                    "DataFrame cursor was cancelled"
+                ))))
+            }
+            other => other,
+        }
+    }
+}
+
+impl RecordBatchStream for DataFrameCursor {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::MemTable;
+    use crate::error::DataFusionError;
+    use crate::prelude::{SessionConfig, SessionContext};
+
+    use std::time::Duration;
+
+    use arrow::array::Int32Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    /// Returns a DataFrame over `num_partitions` partitions of
+    /// `batches_per_partition` batches with 100 rows each
+    fn test_dataframe(
+        num_partitions: usize,
+        batches_per_partition: usize,
+    ) -> Result<DataFrame> {
+        test_dataframe_with_context(
+            &SessionContext::new(),
+            num_partitions,
+            batches_per_partition,
+        )
+    }
+
+    /// Same as [`test_dataframe`], for a DataFrame of `ctx`
+    fn test_dataframe_with_context(
+        ctx: &SessionContext,
+        num_partitions: usize,
+        batches_per_partition: usize,
+    ) -> Result<DataFrame> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(Int32Array::from_iter_values(0..100))],
+        )?;
+        let partitions = vec![vec![batch; batches_per_partition]; num_partitions];
+        let table = MemTable::try_new(schema, partitions)?;
+        ctx.read_table(Arc::new(table))
+    }
+
+    #[tokio::test]
+    async fn cursor_returns_all_batches() -> Result<()> {
+        let mut cursor = test_dataframe(4, 10)?.execute_cursor(1).await?;
+        assert_eq!(cursor.schema().fields().len(), 1);
+
+        let mut num_rows = 0;
+        while let Some(batch) = cursor.next().await {
+            num_rows += batch?.num_rows();
+        }
+        assert_eq!(num_rows, 4000);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cursor_cancellation() -> Result<()> {
+        let mut cursor = test_dataframe(4, 10)?.execute_cursor(1).await?;
+        assert!(cursor.next().await.unwrap().is_ok());
+
+        cursor.cancel_handle().abort();
+        assert!(cursor.is_cancelled());
+
+        let err = cursor.next().await.unwrap().unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: DataFrame cursor was cancelled"
+        );
+        assert!(cursor.next().await.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cursor_cancelled_by_session() -> Result<()> {
+        let ctx = SessionContext::new();
+        let mut cursor = test_dataframe_with_context(&ctx, 4, 10)?
+            .execute_cursor(1)
+            .await?;
+        assert!(cursor.next().await.unwrap().is_ok());
+
+        ctx.cancel_queries();
+        // the batches buffered before the cancellation may still be returned
+        let err = loop {
+            match cursor.next().await {
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => break e,
+                None => panic!("cursor was not cancelled"),
+            }
+        };
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: Query was cancelled"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cursor_timeout() -> Result<()> {
+        let timeout = Duration::from_millis(10);
+        let config = SessionConfig::new().with_query_timeout(timeout);
+        let ctx = SessionContext::new_with_config(config);
+        let mut cursor = test_dataframe_with_context(&ctx, 1, 10)?
+            .execute_cursor(1)
+            .await?;
+        assert!(cursor.next().await.unwrap().is_ok());
+
+        // the consumer is slower than the deadline of the query
+        tokio::time::sleep(timeout * 5).await;
+        match cursor.next().await.unwrap().unwrap_err() {
+            DataFusionError::ExecutionTimeout(t, _) => assert_eq!(t, timeout),
+            other => panic!("unexpected error: {other}"),
+        }
+        assert!(cursor.next().await.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cursor_requires_buffer() -> Result<()> {
+        let err = test_dataframe(1, 1)?.execute_cursor(0).await.err().unwrap();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: max_outstanding_batches must be greater than 0"
+        );
+        Ok(())
+    }
+}
@@ -17,8 +17,15 @@
 
 //! [`DataFrame`] API for building and executing query plans.
 
+mod cursor;
 #[cfg(feature = "parquet")]
 mod parquet;
+mod validate;
+
+pub use cursor::DataFrameCursor;
+pub use validate::{
+    ValidationCheck, ValidationIssue, ValidationReport, ValidationSeverity,
+};
 
 use std::any::Any;
 use std::collections::HashMap;
@@ -32,6 +39,7 @@ use crate::datasource::file_format::json::JsonFormatFactory;
 use crate::datasource::{provider_as_source, MemTable, TableProvider};
 use crate::error::Result;
 use crate::execution::context::{SessionState, TaskContext};
+use crate::execution::CancellationToken;
 use crate::execution::FunctionRegistry;
 use crate::logical_expr::utils::find_window_exprs;
 use crate::logical_expr::{
@@ -1032,6 +1040,70 @@ impl DataFrame {
         TaskContext::from(self.session_state.as_ref())
     }
 
+    /// Use `token` to cancel the execution of this DataFrame.
+    ///
+    /// Calling [`CancellationToken::cancel`], for example from another task
+    /// or thread, makes a running [`Self::collect`] or a stream returned by
+    /// [`Self::execute_stream`] return an error and stops the execution.
+    ///
+    /// By default, a DataFrame uses the token of the [`SessionContext`] it
+    /// was created from, see [`SessionContext::cancel_queries`].
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion::execution::CancellationToken;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let token = CancellationToken::new();
+    /// let df = ctx
+    ///     .read_csv("tests/data/example.csv", CsvReadOptions::new())
+    ///     .await?
+    ///     .with_cancellation_token(token.clone());
+    /// // e.g. from another thread
+    /// token.cancel();
+    /// assert!(df.collect().await.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.session_state.set_cancellation_token(token);
+        self
+    }
+
+    /// Use `task_id` as the id of the [`TaskContext`] that executes this
+    /// DataFrame.
+    ///
+    /// By default, every execution gets a random id. Setting it makes it
+    /// possible to correlate the execution with its tracing spans, recorded
+    /// when `datafusion.execution.enable_tracing` is set, with its spill
+    /// metrics, both of which record the id as `task_id`, and with its spill
+    /// files, whose names start with `datafusion-<task_id>-` (with the
+    /// characters other than ASCII letters, digits, `-` and `_` replaced).
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx
+    ///     .read_csv("tests/data/example.csv", CsvReadOptions::new())
+    ///     .await?
+    ///     .with_task_id("query-1");
+    /// assert_eq!(df.task_ctx().task_id().as_deref(), Some("query-1"));
+    /// let stream = df.execute_stream().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_task_id(mut self, task_id: impl Into<String>) -> Self {
+        self.session_state.set_task_id(task_id);
+        self
+    }
+
     /// Executes this DataFrame and returns a stream over a single partition
     ///
     /// See [Self::collect] to buffer the `RecordBatch`es in memory.
@@ -1703,6 +1775,7 @@ mod tests {
     use datafusion_functions_aggregate::expr_fn::count_distinct;
     use datafusion_physical_expr::expressions::Column;
     use datafusion_physical_plan::{get_plan_string, ExecutionPlanProperties};
+    use futures::StreamExt;
 
     // Get string representation of the plan
     async fn assert_physical_plan(df: &DataFrame, expected: Vec<&str>) {
@@ -3546,4 +3619,41 @@ mod tests {
         assert!(result.is_err());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn cancel_dataframe() -> Result<()> {
+        let ctx = SessionContext::new();
+        let token = CancellationToken::new();
+        let df = ctx
+            .sql("select count(*) from (values (1), (2)) group by column1")
+            .await?
+            .with_cancellation_token(token.clone());
+
+        // other queries of the session are not affected
+        assert!(!ctx.sql("select 1").await?.collect().await?.is_empty());
+
+        token.cancel();
+        let err = df.collect().await.unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: Query was cancelled"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_session_queries() -> Result<()> {
+        let ctx = SessionContext::new();
+        let before = ctx.sql("select 1").await?;
+        ctx.cancel_queries();
+        let after = ctx.sql("select 1").await?;
+
+        let err = before.execute_stream().await?.next().await.unwrap();
+        assert_eq!(
+            err.unwrap_err().strip_backtrace(),
+            "Execution error: Query was cancelled"
+        );
+        assert_eq!(after.collect().await?[0].num_rows(), 1);
+        Ok(())
+    }
 }
@@ -0,0 +1,428 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ValidationReport`]: checking that a [`DataFrame`] can run without
+//! running it
+
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+use std::sync::Arc;
+
+use super::DataFrame;
+use crate::datasource::listing::{ListingTable, ListingTableUrl};
+use crate::datasource::source_as_provider;
+use crate::datasource::TableProvider;
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::logical_expr::LogicalPlan;
+
+use arrow::compute::can_cast_types;
+use datafusion_common::tree_node::TreeNodeRecursion;
+use datafusion_common::TableReference;
+use futures::TryStreamExt;
+
+impl DataFrame {
+    /// Checks that this DataFrame can be executed, without executing it.
+    ///
+    /// The plan is fully planned, including physical planning, and the
+    /// files of the listing tables it scans are listed and their schemas are
+    /// read, to check that:
+    ///
+    /// * the plan is valid and all its expressions can be planned,
+    /// * the object stores of the tables are registered and accessible,
+    /// * the tables have files, and
+    /// * the columns of the files can be read as the columns of the tables.
+    ///
+    /// The problems that are found are returned in the [`ValidationReport`]
+    /// rather than as an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let report = df.select_columns(&["a"])?.validate().await?;
+    /// assert!(report.is_valid());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate(self) -> Result<ValidationReport> {
+        let mut report = ValidationReport::default();
+        if matches!(self.plan, LogicalPlan::Ddl(_) | LogicalPlan::Statement(_)) {
+            report.push(
+                ValidationCheck::Planning,
+                ValidationSeverity::Warning,
+                None,
+                format!("Only queries are validated, not {}", self.plan.display()),
+            );
+            return Ok(report);
+        }
+
+        if let Err(e) = self.session_state.create_physical_plan(&self.plan).await {
+            report.push(
+                ValidationCheck::Planning,
+                ValidationSeverity::Error,
+                None,
+                e.strip_backtrace(),
+            );
+        }
+
+        for (table_name, table) in listing_tables(&self.plan)? {
+            let table = table
+                .as_any()
+                .downcast_ref::<ListingTable>()
+                .expect("listing table");
+            for table_path in table.table_paths() {
+                validate_table_path(
+                    &self.session_state,
+                    &table_name,
+                    table,
+                    table_path,
+                    &mut report,
+                )
+                .await;
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// Returns the listing tables scanned by `plan` and its subqueries, once each
+fn listing_tables(
+    plan: &LogicalPlan,
+) -> Result<Vec<(TableReference, Arc<dyn TableProvider>)>> {
+    let mut seen = HashSet::new();
+    let mut tables = vec![];
+    plan.apply_with_subqueries(|node| {
+        if let LogicalPlan::TableScan(scan) = node {
+            if let Ok(provider) = source_as_provider(&scan.source) {
+                if provider.as_any().is::<ListingTable>()
+                    && seen.insert(scan.table_name.clone())
+                {
+                    tables.push((scan.table_name.clone(), provider));
+                }
+            }
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+    Ok(tables)
+}
+
+/// Lists the files of `table_path` and checks that their schema is
+/// compatible with the schema of `table`
+async fn validate_table_path(
+    state: &SessionState,
+    table_name: &TableReference,
+    table: &ListingTable,
+    table_path: &ListingTableUrl,
+    report: &mut ValidationReport,
+) {
+    let error = |report: &mut ValidationReport, message: String| {
+        report.push(
+            ValidationCheck::FileAccess,
+            ValidationSeverity::Error,
+            Some(table_name.clone()),
+            message,
+        )
+    };
+
+    let store = match state.runtime_env().object_store(table_path) {
+        Ok(store) => store,
+        Err(e) => return error(report, e.strip_backtrace()),
+    };
+    let options = table.options();
+    let files = match table_path
+        .list_all_files(state, store.as_ref(), &options.file_extension)
+        .await
+    {
+        Ok(files) => files.try_collect::<Vec<_>>().await,
+        Err(e) => Err(e),
+    };
+    let files = match files {
+        Ok(files) => files,
+        Err(e) => return error(report, format!("Cannot list {table_path}: {e}")),
+    };
+    if files.is_empty() {
+        report.push(
+            ValidationCheck::FileAccess,
+            ValidationSeverity::Warning,
+            Some(table_name.clone()),
+            format!("No files found in {table_path}"),
+        );
+        return;
+    }
+    let file_schema = match options.format.infer_schema(state, &store, &files).await {
+        Ok(schema) => schema,
+        Err(e) => {
+            return error(
+                report,
+                format!("Cannot read the schema of the files in {table_path}: {e}"),
+            )
+        }
+    };
+
+    let table_schema = table.schema();
+    for field in table_schema.fields() {
+        let is_partition_col = options
+            .table_partition_cols
+            .iter()
+            .any(|(name, _)| name == field.name());
+        if is_partition_col {
+            continue;
+        }
+        let name = field.name();
+        let Ok(file_field) = file_schema.field_with_name(name) else {
+            report.push(
+                ValidationCheck::Schema,
+                ValidationSeverity::Warning,
+                Some(table_name.clone()),
+                format!("Column {name} is not in {table_path} and will be read as null"),
+            );
+            continue;
+        };
+        let (from, to) = (file_field.data_type(), field.data_type());
+        if !can_cast_types(from, to) {
+            report.push(
+                ValidationCheck::Schema,
+                ValidationSeverity::Error,
+                Some(table_name.clone()),
+                format!(
+                    "Column {name} has type {from} in {table_path}, \
+                     which cannot be cast to {to}"
+                ),
+            );
+        }
+    }
+}
+
+/// The result of [`DataFrame::validate`]: the problems found while checking
+/// that a query can run
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns all the problems that were found
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Returns the problems that prevent the query from running
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+    }
+
+    /// Returns the problems that do not prevent the query from running, but
+    /// may indicate a mistake
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Warning)
+    }
+
+    /// Returns `true` if no errors were found
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    pub(crate) fn push(
+        &mut self,
+        check: ValidationCheck,
+        severity: ValidationSeverity,
+        table: Option<TableReference>,
+        message: String,
+    ) {
+        self.issues.push(ValidationIssue {
+            check,
+            severity,
+            table,
+            message,
+        })
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A problem found by [`DataFrame::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The check that found the problem
+    pub check: ValidationCheck,
+    /// Whether the problem prevents the query from running
+    pub severity: ValidationSeverity,
+    /// The table the problem relates to, if any
+    pub table: Option<TableReference>,
+    /// A description of the problem
+    pub message: String,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.severity, self.check)?;
+        if let Some(table) = &self.table {
+            write!(f, " [{table}]")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// The checks performed by [`DataFrame::validate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationCheck {
+    /// Logical and physical planning
+    Planning,
+    /// Accessing the object stores and listing the files of the tables
+    FileAccess,
+    /// Comparing the schemas of the files with the schemas of the tables
+    Schema,
+}
+
+impl Display for ValidationCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Planning => write!(f, "planning"),
+            Self::FileAccess => write!(f, "file access"),
+            Self::Schema => write!(f, "schema"),
+        }
+    }
+}
+
+/// The severity of a [`ValidationIssue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationSeverity {
+    /// The query can run, but may not behave as intended
+    Warning,
+    /// The query cannot run
+    Error,
+}
+
+impl Display for ValidationSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::file_format::csv::CsvFormat;
+    use crate::datasource::listing::{ListingOptions, ListingTableConfig};
+    use crate::prelude::{CsvReadOptions, SessionContext};
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn validate_valid_query() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.register_csv("example", "tests/data/example.csv", CsvReadOptions::new())
+            .await?;
+        let report = ctx.validate("SELECT a, b FROM example WHERE c > 1").await?;
+        assert!(report.is_valid(), "{report}");
+        assert!(report.issues().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validate_planning_error() -> Result<()> {
+        let ctx = SessionContext::new();
+        let report = ctx.validate("SELECT * FROM missing").await?;
+        assert!(!report.is_valid());
+        let issue = &report.issues()[0];
+        assert_eq!(issue.check, ValidationCheck::Planning);
+        assert_eq!(
+            issue.message,
+            "Error during planning: table 'datafusion.public.missing' not found"
+        );
+
+        let report = ctx.validate("CREATE TABLE t (a INT)").await?;
+        assert!(report.is_valid());
+        assert_eq!(report.warnings().count(), 1);
+        // the statement was not executed
+        assert!(!ctx.table_exist("t")?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validate_files() -> Result<()> {
+        let ctx = SessionContext::new();
+        let options = ListingOptions::new(Arc::new(CsvFormat::default()))
+            .with_file_extension(".csv");
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("z", DataType::Utf8, true),
+            Field::new(
+                "c",
+                DataType::Struct(vec![Field::new("x", DataType::Int64, true)].into()),
+                true,
+            ),
+        ]);
+        let register = |name: &str, path: &str| {
+            let config = ListingTableConfig::new(ListingTableUrl::parse(path)?)
+                .with_listing_options(options.clone())
+                .with_schema(Arc::new(schema.clone()));
+            ctx.register_table(name, Arc::new(ListingTable::try_new(config)?))
+        };
+        register("example", "tests/data/example.csv")?;
+        let empty_dir = TempDir::new()?;
+        register("empty", &format!("{}/", empty_dir.path().to_str().unwrap()))?;
+
+        let report = ctx.validate("SELECT * FROM example").await?;
+        let issues = report
+            .issues()
+            .iter()
+            .map(|issue| (issue.check, issue.severity))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            issues,
+            vec![
+                (ValidationCheck::Schema, ValidationSeverity::Warning),
+                (ValidationCheck::Schema, ValidationSeverity::Error),
+            ]
+        );
+        assert!(report.issues()[0].message.starts_with("Column z is not in"));
+        assert_eq!(
+            report.issues()[1].table,
+            Some(TableReference::bare("example"))
+        );
+
+        let report = ctx.validate("SELECT * FROM empty").await?;
+        assert!(report.is_valid());
+        assert_eq!(
+            report.warnings().next().unwrap().check,
+            ValidationCheck::FileAccess
+        );
+        Ok(())
+    }
+}
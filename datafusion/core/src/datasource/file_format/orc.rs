@@ -0,0 +1,246 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`OrcFormat`]: Apache ORC [`FileFormat`] abstractions
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::datatypes::Schema;
+use arrow::datatypes::SchemaRef;
+use async_trait::async_trait;
+use datafusion_common::parsers::CompressionTypeVariant;
+use datafusion_common::stats::Precision;
+use datafusion_common::{internal_err, GetExt, DEFAULT_ORC_EXTENSION};
+use datafusion_physical_expr::PhysicalExpr;
+use futures::{StreamExt, TryStreamExt};
+use object_store::{ObjectMeta, ObjectStore};
+
+use super::file_compression_type::FileCompressionType;
+use super::FileFormat;
+use super::FileFormatFactory;
+use crate::datasource::physical_plan::{fetch_orc_metadata, FileScanConfig, OrcExec};
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::physical_plan::ExecutionPlan;
+use crate::physical_plan::Statistics;
+
+#[derive(Default)]
+/// Factory struct used to create [OrcFormat]
+pub struct OrcFormatFactory;
+
+impl OrcFormatFactory {
+    /// Creates an instance of [OrcFormatFactory]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl FileFormatFactory for OrcFormatFactory {
+    fn create(
+        &self,
+        _state: &SessionState,
+        _format_options: &HashMap<String, String>,
+    ) -> Result<Arc<dyn FileFormat>> {
+        Ok(Arc::new(OrcFormat))
+    }
+
+    fn default(&self) -> Arc<dyn FileFormat> {
+        Arc::new(OrcFormat)
+    }
+}
+
+impl GetExt for OrcFormatFactory {
+    fn get_ext(&self) -> String {
+        // Removes the dot, i.e. ".orc" -> "orc"
+        DEFAULT_ORC_EXTENSION[1..].to_string()
+    }
+}
+
+/// Apache ORC `FileFormat` implementation.
+#[derive(Default, Debug)]
+pub struct OrcFormat;
+
+#[async_trait]
+impl FileFormat for OrcFormat {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_ext(&self) -> String {
+        OrcFormatFactory::new().get_ext()
+    }
+
+    fn get_ext_with_compression(
+        &self,
+        file_compression_type: &FileCompressionType,
+    ) -> Result<String> {
+        let ext = self.get_ext();
+        match file_compression_type.get_variant() {
+            CompressionTypeVariant::UNCOMPRESSED => Ok(ext),
+            _ => internal_err!("ORC FileFormat does not support compression."),
+        }
+    }
+
+    async fn infer_schema(
+        &self,
+        state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<SchemaRef> {
+        let schemas: Vec<Schema> = futures::stream::iter(objects)
+            .map(|object| async move {
+                let metadata = fetch_orc_metadata(store.clone(), object.clone()).await?;
+                Ok::<_, crate::error::DataFusionError>(
+                    metadata
+                        .root_data_type()
+                        .create_arrow_schema(&HashMap::new()),
+                )
+            })
+            .boxed()
+            .buffered(state.config_options().execution.meta_fetch_concurrency)
+            .try_collect()
+            .await?;
+
+        let merged_schema = Schema::try_merge(schemas)?;
+        Ok(Arc::new(merged_schema))
+    }
+
+    async fn infer_stats(
+        &self,
+        _state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        table_schema: SchemaRef,
+        object: &ObjectMeta,
+    ) -> Result<Statistics> {
+        let metadata = fetch_orc_metadata(store.clone(), object.clone()).await?;
+        let mut statistics = Statistics::new_unknown(&table_schema);
+        statistics.num_rows = Precision::Exact(metadata.number_of_rows() as usize);
+        Ok(statistics)
+    }
+
+    async fn create_physical_plan(
+        &self,
+        _state: &SessionState,
+        conf: FileScanConfig,
+        filters: Option<&Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let exec = OrcExec::new(conf, filters.cloned());
+        Ok(Arc::new(exec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::file_format::test_util::scan_format;
+    use crate::physical_plan::collect;
+    use crate::prelude::SessionContext;
+    use crate::test::object_store::local_unpartitioned_file;
+
+    use object_store::local::LocalFileSystem;
+
+    #[test]
+    fn orc_extension() {
+        assert_eq!(OrcFormat.get_ext(), "orc");
+        assert!(OrcFormat
+            .get_ext_with_compression(&FileCompressionType::GZIP)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn infer_orc_schema_and_stats() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let meta = local_unpartitioned_file("tests/data/two_stripes.orc");
+
+        let schema = OrcFormat
+            .infer_schema(&state, &store, &[meta.clone()])
+            .await?;
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| format!("{}: {:?}", f.name(), f.data_type()))
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec!["id: Int64", "name: Utf8"]);
+
+        let stats = OrcFormat.infer_stats(&state, &store, schema, &meta).await?;
+        assert_eq!(stats.num_rows, Precision::Exact(10));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_orc() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+        let exec = get_exec(&state, "two_stripes.orc", None).await?;
+        let batches = collect(exec, task_ctx).await?;
+
+        let expected = [
+            "+----+------+",
+            "| id | name |",
+            "+----+------+",
+            "| 1  | a    |",
+            "| 2  | bb   |",
+            "| 3  | c    |",
+            "| 4  | dd   |",
+            "| 5  | e    |",
+            "| 6  | f    |",
+            "| 7  | gg   |",
+            "| 8  | h    |",
+            "| 9  | ii   |",
+            "| 10 | j    |",
+            "+----+------+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_orc_projection() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+        let exec = get_exec(&state, "two_stripes.orc", Some(vec![1])).await?;
+        let batches = collect(exec, task_ctx).await?;
+
+        // only the projected column is decoded
+        for batch in &batches {
+            assert_eq!(batch.num_columns(), 1);
+        }
+        let expected = [
+            "+------+", "| name |", "+------+", "| a    |", "| bb   |", "| c    |",
+            "| dd   |", "| e    |", "| f    |", "| gg   |", "| h    |", "| ii   |",
+            "| j    |", "+------+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    async fn get_exec(
+        state: &SessionState,
+        file_name: &str,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // a file with two stripes: id 1 to 5 and id 6 to 10
+        let store_root = "tests/data";
+        scan_format(state, &OrcFormat, store_root, file_name, projection, None).await
+    }
+}
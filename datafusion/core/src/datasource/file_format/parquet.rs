@@ -25,7 +25,7 @@ use std::sync::Arc;
 use super::write::demux::start_demuxer_task;
 use super::write::{create_writer, SharedBuffer};
 use super::{FileFormat, FileFormatFactory, FileScanConfig};
-use crate::arrow::array::RecordBatch;
+use crate::arrow::array::{Array, RecordBatch};
 use crate::arrow::datatypes::{Fields, Schema, SchemaRef};
 use crate::datasource::file_format::file_compression_type::FileCompressionType;
 use crate::datasource::physical_plan::{FileGroupDisplay, FileSinkConfig};
@@ -38,19 +38,21 @@ use crate::physical_plan::{
     Statistics,
 };
 
-use arrow::compute::sum;
+use arrow::compute::{sum, SortOptions};
 use datafusion_common::config::{ConfigField, ConfigFileType, TableParquetOptions};
 use datafusion_common::file_options::parquet_writer::ParquetWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::stats::Precision;
 use datafusion_common::{
     exec_err, internal_datafusion_err, not_impl_err, DataFusionError, GetExt,
-    DEFAULT_PARQUET_EXTENSION,
+    ScalarValue, DEFAULT_PARQUET_EXTENSION,
 };
 use datafusion_common_runtime::SpawnedTask;
 use datafusion_execution::TaskContext;
-use datafusion_physical_expr::expressions::{MaxAccumulator, MinAccumulator};
-use datafusion_physical_expr::{PhysicalExpr, PhysicalSortRequirement};
+use datafusion_physical_expr::expressions::{Column, MaxAccumulator, MinAccumulator};
+use datafusion_physical_expr::{
+    LexOrdering, PhysicalExpr, PhysicalSortExpr, PhysicalSortRequirement,
+};
 use datafusion_physical_plan::metrics::MetricsSet;
 
 use async_trait::async_trait;
@@ -204,6 +206,21 @@ impl ParquetFormat {
         self.options.global.skip_metadata
     }
 
+    /// Infer the sort order of the scanned files from the `sorting_columns`
+    /// in their metadata when the table does not specify one.
+    ///
+    /// Defaults to `datafusion.execution.parquet.infer_sort_order`.
+    pub fn with_infer_sort_order(mut self, infer_sort_order: bool) -> Self {
+        self.options.global.infer_sort_order = infer_sort_order;
+        self
+    }
+
+    /// Returns `true` if the sort order of the scanned files is inferred
+    /// from their metadata.
+    pub fn infer_sort_order(&self) -> bool {
+        self.options.global.infer_sort_order
+    }
+
     /// Set Parquet options for the ParquetFormat
     pub fn with_options(mut self, options: TableParquetOptions) -> Self {
         self.options = options;
@@ -214,6 +231,152 @@ impl ParquetFormat {
     pub fn options(&self) -> &TableParquetOptions {
         &self.options
     }
+
+    /// Returns the sort order declared by the metadata of every file scanned
+    /// by `conf`, or `None` if the files do not all declare the same order
+    async fn infer_output_ordering(
+        &self,
+        state: &SessionState,
+        conf: &FileScanConfig,
+    ) -> Result<Option<LexOrdering>> {
+        let store = state.runtime_env().object_store(&conf.object_store_url)?;
+        let orderings: Vec<Option<LexOrdering>> =
+            futures::stream::iter(conf.file_groups.iter().flatten())
+                .map(|file| {
+                    let store = Arc::clone(&store);
+                    async move {
+                        let metadata = fetch_parquet_metadata(
+                            store.as_ref(),
+                            &file.object_meta,
+                            self.metadata_size_hint(),
+                        )
+                        .await?;
+                        file_sort_order(&metadata, &conf.file_schema)
+                    }
+                })
+                .boxed() // Workaround https://github.com/rust-lang/rust/issues/64552
+                .buffered(state.config_options().execution.meta_fetch_concurrency)
+                .try_collect()
+                .await?;
+
+        let mut orderings = orderings.into_iter();
+        let Some(first) = orderings.next().flatten() else {
+            return Ok(None);
+        };
+        if orderings.all(|ordering| ordering.as_ref() == Some(&first)) {
+            debug!("Inferred output ordering {first:?} from parquet metadata");
+            Ok(Some(first))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Returns the sort order of a parquet file, expressed against
+/// `table_schema`, as declared by the `sorting_columns` of its row groups.
+///
+/// `sorting_columns` only describe the order of rows within each row group,
+/// so for files with several row groups the order is only returned if all
+/// row groups declare the same `sorting_columns` and the statistics of the
+/// leading sort column show that the row groups themselves are in order.
+fn file_sort_order(
+    metadata: &ParquetMetaData,
+    table_schema: &Schema,
+) -> Result<Option<LexOrdering>> {
+    let row_groups = metadata.row_groups();
+    let Some(sorting_columns) = row_groups.first().and_then(|rg| rg.sorting_columns())
+    else {
+        return Ok(None);
+    };
+    if row_groups
+        .iter()
+        .any(|rg| rg.sorting_columns() != Some(sorting_columns))
+    {
+        return Ok(None);
+    }
+
+    let schema_descr = metadata.file_metadata().schema_descr();
+    let mut ordering = vec![];
+    let mut leading_column = None;
+    for sorting_column in sorting_columns {
+        let Ok(column_idx) = usize::try_from(sorting_column.column_idx) else {
+            break;
+        };
+        if column_idx >= schema_descr.num_columns() {
+            break;
+        }
+        // only top level columns can be referenced by the sort order
+        let column = schema_descr.column(column_idx);
+        if column.path().parts().len() != 1 {
+            break;
+        }
+        let Ok(idx) = table_schema.index_of(column.name()) else {
+            break;
+        };
+        leading_column.get_or_insert_with(|| column.name().to_string());
+        ordering.push(PhysicalSortExpr {
+            expr: Arc::new(Column::new(column.name(), idx)),
+            options: SortOptions {
+                descending: sorting_column.descending,
+                nulls_first: sorting_column.nulls_first,
+            },
+        });
+    }
+    if ordering.is_empty() {
+        return Ok(None);
+    }
+    if row_groups.len() == 1 {
+        return Ok(Some(ordering));
+    }
+
+    // Check that the row groups do not overlap on the leading sort column
+    let descending = ordering[0].options.descending;
+    let file_schema = parquet_to_arrow_schema(
+        schema_descr,
+        metadata.file_metadata().key_value_metadata(),
+    )?;
+    let Some(name) = leading_column else {
+        return Ok(None);
+    };
+    let converter = StatisticsConverter::try_new(&name, &file_schema, schema_descr)?;
+    let mins = converter.row_group_mins(row_groups)?;
+    let maxes = converter.row_group_maxes(row_groups)?;
+    let null_counts = converter.row_group_null_counts(row_groups)?;
+    // nulls may appear at either end of every row group
+    if mins.null_count() > 0
+        || maxes.null_count() > 0
+        || null_counts.null_count() > 0
+        || null_counts.values().iter().any(|count| *count > 0)
+    {
+        return Ok(None);
+    }
+
+    for i in 1..row_groups.len() {
+        let (last, next) = if descending {
+            (
+                ScalarValue::try_from_array(&mins, i - 1)?,
+                ScalarValue::try_from_array(&maxes, i)?,
+            )
+        } else {
+            (
+                ScalarValue::try_from_array(&maxes, i - 1)?,
+                ScalarValue::try_from_array(&mins, i)?,
+            )
+        };
+        let in_order = if descending {
+            last.partial_cmp(&next).map(|ord| ord.reverse())
+        } else {
+            last.partial_cmp(&next)
+        };
+        match in_order {
+            Some(std::cmp::Ordering::Less) => {}
+            // rows with the same leading value in adjacent row groups may
+            // not be ordered on the remaining sort columns
+            Some(std::cmp::Ordering::Equal) => ordering.truncate(1),
+            _ => return Ok(None),
+        }
+    }
+    Ok(Some(ordering))
 }
 
 /// Clears all metadata (Schema level and field level) on an iterator
@@ -326,10 +489,16 @@ impl FileFormat for ParquetFormat {
 
     async fn create_physical_plan(
         &self,
-        _state: &SessionState,
-        conf: FileScanConfig,
+        state: &SessionState,
+        mut conf: FileScanConfig,
         filters: Option<&Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
+        if conf.output_ordering.is_empty() && self.infer_sort_order() {
+            if let Some(ordering) = self.infer_output_ordering(state, &conf).await? {
+                conf.output_ordering = vec![ordering];
+            }
+        }
+
         let mut builder =
             ParquetExecBuilder::new_with_options(conf, self.options.clone());
 
@@ -1164,8 +1333,9 @@ mod tests {
     use super::*;
 
     use crate::datasource::file_format::parquet::test_util::store_parquet;
+    use crate::physical_plan::displayable;
     use crate::physical_plan::metrics::MetricValue;
-    use crate::prelude::{SessionConfig, SessionContext};
+    use crate::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
     use arrow::array::{Array, ArrayRef, StringArray};
     use arrow_array::types::Int32Type;
     use arrow_array::{DictionaryArray, Int32Array, Int64Array};
@@ -1188,10 +1358,11 @@ mod tests {
         GetOptions, GetResult, ListResult, MultipartUpload, PutMultipartOpts, PutOptions,
         PutPayload, PutResult,
     };
-    use parquet::arrow::arrow_reader::ArrowReaderOptions;
-    use parquet::arrow::ParquetRecordBatchStreamBuilder;
+    use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+    use parquet::arrow::{ArrowWriter, ParquetRecordBatchStreamBuilder};
     use parquet::file::metadata::{KeyValue, ParquetColumnIndex, ParquetOffsetIndex};
     use parquet::file::page_index::index::Index;
+    use parquet::format::SortingColumn;
     use tokio::fs::File;
 
     #[tokio::test]
@@ -1963,6 +2134,112 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    /// Writes the values of column `a` to an in-memory parquet file with
+    /// `row_group_size` rows per row group, declaring `sorting_columns`
+    fn sorted_parquet_metadata(
+        values: Vec<i32>,
+        row_group_size: usize,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> Result<Arc<ParquetMetaData>> {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int32Array::from(values)) as ArrayRef,
+        )])?;
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(row_group_size)
+            .set_sorting_columns(sorting_columns)
+            .build();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        let metadata =
+            ArrowReaderMetadata::load(&bytes::Bytes::from(buf), Default::default())?;
+        Ok(Arc::clone(metadata.metadata()))
+    }
+
+    #[test]
+    fn infer_file_sort_order() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let asc = Some(vec![SortingColumn::new(0, false, false)]);
+        let desc = Some(vec![SortingColumn::new(0, true, false)]);
+        let ordering = |descending| {
+            vec![PhysicalSortExpr {
+                expr: Arc::new(Column::new("a", 0)) as _,
+                options: SortOptions {
+                    descending,
+                    nulls_first: false,
+                },
+            }]
+        };
+
+        // no sorting_columns
+        let metadata = sorted_parquet_metadata(vec![1, 2, 3], 10, None)?;
+        assert_eq!(file_sort_order(&metadata, &schema)?, None);
+
+        // single row group
+        let metadata = sorted_parquet_metadata(vec![1, 2, 3], 10, asc.clone())?;
+        assert_eq!(file_sort_order(&metadata, &schema)?, Some(ordering(false)));
+
+        // ordered row groups
+        let metadata = sorted_parquet_metadata(vec![1, 2, 3, 4], 2, asc.clone())?;
+        assert_eq!(file_sort_order(&metadata, &schema)?, Some(ordering(false)));
+        let metadata = sorted_parquet_metadata(vec![4, 3, 2, 1], 2, desc)?;
+        assert_eq!(file_sort_order(&metadata, &schema)?, Some(ordering(true)));
+
+        // each row group is sorted, but the file is not
+        let metadata = sorted_parquet_metadata(vec![1, 2, 1, 2], 2, asc)?;
+        assert_eq!(file_sort_order(&metadata, &schema)?, None);
+
+        // the sort column is not part of the table
+        let schema = Schema::new(vec![Field::new("b", DataType::Int32, true)]);
+        let metadata = sorted_parquet_metadata(
+            vec![1, 2, 3],
+            10,
+            Some(vec![SortingColumn::new(0, false, false)]),
+        )?;
+        assert_eq!(file_sort_order(&metadata, &schema)?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn infer_output_ordering_from_metadata() -> Result<()> {
+        let tmp_dir = tempfile::TempDir::new()?;
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])?;
+        let props = WriterProperties::builder()
+            .set_sorting_columns(Some(vec![SortingColumn::new(0, false, false)]))
+            .build();
+        let path = tmp_dir.path().join("sorted.parquet");
+        let file = std::fs::File::create(&path)?;
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        let mut config = SessionConfig::new();
+        config.options_mut().execution.parquet.infer_sort_order = true;
+        let ctx = SessionContext::new_with_config(config);
+        ctx.register_parquet("t", path.to_str().unwrap(), ParquetReadOptions::default())
+            .await?;
+
+        let plan = ctx
+            .sql("SELECT a FROM t")
+            .await?
+            .create_physical_plan()
+            .await?;
+        let displayed = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(
+            displayed.contains("output_ordering=[a@0 ASC NULLS LAST]"),
+            "{displayed}"
+        );
+
+        Ok(())
+    }
+
     async fn get_exec(
         state: &SessionState,
         file_name: &str,
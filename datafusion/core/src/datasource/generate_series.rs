@@ -0,0 +1,662 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The `generate_series` and `range` table functions, which produce a
+//! series of integers or timestamps
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::timezone::Tz;
+use arrow::array::{
+    ArrayRef, Int64Array, RecordBatch, RecordBatchOptions, TimestampNanosecondArray,
+};
+use arrow::datatypes::{
+    DataType, Field, IntervalMonthDayNano, IntervalUnit, Schema, SchemaRef, TimeUnit,
+    TimestampNanosecondType,
+};
+use arrow_schema::SortOptions;
+use async_trait::async_trait;
+use datafusion_common::stats::Precision;
+use datafusion_common::{
+    internal_err, plan_err, project_schema, ColumnStatistics, ScalarValue, Statistics,
+};
+use datafusion_physical_expr::expressions::Column;
+use datafusion_physical_expr::{EquivalenceProperties, PhysicalSortExpr};
+
+use crate::datasource::function::TableFunctionImpl;
+use crate::datasource::{TableProvider, TableType};
+use crate::error::Result;
+use crate::execution::context::{SessionState, TaskContext};
+use crate::logical_expr::Expr;
+use crate::physical_plan::stream::RecordBatchStreamAdapter;
+use crate::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan, Partitioning,
+    PlanProperties, SendableRecordBatchStream,
+};
+
+/// The name of the column produced by [`GenerateSeriesFunc`]
+pub const GENERATE_SERIES_COLUMN: &str = "value";
+
+/// Table function producing the integers or timestamps from `start` to
+/// `stop` by `step`, in a single column named `value`:
+///
+/// * `generate_series(stop)`, `generate_series(start, stop)` and
+///   `generate_series(start, stop, step)` for integers, with `start`
+///   defaulting to 0 and `step` to 1
+/// * `generate_series(start, stop, interval)` for timestamps
+///
+/// `generate_series` includes `stop` in the series, while `range` excludes
+/// it. Both produce no rows when an argument is `NULL` or when `step` goes
+/// away from `stop`.
+///
+/// The series is generated lazily by a [`GenerateSeriesExec`].
+#[derive(Debug)]
+pub struct GenerateSeriesFunc {
+    name: &'static str,
+    include_end: bool,
+}
+
+impl GenerateSeriesFunc {
+    /// The `generate_series` function, whose series includes `stop`
+    pub fn generate_series() -> Self {
+        Self {
+            name: "generate_series",
+            include_end: true,
+        }
+    }
+
+    /// The `range` function, whose series excludes `stop`
+    pub fn range() -> Self {
+        Self {
+            name: "range",
+            include_end: false,
+        }
+    }
+}
+
+impl TableFunctionImpl for GenerateSeriesFunc {
+    fn call(&self, exprs: &[Expr]) -> Result<Arc<dyn TableProvider>> {
+        let args = exprs
+            .iter()
+            .map(|expr| match expr {
+                Expr::Literal(value) => Ok(value.clone()),
+                _ => {
+                    plan_err!("{} only accepts constant arguments, got {expr}", self.name)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let series = Series::try_new(self.name, &args, self.include_end)?;
+        Ok(Arc::new(GenerateSeriesTable { series }))
+    }
+}
+
+/// The step between two values of a [`Series`]
+#[derive(Debug, Clone)]
+enum Step {
+    Int64(i64),
+    /// An interval added to timestamps in the time zone
+    Interval(IntervalMonthDayNano, Tz),
+}
+
+/// The values from `start` to `stop` by `step`, as `i64`s
+#[derive(Debug, Clone)]
+struct Series {
+    start: i64,
+    stop: i64,
+    step: Step,
+    include_end: bool,
+    /// `Int64`, or `Timestamp(Nanosecond, _)` for series of timestamps
+    data_type: DataType,
+}
+
+impl Series {
+    fn try_new(name: &str, args: &[ScalarValue], include_end: bool) -> Result<Self> {
+        let (start, stop, step) = match args {
+            [stop] => (ScalarValue::Int64(Some(0)), stop, None),
+            [start, stop] => (start.clone(), stop, None),
+            [start, stop, step] => (start.clone(), stop, Some(step)),
+            _ => return plan_err!("{name} expects 1 to 3 arguments, got {}", args.len()),
+        };
+
+        let arg_type = [start.data_type(), stop.data_type()]
+            .into_iter()
+            .find(|t| !t.is_null())
+            .unwrap_or(DataType::Int64);
+        let (data_type, step) = match &arg_type {
+            DataType::Timestamp(_, tz) => {
+                let Some(step) = step else {
+                    return plan_err!(
+                        "{name} requires an interval step for timestamp arguments"
+                    );
+                };
+                let ScalarValue::IntervalMonthDayNano(step) =
+                    step.cast_to(&DataType::Interval(IntervalUnit::MonthDayNano))?
+                else {
+                    return internal_err!("Expected an interval step");
+                };
+                let parsed_tz = tz.as_deref().unwrap_or("+00:00").parse::<Tz>()?;
+                let data_type = DataType::Timestamp(TimeUnit::Nanosecond, tz.clone());
+                (data_type, step.map(|step| Step::Interval(step, parsed_tz)))
+            }
+            t if t.is_integer() => {
+                let step = match step {
+                    Some(step) => step.cast_to(&DataType::Int64)?,
+                    None => ScalarValue::Int64(Some(1)),
+                };
+                let ScalarValue::Int64(step) = step else {
+                    return internal_err!("Expected an integer step");
+                };
+                (DataType::Int64, step.map(Step::Int64))
+            }
+            t => return plan_err!("{name} does not support arguments of type {t}"),
+        };
+
+        let start = start.cast_to(&data_type)?;
+        let stop = stop.cast_to(&data_type)?;
+        let (Some(start), Some(stop), Some(step)) =
+            (scalar_to_i64(&start), scalar_to_i64(&stop), step)
+        else {
+            // a series with a NULL argument is empty
+            return Ok(Self {
+                start: 1,
+                stop: 0,
+                step: Step::Int64(1),
+                include_end,
+                data_type,
+            });
+        };
+
+        let series = Self {
+            start,
+            stop,
+            step,
+            include_end,
+            data_type,
+        };
+        match &series.step {
+            Step::Int64(0) => plan_err!("{name} step must not be zero"),
+            Step::Interval(step, _) => {
+                let parts = [step.months as i64, step.days as i64, step.nanoseconds];
+                if parts.iter().all(|p| *p == 0) {
+                    plan_err!("{name} step must not be zero")
+                } else if parts.iter().any(|p| *p > 0) && parts.iter().any(|p| *p < 0) {
+                    plan_err!("{name} step must not mix positive and negative parts")
+                } else {
+                    Ok(series)
+                }
+            }
+            Step::Int64(_) => Ok(series),
+        }
+    }
+
+    fn ascending(&self) -> bool {
+        match &self.step {
+            Step::Int64(step) => *step > 0,
+            Step::Interval(step, _) => {
+                step.months > 0 || step.days > 0 || step.nanoseconds > 0
+            }
+        }
+    }
+
+    /// Returns true if `value` is not past the end of the series
+    fn contains(&self, value: i64) -> bool {
+        let before_stop = if self.ascending() {
+            value < self.stop
+        } else {
+            value > self.stop
+        };
+        before_stop || (self.include_end && value == self.stop)
+    }
+
+    /// Returns the value following `value`, or `None` on overflow
+    fn next_value(&self, value: i64) -> Option<i64> {
+        match &self.step {
+            Step::Int64(step) => value.checked_add(*step),
+            Step::Interval(step, tz) => {
+                TimestampNanosecondType::add_month_day_nano(value, *step, *tz)
+            }
+        }
+    }
+
+    /// Returns the number of values of the series, if the step has a fixed
+    /// length
+    fn num_rows(&self) -> Option<usize> {
+        let step = match &self.step {
+            Step::Int64(step) => *step as i128,
+            Step::Interval(step, _) if step.months == 0 && step.days == 0 => {
+                step.nanoseconds as i128
+            }
+            Step::Interval(..) => return None,
+        };
+        let distance = self.stop as i128 - self.start as i128;
+        if distance != 0 && (distance > 0) != (step > 0) {
+            return Some(0);
+        }
+        let mut num_rows = distance / step + 1;
+        if !self.include_end && distance % step == 0 {
+            num_rows -= 1;
+        }
+        usize::try_from(num_rows).ok()
+    }
+
+    /// Returns the value at position `index` of the series, if the step has a
+    /// fixed length
+    fn nth_value(&self, index: usize) -> Option<i64> {
+        let step = match &self.step {
+            Step::Int64(step) => *step,
+            Step::Interval(step, _) if step.months == 0 && step.days == 0 => {
+                step.nanoseconds
+            }
+            Step::Interval(..) => return None,
+        };
+        step.checked_mul(i64::try_from(index).ok()?)?
+            .checked_add(self.start)
+    }
+
+    fn to_scalar(&self, value: i64) -> ScalarValue {
+        match &self.data_type {
+            DataType::Timestamp(_, tz) => {
+                ScalarValue::TimestampNanosecond(Some(value), tz.clone())
+            }
+            _ => ScalarValue::Int64(Some(value)),
+        }
+    }
+
+    fn step_scalar(&self) -> ScalarValue {
+        match &self.step {
+            Step::Int64(step) => ScalarValue::Int64(Some(*step)),
+            Step::Interval(step, _) => ScalarValue::IntervalMonthDayNano(Some(*step)),
+        }
+    }
+
+    fn to_array(&self, values: Vec<i64>) -> ArrayRef {
+        match &self.data_type {
+            DataType::Timestamp(_, tz) => Arc::new(
+                TimestampNanosecondArray::from(values).with_timezone_opt(tz.clone()),
+            ),
+            _ => Arc::new(Int64Array::from(values)),
+        }
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new(
+            GENERATE_SERIES_COLUMN,
+            self.data_type.clone(),
+            false,
+        )]))
+    }
+}
+
+fn scalar_to_i64(value: &ScalarValue) -> Option<i64> {
+    match value {
+        ScalarValue::Int64(v) | ScalarValue::TimestampNanosecond(v, _) => *v,
+        _ => None,
+    }
+}
+
+/// The table returned by [`GenerateSeriesFunc`]
+#[derive(Debug)]
+struct GenerateSeriesTable {
+    series: Series,
+}
+
+#[async_trait]
+impl TableProvider for GenerateSeriesTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.series.schema()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Temporary
+    }
+
+    async fn scan(
+        &self,
+        _state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = project_schema(&self.series.schema(), projection)?;
+        Ok(Arc::new(GenerateSeriesExec::new(
+            self.series.clone(),
+            schema,
+        )))
+    }
+}
+
+/// Execution plan generating the values of a `generate_series` or `range`
+/// table function in batches, in a single partition
+#[derive(Debug)]
+pub struct GenerateSeriesExec {
+    series: Series,
+    /// The output schema, which has no column if the values are not used
+    schema: SchemaRef,
+    cache: PlanProperties,
+}
+
+impl GenerateSeriesExec {
+    fn new(series: Series, schema: SchemaRef) -> Self {
+        let cache = Self::compute_properties(&series, Arc::clone(&schema));
+        Self {
+            series,
+            schema,
+            cache,
+        }
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(series: &Series, schema: SchemaRef) -> PlanProperties {
+        let mut eq_properties = EquivalenceProperties::new(Arc::clone(&schema));
+        if let Ok(index) = schema.index_of(GENERATE_SERIES_COLUMN) {
+            eq_properties.add_new_orderings([vec![PhysicalSortExpr {
+                expr: Arc::new(Column::new(GENERATE_SERIES_COLUMN, index)),
+                options: SortOptions {
+                    descending: !series.ascending(),
+                    nulls_first: false,
+                },
+            }]]);
+        }
+        PlanProperties::new(
+            eq_properties,
+            Partitioning::UnknownPartitioning(1),
+            ExecutionMode::Bounded,
+        )
+    }
+}
+
+impl DisplayAs for GenerateSeriesExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(
+                    f,
+                    "GenerateSeriesExec: start={}, {}={}, step={}",
+                    self.series.to_scalar(self.series.start),
+                    if self.series.include_end {
+                        "stop"
+                    } else {
+                        "end"
+                    },
+                    self.series.to_scalar(self.series.stop),
+                    self.series.step_scalar()
+                )
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for GenerateSeriesExec {
+    fn name(&self) -> &'static str {
+        "GenerateSeriesExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        if partition != 0 {
+            return internal_err!(
+                "GenerateSeriesExec invalid partition {partition} (expected 0)"
+            );
+        }
+        let batch_size = context.session_config().batch_size();
+        let series = self.series.clone();
+        let schema = Arc::clone(&self.schema);
+        let mut next = Some(series.start);
+        let batches = std::iter::from_fn(move || {
+            let mut values = Vec::with_capacity(batch_size);
+            while values.len() < batch_size {
+                match next {
+                    Some(value) if series.contains(value) => {
+                        values.push(value);
+                        next = series.next_value(value);
+                    }
+                    _ => {
+                        next = None;
+                        break;
+                    }
+                }
+            }
+            if values.is_empty() {
+                return None;
+            }
+            let options = RecordBatchOptions::new().with_row_count(Some(values.len()));
+            let columns = if schema.fields().is_empty() {
+                vec![]
+            } else {
+                vec![series.to_array(values)]
+            };
+            Some(
+                RecordBatch::try_new_with_options(Arc::clone(&schema), columns, &options)
+                    .map_err(Into::into),
+            )
+        });
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::iter(batches),
+        )))
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        let Some(num_rows) = self.series.num_rows() else {
+            return Ok(Statistics::new_unknown(&self.schema));
+        };
+        let column_statistics = self
+            .schema
+            .fields()
+            .iter()
+            .map(|_| {
+                let bounds = num_rows
+                    .checked_sub(1)
+                    .and_then(|last| self.series.nth_value(last))
+                    .map(|last| {
+                        let first = self.series.start;
+                        (
+                            self.series.to_scalar(first.min(last)),
+                            self.series.to_scalar(first.max(last)),
+                        )
+                    });
+                let (min_value, max_value) = match bounds {
+                    Some((min, max)) => (Precision::Exact(min), Precision::Exact(max)),
+                    None => (Precision::Absent, Precision::Absent),
+                };
+                ColumnStatistics {
+                    null_count: Precision::Exact(0),
+                    max_value,
+                    min_value,
+                    distinct_count: Precision::Exact(num_rows),
+                }
+            })
+            .collect();
+        Ok(Statistics {
+            num_rows: Precision::Exact(num_rows),
+            total_byte_size: Precision::Exact(num_rows * 8 * self.schema.fields().len()),
+            column_statistics,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::physical_plan::collect;
+    use crate::prelude::{SessionConfig, SessionContext};
+    use datafusion_common::assert_batches_eq;
+
+    fn series(args: &[ScalarValue], include_end: bool) -> Result<Series> {
+        Series::try_new("generate_series", args, include_end)
+    }
+
+    #[test]
+    fn series_num_rows() -> Result<()> {
+        let int = |v: i64| ScalarValue::Int64(Some(v));
+        let cases = [
+            (vec![int(5)], true, 6),
+            (vec![int(5)], false, 5),
+            (vec![int(1), int(10), int(3)], true, 4),
+            (vec![int(1), int(10), int(3)], false, 3),
+            (vec![int(1), int(10), int(9)], false, 1),
+            (vec![int(10), int(1), int(-4)], true, 3),
+            (vec![int(1), int(10), int(-1)], true, 0),
+            (vec![int(3), int(3)], true, 1),
+            (vec![int(3), int(3)], false, 0),
+            (vec![int(1), ScalarValue::Int64(None)], true, 0),
+        ];
+        for (args, include_end, expected) in cases {
+            let series = series(&args, include_end)?;
+            assert_eq!(series.num_rows(), Some(expected), "{args:?}");
+
+            // the generated values match the number of rows
+            let mut count = 0;
+            let mut next = Some(series.start);
+            while let Some(value) = next.filter(|v| series.contains(*v)) {
+                count += 1;
+                next = series.next_value(value);
+            }
+            assert_eq!(count, expected, "{args:?}");
+        }
+
+        let err = series(&[int(1), int(2), int(0)], true).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: generate_series step must not be zero"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn generate_series_sql() -> Result<()> {
+        let ctx =
+            SessionContext::new_with_config(SessionConfig::new().with_batch_size(2));
+
+        let batches = ctx
+            .sql("SELECT * FROM generate_series(1, 5, 2)")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(
+            [
+                "+-------+",
+                "| value |",
+                "+-------+",
+                "| 1     |",
+                "| 3     |",
+                "| 5     |",
+                "+-------+",
+            ],
+            &batches
+        );
+
+        let batches = ctx
+            .sql(
+                "SELECT * FROM range(TIMESTAMP '2024-01-31', TIMESTAMP '2024-04-30', \
+                 INTERVAL '1' MONTH)",
+            )
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(
+            [
+                "+---------------------+",
+                "| value               |",
+                "+---------------------+",
+                "| 2024-01-31T00:00:00 |",
+                "| 2024-02-29T00:00:00 |",
+                "| 2024-03-29T00:00:00 |",
+                "| 2024-04-29T00:00:00 |",
+                "+---------------------+",
+            ],
+            &batches
+        );
+
+        let batches = ctx
+            .sql("SELECT count(*) FROM generate_series(1000)")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(
+            [
+                "+----------+",
+                "| count(*) |",
+                "+----------+",
+                "| 1001     |",
+                "+----------+",
+            ],
+            &batches
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn generate_series_statistics() -> Result<()> {
+        let series = series(
+            &[
+                ScalarValue::Int64(Some(10)),
+                ScalarValue::Int64(Some(1)),
+                ScalarValue::Int64(Some(-3)),
+            ],
+            true,
+        )?;
+        let exec = GenerateSeriesExec::new(series.clone(), series.schema());
+        let statistics = exec.statistics()?;
+        assert_eq!(statistics.num_rows, Precision::Exact(4));
+        assert_eq!(
+            statistics.column_statistics[0].min_value,
+            Precision::Exact(ScalarValue::Int64(Some(1)))
+        );
+        assert_eq!(
+            statistics.column_statistics[0].max_value,
+            Precision::Exact(ScalarValue::Int64(Some(10)))
+        );
+
+        let batches = collect(Arc::new(exec), Arc::new(TaskContext::default())).await?;
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 4);
+        Ok(())
+    }
+}
@@ -0,0 +1,616 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Experimental materialized aggregate views that are refreshed
+//! incrementally, see [`IncrementalAggregateView`]
+
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow::compute::cast;
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::error::ArrowError;
+use arrow::ipc::reader::FileReader;
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use datafusion_common::{exec_datafusion_err, not_impl_err, plan_err, Column};
+use datafusion_expr::{max, min, LogicalPlanBuilder};
+use datafusion_functions_aggregate::expr_fn::sum;
+use futures::TryStreamExt;
+use object_store::ObjectMeta;
+use parking_lot::RwLock;
+
+use crate::dataframe::DataFrame;
+use crate::datasource::listing::{ListingTable, ListingTableConfig, ListingTableUrl};
+use crate::datasource::{provider_as_source, MemTable, TableProvider, TableType};
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::logical_expr::{Expr, LogicalPlan};
+use crate::physical_plan::memory::MemoryExec;
+use crate::physical_plan::ExecutionPlan;
+
+/// The name of the source table in the plans of an [`IncrementalAggregateView`]
+const SOURCE_TABLE: &str = "source";
+
+/// The metadata keys of the watermark in the state file of a view
+const LAST_MODIFIED_KEY: &str = "datafusion.incremental_view.last_modified";
+const FILES_KEY: &str = "datafusion.incremental_view.files";
+
+/// A materialized aggregate view over an append-only [`ListingTable`] that
+/// is refreshed incrementally.
+///
+/// **This is an experimental API.**
+///
+/// The view stores the result of aggregating the source table by
+/// `group_expr`. [`Self::refresh`] only reads the files that were added to
+/// the source table since the previous refresh, aggregates them, and merges
+/// the result into the stored one. The files that were already read are
+/// tracked with a high-watermark on their last modification time, stored in
+/// the view, so that the view registered in the catalog knows how far it has
+/// been refreshed.
+///
+/// The view is held in memory. To keep refreshing it incrementally across
+/// restarts, use [`Self::with_state_location`]: the stored result and the
+/// watermark are then written to an Arrow IPC file after each refresh, and
+/// restored from it when the view is created again.
+///
+/// The source table must be append-only: files must not be modified or
+/// removed once they are added, and new files must have a modification time
+/// that is not older than the files already read.
+///
+/// Only aggregates that can be merged are supported: `sum`, `count`, `min`
+/// and `max`, without `DISTINCT`. The columns of the source table are
+/// referred to without qualifier, or qualified with `source`.
+///
+/// # Example
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use datafusion::prelude::*;
+/// # use datafusion::error::Result;
+/// # use datafusion::datasource::incremental_view::IncrementalAggregateView;
+/// # use datafusion::datasource::listing::{ListingTable, ListingTableUrl};
+/// # use datafusion::functions_aggregate::expr_fn::sum;
+/// # async fn f(ctx: SessionContext, source: Arc<ListingTable>) -> Result<()> {
+/// let state = ListingTableUrl::parse("/var/lib/views/totals.arrow")?;
+/// let view = IncrementalAggregateView::try_new(
+///     source,
+///     vec![col("region")],
+///     vec![sum(col("amount")).alias("total")],
+/// )?
+/// .with_state_location(state, &ctx.state())
+/// .await?;
+/// let view = Arc::new(view);
+/// ctx.register_table("totals", Arc::clone(&view) as _)?;
+///
+/// // e.g. every hour, only reads the new files
+/// let new_files = view.refresh(&ctx.state()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct IncrementalAggregateView {
+    source: Arc<ListingTable>,
+    group_expr: Vec<Expr>,
+    aggr_expr: Vec<Expr>,
+    /// The aggregates that merge the stored result with the result of the
+    /// new files
+    merge_expr: Vec<Expr>,
+    schema: SchemaRef,
+    /// The file the view is persisted to, if any
+    state_location: Option<ListingTableUrl>,
+    state: RwLock<ViewState>,
+    /// Serializes the refreshes
+    refresh_lock: tokio::sync::Mutex<()>,
+}
+
+#[derive(Default)]
+struct ViewState {
+    batches: Vec<RecordBatch>,
+    watermark: Option<Watermark>,
+}
+
+/// The latest modification time of the files read by a view, and the files
+/// with that time, which have been read as well
+#[derive(Debug, Clone)]
+struct Watermark {
+    last_modified: DateTime<Utc>,
+    files: HashSet<String>,
+}
+
+impl Watermark {
+    fn is_new(&self, file: &ObjectMeta) -> bool {
+        file.last_modified > self.last_modified
+            || (file.last_modified == self.last_modified
+                && !self.files.contains(file.location.as_ref()))
+    }
+
+    /// Encodes the watermark as schema metadata
+    fn encode(&self) -> HashMap<String, String> {
+        let mut files = self.files.iter().map(String::as_str).collect::<Vec<_>>();
+        files.sort_unstable();
+        HashMap::from([
+            (
+                LAST_MODIFIED_KEY.to_string(),
+                self.last_modified.to_rfc3339(),
+            ),
+            (FILES_KEY.to_string(), files.join("\n")),
+        ])
+    }
+
+    /// Decodes the watermark from schema metadata, returning `None` if it
+    /// has none
+    fn decode(metadata: &HashMap<String, String>) -> Result<Option<Self>> {
+        let (Some(last_modified), Some(files)) =
+            (metadata.get(LAST_MODIFIED_KEY), metadata.get(FILES_KEY))
+        else {
+            return Ok(None);
+        };
+        let last_modified = DateTime::parse_from_rfc3339(last_modified)
+            .map_err(|e| exec_datafusion_err!("Invalid view watermark: {e}"))?
+            .with_timezone(&Utc);
+        let files = files
+            .split('\n')
+            .filter(|f| !f.is_empty())
+            .map(String::from)
+            .collect();
+        Ok(Some(Self {
+            last_modified,
+            files,
+        }))
+    }
+}
+
+impl IncrementalAggregateView {
+    /// Create a view aggregating `source` by `group_expr`.
+    ///
+    /// The view is empty until [`Self::refresh`] is called.
+    pub fn try_new(
+        source: Arc<ListingTable>,
+        group_expr: Vec<Expr>,
+        aggr_expr: Vec<Expr>,
+    ) -> Result<Self> {
+        if !source.options().table_partition_cols.is_empty() {
+            return not_impl_err!(
+                "Incremental views over partitioned tables are not supported"
+            );
+        }
+        let plan = aggregate_plan(
+            Arc::clone(&source) as _,
+            group_expr.clone(),
+            aggr_expr.clone(),
+        )?;
+        let schema: SchemaRef = Arc::new(plan.schema().as_arrow().clone());
+
+        let merge_expr = aggr_expr
+            .iter()
+            .zip(schema.fields().iter().skip(group_expr.len()))
+            .map(|(expr, field)| merge_expr(expr, field.name()))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            source,
+            group_expr,
+            aggr_expr,
+            merge_expr,
+            schema,
+            state_location: None,
+            state: RwLock::new(ViewState::default()),
+            refresh_lock: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    /// Persists the view to the Arrow IPC file at `location` after each
+    /// refresh, restoring it from that file if it already exists.
+    ///
+    /// The file must only be shared by views with the same source table and
+    /// aggregates.
+    pub async fn with_state_location(
+        mut self,
+        location: ListingTableUrl,
+        state: &SessionState,
+    ) -> Result<Self> {
+        let store = state.runtime_env().object_store(&location)?;
+        let result = match store.get(location.prefix()).await {
+            Ok(result) => Some(result),
+            Err(object_store::Error::NotFound { .. }) => None,
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(result) = result {
+            let reader = FileReader::try_new(Cursor::new(result.bytes().await?), None)?;
+            if reader.schema().fields().len() != self.schema.fields().len() {
+                return plan_err!(
+                    "The state file {location} does not match the schema of the view"
+                );
+            }
+            let watermark = Watermark::decode(reader.schema().metadata())?;
+            let batches = reader.collect::<Result<Vec<_>, ArrowError>>()?;
+            let batches = self.conform(batches)?;
+            *self.state.write() = ViewState { batches, watermark };
+        }
+        self.state_location = Some(location);
+        Ok(self)
+    }
+
+    /// The latest modification time of the files read by the view, or `None`
+    /// if it has never been refreshed
+    pub fn watermark(&self) -> Option<DateTime<Utc>> {
+        let state = self.state.read();
+        state.watermark.as_ref().map(|w| w.last_modified)
+    }
+
+    /// Reads the files added to the source table since the previous refresh
+    /// and merges them into the view, returning the number of files read.
+    pub async fn refresh(&self, state: &SessionState) -> Result<usize> {
+        let _guard = self.refresh_lock.lock().await;
+        let watermark = self.state.read().watermark.clone();
+
+        let mut new_files = vec![];
+        for table_path in self.source.table_paths() {
+            let store = state.runtime_env().object_store(table_path)?;
+            let files: Vec<_> = table_path
+                .list_all_files(
+                    state,
+                    store.as_ref(),
+                    &self.source.options().file_extension,
+                )
+                .await?
+                .try_collect()
+                .await?;
+            for file in files {
+                if watermark.as_ref().map(|w| w.is_new(&file)).unwrap_or(true) {
+                    new_files.push((table_path.object_store(), file));
+                }
+            }
+        }
+        if new_files.is_empty() {
+            return Ok(0);
+        }
+
+        // aggregate the new files
+        let paths = new_files
+            .iter()
+            .map(|(store, file)| {
+                ListingTableUrl::parse(format!("{}{}", store.as_str(), file.location))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let config = ListingTableConfig::new_with_multi_paths(paths)
+            .with_listing_options(self.source.options().clone())
+            .with_schema(self.source.schema());
+        let delta = Arc::new(ListingTable::try_new(config)?);
+        let plan =
+            aggregate_plan(delta, self.group_expr.clone(), self.aggr_expr.clone())?;
+        let delta_batches = DataFrame::new(state.clone(), plan).collect().await?;
+
+        // merge them into the stored result
+        let previous = self.state.read().batches.clone();
+        let batches = if previous.is_empty() {
+            delta_batches
+        } else {
+            let table = MemTable::try_new(
+                Arc::clone(&self.schema),
+                vec![previous, self.conform(delta_batches)?],
+            )?;
+            let group_expr = self
+                .schema
+                .fields()
+                .iter()
+                .take(self.group_expr.len())
+                .map(|field| Expr::Column(Column::from_name(field.name())))
+                .collect::<Vec<_>>();
+            let plan = LogicalPlanBuilder::scan(
+                SOURCE_TABLE,
+                provider_as_source(Arc::new(table)),
+                None,
+            )?
+            .aggregate(group_expr, self.merge_expr.clone())?
+            .build()?;
+            DataFrame::new(state.clone(), plan).collect().await?
+        };
+        let batches = self.conform(batches)?;
+
+        let mut watermark = watermark.unwrap_or_else(|| Watermark {
+            last_modified: DateTime::<Utc>::MIN_UTC,
+            files: HashSet::new(),
+        });
+        for (_, file) in &new_files {
+            let location = file.location.to_string();
+            if file.last_modified == watermark.last_modified {
+                watermark.files.insert(location);
+            } else if file.last_modified > watermark.last_modified {
+                watermark = Watermark {
+                    last_modified: file.last_modified,
+                    files: HashSet::from([location]),
+                };
+            }
+        }
+
+        self.persist(state, &batches, &watermark).await?;
+        *self.state.write() = ViewState {
+            batches,
+            watermark: Some(watermark),
+        };
+        Ok(new_files.len())
+    }
+
+    /// Writes `batches` and `watermark` to the state file of the view, if any
+    async fn persist(
+        &self,
+        state: &SessionState,
+        batches: &[RecordBatch],
+        watermark: &Watermark,
+    ) -> Result<()> {
+        let Some(location) = &self.state_location else {
+            return Ok(());
+        };
+        let schema =
+            Schema::new_with_metadata(self.schema.fields().clone(), watermark.encode());
+        let mut writer = FileWriter::try_new(Vec::new(), &schema)?;
+        for batch in batches {
+            writer.write(batch)?;
+        }
+        let buffer = writer.into_inner()?;
+
+        let store = state.runtime_env().object_store(location)?;
+        store.put(location.prefix(), buffer.into()).await?;
+        Ok(())
+    }
+
+    /// Casts `batches` to the schema of the view, as merging aggregates may
+    /// widen their types
+    fn conform(&self, batches: Vec<RecordBatch>) -> Result<Vec<RecordBatch>> {
+        batches
+            .iter()
+            .map(|batch| {
+                let columns = batch
+                    .columns()
+                    .iter()
+                    .zip(self.schema.fields())
+                    .map(|(column, field)| cast(column, field.data_type()))
+                    .collect::<Result<_, ArrowError>>()?;
+                Ok(RecordBatch::try_new(Arc::clone(&self.schema), columns)?)
+            })
+            .collect()
+    }
+}
+
+/// Returns the plan aggregating `table`
+fn aggregate_plan(
+    table: Arc<dyn TableProvider>,
+    group_expr: Vec<Expr>,
+    aggr_expr: Vec<Expr>,
+) -> Result<LogicalPlan> {
+    LogicalPlanBuilder::scan(SOURCE_TABLE, provider_as_source(table), None)?
+        .aggregate(group_expr, aggr_expr)?
+        .build()
+}
+
+/// Returns the aggregate merging the partial results of `expr` stored in the
+/// column `name`
+fn merge_expr(expr: &Expr, name: &str) -> Result<Expr> {
+    let Expr::AggregateFunction(func) = expr.clone().unalias() else {
+        return plan_err!("Expected an aggregate expression, got {expr}");
+    };
+    if func.distinct {
+        return not_impl_err!(
+            "DISTINCT aggregates are not supported by incremental views"
+        );
+    }
+    let column = Expr::Column(Column::from_name(name));
+    let merged = match func.func_def.name().to_lowercase().as_str() {
+        "sum" | "count" => sum(column),
+        "min" => min(column),
+        "max" => max(column),
+        other => {
+            return not_impl_err!(
+                "Aggregate function {other} is not supported by incremental views"
+            )
+        }
+    };
+    Ok(merged.alias(name))
+}
+
+#[async_trait]
+impl TableProvider for IncrementalAggregateView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let batches = self.state.read().batches.clone();
+        Ok(Arc::new(MemoryExec::try_new(
+            &[batches],
+            self.schema(),
+            projection.cloned(),
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_batches_sorted_eq;
+    use crate::datasource::file_format::csv::CsvFormat;
+    use crate::datasource::listing::ListingOptions;
+    use crate::functions_aggregate::expr_fn::{avg, count};
+    use crate::prelude::{col, SessionContext};
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use tempfile::TempDir;
+
+    /// Returns a CSV table over `dir`, with columns `k` and `v`
+    fn csv_source(dir: &TempDir) -> Result<Arc<ListingTable>> {
+        let schema = Schema::new(vec![
+            Field::new("k", DataType::Utf8, false),
+            Field::new("v", DataType::Int64, false),
+        ]);
+        let options = ListingOptions::new(Arc::new(CsvFormat::default()))
+            .with_file_extension(".csv");
+        let config = ListingTableConfig::new(ListingTableUrl::parse(format!(
+            "{}/",
+            dir.path().to_str().unwrap()
+        ))?)
+        .with_listing_options(options)
+        .with_schema(Arc::new(schema));
+        Ok(Arc::new(ListingTable::try_new(config)?))
+    }
+
+    fn test_view(dir: &TempDir) -> Result<IncrementalAggregateView> {
+        IncrementalAggregateView::try_new(
+            csv_source(dir)?,
+            vec![col("k")],
+            vec![
+                sum(col("v")).alias("total"),
+                count(col("v")).alias("n"),
+                min(col("v")).alias("lo"),
+                max(col("v")).alias("hi"),
+            ],
+        )
+    }
+
+    #[tokio::test]
+    async fn refresh_incrementally() -> Result<()> {
+        let dir = TempDir::new()?;
+        let write = |name: &str, content: &str| {
+            std::fs::write(dir.path().join(name), format!("k,v\n{content}"))
+        };
+        write("1.csv", "a,1\nb,2\na,3\n")?;
+
+        let ctx = SessionContext::new();
+        let view = Arc::new(test_view(&dir)?);
+        ctx.register_table("view", Arc::clone(&view) as _)?;
+        assert_eq!(view.watermark(), None);
+
+        let query = "SELECT * FROM view";
+        let results = ctx.sql(query).await?.collect().await?;
+        assert!(results.iter().all(|b| b.num_rows() == 0));
+
+        assert_eq!(view.refresh(&ctx.state()).await?, 1);
+        assert!(view.watermark().is_some());
+        let expected = [
+            "+---+-------+---+----+----+",
+            "| k | total | n | lo | hi |",
+            "+---+-------+---+----+----+",
+            "| a | 4     | 2 | 1  | 3  |",
+            "| b | 2     | 1 | 2  | 2  |",
+            "+---+-------+---+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &ctx.sql(query).await?.collect().await?);
+
+        // only the new file is read
+        assert_eq!(view.refresh(&ctx.state()).await?, 0);
+        write("2.csv", "a,10\nc,5\n")?;
+        assert_eq!(view.refresh(&ctx.state()).await?, 1);
+        let expected = [
+            "+---+-------+---+----+----+",
+            "| k | total | n | lo | hi |",
+            "+---+-------+---+----+----+",
+            "| a | 14    | 3 | 1  | 10 |",
+            "| b | 2     | 1 | 2  | 2  |",
+            "| c | 5     | 1 | 5  | 5  |",
+            "+---+-------+---+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &ctx.sql(query).await?.collect().await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_incrementally_after_restart() -> Result<()> {
+        let dir = TempDir::new()?;
+        let state_dir = TempDir::new()?;
+        let write = |name: &str, content: &str| {
+            std::fs::write(dir.path().join(name), format!("k,v\n{content}"))
+        };
+        let state_location = ListingTableUrl::parse(
+            state_dir.path().join("view.arrow").to_str().unwrap(),
+        )?;
+        write("1.csv", "a,1\nb,2\na,3\n")?;
+
+        let ctx = SessionContext::new();
+        let view = test_view(&dir)?
+            .with_state_location(state_location.clone(), &ctx.state())
+            .await?;
+        assert_eq!(view.watermark(), None);
+        assert_eq!(view.refresh(&ctx.state()).await?, 1);
+        let watermark = view.watermark();
+        drop(view);
+
+        // the view is restored from its state file when registered again
+        let ctx = SessionContext::new();
+        let view = test_view(&dir)?
+            .with_state_location(state_location, &ctx.state())
+            .await?;
+        let view = Arc::new(view);
+        ctx.register_table("view", Arc::clone(&view) as _)?;
+        assert_eq!(view.watermark(), watermark);
+
+        let query = "SELECT * FROM view";
+        let expected = [
+            "+---+-------+---+----+----+",
+            "| k | total | n | lo | hi |",
+            "+---+-------+---+----+----+",
+            "| a | 4     | 2 | 1  | 3  |",
+            "| b | 2     | 1 | 2  | 2  |",
+            "+---+-------+---+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &ctx.sql(query).await?.collect().await?);
+
+        // and only reads the files added since
+        assert_eq!(view.refresh(&ctx.state()).await?, 0);
+        write("2.csv", "a,10\nc,5\n")?;
+        assert_eq!(view.refresh(&ctx.state()).await?, 1);
+        let expected = [
+            "+---+-------+---+----+----+",
+            "| k | total | n | lo | hi |",
+            "+---+-------+---+----+----+",
+            "| a | 14    | 3 | 1  | 10 |",
+            "| b | 2     | 1 | 2  | 2  |",
+            "| c | 5     | 1 | 5  | 5  |",
+            "+---+-------+---+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &ctx.sql(query).await?.collect().await?);
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_aggregates() -> Result<()> {
+        assert!(merge_expr(&count(col("v")).alias("n"), "n").is_ok());
+
+        let err = merge_expr(&avg(col("v")), "avg(v)").unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "This feature is not implemented: Aggregate function avg is not supported by incremental views"
+        );
+        Ok(())
+    }
+}
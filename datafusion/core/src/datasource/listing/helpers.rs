@@ -18,6 +18,7 @@
 //! Helper functions for the table implementation
 
 use std::collections::HashMap;
+use std::fmt::Debug;
 use std::sync::Arc;
 
 use super::PartitionedFile;
@@ -27,13 +28,14 @@ use crate::logical_expr::{BinaryExpr, Operator};
 use crate::{error::Result, scalar::ScalarValue};
 
 use arrow::{
-    array::{Array, ArrayRef, AsArray, StringBuilder},
+    array::{Array, ArrayRef, AsArray, BooleanArray, StringBuilder},
     compute::{and, cast, prep_null_mask_filter},
     datatypes::{DataType, Field, Schema},
     record_batch::RecordBatch,
 };
 use arrow_schema::Fields;
 use datafusion_expr::execution_props::ExecutionProps;
+use datafusion_expr::utils::split_conjunction;
 use futures::stream::FuturesUnordered;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use log::{debug, trace};
@@ -119,6 +121,27 @@ pub fn expr_applicable_for_cols(col_names: &[String], expr: &Expr) -> bool {
     is_applicable
 }
 
+/// A user supplied filter on the partition values of a [`ListingTable`],
+/// deciding which partition directories are listed.
+///
+/// The filter is called for each partition directory before its content is
+/// listed, so that tables with a huge number of partitions only list the
+/// directories that are needed, whatever the filters of the query.
+///
+/// [`ListingTable`]: super::ListingTable
+pub trait PartitionFilter: Debug + Send + Sync {
+    /// Returns `false` if none of the files below the partition directory with
+    /// the given values should be read.
+    ///
+    /// `values` are the values of the first `values.len()` partition columns
+    /// of the table, in the order of [`table_partition_cols`]. The directories
+    /// below a matching directory are passed to the filter again, with the
+    /// values of the following partition columns.
+    ///
+    /// [`table_partition_cols`]: super::ListingOptions::table_partition_cols
+    fn matches(&self, values: &[ScalarValue]) -> Result<bool>;
+}
+
 /// The maximum number of concurrent listing requests
 const CONCURRENCY_LIMIT: usize = 100;
 
@@ -166,27 +189,39 @@ impl Partition {
     }
 }
 
-/// Returns a recursive list of the partitions in `table_path` up to `max_depth`
+/// Returns a recursive list of the partitions in `table_path` up to `max_depth`,
+/// skipping the directories rejected by `pruner` without listing them
 async fn list_partitions(
     store: &dyn ObjectStore,
     table_path: &ListingTableUrl,
     max_depth: usize,
     partition_prefix: Option<Path>,
+    pruner: &ListingPruner<'_>,
 ) -> Result<Vec<Partition>> {
-    let partition = Partition {
-        path: match partition_prefix {
-            Some(prefix) => Path::from_iter(
+    let partition = match partition_prefix {
+        Some(prefix) => Partition {
+            path: Path::from_iter(
                 Path::from(table_path.prefix().as_ref())
                     .parts()
                     .chain(Path::from(prefix.as_ref()).parts()),
             ),
-            None => table_path.prefix().clone(),
+            depth: prefix.parts().count(),
+            files: None,
+        },
+        None => Partition {
+            path: table_path.prefix().clone(),
+            depth: 0,
+            files: None,
         },
-        depth: 0,
-        files: None,
     };
 
     let mut out = Vec::with_capacity(64);
+    if pruner
+        .prune(partition.depth, vec![partition.path.clone()])?
+        .is_empty()
+    {
+        return Ok(out);
+    }
 
     let mut pending = vec![];
     let mut futures = FuturesUnordered::new();
@@ -202,7 +237,7 @@ async fn list_partitions(
 
         let depth = partition.depth;
         out.push(partition);
-        for path in paths {
+        for path in pruner.prune(depth + 1, paths)? {
             let child = Partition {
                 path,
                 depth: depth + 1,
@@ -226,18 +261,43 @@ async fn prune_partitions(
     filters: &[Expr],
     partition_cols: &[(String, DataType)],
 ) -> Result<Vec<Partition>> {
+    let paths: Vec<_> = partitions.iter().map(|p| &p.path).collect();
+    let mask = match partition_mask(table_path, &paths, filters, partition_cols)? {
+        Some(mask) => mask,
+        None => return Ok(partitions),
+    };
+
+    let filtered = partitions
+        .into_iter()
+        .zip(mask.values())
+        .filter_map(|(p, f)| f.then_some(p))
+        .collect();
+
+    Ok(filtered)
+}
+
+/// Evaluates the conjunction of `filters` on the values of `partition_cols`
+/// parsed from each of `paths`, returning `None` if no filter could be
+/// evaluated. Partitions whose values are missing are null, and the nulls of
+/// the returned mask are false.
+fn partition_mask(
+    table_path: &ListingTableUrl,
+    paths: &[&Path],
+    filters: &[Expr],
+    partition_cols: &[(String, DataType)],
+) -> Result<Option<BooleanArray>> {
     if filters.is_empty() {
-        return Ok(partitions);
+        return Ok(None);
     }
 
     let mut builders: Vec<_> = (0..partition_cols.len())
-        .map(|_| StringBuilder::with_capacity(partitions.len(), partitions.len() * 10))
+        .map(|_| StringBuilder::with_capacity(paths.len(), paths.len() * 10))
         .collect();
 
-    for partition in &partitions {
+    for path in paths {
         let cols = partition_cols.iter().map(|x| x.0.as_str());
-        let parsed = parse_partitions_for_path(table_path, &partition.path, cols)
-            .unwrap_or_default();
+        let parsed =
+            parse_partitions_for_path(table_path, path, cols).unwrap_or_default();
 
         let mut builders = builders.iter_mut();
         for (p, b) in parsed.iter().zip(&mut builders) {
@@ -277,10 +337,7 @@ async fn prune_partitions(
     // Applies `filter` to `batch` returning `None` on error
     let do_filter = |filter| -> Option<ArrayRef> {
         let expr = create_physical_expr(filter, &df_schema, &props).ok()?;
-        expr.evaluate(&batch)
-            .ok()?
-            .into_array(partitions.len())
-            .ok()
+        expr.evaluate(&batch).ok()?.into_array(paths.len()).ok()
     };
 
     //.Compute the conjunction of the filters, ignoring errors
@@ -294,7 +351,7 @@ async fn prune_partitions(
 
     let mask = match mask {
         Some(mask) => mask,
-        None => return Ok(partitions),
+        None => return Ok(None),
     };
 
     // Don't retain partitions that evaluated to null
@@ -304,15 +361,103 @@ async fn prune_partitions(
     };
 
     // Sanity check
-    assert_eq!(prepared.len(), partitions.len());
+    assert_eq!(prepared.len(), paths.len());
 
-    let filtered = partitions
-        .into_iter()
-        .zip(prepared.values())
-        .filter_map(|(p, f)| f.then_some(p))
-        .collect();
+    Ok(Some(prepared))
+}
 
-    Ok(filtered)
+/// Prunes the partition directories of a table while it is listed, so that
+/// the directories that can not contain matching files are never listed
+struct ListingPruner<'a> {
+    table_path: &'a ListingTableUrl,
+    partition_cols: &'a [(String, DataType)],
+    /// The conjuncts of the filters, with the number of leading partition
+    /// columns needed to evaluate each of them
+    conjuncts: Vec<(usize, Expr)>,
+    partition_filter: Option<&'a dyn PartitionFilter>,
+}
+
+impl<'a> ListingPruner<'a> {
+    fn new(
+        table_path: &'a ListingTableUrl,
+        filters: &[Expr],
+        partition_cols: &'a [(String, DataType)],
+        partition_filter: Option<&'a dyn PartitionFilter>,
+    ) -> Self {
+        let names: Vec<_> = partition_cols.iter().map(|(n, _)| n.clone()).collect();
+        // a conjunct is evaluated once all the columns it references are known,
+        // and never with the columns of deeper partitions set to null
+        let conjuncts = filters
+            .iter()
+            .flat_map(split_conjunction)
+            .filter_map(|expr| {
+                let depth = (1..=names.len())
+                    .find(|depth| expr_applicable_for_cols(&names[..*depth], expr))?;
+                Some((depth, expr.clone()))
+            })
+            .collect();
+
+        Self {
+            table_path,
+            partition_cols,
+            conjuncts,
+            partition_filter,
+        }
+    }
+
+    /// Retains the directories of `paths`, all `depth` levels below the table
+    /// path, that can contain matching files
+    fn prune(&self, depth: usize, paths: Vec<Path>) -> Result<Vec<Path>> {
+        if depth == 0 || depth > self.partition_cols.len() {
+            return Ok(paths);
+        }
+        let partition_cols = &self.partition_cols[..depth];
+
+        let filters: Vec<_> = self
+            .conjuncts
+            .iter()
+            .filter(|(d, _)| *d <= depth)
+            .map(|(_, expr)| expr.clone())
+            .collect();
+        let mask = {
+            let paths: Vec<_> = paths.iter().collect();
+            partition_mask(self.table_path, &paths, &filters, partition_cols)?
+        };
+
+        let mut retained = Vec::with_capacity(paths.len());
+        for (i, path) in paths.into_iter().enumerate() {
+            if mask.as_ref().is_some_and(|mask| !mask.value(i)) {
+                continue;
+            }
+            if let Some(partition_filter) = self.partition_filter {
+                // directories with unparsable values are left to the final pruning
+                let values = self.partition_values(&path, partition_cols);
+                if let Some(values) = values {
+                    if !partition_filter.matches(&values)? {
+                        trace!("Skipping partition {path}");
+                        continue;
+                    }
+                }
+            }
+            retained.push(path);
+        }
+        Ok(retained)
+    }
+
+    fn partition_values(
+        &self,
+        path: &Path,
+        partition_cols: &[(String, DataType)],
+    ) -> Option<Vec<ScalarValue>> {
+        let cols = partition_cols.iter().map(|x| x.0.as_str());
+        parse_partitions_for_path(self.table_path, path, cols)?
+            .into_iter()
+            .zip(partition_cols)
+            .map(|(parsed, (_, datatype))| {
+                ScalarValue::try_from_string(parsed.to_string(), datatype).ok()
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug)]
@@ -390,7 +535,8 @@ fn evaluate_partition_prefix<'a>(
 }
 
 /// Discover the partitions on the given path and prune out files
-/// that belong to irrelevant partitions using `filters` expressions.
+/// that belong to irrelevant partitions using `filters` expressions
+/// and `partition_filter`. Directories are pruned as they are listed.
 /// `filters` might contain expressions that can be resolved only at the
 /// file level (e.g. Parquet row group pruning).
 pub async fn pruned_partition_list<'a>(
@@ -400,6 +546,7 @@ pub async fn pruned_partition_list<'a>(
     filters: &'a [Expr],
     file_extension: &'a str,
     partition_cols: &'a [(String, DataType)],
+    partition_filter: Option<&'a dyn PartitionFilter>,
 ) -> Result<BoxStream<'a, Result<PartitionedFile>>> {
     // if no partition col => simply list all the files
     if partition_cols.is_empty() {
@@ -412,9 +559,16 @@ pub async fn pruned_partition_list<'a>(
     }
 
     let partition_prefix = evaluate_partition_prefix(partition_cols, filters);
-    let partitions =
-        list_partitions(store, table_path, partition_cols.len(), partition_prefix)
-            .await?;
+    let pruner =
+        ListingPruner::new(table_path, filters, partition_cols, partition_filter);
+    let partitions = list_partitions(
+        store,
+        table_path,
+        partition_cols.len(),
+        partition_prefix,
+        &pruner,
+    )
+    .await?;
     debug!("Listed {} partitions", partitions.len());
 
     let pruned =
@@ -502,11 +656,13 @@ where
 #[cfg(test)]
 mod tests {
     use std::ops::Not;
+    use std::sync::Mutex;
 
     use futures::StreamExt;
 
     use crate::logical_expr::{case, col, lit, Expr};
     use crate::test::object_store::make_test_store_and_state;
+    use object_store::memory::InMemory;
 
     use super::*;
 
@@ -564,6 +720,7 @@ mod tests {
             &[filter],
             ".parquet",
             &[(String::from("mypartition"), DataType::Utf8)],
+            None,
         )
         .await
         .expect("partition pruning failed")
@@ -588,6 +745,7 @@ mod tests {
             &[filter],
             ".parquet",
             &[(String::from("mypartition"), DataType::Utf8)],
+            None,
         )
         .await
         .expect("partition pruning failed")
@@ -633,6 +791,7 @@ mod tests {
                 (String::from("part1"), DataType::Utf8),
                 (String::from("part2"), DataType::Utf8),
             ],
+            None,
         )
         .await
         .expect("partition pruning failed")
@@ -661,6 +820,134 @@ mod tests {
         );
     }
 
+    /// A [`PartitionFilter`] recording the values it is called with
+    #[derive(Debug, Default)]
+    struct RecordingFilter {
+        /// Value of the first partition column to reject
+        rejected: Option<&'static str>,
+        seen: Mutex<Vec<Vec<String>>>,
+    }
+
+    impl RecordingFilter {
+        fn seen(&self) -> Vec<Vec<String>> {
+            let mut seen = self.seen.lock().unwrap().clone();
+            seen.sort();
+            seen
+        }
+    }
+
+    impl PartitionFilter for RecordingFilter {
+        fn matches(&self, values: &[ScalarValue]) -> Result<bool> {
+            let values: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+            let matches = self.rejected != Some(values[0].as_str());
+            self.seen.lock().unwrap().push(values);
+            Ok(matches)
+        }
+    }
+
+    fn partitioned_test_store() -> (Arc<InMemory>, SessionState) {
+        make_test_store_and_state(&[
+            ("tablepath/part1=p1v1/part2=p2v1/file.parquet", 100),
+            ("tablepath/part1=p1v2/part2=p2v1/file.parquet", 100),
+            ("tablepath/part1=p1v2/part2=p2v2/file.parquet", 100),
+            ("tablepath/part1=p1v3/part2=p2v1/file.parquet", 100),
+        ])
+    }
+
+    async fn list_with_partition_filter(
+        filters: &[Expr],
+        partition_filter: &RecordingFilter,
+    ) -> Vec<String> {
+        let (store, state) = partitioned_test_store();
+        let mut files = pruned_partition_list(
+            &state,
+            store.as_ref(),
+            &ListingTableUrl::parse("file:///tablepath/").unwrap(),
+            filters,
+            ".parquet",
+            &[
+                (String::from("part1"), DataType::Utf8),
+                (String::from("part2"), DataType::Utf8),
+            ],
+            Some(partition_filter),
+        )
+        .await
+        .expect("partition pruning failed")
+        .map_ok(|f| f.object_meta.location.to_string())
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+        files.sort();
+        files
+    }
+
+    #[tokio::test]
+    async fn test_pruned_partition_list_prunes_while_listing() {
+        let partition_filter = RecordingFilter::default();
+        let filters = [
+            col("part1").in_list(vec![lit("p1v1"), lit("p1v2")], false),
+            Expr::eq(col("part2"), lit("p2v1")),
+        ];
+        let files = list_with_partition_filter(&filters, &partition_filter).await;
+
+        assert_eq!(
+            files,
+            [
+                "tablepath/part1=p1v1/part2=p2v1/file.parquet",
+                "tablepath/part1=p1v2/part2=p2v1/file.parquet"
+            ]
+        );
+        // the directories rejected by the filters are never considered
+        assert_eq!(
+            partition_filter.seen(),
+            [
+                vec!["p1v1"],
+                vec!["p1v1", "p2v1"],
+                vec!["p1v2"],
+                vec!["p1v2", "p2v1"]
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pruned_partition_list_partition_filter() {
+        let partition_filter = RecordingFilter {
+            rejected: Some("p1v2"),
+            ..Default::default()
+        };
+        let files = list_with_partition_filter(&[], &partition_filter).await;
+
+        assert_eq!(
+            files,
+            [
+                "tablepath/part1=p1v1/part2=p2v1/file.parquet",
+                "tablepath/part1=p1v3/part2=p2v1/file.parquet"
+            ]
+        );
+        // the rejected directory is not listed
+        assert_eq!(
+            partition_filter.seen(),
+            [
+                vec!["p1v1"],
+                vec!["p1v1", "p2v1"],
+                vec!["p1v2"],
+                vec!["p1v3"],
+                vec!["p1v3", "p2v1"]
+            ]
+        );
+
+        // the filter is also called for the prefix built from the filters
+        let partition_filter = RecordingFilter {
+            rejected: Some("p1v2"),
+            ..Default::default()
+        };
+        let filters = [Expr::eq(col("part1"), lit("p1v2"))];
+        let files = list_with_partition_filter(&filters, &partition_filter).await;
+
+        assert!(files.is_empty());
+        assert_eq!(partition_filter.seen(), [vec!["p1v2"]]);
+    }
+
     #[test]
     fn test_parse_partitions_for_path() {
         assert_eq!(
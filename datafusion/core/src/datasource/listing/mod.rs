@@ -30,6 +30,7 @@ use object_store::{path::Path, ObjectMeta};
 use std::pin::Pin;
 use std::sync::Arc;
 
+pub use self::helpers::PartitionFilter;
 pub use self::url::ListingTableUrl;
 pub use table::{ListingOptions, ListingTable, ListingTableConfig};
 
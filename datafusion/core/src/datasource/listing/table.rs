@@ -21,7 +21,9 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use std::{any::Any, sync::Arc};
 
-use super::helpers::{expr_applicable_for_cols, pruned_partition_list, split_files};
+use super::helpers::{
+    expr_applicable_for_cols, pruned_partition_list, split_files, PartitionFilter,
+};
 use super::PartitionedFile;
 
 use crate::datasource::{
@@ -42,8 +44,8 @@ use crate::{
 use arrow::datatypes::{DataType, Field, SchemaBuilder, SchemaRef};
 use arrow_schema::Schema;
 use datafusion_common::{
-    config_datafusion_err, internal_err, plan_err, project_schema, Constraints,
-    SchemaExt, ToDFSchema,
+    config_datafusion_err, internal_err, plan_err, project_schema,
+    schema_mismatch_details, Constraints, SchemaExt, ToDFSchema,
 };
 use datafusion_execution::cache::cache_manager::FileStatisticsCache;
 use datafusion_execution::cache::cache_unit::DefaultFileStatisticsCache;
@@ -224,6 +226,9 @@ pub struct ListingOptions {
     ///       multiple equivalent orderings, the outer `Vec` will have a
     ///       single element.
     pub file_sort_order: Vec<Vec<Expr>>,
+    /// Optional filter on the partition values, deciding which partition
+    /// directories are listed. See [Self::with_partition_filter] for details
+    pub partition_filter: Option<Arc<dyn PartitionFilter>>,
 }
 
 impl ListingOptions {
@@ -241,6 +246,7 @@ impl ListingOptions {
             collect_stat: true,
             target_partitions: 1,
             file_sort_order: vec![],
+            partition_filter: None,
         }
     }
 
@@ -391,6 +397,49 @@ impl ListingOptions {
         self
     }
 
+    /// Set a [`PartitionFilter`] on [`ListingOptions`] and returns self.
+    ///
+    /// The filter is called with the values of each partition directory
+    /// before it is listed, and the directories it rejects are skipped along
+    /// with all their files. This limits the listing of tables with a huge
+    /// number of partitions to the partitions that are needed, in addition
+    /// to the pruning done with the filters of each query.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow::datatypes::DataType;
+    /// # use datafusion::common::{Result, ScalarValue};
+    /// # use datafusion::datasource::{listing::{ListingOptions, PartitionFilter}, file_format::parquet::ParquetFormat};
+    ///
+    /// /// Only lists the partitions of the years from 2020
+    /// #[derive(Debug)]
+    /// struct RecentYears;
+    ///
+    /// impl PartitionFilter for RecentYears {
+    ///     fn matches(&self, values: &[ScalarValue]) -> Result<bool> {
+    ///         Ok(match &values[0] {
+    ///             ScalarValue::Int32(Some(year)) => *year >= 2020,
+    ///             _ => true,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let listing_options = ListingOptions::new(Arc::new(
+    ///     ParquetFormat::default()
+    ///   ))
+    ///   .with_table_partition_cols(vec![("year".to_string(), DataType::Int32)])
+    ///   .with_partition_filter(Arc::new(RecentYears));
+    ///
+    /// assert!(listing_options.partition_filter.is_some());
+    /// ```
+    pub fn with_partition_filter(
+        mut self,
+        partition_filter: Arc<dyn PartitionFilter>,
+    ) -> Self {
+        self.partition_filter = Some(partition_filter);
+        self
+    }
+
     /// Infer the schema of the files at the given path on the provided object store.
     /// The inferred schema does not include the partitioning columns.
     ///
@@ -863,7 +912,8 @@ impl TableProvider for ListingTable {
         {
             return plan_err!(
                 // Return an error if schema of the input query does not match with the table schema.
-                "Inserting query must have the same schema with the table."
+                "Inserting query must have the same schema with the table.\n{}",
+                schema_mismatch_details(&self.schema(), &input.schema())
             );
         }
 
@@ -885,6 +935,7 @@ impl TableProvider for ListingTable {
             &[],
             &self.options.file_extension,
             &self.options.table_partition_cols,
+            None,
         )
         .await?;
 
@@ -933,6 +984,14 @@ impl TableProvider for ListingTable {
     fn get_column_default(&self, column: &str) -> Option<&Expr> {
         self.column_defaults.get(column)
     }
+
+    fn partition_columns(&self) -> Vec<String> {
+        self.options
+            .table_partition_cols
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
 }
 
 impl ListingTable {
@@ -959,6 +1018,7 @@ impl ListingTable {
                 filters,
                 &self.options.file_extension,
                 &self.options.table_partition_cols,
+                self.options.partition_filter.as_deref(),
             )
         }))
         .await?;
@@ -22,10 +22,13 @@ use std::collections::HashMap;
 use std::fmt::{self, Debug};
 use std::sync::Arc;
 
+use crate::datasource::zone_map::{ZoneMap, ZoneMapStatistics};
 use crate::datasource::{TableProvider, TableType};
 use crate::error::Result;
 use crate::execution::context::SessionState;
-use crate::logical_expr::Expr;
+use crate::logical_expr::utils::conjunction;
+use crate::logical_expr::{Expr, TableProviderFilterPushDown};
+use crate::physical_optimizer::pruning::PruningPredicate;
 use crate::physical_plan::insert::{DataSink, DataSinkExec};
 use crate::physical_plan::memory::MemoryExec;
 use crate::physical_plan::repartition::RepartitionExec;
@@ -37,7 +40,9 @@ use crate::physical_planner::create_physical_sort_exprs;
 
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
-use datafusion_common::{not_impl_err, plan_err, Constraints, DFSchema, SchemaExt};
+use datafusion_common::{
+    not_impl_err, plan_err, schema_mismatch_details, Constraints, DFSchema, SchemaExt,
+};
 use datafusion_execution::TaskContext;
 use datafusion_physical_plan::metrics::MetricsSet;
 
@@ -51,6 +56,9 @@ use tokio::task::JoinSet;
 /// Type alias for partition data
 pub type PartitionData = Arc<RwLock<Vec<RecordBatch>>>;
 
+/// The zone maps of the batches of a partition, see [`MemTable::with_zone_maps`]
+type PartitionZoneMaps = Arc<Mutex<Vec<ZoneMap>>>;
+
 /// In-memory data source for presenting a `Vec<RecordBatch>` as a
 /// data source that can be queried by DataFusion. This allows data to
 /// be pre-loaded into memory and then repeatedly queried without
@@ -64,6 +72,8 @@ pub struct MemTable {
     /// Optional pre-known sort order(s). Must be `SortExpr`s.
     /// inserting data into this table removes the order
     pub sort_order: Arc<Mutex<Vec<Vec<Expr>>>>,
+    /// The zone maps of the batches of each partition, if enabled
+    zone_maps: Option<Vec<PartitionZoneMaps>>,
 }
 
 impl MemTable {
@@ -89,6 +99,7 @@ impl MemTable {
             constraints: Constraints::empty(),
             column_defaults: HashMap::new(),
             sort_order: Arc::new(Mutex::new(vec![])),
+            zone_maps: None,
         })
     }
 
@@ -122,6 +133,56 @@ impl MemTable {
         self
     }
 
+    /// Compute the minimum and maximum values of the columns of each batch
+    /// (zone maps) when data is inserted into this table.
+    ///
+    /// The filters of the queries on this table are then pushed down to the
+    /// scan, which skips the batches whose zone maps show that they can not
+    /// match the filters. The number of skipped batches is reported by the
+    /// `batches_pruned` metric of the [`MemoryExec`].
+    pub fn with_zone_maps(mut self) -> Self {
+        let zone_maps = self
+            .batches
+            .iter()
+            .map(|partition| {
+                let mut zone_maps = vec![];
+                // the batches that are being written are handled by the scans
+                if let Ok(batches) = partition.try_read() {
+                    ZoneMap::extend(&mut zone_maps, &batches);
+                }
+                Arc::new(Mutex::new(zone_maps))
+            })
+            .collect();
+        self.zone_maps = Some(zone_maps);
+        self
+    }
+
+    /// Returns the batches of `partition` that may match `predicate`, and the
+    /// number of batches that were skipped
+    fn prune_batches(
+        &self,
+        partition: usize,
+        batches: &[RecordBatch],
+        predicate: Option<&PruningPredicate>,
+    ) -> Result<(Vec<RecordBatch>, usize)> {
+        let (Some(predicate), Some(zone_maps)) = (predicate, &self.zone_maps) else {
+            return Ok((batches.to_vec(), 0));
+        };
+        let mut zone_maps = zone_maps[partition].lock();
+        ZoneMap::extend(&mut zone_maps, batches);
+
+        let statistics =
+            ZoneMapStatistics::new(&self.schema, &zone_maps[..batches.len()]);
+        let keep = predicate.prune(&statistics)?;
+        let kept = batches
+            .iter()
+            .zip(keep)
+            .filter_map(|(batch, keep)| keep.then(|| batch.clone()))
+            .collect::<Vec<_>>();
+        let pruned = batches.len() - kept.len();
+        Ok((kept, pruned))
+    }
+
     /// Create a mem table by reading from another data source
     pub async fn load(
         t: Arc<dyn TableProvider>,
@@ -204,21 +265,52 @@ impl TableProvider for MemTable {
         TableType::Base
     }
 
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        // the zone maps can only skip batches, the filters are still applied
+        let pushdown = if self.zone_maps.is_some() {
+            TableProviderFilterPushDown::Inexact
+        } else {
+            TableProviderFilterPushDown::Unsupported
+        };
+        Ok(vec![pushdown; filters.len()])
+    }
+
     async fn scan(
         &self,
         state: &SessionState,
         projection: Option<&Vec<usize>>,
-        _filters: &[Expr],
+        filters: &[Expr],
         _limit: Option<usize>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
+        let predicate = match conjunction(filters.to_vec()) {
+            Some(expr) if self.zone_maps.is_some() => {
+                let df_schema = DFSchema::try_from(self.schema.as_ref().clone())?;
+                let expr = state.create_physical_expr(expr, &df_schema)?;
+                PruningPredicate::try_new(expr, self.schema())
+                    .ok()
+                    .filter(|predicate| !predicate.always_true())
+            }
+            _ => None,
+        };
+
         let mut partitions = vec![];
-        for arc_inner_vec in self.batches.iter() {
+        let mut pruned_batches = vec![];
+        for (partition, arc_inner_vec) in self.batches.iter().enumerate() {
             let inner_vec = arc_inner_vec.read().await;
-            partitions.push(inner_vec.clone())
+            let (batches, pruned) =
+                self.prune_batches(partition, &inner_vec, predicate.as_ref())?;
+            partitions.push(batches);
+            pruned_batches.push(pruned);
         }
 
         let mut exec =
             MemoryExec::try_new(&partitions, self.schema(), projection.cloned())?;
+        if self.zone_maps.is_some() {
+            exec = exec.with_pruned_batches(&pruned_batches);
+        }
 
         let show_sizes = state.config_options().explain.show_sizes;
         exec = exec.with_show_sizes(show_sizes);
@@ -272,13 +364,14 @@ impl TableProvider for MemTable {
             .logically_equivalent_names_and_types(&input.schema())
         {
             return plan_err!(
-                "Inserting query must have the same schema with the table."
+                "Inserting query must have the same schema with the table.\n{}",
+                schema_mismatch_details(&self.schema(), &input.schema())
             );
         }
         if overwrite {
             return not_impl_err!("Overwrite not implemented for MemoryTable yet");
         }
-        let sink = Arc::new(MemSink::new(self.batches.clone()));
+        let sink = Arc::new(MemSink::new(self.batches.clone(), self.zone_maps.clone()));
         Ok(Arc::new(DataSinkExec::new(
             input,
             sink,
@@ -296,6 +389,8 @@ impl TableProvider for MemTable {
 struct MemSink {
     /// Target locations for writing data
     batches: Vec<PartitionData>,
+    /// The zone maps of the target partitions, if enabled
+    zone_maps: Option<Vec<PartitionZoneMaps>>,
 }
 
 impl Debug for MemSink {
@@ -318,8 +413,11 @@ impl DisplayAs for MemSink {
 }
 
 impl MemSink {
-    fn new(batches: Vec<PartitionData>) -> Self {
-        Self { batches }
+    fn new(
+        batches: Vec<PartitionData>,
+        zone_maps: Option<Vec<PartitionZoneMaps>>,
+    ) -> Self {
+        Self { batches, zone_maps }
     }
 }
 
@@ -352,9 +450,15 @@ impl DataSink for MemSink {
         }
 
         // write the outputs into the batches
-        for (target, mut batches) in self.batches.iter().zip(new_batches.into_iter()) {
+        for (partition, (target, mut batches)) in
+            self.batches.iter().zip(new_batches.into_iter()).enumerate()
+        {
             // Append all the new batches in one go to minimize locking overhead
-            target.write().await.append(&mut batches);
+            let mut target = target.write().await;
+            target.append(&mut batches);
+            if let Some(zone_maps) = &self.zone_maps {
+                ZoneMap::extend(&mut zone_maps[partition].lock(), &target);
+            }
         }
 
         Ok(row_count as u64)
@@ -373,7 +477,7 @@ mod tests {
     use arrow::datatypes::{DataType, Field, Schema, UInt64Type};
     use arrow::error::ArrowError;
     use datafusion_common::DataFusionError;
-    use datafusion_expr::LogicalPlanBuilder;
+    use datafusion_expr::{col, lit, LogicalPlanBuilder};
 
     #[tokio::test]
     async fn test_with_projection() -> Result<()> {
@@ -763,4 +867,70 @@ mod tests {
         assert_eq!(resulting_data_in_table[0].len(), 2);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_zone_maps() -> Result<()> {
+        let ctx = SessionContext::new();
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batch = |values: Vec<Option<i32>>| {
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(values))])
+        };
+        let batches = vec![
+            batch(vec![Some(1), Some(2), Some(3)])?,
+            batch(vec![Some(4), None, Some(6)])?,
+            batch(vec![None, None])?,
+        ];
+        let table =
+            Arc::new(MemTable::try_new(schema.clone(), vec![batches])?.with_zone_maps());
+
+        let filter = col("a").gt(lit(3));
+        assert_eq!(
+            table.supports_filters_pushdown(&[&filter])?,
+            vec![TableProviderFilterPushDown::Inexact]
+        );
+
+        // the batches of the scan and the number of batches that were skipped
+        let scan = |filters: Vec<Expr>| {
+            let table = Arc::clone(&table);
+            let state = ctx.state();
+            async move {
+                let exec = table.scan(&state, None, &filters, None).await?;
+                let exec = exec.as_any().downcast_ref::<MemoryExec>().unwrap();
+                let pruned = exec
+                    .metrics()
+                    .and_then(|metrics| metrics.sum_by_name("batches_pruned"))
+                    .map(|pruned| pruned.as_usize());
+                Result::Ok((exec.partitions()[0].len(), pruned))
+            }
+        };
+        assert_eq!(scan(vec![]).await?, (3, Some(0)));
+        assert_eq!(scan(vec![col("a").gt(lit(3))]).await?, (1, Some(2)));
+        assert_eq!(scan(vec![col("a").lt(lit(10))]).await?, (2, Some(1)));
+        assert_eq!(scan(vec![col("a").is_null()]).await?, (2, Some(1)));
+        assert_eq!(
+            scan(vec![col("a").gt(lit(1)), col("a").lt(lit(3))]).await?,
+            (1, Some(2))
+        );
+
+        // the zone maps of the inserted batches are computed by the sink
+        let input = MemoryExec::try_new(
+            &[vec![batch(vec![Some(10), Some(20)])?]],
+            schema.clone(),
+            None,
+        )?;
+        let plan = table
+            .insert_into(&ctx.state(), Arc::new(input), false)
+            .await?;
+        collect(plan, ctx.task_ctx()).await?;
+        assert_eq!(table.zone_maps.as_ref().unwrap()[0].lock().len(), 4);
+        assert_eq!(scan(vec![col("a").gt(lit(6))]).await?, (1, Some(3)));
+
+        // without zone maps, the filters are not pushed down
+        let table = MemTable::try_new(schema, vec![vec![]])?;
+        assert_eq!(
+            table.supports_filters_pushdown(&[&filter])?,
+            vec![TableProviderFilterPushDown::Unsupported]
+        );
+        Ok(())
+    }
 }
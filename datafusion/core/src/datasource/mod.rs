@@ -25,16 +25,20 @@ pub mod default_table_source;
 pub mod empty;
 pub mod file_format;
 pub mod function;
+pub mod generate_series;
+pub mod incremental_view;
 pub mod listing;
 pub mod listing_table_factory;
 pub mod memory;
 pub mod physical_plan;
 pub mod provider;
+pub mod retry;
 pub mod schema_adapter;
 mod statistics;
 pub mod stream;
 pub mod streaming;
 pub mod view;
+mod zone_map;
 
 // backwards compatibility
 pub use datafusion_execution::object_store;
@@ -29,6 +29,7 @@ use std::task::{Context, Poll};
 use crate::datasource::listing::PartitionedFile;
 use crate::datasource::physical_plan::file_scan_config::PartitionColumnProjector;
 use crate::datasource::physical_plan::{FileMeta, FileScanConfig};
+use crate::datasource::retry::ScanRetryMetrics;
 use crate::error::Result;
 use crate::physical_plan::metrics::{
     BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder, Time,
@@ -197,6 +198,9 @@ struct FileStreamMetrics {
     /// If using `OnError::Skip` this will provide a count of the number of files
     /// which were skipped and will not be included in the scan results.
     pub file_scan_errors: Count,
+    /// Counts of the retries of the reads through a
+    /// [`RetryObjectStore`](crate::datasource::retry::RetryObjectStore)
+    pub object_store_retries: ScanRetryMetrics,
 }
 
 impl FileStreamMetrics {
@@ -238,6 +242,7 @@ impl FileStreamMetrics {
             time_processing,
             file_open_errors,
             file_scan_errors,
+            object_store_retries: ScanRetryMetrics::new(metrics, partition),
         }
     }
 }
@@ -501,7 +506,8 @@ impl<F: FileOpener> Stream for FileStream<F> {
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
         self.file_stream_metrics.time_processing.start();
-        let result = self.poll_inner(cx);
+        let retry_metrics = self.file_stream_metrics.object_store_retries.clone();
+        let result = retry_metrics.scope(|| self.poll_inner(cx));
         self.file_stream_metrics.time_processing.stop();
         self.baseline_metrics.record_poll(result)
     }
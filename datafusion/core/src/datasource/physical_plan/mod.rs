@@ -24,12 +24,18 @@ mod file_groups;
 mod file_scan_config;
 mod file_stream;
 mod json;
+#[cfg(feature = "orc")]
+mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 mod statistics;
 
 pub(crate) use self::csv::plan_to_csv;
 pub(crate) use self::json::plan_to_json;
+#[cfg(feature = "orc")]
+pub(crate) use self::orc::fetch_orc_metadata;
+#[cfg(feature = "orc")]
+pub use self::orc::{OrcExec, OrcOpener};
 #[cfg(feature = "parquet")]
 pub use self::parquet::{ParquetExec, ParquetFileMetrics, ParquetFileReaderFactory};
 
@@ -0,0 +1,598 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution plan for reading Apache ORC files
+
+use std::any::Any;
+use std::ops::Range;
+use std::sync::Arc;
+
+use super::{FileGroupPartitioner, FileMeta, FileOpenFuture, FileOpener, FileScanConfig};
+use crate::datasource::listing::PartitionedFile;
+use crate::datasource::schema_adapter::{
+    DefaultSchemaAdapterFactory, SchemaAdapterFactory,
+};
+use crate::error::Result;
+use crate::physical_optimizer::pruning::{PruningPredicate, PruningStatistics};
+use crate::physical_plan::metrics::{
+    Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet,
+};
+use crate::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan, Partitioning,
+    PlanProperties, SendableRecordBatchStream, Statistics,
+};
+
+use arrow::array::{ArrayRef, Float64Array, Int64Array, StringArray, UInt64Array};
+use arrow::datatypes::SchemaRef;
+use bytes::Bytes;
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::{Column, DataFusionError};
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::{EquivalenceProperties, LexOrdering, PhysicalExpr};
+
+use futures::future::BoxFuture;
+use futures::{FutureExt, StreamExt};
+use log::debug;
+use object_store::{ObjectMeta, ObjectStore};
+use orc_rust::projection::ProjectionMask;
+use orc_rust::reader::metadata::FileMetadata;
+use orc_rust::reader::AsyncChunkReader;
+use orc_rust::statistics::{ColumnStatistics, TypeStatistics};
+use orc_rust::ArrowReaderBuilder;
+
+/// Execution plan for scanning one or more ORC files
+///
+/// Supports projection pushdown (only the required columns are decoded)
+/// and, when a predicate is supplied, stripe level pruning based on the
+/// min/max statistics stored in the file footer.
+#[derive(Debug, Clone)]
+pub struct OrcExec {
+    base_config: FileScanConfig,
+    projected_statistics: Statistics,
+    projected_schema: SchemaRef,
+    projected_output_ordering: Vec<LexOrdering>,
+    /// Optional predicate used for stripe pruning
+    predicate: Option<Arc<dyn PhysicalExpr>>,
+    /// Optional predicate for pruning stripes
+    pruning_predicate: Option<Arc<PruningPredicate>>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    cache: PlanProperties,
+}
+
+impl OrcExec {
+    /// Create a new ORC reader execution plan provided base configurations
+    /// and an optional predicate used for stripe pruning
+    pub fn new(
+        base_config: FileScanConfig,
+        predicate: Option<Arc<dyn PhysicalExpr>>,
+    ) -> Self {
+        debug!("Creating OrcExec, files: {:?}, projection {:?}, predicate: {:?}, limit: {:?}",
+        base_config.file_groups, base_config.projection, predicate, base_config.limit);
+
+        let metrics = ExecutionPlanMetricsSet::new();
+        let predicate_creation_errors =
+            MetricBuilder::new(&metrics).global_counter("num_predicate_creation_errors");
+
+        let file_schema = &base_config.file_schema;
+        let pruning_predicate = predicate
+            .clone()
+            .and_then(|predicate_expr| {
+                match PruningPredicate::try_new(predicate_expr, file_schema.clone()) {
+                    Ok(pruning_predicate) => Some(Arc::new(pruning_predicate)),
+                    Err(e) => {
+                        debug!("Could not create pruning predicate for: {e}");
+                        predicate_creation_errors.add(1);
+                        None
+                    }
+                }
+            })
+            .filter(|p| !p.always_true());
+
+        let (projected_schema, projected_statistics, projected_output_ordering) =
+            base_config.project();
+        let cache = Self::compute_properties(
+            projected_schema.clone(),
+            &projected_output_ordering,
+            &base_config,
+        );
+        Self {
+            base_config,
+            projected_schema,
+            projected_statistics,
+            projected_output_ordering,
+            predicate,
+            pruning_predicate,
+            metrics,
+            cache,
+        }
+    }
+
+    /// Ref to the base configs
+    pub fn base_config(&self) -> &FileScanConfig {
+        &self.base_config
+    }
+
+    /// Optional predicate.
+    pub fn predicate(&self) -> Option<&Arc<dyn PhysicalExpr>> {
+        self.predicate.as_ref()
+    }
+
+    /// Optional reference to this ORC scan's pruning predicate
+    pub fn pruning_predicate(&self) -> Option<&Arc<PruningPredicate>> {
+        self.pruning_predicate.as_ref()
+    }
+
+    fn output_partitioning_helper(file_scan_config: &FileScanConfig) -> Partitioning {
+        Partitioning::UnknownPartitioning(file_scan_config.file_groups.len())
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        schema: SchemaRef,
+        orderings: &[LexOrdering],
+        file_scan_config: &FileScanConfig,
+    ) -> PlanProperties {
+        // Equivalence Properties
+        let eq_properties = EquivalenceProperties::new_with_orderings(schema, orderings);
+
+        PlanProperties::new(
+            eq_properties,
+            Self::output_partitioning_helper(file_scan_config), // Output Partitioning
+            ExecutionMode::Bounded,                             // Execution Mode
+        )
+    }
+
+    fn with_file_groups(mut self, file_groups: Vec<Vec<PartitionedFile>>) -> Self {
+        self.base_config.file_groups = file_groups;
+        // Changing file groups may invalidate output partitioning. Update it also
+        let output_partitioning = Self::output_partitioning_helper(&self.base_config);
+        self.cache = self.cache.with_partitioning(output_partitioning);
+        self
+    }
+}
+
+impl DisplayAs for OrcExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        write!(f, "OrcExec: ")?;
+        self.base_config.fmt_as(t, f)?;
+        if let Some(pruning_predicate) = &self.pruning_predicate {
+            write!(
+                f,
+                ", pruning_predicate={}",
+                pruning_predicate.predicate_expr()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl ExecutionPlan for OrcExec {
+    fn name(&self) -> &'static str {
+        "OrcExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        Vec::new()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    /// Redistribute files across partitions according to their size
+    /// See comments on [`FileGroupPartitioner`] for more detail.
+    fn repartitioned(
+        &self,
+        target_partitions: usize,
+        config: &ConfigOptions,
+    ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        let repartition_file_min_size = config.optimizer.repartition_file_min_size;
+        let repartitioned_file_groups_option = FileGroupPartitioner::new()
+            .with_target_partitions(target_partitions)
+            .with_repartition_file_min_size(repartition_file_min_size)
+            .with_preserve_order_within_groups(
+                self.properties().output_ordering().is_some(),
+            )
+            .repartition_file_groups(&self.base_config.file_groups);
+
+        if let Some(repartitioned_file_groups) = repartitioned_file_groups_option {
+            let mut new_plan = self.clone();
+            new_plan = new_plan.with_file_groups(repartitioned_file_groups);
+            return Ok(Some(Arc::new(new_plan)));
+        }
+        Ok(None)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        use super::file_stream::FileStream;
+        let object_store = context
+            .runtime_env()
+            .object_store(&self.base_config.object_store_url)?;
+
+        let projection = match self.base_config.file_column_projection_indices() {
+            Some(proj) => proj,
+            None => (0..self.base_config.file_schema.fields().len()).collect(),
+        };
+
+        let opener = OrcOpener {
+            partition_index: partition,
+            projection: Arc::from(projection),
+            batch_size: context.session_config().batch_size(),
+            table_schema: self.base_config.file_schema.clone(),
+            pruning_predicate: self.pruning_predicate.clone(),
+            object_store,
+            stripes_pruned: MetricBuilder::new(&self.metrics)
+                .counter("stripes_pruned_statistics", partition),
+        };
+
+        let stream =
+            FileStream::new(&self.base_config, partition, opener, &self.metrics)?;
+        Ok(Box::pin(stream))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        Ok(self.projected_statistics.clone())
+    }
+}
+
+/// Implements [`FileOpener`] for ORC files
+pub struct OrcOpener {
+    partition_index: usize,
+    projection: Arc<[usize]>,
+    batch_size: usize,
+    table_schema: SchemaRef,
+    pruning_predicate: Option<Arc<PruningPredicate>>,
+    object_store: Arc<dyn ObjectStore>,
+    stripes_pruned: Count,
+}
+
+impl FileOpener for OrcOpener {
+    fn open(&self, file_meta: FileMeta) -> Result<FileOpenFuture> {
+        let mut reader =
+            ObjectStoreReader::new(self.object_store.clone(), file_meta.object_meta);
+        let batch_size = self.batch_size;
+        let projected_schema =
+            SchemaRef::from(self.table_schema.project(&self.projection)?);
+        let schema_adapter =
+            DefaultSchemaAdapterFactory::default().create(projected_schema);
+        let pruning_predicate = self.pruning_predicate.clone();
+        let table_schema = self.table_schema.clone();
+        let stripes_pruned = self.stripes_pruned.clone();
+        let partition_index = self.partition_index;
+        let file_range = file_meta.range;
+
+        Ok(Box::pin(async move {
+            let metadata = Arc::new(read_orc_metadata(&mut reader).await?);
+            let file_schema = metadata
+                .root_data_type()
+                .create_arrow_schema(&Default::default());
+
+            let (schema_mapping, adapted_projections) =
+                schema_adapter.map_schema(&file_schema)?;
+
+            // Stripes are only read by the partition whose byte range
+            // contains the start of the stripe
+            let stripes = metadata.stripe_metadatas();
+            let mut selected: Vec<bool> = stripes
+                .iter()
+                .map(|stripe| match &file_range {
+                    Some(range) => {
+                        let offset = stripe.offset() as i64;
+                        offset >= range.start && offset < range.end
+                    }
+                    None => true,
+                })
+                .collect();
+
+            if let Some(predicate) = &pruning_predicate {
+                let stats = StripeStatistics {
+                    metadata: &metadata,
+                    table_schema: &table_schema,
+                };
+                match predicate.prune(&stats) {
+                    Ok(values) => {
+                        for (keep, value) in selected.iter_mut().zip(values) {
+                            if *keep && !value {
+                                *keep = false;
+                                stripes_pruned.add(1);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        debug!(
+                            "Error evaluating stripe predicate on partition {partition_index}: {e}"
+                        );
+                    }
+                }
+            }
+
+            // Read each contiguous run of selected stripes with its own reader,
+            // as the underlying reader selects stripes by byte range
+            let runs = selected_byte_ranges(&metadata, &selected);
+            let streams = runs.into_iter().map(move |range| {
+                let mask = ProjectionMask::roots(
+                    metadata.root_data_type(),
+                    adapted_projections.iter().map(|i| i + 1),
+                );
+                ArrowReaderBuilder::new(reader.clone(), Arc::clone(&metadata))
+                    .with_batch_size(batch_size)
+                    .with_projection(mask)
+                    .with_file_byte_range(range)
+                    .build_async()
+            });
+
+            let stream =
+                futures::stream::iter(streams)
+                    .flatten()
+                    .map(move |maybe_batch| {
+                        maybe_batch
+                            .and_then(|b| schema_mapping.map_batch(b).map_err(Into::into))
+                    });
+
+            Ok(stream.boxed())
+        }))
+    }
+}
+
+/// Fetches and decodes the footer of the ORC file described by `object_meta`
+pub(crate) async fn fetch_orc_metadata(
+    store: Arc<dyn ObjectStore>,
+    object_meta: ObjectMeta,
+) -> Result<FileMetadata> {
+    let mut reader = ObjectStoreReader::new(store, object_meta);
+    read_orc_metadata(&mut reader).await
+}
+
+async fn read_orc_metadata(reader: &mut ObjectStoreReader) -> Result<FileMetadata> {
+    orc_rust::reader::metadata::read_metadata_async(reader)
+        .await
+        .map_err(|e| DataFusionError::External(Box::new(e)))
+}
+
+/// Computes the byte ranges covering each contiguous run of selected stripes
+fn selected_byte_ranges(metadata: &FileMetadata, selected: &[bool]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut previous_selected = false;
+    for (stripe, keep) in metadata.stripe_metadatas().iter().zip(selected) {
+        let start = stripe.offset() as usize;
+        let end = start + 1;
+        match (keep, previous_selected) {
+            (true, true) => ranges.last_mut().unwrap().end = end,
+            (true, false) => ranges.push(start..end),
+            _ => {}
+        }
+        previous_selected = *keep;
+    }
+    ranges
+}
+
+/// Adapts an [`ObjectStore`] to the [`AsyncChunkReader`] interface used by
+/// the ORC reader so that only the required byte ranges are fetched
+#[derive(Debug, Clone)]
+struct ObjectStoreReader {
+    store: Arc<dyn ObjectStore>,
+    object_meta: ObjectMeta,
+}
+
+impl ObjectStoreReader {
+    fn new(store: Arc<dyn ObjectStore>, object_meta: ObjectMeta) -> Self {
+        Self { store, object_meta }
+    }
+}
+
+impl AsyncChunkReader for ObjectStoreReader {
+    fn len(&mut self) -> BoxFuture<'_, std::io::Result<u64>> {
+        async move { Ok(self.object_meta.size as u64) }.boxed()
+    }
+
+    fn get_bytes(
+        &mut self,
+        offset_from_start: u64,
+        length: u64,
+    ) -> BoxFuture<'_, std::io::Result<Bytes>> {
+        let offset_from_start = offset_from_start as usize;
+        let length = length as usize;
+        let range = offset_from_start..(offset_from_start + length);
+        async move {
+            self.store
+                .get_range(&self.object_meta.location, range)
+                .await
+                .map_err(|e| e.into())
+        }
+        .boxed()
+    }
+}
+
+/// Wraps the stripe level statistics of an ORC file in the
+/// [`PruningStatistics`] interface, one container per stripe
+struct StripeStatistics<'a> {
+    metadata: &'a FileMetadata,
+    table_schema: &'a SchemaRef,
+}
+
+impl<'a> StripeStatistics<'a> {
+    /// Returns the statistics of `column` for each stripe, if the column is
+    /// present in the file
+    fn column_statistics(&self, column: &Column) -> Option<Vec<&'a ColumnStatistics>> {
+        // make sure the column is part of the table before looking it up
+        self.table_schema.field_with_name(&column.name).ok()?;
+        let column_index = self
+            .metadata
+            .root_data_type()
+            .children()
+            .iter()
+            .find(|c| c.name() == column.name)?
+            .data_type()
+            .column_index();
+        self.metadata
+            .stripe_metadatas()
+            .iter()
+            .map(|stripe| stripe.column_statistics().get(column_index))
+            .collect()
+    }
+
+    fn min_max(&self, column: &Column, min: bool) -> Option<ArrayRef> {
+        let stats = self.column_statistics(column)?;
+        let typed = stats.iter().map(|s| s.type_statistics());
+        match stats.first()?.type_statistics()? {
+            TypeStatistics::Integer { .. } => {
+                Some(Arc::new(Int64Array::from_iter(typed.map(|s| match s {
+                    Some(TypeStatistics::Integer {
+                        min: lo, max: hi, ..
+                    }) => Some(if min { *lo } else { *hi }),
+                    _ => None,
+                }))))
+            }
+            TypeStatistics::Double { .. } => {
+                Some(Arc::new(Float64Array::from_iter(typed.map(|s| match s {
+                    Some(TypeStatistics::Double {
+                        min: lo, max: hi, ..
+                    }) => Some(if min { *lo } else { *hi }),
+                    _ => None,
+                }))))
+            }
+            TypeStatistics::String { .. } => {
+                Some(Arc::new(StringArray::from_iter(typed.map(|s| match s {
+                    Some(TypeStatistics::String {
+                        min: lo, max: hi, ..
+                    }) => Some(if min { lo.clone() } else { hi.clone() }),
+                    _ => None,
+                }))))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> PruningStatistics for StripeStatistics<'a> {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.min_max(column, true)
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.min_max(column, false)
+    }
+
+    fn num_containers(&self) -> usize {
+        self.metadata.stripe_metadatas().len()
+    }
+
+    fn null_counts(&self, column: &Column) -> Option<ArrayRef> {
+        let stats = self.column_statistics(column)?;
+        let row_counts = self.metadata.stripe_metadatas().iter();
+        Some(Arc::new(UInt64Array::from_iter(
+            stats.iter().zip(row_counts).map(|(s, stripe)| {
+                Some(stripe.number_of_rows().saturating_sub(s.number_of_values()))
+            }),
+        )))
+    }
+
+    fn row_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        Some(Arc::new(UInt64Array::from_iter_values(
+            self.metadata
+                .stripe_metadatas()
+                .iter()
+                .map(|stripe| stripe.number_of_rows()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::file_format::orc::OrcFormat;
+    use crate::datasource::file_format::FileFormat;
+    use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::physical_plan::collect;
+    use crate::prelude::SessionContext;
+    use crate::test::object_store::local_unpartitioned_file;
+
+    use datafusion_expr::{col, lit, Expr};
+    use datafusion_physical_expr::planner::logical2physical;
+    use object_store::local::LocalFileSystem;
+
+    /// Scans `tests/data/two_stripes.orc`, whose stripes hold the ids 1 to 5
+    /// and 6 to 10, returning the number of rows read and of stripes pruned
+    async fn scan_with_predicate(predicate: Expr) -> Result<(usize, usize)> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let meta = local_unpartitioned_file("tests/data/two_stripes.orc");
+        let file_schema = OrcFormat
+            .infer_schema(&state, &store, &[meta.clone()])
+            .await?;
+
+        let predicate = logical2physical(&predicate, &file_schema);
+        let config = FileScanConfig::new(ObjectStoreUrl::local_filesystem(), file_schema)
+            .with_file(meta.into());
+        let exec = Arc::new(OrcExec::new(config, Some(predicate)));
+        let batches = collect(exec.clone(), state.task_ctx()).await?;
+
+        let num_rows = batches.iter().map(|b| b.num_rows()).sum();
+        let stripes_pruned = exec
+            .metrics()
+            .unwrap()
+            .sum_by_name("stripes_pruned_statistics")
+            .unwrap()
+            .as_usize();
+        Ok((num_rows, stripes_pruned))
+    }
+
+    #[tokio::test]
+    async fn stripe_pruning() -> Result<()> {
+        // only one of the stripes may contain matching rows
+        assert_eq!(scan_with_predicate(col("id").gt(lit(7i64))).await?, (5, 1));
+        assert_eq!(
+            scan_with_predicate(col("name").lt_eq(lit("c"))).await?,
+            (5, 1)
+        );
+
+        // both stripes may contain matching rows
+        assert_eq!(
+            scan_with_predicate(col("id").between(lit(4i64), lit(6i64))).await?,
+            (10, 0)
+        );
+
+        // no stripe contains matching rows
+        assert_eq!(scan_with_predicate(col("id").gt(lit(10i64))).await?, (0, 2));
+        Ok(())
+    }
+}
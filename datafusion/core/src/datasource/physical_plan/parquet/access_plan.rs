@@ -338,6 +338,122 @@ impl ParquetAccessPlan {
     }
 }
 
+/// Positions of rows within a parquet file that must not be returned by a
+/// `ParquetExec`, such as Delta Lake deletion vectors or Iceberg positional
+/// deletes.
+///
+/// Row positions are relative to the start of the file (the first row of
+/// the first row group is position `0`). As the number of rows in each row
+/// group is only known once the file metadata has been read, the deleted rows
+/// are translated into a [`ParquetAccessPlan`] when the file is opened.
+///
+/// A `ParquetDeletionVector` is provided as `extensions` on
+/// [`PartitionedFile`], optionally wrapping a [`ParquetAccessPlan`] that is
+/// applied as well.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion::datasource::listing::PartitionedFile;
+/// # use datafusion::datasource::physical_plan::parquet::ParquetDeletionVector;
+/// // rows 3, 4 and 17 of the file have been deleted
+/// let deletion_vector = ParquetDeletionVector::new([3, 4, 17]);
+/// let partitioned_file = PartitionedFile::new("my_file.parquet", 1234)
+///   .with_extensions(Arc::new(deletion_vector));
+/// ```
+///
+/// [`PartitionedFile`]: crate::datasource::listing::PartitionedFile
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParquetDeletionVector {
+    /// Sorted, de-duplicated positions of the deleted rows
+    deleted_rows: Vec<u64>,
+    /// Optional access plan to combine the deletion vector with
+    access_plan: Option<ParquetAccessPlan>,
+}
+
+impl ParquetDeletionVector {
+    /// Create a new `ParquetDeletionVector` from the positions of the deleted
+    /// rows. The positions do not need to be sorted.
+    pub fn new(deleted_rows: impl IntoIterator<Item = u64>) -> Self {
+        let mut deleted_rows: Vec<u64> = deleted_rows.into_iter().collect();
+        deleted_rows.sort_unstable();
+        deleted_rows.dedup();
+        Self {
+            deleted_rows,
+            access_plan: None,
+        }
+    }
+
+    /// Also restrict the scan to the specified [`ParquetAccessPlan`]
+    pub fn with_access_plan(mut self, access_plan: ParquetAccessPlan) -> Self {
+        self.access_plan = Some(access_plan);
+        self
+    }
+
+    /// Return the sorted positions of the deleted rows
+    pub fn deleted_rows(&self) -> &[u64] {
+        &self.deleted_rows
+    }
+
+    /// Return the wrapped [`ParquetAccessPlan`], if any
+    pub fn access_plan(&self) -> Option<&ParquetAccessPlan> {
+        self.access_plan.as_ref()
+    }
+
+    /// Apply the deleted rows to `access_plan`, returning a plan that does
+    /// not scan any of them.
+    ///
+    /// Row groups whose rows are all deleted are skipped entirely, and row
+    /// groups with some deleted rows are scanned with a [`RowSelection`].
+    pub fn apply(
+        &self,
+        mut access_plan: ParquetAccessPlan,
+        row_group_meta_data: &[RowGroupMetaData],
+    ) -> ParquetAccessPlan {
+        let mut deleted = self.deleted_rows.iter().peekable();
+        let mut row_group_start = 0u64;
+        for (idx, rg_meta) in row_group_meta_data.iter().enumerate() {
+            let num_rows = rg_meta.num_rows() as u64;
+            let row_group_end = row_group_start + num_rows;
+
+            // build skip / select runs for the deleted rows in this row group
+            let mut selectors = vec![];
+            let mut next_row = row_group_start;
+            while let Some(&&row) = deleted.peek() {
+                if row >= row_group_end {
+                    break;
+                }
+                deleted.next();
+                if row > next_row {
+                    selectors.push(RowSelector::select((row - next_row) as usize));
+                }
+                selectors.push(RowSelector::skip(1));
+                next_row = row + 1;
+            }
+
+            if next_row == row_group_end && !selectors.is_empty() {
+                let all_deleted = selectors.iter().all(|s| s.skip);
+                if all_deleted {
+                    access_plan.skip(idx);
+                    row_group_start = row_group_end;
+                    continue;
+                }
+            }
+
+            if !selectors.is_empty() {
+                if next_row < row_group_end {
+                    selectors
+                        .push(RowSelector::select((row_group_end - next_row) as usize));
+                }
+                access_plan.scan_selection(idx, RowSelection::from(selectors));
+            }
+            row_group_start = row_group_end;
+        }
+        access_plan
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -511,6 +627,74 @@ mod test {
         assert_contains!(err, "Invalid ParquetAccessPlan Selection. Row group 1 has 20 rows but selection only specifies 22 rows");
     }
 
+    #[test]
+    fn test_deletion_vector() {
+        // row groups have 10, 20, 30, 40 rows, starting at 0, 10, 30, 60
+        let deletion_vector = ParquetDeletionVector::new(
+            // all of row group 0, first and last row of row group 1, row 75
+            (0..10).chain([10, 29, 75]),
+        );
+        let access_plan =
+            deletion_vector.apply(ParquetAccessPlan::new_all(4), row_group_metadata());
+
+        assert_eq!(
+            access_plan,
+            ParquetAccessPlan::new(vec![
+                RowGroupAccess::Skip,
+                RowGroupAccess::Selection(
+                    vec![
+                        RowSelector::skip(1),
+                        RowSelector::select(18),
+                        RowSelector::skip(1),
+                    ]
+                    .into()
+                ),
+                RowGroupAccess::Scan,
+                RowGroupAccess::Selection(
+                    vec![
+                        RowSelector::select(15),
+                        RowSelector::skip(1),
+                        RowSelector::select(24),
+                    ]
+                    .into()
+                ),
+            ])
+        );
+
+        // the result must be a valid overall selection
+        let row_selection = access_plan
+            .into_overall_row_selection(row_group_metadata())
+            .unwrap();
+        assert!(row_selection.is_some());
+    }
+
+    #[test]
+    fn test_deletion_vector_with_access_plan() {
+        let mut existing = ParquetAccessPlan::new_all(4);
+        existing.skip(1);
+        let deletion_vector = ParquetDeletionVector::new([12, 5]);
+        assert_eq!(deletion_vector.deleted_rows(), &[5, 12]);
+
+        let access_plan = deletion_vector.apply(existing, row_group_metadata());
+        assert_eq!(
+            access_plan,
+            ParquetAccessPlan::new(vec![
+                RowGroupAccess::Selection(
+                    vec![
+                        RowSelector::select(5),
+                        RowSelector::skip(1),
+                        RowSelector::select(4),
+                    ]
+                    .into()
+                ),
+                // already skipped, stays skipped
+                RowGroupAccess::Skip,
+                RowGroupAccess::Scan,
+                RowGroupAccess::Scan,
+            ])
+        );
+    }
+
     static ROW_GROUP_METADATA: OnceLock<Vec<RowGroupMetaData>> = OnceLock::new();
 
     /// [`RowGroupMetaData`] that returns 4 row groups with 10, 20, 30, 40 rows
@@ -60,7 +60,7 @@ mod writer;
 use crate::datasource::schema_adapter::{
     DefaultSchemaAdapterFactory, SchemaAdapterFactory,
 };
-pub use access_plan::{ParquetAccessPlan, RowGroupAccess};
+pub use access_plan::{ParquetAccessPlan, ParquetDeletionVector, RowGroupAccess};
 pub use metrics::ParquetFileMetrics;
 use opener::ParquetOpener;
 pub use reader::{DefaultParquetFileReaderFactory, ParquetFileReaderFactory};
@@ -148,6 +148,9 @@ pub use writer::plan_to_parquet;
 /// * User provided  [`ParquetAccessPlan`]s to skip row groups and/or pages
 /// based on external information. See "Implementing External Indexes" below
 ///
+/// * User provided [`ParquetDeletionVector`]s to exclude deleted rows, as
+/// found in table formats such as Delta Lake and Iceberg
+///
 /// # Implementing External Indexes
 ///
 /// It is possible to restrict the row groups and selections within those row
@@ -20,7 +20,7 @@
 use crate::datasource::physical_plan::parquet::page_filter::PagePruningPredicate;
 use crate::datasource::physical_plan::parquet::row_groups::RowGroupAccessPlanFilter;
 use crate::datasource::physical_plan::parquet::{
-    row_filter, should_enable_page_index, ParquetAccessPlan,
+    row_filter, should_enable_page_index, ParquetAccessPlan, ParquetDeletionVector,
 };
 use crate::datasource::physical_plan::{
     FileMeta, FileOpenFuture, FileOpener, ParquetFileMetrics, ParquetFileReaderFactory,
@@ -36,6 +36,7 @@ use log::debug;
 use parquet::arrow::arrow_reader::ArrowReaderOptions;
 use parquet::arrow::async_reader::AsyncFileReader;
 use parquet::arrow::{ParquetRecordBatchStreamBuilder, ProjectionMask};
+use parquet::file::metadata::RowGroupMetaData;
 use std::sync::Arc;
 
 /// Implements [`FileOpener`] for a parquet file
@@ -139,8 +140,7 @@ impl FileOpener for ParquetOpener {
             let predicate = pruning_predicate.as_ref().map(|p| p.as_ref());
             let rg_metadata = file_metadata.row_groups();
             // track which row groups to actually read
-            let access_plan =
-                create_initial_plan(&file_name, extensions, rg_metadata.len())?;
+            let access_plan = create_initial_plan(&file_name, extensions, rg_metadata)?;
             let mut row_groups = RowGroupAccessPlanFilter::new(access_plan);
             // if there is a range restricting what parts of the file to read
             if let Some(range) = file_range.as_ref() {
@@ -216,8 +216,9 @@ impl FileOpener for ParquetOpener {
 
 /// Return the initial [`ParquetAccessPlan`]
 ///
-/// If the user has supplied one as an extension, use that
-/// otherwise return a plan that scans all row groups
+/// If the user has supplied one as an extension, use that. If the user has
+/// supplied a [`ParquetDeletionVector`], exclude the deleted rows. Otherwise
+/// return a plan that scans all row groups
 ///
 /// Returns an error if an invalid `ParquetAccessPlan` is provided
 ///
@@ -225,19 +226,24 @@ impl FileOpener for ParquetOpener {
 fn create_initial_plan(
     file_name: &str,
     extensions: Option<Arc<dyn std::any::Any + Send + Sync>>,
-    row_group_count: usize,
+    row_group_meta_data: &[RowGroupMetaData],
 ) -> Result<ParquetAccessPlan> {
+    let row_group_count = row_group_meta_data.len();
     if let Some(extensions) = extensions {
         if let Some(access_plan) = extensions.downcast_ref::<ParquetAccessPlan>() {
-            let plan_len = access_plan.len();
-            if plan_len != row_group_count {
-                return exec_err!(
-                    "Invalid ParquetAccessPlan for {file_name}. Specified {plan_len} row groups, but file has {row_group_count}"
-                );
-            }
-
-            // check row group count matches the plan
+            check_plan_len(file_name, access_plan, row_group_count)?;
             return Ok(access_plan.clone());
+        } else if let Some(deletion_vector) =
+            extensions.downcast_ref::<ParquetDeletionVector>()
+        {
+            let access_plan = match deletion_vector.access_plan() {
+                Some(access_plan) => {
+                    check_plan_len(file_name, access_plan, row_group_count)?;
+                    access_plan.clone()
+                }
+                None => ParquetAccessPlan::new_all(row_group_count),
+            };
+            return Ok(deletion_vector.apply(access_plan, row_group_meta_data));
         } else {
             debug!("ParquetExec Ignoring unknown extension specified for {file_name}");
         }
@@ -246,3 +252,18 @@ fn create_initial_plan(
     // default to scanning all row groups
     Ok(ParquetAccessPlan::new_all(row_group_count))
 }
+
+/// check row group count matches the plan
+fn check_plan_len(
+    file_name: &str,
+    access_plan: &ParquetAccessPlan,
+    row_group_count: usize,
+) -> Result<()> {
+    let plan_len = access_plan.len();
+    if plan_len != row_group_count {
+        return exec_err!(
+            "Invalid ParquetAccessPlan for {file_name}. Specified {plan_len} row groups, but file has {row_group_count}"
+        );
+    }
+    Ok(())
+}
@@ -71,6 +71,14 @@ pub trait TableProvider: Sync + Send {
         None
     }
 
+    /// Get the names of the columns this table is partitioned by, if any.
+    ///
+    /// Partition columns are still part of [`Self::schema`], and are
+    /// reported as such by `DESCRIBE`.
+    fn partition_columns(&self) -> Vec<String> {
+        vec![]
+    }
+
     /// Create an [`ExecutionPlan`] for scanning the table with optionally
     /// specified `projection`, `filter` and `limit`, described below.
     ///
@@ -281,6 +289,56 @@ pub trait TableProvider: Sync + Send {
     ) -> Result<Arc<dyn ExecutionPlan>> {
         not_impl_err!("Insert into not implemented for this table")
     }
+
+    /// Returns the remote system that can compute joins and aggregates over
+    /// this table together with the other tables it provides, if any.
+    ///
+    /// See [`FederatedSource`] for details.
+    fn federated_source(&self) -> Option<Arc<dyn FederatedSource>> {
+        None
+    }
+}
+
+/// A remote system, such as another database, that can compute whole
+/// [`LogicalPlan`]s over the tables it provides.
+///
+/// [`TableProvider::scan`] can only push projections, filters and limits
+/// down to the table. When every table read by a join or an aggregate
+/// returns a `FederatedSource` with the same [`name`](Self::name) from
+/// [`TableProvider::federated_source`], the physical planner instead offers
+/// the largest such subtree of the [`LogicalPlan`] to the remote system with
+/// [`Self::supports_plan`], and replaces it with the [`ExecutionPlan`]
+/// returned by [`Self::create_physical_plan`]. DataFusion then only computes
+/// the rest of the query.
+///
+/// The subtree only consists of [`LogicalPlan::TableScan`],
+/// [`LogicalPlan::Projection`], [`LogicalPlan::Filter`],
+/// [`LogicalPlan::Aggregate`], [`LogicalPlan::Join`],
+/// [`LogicalPlan::CrossJoin`], [`LogicalPlan::Sort`],
+/// [`LogicalPlan::Limit`] and [`LogicalPlan::SubqueryAlias`] nodes. A remote
+/// SQL database can, for example, compute it by running the query that
+/// [`plan_to_sql`] generates for it.
+///
+/// [`plan_to_sql`]: datafusion_sql::unparser::plan_to_sql
+#[async_trait]
+pub trait FederatedSource: std::fmt::Debug + Sync + Send {
+    /// Returns the name identifying the remote system. Tables whose sources
+    /// have the same name can be joined by the remote system.
+    fn name(&self) -> &str;
+
+    /// Returns true if the remote system can compute `plan`.
+    fn supports_plan(&self, _plan: &LogicalPlan) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Creates an [`ExecutionPlan`] that computes `plan` with the remote
+    /// system. The output schema of the returned plan must match the schema
+    /// of `plan`.
+    async fn create_physical_plan(
+        &self,
+        state: &SessionState,
+        plan: &LogicalPlan,
+    ) -> Result<Arc<dyn ExecutionPlan>>;
 }
 
 /// A factory which creates [`TableProvider`]s at runtime given a URL.
@@ -0,0 +1,745 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`RetryObjectStore`]: retries failed or stalled reads of an [`ObjectStore`]
+
+use std::fmt::{Debug, Display, Formatter};
+use std::io::ErrorKind;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use futures::stream::BoxStream;
+use futures::{FutureExt, StreamExt};
+use log::debug;
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore,
+    PutMultipartOpts, PutOptions, PutPayload, PutResult,
+};
+
+use crate::physical_plan::metrics::{Count, ExecutionPlanMetricsSet, MetricBuilder};
+
+/// Configuration of the retries performed by a [`RetryObjectStore`]
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// The maximum number of times a read is retried before giving up
+    pub max_retries: usize,
+    /// The delay before the first retry, doubled for every further retry
+    pub initial_backoff: Duration,
+    /// The maximum delay between two retries
+    pub max_backoff: Duration,
+    /// The maximum time to wait for a response to a request, or for the
+    /// next chunk of data of a response. `None` waits indefinitely.
+    pub request_timeout: Option<Duration>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(15),
+            request_timeout: Some(Duration::from_secs(30)),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Set the maximum number of retries
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Set the maximum delay between two retries
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set the request timeout
+    pub fn with_request_timeout(mut self, request_timeout: Option<Duration>) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Returns the delay before the `retry`th retry (starting at 1)
+    fn backoff(&self, retry: usize) -> Duration {
+        let exponent = u32::try_from(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .checked_mul(2_u32.saturating_pow(exponent))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+/// Counters of the retries performed by a [`RetryObjectStore`]
+#[derive(Debug, Default)]
+pub struct RetryMetrics {
+    retries: AtomicUsize,
+    timeouts: AtomicUsize,
+    resumed_bytes: AtomicUsize,
+}
+
+impl RetryMetrics {
+    /// The number of requests that were retried
+    pub fn retries(&self) -> usize {
+        self.retries.load(Ordering::Relaxed)
+    }
+
+    /// The number of requests that timed out
+    pub fn timeouts(&self) -> usize {
+        self.timeouts.load(Ordering::Relaxed)
+    }
+
+    /// The number of bytes that did not have to be read again because a
+    /// failed ranged read was resumed from the offset at which it failed
+    pub fn resumed_bytes(&self) -> usize {
+        self.resumed_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Records a retry resuming after `resumed_bytes` bytes, also in the
+    /// counters of the current scan, if any
+    fn record_retry(&self, resumed_bytes: usize) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+        self.resumed_bytes
+            .fetch_add(resumed_bytes, Ordering::Relaxed);
+        let _ = SCAN_RETRY_METRICS.try_with(|scan| {
+            scan.retries.add(1);
+            scan.resumed_bytes.add(resumed_bytes);
+        });
+    }
+
+    /// Records a timeout, also in the counters of the current scan, if any
+    fn record_timeout(&self) {
+        self.timeouts.fetch_add(1, Ordering::Relaxed);
+        let _ = SCAN_RETRY_METRICS.try_with(|scan| scan.timeouts.add(1));
+    }
+}
+
+tokio::task_local! {
+    /// The retry counters of the partition of the file scan being polled
+    static SCAN_RETRY_METRICS: ScanRetryMetrics;
+}
+
+/// The retry counters of a partition of a file scan, reported in the
+/// [`MetricsSet`] of the scan
+///
+/// [`MetricsSet`]: crate::physical_plan::metrics::MetricsSet
+#[derive(Debug, Clone)]
+pub(crate) struct ScanRetryMetrics {
+    retries: Count,
+    timeouts: Count,
+    resumed_bytes: Count,
+}
+
+impl ScanRetryMetrics {
+    pub(crate) fn new(metrics: &ExecutionPlanMetricsSet, partition: usize) -> Self {
+        Self {
+            retries: MetricBuilder::new(metrics)
+                .counter("object_store_retries", partition),
+            timeouts: MetricBuilder::new(metrics)
+                .counter("object_store_timeouts", partition),
+            resumed_bytes: MetricBuilder::new(metrics)
+                .counter("object_store_resumed_bytes", partition),
+        }
+    }
+
+    /// Runs `f`, recording the retries of the reads of [`RetryObjectStore`]s
+    /// it performs in these counters as well
+    pub(crate) fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        SCAN_RETRY_METRICS.sync_scope(self.clone(), f)
+    }
+}
+
+/// An [`ObjectStore`] that retries the reads of an inner store with
+/// exponential backoff.
+///
+/// * Ranged reads ([`ObjectStore::get_range`], and therefore
+///   [`ObjectStore::get_ranges`] used by the file scans) are retried when the
+///   request fails or stalls for longer than
+///   [`RetryConfig::request_timeout`]. If the response was partially
+///   received, the retry only requests the remaining bytes.
+///
+/// * Other reads ([`ObjectStore::get_opts`] and [`ObjectStore::head`]) are
+///   retried if the request fails, but failures while streaming the body of
+///   the response are returned to the caller.
+///
+/// Only errors that may be transient are retried: [`object_store::Error::Generic`]
+/// errors, which include network errors, and errors caused by timeouts or
+/// connection failures. Writes, listings and other operations are passed to
+/// the inner store unchanged.
+///
+/// The retries are counted in [`Self::metrics`], and in the metrics of the
+/// file scans reading through the store (`object_store_retries`,
+/// `object_store_timeouts` and `object_store_resumed_bytes`).
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// # use datafusion::datasource::retry::{RetryConfig, RetryObjectStore};
+/// # use datafusion::prelude::SessionContext;
+/// # use object_store::memory::InMemory;
+/// # use url::Url;
+/// let config = RetryConfig::default().with_max_retries(3);
+/// let store = Arc::new(RetryObjectStore::new(Arc::new(InMemory::new()), config));
+///
+/// let ctx = SessionContext::new();
+/// let url = Url::parse("memory://").unwrap();
+/// ctx.register_object_store(&url, store);
+/// ```
+pub struct RetryObjectStore {
+    inner: Arc<dyn ObjectStore>,
+    config: RetryConfig,
+    metrics: Arc<RetryMetrics>,
+}
+
+impl RetryObjectStore {
+    /// Create a new [`RetryObjectStore`] retrying the reads of `inner`
+    pub fn new(inner: Arc<dyn ObjectStore>, config: RetryConfig) -> Self {
+        Self {
+            inner,
+            config,
+            metrics: Arc::default(),
+        }
+    }
+
+    /// Returns the retry counters of this store
+    pub fn metrics(&self) -> &Arc<RetryMetrics> {
+        &self.metrics
+    }
+
+    /// Runs `f` until it succeeds, a non transient error occurs or the
+    /// maximum number of retries is reached
+    async fn retry<T, F, Fut>(&self, mut f: F) -> object_store::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = object_store::Result<T>>,
+    {
+        let mut retries = 0;
+        loop {
+            match self.with_timeout(f()).await {
+                Err(e) if retries < self.config.max_retries && is_transient(&e) => {
+                    retries += 1;
+                    self.metrics.record_retry(0);
+                    let backoff = self.config.backoff(retries);
+                    debug!("Retrying request in {backoff:?} after error: {e}");
+                    tokio::time::sleep(backoff).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Fails with a [`object_store::Error::Generic`] error if `fut` does not
+    /// complete within the request timeout
+    async fn with_timeout<T>(
+        &self,
+        fut: impl std::future::Future<Output = object_store::Result<T>>,
+    ) -> object_store::Result<T> {
+        let Some(timeout) = self.config.request_timeout else {
+            return fut.await;
+        };
+        match tokio::time::timeout(timeout, fut).await {
+            Ok(result) => result,
+            Err(_) => {
+                self.metrics.record_timeout();
+                Err(object_store::Error::Generic {
+                    store: "RetryObjectStore",
+                    source: format!("request timed out after {timeout:?}").into(),
+                })
+            }
+        }
+    }
+
+    /// Reads `range` of `location`, appending the received bytes to `buf`
+    /// as they arrive so that they are kept if the read fails midway
+    async fn read_range(
+        &self,
+        location: &Path,
+        range: Range<usize>,
+        buf: &mut BytesMut,
+    ) -> object_store::Result<()> {
+        let expected = range.len();
+        let options = GetOptions {
+            range: Some(range.into()),
+            ..Default::default()
+        };
+        let result = self
+            .with_timeout(self.inner.get_opts(location, options))
+            .await?;
+
+        let mut stream = result.into_stream();
+        let mut received = 0;
+        while let Some(chunk) = self.with_timeout(stream.next().map(Ok)).await? {
+            let chunk = chunk?;
+            received += chunk.len();
+            if received > expected {
+                // the store ignored the range, retrying would not help
+                return Err(object_store::Error::Generic {
+                    store: "RetryObjectStore",
+                    source: Box::new(UnexpectedLength { expected, received }),
+                });
+            }
+            buf.extend_from_slice(&chunk);
+        }
+        if received < expected {
+            return Err(object_store::Error::Generic {
+                store: "RetryObjectStore",
+                source: format!("expected {expected} bytes, received {received}").into(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The error of a ranged read whose response is longer than the range
+#[derive(Debug)]
+struct UnexpectedLength {
+    expected: usize,
+    received: usize,
+}
+
+impl Display for UnexpectedLength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected {} bytes, received at least {}",
+            self.expected, self.received
+        )
+    }
+}
+
+impl std::error::Error for UnexpectedLength {}
+
+/// Returns `true` if the request failing with `e` may succeed when retried
+fn is_transient(e: &object_store::Error) -> bool {
+    match e {
+        object_store::Error::Generic { source, .. } => !source.is::<UnexpectedLength>(),
+        // e.g. a timeout or a connection failure wrapped in another variant
+        _ => has_transient_source(e),
+    }
+}
+
+/// Returns `true` if one of the sources of `e` is a timeout or a connection
+/// failure
+fn has_transient_source(e: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = e.source();
+    while let Some(e) = source {
+        if e.is::<tokio::time::error::Elapsed>() {
+            return true;
+        }
+        if let Some(e) = e.downcast_ref::<std::io::Error>() {
+            if matches!(
+                e.kind(),
+                ErrorKind::TimedOut
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::ConnectionRefused
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::UnexpectedEof
+                    | ErrorKind::Interrupted
+            ) {
+                return true;
+            }
+        }
+        source = e.source();
+    }
+    false
+}
+
+impl Display for RetryObjectStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RetryObjectStore({})", self.inner)
+    }
+}
+
+impl Debug for RetryObjectStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryObjectStore")
+            .field("inner", &self.inner)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ObjectStore for RetryObjectStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOpts,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        self.inner.put_multipart_opts(location, opts).await
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        self.retry(|| self.inner.get_opts(location, options.clone()))
+            .await
+    }
+
+    async fn get_range(
+        &self,
+        location: &Path,
+        range: Range<usize>,
+    ) -> object_store::Result<Bytes> {
+        let mut buf = BytesMut::with_capacity(range.len());
+        let mut retries = 0;
+        loop {
+            let remaining = range.start + buf.len()..range.end;
+            match self.read_range(location, remaining, &mut buf).await {
+                Ok(()) => return Ok(buf.freeze()),
+                Err(e) if retries < self.config.max_retries && is_transient(&e) => {
+                    retries += 1;
+                    self.metrics.record_retry(buf.len());
+                    let backoff = self.config.backoff(retries);
+                    debug!(
+                        "Resuming read of {location} at offset {} in {backoff:?} after error: {e}",
+                        range.start + buf.len()
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn head(&self, location: &Path) -> object_store::Result<ObjectMeta> {
+        self.retry(|| self.inner.head(location)).await
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        self.inner.delete(location).await
+    }
+
+    fn list(
+        &self,
+        prefix: Option<&Path>,
+    ) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+        self.inner.list(prefix)
+    }
+
+    async fn list_with_delimiter(
+        &self,
+        prefix: Option<&Path>,
+    ) -> object_store::Result<ListResult> {
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(
+        &self,
+        from: &Path,
+        to: &Path,
+    ) -> object_store::Result<()> {
+        self.inner.copy_if_not_exists(from, to).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::physical_plan::collect;
+    use crate::prelude::{CsvReadOptions, SessionContext};
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use object_store::memory::InMemory;
+    use object_store::GetResultPayload;
+    use url::Url;
+
+    /// An [`ObjectStore`] whose first `failures` reads either fail or, if
+    /// `truncate` is set, stop with an error after half of the data. If
+    /// `ignore_range` is set, the reads return the whole object.
+    #[derive(Debug)]
+    struct FlakyObjectStore {
+        inner: InMemory,
+        failures: AtomicUsize,
+        truncate: bool,
+        ignore_range: bool,
+    }
+
+    impl Display for FlakyObjectStore {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "FlakyObjectStore")
+        }
+    }
+
+    fn flaky_error() -> object_store::Error {
+        object_store::Error::Generic {
+            store: "FlakyObjectStore",
+            source: "connection reset".into(),
+        }
+    }
+
+    #[async_trait]
+    impl ObjectStore for FlakyObjectStore {
+        async fn put_opts(
+            &self,
+            location: &Path,
+            payload: PutPayload,
+            opts: PutOptions,
+        ) -> object_store::Result<PutResult> {
+            self.inner.put_opts(location, payload, opts).await
+        }
+
+        async fn put_multipart_opts(
+            &self,
+            location: &Path,
+            opts: PutMultipartOpts,
+        ) -> object_store::Result<Box<dyn MultipartUpload>> {
+            self.inner.put_multipart_opts(location, opts).await
+        }
+
+        async fn get_opts(
+            &self,
+            location: &Path,
+            mut options: GetOptions,
+        ) -> object_store::Result<GetResult> {
+            if self.ignore_range {
+                options.range = None;
+            }
+            let fail = self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            if fail && !self.truncate {
+                return Err(flaky_error());
+            }
+
+            let mut result = self.inner.get_opts(location, options).await?;
+            if fail {
+                let data = self.inner.get_range(location, result.range.clone()).await?;
+                let half = data.slice(0..data.len() / 2);
+                result.payload = GetResultPayload::Stream(
+                    futures::stream::iter(vec![Ok(half), Err(flaky_error())]).boxed(),
+                );
+            }
+            Ok(result)
+        }
+
+        async fn delete(&self, location: &Path) -> object_store::Result<()> {
+            self.inner.delete(location).await
+        }
+
+        fn list(
+            &self,
+            prefix: Option<&Path>,
+        ) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+            self.inner.list(prefix)
+        }
+
+        async fn list_with_delimiter(
+            &self,
+            prefix: Option<&Path>,
+        ) -> object_store::Result<ListResult> {
+            self.inner.list_with_delimiter(prefix).await
+        }
+
+        async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+            self.inner.copy(from, to).await
+        }
+
+        async fn copy_if_not_exists(
+            &self,
+            from: &Path,
+            to: &Path,
+        ) -> object_store::Result<()> {
+            self.inner.copy_if_not_exists(from, to).await
+        }
+    }
+
+    async fn retry_store(
+        failures: usize,
+        truncate: bool,
+        max_retries: usize,
+    ) -> object_store::Result<RetryObjectStore> {
+        let inner = InMemory::new();
+        let data: Vec<u8> = (0..100).collect();
+        inner.put(&Path::from("file"), data.into()).await?;
+        let flaky = FlakyObjectStore {
+            inner,
+            failures: AtomicUsize::new(failures),
+            truncate,
+            ignore_range: false,
+        };
+        let config = RetryConfig::default()
+            .with_max_retries(max_retries)
+            .with_initial_backoff(Duration::from_millis(1));
+        Ok(RetryObjectStore::new(Arc::new(flaky), config))
+    }
+
+    #[tokio::test]
+    async fn retry_failed_reads() -> object_store::Result<()> {
+        let store = retry_store(2, false, 3).await?;
+        let bytes = store.get_range(&Path::from("file"), 10..20).await?;
+        assert_eq!(bytes.as_ref(), (10..20).collect::<Vec<u8>>());
+        assert_eq!(store.metrics().retries(), 2);
+        assert_eq!(store.metrics().resumed_bytes(), 0);
+
+        let store = retry_store(2, false, 3).await?;
+        store.head(&Path::from("file")).await?;
+        assert_eq!(store.metrics().retries(), 2);
+
+        // retries are exhausted
+        let store = retry_store(4, false, 3).await?;
+        let err = store.get_range(&Path::from("file"), 10..20).await;
+        assert!(err.unwrap_err().to_string().contains("connection reset"));
+        assert_eq!(store.metrics().retries(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resume_partial_reads() -> object_store::Result<()> {
+        let store = retry_store(2, true, 3).await?;
+        let bytes = store.get_range(&Path::from("file"), 0..100).await?;
+        assert_eq!(bytes.as_ref(), (0..100).collect::<Vec<u8>>());
+        assert_eq!(store.metrics().retries(), 2);
+        // 50 bytes are kept after the first failure, then 25 more
+        assert_eq!(store.metrics().resumed_bytes(), 50 + 75);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reject_longer_responses() -> object_store::Result<()> {
+        let inner = InMemory::new();
+        let data: Vec<u8> = (0..100).collect();
+        inner.put(&Path::from("file"), data.into()).await?;
+        let flaky = FlakyObjectStore {
+            inner,
+            failures: AtomicUsize::new(0),
+            truncate: false,
+            ignore_range: true,
+        };
+        let store = RetryObjectStore::new(Arc::new(flaky), RetryConfig::default());
+
+        let err = store
+            .get_range(&Path::from("file"), 10..20)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected 10 bytes, received at least 100"));
+        // the error is not retried
+        assert_eq!(store.metrics().retries(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn transient_errors() {
+        let io_error = |kind| object_store::Error::NotFound {
+            path: "file".to_string(),
+            source: Box::new(std::io::Error::new(kind, "error")),
+        };
+        assert!(is_transient(&flaky_error()));
+        assert!(is_transient(&io_error(ErrorKind::ConnectionReset)));
+        assert!(is_transient(&io_error(ErrorKind::TimedOut)));
+        assert!(!is_transient(&io_error(ErrorKind::NotFound)));
+        assert!(!is_transient(&object_store::Error::NotImplemented));
+    }
+
+    #[tokio::test]
+    async fn retries_in_scan_metrics() -> crate::error::Result<()> {
+        let inner = InMemory::new();
+        inner
+            .put(
+                &Path::from("data.csv"),
+                Bytes::from_static(b"a\n1\n2\n").into(),
+            )
+            .await?;
+        let flaky = Arc::new(FlakyObjectStore {
+            inner,
+            failures: AtomicUsize::new(0),
+            truncate: false,
+            ignore_range: false,
+        });
+        let config =
+            RetryConfig::default().with_initial_backoff(Duration::from_millis(1));
+        let store = RetryObjectStore::new(Arc::clone(&flaky) as _, config);
+
+        let ctx = SessionContext::new();
+        ctx.register_object_store(&Url::parse("memory://").unwrap(), Arc::new(store));
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+        ctx.register_csv(
+            "t",
+            "memory:///data.csv",
+            CsvReadOptions::new().schema(&schema),
+        )
+        .await?;
+        let plan = ctx
+            .sql("SELECT a FROM t")
+            .await?
+            .create_physical_plan()
+            .await?;
+
+        // the reads of the scan fail twice
+        flaky.failures.store(2, Ordering::SeqCst);
+        let batches = collect(Arc::clone(&plan), ctx.task_ctx()).await?;
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 2);
+
+        let mut scan = plan;
+        loop {
+            let child = scan.children().first().map(|child| Arc::clone(child));
+            match child {
+                Some(child) => scan = child,
+                None => break,
+            }
+        }
+        let metrics = scan.metrics().unwrap();
+        let retries = metrics.sum_by_name("object_store_retries").unwrap();
+        assert_eq!(retries.as_usize(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let config = RetryConfig::default()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_secs(1));
+        assert_eq!(config.backoff(1), Duration::from_millis(100));
+        assert_eq!(config.backoff(2), Duration::from_millis(200));
+        assert_eq!(config.backoff(4), Duration::from_millis(800));
+        assert_eq!(config.backoff(5), Duration::from_secs(1));
+        assert_eq!(config.backoff(100), Duration::from_secs(1));
+    }
+}
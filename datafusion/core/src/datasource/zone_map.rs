@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ZoneMap`]s: the minimum and maximum values of the columns of in memory
+//! batches, used to skip the batches that can not match a predicate
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::physical_optimizer::pruning::PruningStatistics;
+use crate::physical_plan::expressions::{MaxAccumulator, MinAccumulator};
+
+use arrow::array::{ArrayRef, BooleanArray, UInt64Array};
+use arrow::datatypes::{DataType, Schema};
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{Column, ScalarValue};
+use datafusion_expr::Accumulator;
+
+/// The minimum and maximum values and the null count of a column of a batch
+#[derive(Debug, Clone)]
+struct ColumnZone {
+    min: ScalarValue,
+    max: ScalarValue,
+    null_count: usize,
+}
+
+/// The statistics of the columns of a [`RecordBatch`]
+///
+/// The minimum and maximum values are not computed for the columns that the
+/// min/max aggregates do not support, such as nested types, and for
+/// dictionary columns.
+#[derive(Debug, Clone)]
+pub(crate) struct ZoneMap {
+    num_rows: usize,
+    columns: Vec<Option<ColumnZone>>,
+}
+
+impl ZoneMap {
+    /// Computes the zone map of `batch`
+    pub(crate) fn new(batch: &RecordBatch) -> Self {
+        Self {
+            num_rows: batch.num_rows(),
+            columns: batch.columns().iter().map(column_zone).collect(),
+        }
+    }
+
+    /// Computes the zone maps of the `batches` that follow the batches
+    /// `zone_maps` were already computed for
+    pub(crate) fn extend(zone_maps: &mut Vec<ZoneMap>, batches: &[RecordBatch]) {
+        let new_zone_maps = batches.iter().skip(zone_maps.len()).map(Self::new);
+        zone_maps.extend(new_zone_maps);
+    }
+}
+
+/// Returns the zone of `array`, if the min/max aggregates support its type
+fn column_zone(array: &ArrayRef) -> Option<ColumnZone> {
+    let data_type = array.data_type();
+    if matches!(data_type, DataType::Dictionary(_, _)) {
+        return None;
+    }
+    let mut min = MinAccumulator::try_new(data_type).ok()?;
+    let mut max = MaxAccumulator::try_new(data_type).ok()?;
+    min.update_batch(&[Arc::clone(array)]).ok()?;
+    max.update_batch(&[Arc::clone(array)]).ok()?;
+    Some(ColumnZone {
+        min: min.evaluate().ok()?,
+        max: max.evaluate().ok()?,
+        null_count: array.null_count(),
+    })
+}
+
+/// [`PruningStatistics`] of a list of batches, each batch being a container
+pub(crate) struct ZoneMapStatistics<'a> {
+    schema: &'a Schema,
+    zone_maps: &'a [ZoneMap],
+}
+
+impl<'a> ZoneMapStatistics<'a> {
+    /// Creates the statistics of the batches of `zone_maps`, with the columns
+    /// of `schema`
+    pub(crate) fn new(schema: &'a Schema, zone_maps: &'a [ZoneMap]) -> Self {
+        Self { schema, zone_maps }
+    }
+
+    /// Returns the zone of `column` for every batch, if any batch has one
+    fn column_zones(
+        &self,
+        column: &Column,
+    ) -> Option<(&DataType, Vec<Option<&ColumnZone>>)> {
+        let index = self.schema.index_of(&column.name).ok()?;
+        let zones = self
+            .zone_maps
+            .iter()
+            .map(|zone_map| zone_map.columns.get(index).and_then(Option::as_ref))
+            .collect::<Vec<_>>();
+        let data_type = self.schema.field(index).data_type();
+        zones
+            .iter()
+            .any(Option::is_some)
+            .then_some((data_type, zones))
+    }
+
+    fn values(
+        &self,
+        column: &Column,
+        value: impl Fn(&ColumnZone) -> &ScalarValue,
+    ) -> Option<ArrayRef> {
+        let (data_type, zones) = self.column_zones(column)?;
+        let null = ScalarValue::try_from(data_type).ok()?;
+        let values = zones.into_iter().map(|zone| match zone {
+            Some(zone) => value(zone).clone(),
+            None => null.clone(),
+        });
+        ScalarValue::iter_to_array(values).ok()
+    }
+}
+
+impl PruningStatistics for ZoneMapStatistics<'_> {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.values(column, |zone| &zone.min)
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.values(column, |zone| &zone.max)
+    }
+
+    fn num_containers(&self) -> usize {
+        self.zone_maps.len()
+    }
+
+    fn null_counts(&self, column: &Column) -> Option<ArrayRef> {
+        let (_, zones) = self.column_zones(column)?;
+        let null_counts = zones
+            .into_iter()
+            .map(|zone| zone.map(|zone| zone.null_count as u64))
+            .collect::<UInt64Array>();
+        Some(Arc::new(null_counts))
+    }
+
+    fn row_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        let row_counts = self
+            .zone_maps
+            .iter()
+            .map(|zone_map| Some(zone_map.num_rows as u64))
+            .collect::<UInt64Array>();
+        Some(Arc::new(row_counts))
+    }
+
+    fn contained(
+        &self,
+        _column: &Column,
+        _values: &HashSet<ScalarValue>,
+    ) -> Option<BooleanArray> {
+        None
+    }
+}
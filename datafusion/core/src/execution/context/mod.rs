@@ -24,9 +24,9 @@ use std::sync::{Arc, Weak};
 use super::options::ReadOptions;
 use crate::{
     catalog::listing_schema::ListingSchemaProvider,
-    catalog::schema::MemorySchemaProvider,
+    catalog::schema::{MemorySchemaProvider, SchemaProvider},
     catalog::{CatalogProvider, CatalogProviderList, MemoryCatalogProvider},
-    dataframe::DataFrame,
+    dataframe::{DataFrame, ValidationCheck, ValidationReport, ValidationSeverity},
     datasource::{
         function::{TableFunction, TableFunctionImpl},
         listing::{ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl},
@@ -56,6 +56,7 @@ use datafusion_common::{
     tree_node::{TreeNodeRecursion, TreeNodeVisitor},
     DFSchema, SchemaReference, TableReference,
 };
+use datafusion_execution::cancellation::CancellationToken;
 use datafusion_execution::registry::SerializerRegistry;
 use datafusion_expr::{
     expr_rewriter::FunctionRewrite,
@@ -69,6 +70,7 @@ pub use crate::execution::session_state::SessionState;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use log::warn;
 use object_store::ObjectStore;
 use parking_lot::RwLock;
 use url::Url;
@@ -350,6 +352,13 @@ impl SessionContext {
         self.state.write().add_analyzer_rule(analyzer_rule);
     }
 
+    /// Adds a [`StatementInterceptor`] to the end of the existing
+    /// interceptors, to rewrite the SQL text run with [`Self::sql`] before
+    /// it is parsed.
+    pub fn add_statement_interceptor(&self, interceptor: Arc<dyn StatementInterceptor>) {
+        self.state.write().add_statement_interceptor(interceptor);
+    }
+
     /// Registers an [`ObjectStore`] to be used with a specific URL prefix.
     ///
     /// See [`RuntimeEnv::register_object_store`] for more details.
@@ -495,6 +504,42 @@ impl SessionContext {
         self.execute_logical_plan(plan).await
     }
 
+    /// Checks that the query in `sql` can be executed, without executing it.
+    ///
+    /// Unlike [`Self::sql`], statements such as `CREATE TABLE` are not
+    /// executed: only queries are validated. Errors while planning the query
+    /// are returned in the [`ValidationReport`]. See [`DataFrame::validate`]
+    /// for the checks that are performed.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let report = ctx.validate("SELECT * FROM missing").await?;
+    /// assert!(!report.is_valid());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate(&self, sql: &str) -> Result<ValidationReport> {
+        let state = self.state();
+        match state.create_logical_plan(sql).await {
+            Ok(plan) => DataFrame::new(state, plan).validate().await,
+            Err(e) => {
+                let mut report = ValidationReport::default();
+                report.push(
+                    ValidationCheck::Planning,
+                    ValidationSeverity::Error,
+                    None,
+                    e.strip_backtrace(),
+                );
+                Ok(report)
+            }
+        }
+    }
+
     /// Creates logical expresssions from SQL query text.
     ///
     /// # Example: Parsing SQL queries
@@ -561,10 +606,28 @@ impl SessionContext {
                 }
                 .await
             }
-            // TODO what about the other statements (like TransactionStart and TransactionEnd)
             LogicalPlan::Statement(Statement::SetVariable(stmt)) => {
                 self.set_variable(stmt).await
             }
+            // DataFusion does not support transactions, but can ignore the
+            // transaction control statements issued by tools
+            LogicalPlan::Statement(
+                stmt @ (Statement::TransactionStart(_)
+                | Statement::TransactionEnd(_)
+                | Statement::SetTransaction(_)),
+            ) if self
+                .state
+                .read()
+                .config_options()
+                .execution
+                .ignore_transaction_statements =>
+            {
+                warn!(
+                    "Ignoring unsupported transaction statement: {}",
+                    stmt.display()
+                );
+                self.return_empty_dataframe()
+            }
 
             plan => Ok(DataFrame::new(self.state(), plan)),
         }
@@ -641,48 +704,46 @@ impl SessionContext {
             column_defaults,
         } = cmd;
 
-        let input = Arc::try_unwrap(input).unwrap_or_else(|e| e.as_ref().clone());
-        let input = self.state().optimize(&input)?;
-        let table = self.table(name.clone()).await;
-        match (if_not_exists, or_replace, table) {
-            (true, false, Ok(_)) => self.return_empty_dataframe(),
-            (false, true, Ok(_)) => {
-                self.deregister_table(name.clone())?;
-                let schema = Arc::new(input.schema().as_ref().into());
-                let physical = DataFrame::new(self.state(), input);
-
-                let batches: Vec<_> = physical.collect_partitioned().await?;
-                let table = Arc::new(
-                    // pass constraints and column defaults to the mem table.
-                    MemTable::try_new(schema, batches)?
-                        .with_constraints(constraints)
-                        .with_column_defaults(column_defaults.into_iter().collect()),
-                );
-
-                self.register_table(name.clone(), table)?;
-                self.return_empty_dataframe()
+        let existing = self.table_provider(name.clone()).await.ok();
+        match (if_not_exists, or_replace, &existing) {
+            (true, true, _) => {
+                return exec_err!("'IF NOT EXISTS' cannot coexist with 'REPLACE'")
             }
-            (true, true, Ok(_)) => {
-                exec_err!("'IF NOT EXISTS' cannot coexist with 'REPLACE'")
+            (true, false, Some(_)) => return self.return_empty_dataframe(),
+            (false, false, Some(_)) => return exec_err!("Table '{name}' already exists"),
+            (false, true, Some(existing)) if existing.table_type() == TableType::View => {
+                return exec_err!("Cannot replace view '{name}' with a table")
             }
-            (_, _, Err(_)) => {
-                let df_schema = input.schema();
-                let schema = Arc::new(df_schema.as_ref().into());
-                let physical = DataFrame::new(self.state(), input);
-
-                let batches: Vec<_> = physical.collect_partitioned().await?;
-                let table = Arc::new(
-                    // pass constraints and column defaults to the mem table.
-                    MemTable::try_new(schema, batches)?
-                        .with_constraints(constraints)
-                        .with_column_defaults(column_defaults.into_iter().collect()),
-                );
+            _ => {}
+        }
 
-                self.register_table(name, table)?;
-                self.return_empty_dataframe()
-            }
-            (false, false, Ok(_)) => exec_err!("Table '{name}' already exists"),
+        let input = Arc::try_unwrap(input).unwrap_or_else(|e| e.as_ref().clone());
+        let input = self.state().optimize(&input)?;
+        let schema = Arc::new(input.schema().as_ref().into());
+        let physical = DataFrame::new(self.state(), input);
+
+        // the existing table is only replaced once the query has succeeded
+        let batches: Vec<_> = physical.collect_partitioned().await?;
+        // pass constraints and column defaults to the mem table.
+        let mut table = MemTable::try_new(schema, batches)?
+            .with_constraints(constraints)
+            .with_column_defaults(column_defaults.into_iter().collect());
+        let zone_maps = self
+            .state
+            .read()
+            .config_options()
+            .execution
+            .memory_table_zone_maps;
+        if zone_maps {
+            table = table.with_zone_maps();
         }
+        let table = Arc::new(table);
+
+        if existing.is_some() {
+            self.deregister_table(name.clone())?;
+        }
+        self.register_table(name, table)?;
+        self.return_empty_dataframe()
     }
 
     async fn create_view(&self, cmd: CreateView) -> Result<DataFrame> {
@@ -690,27 +751,43 @@ impl SessionContext {
             name,
             input,
             or_replace,
+            if_not_exists,
             definition,
+            temporary,
         } = cmd;
 
-        let view = self.table(name.clone()).await;
-
-        match (or_replace, view) {
-            (true, Ok(_)) => {
-                self.deregister_table(name.clone())?;
-                let table = Arc::new(ViewTable::try_new((*input).clone(), definition)?);
-
-                self.register_table(name, table)?;
-                self.return_empty_dataframe()
+        // temporary views live in a schema of their own and may shadow
+        // tables of the default schema
+        let schema = if temporary {
+            if !matches!(name, TableReference::Bare { .. }) {
+                return plan_err!(
+                    "Temporary view '{name}' cannot be created in a specific schema"
+                );
             }
-            (_, Err(_)) => {
-                let table = Arc::new(ViewTable::try_new((*input).clone(), definition)?);
+            Arc::clone(self.state.read().temporary_schema())
+        } else {
+            self.state.read().schema_for_ref(name.clone())?
+        };
 
-                self.register_table(name, table)?;
-                self.return_empty_dataframe()
+        let existing = schema.table(name.table()).await?;
+        match (if_not_exists, or_replace, &existing) {
+            (true, true, _) => {
+                return exec_err!("'IF NOT EXISTS' cannot coexist with 'REPLACE'")
+            }
+            (true, false, Some(_)) => return self.return_empty_dataframe(),
+            (false, false, Some(_)) => return exec_err!("Table '{name}' already exists"),
+            (false, true, Some(existing)) if existing.table_type() != TableType::View => {
+                return exec_err!("Cannot replace table '{name}' with a view")
             }
-            (false, Ok(_)) => exec_err!("Table '{name}' already exists"),
+            _ => {}
+        }
+
+        let table = Arc::new(ViewTable::try_new((*input).clone(), definition)?);
+        if existing.is_some() {
+            schema.deregister_table(name.table())?;
         }
+        schema.register_table(name.table().to_string(), table)?;
+        self.return_empty_dataframe()
     }
 
     async fn create_catalog_schema(&self, cmd: CreateCatalogSchema) -> Result<DataFrame> {
@@ -880,16 +957,19 @@ impl SessionContext {
     ) -> Result<bool> {
         let table_ref = table_ref.into();
         let table = table_ref.table().to_owned();
-        let maybe_schema = {
+        let (temporary_schema, maybe_schema) = {
             let state = self.state.read();
+            let temporary_schema = state.temporary_schema_for_ref(&table_ref);
             let resolved = state.resolve_table_ref(table_ref);
-            state
+            let maybe_schema = state
                 .catalog_list()
                 .catalog(&resolved.catalog)
-                .and_then(|c| c.schema(&resolved.schema))
+                .and_then(|c| c.schema(&resolved.schema));
+            (temporary_schema, maybe_schema)
         };
 
-        if let Some(schema) = maybe_schema {
+        // a temporary view shadows the table of the same name, if any
+        for schema in temporary_schema.into_iter().chain(maybe_schema) {
             if let Some(table_provider) = schema.table(&table).await? {
                 if table_provider.table_type() == table_type {
                     schema.deregister_table(&table)?;
@@ -1234,6 +1314,9 @@ impl SessionContext {
 
     /// Deregisters the given table.
     ///
+    /// Unqualified names refer to the temporary view of that name first, if
+    /// any.
+    ///
     /// Returns the registered provider, if any
     pub fn deregister_table(
         &self,
@@ -1241,24 +1324,34 @@ impl SessionContext {
     ) -> Result<Option<Arc<dyn TableProvider>>> {
         let table_ref = table_ref.into();
         let table = table_ref.table().to_owned();
-        self.state
-            .read()
-            .schema_for_ref(table_ref)?
+        self.schema_or_temporary_schema_for_ref(table_ref)?
             .deregister_table(&table)
     }
 
-    /// Return `true` if the specified table exists in the schema provider.
+    /// Return `true` if the specified table exists in the schema provider, or
+    /// is a temporary view of this session.
     pub fn table_exist(&self, table_ref: impl Into<TableReference>) -> Result<bool> {
         let table_ref: TableReference = table_ref.into();
         let table = table_ref.table();
         let table_ref = table_ref.clone();
         Ok(self
-            .state
-            .read()
-            .schema_for_ref(table_ref)?
+            .schema_or_temporary_schema_for_ref(table_ref)?
             .table_exist(table))
     }
 
+    /// Returns the schema holding the temporary view `table_ref` refers to,
+    /// or the schema of the catalogs it refers to otherwise
+    fn schema_or_temporary_schema_for_ref(
+        &self,
+        table_ref: TableReference,
+    ) -> Result<Arc<dyn SchemaProvider>> {
+        let state = self.state.read();
+        match state.temporary_schema_for_ref(&table_ref) {
+            Some(schema) => Ok(schema),
+            None => state.schema_for_ref(table_ref),
+        }
+    }
+
     /// Retrieves a [`DataFrame`] representing a table previously
     /// registered by calling the [`register_table`] function.
     ///
@@ -1302,7 +1395,7 @@ impl SessionContext {
     ) -> Result<Arc<dyn TableProvider>> {
         let table_ref = table_ref.into();
         let table = table_ref.table().to_string();
-        let schema = self.state.read().schema_for_ref(table_ref)?;
+        let schema = self.schema_or_temporary_schema_for_ref(table_ref)?;
         match schema.table(&table).await? {
             Some(ref provider) => Ok(Arc::clone(provider)),
             _ => plan_err!("No table named '{table}'"),
@@ -1314,6 +1407,20 @@ impl SessionContext {
         Arc::new(TaskContext::from(self))
     }
 
+    /// Cancel all the queries of this session that were created before this
+    /// call, including the ones that are currently running.
+    ///
+    /// A cancelled query stops at the next point where it checks for
+    /// cancellation and returns an error. [`DataFrame`]s created after this
+    /// call are not affected. To cancel a single query, see
+    /// [`DataFrame::with_cancellation_token`].
+    pub fn cancel_queries(&self) {
+        let mut state = self.state.write();
+        let previous = state.cancellation_token().clone();
+        state.set_cancellation_token(CancellationToken::new());
+        previous.cancel();
+    }
+
     /// Return a new  [`SessionState`] suitable for executing a single query.
     ///
     /// Notes:
@@ -1328,6 +1435,9 @@ impl SessionContext {
     /// [`ConfigOptions`]: crate::config::ConfigOptions
     pub fn state(&self) -> SessionState {
         let mut state = self.state.read().clone();
+        // the configuration may have changed since the session was created
+        state.execution_props_mut().arithmetic_overflow_mode =
+            state.config_options().execution.arithmetic_overflow_mode;
         state.execution_props_mut().start_execution();
         state
     }
@@ -1433,6 +1543,57 @@ pub trait FunctionFactory: Sync + Send {
     ) -> Result<RegisterFunction>;
 }
 
+/// A pluggable hook to rewrite the text of SQL statements before they are
+/// parsed by [`SessionState::create_logical_plan`], and hence by
+/// [`SessionContext::sql`].
+///
+/// Interceptors registered with [`SessionContext::add_statement_interceptor`]
+/// run in registration order, each one receiving the SQL returned by the
+/// previous one. They can for example expand macros, strip hints or add a
+/// `LIMIT` depending on the session configuration.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion::prelude::*;
+/// # use datafusion::error::Result;
+/// # use datafusion::execution::context::StatementInterceptor;
+/// /// Replaces the `$today` macro by the current date
+/// struct TodayMacro;
+///
+/// impl StatementInterceptor for TodayMacro {
+///     fn name(&self) -> &str {
+///         "today_macro"
+///     }
+///
+///     fn intercept(
+///         &self,
+///         sql: &str,
+///         _config: &SessionConfig,
+///     ) -> Result<Option<String>> {
+///         Ok(sql
+///             .contains("$today")
+///             .then(|| sql.replace("$today", "current_date()")))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let ctx = SessionContext::new();
+/// ctx.add_statement_interceptor(Arc::new(TodayMacro));
+/// let df = ctx.sql("SELECT $today").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait StatementInterceptor: Send + Sync {
+    /// Returns the name of this interceptor, used in error messages
+    fn name(&self) -> &str;
+
+    /// Returns the SQL text to parse instead of `sql`, or `None` to leave it
+    /// unchanged
+    fn intercept(&self, sql: &str, config: &SessionConfig) -> Result<Option<String>>;
+}
+
 /// Type of function to create
 pub enum RegisterFunction {
     /// Scalar user defined function
@@ -1566,9 +1727,12 @@ mod tests {
     use crate::assert_batches_eq;
     use crate::execution::memory_pool::MemoryConsumer;
     use crate::execution::runtime_env::RuntimeConfig;
+    use crate::physical_plan::metrics::MetricsRegistry;
     use crate::test;
     use crate::test_util::{plan_and_collect, populate_csv_partitions};
 
+    use datafusion_common::assert_contains;
+    use datafusion_common::cast::as_int64_array;
     use datafusion_common_runtime::SpawnedTask;
 
     use crate::catalog::schema::SchemaProvider;
@@ -1611,6 +1775,115 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn task_id_in_spill_file_names() -> Result<()> {
+        let ctx = SessionContext::new();
+        let task_ctx1 = ctx.task_ctx();
+        let task_ctx2 = ctx.task_ctx();
+
+        // every task context gets a unique id
+        let task_id = task_ctx1.task_id().unwrap();
+        assert_ne!(Some(&task_id), task_ctx2.task_id().as_ref());
+
+        // which is the prefix of its spill files
+        let spill_file = task_ctx1
+            .runtime_env()
+            .disk_manager
+            .create_tmp_file("Testing")?;
+        let file_name = spill_file.path().file_name().unwrap().to_string_lossy();
+        assert!(
+            file_name.starts_with(&format!("datafusion-{task_id}-")),
+            "{file_name}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dataframe_with_task_id() -> Result<()> {
+        let ctx = SessionContext::new();
+        let df = ctx.sql("SELECT 1").await?.with_task_id("query-1");
+
+        // the id is kept by the DataFrames derived from this one
+        let df = df.limit(0, Some(1))?;
+        assert_eq!(df.task_ctx().task_id().as_deref(), Some("query-1"));
+        assert_eq!(df.clone().collect().await?.len(), 1);
+
+        // other queries still get a random id
+        let task_id = ctx.task_ctx().task_id().unwrap();
+        assert_ne!(task_id, "query-1");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn statement_interceptors() -> Result<()> {
+        /// Removes the hints of the statement
+        struct StripHints;
+
+        impl StatementInterceptor for StripHints {
+            fn name(&self) -> &str {
+                "strip_hints"
+            }
+
+            fn intercept(&self, sql: &str, _: &SessionConfig) -> Result<Option<String>> {
+                let Some((head, rest)) = sql.split_once("/*+") else {
+                    return Ok(None);
+                };
+                let Some((_, tail)) = rest.split_once("*/") else {
+                    return plan_err!("Unterminated hint");
+                };
+                Ok(Some(format!("{head}{tail}")))
+            }
+        }
+
+        /// Limits the rows of the statement to the target partitions
+        struct DefaultLimit;
+
+        impl StatementInterceptor for DefaultLimit {
+            fn name(&self) -> &str {
+                "default_limit"
+            }
+
+            fn intercept(
+                &self,
+                sql: &str,
+                config: &SessionConfig,
+            ) -> Result<Option<String>> {
+                let limit = config.target_partitions();
+                Ok(Some(format!("SELECT * FROM ({sql}) LIMIT {limit}")))
+            }
+        }
+
+        let ctx = SessionContext::new_with_config(
+            SessionConfig::new().with_target_partitions(2),
+        );
+        ctx.add_statement_interceptor(Arc::new(StripHints));
+        ctx.add_statement_interceptor(Arc::new(DefaultLimit));
+        assert_eq!(
+            ctx.state().intercept_sql("SELECT /*+ hint */ 1")?,
+            "SELECT * FROM (SELECT  1) LIMIT 2"
+        );
+
+        let results = ctx
+            .sql("SELECT /*+ BROADCAST(t) */ * FROM (VALUES (1), (2), (3)) t(a)")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(
+            ["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"],
+            &results
+        );
+
+        let err = ctx.sql("SELECT /*+ hint 1").await.unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Statement interceptor 'strip_hints'\ncaused by\n\
+             Error during planning: Unterminated hint"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn create_variable_expr() -> Result<()> {
         let tmp_dir = TempDir::new()?;
@@ -1741,6 +2014,31 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn metrics_registry() -> Result<()> {
+        let registry = Arc::new(MetricsRegistry::new(2));
+        let session_state = SessionState::new_with_config_rt(
+            SessionConfig::new(),
+            Arc::new(RuntimeEnv::default()),
+        )
+        .with_metrics_registry(Arc::clone(&registry));
+        let ctx = SessionContext::new_with_state(session_state);
+
+        for _ in 0..3 {
+            ctx.sql("SELECT * FROM (VALUES (1), (2)) WHERE column1 > 1")
+                .await?
+                .collect()
+                .await?;
+        }
+        let queries = registry.recent_queries();
+        assert_eq!(queries.len(), 2);
+        assert_eq!(queries[1].query_id(), 2);
+        assert!(registry
+            .render_prometheus()
+            .contains("datafusion_output_rows_total{operator=\"FilterExec\"} 3"));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn disabled_default_catalog_and_schema() -> Result<()> {
         let ctx = SessionContext::new_with_config(
@@ -1860,6 +2158,60 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn temporary_views_are_local_to_the_session() -> Result<()> {
+        let ctx1 = SessionContext::new();
+        ctx1.register_table("t", test::table_with_sequence(1, 3)?)?;
+
+        // a second session sharing the catalogs of the first one
+        let state = SessionState::new_with_config_rt_and_catalog_list(
+            SessionConfig::new().with_create_default_catalog_and_schema(false),
+            ctx1.runtime_env(),
+            Arc::clone(ctx1.state().catalog_list()),
+        );
+        let ctx2 = SessionContext::new_with_state(state);
+
+        ctx1.sql("CREATE TEMPORARY VIEW v AS SELECT i FROM t WHERE i = 1")
+            .await?;
+        ctx2.sql("CREATE TEMPORARY VIEW v AS SELECT i FROM t WHERE i > 1")
+            .await?;
+        // temporary views shadow tables of the default schema
+        ctx2.sql("CREATE TEMPORARY VIEW t AS SELECT 10 AS i")
+            .await?;
+
+        async fn sum(ctx: &SessionContext, table: &str) -> Result<i64> {
+            let sql = format!("SELECT SUM(i) FROM {table}");
+            let batches = ctx.sql(&sql).await?.collect().await?;
+            Ok(as_int64_array(batches[0].column(0))?.value(0))
+        }
+        assert_eq!(sum(&ctx1, "v").await?, 1);
+        assert_eq!(sum(&ctx2, "v").await?, 5);
+        assert_eq!(sum(&ctx1, "t").await?, 6);
+        assert_eq!(sum(&ctx2, "t").await?, 10);
+        assert_eq!(sum(&ctx2, "datafusion.public.t").await?, 6);
+        assert!(ctx2.table_exist("v")?);
+        assert!(!ctx2.table_exist("datafusion.public.v")?);
+
+        // qualified temporary views are rejected
+        let err = ctx1
+            .sql("CREATE TEMPORARY VIEW public.w AS SELECT 1")
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "cannot be created in a specific schema");
+
+        // dropping the temporary view reveals the table again
+        ctx2.sql("DROP VIEW t").await?;
+        assert_eq!(sum(&ctx2, "t").await?, 6);
+
+        // the temporary views of a session disappear with it
+        let temporary_schema = Arc::downgrade(ctx1.state().temporary_schema());
+        drop(ctx1);
+        assert_eq!(Weak::strong_count(&temporary_schema), 0);
+        assert_eq!(sum(&ctx2, "v").await?, 5);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn catalogs_not_leaked() {
         // the information schema used to introduce cyclic Arcs
@@ -29,13 +29,18 @@ use crate::datasource::file_format::arrow::ArrowFormatFactory;
 use crate::datasource::file_format::avro::AvroFormatFactory;
 use crate::datasource::file_format::csv::CsvFormatFactory;
 use crate::datasource::file_format::json::JsonFormatFactory;
+#[cfg(feature = "orc")]
+use crate::datasource::file_format::orc::OrcFormatFactory;
 #[cfg(feature = "parquet")]
 use crate::datasource::file_format::parquet::ParquetFormatFactory;
 use crate::datasource::file_format::{format_as_file_type, FileFormatFactory};
 use crate::datasource::function::{TableFunction, TableFunctionImpl};
+use crate::datasource::generate_series::GenerateSeriesFunc;
 use crate::datasource::provider::{DefaultTableFactory, TableProviderFactory};
 use crate::datasource::provider_as_source;
-use crate::execution::context::{EmptySerializerRegistry, FunctionFactory, QueryPlanner};
+use crate::execution::context::{
+    EmptySerializerRegistry, FunctionFactory, QueryPlanner, StatementInterceptor,
+};
 #[cfg(feature = "array_expressions")]
 use crate::functions_array;
 use crate::physical_optimizer::optimizer::PhysicalOptimizer;
@@ -54,7 +59,9 @@ use datafusion_common::{
     config_err, not_impl_err, plan_datafusion_err, DFSchema, DataFusionError,
     ResolvedTableReference, TableReference,
 };
+use datafusion_execution::cancellation::CancellationToken;
 use datafusion_execution::config::SessionConfig;
+use datafusion_execution::memory_pool::QueryMemoryPool;
 use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_execution::runtime_env::RuntimeEnv;
 use datafusion_execution::TaskContext;
@@ -74,11 +81,14 @@ use datafusion_optimizer::{
 };
 use datafusion_physical_expr::create_physical_expr;
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use datafusion_physical_plan::metrics::MetricsRegistry;
 use datafusion_physical_plan::ExecutionPlan;
 use datafusion_sql::parser::{DFParser, Statement};
 use datafusion_sql::planner::{ContextProvider, ParserOptions, PlannerContext, SqlToRel};
+use log::debug;
 use sqlparser::ast::Expr as SQLExpr;
 use sqlparser::dialect::dialect_from_str;
+use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
@@ -102,6 +112,8 @@ pub struct SessionState {
     analyzer: Analyzer,
     /// Provides support for customising the SQL planner, e.g. to add support for custom operators like `->>` or `?`
     user_defined_sql_planners: Vec<Arc<dyn UserDefinedSQLPlanner>>,
+    /// Rewrite the SQL text of statements before they are parsed
+    statement_interceptors: Vec<Arc<dyn StatementInterceptor>>,
     /// Responsible for optimizing a logical plan
     optimizer: Optimizer,
     /// Responsible for optimizing a physical execution plan
@@ -110,6 +122,12 @@ pub struct SessionState {
     query_planner: Arc<dyn QueryPlanner + Send + Sync>,
     /// Collection of catalogs containing schemas and ultimately TableProviders
     catalog_list: Arc<dyn CatalogProviderList>,
+    /// Schema holding the temporary views of this session.
+    ///
+    /// It is not part of any catalog, so that sessions sharing the same
+    /// catalogs do not see each other's temporary views, and is dropped with
+    /// the session.
+    temporary_schema: Arc<dyn SchemaProvider>,
     /// Table Functions
     table_functions: HashMap<String, Arc<TableFunction>>,
     /// Scalar functions that are registered with the context
@@ -146,6 +164,17 @@ pub struct SessionState {
     /// It will be invoked on `CREATE FUNCTION` statements.
     /// thus, changing dialect o PostgreSql is required
     function_factory: Option<Arc<dyn FunctionFactory>>,
+
+    /// Token used to cancel the queries executed with this state
+    cancellation_token: CancellationToken,
+
+    /// The `task_id` of the task contexts created from this state. If `None`,
+    /// each task context gets a random id
+    task_id: Option<String>,
+
+    /// Registry retaining the physical plans created with this state to
+    /// inspect and export their metrics
+    metrics_registry: Option<Arc<MetricsRegistry>>,
 }
 
 impl Debug for SessionState {
@@ -153,10 +182,15 @@ impl Debug for SessionState {
         f.debug_struct("SessionState")
             .field("session_id", &self.session_id)
             .field("analyzer", &"...")
+            .field(
+                "statement_interceptors",
+                &self.statement_interceptor_names(),
+            )
             .field("optimizer", &"...")
             .field("physical_optimizers", &"...")
             .field("query_planner", &"...")
             .field("catalog_list", &"...")
+            .field("temporary_schema", &self.temporary_schema.table_names())
             .field("table_functions", &"...")
             .field("scalar_functions", &self.scalar_functions)
             .field("aggregate_functions", &self.aggregate_functions)
@@ -168,6 +202,9 @@ impl Debug for SessionState {
             .field("table_factories", &"...")
             .field("runtime_env", &self.runtime_env)
             .field("function_factory", &"...")
+            .field("cancellation_token", &self.cancellation_token)
+            .field("task_id", &self.task_id)
+            .field("metrics_registry", &self.metrics_registry)
             .finish_non_exhaustive()
     }
 }
@@ -231,14 +268,19 @@ impl SessionState {
             );
         }
 
+        let execution_props = ExecutionProps::new().with_arithmetic_overflow_mode(
+            config.options().execution.arithmetic_overflow_mode,
+        );
         let mut new_self = SessionState {
             session_id,
             analyzer: Analyzer::new(),
             user_defined_sql_planners: vec![],
+            statement_interceptors: vec![],
             optimizer: Optimizer::new(),
             physical_optimizers: PhysicalOptimizer::new(),
             query_planner: Arc::new(DefaultQueryPlanner {}),
             catalog_list,
+            temporary_schema: Arc::new(MemorySchemaProvider::new()),
             table_functions: HashMap::new(),
             scalar_functions: HashMap::new(),
             aggregate_functions: HashMap::new(),
@@ -247,10 +289,13 @@ impl SessionState {
             file_formats: HashMap::new(),
             table_options: TableOptions::default_from_session_config(config.options()),
             config,
-            execution_props: ExecutionProps::new(),
+            execution_props,
             runtime_env: runtime,
             table_factories,
             function_factory: None,
+            cancellation_token: CancellationToken::new(),
+            task_id: None,
+            metrics_registry: None,
         };
 
         #[cfg(feature = "parquet")]
@@ -284,6 +329,13 @@ impl SessionState {
             log::info!("Unable to register default AvroFormat: {e}")
         };
 
+        #[cfg(feature = "orc")]
+        if let Err(e) =
+            new_self.register_file_format(Arc::new(OrcFormatFactory::new()), false)
+        {
+            log::info!("Unable to register default OrcFormat: {e}")
+        };
+
         // register built in functions
         functions::register_all(&mut new_self)
             .expect("can not register built in functions");
@@ -296,6 +348,12 @@ impl SessionState {
         functions_aggregate::register_all(&mut new_self)
             .expect("can not register aggregate functions");
 
+        new_self.register_udtf(
+            "generate_series",
+            Arc::new(GenerateSeriesFunc::generate_series()),
+        );
+        new_self.register_udtf("range", Arc::new(GenerateSeriesFunc::range()));
+
         new_self
     }
     /// Returns new [`SessionState`] using the provided
@@ -386,6 +444,24 @@ impl SessionState {
             })
     }
 
+    /// Returns the schema of the temporary view `table_ref` refers to, if any.
+    ///
+    /// Only unqualified references can refer to temporary views, which then
+    /// shadow tables of the same name in the default schema.
+    pub(crate) fn temporary_schema_for_ref(
+        &self,
+        table_ref: &TableReference,
+    ) -> Option<Arc<dyn SchemaProvider>> {
+        match table_ref {
+            TableReference::Bare { table }
+                if self.temporary_schema.table_exist(table) =>
+            {
+                Some(Arc::clone(&self.temporary_schema))
+            }
+            _ => None,
+        }
+    }
+
     /// Replace the random session id.
     pub fn with_session_id(mut self, session_id: String) -> Self {
         self.session_id = session_id;
@@ -477,6 +553,26 @@ impl SessionState {
         self
     }
 
+    /// Replace the [`StatementInterceptor`]s that rewrite the SQL text of
+    /// statements before they are parsed
+    pub fn with_statement_interceptors(
+        mut self,
+        interceptors: Vec<Arc<dyn StatementInterceptor>>,
+    ) -> Self {
+        self.statement_interceptors = interceptors;
+        self
+    }
+
+    /// Add `interceptor` to the end of the [`StatementInterceptor`]s that
+    /// rewrite the SQL text of statements before they are parsed
+    pub fn add_statement_interceptor(
+        &mut self,
+        interceptor: Arc<dyn StatementInterceptor>,
+    ) -> &Self {
+        self.statement_interceptors.push(interceptor);
+        self
+    }
+
     /// Registers a [`FunctionFactory`] to handle `CREATE FUNCTION` statements
     pub fn with_function_factory(
         mut self,
@@ -491,6 +587,45 @@ impl SessionState {
         self.function_factory = Some(function_factory);
     }
 
+    /// Set the [`CancellationToken`] used to cancel the queries executed
+    /// with this state
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = token;
+        self
+    }
+
+    /// Set the [`CancellationToken`] used to cancel the queries executed
+    /// with this state
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation_token = token;
+    }
+
+    /// Set the `task_id` of the [`TaskContext`]s created from this state,
+    /// instead of a random id for each of them
+    pub fn with_task_id(mut self, task_id: impl Into<String>) -> Self {
+        self.task_id = Some(task_id.into());
+        self
+    }
+
+    /// Set the `task_id` of the [`TaskContext`]s created from this state,
+    /// instead of a random id for each of them
+    pub fn set_task_id(&mut self, task_id: impl Into<String>) {
+        self.task_id = Some(task_id.into());
+    }
+
+    /// Set the [`MetricsRegistry`] that retains the physical plans created
+    /// with this state, to inspect and export their metrics
+    pub fn with_metrics_registry(mut self, registry: Arc<MetricsRegistry>) -> Self {
+        self.metrics_registry = Some(registry);
+        self
+    }
+
+    /// Set the [`MetricsRegistry`] that retains the physical plans created
+    /// with this state, to inspect and export their metrics
+    pub fn set_metrics_registry(&mut self, registry: Arc<MetricsRegistry>) {
+        self.metrics_registry = Some(registry);
+    }
+
     /// Replace the extension [`SerializerRegistry`]
     pub fn with_serializer_registry(
         mut self,
@@ -500,6 +635,19 @@ impl SessionState {
         self
     }
 
+    /// Return the [`StatementInterceptor`]s applied to SQL text before it is
+    /// parsed, in the order they run
+    pub fn statement_interceptors(&self) -> &[Arc<dyn StatementInterceptor>] {
+        &self.statement_interceptors
+    }
+
+    fn statement_interceptor_names(&self) -> Vec<&str> {
+        self.statement_interceptors
+            .iter()
+            .map(|interceptor| interceptor.name())
+            .collect()
+    }
+
     /// Get the function factory
     pub fn function_factory(&self) -> Option<&Arc<dyn FunctionFactory>> {
         self.function_factory.as_ref()
@@ -599,9 +747,14 @@ impl SessionState {
         };
 
         for reference in references {
+            let temporary_schema = self.temporary_schema_for_ref(&reference);
             let resolved = &self.resolve_table_ref(reference);
             if let Entry::Vacant(v) = provider.tables.entry(resolved.to_string()) {
-                if let Ok(schema) = self.schema_for_ref(resolved.clone()) {
+                let schema = match temporary_schema {
+                    Some(schema) => Ok(schema),
+                    None => self.schema_for_ref(resolved.clone()),
+                };
+                if let Ok(schema) = schema {
                     if let Some(table) = schema.table(&resolved.table).await? {
                         v.insert(provider_as_source(table));
                     }
@@ -623,11 +776,32 @@ impl SessionState {
         }
     }
 
+    /// Applies the registered [`StatementInterceptor`]s to `sql`, in order,
+    /// returning the SQL text to parse
+    pub fn intercept_sql<'a>(
+        &self,
+        sql: &'a str,
+    ) -> datafusion_common::Result<Cow<'a, str>> {
+        let mut sql = Cow::Borrowed(sql);
+        for interceptor in &self.statement_interceptors {
+            let rewritten = interceptor.intercept(&sql, &self.config).map_err(|e| {
+                e.context(format!("Statement interceptor '{}'", interceptor.name()))
+            })?;
+            if let Some(rewritten) = rewritten {
+                sql = Cow::Owned(rewritten);
+            }
+        }
+        Ok(sql)
+    }
+
     /// Creates a [`LogicalPlan`] from the provided SQL string. This
     /// interface will plan any SQL DataFusion supports, including DML
     /// like `CREATE TABLE`, and `COPY` (which can write to local
     /// files.
     ///
+    /// The registered [`StatementInterceptor`]s are applied to `sql`
+    /// before it is parsed, see [`Self::intercept_sql`].
+    ///
     /// See [`SessionContext::sql`] and
     /// [`SessionContext::sql_with_options`] for a higher-level
     /// interface that handles DDL and verification of allowed
@@ -639,8 +813,9 @@ impl SessionState {
         &self,
         sql: &str,
     ) -> datafusion_common::Result<LogicalPlan> {
+        let sql = self.intercept_sql(sql)?;
         let dialect = self.config.options().sql_parser.dialect.as_str();
-        let statement = self.sql_to_statement(sql, dialect)?;
+        let statement = self.sql_to_statement(&sql, dialect)?;
         let plan = self.statement_to_plan(statement).await?;
         Ok(plan)
     }
@@ -690,6 +865,7 @@ impl SessionState {
 
                     return Ok(LogicalPlan::Explain(Explain {
                         verbose: e.verbose,
+                        estimates: e.estimates,
                         plan: e.plan.clone(),
                         stringified_plans,
                         schema: e.schema.clone(),
@@ -726,6 +902,7 @@ impl SessionState {
 
             Ok(LogicalPlan::Explain(Explain {
                 verbose: e.verbose,
+                estimates: e.estimates,
                 plan,
                 stringified_plans,
                 schema: e.schema.clone(),
@@ -756,9 +933,14 @@ impl SessionState {
         logical_plan: &LogicalPlan,
     ) -> datafusion_common::Result<Arc<dyn ExecutionPlan>> {
         let logical_plan = self.optimize(logical_plan)?;
-        self.query_planner
+        let plan = self
+            .query_planner
             .create_physical_plan(&logical_plan, self)
-            .await
+            .await?;
+        if let Some(registry) = &self.metrics_registry {
+            registry.register(Arc::clone(&plan));
+        }
+        Ok(plan)
     }
 
     /// Create a [`PhysicalExpr`] from an [`Expr`] after applying type
@@ -805,6 +987,22 @@ impl SessionState {
         &self.runtime_env
     }
 
+    /// Return the [`CancellationToken`] of the queries executed with this state
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
+    }
+
+    /// Return the `task_id` of the [`TaskContext`]s created from this state,
+    /// if it was set with [`Self::with_task_id`]
+    pub fn task_id(&self) -> Option<&str> {
+        self.task_id.as_deref()
+    }
+
+    /// Return the [`MetricsRegistry`] of this state, if any
+    pub fn metrics_registry(&self) -> Option<&Arc<MetricsRegistry>> {
+        self.metrics_registry.as_ref()
+    }
+
     /// Return the execution properties
     pub fn execution_props(&self) -> &ExecutionProps {
         &self.execution_props
@@ -892,6 +1090,11 @@ impl SessionState {
         &self.catalog_list
     }
 
+    /// Return the schema holding the temporary views of this session
+    pub fn temporary_schema(&self) -> &Arc<dyn SchemaProvider> {
+        &self.temporary_schema
+    }
+
     /// set the catalog list
     pub(crate) fn register_catalog_list(
         &mut self,
@@ -1006,6 +1209,16 @@ impl<'a> ContextProvider for SessionContextProvider<'a> {
             .get(name)
             .cloned()
             .ok_or_else(|| plan_datafusion_err!("table function '{name}' not found"))?;
+
+        // evaluate the constant arguments, so that functions only need to
+        // handle literals
+        let schema = DFSchema::empty();
+        let simplifier =
+            ExprSimplifier::new(SessionSimplifyProvider::new(self.state, &schema));
+        let args = args
+            .into_iter()
+            .map(|arg| simplifier.simplify(simplifier.coerce(arg, &schema)?))
+            .collect::<datafusion_common::Result<Vec<_>>>()?;
         let provider = tbl_func.create_table_provider(&args)?;
 
         Ok(provider_as_source(provider))
@@ -1217,16 +1430,48 @@ impl OptimizerConfig for SessionState {
 /// Create a new task context instance from SessionState
 impl From<&SessionState> for TaskContext {
     fn from(state: &SessionState) -> Self {
-        let task_id = None;
+        // each task gets a unique id, unless one was set on the state, which
+        // is used as the prefix of its spill files so they can be traced back
+        // to the query
+        let task_id = state
+            .task_id
+            .clone()
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        let execution = &state.config.options().execution;
+        let mut runtime = state.runtime_env.as_ref().clone();
+        runtime.disk_manager = runtime
+            .disk_manager
+            .with_tmp_file_prefix(format!("datafusion-{task_id}-"));
+        // each task also gets its own pool and disk space limit when the memory
+        // or disk space of queries is limited
+        if let Some(limit) = execution.query_memory_limit {
+            runtime.memory_pool = Arc::new(QueryMemoryPool::new(
+                Arc::clone(&runtime.memory_pool),
+                limit,
+                execution.query_memory_policy,
+            ));
+        }
+        if let Some(limit) = execution.query_spill_limit {
+            runtime.disk_manager =
+                runtime.disk_manager.with_max_temp_directory_size(limit);
+        }
+        if execution.spill_checksums {
+            runtime.disk_manager = runtime.disk_manager.with_checksums();
+        }
+        debug!(
+            "Created task context {task_id} for session {}",
+            state.session_id
+        );
         TaskContext::new(
-            task_id,
+            Some(task_id),
             state.session_id.clone(),
             state.config.clone(),
             state.scalar_functions.clone(),
             state.aggregate_functions.clone(),
             state.window_functions.clone(),
-            state.runtime_env.clone(),
+            Arc::new(runtime),
         )
+        .with_cancellation_token(state.cancellation_token.clone())
     }
 }
 
@@ -51,7 +51,8 @@ use datafusion_expr::logical_plan::JoinType;
 use datafusion_physical_expr::expressions::{Column, NoOp};
 use datafusion_physical_expr::utils::map_columns_before_projection;
 use datafusion_physical_expr::{
-    physical_exprs_equal, EquivalenceProperties, PhysicalExpr, PhysicalExprRef,
+    physical_exprs_contains, physical_exprs_equal, EquivalenceProperties, PhysicalExpr,
+    PhysicalExprRef,
 };
 use datafusion_physical_plan::windows::{get_best_fitting_window, BoundedWindowAggExec};
 use datafusion_physical_plan::ExecutionPlanProperties;
@@ -864,6 +865,10 @@ fn add_roundrobin_on_top(
 /// * `hash_exprs`: Stores Physical Exprs that are used during hashing.
 /// * `n_target`: desired target partition number, if partition number of the
 ///    current executor is less than this value. Partition number will be increased.
+/// * `allow_subset`: whether hash partitioning on a subset of `hash_exprs` is
+///    enough. This only holds for operators with a single input, since joins
+///    need identical partitioning on both sides, and is controlled by flag
+///    `config.optimizer.prefer_existing_subset_partitioning`.
 ///
 /// # Returns
 ///
@@ -873,6 +878,7 @@ fn add_hash_on_top(
     input: DistributionContext,
     hash_exprs: Vec<Arc<dyn PhysicalExpr>>,
     n_target: usize,
+    allow_subset: bool,
 ) -> Result<DistributionContext> {
     // Early return if hash repartition is unnecessary
     // `RepartitionExec: partitioning=Hash([...], 1), input_partitions=1` is unnecessary.
@@ -880,11 +886,12 @@ fn add_hash_on_top(
         return Ok(input);
     }
 
+    let partitioning = input.plan.output_partitioning();
+    let eq_properties = input.plan.equivalence_properties();
+    let subset_satisfied = allow_subset
+        && is_hash_partitioned_on_subset(partitioning, &hash_exprs, eq_properties);
     let dist = Distribution::HashPartitioned(hash_exprs);
-    let satisfied = input
-        .plan
-        .output_partitioning()
-        .satisfy(&dist, input.plan.equivalence_properties());
+    let satisfied = subset_satisfied || partitioning.satisfy(&dist, eq_properties);
 
     // Add hash repartitioning when:
     // - The hash distribution requirement is not satisfied, or
@@ -908,6 +915,28 @@ fn add_hash_on_top(
     Ok(input)
 }
 
+/// Checks whether `partitioning` hashes on a non-empty subset of `hash_exprs`,
+/// after normalizing both sides with the equivalence classes in `eq_properties`.
+/// Rows that agree on all of `hash_exprs` then also agree on the partitioning
+/// keys, so they are already co-located in the same partition.
+pub(crate) fn is_hash_partitioned_on_subset(
+    partitioning: &Partitioning,
+    hash_exprs: &[Arc<dyn PhysicalExpr>],
+    eq_properties: &EquivalenceProperties,
+) -> bool {
+    match partitioning {
+        Partitioning::Hash(partition_exprs, _) if !partition_exprs.is_empty() => {
+            let eq_group = eq_properties.eq_group();
+            let required = eq_group.normalize_exprs(hash_exprs.to_vec());
+            eq_group
+                .normalize_exprs(partition_exprs.clone())
+                .iter()
+                .all(|expr| physical_exprs_contains(&required, expr))
+        }
+        _ => false,
+    }
+}
+
 /// Adds a [`SortPreservingMergeExec`] operator on top of input executor
 /// to satisfy single distribution requirement.
 ///
@@ -1081,6 +1110,23 @@ fn ensure_distribution(
         }
     };
 
+    if let Some(exec) = plan.as_any().downcast_ref::<AggregateExec>() {
+        if let Some(updated_aggregate) = get_partitioned_final_aggregate(
+            exec,
+            &children[0].plan,
+            config.optimizer.prefer_existing_subset_partitioning,
+        )? {
+            plan = updated_aggregate;
+        }
+    }
+
+    // Hash partitioning on a subset of the required keys only co-locates the
+    // required groups for operators with a single input; joins need both sides
+    // to be partitioned identically. It is opt-in, since a low cardinality
+    // subset can leave most partitions empty.
+    let allow_subset_partitioning =
+        children.len() == 1 && config.optimizer.prefer_existing_subset_partitioning;
+
     // This loop iterates over all the children to:
     // - Increase parallelism for every child if it is beneficial.
     // - Satisfy the distribution requirements of every child, if it is not
@@ -1133,7 +1179,12 @@ fn ensure_distribution(
                         // to increase parallelism.
                         child = add_roundrobin_on_top(child, target_partitions)?;
                     }
-                    child = add_hash_on_top(child, exprs.to_vec(), target_partitions)?;
+                    child = add_hash_on_top(
+                        child,
+                        exprs.to_vec(),
+                        target_partitions,
+                        allow_subset_partitioning,
+                    )?;
                 }
                 Distribution::UnspecifiedDistribution => {
                     if add_roundrobin {
@@ -1230,6 +1281,50 @@ fn ensure_distribution(
     )))
 }
 
+/// Returns an [`AggregateMode::FinalPartitioned`] version of the given
+/// [`AggregateMode::Final`] aggregation when its `input` has multiple
+/// partitions that are already hash partitioned on its grouping keys, or on a
+/// subset of them if `allow_subset` is set. Each partition can then be
+/// aggregated separately, instead of merging all of them into a single
+/// partition first.
+///
+/// `CombinePartialFinalAggregate` later combines it with its partial input
+/// into a single [`AggregateMode::SinglePartitioned`] aggregation.
+fn get_partitioned_final_aggregate(
+    exec: &AggregateExec,
+    input: &Arc<dyn ExecutionPlan>,
+    allow_subset: bool,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    if exec.mode() != &AggregateMode::Final
+        || exec.group_expr().is_empty()
+        || !exec.group_expr().is_single()
+        || input.output_partitioning().partition_count() <= 1
+    {
+        return Ok(None);
+    }
+
+    let partitioning = input.output_partitioning();
+    let eq_properties = input.equivalence_properties();
+    let group_exprs = exec.group_expr().input_exprs();
+    let subset_satisfied = allow_subset
+        && is_hash_partitioned_on_subset(partitioning, &group_exprs, eq_properties);
+    let dist = Distribution::HashPartitioned(group_exprs);
+    if !subset_satisfied && !partitioning.satisfy(&dist, eq_properties) {
+        return Ok(None);
+    }
+
+    let partitioned = AggregateExec::try_new(
+        AggregateMode::FinalPartitioned,
+        exec.group_expr().clone(),
+        exec.aggr_expr().to_vec(),
+        exec.filter_expr().to_vec(),
+        Arc::clone(input),
+        exec.input_schema(),
+    )?
+    .with_limit(exec.limit());
+    Ok(Some(Arc::new(partitioned)))
+}
+
 /// Keeps track of distribution changing operators (like `RepartitionExec`,
 /// `SortPreservingMergeExec`, `CoalescePartitionsExec`) and their ancestors.
 /// Using this information, we can optimize distribution of the plan if/when
@@ -1514,6 +1609,18 @@ pub(crate) mod tests {
     fn aggregate_exec_with_alias(
         input: Arc<dyn ExecutionPlan>,
         alias_pairs: Vec<(String, String)>,
+    ) -> Arc<dyn ExecutionPlan> {
+        aggregate_exec_with_alias_and_mode(
+            input,
+            alias_pairs,
+            AggregateMode::FinalPartitioned,
+        )
+    }
+
+    fn aggregate_exec_with_alias_and_mode(
+        input: Arc<dyn ExecutionPlan>,
+        alias_pairs: Vec<(String, String)>,
+        final_mode: AggregateMode,
     ) -> Arc<dyn ExecutionPlan> {
         let schema = schema();
         let mut group_by_expr: Vec<(Arc<dyn PhysicalExpr>, String)> = vec![];
@@ -1538,7 +1645,7 @@ pub(crate) mod tests {
 
         Arc::new(
             AggregateExec::try_new(
-                AggregateMode::FinalPartitioned,
+                final_mode,
                 final_grouping,
                 vec![],
                 vec![],
@@ -4480,6 +4587,161 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[test]
+    fn do_not_add_hash_on_subset_partitioning() -> Result<()> {
+        let left = parquet_exec();
+        let right = parquet_exec();
+        // Join on (a == a)
+        let join_on = vec![(
+            Arc::new(Column::new_with_schema("a", &left.schema()).unwrap()) as _,
+            Arc::new(Column::new_with_schema("a", &right.schema()).unwrap()) as _,
+        )];
+        let join = hash_join_exec(left, right, &join_on, &JoinType::Inner);
+        // group by (a, b)
+        let alias = vec![
+            ("a".to_string(), "a".to_string()),
+            ("b".to_string(), "b".to_string()),
+        ];
+        let physical_plan = aggregate_exec_with_alias(join, alias);
+
+        let mut config = ConfigOptions::new();
+        config.execution.target_partitions = 10;
+
+        // By default, the aggregate is repartitioned on all of its grouping keys
+        let expected = &[
+            "AggregateExec: mode=FinalPartitioned, gby=[a@0 as a, b@1 as b], aggr=[]",
+            "RepartitionExec: partitioning=Hash([a@0, b@1], 10), input_partitions=10",
+            "AggregateExec: mode=Partial, gby=[a@0 as a, b@1 as b], aggr=[]",
+            "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0)]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+        ];
+        let dist_plan =
+            EnforceDistribution::new().optimize(physical_plan.clone(), &config)?;
+        assert_plan_txt!(expected, dist_plan);
+
+        config.optimizer.prefer_existing_subset_partitioning = true;
+        let expected = &[
+            "AggregateExec: mode=FinalPartitioned, gby=[a@0 as a, b@1 as b], aggr=[]",
+            // The join output is already hash partitioned on `a`, which is a
+            // subset of the grouping keys. There shouldn't be a hash repartition here
+            "AggregateExec: mode=Partial, gby=[a@0 as a, b@1 as b], aggr=[]",
+            "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0)]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+        ];
+        let dist_plan = EnforceDistribution::new().optimize(physical_plan, &config)?;
+        assert_plan_txt!(expected, dist_plan);
+
+        Ok(())
+    }
+
+    #[test]
+    fn final_aggregate_on_hash_partitioned_input() -> Result<()> {
+        let left = parquet_exec();
+        let right = parquet_exec();
+        // Join on (a == a)
+        let join_on = vec![(
+            Arc::new(Column::new_with_schema("a", &left.schema()).unwrap()) as _,
+            Arc::new(Column::new_with_schema("a", &right.schema()).unwrap()) as _,
+        )];
+        let join = hash_join_exec(left, right, &join_on, &JoinType::Inner);
+        // group by (a, b) with a final aggregation, e.g. when aggregations
+        // are not repartitioned
+        let alias = vec![
+            ("a".to_string(), "a".to_string()),
+            ("b".to_string(), "b".to_string()),
+        ];
+        let physical_plan =
+            aggregate_exec_with_alias_and_mode(join.clone(), alias, AggregateMode::Final);
+
+        let mut config = ConfigOptions::new();
+        config.execution.target_partitions = 10;
+
+        // By default, partitioning on the subset `a` of the grouping keys is
+        // not reused, and the partitions are merged before the aggregation
+        let expected = &[
+            "AggregateExec: mode=Final, gby=[a@0 as a, b@1 as b], aggr=[]",
+            "CoalescePartitionsExec",
+            "AggregateExec: mode=Partial, gby=[a@0 as a, b@1 as b], aggr=[]",
+            "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0)]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+        ];
+        let dist_plan =
+            EnforceDistribution::new().optimize(physical_plan.clone(), &config)?;
+        assert_plan_txt!(expected, dist_plan);
+
+        config.optimizer.prefer_existing_subset_partitioning = true;
+        let expected = &[
+            // The join output is already hash partitioned on `a`, so each
+            // partition is aggregated separately instead of being merged
+            "AggregateExec: mode=FinalPartitioned, gby=[a@0 as a, b@1 as b], aggr=[]",
+            "AggregateExec: mode=Partial, gby=[a@0 as a, b@1 as b], aggr=[]",
+            "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0)]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+        ];
+        let dist_plan = EnforceDistribution::new().optimize(physical_plan, &config)?;
+        assert_plan_txt!(expected, dist_plan);
+
+        // Partitioning on exactly the grouping keys is reused regardless of
+        // the flag
+        let alias = vec![("a".to_string(), "a".to_string())];
+        let physical_plan =
+            aggregate_exec_with_alias_and_mode(join, alias, AggregateMode::Final);
+
+        let expected = &[
+            "AggregateExec: mode=FinalPartitioned, gby=[a@0 as a], aggr=[]",
+            "AggregateExec: mode=Partial, gby=[a@0 as a], aggr=[]",
+            "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0, a@0)]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+            "RepartitionExec: partitioning=Hash([a@0], 10), input_partitions=10",
+            "RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e]",
+        ];
+        assert_optimized!(expected, physical_plan.clone(), true);
+        assert_optimized!(expected, physical_plan, false);
+
+        // The input is not hash partitioned on the grouping keys, the
+        // partitions are still merged before the final aggregation
+        let alias = vec![("a".to_string(), "a".to_string())];
+        let physical_plan = aggregate_exec_with_alias_and_mode(
+            parquet_exec_multiple(),
+            alias,
+            AggregateMode::Final,
+        );
+
+        let expected = &[
+            "AggregateExec: mode=Final, gby=[a@0 as a], aggr=[]",
+            "CoalescePartitionsExec",
+            "AggregateExec: mode=Partial, gby=[a@0 as a], aggr=[]",
+            "ParquetExec: file_groups={2 groups: [[x:0..100], [y:0..100]]}, projection=[a, b, c, d, e]",
+        ];
+        assert_optimized!(expected, physical_plan.clone(), true, false, 2, true, 10);
+        assert_optimized!(expected, physical_plan, false, false, 2, true, 10);
+
+        Ok(())
+    }
+
     #[test]
     fn optimize_away_unnecessary_repartition() -> Result<()> {
         let physical_plan = coalesce_partitions_exec(repartition_exec(parquet_exec()));
@@ -0,0 +1,371 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! FilterProjectionCommonSubexprs optimizer that computes the expressions
+//! shared by a [`FilterExec`] and the [`ProjectionExec`] on top of it once
+
+use std::sync::Arc;
+
+use crate::config::ConfigOptions;
+use crate::error::Result;
+use crate::physical_optimizer::PhysicalOptimizerRule;
+use crate::physical_plan::filter::FilterExec;
+use crate::physical_plan::projection::ProjectionExec;
+use crate::physical_plan::ExecutionPlan;
+
+use datafusion_common::tree_node::{
+    Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
+};
+use datafusion_expr::{Operator, Volatility};
+use datafusion_physical_expr::expressions::{BinaryExpr, CaseExpr, Column, Literal};
+use datafusion_physical_expr::{
+    physical_exprs_contains, PhysicalExpr, ScalarFunctionExpr,
+};
+
+const CSE_PREFIX: &str = "__common_expr";
+
+/// Optimizer rule that computes the subexpressions that a [`FilterExec`] and
+/// the [`ProjectionExec`] directly on top of it have in common only once.
+///
+/// The logical `CommonSubexprEliminate` rule eliminates the common
+/// subexpressions within a single plan node. The subexpressions shared by a
+/// filter and the projection above it can not be eliminated at that level, as
+/// the filter would be pushed down through the projection computing them.
+///
+/// Given a plan such as
+///
+/// ```text
+/// ProjectionExec(expr=[substr(url@0, 1, 10) as prefix])
+///   FilterExec(substr(url@0, 1, 10) != '')
+/// ```
+///
+/// the shared expression is computed by a new projection below the filter:
+///
+/// ```text
+/// ProjectionExec(expr=[__common_expr_1@1 as prefix])
+///   FilterExec(__common_expr_1@1 != '')
+///     ProjectionExec(expr=[url@0 as url, substr(url@0, 1, 10) as __common_expr_1])
+/// ```
+///
+/// Only the subexpressions that the filter evaluates for all of its input rows
+/// are moved below it, so that the expressions guarded by short circuiting
+/// expressions, e.g. the right side of `b != 0 AND a / b > 1`, are never
+/// evaluated for more rows than before. Volatile expressions are not shared.
+#[derive(Default)]
+pub struct FilterProjectionCommonSubexprs {}
+
+impl FilterProjectionCommonSubexprs {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PhysicalOptimizerRule for FilterProjectionCommonSubexprs {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        _config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        plan.transform_up(|plan| {
+            let Some(projection) = plan.as_any().downcast_ref::<ProjectionExec>() else {
+                return Ok(Transformed::no(plan));
+            };
+            let Some(filter) = projection.input().as_any().downcast_ref::<FilterExec>()
+            else {
+                return Ok(Transformed::no(plan));
+            };
+
+            match share_common_exprs(projection, filter)? {
+                Some(new_plan) => Ok(Transformed::yes(new_plan)),
+                None => Ok(Transformed::no(plan)),
+            }
+        })
+        .data()
+    }
+
+    fn name(&self) -> &str {
+        "FilterProjectionCommonSubexprs"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Rewrites `projection` and `filter` to read the subexpressions they share
+/// from a new projection below `filter`, returning `None` if they share none
+fn share_common_exprs(
+    projection: &ProjectionExec,
+    filter: &FilterExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let mut candidates = vec![];
+    collect_always_evaluated(filter.predicate(), &mut candidates)?;
+    if candidates.is_empty() {
+        return Ok(None);
+    }
+
+    // the largest candidates used by the projection, in order of appearance
+    let mut common = vec![];
+    for (expr, _) in projection.expr() {
+        expr.apply(|expr| {
+            if physical_exprs_contains(&candidates, expr) {
+                if !physical_exprs_contains(&common, expr) {
+                    common.push(expr.clone());
+                }
+                return Ok(TreeNodeRecursion::Jump);
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+    }
+    if common.is_empty() {
+        return Ok(None);
+    }
+
+    // the common expressions are appended to the columns of the filter input
+    let input = filter.input();
+    let input_schema = input.schema();
+    let mut common_exprs: Vec<_> = input_schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let column = Arc::new(Column::new(field.name(), i)) as Arc<dyn PhysicalExpr>;
+            (column, field.name().to_string())
+        })
+        .collect();
+    let mut common_columns = Vec::with_capacity(common.len());
+    let mut alias_counter = 0;
+    for expr in &common {
+        let alias = loop {
+            alias_counter += 1;
+            let alias = format!("{CSE_PREFIX}_{alias_counter}");
+            if input_schema.field_with_name(&alias).is_err() {
+                break alias;
+            }
+        };
+        let column = Column::new(&alias, common_exprs.len());
+        common_columns.push(Arc::new(column) as Arc<dyn PhysicalExpr>);
+        common_exprs.push((expr.clone(), alias));
+    }
+
+    let replace_common = |expr: Arc<dyn PhysicalExpr>| {
+        expr.transform_down(|expr| {
+            match common.iter().position(|common_expr| common_expr.eq(&expr)) {
+                Some(i) => Ok(Transformed::new(
+                    common_columns[i].clone(),
+                    true,
+                    TreeNodeRecursion::Jump,
+                )),
+                None => Ok(Transformed::no(expr)),
+            }
+        })
+        .data()
+    };
+
+    let common_projection = ProjectionExec::try_new(common_exprs, input.clone())?;
+    let new_filter = FilterExec::try_new(
+        replace_common(filter.predicate().clone())?,
+        Arc::new(common_projection),
+    )?
+    .with_default_selectivity(filter.default_selectivity())?;
+    let new_exprs = projection
+        .expr()
+        .iter()
+        .map(|(expr, name)| Ok((replace_common(expr.clone())?, name.clone())))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(Arc::new(ProjectionExec::try_new(
+        new_exprs,
+        Arc::new(new_filter),
+    )?)))
+}
+
+/// Collects the non trivial subexpressions of `expr` that are evaluated for
+/// every row `expr` is evaluated for, skipping volatile ones
+fn collect_always_evaluated(
+    expr: &Arc<dyn PhysicalExpr>,
+    out: &mut Vec<Arc<dyn PhysicalExpr>>,
+) -> Result<()> {
+    let any = expr.as_any();
+    if any.is::<Column>() || any.is::<Literal>() || is_volatile(expr)? {
+        return Ok(());
+    }
+    if !physical_exprs_contains(out, expr) {
+        out.push(expr.clone());
+    }
+
+    if let Some(binary) = any.downcast_ref::<BinaryExpr>() {
+        if matches!(binary.op(), Operator::And | Operator::Or) {
+            // the right side is only evaluated for some of the rows
+            return collect_always_evaluated(binary.left(), out);
+        }
+    }
+    let short_circuits = any.is::<CaseExpr>()
+        || any
+            .downcast_ref::<ScalarFunctionExpr>()
+            .is_some_and(|func| func.fun().short_circuits());
+    if !short_circuits {
+        for child in expr.children() {
+            collect_always_evaluated(child, out)?;
+        }
+    }
+    Ok(())
+}
+
+fn is_volatile(expr: &Arc<dyn PhysicalExpr>) -> Result<bool> {
+    expr.exists(|expr| {
+        Ok(expr
+            .as_any()
+            .downcast_ref::<ScalarFunctionExpr>()
+            .is_some_and(|func| {
+                func.fun().signature().volatility == Volatility::Volatile
+            }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physical_optimizer::test_utils::parquet_exec;
+    use crate::physical_plan::displayable;
+
+    use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+    use datafusion_physical_expr::expressions::{binary, col, lit};
+
+    /// Runs the FilterProjectionCommonSubexprs optimizer and asserts the plan against the expected
+    macro_rules! assert_optimized {
+        ($EXPECTED_LINES: expr, $PLAN: expr) => {
+            let expected_lines: Vec<&str> = $EXPECTED_LINES.iter().map(|s| *s).collect();
+
+            let optimizer = FilterProjectionCommonSubexprs::new();
+            let config = ConfigOptions::new();
+            let optimized = optimizer.optimize($PLAN, &config)?;
+            let plan = displayable(optimized.as_ref()).indent(true).to_string();
+            let actual_lines: Vec<&str> = plan
+                .split('\n')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
+
+            assert_eq!(
+                &expected_lines, &actual_lines,
+                "\n\nexpected:\n\n{:#?}\nactual:\n\n{:#?}\n\n",
+                expected_lines, actual_lines
+            );
+        };
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]))
+    }
+
+    fn projection_over_filter(
+        schema: &SchemaRef,
+        predicate: Arc<dyn PhysicalExpr>,
+        exprs: Vec<(Arc<dyn PhysicalExpr>, &str)>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let filter = FilterExec::try_new(predicate, parquet_exec(schema))?;
+        let exprs = exprs
+            .into_iter()
+            .map(|(expr, name)| (expr, name.to_string()))
+            .collect();
+        Ok(Arc::new(ProjectionExec::try_new(exprs, Arc::new(filter))?))
+    }
+
+    #[test]
+    fn share_filter_expr_with_projection() -> Result<()> {
+        let schema = schema();
+        let a_plus_1 = binary(col("a", &schema)?, Operator::Plus, lit(1i64), &schema)?;
+        let predicate = binary(a_plus_1.clone(), Operator::Gt, lit(5i64), &schema)?;
+        let plan = projection_over_filter(
+            &schema,
+            predicate,
+            vec![
+                (a_plus_1.clone(), "a + 1"),
+                (
+                    binary(a_plus_1, Operator::Multiply, col("b", &schema)?, &schema)?,
+                    "(a + 1) * b",
+                ),
+            ],
+        )?;
+
+        let expected = &[
+            "ProjectionExec: expr=[__common_expr_1@2 as a + 1, __common_expr_1@2 * b@1 as (a + 1) * b]",
+            "FilterExec: __common_expr_1@2 > 5",
+            "ProjectionExec: expr=[a@0 as a, b@1 as b, a@0 + 1 as __common_expr_1]",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+
+    #[test]
+    fn keep_short_circuited_exprs() -> Result<()> {
+        let schema = schema();
+        let a_plus_1 = binary(col("a", &schema)?, Operator::Plus, lit(1i64), &schema)?;
+        let a_div_b = binary(
+            col("a", &schema)?,
+            Operator::Divide,
+            col("b", &schema)?,
+            &schema,
+        )?;
+        let predicate = binary(
+            binary(a_plus_1.clone(), Operator::Gt, lit(5i64), &schema)?,
+            Operator::And,
+            binary(a_div_b.clone(), Operator::Gt, lit(1i64), &schema)?,
+            &schema,
+        )?;
+        let plan = projection_over_filter(
+            &schema,
+            predicate,
+            vec![(a_plus_1, "a + 1"), (a_div_b, "a / b")],
+        )?;
+
+        // `a / b` is only evaluated for the rows where `a + 1 > 5`
+        let expected = &[
+            "ProjectionExec: expr=[__common_expr_1@2 as a + 1, a@0 / b@1 as a / b]",
+            "FilterExec: __common_expr_1@2 > 5 AND a@0 / b@1 > 1",
+            "ProjectionExec: expr=[a@0 as a, b@1 as b, a@0 + 1 as __common_expr_1]",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+
+    #[test]
+    fn keep_plan_without_common_exprs() -> Result<()> {
+        let schema = schema();
+        let predicate = binary(col("a", &schema)?, Operator::Gt, lit(5i64), &schema)?;
+        let plan = projection_over_filter(
+            &schema,
+            predicate,
+            vec![(col("a", &schema)?, "a"), (col("b", &schema)?, "b")],
+        )?;
+
+        let expected = &[
+            "ProjectionExec: expr=[a@0 as a, b@1 as b]",
+            "FilterExec: a@0 > 5",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+}
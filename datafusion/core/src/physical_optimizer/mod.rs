@@ -26,6 +26,7 @@ pub mod coalesce_batches;
 pub mod combine_partial_final_agg;
 pub mod enforce_distribution;
 pub mod enforce_sorting;
+pub mod filter_projection_common_subexprs;
 pub mod join_selection;
 pub mod limited_distinct_aggregation;
 pub mod optimizer;
@@ -33,6 +34,7 @@ pub mod output_requirements;
 pub mod pipeline_checker;
 pub mod projection_pushdown;
 pub mod pruning;
+pub mod remove_redundant_repartition;
 pub mod replace_with_order_preserving_variants;
 mod sort_pushdown;
 pub mod topk_aggregation;
@@ -27,10 +27,12 @@ use crate::physical_optimizer::coalesce_batches::CoalesceBatches;
 use crate::physical_optimizer::combine_partial_final_agg::CombinePartialFinalAggregate;
 use crate::physical_optimizer::enforce_distribution::EnforceDistribution;
 use crate::physical_optimizer::enforce_sorting::EnforceSorting;
+use crate::physical_optimizer::filter_projection_common_subexprs::FilterProjectionCommonSubexprs;
 use crate::physical_optimizer::join_selection::JoinSelection;
 use crate::physical_optimizer::limited_distinct_aggregation::LimitedDistinctAggregation;
 use crate::physical_optimizer::output_requirements::OutputRequirements;
 use crate::physical_optimizer::pipeline_checker::PipelineChecker;
+use crate::physical_optimizer::remove_redundant_repartition::RemoveRedundantRepartition;
 use crate::physical_optimizer::topk_aggregation::TopKAggregation;
 use crate::{error::Result, physical_plan::ExecutionPlan};
 
@@ -102,10 +104,19 @@ impl PhysicalOptimizer {
             // Note that one should always run this rule after running the EnforceDistribution rule
             // as the latter may break local sorting requirements.
             Arc::new(EnforceSorting::new()),
+            // The RemoveRedundantRepartition rule removes repartitions that do not change
+            // the distribution of their input, and merges directly stacked repartitions.
+            // It should run after the EnforceDistribution and EnforceSorting rules, which
+            // add and modify repartitions.
+            Arc::new(RemoveRedundantRepartition::new()),
             // Run once after the local sorting requirement is changed
             Arc::new(OptimizeAggregateOrder::new()),
             // TODO: `try_embed_to_hash_join` in the ProjectionPushdown rule would be block by the CoalesceBatches, so add it before CoalesceBatches. Maybe optimize it in the future.
             Arc::new(ProjectionPushdown::new()),
+            // The FilterProjectionCommonSubexprs rule computes the expressions shared by a
+            // filter and the projection on top of it once. It runs after the projections
+            // are pushed down, and before the CoalesceBatches rule separates them.
+            Arc::new(FilterProjectionCommonSubexprs::new()),
             // The CoalesceBatches rule will not influence the distribution and ordering of the
             // whole plan tree. Therefore, to avoid influencing other rules, it should run last.
             Arc::new(CoalesceBatches::new()),
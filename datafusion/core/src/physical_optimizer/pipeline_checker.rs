@@ -30,6 +30,7 @@ use datafusion_common::config::OptimizerOptions;
 use datafusion_common::plan_err;
 use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
 use datafusion_physical_expr::intervals::utils::{check_support, is_datatype_supported};
+use datafusion_physical_expr::ScalarFunctionExpr;
 use datafusion_physical_plan::joins::SymmetricHashJoinExec;
 
 /// The PipelineChecker rule rejects non-runnable query plans that use
@@ -90,13 +91,19 @@ pub fn check_finiteness_requirements(
 /// This function returns whether a given symmetric hash join is amenable to
 /// data pruning. For this to be possible, it needs to have a filter where
 /// all involved [`PhysicalExpr`]s, [`Operator`]s and data types support
-/// interval calculations.
+/// interval calculations. Scalar functions only bound their results and do
+/// not propagate constraints back to their arguments, so filters containing
+/// them can not prune the join buffers.
 ///
 /// [`PhysicalExpr`]: crate::physical_plan::PhysicalExpr
 /// [`Operator`]: datafusion_expr::Operator
 fn is_prunable(join: &SymmetricHashJoinExec) -> bool {
     join.filter().map_or(false, |filter| {
         check_support(filter.expression(), &join.schema())
+            && !filter
+                .expression()
+                .exists(|expr| Ok(expr.as_any().is::<ScalarFunctionExpr>()))
+                .unwrap_or(true)
             && filter
                 .schema()
                 .fields()
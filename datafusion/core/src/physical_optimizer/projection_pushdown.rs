@@ -39,9 +39,11 @@ use crate::physical_plan::projection::ProjectionExec;
 use crate::physical_plan::repartition::RepartitionExec;
 use crate::physical_plan::sorts::sort::SortExec;
 use crate::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
+use crate::physical_plan::unnest::UnnestExec;
+use crate::physical_plan::windows::{BoundedWindowAggExec, WindowAggExec, WindowExpr};
 use crate::physical_plan::{Distribution, ExecutionPlan, ExecutionPlanProperties};
 
-use arrow_schema::SchemaRef;
+use arrow_schema::{DataType, SchemaRef};
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::tree_node::{
     Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
@@ -147,6 +149,20 @@ pub fn remove_unnecessary_projections(
             try_swapping_with_sym_hash_join(projection, sym_join)?
         } else if let Some(ste) = input.downcast_ref::<StreamingTableExec>() {
             try_swapping_with_streaming_table(projection, ste)?
+        } else if let Some(unnest) = input.downcast_ref::<UnnestExec>() {
+            try_pushdown_through_unnest(projection, unnest)?
+        } else if let Some(window) = input.downcast_ref::<BoundedWindowAggExec>() {
+            try_pushdown_through_window(
+                projection,
+                window.window_expr(),
+                &window.partition_keys,
+            )?
+        } else if let Some(window) = input.downcast_ref::<WindowAggExec>() {
+            try_pushdown_through_window(
+                projection,
+                window.window_expr(),
+                &window.partition_keys,
+            )?
         } else {
             // If the input plan of the projection is not one of the above, we
             // conservatively assume that pushing the projection down may hurt.
@@ -533,6 +549,192 @@ fn try_pushdown_through_union(
     Ok(Some(Arc::new(UnionExec::new(new_children))))
 }
 
+/// Tries to push `projection` down through its input (`unnest`). The unnested
+/// columns are always kept, so only the other input columns that `projection`
+/// does not use are pruned. If possible, returns a new projection on top of an
+/// [`UnnestExec`] whose input is a column-only projection. Otherwise, returns `None`.
+fn try_pushdown_through_unnest(
+    projection: &ProjectionExec,
+    unnest: &UnnestExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let input_schema = unnest.input().schema();
+    let output_schema = unnest.schema();
+
+    // The input column each output column of the unnest is produced from.
+    // A struct column is expanded into one output column per field.
+    let mut output_to_input = vec![];
+    for (index, field) in input_schema.fields().iter().enumerate() {
+        let width = match field.data_type() {
+            DataType::Struct(fields)
+                if unnest.struct_column_indices().contains(&index) =>
+            {
+                fields.len()
+            }
+            _ => 1,
+        };
+        output_to_input.extend(std::iter::repeat(index).take(width));
+    }
+
+    let mut required_columns = unnest
+        .list_column_indices()
+        .iter()
+        .chain(unnest.struct_column_indices())
+        .copied()
+        .collect::<Vec<_>>();
+    for (expr, _) in projection.expr() {
+        required_columns.extend(
+            collect_columns(expr)
+                .iter()
+                .map(|column| output_to_input[column.index()]),
+        );
+    }
+    let required_columns = required_columns
+        .into_iter()
+        .sorted()
+        .dedup()
+        .collect::<Vec<_>>();
+
+    // If no input column can be pruned, we should not try to push it down:
+    if required_columns.is_empty()
+        || required_columns.len() >= input_schema.fields().len()
+    {
+        return Ok(None);
+    }
+
+    let new_index = |index: &usize| required_columns.binary_search(index).unwrap();
+    let new_input_exprs = required_columns
+        .iter()
+        .map(|&index| {
+            let name = input_schema.field(index).name();
+            (Arc::new(Column::new(name, index)) as _, name.clone())
+        })
+        .collect();
+    let new_input = Arc::new(ProjectionExec::try_new(
+        new_input_exprs,
+        unnest.input().clone(),
+    )?);
+
+    // The output columns of the unnest that remain after the pruning
+    let remaining_outputs = (0..output_to_input.len())
+        .filter(|&index| required_columns.contains(&output_to_input[index]))
+        .collect::<Vec<_>>();
+    let new_unnest = Arc::new(UnnestExec::new(
+        new_input,
+        unnest.list_column_indices().iter().map(new_index).collect(),
+        unnest
+            .struct_column_indices()
+            .iter()
+            .map(new_index)
+            .collect(),
+        Arc::new(output_schema.project(&remaining_outputs)?),
+        unnest.options().clone(),
+    ));
+
+    let remaining_exprs = remaining_outputs
+        .iter()
+        .map(|&index| {
+            let name = output_schema.field(index).name();
+            (Arc::new(Column::new(name, index)) as _, name.clone())
+        })
+        .collect::<Vec<_>>();
+    let mut new_exprs = vec![];
+    for (expr, alias) in projection.expr() {
+        let Some(new_expr) = update_expr(expr, &remaining_exprs, false)? else {
+            return Ok(None);
+        };
+        new_exprs.push((new_expr, alias.clone()));
+    }
+
+    let new_projection = ProjectionExec::try_new(new_exprs, new_unnest)?;
+    if is_projection_removable(&new_projection) {
+        return Ok(Some(new_projection.input().clone()));
+    }
+    Ok(Some(Arc::new(new_projection)))
+}
+
+/// Tries to push `projection` down through its input window operator, which
+/// is either a [`BoundedWindowAggExec`] or a [`WindowAggExec`]. The window
+/// expressions refer to the input columns by index, so only the trailing input
+/// columns that neither `projection` nor the window expressions use are pruned.
+/// If possible, returns a new projection on top of the window operator whose
+/// input is a column-only projection. Otherwise, returns `None`.
+fn try_pushdown_through_window(
+    projection: &ProjectionExec,
+    window_expr: &[Arc<dyn WindowExpr>],
+    partition_keys: &[Arc<dyn PhysicalExpr>],
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let window = projection.input();
+    // Window operators always have a single child, so zero indexing is safe.
+    let input = window.children()[0];
+    let input_schema = input.schema();
+    let input_len = input_schema.fields().len();
+
+    let mut used_exprs = partition_keys.to_vec();
+    for expr in window_expr {
+        let exprs = expr.all_expressions();
+        used_exprs.extend(exprs.args);
+        used_exprs.extend(exprs.partition_by_exprs);
+        used_exprs.extend(exprs.order_by_exprs);
+    }
+    used_exprs.extend(projection.expr().iter().map(|(expr, _)| expr.clone()));
+
+    // Keep at least one column so that the number of rows is preserved
+    let remaining_len = used_exprs
+        .iter()
+        .flat_map(collect_columns)
+        .map(|column| column.index())
+        .filter(|&index| index < input_len)
+        .max()
+        .map_or(1, |index| index + 1);
+    // If no input column can be pruned, we should not try to push it down:
+    if remaining_len >= input_len {
+        return Ok(None);
+    }
+
+    let new_input_exprs = input_schema.fields()[..remaining_len]
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            (
+                Arc::new(Column::new(field.name(), index)) as _,
+                field.name().clone(),
+            )
+        })
+        .collect();
+    let new_input = Arc::new(ProjectionExec::try_new(new_input_exprs, input.clone())?);
+    let new_window = window.clone().with_new_children(vec![new_input])?;
+
+    // The window columns are shifted to the left by the number of pruned
+    // input columns.
+    let new_exprs = projection
+        .expr()
+        .iter()
+        .map(|(expr, alias)| {
+            expr.clone()
+                .transform_up(|expr| {
+                    let Some(column) = expr.as_any().downcast_ref::<Column>() else {
+                        return Ok(Transformed::no(expr));
+                    };
+                    if column.index() < input_len {
+                        return Ok(Transformed::no(expr));
+                    }
+                    let index = column.index() - input_len + remaining_len;
+                    Ok(Transformed::yes(
+                        Arc::new(Column::new(column.name(), index)) as _,
+                    ))
+                })
+                .data()
+                .map(|expr| (expr, alias.clone()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let new_projection = ProjectionExec::try_new(new_exprs, new_window)?;
+    if is_projection_removable(&new_projection) {
+        return Ok(Some(new_projection.input().clone()));
+    }
+    Ok(Some(Arc::new(new_projection)))
+}
+
 /// Some projection can't be pushed down left input or right input of hash join because filter or on need may need some columns that won't be used in later.
 /// By embed those projection to hash join, we can reduce the cost of build_batch_from_indices in hash join (build_batch_from_indices need to can compute::take() for each column) and avoid unnecessary output creation.
 fn try_embed_to_hash_join(
@@ -1294,11 +1496,12 @@ mod tests {
     use crate::datasource::file_format::file_compression_type::FileCompressionType;
     use crate::datasource::listing::PartitionedFile;
     use crate::datasource::physical_plan::FileScanConfig;
+    use crate::physical_optimizer::test_utils::bounded_window_exec;
     use crate::physical_plan::get_plan_string;
     use crate::physical_plan::joins::StreamJoinPartitionMode;
 
     use arrow_schema::{DataType, Field, Schema, SortOptions};
-    use datafusion_common::{JoinType, ScalarValue};
+    use datafusion_common::{JoinType, ScalarValue, UnnestOptions};
     use datafusion_execution::object_store::ObjectStoreUrl;
     use datafusion_execution::{SendableRecordBatchStream, TaskContext};
     use datafusion_expr::{
@@ -2652,4 +2855,104 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_unnest_after_projection() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new_list("b", Field::new("item", DataType::Int32, true), true),
+            Field::new("c", DataType::Int32, true),
+            Field::new("d", DataType::Int32, true),
+        ]));
+        let unnested_schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Int32, true),
+            Field::new("d", DataType::Int32, true),
+        ]));
+        let memory = Arc::new(MemoryExec::try_new(&[], schema, None)?);
+        let unnest: Arc<dyn ExecutionPlan> = Arc::new(UnnestExec::new(
+            memory,
+            vec![1],
+            vec![],
+            unnested_schema,
+            UnnestOptions::default(),
+        ));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+            ],
+            unnest,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, c@2 as c]",
+            "  UnnestExec",
+            "    MemoryExec: partitions=0, partition_sizes=[]",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "UnnestExec",
+            "  MemoryExec: partitions=0, partition_sizes=[]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        let new_unnest = after_optimize
+            .as_any()
+            .downcast_ref::<UnnestExec>()
+            .unwrap();
+        assert_eq!(new_unnest.list_column_indices(), &[0]);
+        assert_eq!(
+            new_unnest
+                .input()
+                .as_any()
+                .downcast_ref::<MemoryExec>()
+                .unwrap()
+                .projection()
+                .clone()
+                .unwrap(),
+            vec![1, 2]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_after_projection() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let window = bounded_window_exec("b", vec![], csv);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("count", 5)), "cnt".to_string()),
+            ],
+            window,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, count@5 as cnt]",
+            "  BoundedWindowAggExec: wdw=[count: Ok(Field { name: \"count\", data_type: Int64, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} }), frame: WindowFrame { units: Range, start_bound: Preceding(NULL), end_bound: CurrentRow, is_causal: false }], mode=[Sorted]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // Only the trailing columns that the window does not use are pruned
+        let expected = [
+            "ProjectionExec: expr=[a@0 as a, count@2 as cnt]",
+            "  BoundedWindowAggExec: wdw=[count: Ok(Field { name: \"count\", data_type: Int64, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} }), frame: WindowFrame { units: Range, start_bound: Preceding(NULL), end_bound: CurrentRow, is_causal: false }], mode=[Sorted]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
 }
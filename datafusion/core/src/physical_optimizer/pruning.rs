@@ -1159,7 +1159,8 @@ fn verify_support_type_for_prune(from_type: &DataType, to_type: &DataType) -> Re
     ) && matches!(
         to_type,
         DataType::Int8 | DataType::Int32 | DataType::Int64 | DataType::Decimal128(_, _)
-    ) {
+    ) || is_order_preserving_temporal_cast(from_type, to_type)
+    {
         Ok(())
     } else {
         plan_err!(
@@ -1168,6 +1169,23 @@ fn verify_support_type_for_prune(from_type: &DataType, to_type: &DataType) -> Re
     }
 }
 
+/// Returns true if casting from `from_type` to `to_type` preserves the order
+/// of the values, e.g. the cast of a timestamp column to a timestamp of a
+/// different unit when it is compared with a timestamp literal
+fn is_order_preserving_temporal_cast(from_type: &DataType, to_type: &DataType) -> bool {
+    match (from_type, to_type) {
+        // Timestamps with a time zone are instants in UTC, so only casts between
+        // timestamps that both have or both lack a time zone keep their values
+        (DataType::Timestamp(_, from_tz), DataType::Timestamp(_, to_tz)) => {
+            from_tz.is_some() == to_tz.is_some()
+        }
+        (DataType::Date32 | DataType::Date64, DataType::Timestamp(_, None))
+        | (DataType::Date32, DataType::Date64)
+        | (DataType::Date64, DataType::Date32) => true,
+        _ => false,
+    }
+}
+
 /// replaces a column with an old name with a new name in an expression
 fn rewrite_column_expr(
     e: Arc<dyn PhysicalExpr>,
@@ -1564,7 +1582,9 @@ mod tests {
 
     use arrow::array::Decimal128Array;
     use arrow::{
-        array::{BinaryArray, Int32Array, Int64Array, StringArray},
+        array::{
+            BinaryArray, Int32Array, Int64Array, StringArray, TimestampMillisecondArray,
+        },
         datatypes::TimeUnit,
     };
     use arrow_array::UInt64Array;
@@ -2541,6 +2561,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn row_group_predicate_not_between() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("c1", DataType::Int32, true)]));
+
+        // c1 NOT BETWEEN 1 AND 5 can prune containers that only hold values
+        // inside the range
+        prune_with_expr(
+            col("c1").not_between(lit(1), lit(5)),
+            &schema,
+            &TestStatistics::new().with(
+                "c1",
+                ContainerStats::new_i32(
+                    vec![Some(2), Some(0), Some(6), None], // min
+                    vec![Some(4), Some(3), Some(8), None], // max
+                ),
+            ),
+            &[false, true, true, true],
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn row_group_predicate_between_with_in_list() -> Result<()> {
         let schema = Schema::new(vec![
@@ -2697,6 +2739,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn prune_timestamp_cast() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "c1",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            true,
+        )]));
+        let statistics = TestStatistics::new().with(
+            "c1",
+            ContainerStats::new()
+                .with_min(Arc::new(TimestampMillisecondArray::from(vec![
+                    Some(0),
+                    Some(2000),
+                    None,
+                ])))
+                .with_max(Arc::new(TimestampMillisecondArray::from(vec![
+                    Some(1000),
+                    Some(3000),
+                    None,
+                ]))),
+        );
+
+        prune_with_expr(
+            // CAST(c1 AS Timestamp(Nanosecond, None)) >= 1.5s
+            cast(col("c1"), DataType::Timestamp(TimeUnit::Nanosecond, None)).gt_eq(lit(
+                ScalarValue::TimestampNanosecond(Some(1_500_000_000), None),
+            )),
+            &schema,
+            &statistics,
+            &[false, true, true],
+        );
+
+        prune_with_expr(
+            // CAST(c1 AS Timestamp(Nanosecond, None)) BETWEEN 0.5s AND 1.5s
+            cast(col("c1"), DataType::Timestamp(TimeUnit::Nanosecond, None)).between(
+                lit(ScalarValue::TimestampNanosecond(Some(500_000_000), None)),
+                lit(ScalarValue::TimestampNanosecond(Some(1_500_000_000), None)),
+            ),
+            &schema,
+            &statistics,
+            &[true, false, true],
+        );
+    }
+
     #[test]
     fn prune_decimal_data() {
         // decimal(9,2)
@@ -0,0 +1,334 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! RemoveRedundantRepartition optimizer that removes [`RepartitionExec`]s
+//! which do not change the distribution of their input, and merges
+//! repartitions that are directly stacked on top of each other.
+
+use std::sync::Arc;
+
+use crate::config::ConfigOptions;
+use crate::error::Result;
+use crate::physical_optimizer::PhysicalOptimizerRule;
+use crate::physical_plan::repartition::RepartitionExec;
+use crate::physical_plan::{ExecutionPlan, ExecutionPlanProperties, Partitioning};
+
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_physical_expr::Distribution;
+
+/// Optimizer rule that removes redundant [`RepartitionExec`]s.
+///
+/// A `Hash(exprs, n)` repartition is removed when its input is already hash
+/// partitioned on `exprs` into `n` partitions. A `RoundRobinBatch(n)`
+/// repartition is kept even if its input has `n` partitions, as it balances
+/// the batches of skewed partitions.
+///
+/// A `RepartitionExec` directly on top of another one makes the work of the
+/// inner one useless, so the inner one is removed when:
+///
+/// * neither of them preserves the ordering of its input, and
+/// * the outer one is a round robin repartition, or the input of the inner
+///   one has at least as many partitions as the inner one produces (so that
+///   hashing is not done with less parallelism than before)
+///
+/// The output partitioning of the rewritten plan is the same as, or
+/// stronger than the original one, so distribution requirements that were
+/// satisfied before remain satisfied.
+#[derive(Default)]
+pub struct RemoveRedundantRepartition {}
+
+impl RemoveRedundantRepartition {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PhysicalOptimizerRule for RemoveRedundantRepartition {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        _config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        plan.transform_up(|plan| {
+            let Some(repartition) = plan.as_any().downcast_ref::<RepartitionExec>()
+            else {
+                return Ok(Transformed::no(plan));
+            };
+
+            if is_no_op(repartition) {
+                return Ok(Transformed::yes(repartition.input().clone()));
+            }
+
+            if let Some(inner) = repartition
+                .input()
+                .as_any()
+                .downcast_ref::<RepartitionExec>()
+            {
+                if can_skip_inner(repartition, inner) {
+                    let new_plan = RepartitionExec::try_new(
+                        inner.input().clone(),
+                        repartition.partitioning().clone(),
+                    )?;
+                    return Ok(Transformed::yes(Arc::new(new_plan)));
+                }
+            }
+
+            Ok(Transformed::no(plan))
+        })
+        .data()
+    }
+
+    fn name(&self) -> &str {
+        "RemoveRedundantRepartition"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Returns true if `repartition` does not change the distribution of its input
+fn is_no_op(repartition: &RepartitionExec) -> bool {
+    let input = repartition.input();
+    let input_partitioning = input.output_partitioning();
+    match repartition.partitioning() {
+        Partitioning::Hash(exprs, n) => {
+            input_partitioning.partition_count() == *n
+                && input_partitioning.satisfy(
+                    &Distribution::HashPartitioned(exprs.clone()),
+                    input.equivalence_properties(),
+                )
+        }
+        Partitioning::RoundRobinBatch(_) | Partitioning::UnknownPartitioning(_) => false,
+    }
+}
+
+/// Returns true if `outer` can directly repartition the input of `inner`
+fn can_skip_inner(outer: &RepartitionExec, inner: &RepartitionExec) -> bool {
+    if outer.preserve_order() || inner.preserve_order() {
+        return false;
+    }
+    match outer.partitioning() {
+        Partitioning::RoundRobinBatch(_) => true,
+        Partitioning::Hash(_, _) => {
+            inner.input().output_partitioning().partition_count()
+                >= inner.partitioning().partition_count()
+        }
+        Partitioning::UnknownPartitioning(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physical_optimizer::test_utils::{
+        parquet_exec, parquet_exec_sorted, sort_expr, union_exec,
+    };
+    use crate::physical_plan::displayable;
+
+    use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+    use datafusion_physical_expr::expressions::col;
+    use datafusion_physical_expr::PhysicalExpr;
+
+    /// Runs the RemoveRedundantRepartition optimizer and asserts the plan against the expected
+    macro_rules! assert_optimized {
+        ($EXPECTED_LINES: expr, $PLAN: expr) => {
+            let expected_lines: Vec<&str> = $EXPECTED_LINES.iter().map(|s| *s).collect();
+
+            let optimizer = RemoveRedundantRepartition::new();
+            let config = ConfigOptions::new();
+            let optimized = optimizer.optimize($PLAN, &config)?;
+            let plan = displayable(optimized.as_ref()).indent(true).to_string();
+            let actual_lines: Vec<&str> = plan
+                .split('\n')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect();
+
+            assert_eq!(
+                &expected_lines, &actual_lines,
+                "\n\nexpected:\n\n{:#?}\nactual:\n\n{:#?}\n\n",
+                expected_lines, actual_lines
+            );
+        };
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]))
+    }
+
+    fn round_robin(
+        input: Arc<dyn ExecutionPlan>,
+        n: usize,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(RepartitionExec::try_new(
+            input,
+            Partitioning::RoundRobinBatch(n),
+        )?))
+    }
+
+    fn hash(
+        input: Arc<dyn ExecutionPlan>,
+        exprs: Vec<Arc<dyn PhysicalExpr>>,
+        n: usize,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(RepartitionExec::try_new(
+            input,
+            Partitioning::Hash(exprs, n),
+        )?))
+    }
+
+    #[test]
+    fn keep_round_robin_over_same_partition_count() -> Result<()> {
+        let schema = schema();
+        // the hash partitions may be skewed
+        let plan = round_robin(
+            hash(
+                round_robin(parquet_exec(&schema), 8)?,
+                vec![col("a", &schema)?],
+                8,
+            )?,
+            8,
+        )?;
+
+        let expected = &[
+            "RepartitionExec: partitioning=RoundRobinBatch(8), input_partitions=8",
+            "RepartitionExec: partitioning=Hash([a@0], 8), input_partitions=8",
+            "RepartitionExec: partitioning=RoundRobinBatch(8), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+
+    #[test]
+    fn keep_round_robin_over_skewed_input() -> Result<()> {
+        let schema = schema();
+        // the files, and so the partitions of the union, may have very
+        // different sizes
+        let source = union_exec(vec![parquet_exec(&schema), parquet_exec(&schema)]);
+        let plan = round_robin(source, 2)?;
+
+        let expected = &[
+            "RepartitionExec: partitioning=RoundRobinBatch(2), input_partitions=2",
+            "UnionExec",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+
+    #[test]
+    fn remove_hash_over_same_hash() -> Result<()> {
+        let schema = schema();
+        let input = round_robin(parquet_exec(&schema), 4)?;
+        let plan = hash(
+            hash(input, vec![col("a", &schema)?], 4)?,
+            vec![col("a", &schema)?],
+            4,
+        )?;
+
+        let expected = &[
+            "RepartitionExec: partitioning=Hash([a@0], 4), input_partitions=4",
+            "RepartitionExec: partitioning=RoundRobinBatch(4), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+
+    #[test]
+    fn collapse_hash_over_hash() -> Result<()> {
+        let schema = schema();
+        let input = round_robin(parquet_exec(&schema), 4)?;
+        let plan = hash(
+            hash(input, vec![col("a", &schema)?], 4)?,
+            vec![col("b", &schema)?],
+            8,
+        )?;
+
+        let expected = &[
+            "RepartitionExec: partitioning=Hash([b@1], 8), input_partitions=4",
+            "RepartitionExec: partitioning=RoundRobinBatch(4), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+
+    #[test]
+    fn collapse_round_robin_over_repartition() -> Result<()> {
+        let schema = schema();
+        let plan =
+            round_robin(hash(parquet_exec(&schema), vec![col("a", &schema)?], 4)?, 8)?;
+
+        let expected = &[
+            "RepartitionExec: partitioning=RoundRobinBatch(8), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+
+    #[test]
+    fn keep_round_robin_under_hash() -> Result<()> {
+        let schema = schema();
+        // round robin increases the parallelism of hashing
+        let plan = hash(
+            round_robin(parquet_exec(&schema), 4)?,
+            vec![col("a", &schema)?],
+            4,
+        )?;
+
+        let expected = &[
+            "RepartitionExec: partitioning=Hash([a@0], 4), input_partitions=4",
+            "RepartitionExec: partitioning=RoundRobinBatch(4), input_partitions=1",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+
+    #[test]
+    fn keep_order_preserving_repartition() -> Result<()> {
+        let schema = schema();
+        let source = union_exec(vec![
+            parquet_exec_sorted(&schema, vec![sort_expr("a", &schema)]),
+            parquet_exec_sorted(&schema, vec![sort_expr("a", &schema)]),
+        ]);
+        let inner = Arc::new(
+            RepartitionExec::try_new(source, Partitioning::RoundRobinBatch(4))?
+                .with_preserve_order(),
+        );
+        let plan = round_robin(inner, 2)?;
+
+        let expected = &[
+            "RepartitionExec: partitioning=RoundRobinBatch(2), input_partitions=4",
+            "RepartitionExec: partitioning=RoundRobinBatch(4), input_partitions=2, preserve_order=true, sort_exprs=a@0 ASC",
+            "UnionExec",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b], output_ordering=[a@0 ASC]",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b], output_ordering=[a@0 ASC]",
+        ];
+        assert_optimized!(expected, plan);
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ use crate::physical_optimizer::PhysicalOptimizerRule;
 use crate::physical_plan::aggregates::AggregateExec;
 use crate::physical_plan::coalesce_batches::CoalesceBatchesExec;
 use crate::physical_plan::filter::FilterExec;
+use crate::physical_plan::projection::ProjectionExec;
 use crate::physical_plan::repartition::RepartitionExec;
 use crate::physical_plan::sorts::sort::SortExec;
 use crate::physical_plan::ExecutionPlan;
@@ -84,6 +85,21 @@ impl TopKAggregation {
         Some(Arc::new(new_aggr))
     }
 
+    /// Returns the ordering of the input of `projection` corresponding to
+    /// `order`, if `order` is on a column of the input passed through as is
+    fn project_order(
+        projection: &ProjectionExec,
+        order: &PhysicalSortExpr,
+    ) -> Option<PhysicalSortExpr> {
+        let col = order.expr.as_any().downcast_ref::<Column>()?;
+        let (expr, _) = projection.expr().get(col.index())?;
+        expr.as_any().downcast_ref::<Column>()?;
+        Some(PhysicalSortExpr {
+            expr: expr.clone(),
+            options: order.options,
+        })
+    }
+
     fn transform_sort(plan: Arc<dyn ExecutionPlan>) -> Option<Arc<dyn ExecutionPlan>> {
         let sort = plan.as_any().downcast_ref::<SortExec>()?;
 
@@ -102,16 +118,26 @@ impl TopKAggregation {
         };
 
         let mut cardinality_preserved = true;
+        let mut order = order.clone();
         let closure = |plan: Arc<dyn ExecutionPlan>| {
             if !cardinality_preserved {
                 return Ok(Transformed::no(plan));
             }
             if let Some(aggr) = plan.as_any().downcast_ref::<AggregateExec>() {
                 // either we run into an Aggregate and transform it
-                match Self::transform_agg(aggr, order, limit) {
+                match Self::transform_agg(aggr, &order, limit) {
                     None => cardinality_preserved = false,
                     Some(plan) => return Ok(Transformed::yes(plan)),
                 }
+            } else if let Some(projection) =
+                plan.as_any().downcast_ref::<ProjectionExec>()
+            {
+                // or we continue below a projection, such as one aliasing the
+                // aggregate, sorting by the input column it projects
+                match Self::project_order(projection, &order) {
+                    None => cardinality_preserved = false,
+                    Some(input_order) => order = input_order,
+                }
             } else {
                 // or we continue down whitelisted nodes of other types
                 if !is_cardinality_preserving(plan.clone()) {
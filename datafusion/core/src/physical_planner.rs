@@ -25,7 +25,8 @@ use std::sync::Arc;
 use crate::datasource::file_format::file_type_to_format;
 use crate::datasource::listing::ListingTableUrl;
 use crate::datasource::physical_plan::FileSinkConfig;
-use crate::datasource::source_as_provider;
+use crate::datasource::provider::FederatedSource;
+use crate::datasource::{source_as_provider, DefaultTableSource};
 use crate::error::{DataFusionError, Result};
 use crate::execution::context::{ExecutionProps, SessionState};
 use crate::logical_expr::utils::generate_sort_key;
@@ -38,6 +39,7 @@ use crate::logical_expr::{
 };
 use crate::logical_expr::{Limit, Values};
 use crate::physical_expr::{create_physical_expr, create_physical_exprs};
+use crate::physical_optimizer::enforce_distribution::is_hash_partitioned_on_subset;
 use crate::physical_optimizer::optimizer::PhysicalOptimizerRule;
 use crate::physical_plan::aggregates::{AggregateExec, AggregateMode, PhysicalGroupBy};
 use crate::physical_plan::analyze::AnalyzeExec;
@@ -60,7 +62,7 @@ use crate::physical_plan::unnest::UnnestExec;
 use crate::physical_plan::values::ValuesExec;
 use crate::physical_plan::windows::{BoundedWindowAggExec, WindowAggExec};
 use crate::physical_plan::{
-    aggregates, displayable, udaf, windows, AggregateExpr, ExecutionPlan,
+    aggregates, displayable, udaf, windows, AggregateExpr, Distribution, ExecutionPlan,
     ExecutionPlanProperties, InputOrderMode, Partitioning, PhysicalExpr, WindowExpr,
 };
 
@@ -69,6 +71,7 @@ use arrow::datatypes::{Schema, SchemaRef};
 use arrow_array::builder::StringBuilder;
 use arrow_array::RecordBatch;
 use datafusion_common::display::ToStringifiedPlan;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{
     exec_err, internal_datafusion_err, internal_err, not_impl_err, plan_err, DFSchema,
     ScalarValue,
@@ -93,7 +96,7 @@ use datafusion_sql::utils::window_expr_common_partition_keys;
 use async_trait::async_trait;
 use futures::{StreamExt, TryStreamExt};
 use itertools::{multiunzip, Itertools};
-use log::{debug, trace};
+use log::{debug, trace, warn};
 use sqlparser::ast::NullTreatment;
 use tokio::sync::Mutex;
 
@@ -218,10 +221,14 @@ fn create_physical_name(e: &Expr, is_first_expr: bool) -> Result<String> {
             fun,
             args,
             order_by,
+            distinct,
             ..
-        }) => {
-            create_function_physical_name(&fun.to_string(), false, args, Some(order_by))
-        }
+        }) => create_function_physical_name(
+            &fun.to_string(),
+            *distinct,
+            args,
+            Some(order_by),
+        ),
         Expr::AggregateFunction(AggregateFunction {
             func_def,
             distinct,
@@ -521,6 +528,9 @@ struct LogicalNode<'a> {
     // None if root
     parent_index: Option<usize>,
     state: NodeState,
+    /// The remote system computing this node and all its inputs, in which
+    /// case the node is planned as a leaf
+    federated_source: Option<Arc<dyn FederatedSource>>,
 }
 
 impl DefaultPhysicalPlanner {
@@ -553,12 +563,16 @@ impl DefaultPhysicalPlanner {
         let mut flat_tree_leaf_indices = vec![];
         while let Some((parent_index, node)) = dfs_visit_stack.pop() {
             let current_index = flat_tree.len();
+            let federated_source = find_federated_source(node)?;
+            let inputs = match federated_source {
+                Some(_) => vec![],
+                None => node.inputs(),
+            };
             // Because of how we extend the visit stack here, we visit the children
             // in reverse order of how they appear, so later we need to reverse
             // the order of children when building the nodes.
-            dfs_visit_stack
-                .extend(node.inputs().iter().map(|&n| (Some(current_index), n)));
-            let state = match node.inputs().len() {
+            dfs_visit_stack.extend(inputs.iter().map(|&n| (Some(current_index), n)));
+            let state = match inputs.len() {
                 0 => {
                     flat_tree_leaf_indices.push(current_index);
                     NodeState::ZeroOrOneChild
@@ -574,6 +588,7 @@ impl DefaultPhysicalPlanner {
                 node,
                 parent_index,
                 state,
+                federated_source,
             };
             flat_tree.push(node);
         }
@@ -626,13 +641,31 @@ impl DefaultPhysicalPlanner {
                 "Invalid index whilst creating initial physical plan"
             )
         })?;
-        let mut plan = self
-            .map_logical_node_to_physical(
-                node.node,
-                session_state,
-                ChildrenContainer::None,
-            )
-            .await?;
+        let mut plan = match &node.federated_source {
+            Some(source) => {
+                let plan = source
+                    .create_physical_plan(session_state, node.node)
+                    .await?;
+                if !node.node.schema().matches_arrow_schema(&plan.schema()) {
+                    return plan_err!(
+                        "Federated source {} created an ExecutionPlan with mismatched schema. \
+                        LogicalPlan schema: {:?}, ExecutionPlan schema: {:?}",
+                        source.name(),
+                        node.node.schema(),
+                        plan.schema()
+                    );
+                }
+                plan
+            }
+            None => {
+                self.map_logical_node_to_physical(
+                    node.node,
+                    session_state,
+                    ChildrenContainer::None,
+                )
+                .await?
+            }
+        };
         let mut current_index = leaf_starter_index;
         // parent_index is None only for root
         while let Some(parent_index) = node.parent_index {
@@ -747,10 +780,15 @@ impl DefaultPhysicalPlanner {
             ))),
             LogicalPlan::DescribeTable(DescribeTable {
                 schema,
+                partition_cols,
                 output_schema,
             }) => {
                 let output_schema: Schema = output_schema.as_ref().into();
-                self.plan_describe(schema.clone(), Arc::new(output_schema))?
+                self.plan_describe(
+                    schema.clone(),
+                    partition_cols,
+                    Arc::new(output_schema),
+                )?
             }
 
             // 1 Child
@@ -852,16 +890,24 @@ impl DefaultPhysicalPlanner {
                     && session_state.config().target_partitions() > 1
                     && session_state.config().repartition_window_functions();
 
-                let physical_partition_keys = if can_repartition {
-                    partition_keys
-                        .iter()
-                        .map(|e| {
-                            self.create_physical_expr(e, input.schema(), session_state)
-                        })
-                        .collect::<Result<Vec<Arc<dyn PhysicalExpr>>>>()?
-                } else {
-                    vec![]
-                };
+                let physical_partition_keys = partition_keys
+                    .iter()
+                    .map(|e| self.create_physical_expr(e, input.schema(), session_state))
+                    .collect::<Result<Vec<Arc<dyn PhysicalExpr>>>>()?;
+
+                let (input_exec, physical_partition_keys) =
+                    match partition_aware_window_input(
+                        &input_exec,
+                        &physical_partition_keys,
+                        session_state
+                            .config_options()
+                            .optimizer
+                            .prefer_existing_subset_partitioning,
+                    ) {
+                        Some(input_exec) => (input_exec, physical_partition_keys),
+                        None if can_repartition => (input_exec, physical_partition_keys),
+                        None => (input_exec, vec![]),
+                    };
 
                 let get_sort_keys = |expr: &Expr| match expr {
                     Expr::WindowFunction(WindowFunction {
@@ -1323,6 +1369,7 @@ impl DefaultPhysicalPlanner {
                 let join: Arc<dyn ExecutionPlan> = if join_on.is_empty() {
                     // there is no equal join condition, use the nested loop join
                     // TODO optimize the plan, and use the config of `target_partitions` and `repartition_joins`
+                    warn_on_large_nested_loop_join(&physical_left, &physical_right);
                     Arc::new(NestedLoopJoinExec::try_new(
                         physical_left,
                         physical_right,
@@ -1540,6 +1587,142 @@ impl DefaultPhysicalPlanner {
     }
 }
 
+/// Returns the input to use for a window partitioned by `partition_keys` when
+/// `input` is a global [`SortExec`] over an input that is already hash
+/// partitioned by these keys, or by a subset of them if `allow_subset` is set
+/// (see `datafusion.optimizer.prefer_existing_subset_partitioning`), `None`
+/// otherwise.
+///
+/// Each partition of such an input holds all the rows of the window
+/// partitions it contains, so the window can be computed on each partition
+/// without merging them and repartitioning again: the sort only needs to
+/// order the rows within each partition, and is removed entirely when they
+/// are already ordered.
+fn partition_aware_window_input(
+    input: &Arc<dyn ExecutionPlan>,
+    partition_keys: &[Arc<dyn PhysicalExpr>],
+    allow_subset: bool,
+) -> Option<Arc<dyn ExecutionPlan>> {
+    let sort = input.as_any().downcast_ref::<SortExec>()?;
+    if partition_keys.is_empty() || sort.preserve_partitioning() || sort.fetch().is_some()
+    {
+        return None;
+    }
+    let sort_input = sort.input();
+    let partitioning = sort_input.output_partitioning();
+    let eq_properties = sort_input.equivalence_properties();
+    let required = Distribution::HashPartitioned(partition_keys.to_vec());
+    if !matches!(partitioning, Partitioning::Hash(_, n) if *n > 1)
+        || !(partitioning.satisfy(&required, eq_properties)
+            || (allow_subset
+                && is_hash_partitioned_on_subset(
+                    partitioning,
+                    partition_keys,
+                    eq_properties,
+                )))
+    {
+        return None;
+    }
+    if sort_input
+        .equivalence_properties()
+        .ordering_satisfy(sort.expr())
+    {
+        Some(Arc::clone(sort_input))
+    } else {
+        Some(Arc::new(
+            SortExec::new(sort.expr().to_vec(), Arc::clone(sort_input))
+                .with_preserve_partitioning(true),
+        ))
+    }
+}
+
+/// Returns the [`FederatedSource`] that can compute `plan` as a whole, if
+/// `plan` joins or aggregates tables that all belong to the same remote
+/// system, and that system supports it.
+///
+/// See [`FederatedSource`] for details.
+fn find_federated_source(plan: &LogicalPlan) -> Result<Option<Arc<dyn FederatedSource>>> {
+    let mut source: Option<Arc<dyn FederatedSource>> = None;
+    let mut joins_or_aggregates = false;
+    let mut supported = true;
+    plan.apply(|node| {
+        match node {
+            LogicalPlan::Join(_)
+            | LogicalPlan::CrossJoin(_)
+            | LogicalPlan::Aggregate(_) => joins_or_aggregates = true,
+            LogicalPlan::Projection(_)
+            | LogicalPlan::Filter(_)
+            | LogicalPlan::Sort(_)
+            | LogicalPlan::Limit(_)
+            | LogicalPlan::SubqueryAlias(_) => {}
+            LogicalPlan::TableScan(scan) => {
+                let scan_source = scan
+                    .source
+                    .as_any()
+                    .downcast_ref::<DefaultTableSource>()
+                    .and_then(|source| source.table_provider.federated_source());
+                match scan_source {
+                    Some(scan_source)
+                        if source.as_ref().map_or(true, |source| {
+                            source.name() == scan_source.name()
+                        }) =>
+                    {
+                        source.get_or_insert(scan_source);
+                    }
+                    _ => supported = false,
+                }
+            }
+            _ => supported = false,
+        }
+        Ok(if supported {
+            TreeNodeRecursion::Continue
+        } else {
+            TreeNodeRecursion::Stop
+        })
+    })?;
+    match source {
+        Some(source) if supported && joins_or_aggregates => {
+            Ok(source.supports_plan(plan)?.then_some(source))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Number of row pairs above which planning a nested loop join logs a warning.
+const NESTED_LOOP_JOIN_WARNING_ROW_PAIRS: usize = 100_000_000;
+
+/// Logs a warning when the input statistics estimate that a nested loop join
+/// between `left` and `right` evaluates its join filter on more than
+/// [`NESTED_LOOP_JOIN_WARNING_ROW_PAIRS`] row pairs. Such joins usually stem
+/// from a missing equality predicate and may take a very long time to run.
+fn warn_on_large_nested_loop_join(
+    left: &Arc<dyn ExecutionPlan>,
+    right: &Arc<dyn ExecutionPlan>,
+) {
+    if let Some(row_pairs) = estimated_row_pairs(left, right) {
+        if row_pairs > NESTED_LOOP_JOIN_WARNING_ROW_PAIRS {
+            warn!(
+                "Join without equality predicates is planned as a nested loop join \
+                over an estimated {row_pairs} row pairs"
+            );
+        }
+    }
+}
+
+/// Estimates the number of row pairs produced by combining every row of
+/// `left` with every row of `right`, or `None` if either row count is unknown.
+fn estimated_row_pairs(
+    left: &Arc<dyn ExecutionPlan>,
+    right: &Arc<dyn ExecutionPlan>,
+) -> Option<usize> {
+    let num_rows = |plan: &Arc<dyn ExecutionPlan>| {
+        plan.statistics()
+            .ok()
+            .and_then(|stats| stats.num_rows.get_value().copied())
+    };
+    Some(num_rows(left)?.saturating_mul(num_rows(right)?))
+}
+
 /// Expand and align a GROUPING SET expression.
 /// (see <https://www.postgresql.org/docs/current/queries-table-expressions.html#QUERIES-GROUPING-SETS>)
 ///
@@ -1751,6 +1934,7 @@ pub fn create_window_expr_with_name(
             order_by,
             window_frame,
             null_treatment,
+            distinct,
         }) => {
             let physical_args =
                 create_physical_exprs(args, logical_schema, execution_props)?;
@@ -1769,7 +1953,7 @@ pub fn create_window_expr_with_name(
             let window_frame = Arc::new(window_frame.clone());
             let ignore_nulls = null_treatment.unwrap_or(NullTreatment::RespectNulls)
                 == NullTreatment::IgnoreNulls;
-            windows::create_window_expr(
+            windows::create_window_expr_with_options(
                 fun,
                 name,
                 &physical_args,
@@ -1779,6 +1963,10 @@ pub fn create_window_expr_with_name(
                 window_frame,
                 physical_schema,
                 ignore_nulls,
+                &windows::WindowExprOptions {
+                    distinct: *distinct,
+                    overflow_mode: execution_props.arithmetic_overflow_mode,
+                },
             )
         }
         other => plan_err!("Invalid window expression '{other:?}'"),
@@ -1874,7 +2062,7 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
                     };
                     let ordering_reqs: Vec<PhysicalSortExpr> =
                         physical_sort_exprs.clone().unwrap_or(vec![]);
-                    let agg_expr = udaf::create_aggregate_expr(
+                    let agg_expr = udaf::create_aggregate_function_expr(
                         fun,
                         &physical_args,
                         args,
@@ -1884,8 +2072,15 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
                         name,
                         ignore_nulls,
                         *distinct,
-                    )?;
-                    (agg_expr, filter, physical_sort_exprs)
+                    )?
+                    .with_arithmetic_overflow_mode(
+                        execution_props.arithmetic_overflow_mode,
+                    );
+                    (
+                        Arc::new(agg_expr) as Arc<dyn AggregateExpr>,
+                        filter,
+                        physical_sort_exprs,
+                    )
                 }
             };
             Ok((agg_expr, filter, order_by))
@@ -1969,6 +2164,8 @@ impl DefaultPhysicalPlanner {
             let mut stringified_plans = vec![];
 
             let config = &session_state.config_options().explain;
+            // `EXPLAIN (ESTIMATES)` shows the statistics of the physical plans
+            let show_statistics = config.show_statistics || e.estimates;
 
             if !config.physical_plan_only {
                 stringified_plans.clone_from(&e.stringified_plans);
@@ -1986,24 +2183,23 @@ impl DefaultPhysicalPlanner {
                         // Include statistics / schema if enabled
                         stringified_plans.push(
                             displayable(input.as_ref())
-                                .set_show_statistics(config.show_statistics)
+                                .set_show_statistics(show_statistics)
                                 .set_show_schema(config.show_schema)
                                 .to_stringified(e.verbose, InitialPhysicalPlan),
                         );
 
-                        // Show statistics + schema in verbose output even if not
-                        // explicitly requested
+                        // Show statistics, partitioning and ordering + schema in
+                        // verbose output even if not explicitly requested
                         if e.verbose {
-                            if !config.show_statistics {
-                                stringified_plans.push(
-                                    displayable(input.as_ref())
-                                        .set_show_statistics(true)
-                                        .to_stringified(
-                                            e.verbose,
-                                            InitialPhysicalPlanWithStats,
-                                        ),
-                                );
-                            }
+                            stringified_plans.push(
+                                displayable(input.as_ref())
+                                    .set_show_statistics(true)
+                                    .set_show_properties(true)
+                                    .to_stringified(
+                                        e.verbose,
+                                        InitialPhysicalPlanWithStats,
+                                    ),
+                            );
                             if !config.show_schema {
                                 stringified_plans.push(
                                     displayable(input.as_ref())
@@ -2024,7 +2220,7 @@ impl DefaultPhysicalPlanner {
                                 let plan_type = OptimizedPhysicalPlan { optimizer_name };
                                 stringified_plans.push(
                                     displayable(plan)
-                                        .set_show_statistics(config.show_statistics)
+                                        .set_show_statistics(show_statistics)
                                         .set_show_schema(config.show_schema)
                                         .to_stringified(e.verbose, plan_type),
                                 );
@@ -2035,24 +2231,23 @@ impl DefaultPhysicalPlanner {
                                 // This plan will includes statistics if show_statistics is on
                                 stringified_plans.push(
                                     displayable(input.as_ref())
-                                        .set_show_statistics(config.show_statistics)
+                                        .set_show_statistics(show_statistics)
                                         .set_show_schema(config.show_schema)
                                         .to_stringified(e.verbose, FinalPhysicalPlan),
                                 );
 
-                                // Show statistics + schema in verbose output even if not
-                                // explicitly requested
+                                // Show statistics, partitioning and ordering + schema
+                                // in verbose output even if not explicitly requested
                                 if e.verbose {
-                                    if !config.show_statistics {
-                                        stringified_plans.push(
-                                            displayable(input.as_ref())
-                                                .set_show_statistics(true)
-                                                .to_stringified(
-                                                    e.verbose,
-                                                    FinalPhysicalPlanWithStats,
-                                                ),
-                                        );
-                                    }
+                                    stringified_plans.push(
+                                        displayable(input.as_ref())
+                                            .set_show_statistics(true)
+                                            .set_show_properties(true)
+                                            .to_stringified(
+                                                e.verbose,
+                                                FinalPhysicalPlanWithStats,
+                                            ),
+                                    );
                                     if !config.show_schema {
                                         stringified_plans.push(
                                             displayable(input.as_ref())
@@ -2158,11 +2353,13 @@ impl DefaultPhysicalPlanner {
     fn plan_describe(
         &self,
         table_schema: Arc<Schema>,
+        partition_cols: &[String],
         output_schema: Arc<Schema>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
         let mut column_names = StringBuilder::new();
         let mut data_types = StringBuilder::new();
         let mut is_nullables = StringBuilder::new();
+        let mut is_partitions = StringBuilder::new();
         for field in table_schema.fields() {
             column_names.append_value(field.name());
 
@@ -2173,6 +2370,14 @@ impl DefaultPhysicalPlanner {
             // "YES if the column is possibly nullable, NO if it is known not nullable. "
             let nullable_str = if field.is_nullable() { "YES" } else { "NO" };
             is_nullables.append_value(nullable_str);
+
+            // "YES if the table is partitioned by the column, NO otherwise"
+            let partition_str = if partition_cols.contains(field.name()) {
+                "YES"
+            } else {
+                "NO"
+            };
+            is_partitions.append_value(partition_str);
         }
 
         let record_batch = RecordBatch::try_new(
@@ -2181,6 +2386,7 @@ impl DefaultPhysicalPlanner {
                 Arc::new(column_names.finish()),
                 Arc::new(data_types.finish()),
                 Arc::new(is_nullables.finish()),
+                Arc::new(is_partitions.finish()),
             ],
         )?;
 
@@ -2261,7 +2467,7 @@ mod tests {
 
     use super::*;
     use crate::datasource::file_format::options::CsvReadOptions;
-    use crate::datasource::MemTable;
+    use crate::datasource::{MemTable, TableProvider};
     use crate::physical_plan::{
         expressions, DisplayAs, DisplayFormatType, ExecutionMode, PlanProperties,
         SendableRecordBatchStream,
@@ -2274,7 +2480,9 @@ mod tests {
     use datafusion_common::{assert_contains, DFSchemaRef, TableReference};
     use datafusion_execution::runtime_env::RuntimeEnv;
     use datafusion_execution::TaskContext;
-    use datafusion_expr::{col, lit, LogicalPlanBuilder, UserDefinedLogicalNodeCore};
+    use datafusion_expr::{
+        col, lit, LogicalPlanBuilder, TableType, UserDefinedLogicalNodeCore,
+    };
     use datafusion_functions_aggregate::expr_fn::sum;
     use datafusion_physical_expr::EquivalenceProperties;
 
@@ -2436,6 +2644,78 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_estimated_row_pairs() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let left: Arc<dyn ExecutionPlan> = Arc::new(MemoryExec::try_new(
+            &[vec![batch.clone()]],
+            schema.clone(),
+            None,
+        )?);
+        let right: Arc<dyn ExecutionPlan> = Arc::new(MemoryExec::try_new(
+            &[vec![batch.clone()], vec![batch]],
+            schema,
+            None,
+        )?);
+        assert_eq!(estimated_row_pairs(&left, &right), Some(18));
+        Ok(())
+    }
+
+    /// A logger that captures the warnings logged by the tests
+    struct WarningCapture(std::sync::Mutex<Vec<String>>);
+
+    impl log::Log for WarningCapture {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::Level::Warn
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                self.0.lock().unwrap().push(record.args().to_string());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static WARNINGS: WarningCapture = WarningCapture(std::sync::Mutex::new(vec![]));
+
+    #[tokio::test]
+    async fn test_large_nested_loop_join_warning() -> Result<()> {
+        if log::set_logger(&WARNINGS).is_ok() {
+            log::set_max_level(log::LevelFilter::Warn);
+        }
+
+        let ctx = SessionContext::new();
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Int32Array::from_iter_values(0..20_000))],
+        )?;
+        ctx.register_batch("l", batch.clone())?;
+        ctx.register_batch("r", batch)?;
+
+        // a join without equality predicates is planned as a nested loop join
+        let plan = ctx
+            .sql("SELECT * FROM l JOIN r ON l.a < r.a")
+            .await?
+            .create_physical_plan()
+            .await?;
+        let formatted = displayable(plan.as_ref()).indent(true).to_string();
+        assert_contains!(&formatted, "NestedLoopJoinExec");
+
+        // which is large enough to log a warning
+        let expected = "Join without equality predicates is planned as a nested loop \
+            join over an estimated 400000000 row pairs";
+        let warnings = WARNINGS.0.lock().unwrap();
+        assert!(warnings.iter().any(|w| w == expected), "{warnings:?}");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_limit_with_partitions() -> Result<()> {
         let schema = Schema::new(vec![Field::new("id", DataType::Int32, false)]);
@@ -2454,6 +2734,141 @@ mod tests {
         Ok(())
     }
 
+    /// A table whose scan is hash partitioned by `k` into two partitions,
+    /// and optionally sorted by `k, v` within each partition
+    struct HashPartitionedTable {
+        schema: SchemaRef,
+        sorted: bool,
+    }
+
+    #[async_trait]
+    impl TableProvider for HashPartitionedTable {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn schema(&self) -> SchemaRef {
+            Arc::clone(&self.schema)
+        }
+
+        fn table_type(&self) -> TableType {
+            TableType::Base
+        }
+
+        async fn scan(
+            &self,
+            _state: &SessionState,
+            _projection: Option<&Vec<usize>>,
+            _filters: &[Expr],
+            _limit: Option<usize>,
+        ) -> Result<Arc<dyn ExecutionPlan>> {
+            let k = expressions::col("k", &self.schema)?;
+            let v = expressions::col("v", &self.schema)?;
+            let options = SortOptions {
+                descending: false,
+                nulls_first: false,
+            };
+            let orderings = if self.sorted {
+                vec![vec![
+                    PhysicalSortExpr {
+                        expr: Arc::clone(&k),
+                        options,
+                    },
+                    PhysicalSortExpr { expr: v, options },
+                ]]
+            } else {
+                vec![]
+            };
+            let cache = PlanProperties::new(
+                EquivalenceProperties::new_with_orderings(
+                    Arc::clone(&self.schema),
+                    &orderings,
+                ),
+                Partitioning::Hash(vec![k], 2),
+                ExecutionMode::Bounded,
+            );
+            Ok(Arc::new(NoOpExecutionPlan { cache }))
+        }
+    }
+
+    #[tokio::test]
+    async fn window_on_hash_partitioned_input() -> Result<()> {
+        let config = SessionConfig::new().with_target_partitions(4);
+        let ctx = SessionContext::new_with_config(config);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("k", DataType::Int32, false),
+            Field::new("v", DataType::Int32, false),
+        ]));
+        let sql = "SELECT k, v, ROW_NUMBER() OVER (PARTITION BY k ORDER BY v) FROM t";
+
+        // the input is neither exchanged nor sorted again
+        let table = HashPartitionedTable {
+            schema: Arc::clone(&schema),
+            sorted: true,
+        };
+        ctx.register_table("t", Arc::new(table))?;
+        let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert_contains!(&plan, "BoundedWindowAggExec");
+        assert!(!plan.contains("RepartitionExec"), "{plan}");
+        assert!(!plan.contains("SortExec"), "{plan}");
+
+        // the rows are only sorted within each partition
+        let table = HashPartitionedTable {
+            schema,
+            sorted: false,
+        };
+        ctx.deregister_table("t")?;
+        ctx.register_table("t", Arc::new(table))?;
+        let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        assert_contains!(&plan, "preserve_partitioning=[true]");
+        assert!(!plan.contains("RepartitionExec"), "{plan}");
+        assert!(!plan.contains("SortPreservingMergeExec"), "{plan}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn window_on_subset_hash_partitioned_input() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("k", DataType::Int32, false),
+            Field::new("v", DataType::Int32, false),
+        ]));
+        let sql = "SELECT k, v, ROW_NUMBER() OVER (PARTITION BY k, v) FROM t";
+
+        for prefer_existing_subset_partitioning in [false, true] {
+            let mut config = SessionConfig::new().with_target_partitions(4);
+            config
+                .options_mut()
+                .optimizer
+                .prefer_existing_subset_partitioning =
+                prefer_existing_subset_partitioning;
+            let ctx = SessionContext::new_with_config(config);
+            let table = HashPartitionedTable {
+                schema: Arc::clone(&schema),
+                sorted: true,
+            };
+            ctx.register_table("t", Arc::new(table))?;
+
+            let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+            let plan = displayable(plan.as_ref()).indent(true).to_string();
+            assert_contains!(&plan, "BoundedWindowAggExec");
+            if prefer_existing_subset_partitioning {
+                // the input is hash partitioned by `k` only, which still keeps
+                // each window partition of `k, v` within a single partition
+                assert!(!plan.contains("RepartitionExec"), "{plan}");
+                assert!(!plan.contains("SortExec"), "{plan}");
+            } else {
+                // by default, the input is repartitioned on all the keys
+                assert_contains!(
+                    &plan,
+                    "RepartitionExec: partitioning=Hash([k@0, v@1], 4)"
+                );
+            }
+        }
+        Ok(())
+    }
+
     #[tokio::test]
     async fn errors() -> Result<()> {
         let bool_expr = col("c1").eq(col("c1"));
@@ -2979,8 +3394,8 @@ mod tests {
 // display it online here: https://dreampuf.github.io/GraphvizOnline
 
 digraph {
-    1[shape=box label="ProjectionExec: expr=[id@0 + 2 as employee.id + Int32(2)]", tooltip=""]
-    2[shape=box label="EmptyExec", tooltip=""]
+    1[shape=box label="ProjectionExec: expr=[id@0 + 2 as employee.id + Int32(2)], partitions=1", tooltip=""]
+    2[shape=box label="EmptyExec, partitions=1", tooltip=""]
     1 -> 2 [arrowhead=none, arrowtail=normal, dir=back]
 }
 // End DataFusion GraphViz Plan
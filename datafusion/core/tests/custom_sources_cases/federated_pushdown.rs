@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use arrow::array::Int32Array;
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use datafusion::assert_batches_sorted_eq;
+use datafusion::datasource::provider::{FederatedSource, TableProvider, TableType};
+use datafusion::datasource::{provider_as_source, source_as_provider, MemTable};
+use datafusion::error::Result;
+use datafusion::execution::context::SessionState;
+use datafusion::logical_expr::{Expr, LogicalPlan};
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::prelude::*;
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+
+use async_trait::async_trait;
+
+/// A remote system that computes the plans it receives by scanning the
+/// in-memory tables behind its [`RemoteTable`]s, and records them
+#[derive(Debug, Default)]
+struct Remote {
+    plans: Mutex<Vec<String>>,
+}
+
+impl Remote {
+    fn take_plans(&self) -> Vec<String> {
+        std::mem::take(&mut self.plans.lock().unwrap())
+    }
+}
+
+#[async_trait]
+impl FederatedSource for Remote {
+    fn name(&self) -> &str {
+        "remote"
+    }
+
+    async fn create_physical_plan(
+        &self,
+        state: &SessionState,
+        plan: &LogicalPlan,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.plans
+            .lock()
+            .unwrap()
+            .push(plan.display_indent().to_string());
+        let plan = plan
+            .clone()
+            .transform_up(|node| match node {
+                LogicalPlan::TableScan(mut scan) => {
+                    let table = source_as_provider(&scan.source)?;
+                    let table = table.as_any().downcast_ref::<RemoteTable>().unwrap();
+                    scan.source = provider_as_source(table.inner.clone());
+                    Ok(Transformed::yes(LogicalPlan::TableScan(scan)))
+                }
+                node => Ok(Transformed::no(node)),
+            })
+            .data()?;
+        state.create_physical_plan(&plan).await
+    }
+}
+
+/// A table of the [`Remote`] system
+struct RemoteTable {
+    remote: Arc<Remote>,
+    inner: Arc<MemTable>,
+}
+
+#[async_trait]
+impl TableProvider for RemoteTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.inner.scan(state, projection, filters, limit).await
+    }
+
+    fn federated_source(&self) -> Option<Arc<dyn FederatedSource>> {
+        Some(self.remote.clone())
+    }
+}
+
+fn mem_table(columns: &[(&str, Vec<i32>)]) -> Result<Arc<MemTable>> {
+    let schema = Arc::new(Schema::new(
+        columns
+            .iter()
+            .map(|(name, _)| Field::new(*name, DataType::Int32, false))
+            .collect::<Vec<_>>(),
+    ));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        columns
+            .iter()
+            .map(|(_, values)| Arc::new(Int32Array::from(values.clone())) as _)
+            .collect(),
+    )?;
+    Ok(Arc::new(MemTable::try_new(schema, vec![vec![batch]])?))
+}
+
+fn context(remote: &Arc<Remote>) -> Result<SessionContext> {
+    let ctx = SessionContext::new();
+    let remote_t1 = mem_table(&[("a", vec![1, 2, 3]), ("b", vec![10, 20, 30])])?;
+    let remote_t2 = mem_table(&[("a", vec![1, 1, 3]), ("c", vec![100, 101, 300])])?;
+    for (name, inner) in [("remote_t1", remote_t1), ("remote_t2", remote_t2)] {
+        let table = RemoteTable {
+            remote: remote.clone(),
+            inner,
+        };
+        ctx.register_table(name, Arc::new(table))?;
+    }
+    ctx.register_table("local_t3", mem_table(&[("a", vec![1, 2])])?)?;
+    Ok(ctx)
+}
+
+#[tokio::test]
+async fn join_and_aggregate_pushed_to_federated_source() -> Result<()> {
+    let remote = Arc::new(Remote::default());
+    let ctx = context(&remote)?;
+
+    let batches = ctx
+        .sql(
+            "SELECT remote_t1.a, count(*) AS n FROM remote_t1 \
+            JOIN remote_t2 ON remote_t1.a = remote_t2.a GROUP BY remote_t1.a",
+        )
+        .await?
+        .collect()
+        .await?;
+    let expected = [
+        "+---+---+",
+        "| a | n |",
+        "+---+---+",
+        "| 1 | 2 |",
+        "| 3 | 1 |",
+        "+---+---+",
+    ];
+    assert_batches_sorted_eq!(expected, &batches);
+
+    let plans = remote.take_plans();
+    assert_eq!(plans.len(), 1, "{plans:?}");
+    assert!(plans[0].starts_with("Projection:"), "{}", plans[0]);
+    assert!(plans[0].contains("Aggregate:"), "{}", plans[0]);
+    assert!(plans[0].contains("Inner Join:"), "{}", plans[0]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn join_with_local_table_not_pushed_to_federated_source() -> Result<()> {
+    let remote = Arc::new(Remote::default());
+    let ctx = context(&remote)?;
+
+    let batches = ctx
+        .sql(
+            "SELECT remote_t1.a, remote_t1.b FROM remote_t1 \
+            JOIN local_t3 ON remote_t1.a = local_t3.a",
+        )
+        .await?
+        .collect()
+        .await?;
+    let expected = [
+        "+---+----+",
+        "| a | b  |",
+        "+---+----+",
+        "| 1 | 10 |",
+        "| 2 | 20 |",
+        "+---+----+",
+    ];
+    assert_batches_sorted_eq!(expected, &batches);
+
+    // scans of single tables keep using `TableProvider::scan`
+    let batches = ctx
+        .sql("SELECT a FROM remote_t1 WHERE b > 10")
+        .await?
+        .collect()
+        .await?;
+    let expected = ["+---+", "| a |", "+---+", "| 2 |", "| 3 |", "+---+"];
+    assert_batches_sorted_eq!(expected, &batches);
+
+    assert!(remote.take_plans().is_empty());
+    Ok(())
+}
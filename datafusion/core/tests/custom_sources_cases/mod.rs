@@ -45,6 +45,7 @@ use datafusion_physical_plan::{ExecutionMode, PlanProperties};
 use async_trait::async_trait;
 use futures::stream::Stream;
 
+mod federated_pushdown;
 mod provider_filter_pushdown;
 mod statistics;
 
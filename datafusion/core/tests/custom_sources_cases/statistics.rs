@@ -247,6 +247,24 @@ async fn sql_filter() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn sql_filter_monotonic_function() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx = init_ctx(stats, schema)?;
+
+    // exp is increasing, so exp(c1) is bounded below by exp(-24) > 0
+    let df = ctx
+        .sql("SELECT * FROM stats_table WHERE exp(c1) < 0")
+        .await
+        .unwrap();
+
+    let physical_plan = df.create_physical_plan().await.unwrap();
+    let stats = physical_plan.statistics()?;
+    assert_eq!(stats.num_rows, Precision::Inexact(0));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn sql_limit() -> Result<()> {
     let (stats, schema) = fully_defined();
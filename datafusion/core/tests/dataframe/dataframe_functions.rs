@@ -631,6 +631,27 @@ async fn test_fn_regexp_like() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[cfg(feature = "unicode_expressions")]
+async fn test_fn_regexp_extract() -> Result<()> {
+    let expr = regexp_extract(col("a"), lit("([a-z]+)([A-Z]+)"), Some(lit(2i64)));
+
+    let expected = [
+        "+----------------------------------------------------------+",
+        "| regexp_extract(test.a,Utf8(\"([a-z]+)([A-Z]+)\"),Int64(2)) |",
+        "+----------------------------------------------------------+",
+        "| DEF                                                      |",
+        "|                                                          |",
+        "|                                                          |",
+        "| D                                                        |",
+        "+----------------------------------------------------------+",
+    ];
+
+    assert_fn_batches!(expr, expected);
+
+    Ok(())
+}
+
 #[tokio::test]
 #[cfg(feature = "unicode_expressions")]
 async fn test_fn_regexp_match() -> Result<()> {
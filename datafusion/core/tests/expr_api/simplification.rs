@@ -693,3 +693,21 @@ fn test_simplify_cycles() {
     let expected = lit(true);
     test_simplify_with_cycle_count(expr, expected, 3);
 }
+
+#[test]
+fn test_simplify_volatile_duplicates() {
+    // each call of random() returns a different value
+    let rand = || Expr::ScalarFunction(ScalarFunction::new_udf(math::random(), vec![]));
+
+    // random() > 0.5 OR random() > 0.5 --> no rewrite
+    let expr = rand().gt(lit(0.5)).or(rand().gt(lit(0.5)));
+    test_simplify(expr.clone(), expr);
+
+    // random() < 0.5 AND (random() < 0.5 AND c2) --> no rewrite
+    let expr = rand().lt(lit(0.5)).and(rand().lt(lit(0.5)).and(col("c2")));
+    test_simplify(expr.clone(), expr);
+
+    // c3 > 5 OR c3 > 5 --> c3 > 5
+    let expr = col("c3").gt(lit(5i64)).or(col("c3").gt(lit(5i64)));
+    test_simplify(expr, col("c3").gt(lit(5i64)));
+}
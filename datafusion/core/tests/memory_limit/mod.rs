@@ -40,6 +40,7 @@ use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
 use datafusion::physical_optimizer::join_selection::JoinSelection;
 use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
 use datafusion::physical_plan::{ExecutionPlan, SendableRecordBatchStream};
+use datafusion_common::config::QueryMemoryPolicy;
 use datafusion_common::{assert_contains, Result};
 
 use datafusion::prelude::{SessionConfig, SessionContext};
@@ -65,6 +66,52 @@ async fn oom_sort() {
         .await
 }
 
+#[tokio::test]
+async fn oom_sort_query_memory_limit() {
+    // the runtime has plenty of memory, but the query is limited
+    let config =
+        SessionConfig::new().with_query_memory_limit(200_000, QueryMemoryPolicy::Spill);
+    TestCase::new()
+        .with_query("select * from t order by host DESC")
+        .with_expected_errors(vec![
+            "Resources exhausted: Memory Exhausted while Sorting (DiskManager is disabled)",
+        ])
+        .with_memory_limit(100_000_000)
+        .with_config(config)
+        .run()
+        .await
+}
+
+#[tokio::test]
+async fn sort_query_spill_limit() {
+    // the sort spills, but the query may not use enough disk space
+    let config = SessionConfig::new().with_query_spill_limit(1_000);
+    TestCase::new()
+        .with_query("select * from t order by host DESC")
+        .with_expected_errors(vec![
+            "Resources exhausted: The used disk space during the spilling process has exceeded the allowable limit of 1000.0 B",
+        ])
+        .with_memory_limit(200_000)
+        .with_disk_manager_config(DiskManagerConfig::NewOs)
+        .with_config(config)
+        .run()
+        .await
+}
+
+#[tokio::test]
+async fn sort_query_spill_checksums() {
+    // the spilled batches are read back after verifying their checksums
+    let config = SessionConfig::new().with_spill_checksums(true);
+    TestCase::new()
+        .with_query("select * from t order by host DESC")
+        .with_memory_limit(200_000)
+        .with_disk_manager_config(DiskManagerConfig::NewOs)
+        .with_config(config)
+        .with_expected_success()
+        .run()
+        .await
+}
+
 #[tokio::test]
 async fn group_by_none() {
     TestCase::new()
@@ -18,11 +18,15 @@
 //! Tests for passing user provided [`ParquetAccessPlan`]` to `ParquetExec`]`
 use crate::parquet::utils::MetricsFinder;
 use crate::parquet::{create_data_batch, Scenario};
+use arrow::record_batch::RecordBatch;
 use arrow::util::pretty::pretty_format_batches;
 use arrow_schema::SchemaRef;
+use datafusion::assert_batches_eq;
 use datafusion::common::Result;
 use datafusion::datasource::listing::PartitionedFile;
-use datafusion::datasource::physical_plan::parquet::{ParquetAccessPlan, RowGroupAccess};
+use datafusion::datasource::physical_plan::parquet::{
+    ParquetAccessPlan, ParquetDeletionVector, RowGroupAccess,
+};
 use datafusion::datasource::physical_plan::{FileScanConfig, ParquetExec};
 use datafusion::prelude::SessionContext;
 use datafusion_common::{assert_contains, DFSchema};
@@ -244,6 +248,74 @@ async fn bad_selection() {
     assert_contains!(&err_string, "Internal error: Invalid ParquetAccessPlan Selection. Row group 0 has 5 rows but selection only specifies 4 rows");
 }
 
+#[tokio::test]
+async fn deletion_vector() {
+    // Row group 0 has rows 0-4 with values a-d and null, row group 1 has
+    // rows 5-9 with values e-i
+    let deletion_vector = ParquetDeletionVector::new([9, 1, 4, 5, 6, 7]);
+    let results = scan_with_extension(Arc::new(deletion_vector))
+        .await
+        .unwrap();
+
+    #[rustfmt::skip]
+    let expected = [
+        "+------+",
+        "| utf8 |",
+        "+------+",
+        "| a    |",
+        "| c    |",
+        "| d    |",
+        "| h    |",
+        "+------+",
+    ];
+    assert_batches_eq!(expected, &results);
+}
+
+#[tokio::test]
+async fn deletion_vector_and_access_plan() {
+    // the deleted rows are excluded from the row groups the plan scans
+    let access_plan =
+        ParquetAccessPlan::new(vec![RowGroupAccess::Skip, RowGroupAccess::Scan]);
+    let deletion_vector =
+        ParquetDeletionVector::new([0, 6]).with_access_plan(access_plan);
+    let results = scan_with_extension(Arc::new(deletion_vector))
+        .await
+        .unwrap();
+
+    #[rustfmt::skip]
+    let expected = [
+        "+------+",
+        "| utf8 |",
+        "+------+",
+        "| e    |",
+        "| g    |",
+        "| h    |",
+        "| i    |",
+        "+------+",
+    ];
+    assert_batches_eq!(expected, &results);
+}
+
+/// Read the test file with `extension` on its [`PartitionedFile`]
+async fn scan_with_extension(
+    extension: Arc<dyn std::any::Any + Send + Sync>,
+) -> Result<Vec<RecordBatch>> {
+    let ctx = SessionContext::new();
+    let TestData {
+        temp_file: _,
+        schema,
+        file_name,
+        file_size,
+    } = get_test_data();
+
+    let partitioned_file =
+        PartitionedFile::new(file_name, *file_size).with_extensions(extension);
+    let config = FileScanConfig::new(ObjectStoreUrl::local_filesystem(), schema.clone())
+        .with_file(partitioned_file);
+    let plan: Arc<dyn ExecutionPlan> = ParquetExec::builder(config).build_arc();
+    datafusion::physical_plan::collect(plan, ctx.task_ctx()).await
+}
+
 /// Return a RowSelection of 1 rows from a row group of 5 rows
 fn select_one_row() -> RowSelection {
     RowSelection::from(vec![
@@ -206,6 +206,40 @@ async fn prune_timestamps_seconds() {
         .await;
 }
 
+#[tokio::test]
+async fn prune_timestamps_between_strings() {
+    RowGroupPruningTest::new()
+        .with_scenario(Scenario::Timestamps)
+        .with_query(
+            "SELECT * FROM t where seconds BETWEEN '2020-01-11 00:00:00' AND '2020-01-12 00:00:00'",
+        )
+        .with_expected_errors(Some(0))
+        .with_matched_by_stats(Some(1))
+        .with_pruned_by_stats(Some(3))
+        .with_matched_by_bloom_filter(Some(0))
+        .with_pruned_by_bloom_filter(Some(0))
+        .with_expected_rows(3)
+        .test_row_group_prune()
+        .await;
+}
+
+#[tokio::test]
+async fn prune_timestamps_not_between_strings() {
+    RowGroupPruningTest::new()
+        .with_scenario(Scenario::Timestamps)
+        .with_query(
+            "SELECT * FROM t where millis NOT BETWEEN '2020-01-01 00:00:00' AND '2020-01-03 00:00:00'",
+        )
+        .with_expected_errors(Some(0))
+        .with_matched_by_stats(Some(1))
+        .with_pruned_by_stats(Some(3))
+        .with_matched_by_bloom_filter(Some(0))
+        .with_pruned_by_bloom_filter(Some(0))
+        .with_expected_rows(4)
+        .test_row_group_prune()
+        .await;
+}
+
 #[tokio::test]
 async fn prune_date32() {
     RowGroupPruningTest::new()
@@ -167,6 +167,33 @@ async fn explain_analyze_baseline_metrics() {
 fn nanos_from_timestamp(ts: &Timestamp) -> i64 {
     ts.value().unwrap().timestamp_nanos_opt().unwrap()
 }
+#[tokio::test]
+async fn graphviz_physical_plan_with_metrics() {
+    let config = SessionConfig::new().with_target_partitions(3);
+    let ctx = SessionContext::new_with_config(config);
+    register_aggregate_csv_by_sql(&ctx).await;
+    let sql = "SELECT c1, count(*) FROM aggregate_test_100 GROUP BY c1";
+    let dataframe = ctx.sql(sql).await.unwrap();
+    let physical_plan = dataframe.create_physical_plan().await.unwrap();
+
+    // before execution, operators are labelled with their partition counts
+    let graph = displayable(physical_plan.as_ref()).graphviz().to_string();
+    assert_contains!(&graph, "digraph {");
+    assert_contains!(&graph, "RepartitionExec: partitioning=Hash([c1@0], 3)");
+    assert_contains!(&graph, "input_partitions=3, partitions=3");
+
+    // after execution, the tooltips show the metrics
+    collect(physical_plan.clone(), ctx.task_ctx())
+        .await
+        .unwrap();
+    let graph = DisplayableExecutionPlan::with_metrics(physical_plan.as_ref())
+        .set_show_properties(true)
+        .graphviz()
+        .to_string();
+    assert_contains!(&graph, "tooltip=\"metrics=[output_rows=5, elapsed_compute=");
+    assert_contains!(&graph, "partitioning=Hash([c1@0], 3)\"]");
+}
+
 #[tokio::test]
 async fn csv_explain_plans() {
     // This test verify the look of each plan in its full cycle plan creation
@@ -764,6 +791,73 @@ async fn parquet_explain_analyze_verbose() {
     assert_contains!(&formatted, "row_groups_pruned_statistics{partition=0");
 }
 
+#[tokio::test]
+async fn memory_table_zone_maps_explain_analyze() {
+    let mut config = ConfigOptions::new();
+    config.execution.memory_table_zone_maps = true;
+    config.execution.target_partitions = 1;
+    let ctx = SessionContext::new_with_config(config.into());
+    // every statement adds a batch to the single partition of the table
+    for sql in [
+        "CREATE TABLE t(a INT) AS VALUES (1), (2)",
+        "INSERT INTO t VALUES (6), (7)",
+        "INSERT INTO t VALUES (3)",
+    ] {
+        execute_to_batches(&ctx, sql).await;
+    }
+
+    let sql = "EXPLAIN SELECT a FROM t WHERE a > 5";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let formatted = arrow::util::pretty::pretty_format_batches(&actual)
+        .unwrap()
+        .to_string();
+    assert_contains!(
+        &formatted,
+        "TableScan: t projection=[a], partial_filters=[t.a > Int32(5)]"
+    );
+
+    let sql = "EXPLAIN ANALYZE SELECT a FROM t WHERE a > 5";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let formatted = arrow::util::pretty::pretty_format_batches(&actual)
+        .unwrap()
+        .to_string();
+    assert_metrics!(
+        &formatted,
+        "MemoryExec: partitions=1, partition_sizes=[1]",
+        "metrics=[batches_pruned=2]"
+    );
+
+    let sql = "SELECT a FROM t WHERE a > 5 ORDER BY a";
+    let actual = execute_to_batches(&ctx, sql).await;
+    assert_batches_eq!(
+        ["+---+", "| a |", "+---+", "| 6 |", "| 7 |", "+---+"],
+        &actual
+    );
+}
+
+#[tokio::test]
+async fn explain_analyze_sampled_metrics() {
+    let config = SessionConfig::new()
+        .with_target_partitions(1)
+        .with_batch_size(10)
+        .with_metrics_sampling_interval(4);
+    let ctx = SessionContext::new_with_config(config);
+    register_aggregate_csv_by_sql(&ctx).await;
+    let sql = "EXPLAIN ANALYZE SELECT c1 FROM aggregate_test_100 \
+               WHERE c13 != 'C2GT5KVyOPZpgKVl110TyZO0NcJ434'";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let formatted = arrow::util::pretty::pretty_format_batches(&actual)
+        .unwrap()
+        .to_string();
+
+    // the row counts are exact, only the compute times are sampled
+    assert_metrics!(
+        &formatted,
+        "FilterExec: c13@1 != C2GT5KVyOPZpgKVl110TyZO0NcJ434",
+        "metrics=[output_rows=99, elapsed_compute="
+    );
+}
+
 #[tokio::test]
 #[cfg_attr(tarpaulin, ignore)]
 async fn csv_explain_analyze_verbose() {
@@ -27,6 +27,7 @@ use std::sync::{
 };
 
 use datafusion::datasource::MemTable;
+use datafusion::execution::FunctionRegistry;
 use datafusion::test_util::plan_and_collect;
 use datafusion::{
     arrow::{
@@ -45,8 +46,9 @@ use datafusion::{
 };
 use datafusion_common::{assert_contains, cast::as_primitive_array, exec_err};
 use datafusion_expr::{
-    create_udaf, function::AccumulatorArgs, AggregateUDFImpl, GroupsAccumulator,
-    SimpleAggregateUDF,
+    create_udaf,
+    function::{AccumulatorArgs, StateFieldsArgs},
+    AggregateUDFImpl, GroupsAccumulator, SimpleAggregateUDF,
 };
 use datafusion_functions_aggregate::average::AvgAccumulator;
 
@@ -145,6 +147,35 @@ async fn test_udaf_as_window_with_frame_without_retract_batch() {
     assert_contains!(err.to_string(), "This feature is not implemented: Aggregate can not be used as a sliding accumulator because `retract_batch` is not implemented: time_sum(t.time) ORDER BY [t.time ASC NULLS LAST] ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING");
 }
 
+/// User defined aggregate used as a window function with a window frame, that
+/// does not implement retract_batch but opts in to recomputing every window
+#[tokio::test]
+async fn test_udaf_as_window_with_frame_recompute_sliding_windows() {
+    let test_state = Arc::new(TestState::new().with_error_on_retract_batch());
+
+    let TestContext { ctx, test_state } = TestContext::new_with_test_state(test_state);
+    let time_sum = ctx.udaf("time_sum").unwrap().as_ref().clone();
+    ctx.register_udaf(AggregateUDF::from(RecomputeSlidingWindows {
+        inner: time_sum,
+    }));
+
+    let sql = "SELECT time_sum(time) OVER(ORDER BY time ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) as time_sum from t";
+    let expected = [
+        "+----------------------------+",
+        "| time_sum                   |",
+        "+----------------------------+",
+        "| 1970-01-01T00:00:00.000005 |",
+        "| 1970-01-01T00:00:00.000009 |",
+        "| 1970-01-01T00:00:00.000012 |",
+        "| 1970-01-01T00:00:00.000014 |",
+        "| 1970-01-01T00:00:00.000010 |",
+        "+----------------------------+",
+    ];
+    assert_batches_eq!(expected, &execute(&ctx, sql).await.unwrap());
+    assert!(test_state.update_batch());
+    assert!(!test_state.retract_batch());
+}
+
 /// Basic query for with a udaf returning a structure
 #[tokio::test]
 async fn test_udaf_returning_struct() {
@@ -697,6 +728,42 @@ impl Accumulator for FirstSelector {
     }
 }
 
+/// Wraps an aggregate UDF, opting in to recomputing sliding windows
+#[derive(Debug)]
+struct RecomputeSlidingWindows {
+    inner: AggregateUDF,
+}
+
+impl AggregateUDFImpl for RecomputeSlidingWindows {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn signature(&self) -> &Signature {
+        self.inner.signature()
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        self.inner.return_type(arg_types)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        self.inner.accumulator(acc_args)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        self.inner.state_fields(args)
+    }
+
+    fn recompute_sliding_windows(&self) -> bool {
+        true
+    }
+}
+
 #[derive(Debug, Clone)]
 struct TestGroupsAccumulator {
     signature: Signature,
@@ -247,6 +247,123 @@ async fn test_row_mismatch_error_in_scalar_udf() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn scalar_udf_scalar_args() -> Result<()> {
+    let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+
+    let batch = RecordBatch::try_new(
+        Arc::new(schema.clone()),
+        vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+    )?;
+
+    let ctx = SessionContext::new();
+
+    ctx.register_batch("t", batch)?;
+
+    // udf that expects a scalar argument and returns a single row
+    let plus_one = Arc::new(|args: &[ColumnarValue]| {
+        let ColumnarValue::Scalar(ScalarValue::Int32(Some(v))) = &args[0] else {
+            return internal_err!("Expected a scalar argument, got {:?}", args[0]);
+        };
+        Ok(ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+            v + 1,
+        ]))))
+    });
+
+    // volatile, so that the call is not simplified to a constant
+    ctx.register_udf(create_udf(
+        "plus_one",
+        vec![DataType::Int32],
+        Arc::new(DataType::Int32),
+        Volatility::Volatile,
+        plus_one,
+    ));
+
+    let result = plan_and_collect(&ctx, "select a, plus_one(1) b from t").await?;
+    let expected = [
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | 2 |",
+        "| 2 | 2 |",
+        "| 3 | 2 |",
+        "+---+---+",
+    ];
+    assert_batches_eq!(expected, &result);
+    Ok(())
+}
+
+/// A function that returns the number of rows of the batch it is invoked on
+#[derive(Debug)]
+struct BatchSizeUDF {
+    signature: Signature,
+}
+
+impl ScalarUDFImpl for BatchSizeUDF {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "batch_size"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int32)
+    }
+
+    fn invoke(&self, _args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        not_impl_err!("{} must be invoked with the number of rows", self.name())
+    }
+
+    fn invoke_batch(
+        &self,
+        args: &[ColumnarValue],
+        number_rows: usize,
+    ) -> Result<ColumnarValue> {
+        assert!(matches!(args[0], ColumnarValue::Scalar(_)));
+        Ok(ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+            number_rows as i32;
+            number_rows
+        ]))))
+    }
+}
+
+#[tokio::test]
+async fn scalar_udf_invoke_batch() -> Result<()> {
+    let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+
+    let batch = RecordBatch::try_new(
+        Arc::new(schema.clone()),
+        vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+    )?;
+
+    let ctx = SessionContext::new();
+
+    ctx.register_batch("t", batch)?;
+
+    ctx.register_udf(ScalarUDF::from(BatchSizeUDF {
+        signature: Signature::exact(vec![DataType::Int32], Volatility::Volatile),
+    }));
+
+    let result = plan_and_collect(&ctx, "select a, batch_size(0) b from t").await?;
+    let expected = [
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | 3 |",
+        "| 2 | 3 |",
+        "| 3 | 3 |",
+        "+---+---+",
+    ];
+    assert_batches_eq!(expected, &result);
+    Ok(())
+}
+
 #[tokio::test]
 async fn scalar_udf_zero_params() -> Result<()> {
     let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
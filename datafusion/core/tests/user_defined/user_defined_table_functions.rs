@@ -90,6 +90,40 @@ async fn test_simple_read_csv_udtf() -> Result<()> {
     Ok(())
 }
 
+/// test that the arguments of a udtf are evaluated before the call, and that
+/// the function name is normalized and qualifies the columns
+#[tokio::test]
+async fn test_udtf_constant_arguments() -> Result<()> {
+    let ctx = SessionContext::new();
+
+    ctx.register_udtf("read_csv", Arc::new(SimpleCsvTableFunc {}));
+
+    let rbs = ctx
+        .sql(
+            "SELECT read_csv.n_name FROM READ_CSV('tests/tpch-csv/' || 'nation.csv', 1 + 2)",
+        )
+        .await?
+        .collect()
+        .await?;
+    let expected = [
+        "+-----------+",
+        "| n_name    |",
+        "+-----------+",
+        "| ARGENTINA |",
+        "| BRAZIL    |",
+        "| CANADA    |",
+        "+-----------+",
+    ];
+    assert_batches_eq!(expected, &rbs);
+
+    let err = ctx.sql("SELECT * FROM read_csv(*)").await.unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Unsupported function argument type"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_deregister_udtf() -> Result<()> {
     let ctx = SessionContext::new();
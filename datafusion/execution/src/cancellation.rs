@@ -0,0 +1,259 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CancellationToken`] for cooperatively cancelling a running query
+
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+use std::task::{Context, Poll};
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef;
+use datafusion_common::{exec_err, Result};
+use futures::task::AtomicWaker;
+use futures::Stream;
+use parking_lot::Mutex;
+
+use crate::{RecordBatchStream, SendableRecordBatchStream};
+
+/// A token used to cancel the execution of a query from another task or
+/// thread.
+///
+/// Clones of a token share the same state: once [`Self::cancel`] is called
+/// on any clone, all of them report [`Self::is_cancelled`].
+///
+/// A token is attached to a query via [`TaskContext::with_cancellation_token`].
+/// Operators that may run for a long time without yielding a batch (such as
+/// joins, aggregates and sorts) check the token between input batches via
+/// [`Self::check`], and the stream returned to the user is wrapped in a
+/// [`CancellableStream`] so that a query waiting on its input also stops
+/// promptly. Dropping the stream then stops the remaining operators.
+///
+/// [`TaskContext::with_cancellation_token`]: crate::TaskContext::with_cancellation_token
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    /// The wakers of the [`CancellableStream`]s using this token
+    wakers: Mutex<Vec<Weak<AtomicWaker>>>,
+    /// The tokens created by [`CancellationToken::child_token`]
+    children: Mutex<Vec<Weak<Inner>>>,
+}
+
+impl CancellationToken {
+    /// Create a new token that is not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the query this token is attached to.
+    ///
+    /// Calling this more than once has no further effect.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Release);
+        for waker in self.inner.wakers.lock().drain(..) {
+            if let Some(waker) = waker.upgrade() {
+                waker.wake();
+            }
+        }
+        for child in self.inner.children.lock().drain(..) {
+            if let Some(inner) = child.upgrade() {
+                Self { inner }.cancel();
+            }
+        }
+    }
+
+    /// Create a new token that is cancelled when this token is cancelled,
+    /// but that can also be cancelled on its own without affecting this
+    /// token.
+    ///
+    /// This is used to cancel a single query, for example when it times out,
+    /// while still honoring the cancellation of its session.
+    pub fn child_token(&self) -> Self {
+        let child = Self::new();
+        {
+            let mut children = self.inner.children.lock();
+            // forget the children that have been dropped
+            children.retain(|c| c.strong_count() > 0);
+            children.push(Arc::downgrade(&child.inner));
+        }
+        // the parent may have been cancelled before the child was registered
+        if self.is_cancelled() {
+            child.cancel();
+        }
+        child
+    }
+
+    /// Returns `true` if [`Self::cancel`] has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns an error if [`Self::cancel`] has been called
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            exec_err!("Query was cancelled")
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Register a waker to wake when this token is cancelled
+    fn register(&self) -> Arc<AtomicWaker> {
+        let waker = Arc::new(AtomicWaker::new());
+        let mut wakers = self.inner.wakers.lock();
+        // forget the streams that have been dropped
+        wakers.retain(|w| w.strong_count() > 0);
+        wakers.push(Arc::downgrade(&waker));
+        waker
+    }
+}
+
+/// A [`RecordBatchStream`] that ends with an error as soon as its
+/// [`CancellationToken`] is cancelled, even if the inner stream is waiting on
+/// its input.
+///
+/// The inner stream is dropped when the cancellation is observed, releasing
+/// the resources of the execution.
+pub struct CancellableStream {
+    schema: SchemaRef,
+    /// The inner stream, or `None` once the cancellation has been reported
+    inner: Option<SendableRecordBatchStream>,
+    token: CancellationToken,
+    waker: Arc<AtomicWaker>,
+}
+
+impl CancellableStream {
+    /// Wrap `inner` so that it is stopped when `token` is cancelled
+    pub fn new(inner: SendableRecordBatchStream, token: CancellationToken) -> Self {
+        Self {
+            schema: inner.schema(),
+            inner: Some(inner),
+            waker: token.register(),
+            token,
+        }
+    }
+}
+
+impl Stream for CancellableStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let Some(inner) = this.inner.as_mut() else {
+            return Poll::Ready(None);
+        };
+        // register before checking, so a concurrent `cancel` is not missed
+        this.waker.register(cx.waker());
+        if let Err(e) = this.token.check() {
+            this.inner = None;
+            return Poll::Ready(Some(Err(e)));
+        }
+        inner.as_mut().poll_next(cx)
+    }
+}
+
+impl RecordBatchStream for CancellableStream {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::datatypes::Schema;
+    use futures::StreamExt;
+
+    /// A stream that never produces a batch
+    struct PendingStream(SchemaRef);
+
+    impl Stream for PendingStream {
+        type Item = Result<RecordBatch>;
+
+        fn poll_next(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            Poll::Pending
+        }
+    }
+
+    impl RecordBatchStream for PendingStream {
+        fn schema(&self) -> SchemaRef {
+            Arc::clone(&self.0)
+        }
+    }
+
+    #[test]
+    fn cancel_token() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        assert!(clone.check().is_ok());
+
+        token.cancel();
+        assert!(clone.is_cancelled());
+        assert_eq!(
+            clone.check().unwrap_err().strip_backtrace(),
+            "Execution error: Query was cancelled"
+        );
+    }
+
+    #[test]
+    fn cancel_child_token() {
+        let parent = CancellationToken::new();
+        let child = parent.child_token();
+        child.cancel();
+        assert!(child.is_cancelled());
+        assert!(!parent.is_cancelled());
+
+        let child = parent.child_token();
+        parent.cancel();
+        assert!(child.is_cancelled());
+        assert!(parent.child_token().is_cancelled());
+    }
+
+    #[test]
+    fn cancel_pending_stream() {
+        let token = CancellationToken::new();
+        let inner = PendingStream(Arc::new(Schema::empty()));
+        let mut stream = CancellableStream::new(Box::pin(inner), token.clone());
+
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+        token.cancel();
+        match stream.poll_next_unpin(&mut cx) {
+            Poll::Ready(Some(Err(e))) => {
+                assert_eq!(e.strip_backtrace(), "Execution error: Query was cancelled")
+            }
+            other => panic!("unexpected poll result: {other:?}"),
+        }
+        assert!(matches!(stream.poll_next_unpin(&mut cx), Poll::Ready(None)));
+    }
+}
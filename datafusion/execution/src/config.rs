@@ -20,10 +20,11 @@ use std::{
     collections::HashMap,
     hash::{BuildHasherDefault, Hasher},
     sync::Arc,
+    time::Duration,
 };
 
 use datafusion_common::{
-    config::{ConfigExtension, ConfigOptions},
+    config::{ConfigExtension, ConfigOptions, QueryMemoryPolicy},
     Result, ScalarValue,
 };
 
@@ -200,6 +201,60 @@ impl SessionConfig {
         self
     }
 
+    /// Abort the queries that run for longer than `timeout` with a
+    /// [`DataFusionError::ExecutionTimeout`] error
+    ///
+    /// [`DataFusionError::ExecutionTimeout`]: datafusion_common::DataFusionError::ExecutionTimeout
+    pub fn with_query_timeout(mut self, timeout: Duration) -> Self {
+        self.options.execution.query_timeout_ms =
+            Some(u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX));
+        self
+    }
+
+    /// Limit the memory reserved by the operators of each query to `limit`
+    /// bytes, shared between them according to `policy`
+    ///
+    /// See [`QueryMemoryPool`] for details
+    ///
+    /// [`QueryMemoryPool`]: crate::memory_pool::QueryMemoryPool
+    pub fn with_query_memory_limit(
+        mut self,
+        limit: usize,
+        policy: QueryMemoryPolicy,
+    ) -> Self {
+        self.options.execution.query_memory_limit = Some(limit);
+        self.options.execution.query_memory_policy = policy;
+        self
+    }
+
+    /// Limit the disk space used by the temporary files spilled by each query
+    /// to `limit` bytes, in addition to the limit of the [`DiskManager`] of
+    /// the runtime.
+    ///
+    /// [`DiskManager`]: crate::disk_manager::DiskManager
+    pub fn with_query_spill_limit(mut self, limit: u64) -> Self {
+        self.options.execution.query_spill_limit = Some(limit);
+        self
+    }
+
+    /// Enables or disables the checksums of the temporary files spilled to
+    /// disk, see [`DiskManager::with_checksums`].
+    ///
+    /// [`DiskManager::with_checksums`]: crate::disk_manager::DiskManager::with_checksums
+    pub fn with_spill_checksums(mut self, enabled: bool) -> Self {
+        self.options.execution.spill_checksums = enabled;
+        self
+    }
+
+    /// Time only one in `interval` batches for the compute time metrics of
+    /// the operators, see [`Self::metrics_sampling_interval`]
+    pub fn with_metrics_sampling_interval(mut self, interval: usize) -> Self {
+        // sampling interval must be greater than zero
+        assert!(interval > 0);
+        self.options.execution.metrics_sampling_interval = interval;
+        self
+    }
+
     /// Insert new [ConfigExtension]
     pub fn with_option_extension<T: ConfigExtension>(mut self, extension: T) -> Self {
         self.options_mut().extensions.insert(extension);
@@ -383,6 +438,22 @@ impl SessionConfig {
         self.options.execution.batch_size
     }
 
+    /// Get the number of batches the operators time one batch of, for their
+    /// compute time metrics. 1 means that every batch is timed
+    pub fn metrics_sampling_interval(&self) -> usize {
+        self.options.execution.metrics_sampling_interval
+    }
+
+    /// Get the maximum wall-clock time a query may run, if any
+    ///
+    /// See [`Self::with_query_timeout`]
+    pub fn query_timeout(&self) -> Option<Duration> {
+        self.options
+            .execution
+            .query_timeout_ms
+            .map(Duration::from_millis)
+    }
+
     /// Get the currently configured scalar_update_factor for aggregate
     pub fn agg_scalar_update_factor(&self) -> usize {
         self.options.execution.aggregate.scalar_update_factor
@@ -18,14 +18,27 @@
 //! Manages files generated during query execution, files are
 //! hashed among the directories listed in RuntimeConfig::local_dirs.
 
+use crate::memory_pool::human_readable_size;
+#[cfg(feature = "spill_encryption")]
+use crate::spill_encryption::{Decryptor, Encryptor};
 use datafusion_common::{DataFusionError, Result};
 use log::debug;
 use parking_lot::Mutex;
 use rand::{thread_rng, Rng};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tempfile::{Builder, NamedTempFile, TempDir};
 
+#[cfg(feature = "spill_encryption")]
+pub use crate::spill_encryption::SpillEncryptionKey;
+
+/// The maximum length of the prefix of the names of temporary files, see
+/// [`DiskManager::with_tmp_file_prefix`]
+const MAX_TMP_FILE_PREFIX_LEN: usize = 128;
+
 /// Configuration for temporary disk access
 #[derive(Debug, Clone)]
 pub enum DiskManagerConfig {
@@ -69,13 +82,29 @@ impl DiskManagerConfig {
 
 /// Manages files generated during query execution, e.g. spill files generated
 /// while processing dataset larger than available memory.
+///
+/// The total size of the temporary files can be limited with
+/// [`Self::with_max_temp_directory_size`], their content can be encrypted
+/// with `with_encryption_key` (with the `spill_encryption` feature), and
+/// verified with checksums when read back with [`Self::with_checksums`].
 #[derive(Debug)]
 pub struct DiskManager {
     /// TempDirs to put temporary files in.
     ///
     /// If `Some(vec![])` a new OS specified temporary directory will be created
     /// If `None` an error will be returned (configured not to spill)
-    local_dirs: Mutex<Option<Vec<Arc<TempDir>>>>,
+    ///
+    /// Shared with the disk managers derived from this one.
+    local_dirs: Arc<Mutex<Option<Vec<Arc<TempDir>>>>>,
+    /// The disk space used by the temporary files of this disk manager
+    disk_usage: Arc<DiskUsage>,
+    /// The key the temporary files are encrypted with, if any
+    #[cfg(feature = "spill_encryption")]
+    encryption_key: Option<SpillEncryptionKey>,
+    /// Should the temporary files be written with checksums
+    checksums: bool,
+    /// The prefix of the names of the temporary files, if any
+    tmp_file_prefix: Option<String>,
 }
 
 impl DiskManager {
@@ -83,25 +112,137 @@ impl DiskManager {
     pub fn try_new(config: DiskManagerConfig) -> Result<Arc<Self>> {
         match config {
             DiskManagerConfig::Existing(manager) => Ok(manager),
-            DiskManagerConfig::NewOs => Ok(Arc::new(Self {
-                local_dirs: Mutex::new(Some(vec![])),
-            })),
+            DiskManagerConfig::NewOs => Ok(Arc::new(Self::new(Some(vec![])))),
             DiskManagerConfig::NewSpecified(conf_dirs) => {
                 let local_dirs = create_local_dirs(conf_dirs)?;
                 debug!(
                     "Created local dirs {:?} as DataFusion working directory",
                     local_dirs
                 );
-                Ok(Arc::new(Self {
-                    local_dirs: Mutex::new(Some(local_dirs)),
-                }))
+                Ok(Arc::new(Self::new(Some(local_dirs))))
             }
-            DiskManagerConfig::Disabled => Ok(Arc::new(Self {
-                local_dirs: Mutex::new(None),
-            })),
+            DiskManagerConfig::Disabled => Ok(Arc::new(Self::new(None))),
+        }
+    }
+
+    fn new(local_dirs: Option<Vec<Arc<TempDir>>>) -> Self {
+        Self {
+            local_dirs: Arc::new(Mutex::new(local_dirs)),
+            disk_usage: Arc::new(DiskUsage::new(None, None)),
+            #[cfg(feature = "spill_encryption")]
+            encryption_key: None,
+            checksums: false,
+            tmp_file_prefix: None,
         }
     }
 
+    /// Returns a new [`DiskManager`] that creates temporary files in the same
+    /// directories as this one, and returns an error once the temporary files
+    /// it created use more than `max_temp_directory_size` bytes of disk space.
+    ///
+    /// The files also count towards the limits of this disk manager. This can
+    /// be used to limit the disk space used by a single query.
+    pub fn with_max_temp_directory_size(
+        self: &Arc<Self>,
+        max_temp_directory_size: u64,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            local_dirs: Arc::clone(&self.local_dirs),
+            disk_usage: Arc::new(DiskUsage::new(
+                Some(max_temp_directory_size),
+                Some(Arc::clone(&self.disk_usage)),
+            )),
+            #[cfg(feature = "spill_encryption")]
+            encryption_key: self.encryption_key.clone(),
+            checksums: self.checksums,
+            tmp_file_prefix: self.tmp_file_prefix.clone(),
+        })
+    }
+
+    /// Returns a new [`DiskManager`] that creates temporary files in the same
+    /// directories as this one, and transparently encrypts their content with
+    /// `key`.
+    ///
+    /// See [`SpillEncryptionKey`] for more details.
+    #[cfg(feature = "spill_encryption")]
+    pub fn with_encryption_key(self: &Arc<Self>, key: SpillEncryptionKey) -> Arc<Self> {
+        Arc::new(Self {
+            local_dirs: Arc::clone(&self.local_dirs),
+            disk_usage: Arc::new(DiskUsage::new(
+                None,
+                Some(Arc::clone(&self.disk_usage)),
+            )),
+            encryption_key: Some(key),
+            checksums: self.checksums,
+            tmp_file_prefix: self.tmp_file_prefix.clone(),
+        })
+    }
+
+    /// Returns a new [`DiskManager`] that creates temporary files in the same
+    /// directories as this one, whose data should be written with checksums
+    /// that are verified when it is read back.
+    ///
+    /// See [`RefCountedTempFile::checksums`].
+    pub fn with_checksums(self: &Arc<Self>) -> Arc<Self> {
+        Arc::new(Self {
+            local_dirs: Arc::clone(&self.local_dirs),
+            disk_usage: Arc::new(DiskUsage::new(
+                None,
+                Some(Arc::clone(&self.disk_usage)),
+            )),
+            #[cfg(feature = "spill_encryption")]
+            encryption_key: self.encryption_key.clone(),
+            checksums: true,
+            tmp_file_prefix: self.tmp_file_prefix.clone(),
+        })
+    }
+
+    /// Returns a new [`DiskManager`] that creates temporary files in the same
+    /// directories as this one, whose names start with `prefix`.
+    ///
+    /// This can be used to tell which query the temporary files belong to,
+    /// e.g. by prefixing them with the [`TaskContext::task_id`]. As the
+    /// prefix may come from the user, the characters other than ASCII
+    /// letters, digits, `-` and `_` are replaced with `_`, so that the files
+    /// can not be created outside of the temporary directories, and it is
+    /// truncated to 128 characters.
+    ///
+    /// [`TaskContext::task_id`]: crate::TaskContext::task_id
+    pub fn with_tmp_file_prefix(
+        self: &Arc<Self>,
+        prefix: impl Into<String>,
+    ) -> Arc<Self> {
+        let prefix = prefix
+            .into()
+            .chars()
+            .take(MAX_TMP_FILE_PREFIX_LEN)
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        Arc::new(Self {
+            local_dirs: Arc::clone(&self.local_dirs),
+            disk_usage: Arc::new(DiskUsage::new(
+                None,
+                Some(Arc::clone(&self.disk_usage)),
+            )),
+            #[cfg(feature = "spill_encryption")]
+            encryption_key: self.encryption_key.clone(),
+            checksums: self.checksums,
+            tmp_file_prefix: Some(prefix),
+        })
+    }
+
+    /// Returns the disk space used by the temporary files created by this
+    /// disk manager, as recorded by their [`TempFileWriter`]s
+    pub fn used_disk_space(&self) -> u64 {
+        self.disk_usage.used.load(Ordering::Relaxed)
+    }
+
     /// Return true if this disk manager supports creating temporary
     /// files. If this returns false, any call to `create_tmp_file`
     /// will error.
@@ -138,17 +279,88 @@ impl DiskManager {
         }
 
         let dir_index = thread_rng().gen_range(0..local_dirs.len());
+        let mut builder = Builder::new();
+        if let Some(prefix) = &self.tmp_file_prefix {
+            builder.prefix(prefix);
+        }
+        let tempfile = builder
+            .tempfile_in(local_dirs[dir_index].as_ref())
+            .map_err(DataFusionError::IoError)?;
+        debug!(
+            "Created temporary file '{}' for {}",
+            tempfile.path().to_string_lossy(),
+            request_description,
+        );
+
         Ok(RefCountedTempFile {
             parent_temp_dir: local_dirs[dir_index].clone(),
-            tempfile: Builder::new()
-                .tempfile_in(local_dirs[dir_index].as_ref())
-                .map_err(DataFusionError::IoError)?,
+            tempfile,
+            disk_usage: Arc::new(TempFileDiskUsage {
+                disk_usage: Arc::clone(&self.disk_usage),
+                size: AtomicU64::new(0),
+            }),
+            #[cfg(feature = "spill_encryption")]
+            encryption_key: self.encryption_key.clone(),
+            checksums: self.checksums,
         })
     }
 }
 
+/// Tracks the disk space used by the temporary files of a [`DiskManager`],
+/// and of the disk managers it was derived from
+#[derive(Debug)]
+struct DiskUsage {
+    used: AtomicU64,
+    limit: Option<u64>,
+    parent: Option<Arc<DiskUsage>>,
+}
+
+impl DiskUsage {
+    fn new(limit: Option<u64>, parent: Option<Arc<DiskUsage>>) -> Self {
+        Self {
+            used: AtomicU64::new(0),
+            limit,
+            parent,
+        }
+    }
+
+    /// Records `bytes` more bytes of disk usage, returning an error without
+    /// recording anything if a limit would be exceeded
+    fn grow(&self, bytes: u64) -> Result<()> {
+        let used = self.used.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        if let Some(limit) = self.limit.filter(|limit| used > *limit) {
+            self.used.fetch_sub(bytes, Ordering::Relaxed);
+            return Err(DataFusionError::ResourcesExhausted(format!(
+                "The used disk space during the spilling process has exceeded the \
+                 allowable limit of {}",
+                human_readable_size(limit as usize)
+            )));
+        }
+        if let Some(parent) = &self.parent {
+            if let Err(e) = parent.grow(bytes) {
+                self.used.fetch_sub(bytes, Ordering::Relaxed);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Records that `bytes` bytes of disk space were released
+    fn shrink(&self, bytes: u64) {
+        self.used.fetch_sub(bytes, Ordering::Relaxed);
+        if let Some(parent) = &self.parent {
+            parent.shrink(bytes);
+        }
+    }
+}
+
 /// A wrapper around a [`NamedTempFile`] that also contains
 /// a reference to its parent temporary directory
+///
+/// The file should be written with [`Self::writer`] and read with
+/// [`Self::reader`], which encrypt and decrypt its content if its
+/// [`DiskManager`] has an encryption key, and record the size of the file in
+/// the disk usage of its [`DiskManager`].
 #[derive(Debug)]
 pub struct RefCountedTempFile {
     /// The reference to the directory in which temporary files are created to ensure
@@ -156,6 +368,11 @@ pub struct RefCountedTempFile {
     #[allow(dead_code)]
     parent_temp_dir: Arc<TempDir>,
     tempfile: NamedTempFile,
+    /// The disk space used by this file
+    disk_usage: Arc<TempFileDiskUsage>,
+    #[cfg(feature = "spill_encryption")]
+    encryption_key: Option<SpillEncryptionKey>,
+    checksums: bool,
 }
 
 impl RefCountedTempFile {
@@ -166,6 +383,169 @@ impl RefCountedTempFile {
     pub fn inner(&self) -> &NamedTempFile {
         &self.tempfile
     }
+
+    /// Returns true if the data written to this file should include
+    /// checksums, verified when it is read back, to detect the corruption of
+    /// the file. The checksums are computed by the users of the file, who
+    /// know how the data is split into messages.
+    pub fn checksums(&self) -> bool {
+        self.checksums
+    }
+
+    /// Returns a writer that replaces the content of this file
+    ///
+    /// The bytes are recorded in the disk usage of the [`DiskManager`] before
+    /// they are written. Once the disk space limit of the [`DiskManager`], or
+    /// of one of the disk managers it was derived from, would be exceeded,
+    /// writing fails with an [`std::io::Error`] that wraps the
+    /// [`DataFusionError::ResourcesExhausted`] error.
+    pub fn writer(&self) -> Result<TempFileWriter> {
+        let file = BufWriter::new(File::create(self.path())?);
+        // the previous content of the file is gone
+        self.disk_usage.reset();
+        #[allow(unused_mut)]
+        let mut writer = TempFileWriter {
+            file,
+            disk_usage: Arc::clone(&self.disk_usage),
+            #[cfg(feature = "spill_encryption")]
+            encryptor: None,
+        };
+        #[cfg(feature = "spill_encryption")]
+        if let Some(key) = &self.encryption_key {
+            let encryptor = Encryptor::new(key);
+            writer.write_to_file(encryptor.nonce_prefix())?;
+            writer.encryptor = Some(encryptor);
+        }
+        Ok(writer)
+    }
+
+    /// Returns a reader of the content of this file
+    pub fn reader(&self) -> Result<TempFileReader> {
+        #[allow(unused_mut)]
+        let mut file = BufReader::new(File::open(self.path())?);
+        Ok(TempFileReader {
+            #[cfg(feature = "spill_encryption")]
+            decryptor: match &self.encryption_key {
+                Some(key) => Some(Decryptor::try_new(key, &mut file)?),
+                None => None,
+            },
+            file,
+        })
+    }
+}
+
+/// The disk space used by a [`RefCountedTempFile`], shared with its writers.
+/// It is released once the file and its writers are dropped.
+#[derive(Debug)]
+struct TempFileDiskUsage {
+    /// The disk usage of the [`DiskManager`] that created the file
+    disk_usage: Arc<DiskUsage>,
+    /// The size of the file, as recorded in `disk_usage`
+    size: AtomicU64,
+}
+
+impl TempFileDiskUsage {
+    /// Records that `bytes` more bytes are written to the file
+    fn grow(&self, bytes: u64) -> Result<()> {
+        self.disk_usage.grow(bytes)?;
+        self.size.fetch_add(bytes, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Releases the disk space recorded for the file
+    fn reset(&self) {
+        self.disk_usage.shrink(self.size.swap(0, Ordering::Relaxed));
+    }
+}
+
+impl Drop for TempFileDiskUsage {
+    fn drop(&mut self) {
+        self.reset();
+    }
+}
+
+/// Writes a [`RefCountedTempFile`], see [`RefCountedTempFile::writer`]
+///
+/// [`Self::finish`] must be called once all the data is written.
+pub struct TempFileWriter {
+    file: BufWriter<File>,
+    disk_usage: Arc<TempFileDiskUsage>,
+    #[cfg(feature = "spill_encryption")]
+    encryptor: Option<Encryptor>,
+}
+
+impl TempFileWriter {
+    /// Writes the remaining data and flushes the file
+    ///
+    /// An encrypted file ends with a chunk written by this method, and can
+    /// not be read back without it.
+    pub fn finish(mut self) -> Result<()> {
+        #[cfg(feature = "spill_encryption")]
+        if let Some(encryptor) = self.encryptor.as_mut() {
+            let chunk = encryptor.finish()?;
+            self.write_to_file(&chunk)?;
+        }
+        self.file.flush()?;
+        Ok(())
+    }
+
+    /// Writes `bytes` to the file, after recording them in its disk usage
+    fn write_to_file(&mut self, bytes: &[u8]) -> Result<()> {
+        self.disk_usage.grow(bytes.len() as u64)?;
+        self.file.write_all(bytes)?;
+        Ok(())
+    }
+}
+
+impl Write for TempFileWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        #[cfg(feature = "spill_encryption")]
+        if let Some(encryptor) = self.encryptor.as_mut() {
+            let (len, chunk) = encryptor.write(buf)?;
+            if let Some(chunk) = chunk {
+                self.write_to_file(&chunk)
+                    .map_err(|e| std::io::Error::new(ErrorKind::Other, e))?;
+            }
+            return Ok(len);
+        }
+        self.write_to_file(buf)
+            .map_err(|e| std::io::Error::new(ErrorKind::Other, e))?;
+        Ok(buf.len())
+    }
+
+    /// Flushes the file. The content of an encrypted file is only written
+    /// once a chunk is complete, or by [`TempFileWriter::finish`].
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Reads a [`RefCountedTempFile`], see [`RefCountedTempFile::reader`]
+pub struct TempFileReader {
+    file: BufReader<File>,
+    #[cfg(feature = "spill_encryption")]
+    decryptor: Option<Decryptor>,
+}
+
+impl Read for TempFileReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        #[cfg(feature = "spill_encryption")]
+        if let Some(decryptor) = self.decryptor.as_mut() {
+            return decryptor.read(&mut self.file, buf);
+        }
+        self.file.read(buf)
+    }
+}
+
+impl Seek for TempFileReader {
+    /// Seeks in the decrypted content of an encrypted file
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        #[cfg(feature = "spill_encryption")]
+        if let Some(decryptor) = self.decryptor.as_mut() {
+            return decryptor.seek(pos);
+        }
+        self.file.seek(pos)
+    }
 }
 
 /// Setup local dirs by creating one new dir in each of the given dirs
@@ -276,6 +656,145 @@ mod tests {
         assert!(found, "Can't find {file_path:?} in dirs: {dirs:?}");
     }
 
+    #[test]
+    fn test_tmp_file_prefix() -> Result<()> {
+        let dm = DiskManager::try_new(DiskManagerConfig::new())?;
+        let prefixed = dm.with_tmp_file_prefix("datafusion-query-1-");
+
+        let file = prefixed.create_tmp_file("Testing")?;
+        let file_name = file.path().file_name().unwrap().to_string_lossy();
+        assert!(file_name.starts_with("datafusion-query-1-"), "{file_name}");
+
+        // the files are created in the same directories
+        let local_dirs = local_dir_snapshot(&dm);
+        assert_path_in_dirs(file.path(), local_dirs.iter().map(|p| p.as_path()));
+
+        // the prefix is kept by the disk managers derived from this one
+        let file = prefixed
+            .with_max_temp_directory_size(100)
+            .create_tmp_file("Testing")?;
+        let file_name = file.path().file_name().unwrap().to_string_lossy();
+        assert!(file_name.starts_with("datafusion-query-1-"), "{file_name}");
+
+        // a prefix can not move the files out of the directories
+        let file = dm
+            .with_tmp_file_prefix("../../query/1-")
+            .create_tmp_file("Testing")?;
+        let file_name = file.path().file_name().unwrap().to_string_lossy();
+        assert!(file_name.starts_with("______query_1-"), "{file_name}");
+        assert_path_in_dirs(file.path(), local_dirs.iter().map(|p| p.as_path()));
+
+        // and is truncated
+        let file = dm
+            .with_tmp_file_prefix("q".repeat(1000))
+            .create_tmp_file("Testing")?;
+        let file_name = file.path().file_name().unwrap().to_string_lossy();
+        assert!(file_name.starts_with(&"q".repeat(128)), "{file_name}");
+        assert!(!file_name.starts_with(&"q".repeat(129)), "{file_name}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_temp_directory_size() -> Result<()> {
+        let dm = DiskManager::try_new(DiskManagerConfig::new())?;
+        let limited = dm.with_max_temp_directory_size(100);
+
+        let file1 = limited.create_tmp_file("Testing")?;
+        let mut writer1 = file1.writer()?;
+        writer1.write_all(&[0; 60])?;
+        assert_eq!(limited.used_disk_space(), 60);
+        assert_eq!(dm.used_disk_space(), 60);
+
+        // the limit is enforced while the file is written
+        let file2 = limited.create_tmp_file("Testing")?;
+        let mut writer2 = file2.writer()?;
+        writer2.write_all(&[0; 30])?;
+        let err = writer2.write_all(&[0; 30]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Resources exhausted: The used disk space during the spilling process has exceeded the allowable limit of 100.0 B"
+        );
+        assert_eq!(limited.used_disk_space(), 90);
+
+        // replacing the content of a file releases its disk space
+        let writer2 = file2.writer()?;
+        assert_eq!(limited.used_disk_space(), 60);
+
+        // the disk space is released when the files and their writers are
+        // dropped
+        writer1.finish()?;
+        drop(file1);
+        assert_eq!(limited.used_disk_space(), 0);
+        writer2.finish()?;
+        drop(file2);
+        assert_eq!(limited.used_disk_space(), 0);
+        assert_eq!(dm.used_disk_space(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "spill_encryption")]
+    fn test_encrypted_temp_file() -> Result<()> {
+        use crate::spill_encryption::{CHUNK_LEN, NONCE_PREFIX_LEN, TAG_LEN};
+
+        let dm = DiskManager::try_new(DiskManagerConfig::new())?
+            .with_encryption_key(SpillEncryptionKey::generate());
+        // three full chunks and a partial one
+        let data = (0..=255).cycle().take(200_000).collect::<Vec<u8>>();
+
+        let file = dm.create_tmp_file("Testing")?;
+        let mut writer = file.writer()?;
+        writer.write_all(&data)?;
+        writer.finish()?;
+
+        // the content on disk is encrypted
+        let raw = std::fs::read(file.path())?;
+        assert_eq!(raw.len(), NONCE_PREFIX_LEN + data.len() + 4 * TAG_LEN);
+        assert_eq!(dm.used_disk_space(), raw.len() as u64);
+        assert_ne!(&raw[NONCE_PREFIX_LEN..][..CHUNK_LEN], &data[..CHUNK_LEN]);
+
+        let mut read = vec![];
+        file.reader()?.read_to_end(&mut read)?;
+        assert_eq!(read, data);
+
+        // seeking keeps the content readable, also across chunks
+        let mut reader = file.reader()?;
+        assert_eq!(reader.seek(SeekFrom::End(-10))?, 199_990);
+        let mut tail = vec![];
+        reader.read_to_end(&mut tail)?;
+        assert_eq!(tail, data[199_990..]);
+        assert_eq!(reader.seek(SeekFrom::Start(60_000))?, 60_000);
+        let mut middle = vec![0; 100_000];
+        reader.read_exact(&mut middle)?;
+        assert_eq!(middle, data[60_000..160_000]);
+
+        // modifying the file is detected
+        let mut modified = raw.clone();
+        modified[NONCE_PREFIX_LEN + CHUNK_LEN + 100] ^= 1;
+        std::fs::write(file.path(), &modified)?;
+        let err = file.reader()?.read_to_end(&mut vec![]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // and so is dropping its last chunk
+        let truncated = &raw[..NONCE_PREFIX_LEN + 3 * (CHUNK_LEN + TAG_LEN)];
+        std::fs::write(file.path(), truncated)?;
+        let read = file
+            .reader()
+            .and_then(|mut reader| Ok(reader.read_to_end(&mut vec![])?));
+        assert!(read.is_err());
+
+        // or replacing it with the tag of an empty chunk
+        let mut replaced = truncated.to_vec();
+        replaced.extend_from_slice(&raw[raw.len() - TAG_LEN..]);
+        std::fs::write(file.path(), &replaced)?;
+        let err = file.reader()?.read_to_end(&mut vec![]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
     #[test]
     fn test_temp_file_still_alive_after_disk_manager_dropped() -> Result<()> {
         // Test for the case using OS arranged temporary directory
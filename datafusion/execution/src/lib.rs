@@ -18,11 +18,14 @@
 //! DataFusion execution configuration and runtime structures
 
 pub mod cache;
+pub mod cancellation;
 pub mod config;
 pub mod disk_manager;
 pub mod memory_pool;
 pub mod object_store;
 pub mod runtime_env;
+#[cfg(feature = "spill_encryption")]
+mod spill_encryption;
 mod stream;
 mod task;
 
@@ -32,6 +35,7 @@ pub mod registry {
     };
 }
 
+pub use cancellation::CancellationToken;
 pub use disk_manager::DiskManager;
 pub use registry::FunctionRegistry;
 pub use stream::{RecordBatchStream, SendableRecordBatchStream};
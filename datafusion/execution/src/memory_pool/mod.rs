@@ -82,6 +82,9 @@ pub use pool::*;
 ///
 /// * [`FairSpillPool`]: Limits memory usage to a fixed size, allocating memory
 /// to all spilling operators fairly
+///
+/// * [`QueryMemoryPool`]: Limits the memory usage of a single query, on top of
+/// a pool shared by all queries
 pub trait MemoryPool: Send + Sync + std::fmt::Debug {
     /// Registers a new [`MemoryConsumer`]
     ///
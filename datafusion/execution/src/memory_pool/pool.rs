@@ -16,10 +16,12 @@
 // under the License.
 
 use crate::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
+use datafusion_common::config::QueryMemoryPolicy;
 use datafusion_common::{DataFusionError, Result};
 use log::debug;
 use parking_lot::Mutex;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 /// A [`MemoryPool`] that enforces no limit
 #[derive(Debug, Default)]
@@ -231,6 +233,156 @@ impl MemoryPool for FairSpillPool {
     }
 }
 
+/// A [`MemoryPool`] that limits the memory reserved by the operators of a
+/// single query, on top of a parent pool that is shared by all the queries
+/// of a runtime.
+///
+/// Every reservation is also made in the parent pool, so the limits of both
+/// pools apply. Within the query, memory is shared between operators as
+/// configured by [`QueryMemoryPolicy`]:
+///
+/// * [`QueryMemoryPolicy::Fail`]: first come first served, like
+/// [`GreedyMemoryPool`]
+///
+/// * [`QueryMemoryPolicy::Spill`]: each spillable operator is limited to a
+/// fair share of the memory not used by unspillable operators, like
+/// [`FairSpillPool`]
+///
+/// The memory currently reserved by the query is returned by
+/// [`MemoryPool::reserved`], and the highest amount reserved during its
+/// execution by [`Self::peak_reserved`].
+#[derive(Debug)]
+pub struct QueryMemoryPool {
+    parent: Arc<dyn MemoryPool>,
+    /// The memory limit of the query
+    limit: usize,
+    policy: QueryMemoryPolicy,
+    state: Mutex<QueryMemoryPoolState>,
+}
+
+#[derive(Debug, Default)]
+struct QueryMemoryPoolState {
+    /// The number of consumers that can spill
+    num_spill: usize,
+    /// The total amount of memory reserved that can be spilled
+    spillable: usize,
+    /// The total amount of memory reserved by consumers that cannot spill
+    unspillable: usize,
+    /// The highest amount of memory reserved so far
+    peak: usize,
+}
+
+impl QueryMemoryPoolState {
+    fn add(&mut self, can_spill: bool, additional: usize) {
+        match can_spill {
+            true => self.spillable += additional,
+            false => self.unspillable += additional,
+        }
+        self.peak = self.peak.max(self.spillable + self.unspillable);
+    }
+}
+
+impl QueryMemoryPool {
+    /// Allocate up to `limit` bytes from `parent`, sharing them between
+    /// operators according to `policy`
+    pub fn new(
+        parent: Arc<dyn MemoryPool>,
+        limit: usize,
+        policy: QueryMemoryPolicy,
+    ) -> Self {
+        debug!("Created new QueryMemoryPool(limit={limit}, policy={policy})");
+        Self {
+            parent,
+            limit,
+            policy,
+            state: Mutex::new(QueryMemoryPoolState::default()),
+        }
+    }
+
+    /// Return the memory limit of the query
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Return the highest amount of memory reserved by the query so far
+    pub fn peak_reserved(&self) -> usize {
+        self.state.lock().peak
+    }
+}
+
+impl MemoryPool for QueryMemoryPool {
+    fn register(&self, consumer: &MemoryConsumer) {
+        if consumer.can_spill {
+            self.state.lock().num_spill += 1;
+        }
+        self.parent.register(consumer)
+    }
+
+    fn unregister(&self, consumer: &MemoryConsumer) {
+        if consumer.can_spill {
+            let mut state = self.state.lock();
+            state.num_spill = state.num_spill.checked_sub(1).unwrap();
+        }
+        self.parent.unregister(consumer)
+    }
+
+    fn grow(&self, reservation: &MemoryReservation, additional: usize) {
+        let can_spill = reservation.registration.consumer.can_spill;
+        self.state.lock().add(can_spill, additional);
+        self.parent.grow(reservation, additional)
+    }
+
+    fn shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        {
+            let mut state = self.state.lock();
+            match reservation.registration.consumer.can_spill {
+                true => state.spillable -= shrink,
+                false => state.unspillable -= shrink,
+            }
+        }
+        self.parent.shrink(reservation, shrink)
+    }
+
+    fn try_grow(&self, reservation: &MemoryReservation, additional: usize) -> Result<()> {
+        let can_spill = reservation.registration.consumer.can_spill;
+        let mut state = self.state.lock();
+
+        let (fits, available) = match (self.policy, can_spill) {
+            (QueryMemoryPolicy::Spill, true) => {
+                // No spiller may use more than their fraction of the memory
+                // available to spilling consumers
+                let spill_available = self.limit.saturating_sub(state.unspillable);
+                let available = spill_available
+                    .checked_div(state.num_spill)
+                    .unwrap_or(spill_available);
+                (reservation.size + additional <= available, available)
+            }
+            _ => {
+                let available = self
+                    .limit
+                    .saturating_sub(state.unspillable + state.spillable);
+                (additional <= available, available)
+            }
+        };
+        if !fits {
+            return Err(insufficient_capacity_err(
+                reservation,
+                additional,
+                available,
+            ));
+        }
+
+        self.parent.try_grow(reservation, additional)?;
+        state.add(can_spill, additional);
+        Ok(())
+    }
+
+    fn reserved(&self) -> usize {
+        let state = self.state.lock();
+        state.spillable + state.unspillable
+    }
+}
+
 fn insufficient_capacity_err(
     reservation: &MemoryReservation,
     additional: usize,
@@ -242,7 +394,6 @@ fn insufficient_capacity_err(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Arc;
 
     #[test]
     fn test_fair() {
@@ -310,4 +461,72 @@ mod tests {
         let err = r4.try_grow(30).unwrap_err().strip_backtrace();
         assert_eq!(err, "Resources exhausted: Failed to allocate additional 30 bytes for s4 with 0 bytes already allocated - maximum available is 20");
     }
+
+    #[test]
+    fn test_query_pool() {
+        let parent = Arc::new(GreedyMemoryPool::new(100)) as Arc<dyn MemoryPool>;
+        let q1 = Arc::new(QueryMemoryPool::new(
+            Arc::clone(&parent),
+            60,
+            QueryMemoryPolicy::Fail,
+        ));
+        let q2 = Arc::new(QueryMemoryPool::new(
+            Arc::clone(&parent),
+            60,
+            QueryMemoryPolicy::Fail,
+        ));
+        let (p1, p2) = (Arc::clone(&q1) as _, Arc::clone(&q2) as _);
+
+        let mut r1 = MemoryConsumer::new("r1").register(&p1);
+        r1.try_grow(50).unwrap();
+        assert_eq!(q1.reserved(), 50);
+        assert_eq!(parent.reserved(), 50);
+
+        // limited by the query
+        let err = r1.try_grow(20).unwrap_err().strip_backtrace();
+        assert_eq!(err, "Resources exhausted: Failed to allocate additional 20 bytes for r1 with 50 bytes already allocated - maximum available is 10");
+
+        // limited by the parent
+        let mut r2 = MemoryConsumer::new("r2").register(&p2);
+        let err = r2.try_grow(60).unwrap_err().strip_backtrace();
+        assert_eq!(err, "Resources exhausted: Failed to allocate additional 60 bytes for r2 with 0 bytes already allocated - maximum available is 50");
+        assert_eq!(q2.reserved(), 0);
+
+        r2.try_grow(50).unwrap();
+        assert_eq!(parent.reserved(), 100);
+
+        r1.shrink(40);
+        assert_eq!(q1.reserved(), 10);
+        assert_eq!(q1.peak_reserved(), 50);
+        assert_eq!(parent.reserved(), 60);
+    }
+
+    #[test]
+    fn test_query_pool_spill() {
+        let parent = Arc::new(UnboundedMemoryPool::default()) as Arc<dyn MemoryPool>;
+        let query = Arc::new(QueryMemoryPool::new(parent, 100, QueryMemoryPolicy::Spill))
+            as Arc<dyn MemoryPool>;
+
+        let mut r1 = MemoryConsumer::new("unspillable").register(&query);
+        r1.try_grow(20).unwrap();
+
+        let mut r2 = MemoryConsumer::new("r2")
+            .with_can_spill(true)
+            .register(&query);
+        let mut r3 = MemoryConsumer::new("r3")
+            .with_can_spill(true)
+            .register(&query);
+
+        // each spiller gets half of the remaining 80 bytes
+        r2.try_grow(40).unwrap();
+        let err = r3.try_grow(50).unwrap_err().strip_backtrace();
+        assert_eq!(err, "Resources exhausted: Failed to allocate additional 50 bytes for r3 with 0 bytes already allocated - maximum available is 40");
+        r3.try_grow(40).unwrap();
+        assert_eq!(query.reserved(), 100);
+
+        // once r3 is done, r2 can use all the memory
+        drop(r3);
+        r2.try_grow(40).unwrap();
+        assert_eq!(query.reserved(), 100);
+    }
 }
@@ -25,6 +25,8 @@ use crate::{
 };
 
 use crate::cache::cache_manager::{CacheManager, CacheManagerConfig};
+#[cfg(feature = "spill_encryption")]
+use crate::disk_manager::SpillEncryptionKey;
 use datafusion_common::{DataFusionError, Result};
 use object_store::ObjectStore;
 use std::fmt::{Debug, Formatter};
@@ -66,6 +68,9 @@ impl RuntimeEnv {
         let RuntimeConfig {
             memory_pool,
             disk_manager,
+            max_temp_directory_size,
+            #[cfg(feature = "spill_encryption")]
+            spill_encryption_key,
             cache_manager,
             object_store_registry,
         } = config;
@@ -73,9 +78,19 @@ impl RuntimeEnv {
         let memory_pool =
             memory_pool.unwrap_or_else(|| Arc::new(UnboundedMemoryPool::default()));
 
+        let mut disk_manager = DiskManager::try_new(disk_manager)?;
+        if let Some(max_temp_directory_size) = max_temp_directory_size {
+            disk_manager =
+                disk_manager.with_max_temp_directory_size(max_temp_directory_size);
+        }
+        #[cfg(feature = "spill_encryption")]
+        if let Some(key) = spill_encryption_key {
+            disk_manager = disk_manager.with_encryption_key(key);
+        }
+
         Ok(Self {
             memory_pool,
-            disk_manager: DiskManager::try_new(disk_manager)?,
+            disk_manager,
             cache_manager: CacheManager::try_new(&cache_manager)?,
             object_store_registry,
         })
@@ -151,6 +166,12 @@ impl Default for RuntimeEnv {
 pub struct RuntimeConfig {
     /// DiskManager to manage temporary disk file usage
     pub disk_manager: DiskManagerConfig,
+    /// The maximum disk space, in bytes, used by the temporary files of all
+    /// the queries. Unlimited if `None`
+    pub max_temp_directory_size: Option<u64>,
+    /// The key temporary files are encrypted with. Not encrypted if `None`
+    #[cfg(feature = "spill_encryption")]
+    pub spill_encryption_key: Option<SpillEncryptionKey>,
     /// [`MemoryPool`] from which to allocate memory
     ///
     /// Defaults to using an [`UnboundedMemoryPool`] if `None`
@@ -172,6 +193,9 @@ impl RuntimeConfig {
     pub fn new() -> Self {
         Self {
             disk_manager: Default::default(),
+            max_temp_directory_size: None,
+            #[cfg(feature = "spill_encryption")]
+            spill_encryption_key: None,
             memory_pool: Default::default(),
             cache_manager: Default::default(),
             object_store_registry: Arc::new(DefaultObjectStoreRegistry::default()),
@@ -184,6 +208,20 @@ impl RuntimeConfig {
         self
     }
 
+    /// Limit the disk space used by temporary files to
+    /// `max_temp_directory_size` bytes. Spilling more data returns an error.
+    pub fn with_max_temp_directory_size(mut self, max_temp_directory_size: u64) -> Self {
+        self.max_temp_directory_size = Some(max_temp_directory_size);
+        self
+    }
+
+    /// Encrypt temporary files with `key`
+    #[cfg(feature = "spill_encryption")]
+    pub fn with_spill_encryption_key(mut self, key: SpillEncryptionKey) -> Self {
+        self.spill_encryption_key = Some(key);
+        self
+    }
+
     /// Customize memory policy
     pub fn with_memory_pool(mut self, memory_pool: Arc<dyn MemoryPool>) -> Self {
         self.memory_pool = Some(memory_pool);
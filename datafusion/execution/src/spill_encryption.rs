@@ -0,0 +1,280 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encryption of the temporary files of a [`DiskManager`], enabled by the
+//! `spill_encryption` feature
+//!
+//! [`DiskManager`]: crate::DiskManager
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use rand::{thread_rng, Rng};
+use std::fmt::{Debug, Formatter};
+use std::fs::File;
+use std::io::{BufReader, ErrorKind, Read, Seek, SeekFrom};
+
+/// The length of the random nonce prefix stored at the start of an encrypted
+/// file
+pub(crate) const NONCE_PREFIX_LEN: usize = 19;
+
+/// The length of the content of each encrypted chunk, except for the last one
+pub(crate) const CHUNK_LEN: usize = 64 * 1024;
+
+/// The length of the authentication tag of each encrypted chunk
+pub(crate) const TAG_LEN: usize = 16;
+
+/// A key used to encrypt the temporary files created by a
+/// [`DiskManager`], for environments where spilled data is sensitive.
+///
+/// The files are encrypted with XChaCha20-Poly1305 in chunks of 64 KiB,
+/// following the STREAM construction: the nonce of each chunk consists of a
+/// random prefix stored at the start of the file, the index of the chunk and
+/// a flag marking the last chunk. Reading a file fails if its content was
+/// modified, reordered or truncated.
+///
+/// [`DiskManager`]: crate::DiskManager
+#[derive(Clone)]
+pub struct SpillEncryptionKey([u8; 32]);
+
+impl SpillEncryptionKey {
+    /// Create a key from the given bytes
+    pub fn new(key: [u8; 32]) -> Self {
+        Self(key)
+    }
+
+    /// Create a random key. Files encrypted with it can only be read by the
+    /// process that created them, which is enough for spill files.
+    pub fn generate() -> Self {
+        Self(thread_rng().gen())
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(Key::from_slice(&self.0))
+    }
+}
+
+impl Debug for SpillEncryptionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // do not leak the key in logs
+        write!(f, "SpillEncryptionKey(..)")
+    }
+}
+
+/// Returns the nonce of the chunk `index` of an encrypted file
+fn chunk_nonce(prefix: &[u8; NONCE_PREFIX_LEN], index: u32, last: bool) -> XNonce {
+    let mut nonce = XNonce::default();
+    nonce[..NONCE_PREFIX_LEN].copy_from_slice(prefix);
+    nonce[NONCE_PREFIX_LEN..NONCE_PREFIX_LEN + 4].copy_from_slice(&index.to_be_bytes());
+    nonce[NONCE_PREFIX_LEN + 4] = last as u8;
+    nonce
+}
+
+/// Encrypts the chunks of a file, see [`SpillEncryptionKey`]
+pub(crate) struct Encryptor {
+    cipher: XChaCha20Poly1305,
+    nonce_prefix: [u8; NONCE_PREFIX_LEN],
+    /// The index of the next chunk
+    chunk_index: u32,
+    /// The content of the next chunk
+    buffer: Vec<u8>,
+}
+
+impl Encryptor {
+    /// Creates an encryptor with a random nonce prefix, which must be written
+    /// at the start of the file, see [`Self::nonce_prefix`]
+    pub(crate) fn new(key: &SpillEncryptionKey) -> Self {
+        Self {
+            cipher: key.cipher(),
+            nonce_prefix: thread_rng().gen(),
+            chunk_index: 0,
+            buffer: Vec::with_capacity(CHUNK_LEN),
+        }
+    }
+
+    pub(crate) fn nonce_prefix(&self) -> &[u8] {
+        &self.nonce_prefix
+    }
+
+    /// Buffers a prefix of `buf`, returning its length, and the encrypted
+    /// chunk to write to the file once the buffer is full
+    pub(crate) fn write(
+        &mut self,
+        buf: &[u8],
+    ) -> std::io::Result<(usize, Option<Vec<u8>>)> {
+        let len = buf.len().min(CHUNK_LEN - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..len]);
+        let chunk = if self.buffer.len() == CHUNK_LEN {
+            Some(self.seal_chunk(false)?)
+        } else {
+            None
+        };
+        Ok((len, chunk))
+    }
+
+    /// Encrypts the buffered content as the last chunk of the file
+    pub(crate) fn finish(&mut self) -> std::io::Result<Vec<u8>> {
+        self.seal_chunk(true)
+    }
+
+    /// Encrypts the buffered content as the next chunk
+    fn seal_chunk(&mut self, last: bool) -> std::io::Result<Vec<u8>> {
+        let nonce = chunk_nonce(&self.nonce_prefix, self.chunk_index, last);
+        let chunk = self
+            .cipher
+            .encrypt(&nonce, self.buffer.as_slice())
+            .map_err(|_| {
+                std::io::Error::new(
+                    ErrorKind::Other,
+                    "failed to encrypt a chunk of a temporary file",
+                )
+            })?;
+        self.buffer.clear();
+        self.chunk_index = self.chunk_index.checked_add(1).ok_or_else(|| {
+            std::io::Error::new(ErrorKind::Other, "encrypted temporary file too large")
+        })?;
+        Ok(chunk)
+    }
+}
+
+/// Decrypts the chunks of a file, see [`SpillEncryptionKey`]
+pub(crate) struct Decryptor {
+    cipher: XChaCha20Poly1305,
+    nonce_prefix: [u8; NONCE_PREFIX_LEN],
+    /// The number of chunks of the file
+    num_chunks: u64,
+    /// The length of the last chunk of the file, including its tag
+    last_chunk_len: u64,
+    /// The length of the decrypted content of the file
+    len: u64,
+    /// The position of the reader in the decrypted content
+    position: u64,
+    /// The index of the chunk in `buffer`, if any
+    chunk_index: Option<u64>,
+    /// The decrypted content of the chunk `chunk_index`
+    buffer: Vec<u8>,
+}
+
+impl Decryptor {
+    /// Creates a decryptor of `file`, reading its nonce prefix
+    pub(crate) fn try_new(
+        key: &SpillEncryptionKey,
+        file: &mut BufReader<File>,
+    ) -> std::io::Result<Self> {
+        let file_len = file.get_ref().metadata()?.len();
+        let mut nonce_prefix = [0; NONCE_PREFIX_LEN];
+        file.read_exact(&mut nonce_prefix)?;
+
+        // all the chunks but the last one have the same length, and the last
+        // one is shorter, so their number follows from the length of the file
+        let encrypted_len = file_len - NONCE_PREFIX_LEN as u64;
+        let num_chunks = encrypted_len / (CHUNK_LEN + TAG_LEN) as u64 + 1;
+        let last_chunk_len = encrypted_len % (CHUNK_LEN + TAG_LEN) as u64;
+        if last_chunk_len < TAG_LEN as u64 {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "encrypted temporary file is truncated",
+            ));
+        }
+        Ok(Self {
+            cipher: key.cipher(),
+            nonce_prefix,
+            num_chunks,
+            last_chunk_len,
+            len: encrypted_len - num_chunks * TAG_LEN as u64,
+            position: 0,
+            chunk_index: None,
+            buffer: vec![],
+        })
+    }
+
+    /// Reads the decrypted content of `file` at the current position
+    pub(crate) fn read(
+        &mut self,
+        file: &mut BufReader<File>,
+        buf: &mut [u8],
+    ) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.position >= self.len {
+            // only the last chunk is marked as such, so it is authenticated
+            // even if it is empty, to detect that chunks were dropped
+            self.chunk(file, self.num_chunks - 1)?;
+            return Ok(0);
+        }
+        let index = self.position / CHUNK_LEN as u64;
+        let start = (self.position % CHUNK_LEN as u64) as usize;
+        let chunk = self.chunk(file, index)?;
+        let len = buf.len().min(chunk.len() - start);
+        buf[..len].copy_from_slice(&chunk[start..start + len]);
+        self.position += len as u64;
+        Ok(len)
+    }
+
+    /// Moves the current position, in the decrypted content
+    pub(crate) fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        self.position = position;
+        Ok(position)
+    }
+
+    /// Returns the decrypted content of the chunk `index` of `file`
+    fn chunk(
+        &mut self,
+        file: &mut BufReader<File>,
+        index: u64,
+    ) -> std::io::Result<&[u8]> {
+        if self.chunk_index == Some(index) {
+            return Ok(&self.buffer);
+        }
+        let last = index + 1 == self.num_chunks;
+        let chunk_len = if last {
+            self.last_chunk_len
+        } else {
+            (CHUNK_LEN + TAG_LEN) as u64
+        };
+        let offset = NONCE_PREFIX_LEN as u64 + index * (CHUNK_LEN + TAG_LEN) as u64;
+        file.seek(SeekFrom::Start(offset))?;
+        let mut encrypted = vec![0; chunk_len as usize];
+        file.read_exact(&mut encrypted)?;
+
+        let invalid = || {
+            std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("chunk {index} of the encrypted temporary file is invalid"),
+            )
+        };
+        let nonce_index = u32::try_from(index).map_err(|_| invalid())?;
+        let nonce = chunk_nonce(&self.nonce_prefix, nonce_index, last);
+        self.buffer = self
+            .cipher
+            .decrypt(&nonce, encrypted.as_slice())
+            .map_err(|_| invalid())?;
+        self.chunk_index = Some(index);
+        Ok(&self.buffer)
+    }
+}
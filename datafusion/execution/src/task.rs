@@ -24,6 +24,7 @@ use datafusion_common::{plan_datafusion_err, DataFusionError, Result};
 use datafusion_expr::{AggregateUDF, ScalarUDF, WindowUDF};
 
 use crate::{
+    cancellation::CancellationToken,
     config::SessionConfig,
     memory_pool::MemoryPool,
     registry::FunctionRegistry,
@@ -37,7 +38,7 @@ use crate::{
 /// multi-query API.
 ///
 /// [`SessionContext`]: https://docs.rs/datafusion/latest/datafusion/execution/context/struct.SessionContext.html
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TaskContext {
     /// Session Id
     session_id: String,
@@ -53,6 +54,8 @@ pub struct TaskContext {
     window_functions: HashMap<String, Arc<WindowUDF>>,
     /// Runtime environment associated with this task context
     runtime: Arc<RuntimeEnv>,
+    /// Token used to cancel the execution of this task
+    cancellation_token: CancellationToken,
 }
 
 impl Default for TaskContext {
@@ -69,6 +72,7 @@ impl Default for TaskContext {
             aggregate_functions: HashMap::new(),
             window_functions: HashMap::new(),
             runtime: Arc::new(runtime),
+            cancellation_token: CancellationToken::new(),
         }
     }
 }
@@ -96,6 +100,7 @@ impl TaskContext {
             aggregate_functions,
             window_functions,
             runtime,
+            cancellation_token: CancellationToken::new(),
         }
     }
 
@@ -110,6 +115,10 @@ impl TaskContext {
     }
 
     /// Return the `task_id` of this [TaskContext]
+    ///
+    /// The task contexts created from a `SessionState` get a unique id, which
+    /// is included in the names of the spill files and the trace logs of the
+    /// operators, so they can be correlated with the query that created them.
     pub fn task_id(&self) -> Option<String> {
         self.task_id.clone()
     }
@@ -124,6 +133,12 @@ impl TaskContext {
         self.runtime.clone()
     }
 
+    /// Return the [`CancellationToken`] of this [TaskContext]. Operators
+    /// check it to stop early when the query is cancelled.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
+    }
+
     /// Update the [`SessionConfig`]
     pub fn with_session_config(mut self, session_config: SessionConfig) -> Self {
         self.session_config = session_config;
@@ -135,6 +150,12 @@ impl TaskContext {
         self.runtime = runtime;
         self
     }
+
+    /// Update the [`CancellationToken`]
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = token;
+        self
+    }
 }
 
 impl FunctionRegistry for TaskContext {
@@ -207,6 +207,12 @@ pub trait Accumulator: Send + Sync + Debug {
     /// If this function returns true, [`Self::retract_batch`] will be
     /// called for sliding window functions such as queries with an
     /// `OVER (ROWS BETWEEN 1 PRECEDING AND 2 FOLLOWING)`
+    ///
+    /// Otherwise, such window functions return an error, unless the
+    /// aggregate opts in to computing every window from scratch with
+    /// [`recompute_sliding_windows`].
+    ///
+    /// [`recompute_sliding_windows`]: crate::AggregateUDFImpl::recompute_sliding_windows
     fn supports_retract_batch(&self) -> bool {
         false
     }
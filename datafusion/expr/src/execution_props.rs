@@ -18,6 +18,7 @@
 use crate::var_provider::{VarProvider, VarType};
 use chrono::{DateTime, TimeZone, Utc};
 use datafusion_common::alias::AliasGenerator;
+use datafusion_common::config::ArithmeticOverflowMode;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -37,6 +38,9 @@ pub struct ExecutionProps {
     pub alias_generator: Arc<AliasGenerator>,
     /// Providers for scalar variables
     pub var_providers: Option<HashMap<VarType, Arc<dyn VarProvider + Send + Sync>>>,
+    /// What integer arithmetic does on overflow, see
+    /// `datafusion.execution.arithmetic_overflow_mode`
+    pub arithmetic_overflow_mode: ArithmeticOverflowMode,
 }
 
 impl Default for ExecutionProps {
@@ -54,6 +58,7 @@ impl ExecutionProps {
             query_execution_start_time: Utc.timestamp_nanos(0),
             alias_generator: Arc::new(AliasGenerator::new()),
             var_providers: None,
+            arithmetic_overflow_mode: ArithmeticOverflowMode::Wrap,
         }
     }
 
@@ -66,6 +71,12 @@ impl ExecutionProps {
         self
     }
 
+    /// Set what integer arithmetic does on overflow
+    pub fn with_arithmetic_overflow_mode(mut self, mode: ArithmeticOverflowMode) -> Self {
+        self.arithmetic_overflow_mode = mode;
+        self
+    }
+
     /// Marks the execution of query started timestamp.
     /// This also instantiates a new alias generator.
     pub fn start_execution(&mut self) -> &Self {
@@ -107,6 +118,6 @@ mod test {
     #[test]
     fn debug() {
         let props = ExecutionProps::new();
-        assert_eq!("ExecutionProps { query_execution_start_time: 1970-01-01T00:00:00Z, alias_generator: AliasGenerator { next_id: 1 }, var_providers: None }", format!("{props:?}"));
+        assert_eq!("ExecutionProps { query_execution_start_time: 1970-01-01T00:00:00Z, alias_generator: AliasGenerator { next_id: 1 }, var_providers: None, arithmetic_overflow_mode: Wrap }", format!("{props:?}"));
     }
 }
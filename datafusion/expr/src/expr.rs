@@ -784,6 +784,9 @@ pub struct WindowFunction {
     pub window_frame: window_frame::WindowFrame,
     /// Specifies how NULL value is treated: ignore or respect
     pub null_treatment: Option<NullTreatment>,
+    /// Whether an aggregate function only aggregates the distinct values
+    /// of the window frame
+    pub distinct: bool,
 }
 
 impl WindowFunction {
@@ -803,8 +806,16 @@ impl WindowFunction {
             order_by,
             window_frame,
             null_treatment,
+            distinct: false,
         }
     }
+
+    /// Only aggregate the distinct values of the window frame, for aggregate
+    /// functions
+    pub fn with_distinct(mut self, distinct: bool) -> Self {
+        self.distinct = distinct;
+        self
+    }
 }
 
 /// Find DataFusion's built-in window function by name.
@@ -1122,6 +1133,60 @@ impl Expr {
         Expr::Like(Like::new(true, Box::new(self), Box::new(other), None, true))
     }
 
+    /// Return `self SIMILAR TO other`
+    pub fn similar_to(self, other: Expr) -> Expr {
+        Expr::SimilarTo(Like::new(
+            false,
+            Box::new(self),
+            Box::new(other),
+            None,
+            false,
+        ))
+    }
+
+    /// Return `self NOT SIMILAR TO other`
+    pub fn not_similar_to(self, other: Expr) -> Expr {
+        Expr::SimilarTo(Like::new(
+            true,
+            Box::new(self),
+            Box::new(other),
+            None,
+            false,
+        ))
+    }
+
+    /// Sets the escape character of a `LIKE`, `ILIKE` or `SIMILAR TO`
+    /// expression, returning other expressions unchanged.
+    ///
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// let expr = col("name").like(lit("100$%")).escape('$');
+    /// assert_eq!(expr.to_string(), "name LIKE Utf8(\"100$%\") ESCAPE '$'");
+    /// ```
+    pub fn escape(self, escape_char: char) -> Expr {
+        match self {
+            Expr::Like(like) => Expr::Like(Like {
+                escape_char: Some(escape_char),
+                ..like
+            }),
+            Expr::SimilarTo(like) => Expr::SimilarTo(Like {
+                escape_char: Some(escape_char),
+                ..like
+            }),
+            expr => expr,
+        }
+    }
+
+    /// Return `CAST(self AS data_type)`
+    pub fn cast(self, data_type: DataType) -> Expr {
+        Expr::Cast(Cast::new(Box::new(self), data_type))
+    }
+
+    /// Return `TRY_CAST(self AS data_type)`
+    pub fn try_cast(self, data_type: DataType) -> Expr {
+        Expr::TryCast(TryCast::new(Box::new(self), data_type))
+    }
+
     /// Return the name to use for the specific Expr, recursing into
     /// `Expr::Sort` as appropriate
     pub fn name_for_alias(&self) -> Result<String> {
@@ -1258,6 +1323,11 @@ impl Expr {
         Expr::InList(InList::new(Box::new(self), list, negated))
     }
 
+    /// Return `self NOT IN <list>`
+    pub fn not_in_list(self, list: Vec<Expr>) -> Expr {
+        self.in_list(list, true)
+    }
+
     /// Return `IsNull(Box(self))
     pub fn is_null(self) -> Expr {
         Expr::IsNull(Box::new(self))
@@ -1278,6 +1348,16 @@ impl Expr {
         Expr::Sort(Sort::new(Box::new(self), asc, nulls_first))
     }
 
+    /// Create an ascending sort expression, with nulls last
+    pub fn sort_asc(self) -> Expr {
+        self.sort(true, false)
+    }
+
+    /// Create a descending sort expression, with nulls first
+    pub fn sort_desc(self) -> Expr {
+        self.sort(false, true)
+    }
+
     /// Return `IsTrue(Box(self))`
     pub fn is_true(self) -> Expr {
         Expr::IsTrue(Box::new(self))
@@ -1616,10 +1696,12 @@ impl Expr {
                 order_by: _order_by,
                 window_frame,
                 null_treatment,
+                distinct,
             }) => {
                 fun.hash(hasher);
                 window_frame.hash(hasher);
                 null_treatment.hash(hasher);
+                distinct.hash(hasher);
             }
             Expr::InList(InList {
                 expr: _expr,
@@ -1781,8 +1863,9 @@ impl fmt::Display for Expr {
                 order_by,
                 window_frame,
                 null_treatment,
+                distinct,
             }) => {
-                fmt_function(f, &fun.to_string(), false, args, true)?;
+                fmt_function(f, &fun.to_string(), *distinct, args, true)?;
 
                 if let Some(nt) = null_treatment {
                     write!(f, "{}", nt)?;
@@ -2103,8 +2186,9 @@ fn write_name<W: Write>(w: &mut W, e: &Expr) -> Result<()> {
             partition_by,
             order_by,
             null_treatment,
+            distinct,
         }) => {
-            write_function_name(w, &fun.to_string(), false, args)?;
+            write_function_name(w, &fun.to_string(), *distinct, args)?;
 
             if let Some(nt) = null_treatment {
                 w.write_str(" ")?;
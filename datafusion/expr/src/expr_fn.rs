@@ -233,6 +233,51 @@ pub fn in_list(expr: Expr, list: Vec<Expr>, negated: bool) -> Expr {
     Expr::InList(InList::new(Box::new(expr), list, negated))
 }
 
+/// Create a `expr NOT IN (list)` expression
+pub fn not_in_list(expr: Expr, list: Vec<Expr>) -> Expr {
+    Expr::InList(InList::new(Box::new(expr), list, true))
+}
+
+/// Create a `expr BETWEEN low AND high` expression
+pub fn between(expr: Expr, low: Expr, high: Expr) -> Expr {
+    expr.between(low, high)
+}
+
+/// Create a `expr NOT BETWEEN low AND high` expression
+pub fn not_between(expr: Expr, low: Expr, high: Expr) -> Expr {
+    expr.not_between(low, high)
+}
+
+/// Create a `expr LIKE pattern` expression
+pub fn like(expr: Expr, pattern: Expr) -> Expr {
+    expr.like(pattern)
+}
+
+/// Create a `expr NOT LIKE pattern` expression
+pub fn not_like(expr: Expr, pattern: Expr) -> Expr {
+    expr.not_like(pattern)
+}
+
+/// Create a `expr ILIKE pattern` expression
+pub fn ilike(expr: Expr, pattern: Expr) -> Expr {
+    expr.ilike(pattern)
+}
+
+/// Create a `expr NOT ILIKE pattern` expression
+pub fn not_ilike(expr: Expr, pattern: Expr) -> Expr {
+    expr.not_ilike(pattern)
+}
+
+/// Create a `expr SIMILAR TO pattern` expression
+pub fn similar_to(expr: Expr, pattern: Expr) -> Expr {
+    expr.similar_to(pattern)
+}
+
+/// Create a `expr NOT SIMILAR TO pattern` expression
+pub fn not_similar_to(expr: Expr, pattern: Expr) -> Expr {
+    expr.not_similar_to(pattern)
+}
+
 /// Create an EXISTS subquery expression
 pub fn exists(subquery: Arc<LogicalPlan>) -> Expr {
     let outer_ref_columns = subquery.all_out_ref_exprs();
@@ -322,6 +367,11 @@ pub fn is_null(expr: Expr) -> Expr {
     Expr::IsNull(Box::new(expr))
 }
 
+/// Create is not null expression
+pub fn is_not_null(expr: Expr) -> Expr {
+    Expr::IsNotNull(Box::new(expr))
+}
+
 /// Create is true expression
 pub fn is_true(expr: Expr) -> Expr {
     Expr::IsTrue(Box::new(expr))
@@ -352,6 +402,16 @@ pub fn is_not_unknown(expr: Expr) -> Expr {
     Expr::IsNotUnknown(Box::new(expr))
 }
 
+/// Create an ascending sort expression, with nulls last
+pub fn asc(expr: Expr) -> Expr {
+    expr.sort_asc()
+}
+
+/// Create a descending sort expression, with nulls first
+pub fn desc(expr: Expr) -> Expr {
+    expr.sort_desc()
+}
+
 /// Create a CASE WHEN statement with literal WHEN expressions for comparison to the base expression.
 pub fn case(expr: Expr) -> CaseBuilder {
     CaseBuilder::new(Some(Box::new(expr)), vec![], vec![], None)
@@ -690,4 +750,41 @@ mod test {
             "col2 IS NOT NULL"
         );
     }
+
+    #[test]
+    fn expression_builders() -> Result<()> {
+        let cases = [
+            (
+                between(col("a"), lit(1), lit(5)),
+                "a BETWEEN Int32(1) AND Int32(5)",
+            ),
+            (
+                not_between(col("a"), lit(1), lit(5)),
+                "a NOT BETWEEN Int32(1) AND Int32(5)",
+            ),
+            (
+                not_in_list(col("a"), vec![lit(1), lit(2)]),
+                "a NOT IN ([Int32(1), Int32(2)])",
+            ),
+            (like(col("b"), lit("x%")), "b LIKE Utf8(\"x%\")"),
+            (not_ilike(col("b"), lit("x%")), "b NOT ILIKE Utf8(\"x%\")"),
+            (
+                similar_to(col("b"), lit("x|y")).escape('!'),
+                "b SIMILAR TO Utf8(\"x|y\") ESCAPE '!'",
+            ),
+            (is_not_null(col("a")), "a IS NOT NULL"),
+            (col("a").cast(DataType::Utf8), "CAST(a AS Utf8)"),
+            (col("a").try_cast(DataType::Utf8), "TRY_CAST(a AS Utf8)"),
+            (asc(col("a")), "a ASC NULLS LAST"),
+            (desc(col("a")), "a DESC NULLS FIRST"),
+            (
+                when(col("a").gt(lit(1)), lit("big")).otherwise(lit("small"))?,
+                "CASE WHEN a > Int32(1) THEN Utf8(\"big\") ELSE Utf8(\"small\") END",
+            ),
+        ];
+        for (expr, expected) in cases {
+            assert_eq!(expr.to_string(), expected);
+        }
+        Ok(())
+    }
 }
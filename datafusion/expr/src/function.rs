@@ -20,6 +20,7 @@
 use crate::ColumnarValue;
 use crate::{Accumulator, Expr, PartitionEvaluator};
 use arrow::datatypes::{DataType, Field, Schema};
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::Result;
 use std::sync::Arc;
 
@@ -93,6 +94,10 @@ pub struct AccumulatorArgs<'a> {
 
     /// The logical expression of arguments the aggregate function takes.
     pub input_exprs: &'a [Expr],
+
+    /// What integer arithmetic does on overflow, see
+    /// `datafusion.execution.arithmetic_overflow_mode`
+    pub arithmetic_overflow_mode: ArithmeticOverflowMode,
 }
 
 /// [`StateFieldsArgs`] contains information about the fields that an
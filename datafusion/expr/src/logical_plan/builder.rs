@@ -48,6 +48,7 @@ use crate::{
     WriteOp,
 };
 
+use arrow::compute::can_cast_types;
 use arrow::datatypes::{DataType, Field, Fields, Schema, SchemaRef};
 use datafusion_common::display::ToStringifiedPlan;
 use datafusion_common::file_options::file_type::FileType;
@@ -965,6 +966,7 @@ impl LogicalPlanBuilder {
 
             Ok(Self::from(LogicalPlan::Explain(Explain {
                 verbose,
+                estimates: false,
                 plan: Arc::new(self.plan),
                 stringified_plans,
                 schema,
@@ -1371,32 +1373,42 @@ pub fn union(left_plan: LogicalPlan, right_plan: LogicalPlan) -> Result<LogicalP
             "Union queries must have the same number of columns, (left is {left_col_num}, right is {right_col_num})");
     }
 
-    // create union schema
-    let union_qualified_fields =
-        zip(left_plan.schema().iter(), right_plan.schema().iter())
-            .map(
-                |((left_qualifier, left_field), (_right_qualifier, right_field))| {
-                    let nullable = left_field.is_nullable() || right_field.is_nullable();
-                    let data_type = comparison_coercion(
-                        left_field.data_type(),
-                        right_field.data_type(),
-                    )
-                    .ok_or_else(|| {
-                        plan_datafusion_err!(
-                "UNION Column {} (type: {}) is not compatible with column {} (type: {})",
-                right_field.name(),
-                right_field.data_type(),
-                left_field.name(),
-                left_field.data_type()
-                )
-                    })?;
-                    Ok((
-                        left_qualifier.cloned(),
-                        Arc::new(Field::new(left_field.name(), data_type, nullable)),
-                    ))
-                },
-            )
-            .collect::<Result<Vec<_>>>()?;
+    // create union schema, reporting all incompatible columns at once
+    let mut union_qualified_fields = Vec::with_capacity(left_col_num);
+    let mut incompatible = vec![];
+    for (i, ((left_qualifier, left_field), (_right_qualifier, right_field))) in
+        zip(left_plan.schema().iter(), right_plan.schema().iter()).enumerate()
+    {
+        let nullable = left_field.is_nullable() || right_field.is_nullable();
+        match comparison_coercion(left_field.data_type(), right_field.data_type()) {
+            Some(data_type) => union_qualified_fields.push((
+                left_qualifier.cloned(),
+                Arc::new(Field::new(left_field.name(), data_type, nullable)),
+            )),
+            None => {
+                let mut detail = format!(
+                    "UNION Column {} (type: {}) is not compatible with column {} (type: {})",
+                    right_field.name(),
+                    right_field.data_type(),
+                    left_field.name(),
+                    left_field.data_type()
+                );
+                if left_col_num > 1 {
+                    detail.push_str(&format!(" at position {}", i + 1));
+                }
+                if can_cast_types(right_field.data_type(), left_field.data_type()) {
+                    detail.push_str(&format!(
+                        ", consider casting it to {}",
+                        left_field.data_type()
+                    ));
+                }
+                incompatible.push(detail);
+            }
+        }
+    }
+    if !incompatible.is_empty() {
+        return plan_err!("{}", incompatible.join("\n"));
+    }
     let union_schema =
         DFSchema::new_with_metadata(union_qualified_fields, HashMap::new())?;
 
@@ -247,10 +247,15 @@ pub struct CreateView {
     pub name: TableReference,
     /// The logical plan
     pub input: Arc<LogicalPlan>,
-    /// Option to not error if table already exists
+    /// Option to replace the view if it already exists
     pub or_replace: bool,
+    /// Option to not error if the view already exists
+    pub if_not_exists: bool,
     /// SQL used to create the view, if available
     pub definition: Option<String>,
+    /// Option to create a view that is only visible to, and dropped with,
+    /// the session creating it
+    pub temporary: bool,
 }
 
 /// Creates a catalog (aka "Database").
@@ -364,6 +364,7 @@ impl LogicalPlan {
             Field::new("column_name", DataType::Utf8, false),
             Field::new("data_type", DataType::Utf8, false),
             Field::new("is_nullable", DataType::Utf8, false),
+            Field::new("is_partition", DataType::Utf8, false),
         ])
     }
 
@@ -1012,13 +1013,17 @@ impl LogicalPlan {
             LogicalPlan::Ddl(DdlStatement::CreateView(CreateView {
                 name,
                 or_replace,
+                if_not_exists,
                 definition,
+                temporary,
                 ..
             })) => Ok(LogicalPlan::Ddl(DdlStatement::CreateView(CreateView {
                 input: Arc::new(inputs.swap_remove(0)),
                 name: name.clone(),
                 or_replace: *or_replace,
+                if_not_exists: *if_not_exists,
                 definition: definition.clone(),
+                temporary: *temporary,
             }))),
             LogicalPlan::Extension(e) => Ok(LogicalPlan::Extension(Extension {
                 node: e.node.with_exprs_and_inputs(expr, inputs)?,
@@ -1085,6 +1090,7 @@ impl LogicalPlan {
                 assert_eq!(inputs.len(), 1, "Invalid EXPLAIN command. Inputs are empty");
                 Ok(LogicalPlan::Explain(Explain {
                     verbose: e.verbose,
+                    estimates: e.estimates,
                     plan: Arc::new(inputs.swap_remove(0)),
                     stringified_plans: e.stringified_plans.clone(),
                     schema: e.schema.clone(),
@@ -2451,36 +2457,63 @@ pub struct Prepare {
 ///
 /// ```sql
 /// > describe traces;
-/// +--------------------+-----------------------------+-------------+
-/// | column_name        | data_type                   | is_nullable |
-/// +--------------------+-----------------------------+-------------+
-/// | attributes         | Utf8                        | YES         |
-/// | duration_nano      | Int64                       | YES         |
-/// | end_time_unix_nano | Int64                       | YES         |
-/// | service.name       | Dictionary(Int32, Utf8)     | YES         |
-/// | span.kind          | Utf8                        | YES         |
-/// | span.name          | Utf8                        | YES         |
-/// | span_id            | Dictionary(Int32, Utf8)     | YES         |
-/// | time               | Timestamp(Nanosecond, None) | NO          |
-/// | trace_id           | Dictionary(Int32, Utf8)     | YES         |
-/// | otel.status_code   | Utf8                        | YES         |
-/// | parent_span_id     | Utf8                        | YES         |
-/// +--------------------+-----------------------------+-------------+
+/// +--------------------+-----------------------------+-------------+--------------+
+/// | column_name        | data_type                   | is_nullable | is_partition |
+/// +--------------------+-----------------------------+-------------+--------------+
+/// | attributes         | Utf8                        | YES         | NO           |
+/// | duration_nano      | Int64                       | YES         | NO           |
+/// | end_time_unix_nano | Int64                       | YES         | NO           |
+/// | service.name       | Dictionary(Int32, Utf8)     | YES         | NO           |
+/// | span.kind          | Utf8                        | YES         | NO           |
+/// | span.name          | Utf8                        | YES         | NO           |
+/// | span_id            | Dictionary(Int32, Utf8)     | YES         | NO           |
+/// | time               | Timestamp(Nanosecond, None) | NO          | NO           |
+/// | trace_id           | Dictionary(Int32, Utf8)     | YES         | NO           |
+/// | otel.status_code   | Utf8                        | YES         | NO           |
+/// | parent_span_id     | Utf8                        | YES         | NO           |
+/// | date               | Utf8                        | NO          | YES          |
+/// +--------------------+-----------------------------+-------------+--------------+
 /// ```
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DescribeTable {
     /// Table schema
     pub schema: Arc<Schema>,
+    /// Names of the columns the table is partitioned by
+    pub partition_cols: Vec<String>,
     /// schema of describe table output
     pub output_schema: DFSchemaRef,
 }
 
+impl DescribeTable {
+    /// Create a new `DescribeTable` for a table with the given `schema`
+    /// and no partition columns
+    pub fn new(schema: Arc<Schema>) -> Self {
+        let output_schema = DFSchema::try_from(LogicalPlan::describe_schema())
+            .expect("describe schema has no duplicate field names");
+        Self {
+            schema,
+            partition_cols: vec![],
+            output_schema: Arc::new(output_schema),
+        }
+    }
+
+    /// Set the names of the columns the table is partitioned by, which are
+    /// reported in the `is_partition` column of the output
+    pub fn with_partition_cols(mut self, partition_cols: Vec<String>) -> Self {
+        self.partition_cols = partition_cols;
+        self
+    }
+}
+
 /// Produces a relation with string representations of
 /// various parts of the plan
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Explain {
     /// Should extra (detailed, intermediate plans) be included?
     pub verbose: bool,
+    /// Should the estimated statistics (row counts and byte sizes) of the
+    /// physical plans be included, as requested by `EXPLAIN (ESTIMATES)`?
+    pub estimates: bool,
     /// The logical plan that is being EXPLAIN'd
     pub plan: Arc<LogicalPlan>,
     /// Represent the various stages plans have gone through
@@ -31,6 +31,8 @@ pub enum Statement {
     TransactionStart(TransactionStart),
     // Commit or rollback a transaction
     TransactionEnd(TransactionEnd),
+    // Set the characteristics of the current transaction
+    SetTransaction(SetTransaction),
     /// Set a Variable
     SetVariable(SetVariable),
 }
@@ -41,6 +43,7 @@ impl Statement {
         match self {
             Statement::TransactionStart(TransactionStart { schema, .. }) => schema,
             Statement::TransactionEnd(TransactionEnd { schema, .. }) => schema,
+            Statement::SetTransaction(SetTransaction { schema, .. }) => schema,
             Statement::SetVariable(SetVariable { schema, .. }) => schema,
         }
     }
@@ -51,6 +54,7 @@ impl Statement {
         match self {
             Statement::TransactionStart(_) => "TransactionStart",
             Statement::TransactionEnd(_) => "TransactionEnd",
+            Statement::SetTransaction(_) => "SetTransaction",
             Statement::SetVariable(_) => "SetVariable",
         }
     }
@@ -79,6 +83,20 @@ impl Statement {
                     }) => {
                         write!(f, "TransactionEnd: {conclusion:?} chain:={chain}")
                     }
+                    Statement::SetTransaction(SetTransaction {
+                        access_mode,
+                        isolation_level,
+                        ..
+                    }) => {
+                        write!(f, "SetTransaction:")?;
+                        if let Some(access_mode) = access_mode {
+                            write!(f, " {access_mode:?}")?;
+                        }
+                        if let Some(isolation_level) = isolation_level {
+                            write!(f, " {isolation_level:?}")?;
+                        }
+                        Ok(())
+                    }
                     Statement::SetVariable(SetVariable {
                         variable, value, ..
                     }) => {
@@ -136,6 +154,18 @@ pub struct TransactionEnd {
     pub schema: DFSchemaRef,
 }
 
+/// Set the characteristics of the current transaction. Characteristics that
+/// are not specified are left unchanged
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SetTransaction {
+    /// indicates if transaction is allowed to write
+    pub access_mode: Option<TransactionAccessMode>,
+    /// indicates ANSI isolation level
+    pub isolation_level: Option<TransactionIsolationLevel>,
+    /// Empty schema
+    pub schema: DFSchemaRef,
+}
+
 /// Set a Variable's value -- value in
 /// [`ConfigOptions`](datafusion_common::config::ConfigOptions)
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -213,6 +213,7 @@ impl TreeNode for LogicalPlan {
             .update_data(LogicalPlan::Distinct),
             LogicalPlan::Explain(Explain {
                 verbose,
+                estimates,
                 plan,
                 stringified_plans,
                 schema,
@@ -220,6 +221,7 @@ impl TreeNode for LogicalPlan {
             }) => rewrite_arc(plan, f)?.update_data(|plan| {
                 LogicalPlan::Explain(Explain {
                     verbose,
+                    estimates,
                     plan,
                     stringified_plans,
                     schema,
@@ -290,13 +292,17 @@ impl TreeNode for LogicalPlan {
                         name,
                         input,
                         or_replace,
+                        if_not_exists,
                         definition,
+                        temporary,
                     }) => rewrite_arc(input, f)?.update_data(|input| {
                         DdlStatement::CreateView(CreateView {
                             name,
                             input,
                             or_replace,
+                            if_not_exists,
                             definition,
+                            temporary,
                         })
                     }),
                     // no inputs in these statements
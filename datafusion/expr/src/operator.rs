@@ -18,8 +18,10 @@
 //! Operator module contains foundational types that are used to represent operators in DataFusion.
 
 use crate::expr_fn::binary_expr;
+use crate::lit;
 use crate::Expr;
 use crate::Like;
+use datafusion_common::ScalarValue;
 use std::fmt;
 use std::ops;
 use std::ops::Not;
@@ -382,6 +384,42 @@ impl ops::Shr for Expr {
     }
 }
 
+/// Support `<expr> <op> <scalar>` and `<scalar> <op> <expr>` fluent style for
+/// arithmetic operators, wrapping the scalar in a literal
+macro_rules! impl_scalar_operators {
+    ($($TYPE:ty),+) => {
+        $(
+            impl_scalar_operator!($TYPE, Add, add, Plus);
+            impl_scalar_operator!($TYPE, Sub, sub, Minus);
+            impl_scalar_operator!($TYPE, Mul, mul, Multiply);
+            impl_scalar_operator!($TYPE, Div, div, Divide);
+            impl_scalar_operator!($TYPE, Rem, rem, Modulo);
+        )+
+    };
+}
+
+macro_rules! impl_scalar_operator {
+    ($TYPE:ty, $TRAIT:ident, $METHOD:ident, $OP:ident) => {
+        impl ops::$TRAIT<$TYPE> for Expr {
+            type Output = Expr;
+
+            fn $METHOD(self, rhs: $TYPE) -> Expr {
+                binary_expr(self, Operator::$OP, lit(rhs))
+            }
+        }
+
+        impl ops::$TRAIT<Expr> for $TYPE {
+            type Output = Expr;
+
+            fn $METHOD(self, rhs: Expr) -> Expr {
+                binary_expr(lit(self), Operator::$OP, rhs)
+            }
+        }
+    };
+}
+
+impl_scalar_operators!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, ScalarValue);
+
 /// Support `- <expr>` fluent style
 impl ops::Neg for Expr {
     type Output = Self;
@@ -430,7 +468,8 @@ impl Not for Expr {
 
 #[cfg(test)]
 mod tests {
-    use crate::lit;
+    use crate::{col, lit};
+    use datafusion_common::ScalarValue;
 
     #[test]
     fn test_operators() {
@@ -489,4 +528,20 @@ mod tests {
         // Not
         assert_eq!(format!("{}", !lit(1u32)), "NOT UInt32(1)");
     }
+
+    #[test]
+    fn test_scalar_operators() {
+        assert_eq!(format!("{}", col("a") + 1i64), "a + Int64(1)");
+        assert_eq!(format!("{}", 1i64 - col("a")), "Int64(1) - a");
+        assert_eq!(format!("{}", col("a") * 2.5f64), "a * Float64(2.5)");
+        assert_eq!(format!("{}", 10u8 / col("a")), "UInt8(10) / a");
+        assert_eq!(
+            format!("{}", col("a") % ScalarValue::Int32(Some(3))),
+            "a % Int32(3)"
+        );
+        assert_eq!(
+            format!("{}", (col("a") + 1) * 2),
+            "(a + Int32(1)) * Int32(2)"
+        );
+    }
 }
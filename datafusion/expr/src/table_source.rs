@@ -127,4 +127,11 @@ pub trait TableSource: Sync + Send {
     fn get_column_default(&self, _column: &str) -> Option<&Expr> {
         None
     }
+
+    /// Get the names of the columns this table is partitioned by, if any.
+    ///
+    /// Partition columns are still part of [`Self::schema`]
+    fn partition_columns(&self) -> Vec<String> {
+        vec![]
+    }
 }
@@ -286,6 +286,7 @@ impl TreeNode for Expr {
                 order_by,
                 window_frame,
                 null_treatment,
+                distinct,
             }) => map_until_stop_and_collect!(
                 transform_vec(args, &mut f),
                 partition_by,
@@ -294,14 +295,17 @@ impl TreeNode for Expr {
                 transform_vec(order_by, &mut f)
             )?
             .update_data(|(new_args, new_partition_by, new_order_by)| {
-                Expr::WindowFunction(WindowFunction::new(
-                    fun,
-                    new_args,
-                    new_partition_by,
-                    new_order_by,
-                    window_frame,
-                    null_treatment,
-                ))
+                Expr::WindowFunction(
+                    WindowFunction::new(
+                        fun,
+                        new_args,
+                        new_partition_by,
+                        new_order_by,
+                        window_frame,
+                        null_treatment,
+                    )
+                    .with_distinct(distinct),
+                )
             }),
             Expr::AggregateFunction(AggregateFunction {
                 args,
@@ -276,6 +276,22 @@ pub fn avg_sum_type(arg_type: &DataType) -> Result<DataType> {
     }
 }
 
+/// Returns the [`DataType::Decimal256`] to widen a [`DataType::Decimal128`]
+/// argument of a sum to, if the sum may need more than 38 digits
+pub fn widen_decimal_sum_type(arg_type: &DataType) -> Option<DataType> {
+    match arg_type {
+        DataType::Decimal128(precision, scale)
+            if *precision + 10 > DECIMAL128_MAX_PRECISION =>
+        {
+            Some(DataType::Decimal256(*precision, *scale))
+        }
+        DataType::Dictionary(_, dict_value_type) => {
+            widen_decimal_sum_type(dict_value_type.as_ref())
+        }
+        _ => None,
+    }
+}
+
 pub fn is_sum_support_arg_type(arg_type: &DataType) -> bool {
     match arg_type {
         DataType::Dictionary(_, dict_value_type) => {
@@ -404,6 +420,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_widen_decimal_sum_type() {
+        assert_eq!(widen_decimal_sum_type(&DataType::Decimal128(28, 2)), None);
+        assert_eq!(
+            widen_decimal_sum_type(&DataType::Decimal128(29, 2)),
+            Some(DataType::Decimal256(29, 2))
+        );
+        assert_eq!(widen_decimal_sum_type(&DataType::Decimal256(40, 2)), None);
+        assert_eq!(widen_decimal_sum_type(&DataType::Float64), None);
+    }
+
     #[test]
     fn test_covariance_return_data_type() -> Result<()> {
         let data_type = DataType::Float64;
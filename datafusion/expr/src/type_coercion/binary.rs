@@ -25,8 +25,8 @@ use crate::Operator;
 use arrow::array::{new_empty_array, Array};
 use arrow::compute::can_cast_types;
 use arrow::datatypes::{
-    DataType, Field, TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE,
-    DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
+    DataType, Field, IntervalUnit, TimeUnit, DECIMAL128_MAX_PRECISION,
+    DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
 };
 
 use datafusion_common::{exec_datafusion_err, plan_datafusion_err, plan_err, Result};
@@ -101,9 +101,14 @@ fn signature(lhs: &DataType, op: &Operator, rhs: &DataType) -> Result<Signature>
             })
         }
         LikeMatch | ILikeMatch | NotLikeMatch | NotILikeMatch => {
-            regex_coercion(lhs, rhs).map(Signature::comparison).ok_or_else(|| {
+            like_coercion(lhs, rhs).map(|t| Signature {
+                // the like kernels evaluate dictionaries of strings directly
+                lhs: like_dictionary_type(lhs, &t).unwrap_or_else(|| t.clone()),
+                rhs: t,
+                ret: Boolean,
+            }).ok_or_else(|| {
                 plan_datafusion_err!(
-                    "Cannot infer common argument type for regex operation {lhs} {op} {rhs}"
+                    "Cannot infer common argument type for like operation {lhs} {op} {rhs}"
                 )
             })
         }
@@ -168,6 +173,13 @@ fn signature(lhs: &DataType, op: &Operator, rhs: &DataType) -> Result<Signature>
                     rhs: coerced,
                     ret,
                 })
+            } else if let Some((lhs, rhs)) = interval_scaling_coercion(op, lhs, rhs) {
+                // Interval multiplied or divided by a number, e.g. Interval * Int64
+                Ok(Signature{
+                    lhs,
+                    rhs,
+                    ret: DataType::Interval(IntervalUnit::MonthDayNano),
+                })
             } else if let Some((lhs, rhs)) = math_decimal_coercion(lhs, rhs) {
                 // Decimal arithmetic, e.g. Decimal(10, 2) + Decimal(10, 0)
                 let ret = get_result(&lhs, &rhs).map_err(|e| {
@@ -226,12 +238,21 @@ fn math_decimal_coercion(
             let (lhs_type, value_type) = math_decimal_coercion(lhs_type, value_type)?;
             Some((lhs_type, value_type))
         }
-        (Null, dec_type @ Decimal128(_, _)) | (dec_type @ Decimal128(_, _), Null) => {
+        (Null, dec_type @ (Decimal128(_, _) | Decimal256(_, _)))
+        | (dec_type @ (Decimal128(_, _) | Decimal256(_, _)), Null) => {
             Some((dec_type.clone(), dec_type.clone()))
         }
         (Decimal128(_, _), Decimal128(_, _)) | (Decimal256(_, _), Decimal256(_, _)) => {
             Some((lhs_type.clone(), rhs_type.clone()))
         }
+        // The result of an operation on a Decimal256 is a Decimal256, so the
+        // Decimal128 operand is widened without changing its precision and scale
+        (Decimal128(precision, scale), Decimal256(_, _)) => {
+            Some((Decimal256(*precision, *scale), rhs_type.clone()))
+        }
+        (Decimal256(_, _), Decimal128(precision, scale)) => {
+            Some((lhs_type.clone(), Decimal256(*precision, *scale)))
+        }
         // Unlike with comparison we don't coerce to a decimal in the case of floating point
         // numbers, instead falling back to floating point arithmetic instead
         (Decimal128(_, _), Int8 | Int16 | Int32 | Int64) => {
@@ -640,6 +661,12 @@ pub fn decimal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<Data
         (Decimal128(_, _), Decimal128(_, _)) => {
             get_wider_decimal_type(lhs_type, rhs_type)
         }
+        (Decimal128(p, s), Decimal256(_, _)) => {
+            get_wider_decimal_type(&Decimal256(*p, *s), rhs_type)
+        }
+        (Decimal256(_, _), Decimal128(p, s)) => {
+            get_wider_decimal_type(lhs_type, &Decimal256(*p, *s))
+        }
         (Decimal128(_, _), _) => get_common_decimal_type(lhs_type, rhs_type),
         (_, Decimal128(_, _)) => get_common_decimal_type(rhs_type, lhs_type),
         (Decimal256(_, _), Decimal256(_, _)) => {
@@ -955,18 +982,17 @@ fn list_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
 }
 
 /// Coercion rules for binary (Binary/LargeBinary) to string (Utf8/LargeUtf8):
-/// If one argument is binary and the other is a string then coerce to string
-/// (e.g. for `like`)
+/// If one argument is binary and the other is a string or binary then coerce
+/// to string (e.g. for `like`)
 fn binary_to_string_coercion(
     lhs_type: &DataType,
     rhs_type: &DataType,
 ) -> Option<DataType> {
     use arrow::datatypes::DataType::*;
     match (lhs_type, rhs_type) {
-        (Binary, Utf8) => Some(Utf8),
-        (Binary, LargeUtf8) => Some(LargeUtf8),
-        (LargeBinary, Utf8) => Some(LargeUtf8),
-        (LargeBinary, LargeUtf8) => Some(LargeUtf8),
+        (Binary, Utf8 | Binary) => Some(Utf8),
+        (Binary, LargeUtf8 | LargeBinary) => Some(LargeUtf8),
+        (LargeBinary, Utf8 | LargeUtf8 | Binary | LargeBinary) => Some(LargeUtf8),
         (Utf8, Binary) => Some(Utf8),
         (Utf8, LargeBinary) => Some(LargeUtf8),
         (LargeUtf8, Binary) => Some(LargeUtf8),
@@ -994,10 +1020,45 @@ pub fn like_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataTyp
     string_coercion(lhs_type, rhs_type)
         .or_else(|| list_coercion(lhs_type, rhs_type))
         .or_else(|| binary_to_string_coercion(lhs_type, rhs_type))
-        .or_else(|| dictionary_coercion(lhs_type, rhs_type, false))
+        .or_else(|| like_dictionary_coercion(lhs_type, rhs_type))
         .or_else(|| null_coercion(lhs_type, rhs_type))
 }
 
+/// Coercion rules for like operations on dictionaries: the like coercion of
+/// their value types
+fn like_dictionary_coercion(
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Option<DataType> {
+    use arrow::datatypes::DataType::*;
+    match (lhs_type, rhs_type) {
+        (Dictionary(_, lhs_value_type), Dictionary(_, rhs_value_type)) => {
+            like_coercion(lhs_value_type, rhs_value_type)
+        }
+        (Dictionary(_, value_type), _) => like_coercion(value_type, rhs_type),
+        (_, Dictionary(_, value_type)) => like_coercion(lhs_type, value_type),
+        _ => None,
+    }
+}
+
+/// Returns `expr_type` if it is a dictionary of strings whose values have the
+/// type `coerced_type` that the pattern of a like operation is coerced to.
+///
+/// The like kernels match the values of such dictionaries directly, so that
+/// they do not need to be cast to strings.
+pub fn like_dictionary_type(
+    expr_type: &DataType,
+    coerced_type: &DataType,
+) -> Option<DataType> {
+    use arrow::datatypes::DataType::*;
+    match expr_type {
+        Dictionary(_, value_type) if value_type.as_ref() == coerced_type => {
+            matches!(coerced_type, Utf8 | LargeUtf8).then(|| expr_type.clone())
+        }
+        _ => None,
+    }
+}
+
 /// coercion rules for regular expression comparison operations.
 /// This is a union of string coercion rules and dictionary coercion rules
 pub fn regex_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
@@ -1044,18 +1105,10 @@ fn temporal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataTyp
         }
         (Timestamp(lhs_unit, lhs_tz), Timestamp(rhs_unit, rhs_tz)) => {
             let tz = match (lhs_tz, rhs_tz) {
-                (Some(lhs_tz), Some(rhs_tz)) => {
-                    match (lhs_tz.as_ref(), rhs_tz.as_ref()) {
-                        // UTC and "+00:00" are the same by definition. Most other timezones
-                        // do not have a 1-1 mapping between timezone and an offset from UTC
-                        ("UTC", "+00:00") | ("+00:00", "UTC") => Some(lhs_tz.clone()),
-                        (lhs, rhs) if lhs == rhs => Some(lhs_tz.clone()),
-                        // can't cast across timezones
-                        _ => {
-                            return None;
-                        }
-                    }
-                }
+                // Timestamps with a time zone are stored as instants in UTC, so
+                // casting between time zones only changes how they are displayed
+                // and the values remain comparable
+                (Some(lhs_tz), Some(_)) => Some(lhs_tz.clone()),
                 (Some(lhs_tz), None) => Some(lhs_tz.clone()),
                 (None, Some(rhs_tz)) => Some(rhs_tz.clone()),
                 (None, None) => None,
@@ -1086,6 +1139,28 @@ fn temporal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataTyp
     }
 }
 
+/// Coercion rules for multiplying an interval by a number, or dividing it by
+/// a number: the interval is coerced to `Interval(MonthDayNano)` and the
+/// number to `Float64`
+fn interval_scaling_coercion(
+    op: &Operator,
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Option<(DataType, DataType)> {
+    use arrow::datatypes::DataType::*;
+    let is_number = |data_type: &DataType| data_type.is_numeric() || *data_type == Null;
+    let interval = Interval(IntervalUnit::MonthDayNano);
+    match (op, lhs_type, rhs_type) {
+        (Operator::Multiply | Operator::Divide, Interval(_), rhs) if is_number(rhs) => {
+            Some((interval, Float64))
+        }
+        (Operator::Multiply, lhs, Interval(_)) if is_number(lhs) => {
+            Some((Float64, interval))
+        }
+        _ => None,
+    }
+}
+
 /// coercion rules from NULL type. Since NULL can be casted to any other type in arrow,
 /// either lhs or rhs is NULL, if NULL can be casted to type of the other side, the coercion is valid.
 fn null_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
@@ -1130,6 +1205,7 @@ mod tests {
             DataType::Decimal128(38, 10),
             DataType::Decimal128(20, 8),
             DataType::Null,
+            DataType::Decimal256(40, 5),
         ];
         let result_types = [
             DataType::Decimal128(20, 3),
@@ -1141,6 +1217,7 @@ mod tests {
             DataType::Decimal128(38, 10),
             DataType::Decimal128(25, 8),
             DataType::Decimal128(20, 3),
+            DataType::Decimal256(40, 5),
         ];
         let comparison_op_types = [
             Operator::NotEq,
@@ -1324,6 +1401,65 @@ mod tests {
             DataType::LargeUtf8,
             Some(DataType::LargeUtf8)
         );
+        test_like_rule!(DataType::Binary, DataType::Binary, Some(DataType::Utf8));
+        test_like_rule!(
+            DataType::LargeBinary,
+            DataType::Binary,
+            Some(DataType::LargeUtf8)
+        );
+
+        // dictionaries coerce according to their values
+        let dict = |value_type: DataType| {
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(value_type))
+        };
+        test_like_rule!(dict(DataType::Utf8), DataType::Utf8, Some(DataType::Utf8));
+        test_like_rule!(
+            dict(DataType::LargeUtf8),
+            DataType::Utf8,
+            Some(DataType::LargeUtf8)
+        );
+        test_like_rule!(dict(DataType::Binary), DataType::Utf8, Some(DataType::Utf8));
+        test_like_rule!(
+            dict(DataType::Utf8),
+            dict(DataType::LargeBinary),
+            Some(DataType::LargeUtf8)
+        );
+        test_like_rule!(dict(DataType::Int32), DataType::Utf8, None);
+    }
+
+    #[test]
+    fn test_like_operator_signature() -> Result<()> {
+        let dict = |value_type: DataType| {
+            DataType::Dictionary(Box::new(DataType::Int16), Box::new(value_type))
+        };
+        for op in [
+            Operator::LikeMatch,
+            Operator::ILikeMatch,
+            Operator::NotLikeMatch,
+            Operator::NotILikeMatch,
+        ] {
+            // dictionaries of strings are kept
+            let (lhs, rhs) =
+                get_input_types(&dict(DataType::Utf8), &op, &DataType::Utf8)?;
+            assert_eq!(lhs, dict(DataType::Utf8));
+            assert_eq!(rhs, DataType::Utf8);
+
+            let (lhs, rhs) =
+                get_input_types(&dict(DataType::LargeUtf8), &op, &DataType::Utf8)?;
+            assert_eq!(lhs, dict(DataType::LargeUtf8));
+            assert_eq!(rhs, DataType::LargeUtf8);
+
+            // other dictionaries are cast to strings
+            let (lhs, rhs) =
+                get_input_types(&dict(DataType::Binary), &op, &DataType::Utf8)?;
+            assert_eq!(lhs, DataType::Utf8);
+            assert_eq!(rhs, DataType::Utf8);
+
+            let (lhs, rhs) = get_input_types(&DataType::Binary, &op, &DataType::Binary)?;
+            assert_eq!(lhs, DataType::Utf8);
+            assert_eq!(rhs, DataType::Utf8);
+        }
+        Ok(())
     }
 
     #[test]
@@ -1388,6 +1524,18 @@ mod tests {
             Operator::Lt,
             DataType::Timestamp(TimeUnit::Nanosecond, None)
         );
+        test_coercion_binary_rule!(
+            DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into())),
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("America/New_York".into())),
+            Operator::Lt,
+            DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into()))
+        );
+        test_coercion_binary_rule!(
+            DataType::Timestamp(TimeUnit::Second, None),
+            DataType::Timestamp(TimeUnit::Second, Some("Europe/Brussels".into())),
+            Operator::Eq,
+            DataType::Timestamp(TimeUnit::Second, Some("Europe/Brussels".into()))
+        );
         test_coercion_binary_rule!(
             DataType::Utf8,
             DataType::Utf8,
@@ -1513,6 +1661,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_type_coercion_interval_scaling() -> Result<()> {
+        let interval = DataType::Interval(IntervalUnit::MonthDayNano);
+        for (lhs, op, rhs, coerced_lhs, coerced_rhs) in [
+            (
+                DataType::Interval(IntervalUnit::YearMonth),
+                Operator::Multiply,
+                DataType::Int32,
+                interval.clone(),
+                DataType::Float64,
+            ),
+            (
+                DataType::Decimal128(10, 2),
+                Operator::Multiply,
+                DataType::Interval(IntervalUnit::DayTime),
+                DataType::Float64,
+                interval.clone(),
+            ),
+            (
+                interval.clone(),
+                Operator::Divide,
+                DataType::Float32,
+                interval.clone(),
+                DataType::Float64,
+            ),
+        ] {
+            assert_eq!(
+                get_input_types(&lhs, &op, &rhs)?,
+                (coerced_lhs, coerced_rhs)
+            );
+            assert_eq!(get_result_type(&lhs, &op, &rhs)?, interval);
+        }
+
+        // a number can not be divided by an interval
+        let err = get_input_types(&DataType::Int64, &Operator::Divide, &interval)
+            .unwrap_err()
+            .to_string();
+        assert_contains!(err, "Cannot coerce arithmetic expression");
+        Ok(())
+    }
+
     fn test_math_decimal_coercion_rule(
         lhs_type: DataType,
         rhs_type: DataType,
@@ -1569,6 +1758,20 @@ mod tests {
             DataType::Decimal128(10, 2),
         );
 
+        test_math_decimal_coercion_rule(
+            DataType::Decimal128(38, 2),
+            DataType::Decimal256(50, 4),
+            DataType::Decimal256(38, 2),
+            DataType::Decimal256(50, 4),
+        );
+
+        test_math_decimal_coercion_rule(
+            DataType::Decimal256(50, 4),
+            DataType::Null,
+            DataType::Decimal256(50, 4),
+            DataType::Decimal256(50, 4),
+        );
+
         Ok(())
     }
 
@@ -211,10 +211,20 @@ impl AggregateUDF {
         self.inner.create_sliding_accumulator(args)
     }
 
+    /// See [`AggregateUDFImpl::recompute_sliding_windows`] for more details.
+    pub fn recompute_sliding_windows(&self) -> bool {
+        self.inner.recompute_sliding_windows()
+    }
+
     pub fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
         self.inner.coerce_types(arg_types)
     }
 
+    /// See [`AggregateUDFImpl::widen_decimal_types`] for more details.
+    pub fn widen_decimal_types(&self, arg_types: &[DataType]) -> Option<Vec<DataType>> {
+        self.inner.widen_decimal_types(arg_types)
+    }
+
     /// See [`AggregateUDFImpl::with_beneficial_ordering`] for more details.
     pub fn with_beneficial_ordering(
         self,
@@ -409,9 +419,16 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
     /// Sliding accumulator is an alternative accumulator that can be used for
     /// window functions. It has retract method to revert the previous update.
     ///
+    /// It is used for window frames whose start moves, such as `ROWS BETWEEN
+    /// 1 PRECEDING AND CURRENT ROW`. The returned accumulator must
+    /// [support retract_batch], otherwise such queries fail with a not
+    /// implemented error, unless [`Self::recompute_sliding_windows`] returns
+    /// `true`.
+    ///
     /// See [retract_batch] for more details.
     ///
     /// [retract_batch]: crate::accumulator::Accumulator::retract_batch
+    /// [support retract_batch]: crate::accumulator::Accumulator::supports_retract_batch
     fn create_sliding_accumulator(
         &self,
         args: AccumulatorArgs,
@@ -419,6 +436,20 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
         self.accumulator(args)
     }
 
+    /// Returns `true` if this aggregate can be used in window frames whose
+    /// start moves even though its [sliding accumulator] does not support
+    /// `retract_batch`.
+    ///
+    /// DataFusion then creates a new accumulator and updates it with all the
+    /// rows of the window whenever rows leave the window. This gives the same
+    /// results, but the cost grows with the size of the window for every row.
+    /// Defaults to `false`.
+    ///
+    /// [sliding accumulator]: Self::create_sliding_accumulator
+    fn recompute_sliding_windows(&self) -> bool {
+        false
+    }
+
     /// Sets the indicator whether ordering requirements of the AggregateUDFImpl is
     /// satisfied by its input. If this is not the case, UDFs with order
     /// sensitivity `AggregateOrderSensitivity::Beneficial` can still produce
@@ -504,6 +535,20 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
     fn coerce_types(&self, _arg_types: &[DataType]) -> Result<Vec<DataType>> {
         not_impl_err!("Function {} does not implement coerce_types", self.name())
     }
+
+    /// Returns the types the arguments are cast to when the
+    /// `datafusion.execution.widen_decimal_aggregates` option is enabled, or
+    /// `None` (the default) to keep the coerced types of the arguments.
+    ///
+    /// Aggregates that add up their input, like `SUM` and `AVG`, can return
+    /// [`DataType::Decimal256`] for [`DataType::Decimal128`] arguments whose
+    /// results may need more than 38 digits, instead of overflowing.
+    ///
+    /// # Parameters
+    /// * `arg_types`: The types of the arguments, after type coercion
+    fn widen_decimal_types(&self, _arg_types: &[DataType]) -> Option<Vec<DataType>> {
+        None
+    }
 }
 
 pub enum ReversedUDAF {
@@ -196,6 +196,18 @@ impl ScalarUDF {
         self.inner.invoke_no_args(number_rows)
     }
 
+    /// Invoke the function on `args` for a batch of `number_rows` rows,
+    /// returning the appropriate result.
+    ///
+    /// See [`ScalarUDFImpl::invoke_batch`] for more details.
+    pub fn invoke_batch(
+        &self,
+        args: &[ColumnarValue],
+        number_rows: usize,
+    ) -> Result<ColumnarValue> {
+        self.inner.invoke_batch(args, number_rows)
+    }
+
     /// Returns a `ScalarFunctionImplementation` that can invoke the function
     /// during execution
     pub fn fun(&self) -> ScalarFunctionImplementation {
@@ -407,7 +419,21 @@ pub trait ScalarUDFImpl: Debug + Send + Sync {
     /// [`ColumnarValue::values_to_arrays`] can be used to convert the arguments
     /// to arrays, which will likely be simpler code, but be slower.
     ///
+    /// # Zero-copy arguments and results
+    ///
+    /// The arguments are passed exactly as they were evaluated: a constant
+    /// argument is a single [`ColumnarValue::Scalar`], and is never expanded to
+    /// an array by DataFusion before the function is invoked. An array argument
+    /// is an [`ArrayRef`], so cloning it, or returning it unchanged (as
+    /// `coalesce` does for instance), shares its buffers without copying them.
+    ///
+    /// When all the arguments are scalars, the function should return a
+    /// [`ColumnarValue::Scalar`]. Returning an array with a single row is also
+    /// accepted, and is converted to a scalar by the caller. Otherwise the
+    /// function must return an array with one row per input row.
+    ///
     /// [invoke_no_args]: ScalarUDFImpl::invoke_no_args
+    /// [`ArrayRef`]: arrow::array::ArrayRef
     fn invoke(&self, _args: &[ColumnarValue]) -> Result<ColumnarValue>;
 
     /// Invoke the function without `args`, instead the number of rows are provided,
@@ -419,6 +445,28 @@ pub trait ScalarUDFImpl: Debug + Send + Sync {
         )
     }
 
+    /// Invoke the function on `args` for a batch of `number_rows` rows,
+    /// returning the appropriate result.
+    ///
+    /// This is the method called during execution. Knowing the number of rows
+    /// lets a function produce an array of the right length when all of its
+    /// arguments are scalars, without materializing them. The same
+    /// zero-copy guarantees as [`Self::invoke`] apply.
+    ///
+    /// The default implementation calls [`Self::invoke_no_args`] if there are
+    /// no arguments, and [`Self::invoke`] otherwise.
+    fn invoke_batch(
+        &self,
+        args: &[ColumnarValue],
+        number_rows: usize,
+    ) -> Result<ColumnarValue> {
+        if args.is_empty() {
+            self.invoke_no_args(number_rows)
+        } else {
+            self.invoke(args)
+        }
+    }
+
     /// Returns any aliases (alternate names) for this function.
     ///
     /// Aliases can be used to invoke the same function using different names.
@@ -583,6 +631,18 @@ impl ScalarUDFImpl for AliasedScalarUDFImpl {
         self.inner.invoke(args)
     }
 
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        self.inner.invoke_no_args(number_rows)
+    }
+
+    fn invoke_batch(
+        &self,
+        args: &[ColumnarValue],
+        number_rows: usize,
+    ) -> Result<ColumnarValue> {
+        self.inner.invoke_batch(args, number_rows)
+    }
+
     fn aliases(&self) -> &[String] {
         &self.aliases
     }
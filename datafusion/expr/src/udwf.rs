@@ -141,6 +141,7 @@ impl WindowUDF {
             order_by,
             window_frame,
             null_treatment: None,
+            distinct: false,
         })
     }
 
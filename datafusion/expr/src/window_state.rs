@@ -25,6 +25,7 @@ use arrow::{
     array::ArrayRef,
     compute::{concat, concat_batches, SortOptions},
     datatypes::{DataType, SchemaRef},
+    error::ArrowError,
     record_batch::RecordBatch,
 };
 use datafusion_common::{
@@ -386,46 +387,64 @@ impl WindowFrameStateRange {
         delta: Option<&ScalarValue>,
         length: usize,
     ) -> Result<usize> {
+        let search_start = if SIDE {
+            last_range.start
+        } else {
+            last_range.end
+        };
         let current_row_values = get_row_at_idx(range_columns, idx)?;
         let end_range = if let Some(delta) = delta {
-            let is_descending: bool = self
-                .sort_options
-                .first()
-                .ok_or_else(|| {
-                    DataFusionError::Internal(
-                        "Sort options unexpectedly absent in a window frame".to_string(),
-                    )
-                })?
-                .descending;
-
-            current_row_values
-                .iter()
-                .map(|value| {
-                    if value.is_null() {
-                        return Ok(value.clone());
-                    }
-                    if SEARCH_SIDE == is_descending {
-                        // TODO: Handle positive overflows.
-                        value.add(delta)
-                    } else if value.is_unsigned() && value < delta {
-                        // NOTE: This gets a polymorphic zero without having long coercion code for ScalarValue.
-                        //       If we decide to implement a "default" construction mechanism for ScalarValue,
-                        //       change the following statement to use that.
-                        value.sub(value)
-                    } else {
-                        // TODO: Handle negative overflows.
-                        value.sub(delta)
+            let sort_options = self.sort_options.first().ok_or_else(|| {
+                DataFusionError::Internal(
+                    "Sort options unexpectedly absent in a window frame".to_string(),
+                )
+            })?;
+
+            let mut end_range = Vec::with_capacity(current_row_values.len());
+            for value in current_row_values {
+                if value.is_null() {
+                    // The frame of a NULL row spans its NULL peers
+                    end_range.push(value);
+                    continue;
+                }
+                let target = if SEARCH_SIDE == sort_options.descending {
+                    value.add_checked(delta)
+                } else {
+                    value.sub_checked(delta)
+                };
+                match target {
+                    Ok(target) => end_range.push(target),
+                    Err(DataFusionError::ArrowError(
+                        ArrowError::ArithmeticOverflow(_),
+                        _,
+                    )) => {
+                        // The boundary lies beyond every value of the column
+                        // type: before all non-NULL rows for PRECEDING, after
+                        // them for FOLLOWING. NULL rows stay on their side.
+                        let nulls_first = sort_options.nulls_first;
+                        let compare_fn = |current: &[ScalarValue], _: &[ScalarValue]| {
+                            let is_null = current[0].is_null();
+                            Ok(if SEARCH_SIDE {
+                                is_null && nulls_first
+                            } else {
+                                !is_null || nulls_first
+                            })
+                        };
+                        return search_in_slice(
+                            range_columns,
+                            &[],
+                            compare_fn,
+                            search_start,
+                            length,
+                        );
                     }
-                })
-                .collect::<Result<Vec<ScalarValue>>>()?
+                    Err(e) => return Err(e),
+                }
+            }
+            end_range
         } else {
             current_row_values
         };
-        let search_start = if SIDE {
-            last_range.start
-        } else {
-            last_range.end
-        };
         let compare_fn = |current: &[ScalarValue], target: &[ScalarValue]| {
             let cmp = compare_rows(current, target, &self.sort_options)?;
             Ok(if SIDE { cmp.is_lt() } else { cmp.is_le() })
@@ -669,7 +688,7 @@ fn check_equality(current: &[ScalarValue], target: &[ScalarValue]) -> Result<boo
 mod tests {
     use super::*;
 
-    use arrow::array::Float64Array;
+    use arrow::array::{Float64Array, Int32Array, Int8Array, UInt32Array};
 
     fn get_test_data() -> (Vec<ArrayRef>, Vec<SortOptions>) {
         let range_columns: Vec<ArrayRef> = vec![Arc::new(Float64Array::from(vec![
@@ -705,6 +724,112 @@ mod tests {
         Ok(())
     }
 
+    fn range_frames(
+        values: ArrayRef,
+        sort_options: SortOptions,
+        start_bound: WindowFrameBound,
+        end_bound: WindowFrameBound,
+    ) -> Result<Vec<Range<usize>>> {
+        let window_frame = Arc::new(WindowFrame::new_bounds(
+            WindowFrameUnits::Range,
+            start_bound,
+            end_bound,
+        ));
+        let mut state = WindowFrameStateRange::new(vec![sort_options]);
+        let n_row = values.len();
+        let range_columns = vec![values];
+        let mut last_range = Range { start: 0, end: 0 };
+        (0..n_row)
+            .map(|idx| {
+                last_range = state.calculate_range(
+                    &window_frame,
+                    &last_range,
+                    &range_columns,
+                    n_row,
+                    idx,
+                )?;
+                Ok(last_range.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_window_frame_range_descending_nulls_first() -> Result<()> {
+        let values = Arc::new(Int32Array::from(vec![
+            None,
+            None,
+            Some(10),
+            Some(8),
+            Some(7),
+            Some(5),
+            Some(1),
+        ]));
+        let sort_options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let ranges = range_frames(
+            values,
+            sort_options,
+            WindowFrameBound::Preceding(ScalarValue::Int32(Some(2))),
+            WindowFrameBound::Following(ScalarValue::Int32(Some(1))),
+        )?;
+        assert_eq!(ranges, vec![0..2, 0..2, 2..3, 2..5, 3..5, 4..6, 6..7]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_range_overflow() -> Result<()> {
+        // the boundaries of the first and third rows are beyond the range of
+        // Int8, and must not wrap around
+        let values =
+            Arc::new(Int8Array::from(vec![Some(-120), Some(0), Some(120), None]));
+        let sort_options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let ranges = range_frames(
+            values,
+            sort_options,
+            WindowFrameBound::Preceding(ScalarValue::Int8(Some(10))),
+            WindowFrameBound::Following(ScalarValue::Int8(Some(10))),
+        )?;
+        assert_eq!(ranges, vec![0..1, 1..2, 2..3, 3..4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_range_unsigned_descending_nulls_last() -> Result<()> {
+        let values = Arc::new(UInt32Array::from(vec![Some(5), Some(3), Some(1), None]));
+        let sort_options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let ranges = range_frames(
+            values,
+            sort_options,
+            WindowFrameBound::CurrentRow,
+            WindowFrameBound::Following(ScalarValue::UInt32(Some(2))),
+        )?;
+        assert_eq!(ranges, vec![0..2, 1..3, 2..3, 3..4]);
+
+        // a NULL row is not part of the frames of the other rows, even when
+        // the frame extends to the end of the values
+        let values = Arc::new(UInt32Array::from(vec![None, Some(5), Some(3), Some(1)]));
+        let sort_options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let ranges = range_frames(
+            values,
+            sort_options,
+            WindowFrameBound::Preceding(ScalarValue::UInt32(Some(2))),
+            WindowFrameBound::CurrentRow,
+        )?;
+        assert_eq!(ranges, vec![0..1, 1..2, 1..3, 2..4]);
+        Ok(())
+    }
+
     #[test]
     fn test_window_frame_group_boundaries() -> Result<()> {
         let window_frame = Arc::new(WindowFrame::new_bounds(
@@ -0,0 +1,44 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Serves a DataFusion [`SessionContext`] over [Arrow Flight SQL], so that
+//! clients such as the "JDBC Driver for Arrow Flight SQL" or ADBC drivers can
+//! run queries against an embedded DataFusion.
+//!
+//! ```no_run
+//! # use datafusion::error::Result;
+//! # use datafusion::prelude::SessionContext;
+//! # use datafusion_flight_sql::FlightSqlServer;
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<()> {
+//! let ctx = SessionContext::new();
+//! // register tables with `ctx` ...
+//! FlightSqlServer::new(ctx)
+//!     .serve("127.0.0.1:50051".parse().unwrap())
+//!     .await
+//! # }
+//! ```
+//!
+//! JDBC clients can then connect with the connection string
+//! `jdbc:arrow-flight-sql://127.0.0.1:50051/?useEncryption=false`.
+//!
+//! [`SessionContext`]: datafusion::prelude::SessionContext
+//! [Arrow Flight SQL]: https://arrow.apache.org/docs/format/FlightSql.html
+
+mod service;
+
+pub use service::FlightSqlServer;
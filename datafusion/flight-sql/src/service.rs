@@ -0,0 +1,867 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arrow_flight::close_session_result::Status as CloseSessionStatus;
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::sql::server::{FlightSqlService, PeekableFlightDataStream};
+use arrow_flight::sql::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, CommandGetCatalogs, CommandGetDbSchemas,
+    CommandGetTables, CommandPreparedStatementQuery, CommandPreparedStatementUpdate,
+    CommandStatementQuery, CommandStatementUpdate, ProstMessageExt, SqlInfo,
+    TicketStatementQuery,
+};
+use arrow_flight::{
+    Action, CloseSessionRequest, CloseSessionResult, FlightDescriptor, FlightEndpoint,
+    FlightInfo, HandshakeRequest, HandshakeResponse, IpcMessage, SchemaAsIpc, Ticket,
+};
+use dashmap::DashMap;
+use datafusion::arrow::array::{AsArray, RecordBatch};
+use datafusion::arrow::datatypes::{Schema, SchemaRef, UInt64Type};
+use datafusion::arrow::error::ArrowError;
+use datafusion::arrow::ipc::writer::IpcWriteOptions;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::prelude::{DataFrame, SessionContext};
+use futures::{stream, Stream, TryStreamExt};
+use log::info;
+use parking_lot::Mutex;
+use prost::Message;
+use tonic::metadata::MetadataValue;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status, Streaming};
+use uuid::Uuid;
+
+type DoGetStream = <FlightSqlServer as FlightService>::DoGetStream;
+
+/// How long a session can stay unused before it is closed, by default
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// How many sessions can be open at the same time, by default
+const DEFAULT_MAX_SESSIONS: usize = 1024;
+
+/// A [`FlightSqlService`] that runs queries in a [`SessionContext`]
+///
+/// Clients must perform a `Handshake` first. It creates a session with its
+/// own copy of the session state, identified by the bearer token returned in
+/// the `authorization` header, so that settings changed with `SET` and
+/// prepared statements only apply to it. Requests without a valid token are
+/// rejected as unauthenticated. No other authentication is performed.
+///
+/// Sessions are closed by the `CloseSession` action, or once they have not
+/// been used for the idle timeout. Handshakes fail while the maximum number
+/// of sessions are open.
+///
+/// `GetFlightInfo` only plans queries: they are run, and their results
+/// streamed to the client, by the following `DoGet`.
+pub struct FlightSqlServer {
+    /// The template for sessions created by `Handshake`
+    ctx: SessionContext,
+    /// Sessions created by `Handshake`, by bearer token
+    sessions: DashMap<String, Arc<Session>>,
+    /// How long a session can stay unused before it is closed
+    idle_timeout: Duration,
+    /// How many sessions can be open at the same time
+    max_sessions: usize,
+}
+
+/// A client session created by `Handshake`
+struct Session {
+    ctx: SessionContext,
+    /// Prepared statements, by handle
+    statements: DashMap<String, LogicalPlan>,
+    /// When the session was last used
+    last_used: Mutex<Instant>,
+}
+
+impl Session {
+    fn new(ctx: SessionContext) -> Self {
+        Self {
+            ctx,
+            statements: DashMap::new(),
+            last_used: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Returns how long the session has not been used
+    fn idle_time(&self) -> Duration {
+        self.last_used.lock().elapsed()
+    }
+
+    fn prepared_statement(&self, handle: &[u8]) -> Result<LogicalPlan, Status> {
+        let handle = std::str::from_utf8(handle)
+            .map_err(|e| Status::invalid_argument(format!("invalid handle: {e}")))?;
+        self.statements
+            .get(handle)
+            .map(|plan| plan.clone())
+            .ok_or_else(|| {
+                Status::not_found(format!("prepared statement {handle} not found"))
+            })
+    }
+}
+
+impl FlightSqlServer {
+    /// Create a new server running queries in copies of the state of `ctx`
+    pub fn new(ctx: SessionContext) -> Self {
+        Self {
+            ctx,
+            sessions: DashMap::new(),
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            max_sessions: DEFAULT_MAX_SESSIONS,
+        }
+    }
+
+    /// Closes sessions that have not been used for `idle_timeout`, 30 minutes
+    /// by default
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Sets the maximum number of open sessions, 1024 by default
+    pub fn with_max_sessions(mut self, max_sessions: usize) -> Self {
+        self.max_sessions = max_sessions;
+        self
+    }
+
+    /// Serves Flight SQL requests on `addr` until the server fails
+    pub async fn serve(self, addr: SocketAddr) -> Result<()> {
+        info!("Listening for Flight SQL requests on {addr}");
+        Server::builder()
+            .add_service(FlightServiceServer::new(self))
+            .serve(addr)
+            .await
+            .map_err(|e| DataFusionError::External(Box::new(e)))
+    }
+
+    /// Creates a session, returning its bearer token
+    fn create_session(&self) -> Result<String, Status> {
+        let idle_timeout = self.idle_timeout;
+        self.sessions
+            .retain(|_, session| session.idle_time() < idle_timeout);
+        if self.sessions.len() >= self.max_sessions {
+            return Err(Status::resource_exhausted(format!(
+                "too many open sessions, the maximum is {}",
+                self.max_sessions
+            )));
+        }
+
+        let token = Uuid::new_v4().hyphenated().to_string();
+        let ctx = SessionContext::new_with_state(self.ctx.state());
+        self.sessions
+            .insert(token.clone(), Arc::new(Session::new(ctx)));
+        Ok(token)
+    }
+
+    /// Returns the session of the client sending `request`
+    fn session<T>(&self, request: &Request<T>) -> Result<Arc<Session>, Status> {
+        let token = bearer_token(request)?;
+        let session = self
+            .sessions
+            .get(token)
+            .map(|session| Arc::clone(&session))
+            .ok_or_else(|| Status::unauthenticated(format!("unknown session {token}")))?;
+        if session.idle_time() >= self.idle_timeout {
+            self.sessions.remove(token);
+            return Err(Status::unauthenticated(format!("session {token} expired")));
+        }
+        *session.last_used.lock() = Instant::now();
+        Ok(session)
+    }
+}
+
+/// Returns the bearer token in the `authorization` header of `request`
+fn bearer_token<T>(request: &Request<T>) -> Result<&str, Status> {
+    let auth = request.metadata().get("authorization").ok_or_else(|| {
+        Status::unauthenticated("missing authorization header, perform a handshake first")
+    })?;
+    auth.to_str()
+        .ok()
+        .and_then(|auth| auth.strip_prefix("Bearer "))
+        .ok_or_else(|| Status::unauthenticated("invalid authorization header"))
+}
+
+/// Returns a [`FlightInfo`] for results with the given `schema` that are
+/// fetched with a single `DoGet` of `ticket`
+fn flight_info(
+    schema: &Schema,
+    ticket: impl ProstMessageExt,
+    descriptor: FlightDescriptor,
+) -> Result<FlightInfo, Status> {
+    let ticket = Ticket::new(ticket.as_any().encode_to_vec());
+    let info = FlightInfo::new()
+        .try_with_schema(schema)
+        .map_err(|e| Status::internal(format!("unable to encode schema: {e}")))?
+        .with_endpoint(FlightEndpoint::new().with_ticket(ticket))
+        .with_descriptor(descriptor);
+    Ok(info)
+}
+
+/// Runs `df`, streaming its results to the client as they are produced
+async fn execute(df: DataFrame) -> Result<Response<DoGetStream>, Status> {
+    let stream = df.execute_stream().await.map_err(to_status)?;
+    let stream = FlightDataEncoderBuilder::new()
+        .with_schema(stream.schema())
+        .build(stream.map_err(|e| FlightError::ExternalError(Box::new(e))))
+        .map_err(Status::from);
+    Ok(Response::new(Box::pin(stream)))
+}
+
+/// Runs a statement sent with `DoPut`, returning the number of affected rows
+/// for DML statements and -1 (unknown) otherwise
+async fn execute_update(df: DataFrame) -> Result<i64, Status> {
+    let is_dml = matches!(df.logical_plan(), LogicalPlan::Dml(_));
+    let batches = df.collect().await.map_err(to_status)?;
+    // DML statements return the number of affected rows as a single `count`
+    let count = batches
+        .first()
+        .filter(|_| is_dml)
+        .and_then(|batch| batch.column(0).as_primitive_opt::<UInt64Type>())
+        .map(|count| count.value(0) as i64);
+    Ok(count.unwrap_or(-1))
+}
+
+/// Streams a single metadata `batch` with the given `schema`
+fn metadata_stream(
+    schema: SchemaRef,
+    batch: Result<RecordBatch, ArrowError>,
+) -> Response<DoGetStream> {
+    let batches = stream::once(async move { batch.map_err(FlightError::from) });
+    let stream = FlightDataEncoderBuilder::new()
+        .with_schema(schema)
+        .build(batches)
+        .map_err(Status::from);
+    Response::new(Box::pin(stream))
+}
+
+fn to_status(e: DataFusionError) -> Status {
+    match e {
+        DataFusionError::Plan(_) | DataFusionError::SQL(_, _) => {
+            Status::invalid_argument(e.to_string())
+        }
+        _ => Status::internal(e.to_string()),
+    }
+}
+
+#[tonic::async_trait]
+impl FlightSqlService for FlightSqlServer {
+    type FlightService = FlightSqlServer;
+
+    async fn do_handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<
+        Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
+        Status,
+    > {
+        // No authentication takes place, every handshake creates a session
+        let token = self.create_session()?;
+        info!("do_handshake: created session {token}");
+
+        let result = HandshakeResponse {
+            protocol_version: 0,
+            payload: token.clone().into(),
+        };
+        let output = stream::iter(vec![Ok(result)]);
+        let mut response: Response<
+            Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>,
+        > = Response::new(Box::pin(output));
+        let authorization = MetadataValue::try_from(format!("Bearer {token}"))
+            .map_err(|e| Status::internal(format!("invalid token: {e}")))?;
+        response
+            .metadata_mut()
+            .insert("authorization", authorization);
+        Ok(response)
+    }
+
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        info!("get_flight_info_statement: {}", query.query);
+        let ctx = self.session(&request)?.ctx.clone();
+        // Only plan the query, so that statements with side effects run once
+        let plan = ctx
+            .state()
+            .create_logical_plan(&query.query)
+            .await
+            .map_err(to_status)?;
+
+        // The statement is planned again by `do_get_statement`, so that no
+        // state has to be kept for clients that never fetch the results
+        let ticket = TicketStatementQuery {
+            statement_handle: query.query.into(),
+        };
+        let info = flight_info(plan.schema().inner(), ticket, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        let query = String::from_utf8(ticket.statement_handle.to_vec())
+            .map_err(|e| Status::invalid_argument(format!("invalid ticket: {e}")))?;
+        info!("do_get_statement: {query}");
+        let ctx = self.session(&request)?.ctx.clone();
+        execute(ctx.sql(&query).await.map_err(to_status)?).await
+    }
+
+    async fn get_flight_info_prepared_statement(
+        &self,
+        cmd: CommandPreparedStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        info!("get_flight_info_prepared_statement");
+        let session = self.session(&request)?;
+        let plan = session.prepared_statement(&cmd.prepared_statement_handle)?;
+        let info = flight_info(plan.schema().inner(), cmd, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_prepared_statement(
+        &self,
+        query: CommandPreparedStatementQuery,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        info!("do_get_prepared_statement");
+        let session = self.session(&request)?;
+        let plan = session.prepared_statement(&query.prepared_statement_handle)?;
+        execute(
+            session
+                .ctx
+                .execute_logical_plan(plan)
+                .await
+                .map_err(to_status)?,
+        )
+        .await
+    }
+
+    async fn get_flight_info_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        info!("get_flight_info_catalogs");
+        self.session(&request)?;
+        let schema = query.clone().into_builder().schema();
+        let info = flight_info(&schema, query, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        info!("do_get_catalogs");
+        let ctx = self.session(&request)?.ctx.clone();
+        let mut builder = query.into_builder();
+        let mut catalogs = ctx.catalog_names();
+        catalogs.sort();
+        for catalog in catalogs {
+            builder.append(catalog);
+        }
+        Ok(metadata_stream(builder.schema(), builder.build()))
+    }
+
+    async fn get_flight_info_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        info!("get_flight_info_schemas");
+        self.session(&request)?;
+        let schema = query.clone().into_builder().schema();
+        let info = flight_info(&schema, query, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        info!("do_get_schemas");
+        let ctx = self.session(&request)?.ctx.clone();
+        // the builder applies the catalog and schema name filters
+        let mut builder = query.into_builder();
+        for catalog in ctx.catalog_names() {
+            let Some(catalog_provider) = ctx.catalog(&catalog) else {
+                continue;
+            };
+            for schema in catalog_provider.schema_names() {
+                builder.append(&catalog, schema);
+            }
+        }
+        Ok(metadata_stream(builder.schema(), builder.build()))
+    }
+
+    async fn get_flight_info_tables(
+        &self,
+        query: CommandGetTables,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        info!("get_flight_info_tables");
+        self.session(&request)?;
+        let schema = query.clone().into_builder().schema();
+        let info = flight_info(&schema, query, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_tables(
+        &self,
+        query: CommandGetTables,
+        request: Request<Ticket>,
+    ) -> Result<Response<DoGetStream>, Status> {
+        info!("do_get_tables");
+        let ctx = self.session(&request)?.ctx.clone();
+        // the builder applies the name and table type filters
+        let mut builder = query.into_builder();
+        for catalog in ctx.catalog_names() {
+            let Some(catalog_provider) = ctx.catalog(&catalog) else {
+                continue;
+            };
+            for schema in catalog_provider.schema_names() {
+                let Some(schema_provider) = catalog_provider.schema(&schema) else {
+                    continue;
+                };
+                for table in schema_provider.table_names() {
+                    let Some(table_provider) =
+                        schema_provider.table(&table).await.map_err(to_status)?
+                    else {
+                        continue;
+                    };
+                    builder
+                        .append(
+                            &catalog,
+                            &schema,
+                            table,
+                            table_provider.table_type().to_string(),
+                            &table_provider.schema(),
+                        )
+                        .map_err(|e| Status::internal(e.to_string()))?;
+                }
+            }
+        }
+        Ok(metadata_stream(builder.schema(), builder.build()))
+    }
+
+    async fn do_put_statement_update(
+        &self,
+        ticket: CommandStatementUpdate,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<i64, Status> {
+        info!("do_put_statement_update: {}", ticket.query);
+        let ctx = self.session(&request)?.ctx.clone();
+        execute_update(ctx.sql(&ticket.query).await.map_err(to_status)?).await
+    }
+
+    async fn do_put_prepared_statement_update(
+        &self,
+        query: CommandPreparedStatementUpdate,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<i64, Status> {
+        info!("do_put_prepared_statement_update");
+        let session = self.session(&request)?;
+        let plan = session.prepared_statement(&query.prepared_statement_handle)?;
+        let df = session
+            .ctx
+            .execute_logical_plan(plan)
+            .await
+            .map_err(to_status)?;
+        execute_update(df).await
+    }
+
+    async fn do_action_create_prepared_statement(
+        &self,
+        query: ActionCreatePreparedStatementRequest,
+        request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        info!("do_action_create_prepared_statement: {}", query.query);
+        let session = self.session(&request)?;
+        // The statement is only planned: it runs when it is executed
+        let plan = session
+            .ctx
+            .state()
+            .create_logical_plan(&query.query)
+            .await
+            .map_err(to_status)?;
+        // Binding parameters with `DoPut` is not implemented
+        if !plan.get_parameter_types().map_err(to_status)?.is_empty() {
+            return Err(Status::unimplemented(
+                "prepared statements with parameters are not supported",
+            ));
+        }
+
+        let message =
+            SchemaAsIpc::new(plan.schema().inner(), &IpcWriteOptions::default())
+                .try_into()
+                .map_err(|e: ArrowError| {
+                    Status::internal(format!("unable to encode schema: {e}"))
+                })?;
+        let IpcMessage(dataset_schema) = message;
+
+        let handle = Uuid::new_v4().hyphenated().to_string();
+        session.statements.insert(handle.clone(), plan);
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: handle.into(),
+            dataset_schema,
+            // the statement has no parameters
+            parameter_schema: Default::default(),
+        })
+    }
+
+    async fn do_action_close_prepared_statement(
+        &self,
+        query: ActionClosePreparedStatementRequest,
+        request: Request<Action>,
+    ) -> Result<(), Status> {
+        let session = self.session(&request)?;
+        if let Ok(handle) = std::str::from_utf8(&query.prepared_statement_handle) {
+            info!("do_action_close_prepared_statement: {handle}");
+            session.statements.remove(handle);
+        }
+        Ok(())
+    }
+
+    async fn do_action_close_session(
+        &self,
+        _query: CloseSessionRequest,
+        request: Request<Action>,
+    ) -> Result<CloseSessionResult, Status> {
+        let token = bearer_token(&request)?;
+        info!("do_action_close_session: {token}");
+        if self.sessions.remove(token).is_none() {
+            return Err(Status::unauthenticated(format!("unknown session {token}")));
+        }
+        Ok(CloseSessionResult {
+            status: CloseSessionStatus::Closed as i32,
+        })
+    }
+
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_flight::decode::FlightRecordBatchStream;
+    use datafusion::arrow::array::Int32Array;
+    use datafusion::arrow::datatypes::{DataType, Field};
+    use datafusion::assert_batches_eq;
+
+    fn server() -> Result<FlightSqlServer> {
+        let ctx = SessionContext::new();
+        let schema = Arc::new(Schema::new(vec![Field::new("i", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        ctx.register_batch("t", batch)?;
+        Ok(FlightSqlServer::new(ctx))
+    }
+
+    /// Returns a request from the session with bearer `token`
+    fn request<T>(token: &str, message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        let authorization = MetadataValue::try_from(format!("Bearer {token}")).unwrap();
+        request
+            .metadata_mut()
+            .insert("authorization", authorization);
+        request
+    }
+
+    fn descriptor(token: &str, cmd: impl ProstMessageExt) -> Request<FlightDescriptor> {
+        request(
+            token,
+            FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec()),
+        )
+    }
+
+    /// Fetches the results described by `info` with `DoGet`
+    async fn fetch(
+        server: &FlightSqlServer,
+        token: &str,
+        info: Response<FlightInfo>,
+    ) -> Result<Vec<RecordBatch>> {
+        let info = info.into_inner();
+        assert_eq!(info.endpoint.len(), 1);
+        let ticket = info.endpoint[0].ticket.clone().unwrap();
+        let stream = server
+            .do_get(request(token, ticket))
+            .await
+            .unwrap()
+            .into_inner();
+        let batches: Vec<RecordBatch> = FlightRecordBatchStream::new_from_flight_data(
+            stream.map_err(FlightError::from),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        Ok(batches)
+    }
+
+    async fn create_prepared_statement(
+        server: &FlightSqlServer,
+        token: &str,
+        query: &str,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        let request_message = ActionCreatePreparedStatementRequest {
+            query: query.to_string(),
+            transaction_id: None,
+        };
+        let action = request(token, Action::new("CreatePreparedStatement", ""));
+        server
+            .do_action_create_prepared_statement(request_message, action)
+            .await
+    }
+
+    #[tokio::test]
+    async fn statement_query() -> Result<()> {
+        let server = server()?;
+        let token = server.create_session().unwrap();
+        let query = CommandStatementQuery {
+            query: "SELECT i * 2 AS j FROM t ORDER BY i".to_string(),
+            transaction_id: None,
+        };
+        let info = server
+            .get_flight_info_statement(query.clone(), descriptor(&token, query))
+            .await
+            .unwrap();
+        let schema = info.get_ref().clone().try_decode_schema().unwrap();
+        assert_eq!(schema.field(0).name(), "j");
+
+        let expected = [
+            "+---+", "| j |", "+---+", "| 2 |", "| 4 |", "| 6 |", "+---+",
+        ];
+        assert_batches_eq!(expected, &fetch(&server, &token, info).await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn statement_runs_on_do_get() -> Result<()> {
+        let server = server()?;
+        let token = server.create_session().unwrap();
+        let query = CommandStatementQuery {
+            query: "CREATE TABLE t2 AS VALUES (1)".to_string(),
+            transaction_id: None,
+        };
+        let info = server
+            .get_flight_info_statement(query.clone(), descriptor(&token, query))
+            .await
+            .unwrap();
+        // getting the flight info only plans the statement
+        assert!(!server.ctx.table_exist("t2")?);
+
+        fetch(&server, &token, info).await?;
+        assert!(server.ctx.table_exist("t2")?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unauthenticated_requests() -> Result<()> {
+        let server = server()?;
+        let query = CommandGetCatalogs {};
+
+        // requests without a session are rejected, instead of running in the
+        // shared session
+        let request =
+            Request::new(FlightDescriptor::new_cmd(query.as_any().encode_to_vec()));
+        let err = server
+            .get_flight_info_catalogs(query.clone(), request)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+        let ticket = Ticket::new(query.as_any().encode_to_vec());
+        let err = server.do_get(Request::new(ticket)).await.err().unwrap();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+        let ticket = Ticket::new(query.as_any().encode_to_vec());
+        let err = server
+            .do_get(request("unknown", ticket))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prepared_statement() -> Result<()> {
+        let server = server()?;
+        let token = server.create_session().unwrap();
+        let result =
+            create_prepared_statement(&server, &token, "SELECT count(*) AS c FROM t")
+                .await
+                .unwrap();
+
+        let query = CommandPreparedStatementQuery {
+            prepared_statement_handle: result.prepared_statement_handle.clone(),
+        };
+        // a prepared statement can be executed several times
+        let expected = ["+---+", "| c |", "+---+", "| 3 |", "+---+"];
+        for _ in 0..2 {
+            let info = server
+                .get_flight_info_prepared_statement(
+                    query.clone(),
+                    descriptor(&token, query.clone()),
+                )
+                .await
+                .unwrap();
+            assert_batches_eq!(expected, &fetch(&server, &token, info).await?);
+        }
+
+        // prepared statements belong to the session that created them
+        let other_token = server.create_session().unwrap();
+        let err = server
+            .get_flight_info_prepared_statement(
+                query.clone(),
+                descriptor(&other_token, query.clone()),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+
+        let close = ActionClosePreparedStatementRequest {
+            prepared_statement_handle: result.prepared_statement_handle,
+        };
+        let action = request(&token, Action::new("ClosePreparedStatement", ""));
+        server
+            .do_action_close_prepared_statement(close, action)
+            .await
+            .unwrap();
+        let err = server
+            .get_flight_info_prepared_statement(query.clone(), descriptor(&token, query))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prepared_statement_with_parameters() -> Result<()> {
+        let server = server()?;
+        let token = server.create_session().unwrap();
+        let err =
+            create_prepared_statement(&server, &token, "SELECT i FROM t WHERE i = $1")
+                .await
+                .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unimplemented);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn close_session() -> Result<()> {
+        let server = server()?.with_max_sessions(1);
+        let token = server.create_session().unwrap();
+
+        // no more sessions can be created while one is open
+        let err = server.create_session().unwrap_err();
+        assert_eq!(err.code(), tonic::Code::ResourceExhausted);
+
+        let action = request(&token, Action::new("CloseSession", ""));
+        let result = server
+            .do_action_close_session(CloseSessionRequest {}, action)
+            .await
+            .unwrap();
+        assert_eq!(result.status, CloseSessionStatus::Closed as i32);
+
+        // the closed session can not be used anymore
+        let query = CommandGetCatalogs {};
+        let err = server
+            .get_flight_info_catalogs(query.clone(), descriptor(&token, query))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+        server.create_session().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn idle_sessions_expire() -> Result<()> {
+        let server = server()?
+            .with_idle_timeout(Duration::ZERO)
+            .with_max_sessions(1);
+        let token = server.create_session().unwrap();
+
+        let query = CommandGetCatalogs {};
+        let err = server
+            .get_flight_info_catalogs(query.clone(), descriptor(&token, query))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+        assert!(server.sessions.is_empty());
+
+        // expired sessions do not count towards the maximum
+        server.create_session().unwrap();
+        server.create_session().unwrap();
+        assert_eq!(server.sessions.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn catalogs_and_tables() -> Result<()> {
+        let server = server()?;
+        let token = server.create_session().unwrap();
+        let query = CommandGetCatalogs {};
+        let info = server
+            .get_flight_info_catalogs(query.clone(), descriptor(&token, query))
+            .await
+            .unwrap();
+        let expected = [
+            "+--------------+",
+            "| catalog_name |",
+            "+--------------+",
+            "| datafusion   |",
+            "+--------------+",
+        ];
+        assert_batches_eq!(expected, &fetch(&server, &token, info).await?);
+
+        let query = CommandGetTables {
+            catalog: None,
+            db_schema_filter_pattern: None,
+            table_name_filter_pattern: Some("t".to_string()),
+            table_types: vec![],
+            include_schema: false,
+        };
+        let info = server
+            .get_flight_info_tables(query.clone(), descriptor(&token, query))
+            .await
+            .unwrap();
+        let expected = [
+            "+--------------+----------------+------------+------------+",
+            "| catalog_name | db_schema_name | table_name | table_type |",
+            "+--------------+----------------+------------+------------+",
+            "| datafusion   | public         | t          | BASE TABLE |",
+            "+--------------+----------------+------------+------------+",
+        ];
+        assert_batches_eq!(expected, &fetch(&server, &token, info).await?);
+        Ok(())
+    }
+}
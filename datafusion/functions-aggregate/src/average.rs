@@ -28,12 +28,15 @@ use arrow::datatypes::{
 };
 use datafusion_common::{exec_err, not_impl_err, Result, ScalarValue};
 use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
-use datafusion_expr::type_coercion::aggregates::{avg_return_type, coerce_avg_type};
+use datafusion_expr::type_coercion::aggregates::{
+    avg_return_type, coerce_avg_type, widen_decimal_sum_type,
+};
 use datafusion_expr::utils::format_state_name;
 use datafusion_expr::Volatility::Immutable;
 use datafusion_expr::{
     Accumulator, AggregateUDFImpl, EmitTo, GroupsAccumulator, ReversedUDAF, Signature,
 };
+use datafusion_physical_expr_common::aggregate::distinct::DistinctAccumulator;
 use datafusion_physical_expr_common::aggregate::groups_accumulator::accumulate::NullState;
 use datafusion_physical_expr_common::aggregate::utils::DecimalAverager;
 use log::debug;
@@ -89,7 +92,14 @@ impl AggregateUDFImpl for Avg {
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
         if acc_args.is_distinct {
-            return exec_err!("avg(DISTINCT) aggregations are not available");
+            let accumulator = self.accumulator(AccumulatorArgs {
+                is_distinct: false,
+                ..acc_args
+            })?;
+            return Ok(Box::new(DistinctAccumulator::try_new(
+                acc_args.input_type,
+                accumulator,
+            )?));
         }
         use DataType::*;
         // instantiate specialized accumulator based for the type
@@ -127,6 +137,12 @@ impl AggregateUDFImpl for Avg {
     }
 
     fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        if args.is_distinct {
+            return Ok(vec![DistinctAccumulator::state_field(
+                args.name,
+                args.input_type,
+            )]);
+        }
         Ok(vec![
             Field::new(
                 format_state_name(args.name, "count"),
@@ -142,10 +158,13 @@ impl AggregateUDFImpl for Avg {
     }
 
     fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
-        matches!(
-            args.data_type,
-            DataType::Float64 | DataType::Decimal128(_, _)
-        )
+        !args.is_distinct
+            && matches!(
+                args.data_type,
+                DataType::Float64
+                    | DataType::Decimal128(_, _)
+                    | DataType::Decimal256(_, _)
+            )
     }
 
     fn create_groups_accumulator(
@@ -232,6 +251,10 @@ impl AggregateUDFImpl for Avg {
         }
         coerce_avg_type(self.name(), arg_types)
     }
+
+    fn widen_decimal_types(&self, arg_types: &[DataType]) -> Option<Vec<DataType>> {
+        Some(vec![widen_decimal_sum_type(&arg_types[0])?])
+    }
 }
 
 /// An accumulator to compute the average
@@ -158,8 +158,10 @@ impl Accumulator for CorrelationAccumulator {
         if let ScalarValue::Float64(Some(c)) = covar {
             if let ScalarValue::Float64(Some(s1)) = stddev1 {
                 if let ScalarValue::Float64(Some(s2)) = stddev2 {
+                    // like PostgreSQL, the correlation is undefined when
+                    // either input is constant
                     if s1 == 0_f64 || s2 == 0_f64 {
-                        return Ok(ScalarValue::Float64(Some(0_f64)));
+                        return Ok(ScalarValue::Float64(None));
                     } else {
                         return Ok(ScalarValue::Float64(Some(c / s1 / s2)));
                     }
@@ -206,6 +208,10 @@ impl Accumulator for CorrelationAccumulator {
         Ok(())
     }
 
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+
     fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = if values[0].null_count() != 0 || values[1].null_count() != 0 {
             let mask = and(&is_not_null(&values[0])?, &is_not_null(&values[1])?)?;
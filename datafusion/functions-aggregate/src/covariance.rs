@@ -297,6 +297,10 @@ impl Accumulator for CovarianceAccumulator {
         Ok(())
     }
 
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+
     fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values1 = &cast(&values[0], &DataType::Float64)?;
         let values2 = &cast(&values[1], &DataType::Float64)?;
@@ -322,6 +326,16 @@ impl Accumulator for CovarianceAccumulator {
             let value1 = unwrap_or_internal_err!(value1);
             let value2 = unwrap_or_internal_err!(value2);
 
+            if self.count <= 1 {
+                // the last value is removed, restart from an empty state
+                // rather than dividing by a zero count
+                self.count = 0;
+                self.mean1 = 0_f64;
+                self.mean2 = 0_f64;
+                self.algo_const = 0_f64;
+                continue;
+            }
+
             let new_count = self.count - 1;
             let delta1 = self.mean1 - value1;
             let new_mean1 = delta1 / new_count as f64 + self.mean1;
@@ -62,6 +62,7 @@ pub mod covariance;
 pub mod first_last;
 pub mod hyperloglog;
 pub mod median;
+pub mod mode;
 pub mod regr;
 pub mod stddev;
 pub mod sum;
@@ -105,6 +106,7 @@ pub mod expr_fn {
     pub use super::first_last::last_value;
     pub use super::grouping::grouping;
     pub use super::median::median;
+    pub use super::mode::mode;
     pub use super::regr::regr_avgx;
     pub use super::regr::regr_avgy;
     pub use super::regr::regr_count;
@@ -131,6 +133,7 @@ pub fn all_default_aggregate_functions() -> Vec<Arc<AggregateUDF>> {
         correlation::corr_udaf(),
         sum::sum_udaf(),
         median::median_udaf(),
+        mode::mode_udaf(),
         count::count_udaf(),
         regr::regr_slope_udaf(),
         regr::regr_intercept_udaf(),
@@ -0,0 +1,269 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the `mode` aggregate function, the most frequent value of a set
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use ahash::RandomState;
+use arrow::array::{Array, ArrayRef, AsArray, Int64Array};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Int64Type};
+use arrow::row::{OwnedRow, RowConverter, SortField};
+use datafusion_common::utils::array_into_list_array_nullable;
+use datafusion_common::{not_impl_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::{format_state_name, AggregateOrderSensitivity};
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+
+make_udaf_expr_and_func!(
+    Mode,
+    mode,
+    expression,
+    "Returns the most frequent value of a set of values",
+    mode_udaf
+);
+
+/// MODE aggregate expression: the most frequent non-null value, the smallest
+/// of them in case of a tie.
+///
+/// Like `MEDIAN`, this counts every distinct value in memory before a result
+/// can be computed, so the memory usage is proportional to the cardinality of
+/// the input. The counts are the intermediate state, so that grouped
+/// aggregations can spill them to disk when the memory is limited.
+#[derive(Debug)]
+pub struct Mode {
+    signature: Signature,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Mode {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for Mode {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "mode"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(value_type(&arg_types[0]).clone())
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        Ok(vec![
+            Field::new_list(
+                format_state_name(args.name, "values"),
+                Field::new("item", args.return_type.clone(), true),
+                true,
+            ),
+            Field::new_list(
+                format_state_name(args.name, "counts"),
+                Field::new("item", DataType::Int64, true),
+                true,
+            ),
+        ])
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        if acc_args.is_distinct {
+            return not_impl_err!("MODE(DISTINCT) aggregations are not available");
+        }
+        Ok(Box::new(ModeAccumulator::try_new(acc_args.data_type)?))
+    }
+
+    fn order_sensitivity(&self) -> AggregateOrderSensitivity {
+        AggregateOrderSensitivity::Insensitive
+    }
+}
+
+/// Counts the values in the [row format], whose order is the order of the
+/// values, to find the smallest of the most frequent values.
+///
+/// [row format]: arrow::row
+struct ModeAccumulator {
+    data_type: DataType,
+    converter: RowConverter,
+    counts: HashMap<OwnedRow, i64, RandomState>,
+}
+
+impl Debug for ModeAccumulator {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ModeAccumulator({})", self.data_type)
+    }
+}
+
+impl ModeAccumulator {
+    fn try_new(data_type: &DataType) -> Result<Self> {
+        Ok(Self {
+            data_type: data_type.clone(),
+            converter: RowConverter::new(vec![SortField::new(data_type.clone())])?,
+            counts: HashMap::default(),
+        })
+    }
+
+    /// Adds `counts` to the counts of `values`, ignoring NULL values
+    fn add_counts(
+        &mut self,
+        values: &ArrayRef,
+        counts: impl Fn(usize) -> i64,
+    ) -> Result<()> {
+        let values = if values.data_type() == &self.data_type {
+            Arc::clone(values)
+        } else {
+            cast(values, &self.data_type)?
+        };
+        let nulls = values.logical_nulls();
+        let rows = self.converter.convert_columns(&[values])?;
+        for (i, row) in rows.iter().enumerate() {
+            if nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i)) {
+                *self.counts.entry(row.owned()).or_default() += counts(i);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Accumulator for ModeAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.add_counts(&values[0], |_| 1)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let values = states[0].as_list::<i32>();
+        let counts = states[1].as_list::<i32>();
+        for (values, counts) in values.iter().zip(counts.iter()) {
+            let (Some(values), Some(counts)) = (values, counts) else {
+                continue;
+            };
+            let counts = counts.as_primitive::<Int64Type>();
+            self.add_counts(&values, |i| counts.value(i))?;
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let values = self
+            .converter
+            .convert_rows(self.counts.keys().map(|row| row.row()))?;
+        let counts = Int64Array::from_iter_values(self.counts.values().copied());
+        Ok(vec![
+            ScalarValue::List(Arc::new(array_into_list_array_nullable(Arc::clone(
+                &values[0],
+            )))),
+            ScalarValue::List(Arc::new(array_into_list_array_nullable(Arc::new(counts)))),
+        ])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let mode = self.counts.iter().max_by(|(row1, count1), (row2, count2)| {
+            count1.cmp(count2).then_with(|| row2.cmp(row1))
+        });
+        let Some((row, _)) = mode else {
+            return ScalarValue::try_from(&self.data_type);
+        };
+        let values = self.converter.convert_rows([row.row()])?;
+        ScalarValue::try_from_array(&values[0], 0)
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self.converter.size()
+            + self.counts.capacity()
+                * (std::mem::size_of::<OwnedRow>() + std::mem::size_of::<i64>())
+            + self
+                .counts
+                .keys()
+                .map(|row| row.as_ref().len())
+                .sum::<usize>()
+    }
+}
+
+/// Returns the type of the values of `data_type`, unpacking dictionaries
+fn value_type(data_type: &DataType) -> &DataType {
+    match data_type {
+        DataType::Dictionary(_, values) => value_type(values),
+        _ => data_type,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::StringArray;
+
+    #[test]
+    fn mode_of_merged_states() -> Result<()> {
+        let mut acc1 = ModeAccumulator::try_new(&DataType::Utf8)?;
+        let values: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("b"),
+            Some("a"),
+            None,
+            Some("b"),
+        ]));
+        acc1.update_batch(&[values])?;
+
+        let mut acc2 = ModeAccumulator::try_new(&DataType::Utf8)?;
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "c", "a"]));
+        acc2.update_batch(&[values])?;
+        let state = acc2
+            .state()?
+            .iter()
+            .map(|value| value.to_array())
+            .collect::<Result<Vec<_>>>()?;
+
+        // "a" and "b" are both found twice before merging
+        assert_eq!(acc1.evaluate()?, ScalarValue::from("a"));
+        acc1.merge_batch(&state)?;
+        assert_eq!(acc1.evaluate()?, ScalarValue::from("a"));
+
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["b", "b"]));
+        acc1.update_batch(&[values])?;
+        assert_eq!(acc1.evaluate()?, ScalarValue::from("b"));
+        Ok(())
+    }
+
+    #[test]
+    fn mode_of_no_values() -> Result<()> {
+        let mut acc = ModeAccumulator::try_new(&DataType::Int32)?;
+        let values: ArrayRef = Arc::new(arrow::array::Int32Array::from(vec![None]));
+        acc.update_batch(&[values])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Int32(None));
+        Ok(())
+    }
+}
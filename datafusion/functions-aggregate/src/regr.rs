@@ -482,12 +482,15 @@ impl Accumulator for RegrAccumulator {
             }
             RegrType::Count => Ok(ScalarValue::Float64(Some(self.count as f64))),
             RegrType::R2 => {
-                // Only 0/1 point or all x(or y) is the same
-                let nullif_cond = self.count <= 1 || var_pop_x == 0.0 || var_pop_y == 0.0;
-                nullif_or_stat(
-                    nullif_cond,
-                    (cov_pop_x_y * cov_pop_x_y) / (var_pop_x * var_pop_y),
-                )
+                // Only 0/1 point or all x is the same
+                let nullif_cond = self.count <= 1 || var_pop_x == 0.0;
+                // All y is the same: the fit is perfect, as in PostgreSQL
+                let r2 = if var_pop_y == 0.0 {
+                    1.0
+                } else {
+                    (cov_pop_x_y * cov_pop_x_y) / (var_pop_x * var_pop_y)
+                };
+                nullif_or_stat(nullif_cond, r2)
             }
             RegrType::AvgX => nullif_or_stat(self.count < 1, self.mean_x),
             RegrType::AvgY => nullif_or_stat(self.count < 1, self.mean_y),
@@ -20,16 +20,22 @@
 use std::any::Any;
 use std::fmt::{Debug, Formatter};
 
-use arrow::{array::ArrayRef, datatypes::DataType, datatypes::Field};
+use std::sync::Arc;
 
-use datafusion_common::{internal_err, not_impl_err, Result};
+use arrow::array::{ArrayRef, BooleanArray};
+use arrow::datatypes::{DataType, Field, Float64Type};
+
+use datafusion_common::{internal_err, Result};
 use datafusion_common::{plan_err, ScalarValue};
 use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
 use datafusion_expr::utils::format_state_name;
-use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use datafusion_expr::{
+    Accumulator, AggregateUDFImpl, EmitTo, GroupsAccumulator, Signature, Volatility,
+};
+use datafusion_physical_expr_common::aggregate::distinct::DistinctAccumulator;
 use datafusion_physical_expr_common::aggregate::stats::StatsType;
 
-use crate::variance::VarianceAccumulator;
+use crate::variance::{VarianceAccumulator, VarianceGroupsAccumulator};
 
 make_udaf_expr_and_func!(
     Stddev,
@@ -93,6 +99,12 @@ impl AggregateUDFImpl for Stddev {
     }
 
     fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        if args.is_distinct {
+            return Ok(vec![DistinctAccumulator::state_field(
+                args.name,
+                args.input_type,
+            )]);
+        }
         Ok(vec![
             Field::new(
                 format_state_name(args.name, "count"),
@@ -109,10 +121,25 @@ impl AggregateUDFImpl for Stddev {
     }
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let accumulator = Box::new(StddevAccumulator::try_new(StatsType::Sample)?);
         if acc_args.is_distinct {
-            return not_impl_err!("STDDEV_POP(DISTINCT) aggregations are not available");
+            return Ok(Box::new(DistinctAccumulator::try_new(
+                acc_args.input_type,
+                accumulator,
+            )?));
         }
-        Ok(Box::new(StddevAccumulator::try_new(StatsType::Sample)?))
+        Ok(accumulator)
+    }
+
+    fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
+        !args.is_distinct
+    }
+
+    fn create_groups_accumulator(
+        &self,
+        _args: AccumulatorArgs,
+    ) -> Result<Box<dyn GroupsAccumulator>> {
+        Ok(Box::new(StddevGroupsAccumulator::new(StatsType::Sample)))
     }
 
     fn aliases(&self) -> &[String] {
@@ -172,6 +199,12 @@ impl AggregateUDFImpl for StddevPop {
     }
 
     fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        if args.is_distinct {
+            return Ok(vec![DistinctAccumulator::state_field(
+                args.name,
+                args.input_type,
+            )]);
+        }
         Ok(vec![
             Field::new(
                 format_state_name(args.name, "count"),
@@ -188,10 +221,27 @@ impl AggregateUDFImpl for StddevPop {
     }
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let accumulator = Box::new(StddevAccumulator::try_new(StatsType::Population)?);
         if acc_args.is_distinct {
-            return not_impl_err!("STDDEV_POP(DISTINCT) aggregations are not available");
+            return Ok(Box::new(DistinctAccumulator::try_new(
+                acc_args.input_type,
+                accumulator,
+            )?));
         }
-        Ok(Box::new(StddevAccumulator::try_new(StatsType::Population)?))
+        Ok(accumulator)
+    }
+
+    fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
+        !args.is_distinct
+    }
+
+    fn create_groups_accumulator(
+        &self,
+        _args: AccumulatorArgs,
+    ) -> Result<Box<dyn GroupsAccumulator>> {
+        Ok(Box::new(StddevGroupsAccumulator::new(
+            StatsType::Population,
+        )))
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
@@ -267,12 +317,65 @@ impl Accumulator for StddevAccumulator {
     }
 }
 
+/// A [`GroupsAccumulator`] to compute the standard deviation of each group
+#[derive(Debug)]
+pub struct StddevGroupsAccumulator {
+    variance: VarianceGroupsAccumulator,
+}
+
+impl StddevGroupsAccumulator {
+    /// Creates a new `StddevGroupsAccumulator`
+    pub fn new(s_type: StatsType) -> Self {
+        Self {
+            variance: VarianceGroupsAccumulator::new(s_type),
+        }
+    }
+}
+
+impl GroupsAccumulator for StddevGroupsAccumulator {
+    fn update_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        self.variance
+            .update_batch(values, group_indices, opt_filter, total_num_groups)
+    }
+
+    fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
+        let variance = self.variance.variance(emit_to);
+        Ok(Arc::new(variance.unary::<_, Float64Type>(f64::sqrt)))
+    }
+
+    fn state(&mut self, emit_to: EmitTo) -> Result<Vec<ArrayRef>> {
+        self.variance.state(emit_to)
+    }
+
+    fn merge_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        self.variance
+            .merge_batch(values, group_indices, opt_filter, total_num_groups)
+    }
+
+    fn size(&self) -> usize {
+        self.variance.size()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
     use arrow::{array::*, datatypes::*};
 
+    use datafusion_common::config::ArithmeticOverflowMode;
     use datafusion_expr::AggregateUDF;
     use datafusion_physical_expr_common::aggregate::utils::get_accum_scalar_values_as_arrays;
     use datafusion_physical_expr_common::expressions::column::col;
@@ -317,6 +420,75 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn stddev_groups_accumulator() -> Result<()> {
+        let mut acc = StddevGroupsAccumulator::new(StatsType::Population);
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(1_f64),
+            Some(2_f64),
+            None,
+            Some(3_f64),
+            Some(4_f64),
+            Some(5_f64),
+            Some(7_f64),
+        ]));
+        acc.update_batch(&[values], &[0, 0, 1, 0, 1, 1, 2], None, 4)?;
+
+        // merge the state of another accumulator, whose first groups are empty
+        let mut other = StddevGroupsAccumulator::new(StatsType::Population);
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![4_f64, 5_f64]));
+        other.update_batch(&[values], &[2, 2], None, 3)?;
+        let state = other.state(EmitTo::All)?;
+        acc.merge_batch(&state, &[0, 1, 2], None, 4)?;
+
+        let actual = acc.evaluate(EmitTo::All)?;
+        let actual = actual.as_primitive::<Float64Type>();
+        let expected = [(2_f64 / 3_f64).sqrt(), 0.5, 14_f64.sqrt() / 3_f64];
+        for (i, expected) in expected.into_iter().enumerate() {
+            assert!((actual.value(i) - expected).abs() < 1e-12, "{actual:?}");
+        }
+        // the group without values is null
+        assert!(actual.is_null(3));
+
+        // the sample standard deviation of a single value is null
+        let mut acc = StddevGroupsAccumulator::new(StatsType::Sample);
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![1_f64, 2_f64, 3_f64]));
+        let filter = BooleanArray::from(vec![true, false, true]);
+        acc.update_batch(&[values], &[0, 0, 1], Some(&filter), 2)?;
+        let actual = acc.evaluate(EmitTo::All)?;
+        assert_eq!(actual.null_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn stddev_groups_accumulator_emit_first() -> Result<()> {
+        let mut acc = StddevGroupsAccumulator::new(StatsType::Sample);
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![
+            1_f64, 3_f64, 2_f64, 6_f64, 5_f64, 11_f64,
+        ]));
+        acc.update_batch(&[values], &[0, 0, 1, 1, 2, 2], None, 3)?;
+
+        let first = acc.evaluate(EmitTo::First(1))?;
+        let first = first.as_primitive::<Float64Type>();
+        assert_eq!(first.len(), 1);
+        assert!((first.value(0) - 2_f64.sqrt()).abs() < 1e-12, "{first:?}");
+
+        // the state of the remaining groups round trips through merge_batch
+        let state = acc.state(EmitTo::All)?;
+        let mut merged = StddevGroupsAccumulator::new(StatsType::Sample);
+        let filter = BooleanArray::from(vec![true, true]);
+        merged.merge_batch(&state, &[1, 0], Some(&filter), 2)?;
+        let actual = merged.evaluate(EmitTo::All)?;
+        let actual = actual.as_primitive::<Float64Type>();
+        let expected = [18_f64.sqrt(), 8_f64.sqrt()];
+        for (i, expected) in expected.into_iter().enumerate() {
+            assert!((actual.value(i) - expected).abs() < 1e-12, "{actual:?}");
+        }
+
+        Ok(())
+    }
+
     fn merge(
         batch1: &RecordBatch,
         batch2: &RecordBatch,
@@ -333,6 +505,7 @@ mod tests {
             is_distinct: false,
             input_type: &DataType::Float64,
             input_exprs: &[datafusion_expr::col("a")],
+            arithmetic_overflow_mode: ArithmeticOverflowMode::Wrap,
         };
 
         let args2 = AccumulatorArgs {
@@ -344,6 +517,7 @@ mod tests {
             is_distinct: false,
             input_type: &DataType::Float64,
             input_exprs: &[datafusion_expr::col("a")],
+            arithmetic_overflow_mode: ArithmeticOverflowMode::Wrap,
         };
 
         let mut accum1 = agg1.accumulator(args1)?;
@@ -17,16 +17,24 @@
 
 //! [`StringAgg`] and [`StringAggAccumulator`] accumulator for the `string_agg` function
 
-use arrow::array::ArrayRef;
-use arrow_schema::DataType;
+use arrow::array::{new_empty_array, ArrayRef, AsArray, LargeStringArray, StructArray};
+use arrow::compute::{lexsort_to_indices, SortColumn, SortOptions};
+use arrow_schema::{DataType, Field, Fields};
 use datafusion_common::cast::as_generic_string_array;
+use datafusion_common::utils::{array_into_list_array, get_row_at_idx};
 use datafusion_common::Result;
-use datafusion_common::{not_impl_err, ScalarValue};
-use datafusion_expr::function::AccumulatorArgs;
+use datafusion_common::{exec_err, not_impl_err, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::{format_state_name, AggregateOrderSensitivity};
 use datafusion_expr::{
     Accumulator, AggregateUDFImpl, Expr, Signature, TypeSignature, Volatility,
 };
+use datafusion_physical_expr_common::aggregate::utils::{
+    get_sort_options, ordering_fields,
+};
+use datafusion_physical_expr_common::sort_expr::limited_convert_logical_sort_exprs_to_physical;
 use std::any::Any;
+use std::sync::Arc;
 
 make_udaf_expr_and_func!(
     StringAgg,
@@ -82,21 +90,73 @@ impl AggregateUDFImpl for StringAgg {
     }
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
-        match &acc_args.input_exprs[1] {
+        let delimiter = match &acc_args.input_exprs[1] {
             Expr::Literal(ScalarValue::Utf8(Some(delimiter)))
             | Expr::Literal(ScalarValue::LargeUtf8(Some(delimiter))) => {
-                Ok(Box::new(StringAggAccumulator::new(delimiter)))
+                delimiter.as_str()
             }
             Expr::Literal(ScalarValue::Utf8(None))
             | Expr::Literal(ScalarValue::LargeUtf8(None))
-            | Expr::Literal(ScalarValue::Null) => {
-                Ok(Box::new(StringAggAccumulator::new("")))
+            | Expr::Literal(ScalarValue::Null) => "",
+            _ => {
+                return not_impl_err!(
+                    "StringAgg not supported for delimiter {}",
+                    &acc_args.input_exprs[1]
+                )
             }
-            _ => not_impl_err!(
-                "StringAgg not supported for delimiter {}",
-                &acc_args.input_exprs[1]
-            ),
+        };
+        if acc_args.sort_exprs.is_empty() {
+            return Ok(Box::new(StringAggAccumulator::new(delimiter)));
+        }
+
+        let ordering_req = limited_convert_logical_sort_exprs_to_physical(
+            acc_args.sort_exprs,
+            acc_args.schema,
+        )?;
+        let ordering_dtypes = ordering_req
+            .iter()
+            .map(|e| e.expr.data_type(acc_args.schema))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Box::new(OrderedStringAggAccumulator::new(
+            delimiter,
+            ordering_fields(&ordering_req, &ordering_dtypes),
+            get_sort_options(&ordering_req),
+        )))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        if args.ordering_fields.is_empty() {
+            return Ok(vec![Field::new(
+                format_state_name(args.name, "value"),
+                DataType::LargeUtf8,
+                true,
+            )]);
         }
+        let orderings = DataType::Struct(Fields::from(args.ordering_fields.to_vec()));
+        Ok(vec![
+            Field::new_list(
+                format_state_name(args.name, "string_agg"),
+                Field::new("item", DataType::LargeUtf8, true),
+                true,
+            ),
+            Field::new_list(
+                format_state_name(args.name, "string_agg_orderings"),
+                Field::new("item", orderings, true),
+                true,
+            ),
+        ])
+    }
+
+    fn order_sensitivity(&self) -> AggregateOrderSensitivity {
+        // the accumulator sorts the values itself when an ordering is given
+        AggregateOrderSensitivity::Beneficial
+    }
+
+    fn with_beneficial_ordering(
+        self: Arc<Self>,
+        _beneficial_ordering: bool,
+    ) -> Result<Option<Arc<dyn AggregateUDFImpl>>> {
+        Ok(Some(self))
     }
 }
 
@@ -151,3 +211,135 @@ impl Accumulator for StringAggAccumulator {
             + self.delimiter.capacity()
     }
 }
+
+/// Accumulator for `STRING_AGG(x, delimiter ORDER BY y)`.
+///
+/// The values are kept along with their ordering values and only sorted when
+/// evaluated, as the states of the partitions can be merged in any order.
+#[derive(Debug)]
+pub(crate) struct OrderedStringAggAccumulator {
+    values: Vec<String>,
+    /// The values of the ordering expressions for each value in `values`
+    ordering_values: Vec<Vec<ScalarValue>>,
+    ordering_fields: Vec<Field>,
+    sort_options: Vec<SortOptions>,
+    delimiter: String,
+}
+
+impl OrderedStringAggAccumulator {
+    pub fn new(
+        delimiter: &str,
+        ordering_fields: Vec<Field>,
+        sort_options: Vec<SortOptions>,
+    ) -> Self {
+        Self {
+            values: vec![],
+            ordering_values: vec![],
+            ordering_fields,
+            sort_options,
+            delimiter: delimiter.to_string(),
+        }
+    }
+
+    /// Returns the values of each ordering expression as an array
+    fn ordering_columns(&self) -> Result<Vec<ArrayRef>> {
+        self.ordering_fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                if self.ordering_values.is_empty() {
+                    return Ok(new_empty_array(field.data_type()));
+                }
+                ScalarValue::iter_to_array(
+                    self.ordering_values.iter().map(|row| row[i].clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Accumulator for OrderedStringAggAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        // the ordering columns follow the arguments of the function
+        let ordering_columns = &values[values.len() - self.sort_options.len()..];
+        let string_array = as_generic_string_array::<i64>(&values[0])?;
+        for (i, value) in string_array.iter().enumerate() {
+            if let Some(value) = value {
+                self.values.push(value.to_string());
+                self.ordering_values
+                    .push(get_row_at_idx(ordering_columns, i)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let [values, orderings, ..] = states else {
+            return exec_err!("STRING_AGG states should have two elements");
+        };
+        let values = values.as_list::<i32>().iter();
+        let orderings = orderings.as_list::<i32>().iter();
+        for (values, orderings) in values.zip(orderings) {
+            let (Some(values), Some(orderings)) = (values, orderings) else {
+                continue;
+            };
+            let string_array = as_generic_string_array::<i64>(&values)?;
+            let orderings = orderings.as_struct();
+            for (i, value) in string_array.iter().enumerate() {
+                if let Some(value) = value {
+                    self.values.push(value.to_string());
+                    self.ordering_values
+                        .push(get_row_at_idx(orderings.columns(), i)?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let values = LargeStringArray::from_iter_values(&self.values);
+        let orderings = StructArray::try_new(
+            Fields::from(self.ordering_fields.clone()),
+            self.ordering_columns()?,
+            None,
+        )?;
+        Ok(vec![
+            ScalarValue::List(Arc::new(array_into_list_array(Arc::new(values), true))),
+            ScalarValue::List(Arc::new(array_into_list_array(Arc::new(orderings), true))),
+        ])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        if self.values.is_empty() {
+            return Ok(ScalarValue::LargeUtf8(None));
+        }
+        let sort_columns = self
+            .ordering_columns()?
+            .into_iter()
+            .zip(&self.sort_options)
+            .map(|(values, options)| SortColumn {
+                values,
+                options: Some(*options),
+            })
+            .collect::<Vec<_>>();
+        let indices = lexsort_to_indices(&sort_columns, None)?;
+        let sorted = indices
+            .values()
+            .iter()
+            .map(|i| self.values[*i as usize].as_str())
+            .collect::<Vec<_>>();
+        Ok(ScalarValue::LargeUtf8(Some(sorted.join(&self.delimiter))))
+    }
+
+    fn size(&self) -> usize {
+        let mut size = std::mem::size_of_val(self)
+            + self.values.iter().map(|v| v.capacity()).sum::<usize>()
+            + std::mem::size_of::<String>() * self.values.capacity()
+            + std::mem::size_of::<Vec<ScalarValue>>() * self.ordering_values.capacity()
+            + self.delimiter.capacity();
+        for row in &self.ordering_values {
+            size += ScalarValue::size_of_vec(row) - std::mem::size_of_val(row);
+        }
+        size
+    }
+}
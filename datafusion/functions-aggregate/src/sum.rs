@@ -21,10 +21,11 @@ use ahash::RandomState;
 use datafusion_expr::utils::AggregateOrderSensitivity;
 use std::any::Any;
 use std::collections::HashSet;
+use std::sync::Arc;
 
 use arrow::array::Array;
 use arrow::array::ArrowNativeTypeOp;
-use arrow::array::{ArrowNumericType, AsArray};
+use arrow::array::{ArrowNumericType, AsArray, BooleanArray, PrimitiveArray};
 use arrow::datatypes::ArrowNativeType;
 use arrow::datatypes::ArrowPrimitiveType;
 use arrow::datatypes::{
@@ -32,13 +33,17 @@ use arrow::datatypes::{
     DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
 };
 use arrow::{array::ArrayRef, datatypes::Field};
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{exec_err, not_impl_err, Result, ScalarValue};
 use datafusion_expr::function::AccumulatorArgs;
 use datafusion_expr::function::StateFieldsArgs;
+use datafusion_expr::type_coercion::aggregates::widen_decimal_sum_type;
 use datafusion_expr::utils::format_state_name;
 use datafusion_expr::{
-    Accumulator, AggregateUDFImpl, GroupsAccumulator, ReversedUDAF, Signature, Volatility,
+    Accumulator, AggregateUDFImpl, EmitTo, GroupsAccumulator, ReversedUDAF, Signature,
+    Volatility,
 };
+use datafusion_physical_expr_common::aggregate::groups_accumulator::accumulate::NullState;
 use datafusion_physical_expr_common::aggregate::groups_accumulator::prim_op::PrimitiveGroupsAccumulator;
 use datafusion_physical_expr_common::aggregate::utils::Hashable;
 
@@ -129,6 +134,10 @@ impl AggregateUDFImpl for Sum {
         Ok(vec![coerced_type(&arg_types[0])?])
     }
 
+    fn widen_decimal_types(&self, arg_types: &[DataType]) -> Option<Vec<DataType>> {
+        Some(vec![widen_decimal_sum_type(&arg_types[0])?])
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         match &arg_types[0] {
             DataType::Int64 => Ok(DataType::Int64),
@@ -153,17 +162,18 @@ impl AggregateUDFImpl for Sum {
     }
 
     fn accumulator(&self, args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let mode = overflow_mode(&args);
         if args.is_distinct {
             macro_rules! helper {
                 ($t:ty, $dt:expr) => {
-                    Ok(Box::new(DistinctSumAccumulator::<$t>::try_new(&$dt)?))
+                    Ok(Box::new(DistinctSumAccumulator::<$t>::try_new(&$dt, mode)?))
                 };
             }
             downcast_sum!(args, helper)
         } else {
             macro_rules! helper {
                 ($t:ty, $dt:expr) => {
-                    Ok(Box::new(SumAccumulator::<$t>::new($dt.clone())))
+                    Ok(Box::new(SumAccumulator::<$t>::new($dt.clone(), mode)))
                 };
             }
             downcast_sum!(args, helper)
@@ -198,24 +208,51 @@ impl AggregateUDFImpl for Sum {
         &self,
         args: AccumulatorArgs,
     ) -> Result<Box<dyn GroupsAccumulator>> {
-        macro_rules! helper {
-            ($t:ty, $dt:expr) => {
-                Ok(Box::new(PrimitiveGroupsAccumulator::<$t, _>::new(
-                    &$dt,
-                    |x, y| *x = x.add_wrapping(y),
-                )))
-            };
+        match overflow_mode(&args) {
+            ArithmeticOverflowMode::Wrap => {
+                macro_rules! helper {
+                    ($t:ty, $dt:expr) => {
+                        Ok(Box::new(PrimitiveGroupsAccumulator::<$t, _>::new(
+                            &$dt,
+                            |x, y| *x = x.add_wrapping(y),
+                        )))
+                    };
+                }
+                downcast_sum!(args, helper)
+            }
+            ArithmeticOverflowMode::Saturate => {
+                macro_rules! helper {
+                    ($t:ty, $dt:expr) => {
+                        Ok(Box::new(PrimitiveGroupsAccumulator::<$t, _>::new(
+                            &$dt,
+                            |x, y| *x = saturating_add(*x, y),
+                        )))
+                    };
+                }
+                downcast_sum!(args, helper)
+            }
+            ArithmeticOverflowMode::Error => {
+                macro_rules! helper {
+                    ($t:ty, $dt:expr) => {
+                        Ok(Box::new(CheckedSumGroupsAccumulator::<$t>::new(&$dt)))
+                    };
+                }
+                downcast_sum!(args, helper)
+            }
         }
-        downcast_sum!(args, helper)
     }
 
     fn create_sliding_accumulator(
         &self,
         args: AccumulatorArgs,
     ) -> Result<Box<dyn Accumulator>> {
+        let mode = overflow_mode(&args);
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
-                Ok(Box::new(SlidingSumAccumulator::<$t>::new($dt.clone())))
+                Ok(Box::new(SlidingSumAccumulator::<$t>::new(
+                    $dt.clone(),
+                    mode,
+                )))
             };
         }
         downcast_sum!(args, helper)
@@ -230,10 +267,83 @@ impl AggregateUDFImpl for Sum {
     }
 }
 
+/// The overflow mode of a SUM, which only applies to integer results
+fn overflow_mode(args: &AccumulatorArgs) -> ArithmeticOverflowMode {
+    if args.data_type.is_integer() {
+        args.arithmetic_overflow_mode
+    } else {
+        ArithmeticOverflowMode::Wrap
+    }
+}
+
+/// Adds `y` to `x`, clamping the result to the range of the type
+fn saturating_add<N: ArrowNativeTypeOp>(x: N, y: N) -> N {
+    x.add_checked(y).unwrap_or(if y.is_gt(N::ZERO) {
+        N::MAX_TOTAL_ORDER
+    } else {
+        N::MIN_TOTAL_ORDER
+    })
+}
+
+/// Adds `y` to `x`, handling an overflow according to `mode`
+fn add_with_overflow_mode<N: ArrowNativeTypeOp>(
+    x: N,
+    y: N,
+    mode: ArithmeticOverflowMode,
+) -> Result<N> {
+    match mode {
+        ArithmeticOverflowMode::Wrap => Ok(x.add_wrapping(y)),
+        ArithmeticOverflowMode::Error => Ok(x.add_checked(y)?),
+        ArithmeticOverflowMode::Saturate => Ok(saturating_add(x, y)),
+    }
+}
+
+/// Subtracts `y` from `x`, handling an overflow according to `mode`
+fn sub_with_overflow_mode<N: ArrowNativeTypeOp>(
+    x: N,
+    y: N,
+    mode: ArithmeticOverflowMode,
+) -> Result<N> {
+    match mode {
+        ArithmeticOverflowMode::Wrap => Ok(x.sub_wrapping(y)),
+        ArithmeticOverflowMode::Error => Ok(x.sub_checked(y)?),
+        ArithmeticOverflowMode::Saturate => {
+            Ok(x.sub_checked(y).unwrap_or(if y.is_gt(N::ZERO) {
+                N::MIN_TOTAL_ORDER
+            } else {
+                N::MAX_TOTAL_ORDER
+            }))
+        }
+    }
+}
+
+/// Returns the sum of the non null `values`, handling an overflow according
+/// to `mode`, or `None` if there are none
+fn sum_batch<T: ArrowNumericType>(
+    values: &PrimitiveArray<T>,
+    mode: ArithmeticOverflowMode,
+) -> Result<Option<T::Native>> {
+    match mode {
+        ArithmeticOverflowMode::Wrap => Ok(arrow::compute::sum(values)),
+        ArithmeticOverflowMode::Error => Ok(arrow::compute::sum_checked(values)?),
+        ArithmeticOverflowMode::Saturate => {
+            if values.null_count() == values.len() {
+                return Ok(None);
+            }
+            let sum = values
+                .iter()
+                .flatten()
+                .fold(T::Native::ZERO, |acc, v| saturating_add(acc, v));
+            Ok(Some(sum))
+        }
+    }
+}
+
 /// This accumulator computes SUM incrementally
 struct SumAccumulator<T: ArrowNumericType> {
     sum: Option<T::Native>,
     data_type: DataType,
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl<T: ArrowNumericType> std::fmt::Debug for SumAccumulator<T> {
@@ -243,10 +353,11 @@ impl<T: ArrowNumericType> std::fmt::Debug for SumAccumulator<T> {
 }
 
 impl<T: ArrowNumericType> SumAccumulator<T> {
-    fn new(data_type: DataType) -> Self {
+    fn new(data_type: DataType, overflow_mode: ArithmeticOverflowMode) -> Self {
         Self {
             sum: None,
             data_type,
+            overflow_mode,
         }
     }
 }
@@ -258,9 +369,9 @@ impl<T: ArrowNumericType> Accumulator for SumAccumulator<T> {
 
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
-        if let Some(x) = arrow::compute::sum(values) {
+        if let Some(x) = sum_batch(values, self.overflow_mode)? {
             let v = self.sum.get_or_insert(T::Native::usize_as(0));
-            *v = v.add_wrapping(x);
+            *v = add_with_overflow_mode(*v, x, self.overflow_mode)?;
         }
         Ok(())
     }
@@ -285,6 +396,7 @@ struct SlidingSumAccumulator<T: ArrowNumericType> {
     sum: T::Native,
     count: u64,
     data_type: DataType,
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl<T: ArrowNumericType> std::fmt::Debug for SlidingSumAccumulator<T> {
@@ -294,11 +406,12 @@ impl<T: ArrowNumericType> std::fmt::Debug for SlidingSumAccumulator<T> {
 }
 
 impl<T: ArrowNumericType> SlidingSumAccumulator<T> {
-    fn new(data_type: DataType) -> Self {
+    fn new(data_type: DataType, overflow_mode: ArithmeticOverflowMode) -> Self {
         Self {
             sum: T::Native::usize_as(0),
             count: 0,
             data_type,
+            overflow_mode,
         }
     }
 }
@@ -311,16 +424,16 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
         self.count += (values.len() - values.null_count()) as u64;
-        if let Some(x) = arrow::compute::sum(values) {
-            self.sum = self.sum.add_wrapping(x)
+        if let Some(x) = sum_batch(values, self.overflow_mode)? {
+            self.sum = add_with_overflow_mode(self.sum, x, self.overflow_mode)?
         }
         Ok(())
     }
 
     fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
         let values = states[0].as_primitive::<T>();
-        if let Some(x) = arrow::compute::sum(values) {
-            self.sum = self.sum.add_wrapping(x)
+        if let Some(x) = sum_batch(values, self.overflow_mode)? {
+            self.sum = add_with_overflow_mode(self.sum, x, self.overflow_mode)?
         }
         if let Some(x) = arrow::compute::sum(states[1].as_primitive::<UInt64Type>()) {
             self.count += x;
@@ -339,8 +452,8 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
 
     fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
-        if let Some(x) = arrow::compute::sum(values) {
-            self.sum = self.sum.sub_wrapping(x)
+        if let Some(x) = sum_batch(values, self.overflow_mode)? {
+            self.sum = sub_with_overflow_mode(self.sum, x, self.overflow_mode)?
         }
         self.count -= (values.len() - values.null_count()) as u64;
         Ok(())
@@ -351,9 +464,99 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
     }
 }
 
+/// A [`GroupsAccumulator`] for SUM that fails when a sum overflows, used in
+/// [`ArithmeticOverflowMode::Error`]
+///
+/// This is separate from [`PrimitiveGroupsAccumulator`] as its update
+/// function can not fail.
+#[derive(Debug)]
+struct CheckedSumGroupsAccumulator<T: ArrowPrimitiveType> {
+    /// The sum of each group
+    sums: Vec<T::Native>,
+    /// The output type (needed for Decimal precision and scale)
+    data_type: DataType,
+    /// Track nulls in the input / filters
+    null_state: NullState,
+}
+
+impl<T: ArrowPrimitiveType> CheckedSumGroupsAccumulator<T> {
+    fn new(data_type: &DataType) -> Self {
+        Self {
+            sums: vec![],
+            data_type: data_type.clone(),
+            null_state: NullState::new(),
+        }
+    }
+}
+
+impl<T: ArrowPrimitiveType> GroupsAccumulator for CheckedSumGroupsAccumulator<T> {
+    fn update_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        let values = values[0].as_primitive::<T>();
+        self.sums.resize(total_num_groups, T::default_value());
+
+        // `NullState::accumulate` can not fail, so keep the first overflow
+        // and report it once all values have been visited
+        let mut overflow = None;
+        self.null_state.accumulate(
+            group_indices,
+            values,
+            opt_filter,
+            total_num_groups,
+            |group_index, new_value| {
+                let sum = &mut self.sums[group_index];
+                match sum.add_checked(new_value) {
+                    Ok(v) => *sum = v,
+                    Err(e) => {
+                        overflow.get_or_insert(e);
+                    }
+                }
+            },
+        );
+
+        match overflow {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        }
+    }
+
+    fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
+        let sums = emit_to.take_needed(&mut self.sums);
+        let nulls = self.null_state.build(emit_to);
+        let sums = PrimitiveArray::<T>::new(sums.into(), Some(nulls))
+            .with_data_type(self.data_type.clone());
+        Ok(Arc::new(sums))
+    }
+
+    fn state(&mut self, emit_to: EmitTo) -> Result<Vec<ArrayRef>> {
+        self.evaluate(emit_to).map(|arr| vec![arr])
+    }
+
+    fn merge_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        // the state is the partial sums, merged the same way as values
+        self.update_batch(values, group_indices, opt_filter, total_num_groups)
+    }
+
+    fn size(&self) -> usize {
+        self.sums.capacity() * std::mem::size_of::<T::Native>() + self.null_state.size()
+    }
+}
+
 struct DistinctSumAccumulator<T: ArrowPrimitiveType> {
     values: HashSet<Hashable<T::Native>, RandomState>,
     data_type: DataType,
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl<T: ArrowPrimitiveType> std::fmt::Debug for DistinctSumAccumulator<T> {
@@ -363,10 +566,14 @@ impl<T: ArrowPrimitiveType> std::fmt::Debug for DistinctSumAccumulator<T> {
 }
 
 impl<T: ArrowPrimitiveType> DistinctSumAccumulator<T> {
-    pub fn try_new(data_type: &DataType) -> Result<Self> {
+    pub fn try_new(
+        data_type: &DataType,
+        overflow_mode: ArithmeticOverflowMode,
+    ) -> Result<Self> {
         Ok(Self {
             values: HashSet::default(),
             data_type: data_type.clone(),
+            overflow_mode,
         })
     }
 }
@@ -421,7 +628,7 @@ impl<T: ArrowPrimitiveType> Accumulator for DistinctSumAccumulator<T> {
     fn evaluate(&mut self) -> Result<ScalarValue> {
         let mut acc = T::Native::usize_as(0);
         for distinct_value in self.values.iter() {
-            acc = acc.add_wrapping(distinct_value.0)
+            acc = add_with_overflow_mode(acc, distinct_value.0, self.overflow_mode)?
         }
         let v = (!self.values.is_empty()).then_some(acc);
         ScalarValue::new_primitive::<T>(v, &self.data_type)
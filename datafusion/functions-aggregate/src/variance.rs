@@ -19,21 +19,24 @@
 //! [`VariancePopulation`]: variance population aggregations.
 
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use arrow::{
-    array::{ArrayRef, Float64Array, UInt64Array},
+    array::{Array, ArrayRef, AsArray, BooleanArray, Float64Array, UInt64Array},
     compute::kernels::cast,
-    datatypes::{DataType, Field},
+    datatypes::{DataType, Field, Float64Type, UInt64Type},
 };
 
 use datafusion_common::{
-    downcast_value, not_impl_err, plan_err, DataFusionError, Result, ScalarValue,
+    downcast_value, internal_err, plan_err, DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::{
     function::{AccumulatorArgs, StateFieldsArgs},
     utils::format_state_name,
-    Accumulator, AggregateUDFImpl, Signature, Volatility,
+    Accumulator, AggregateUDFImpl, EmitTo, GroupsAccumulator, Signature, Volatility,
 };
+use datafusion_physical_expr_common::aggregate::distinct::DistinctAccumulator;
+use datafusion_physical_expr_common::aggregate::groups_accumulator::accumulate::accumulate;
 use datafusion_physical_expr_common::aggregate::stats::StatsType;
 
 make_udaf_expr_and_func!(
@@ -103,6 +106,12 @@ impl AggregateUDFImpl for VarianceSample {
     }
 
     fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        if args.is_distinct {
+            return Ok(vec![DistinctAccumulator::state_field(
+                args.name,
+                args.input_type,
+            )]);
+        }
         let name = args.name;
         Ok(vec![
             Field::new(format_state_name(name, "count"), DataType::UInt64, true),
@@ -112,11 +121,25 @@ impl AggregateUDFImpl for VarianceSample {
     }
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let accumulator = Box::new(VarianceAccumulator::try_new(StatsType::Sample)?);
         if acc_args.is_distinct {
-            return not_impl_err!("VAR(DISTINCT) aggregations are not available");
+            return Ok(Box::new(DistinctAccumulator::try_new(
+                acc_args.input_type,
+                accumulator,
+            )?));
         }
+        Ok(accumulator)
+    }
 
-        Ok(Box::new(VarianceAccumulator::try_new(StatsType::Sample)?))
+    fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
+        !args.is_distinct
+    }
+
+    fn create_groups_accumulator(
+        &self,
+        _args: AccumulatorArgs,
+    ) -> Result<Box<dyn GroupsAccumulator>> {
+        Ok(Box::new(VarianceGroupsAccumulator::new(StatsType::Sample)))
     }
 
     fn aliases(&self) -> &[String] {
@@ -175,6 +198,12 @@ impl AggregateUDFImpl for VariancePopulation {
     }
 
     fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        if args.is_distinct {
+            return Ok(vec![DistinctAccumulator::state_field(
+                args.name,
+                args.input_type,
+            )]);
+        }
         let name = args.name;
         Ok(vec![
             Field::new(format_state_name(name, "count"), DataType::UInt64, true),
@@ -184,13 +213,27 @@ impl AggregateUDFImpl for VariancePopulation {
     }
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let accumulator = Box::new(VarianceAccumulator::try_new(StatsType::Population)?);
         if acc_args.is_distinct {
-            return not_impl_err!("VAR_POP(DISTINCT) aggregations are not available");
+            return Ok(Box::new(DistinctAccumulator::try_new(
+                acc_args.input_type,
+                accumulator,
+            )?));
         }
+        Ok(accumulator)
+    }
+
+    fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
+        !args.is_distinct
+    }
 
-        Ok(Box::new(VarianceAccumulator::try_new(
+    fn create_groups_accumulator(
+        &self,
+        _args: AccumulatorArgs,
+    ) -> Result<Box<dyn GroupsAccumulator>> {
+        Ok(Box::new(VarianceGroupsAccumulator::new(
             StatsType::Population,
-        )?))
+        )))
     }
 
     fn aliases(&self) -> &[String] {
@@ -272,6 +315,15 @@ impl Accumulator for VarianceAccumulator {
         let arr = downcast_value!(values, Float64Array).iter().flatten();
 
         for value in arr {
+            if self.count <= 1 {
+                // the last value is removed, restart from an empty state
+                // rather than dividing by a zero count
+                self.count = 0;
+                self.mean = 0_f64;
+                self.m2 = 0_f64;
+                continue;
+            }
+
             let new_count = self.count - 1;
             let delta1 = self.mean - value;
             let new_mean = delta1 / new_count as f64 + self.mean;
@@ -344,3 +396,355 @@ impl Accumulator for VarianceAccumulator {
         true
     }
 }
+
+/// A [`GroupsAccumulator`] to compute the variance of each group, using the
+/// same algorithm as [`VarianceAccumulator`].
+///
+/// The state of all the groups is stored in flat vectors, rather than in a
+/// boxed [`VarianceAccumulator`] per group.
+#[derive(Debug)]
+pub struct VarianceGroupsAccumulator {
+    m2s: Vec<f64>,
+    means: Vec<f64>,
+    counts: Vec<u64>,
+    stats_type: StatsType,
+}
+
+impl VarianceGroupsAccumulator {
+    /// Creates a new `VarianceGroupsAccumulator`
+    pub fn new(s_type: StatsType) -> Self {
+        Self {
+            m2s: Vec::new(),
+            means: Vec::new(),
+            counts: Vec::new(),
+            stats_type: s_type,
+        }
+    }
+
+    fn resize(&mut self, total_num_groups: usize) {
+        self.m2s.resize(total_num_groups, 0_f64);
+        self.means.resize(total_num_groups, 0_f64);
+        self.counts.resize(total_num_groups, 0_u64);
+    }
+
+    /// Returns the variance of the groups selected by `emit_to`, removing
+    /// them from the accumulator
+    pub fn variance(&mut self, emit_to: EmitTo) -> Float64Array {
+        let counts = emit_to.take_needed(&mut self.counts);
+        // the means are not needed to compute the variance
+        let _ = emit_to.take_needed(&mut self.means);
+        let m2s = emit_to.take_needed(&mut self.m2s);
+
+        counts
+            .into_iter()
+            .zip(m2s)
+            .map(|(count, m2)| match (count, self.stats_type) {
+                (0, _) | (1, StatsType::Sample) => None,
+                (count, StatsType::Population) => Some(m2 / count as f64),
+                (count, StatsType::Sample) => Some(m2 / (count - 1) as f64),
+            })
+            .collect()
+    }
+}
+
+/// Invokes `value_fn(group_index, count, mean, m2)` for every partial state
+/// that passes the filter. The states have no nulls, as empty groups have a
+/// count of zero.
+fn merge_states<F>(
+    group_indices: &[usize],
+    counts: &UInt64Array,
+    means: &Float64Array,
+    m2s: &Float64Array,
+    opt_filter: Option<&BooleanArray>,
+    mut value_fn: F,
+) where
+    F: FnMut(usize, u64, f64, f64),
+{
+    let iter = group_indices
+        .iter()
+        .zip(counts.values().iter())
+        .zip(means.values().iter())
+        .zip(m2s.values().iter());
+    match opt_filter {
+        None => iter.for_each(|(((&group_index, &count), &mean), &m2)| {
+            value_fn(group_index, count, mean, m2)
+        }),
+        Some(filter) => {
+            assert_eq!(filter.len(), group_indices.len());
+            iter.zip(filter.iter()).for_each(
+                |((((&group_index, &count), &mean), &m2), filter_value)| {
+                    if let Some(true) = filter_value {
+                        value_fn(group_index, count, mean, m2)
+                    }
+                },
+            )
+        }
+    }
+}
+
+impl GroupsAccumulator for VarianceGroupsAccumulator {
+    fn update_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        assert_eq!(values.len(), 1, "single argument to update_batch");
+        let values = cast(&values[0], &DataType::Float64)?;
+        let values = values.as_primitive::<Float64Type>();
+
+        self.resize(total_num_groups);
+        accumulate(group_indices, values, opt_filter, |group_index, value| {
+            let new_count = self.counts[group_index] + 1;
+            let delta1 = value - self.means[group_index];
+            let new_mean = delta1 / new_count as f64 + self.means[group_index];
+            let delta2 = value - new_mean;
+
+            self.counts[group_index] = new_count;
+            self.means[group_index] = new_mean;
+            self.m2s[group_index] += delta1 * delta2;
+        });
+
+        Ok(())
+    }
+
+    fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
+        Ok(Arc::new(self.variance(emit_to)))
+    }
+
+    fn state(&mut self, emit_to: EmitTo) -> Result<Vec<ArrayRef>> {
+        let counts = emit_to.take_needed(&mut self.counts);
+        let means = emit_to.take_needed(&mut self.means);
+        let m2s = emit_to.take_needed(&mut self.m2s);
+
+        Ok(vec![
+            Arc::new(UInt64Array::new(counts.into(), None)),
+            Arc::new(Float64Array::new(means.into(), None)),
+            Arc::new(Float64Array::new(m2s.into(), None)),
+        ])
+    }
+
+    fn merge_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        assert_eq!(values.len(), 3, "three arguments to merge_batch");
+        let counts = values[0].as_primitive::<UInt64Type>();
+        let means = values[1].as_primitive::<Float64Type>();
+        let m2s = values[2].as_primitive::<Float64Type>();
+        if values.iter().any(|state| state.null_count() > 0) {
+            return internal_err!("The partial states of variance can not be null");
+        }
+
+        self.resize(total_num_groups);
+        merge_states(
+            group_indices,
+            counts,
+            means,
+            m2s,
+            opt_filter,
+            |group_index, c, partial_mean, partial_m2| {
+                if c == 0_u64 {
+                    return;
+                }
+                let count = self.counts[group_index];
+                let mean = self.means[group_index];
+                let m2 = self.m2s[group_index];
+                let new_count = count + c;
+                let new_mean = mean * count as f64 / new_count as f64
+                    + partial_mean * c as f64 / new_count as f64;
+                let delta = mean - partial_mean;
+                let new_m2 = m2
+                    + partial_m2
+                    + delta * delta * count as f64 * c as f64 / new_count as f64;
+
+                self.counts[group_index] = new_count;
+                self.means[group_index] = new_mean;
+                self.m2s[group_index] = new_m2;
+            },
+        );
+
+        Ok(())
+    }
+
+    fn size(&self) -> usize {
+        self.m2s.capacity() * std::mem::size_of::<f64>()
+            + self.means.capacity() * std::mem::size_of::<f64>()
+            + self.counts.capacity() * std::mem::size_of::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the variance of `values` computed with [`VarianceAccumulator`]
+    fn expected_variance(values: &[f64], s_type: StatsType) -> Result<ScalarValue> {
+        let mut acc = VarianceAccumulator::try_new(s_type)?;
+        acc.update_batch(&[Arc::new(Float64Array::from(values.to_vec()))])?;
+        acc.evaluate()
+    }
+
+    fn assert_variance(actual: &Float64Array, expected: &[ScalarValue]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?}");
+        for (i, expected) in expected.iter().enumerate() {
+            match expected {
+                ScalarValue::Float64(Some(expected)) => {
+                    assert!((actual.value(i) - expected).abs() < 1e-12, "{actual:?}")
+                }
+                _ => assert!(actual.is_null(i), "{actual:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn variance_groups_accumulator() -> Result<()> {
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(1_f64),
+            Some(2_f64),
+            None,
+            Some(3_f64),
+            Some(4_f64),
+            Some(5_f64),
+            Some(7_f64),
+        ]));
+        let group_indices = [0, 0, 1, 0, 1, 1, 2];
+
+        for s_type in [StatsType::Population, StatsType::Sample] {
+            let mut acc = VarianceGroupsAccumulator::new(s_type);
+            acc.update_batch(&[Arc::clone(&values)], &group_indices, None, 4)?;
+            let actual = acc.evaluate(EmitTo::All)?;
+            let expected = [
+                expected_variance(&[1_f64, 2_f64, 3_f64], s_type)?,
+                expected_variance(&[4_f64, 5_f64], s_type)?,
+                expected_variance(&[7_f64], s_type)?,
+                // the group without values
+                ScalarValue::Float64(None),
+            ];
+            assert_variance(actual.as_primitive(), &expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn variance_groups_accumulator_with_filter() -> Result<()> {
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(1_f64),
+            Some(2_f64),
+            None,
+            Some(4_f64),
+            Some(8_f64),
+        ]));
+        let filter = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            None,
+            Some(true),
+        ]);
+
+        let mut acc = VarianceGroupsAccumulator::new(StatsType::Population);
+        acc.update_batch(&[values], &[0, 0, 0, 0, 0], Some(&filter), 1)?;
+        let actual = acc.evaluate(EmitTo::All)?;
+        let expected = [expected_variance(&[1_f64, 8_f64], StatsType::Population)?];
+        assert_variance(actual.as_primitive(), &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn variance_groups_accumulator_merge() -> Result<()> {
+        // the same values, split between two partial accumulators
+        let mut partial1 = VarianceGroupsAccumulator::new(StatsType::Sample);
+        let values: ArrayRef =
+            Arc::new(Float64Array::from(vec![1_f64, 2_f64, 10_f64, 3_f64]));
+        partial1.update_batch(&[values], &[0, 0, 1, 0], None, 2)?;
+
+        let mut partial2 = VarianceGroupsAccumulator::new(StatsType::Sample);
+        let values: ArrayRef =
+            Arc::new(Float64Array::from(vec![20_f64, 4_f64, 30_f64, 5_f64]));
+        partial2.update_batch(&[values], &[1, 2, 1, 2], None, 3)?;
+
+        let state1 = partial1.state(EmitTo::All)?;
+        let state2 = partial2.state(EmitTo::All)?;
+        // the groups without values have a count of zero and no nulls
+        assert_eq!(state2[0].as_primitive::<UInt64Type>().value(0), 0);
+        assert!(state2.iter().all(|state| state.null_count() == 0));
+
+        let mut acc = VarianceGroupsAccumulator::new(StatsType::Sample);
+        acc.merge_batch(&state1, &[0, 1], None, 3)?;
+        acc.merge_batch(&state2, &[0, 1, 2], None, 3)?;
+        let actual = acc.evaluate(EmitTo::All)?;
+        let expected = [
+            expected_variance(&[1_f64, 2_f64, 3_f64], StatsType::Sample)?,
+            expected_variance(&[10_f64, 20_f64, 30_f64], StatsType::Sample)?,
+            expected_variance(&[4_f64, 5_f64], StatsType::Sample)?,
+        ];
+        assert_variance(actual.as_primitive(), &expected);
+
+        // filtered out states are not merged
+        let mut acc = VarianceGroupsAccumulator::new(StatsType::Sample);
+        let filter = BooleanArray::from(vec![false, true]);
+        let state = partial_state(&[1_f64, 2_f64, 3_f64], &[10_f64])?;
+        acc.merge_batch(&state, &[0, 0], Some(&filter), 1)?;
+        let actual = acc.evaluate(EmitTo::All)?;
+        assert_variance(actual.as_primitive(), &[ScalarValue::Float64(None)]);
+
+        // null states are rejected
+        let nulls: ArrayRef = Arc::new(UInt64Array::from(vec![None, Some(1)]));
+        let state = vec![nulls, Arc::clone(&state[1]), Arc::clone(&state[2])];
+        assert!(acc.merge_batch(&state, &[0, 0], None, 1).is_err());
+
+        Ok(())
+    }
+
+    /// Returns the state of a [`VarianceGroupsAccumulator`] whose two groups
+    /// saw `group0` and `group1`
+    fn partial_state(group0: &[f64], group1: &[f64]) -> Result<Vec<ArrayRef>> {
+        let mut acc = VarianceGroupsAccumulator::new(StatsType::Sample);
+        let values: Vec<f64> = group0.iter().chain(group1).copied().collect();
+        let group_indices: Vec<usize> = group0
+            .iter()
+            .map(|_| 0)
+            .chain(group1.iter().map(|_| 1))
+            .collect();
+        acc.update_batch(
+            &[Arc::new(Float64Array::from(values))],
+            &group_indices,
+            None,
+            2,
+        )?;
+        acc.state(EmitTo::All)
+    }
+
+    #[test]
+    fn variance_groups_accumulator_emit_first() -> Result<()> {
+        let mut acc = VarianceGroupsAccumulator::new(StatsType::Population);
+        let values: ArrayRef =
+            Arc::new(Float64Array::from(vec![1_f64, 3_f64, 2_f64, 6_f64, 5_f64]));
+        acc.update_batch(&[values], &[0, 0, 1, 1, 2], None, 3)?;
+
+        // emitting the first groups shifts the remaining ones
+        let first = acc.evaluate(EmitTo::First(2))?;
+        let expected = [
+            expected_variance(&[1_f64, 3_f64], StatsType::Population)?,
+            expected_variance(&[2_f64, 6_f64], StatsType::Population)?,
+        ];
+        assert_variance(first.as_primitive(), &expected);
+
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![7_f64]));
+        acc.update_batch(&[values], &[0], None, 1)?;
+        let state = acc.state(EmitTo::First(1))?;
+        assert_eq!(state[0].as_primitive::<UInt64Type>().values(), &[2]);
+
+        let mut merged = VarianceGroupsAccumulator::new(StatsType::Population);
+        merged.merge_batch(&state, &[0], None, 1)?;
+        let actual = merged.evaluate(EmitTo::All)?;
+        let expected = [expected_variance(&[5_f64, 7_f64], StatsType::Population)?];
+        assert_variance(actual.as_primitive(), &expected);
+        Ok(())
+    }
+}
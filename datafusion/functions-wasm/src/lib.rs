@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! WebAssembly scalar functions for [DataFusion].
+//!
+//! This crate loads user functions compiled to WebAssembly and runs them over
+//! Arrow arrays as [`ScalarUDF`]s. The functions run in a sandbox: a module
+//! cannot import anything from the host, and its memory and execution are
+//! bounded by [`WasmLimits`].
+//!
+//! [DataFusion]: https://crates.io/crates/datafusion
+//! [`ScalarUDF`]: datafusion_expr::ScalarUDF
+//!
+//! # Module interface
+//!
+//! Functions are vectorized: each call processes a whole batch of rows. A
+//! module must export:
+//!
+//! * `memory`, its linear memory
+//! * `alloc(len: i32) -> i32`, returning the address of `len` free bytes
+//! * `dealloc(ptr: i32, len: i32)`, releasing memory returned by `alloc`
+//! * the function itself, taking the address of each argument buffer, the
+//!   number of rows and the address of the result buffer:
+//!   `f(arg_0: i32, ..., arg_n: i32, num_rows: i32, result: i32)`. A
+//!   function without arguments only takes `num_rows` and `result`.
+//!
+//! Argument and result buffers hold `num_rows` little endian values of a
+//! fixed width numeric type (`Int8` to `UInt64`, `Float32` and `Float64`).
+//! A result row is null when any argument of the row is null, and the value
+//! computed for such rows is ignored.
+//!
+//! ```
+//! # use arrow::array::{ArrayRef, Int64Array};
+//! # use arrow::datatypes::DataType;
+//! # use datafusion_common::Result;
+//! # use datafusion_expr::ColumnarValue;
+//! # use datafusion_functions_wasm::WasmModule;
+//! # use std::sync::Arc;
+//! # fn main() -> Result<()> {
+//! // modules are usually compiled from another language, the text format is
+//! // accepted as well
+//! let module = WasmModule::try_new(
+//!     r#"(module
+//!         (memory (export "memory") 1)
+//!         (global $next (mut i32) (i32.const 8))
+//!         (func (export "alloc") (param $len i32) (result i32)
+//!           (global.get $next)
+//!           (global.set $next (i32.add (global.get $next) (local.get $len))))
+//!         (func (export "dealloc") (param i32 i32))
+//!         (func (export "double") (param $in i32) (param $n i32) (param $out i32)
+//!           (local $i i32)
+//!           (block $done
+//!             (loop $row
+//!               (br_if $done (i32.ge_u (local.get $i) (local.get $n)))
+//!               (i64.store
+//!                 (i32.add (local.get $out) (i32.shl (local.get $i) (i32.const 3)))
+//!                 (i64.mul
+//!                   (i64.load
+//!                     (i32.add (local.get $in) (i32.shl (local.get $i) (i32.const 3))))
+//!                   (i64.const 2)))
+//!               (local.set $i (i32.add (local.get $i) (i32.const 1)))
+//!               (br $row)))))"#,
+//! )?;
+//! let double = module.scalar_udf("double", vec![DataType::Int64], DataType::Int64)?;
+//!
+//! let input: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]));
+//! let ColumnarValue::Array(result) = double.invoke(&[ColumnarValue::Array(input)])?
+//! else {
+//!     unreachable!()
+//! };
+//! let expected: ArrayRef = Arc::new(Int64Array::from(vec![Some(2), None, Some(6)]));
+//! assert_eq!(&result, &expected);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod udf;
+
+pub use udf::{WasmLimits, WasmModule, WasmScalarUDF};
@@ -0,0 +1,570 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`WasmScalarUDF`]: scalar functions implemented by a WebAssembly module
+
+use std::any::Any;
+use std::fmt::Debug;
+
+use arrow::array::{make_array, Array, ArrayData, ArrayRef};
+use arrow::buffer::{Buffer, NullBuffer};
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, plan_err, DataFusionError, Result};
+use datafusion_expr::{ColumnarValue, ScalarUDF, ScalarUDFImpl, Signature, Volatility};
+use parking_lot::Mutex;
+use wasmtime::{
+    Config, Engine, Instance, Memory, Module, Store, StoreLimits, StoreLimitsBuilder,
+    TypedFunc, Val, ValType,
+};
+
+/// Bounds on the resources a WebAssembly function may use
+#[derive(Debug, Clone)]
+pub struct WasmLimits {
+    /// Maximum size in bytes of the linear memory of an instance
+    pub max_memory_bytes: usize,
+    /// Fuel available to each call, roughly the number of WebAssembly
+    /// instructions it may execute. `None` does not meter execution, so a
+    /// function that never returns blocks the calling thread forever.
+    pub fuel: Option<u64>,
+}
+
+impl Default for WasmLimits {
+    fn default() -> Self {
+        Self {
+            max_memory_bytes: 64 * 1024 * 1024,
+            fuel: Some(1_000_000_000),
+        }
+    }
+}
+
+/// A compiled WebAssembly module whose exported functions can be used as
+/// [`ScalarUDF`]s. See the [crate documentation](crate) for the interface the
+/// module must implement.
+#[derive(Clone)]
+pub struct WasmModule {
+    engine: Engine,
+    module: Module,
+    limits: WasmLimits,
+}
+
+impl Debug for WasmModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmModule")
+            .field("limits", &self.limits)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WasmModule {
+    /// Compiles a module from its binary or text representation, with the
+    /// default [`WasmLimits`]
+    pub fn try_new(wasm: impl AsRef<[u8]>) -> Result<Self> {
+        Self::try_new_with_limits(wasm, WasmLimits::default())
+    }
+
+    /// Compiles a module from its binary or text representation
+    pub fn try_new_with_limits(
+        wasm: impl AsRef<[u8]>,
+        limits: WasmLimits,
+    ) -> Result<Self> {
+        let mut config = Config::new();
+        config.consume_fuel(limits.fuel.is_some());
+        let engine = Engine::new(&config).map_err(wasm_err)?;
+        let module = Module::new(&engine, wasm).map_err(wasm_err)?;
+        Ok(Self {
+            engine,
+            module,
+            limits,
+        })
+    }
+
+    /// Returns a [`ScalarUDF`] calling the function `name` exported by the
+    /// module, with the given argument and return types
+    pub fn scalar_udf(
+        &self,
+        name: &str,
+        arg_types: Vec<DataType>,
+        return_type: DataType,
+    ) -> Result<ScalarUDF> {
+        Ok(ScalarUDF::new_from_impl(WasmScalarUDF::try_new(
+            self,
+            name,
+            arg_types,
+            return_type,
+        )?))
+    }
+}
+
+/// A [`ScalarUDFImpl`] calling a function exported by a [`WasmModule`].
+///
+/// Each function keeps a pool of instances of the module, so functions do
+/// not share memory and concurrent calls, e.g. from different partitions, run
+/// on different instances. An instance whose call fails is dropped rather
+/// than returned to the pool, as the state of a module after a trap is
+/// undefined.
+pub struct WasmScalarUDF {
+    name: String,
+    signature: Signature,
+    return_type: DataType,
+    module: WasmModule,
+    /// Instances not used by a call
+    pool: Mutex<Vec<WasmInstance>>,
+}
+
+impl Debug for WasmScalarUDF {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmScalarUDF")
+            .field("name", &self.name)
+            .field("signature", &self.signature)
+            .field("return_type", &self.return_type)
+            .field("module", &self.module)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WasmScalarUDF {
+    /// Instantiates `module` and checks that it exports the function `name`
+    /// with the expected parameters
+    pub fn try_new(
+        module: &WasmModule,
+        name: &str,
+        arg_types: Vec<DataType>,
+        return_type: DataType,
+    ) -> Result<Self> {
+        if let Some(data_type) = arg_types
+            .iter()
+            .chain(std::iter::once(&return_type))
+            .find(|t| !is_supported_type(t))
+        {
+            return plan_err!(
+                "WebAssembly function '{name}' does not support type {data_type}"
+            );
+        }
+
+        let instance = WasmInstance::try_new(module, name, arg_types.len())?;
+        Ok(Self {
+            name: name.to_string(),
+            signature: Signature::exact(arg_types, Volatility::Immutable),
+            return_type,
+            module: module.clone(),
+            pool: Mutex::new(vec![instance]),
+        })
+    }
+
+    /// Calls the function on `args` for `num_rows` rows, on an instance taken
+    /// from the pool or created if the pool is empty
+    fn call(&self, args: &[ArrayRef], num_rows: usize) -> Result<ColumnarValue> {
+        let instance = self.pool.lock().pop();
+        let mut instance = match instance {
+            Some(instance) => instance,
+            None => WasmInstance::try_new(&self.module, &self.name, args.len())?,
+        };
+        // the instance is dropped on error, as it may have trapped half way
+        // through the call
+        let result =
+            instance.call(args, num_rows, &self.return_type, self.module.limits.fuel)?;
+        self.pool.lock().push(instance);
+        Ok(ColumnarValue::Array(result))
+    }
+}
+
+impl ScalarUDFImpl for WasmScalarUDF {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let args = ColumnarValue::values_to_arrays(args)?;
+        // without arguments nor a row count, the function computes one row
+        let num_rows = args.first().map(|a| a.len()).unwrap_or(1);
+        self.call(&args, num_rows)
+    }
+
+    fn invoke_no_args(&self, number_rows: usize) -> Result<ColumnarValue> {
+        self.call(&[], number_rows)
+    }
+
+    fn invoke_batch(
+        &self,
+        args: &[ColumnarValue],
+        number_rows: usize,
+    ) -> Result<ColumnarValue> {
+        let args = args
+            .iter()
+            .map(|arg| arg.clone().into_array(number_rows))
+            .collect::<Result<Vec<_>>>()?;
+        self.call(&args, number_rows)
+    }
+}
+
+/// An instance of a [`WasmModule`] and the exports used to call a function
+struct WasmInstance {
+    store: Store<StoreLimits>,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    dealloc: TypedFunc<(i32, i32), ()>,
+    func: wasmtime::Func,
+}
+
+impl WasmInstance {
+    /// Instantiates `module` and checks that it exports the function `name`
+    /// taking `num_args` arguments
+    fn try_new(module: &WasmModule, name: &str, num_args: usize) -> Result<Self> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(module.limits.max_memory_bytes)
+            .build();
+        let mut store = Store::new(&module.engine, limits);
+        store.limiter(|limits| limits);
+        // the module is instantiated without imports, so it has no access to
+        // the host
+        let instance =
+            Instance::new(&mut store, &module.module, &[]).map_err(wasm_err)?;
+
+        let Some(memory) = instance.get_memory(&mut store, "memory") else {
+            return plan_err!("WebAssembly module does not export its memory");
+        };
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, "alloc")
+            .map_err(wasm_err)?;
+        let dealloc = instance
+            .get_typed_func::<(i32, i32), ()>(&mut store, "dealloc")
+            .map_err(wasm_err)?;
+        let Some(func) = instance.get_func(&mut store, name) else {
+            return plan_err!("WebAssembly module does not export function '{name}'");
+        };
+        let ty = func.ty(&store);
+        if ty.params().len() != num_args + 2
+            || !ty.params().all(|p| matches!(p, ValType::I32))
+            || ty.results().len() != 0
+        {
+            return plan_err!(
+                "WebAssembly function '{name}' must take {} i32 parameters and \
+                 return nothing",
+                num_args + 2
+            );
+        }
+
+        Ok(Self {
+            store,
+            memory,
+            alloc,
+            dealloc,
+            func,
+        })
+    }
+
+    /// Calls the function on `args` for `num_rows` rows, copying them to and
+    /// the result from the memory of the instance
+    fn call(
+        &mut self,
+        args: &[ArrayRef],
+        num_rows: usize,
+        return_type: &DataType,
+        fuel: Option<u64>,
+    ) -> Result<ArrayRef> {
+        if let Some(fuel) = fuel {
+            self.store.set_fuel(fuel).map_err(wasm_err)?;
+        }
+
+        let mut buffers = Vec::with_capacity(args.len() + 1);
+        let mut params = Vec::with_capacity(args.len() + 2);
+        for arg in args {
+            let data = arg.to_data();
+            let width = byte_width(arg.data_type());
+            let bytes = &data.buffers()[0].as_slice()
+                [data.offset() * width..(data.offset() + num_rows) * width];
+            let ptr = self.alloc(bytes.len())?;
+            self.memory
+                .write(&mut self.store, ptr as usize, bytes)
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            buffers.push((ptr, bytes.len()));
+            params.push(Val::I32(ptr));
+        }
+        let result_len = num_rows * byte_width(return_type);
+        let result_ptr = self.alloc(result_len)?;
+        buffers.push((result_ptr, result_len));
+        params.push(Val::I32(to_i32(num_rows)?));
+        params.push(Val::I32(result_ptr));
+
+        self.func
+            .call(&mut self.store, &params, &mut [])
+            .map_err(wasm_err)?;
+
+        let mut result = vec![0u8; result_len];
+        self.memory
+            .read(&self.store, result_ptr as usize, &mut result)
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        for (ptr, len) in buffers {
+            self.dealloc
+                .call(&mut self.store, (ptr, to_i32(len)?))
+                .map_err(wasm_err)?;
+        }
+
+        let nulls = args.iter().fold(None, |nulls, arg| {
+            NullBuffer::union(nulls.as_ref(), arg.logical_nulls().as_ref())
+        });
+        let data = ArrayData::builder(return_type.clone())
+            .len(num_rows)
+            .add_buffer(Buffer::from_vec(result))
+            .nulls(nulls)
+            .align_buffers(true)
+            .build()?;
+        Ok(make_array(data))
+    }
+
+    /// Allocates `len` bytes in the memory of the instance
+    fn alloc(&mut self, len: usize) -> Result<i32> {
+        let ptr = self
+            .alloc
+            .call(&mut self.store, to_i32(len)?)
+            .map_err(wasm_err)?;
+        if ptr < 0 || ptr as usize + len > self.memory.data_size(&self.store) {
+            return exec_err!("WebAssembly alloc returned an invalid address {ptr}");
+        }
+        Ok(ptr)
+    }
+}
+
+fn is_supported_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+    )
+}
+
+fn byte_width(data_type: &DataType) -> usize {
+    data_type.primitive_width().unwrap_or_default()
+}
+
+fn to_i32(value: usize) -> Result<i32> {
+    i32::try_from(value)
+        .or_else(|_| exec_err!("{value} exceeds the 32 bit address space of WebAssembly"))
+}
+
+fn wasm_err(e: wasmtime::Error) -> DataFusionError {
+    DataFusionError::External(e.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Float64Array, Int32Array};
+    use std::sync::Arc;
+
+    const MODULE: &str = r#"(module
+        (memory (export "memory") 1)
+        (global $next (mut i32) (i32.const 8))
+        (func (export "alloc") (param $len i32) (result i32)
+          (global.get $next)
+          (global.set $next (i32.add (global.get $next) (local.get $len))))
+        (func (export "dealloc") (param i32 i32)
+          (global.set $next (i32.const 8)))
+        (func (export "add_f64")
+          (param $a i32) (param $b i32) (param $n i32) (param $out i32)
+          (local $i i32)
+          (local $offset i32)
+          (block $done
+            (loop $row
+              (br_if $done (i32.ge_u (local.get $i) (local.get $n)))
+              (local.set $offset (i32.shl (local.get $i) (i32.const 3)))
+              (f64.store
+                (i32.add (local.get $out) (local.get $offset))
+                (f64.add
+                  (f64.load (i32.add (local.get $a) (local.get $offset)))
+                  (f64.load (i32.add (local.get $b) (local.get $offset)))))
+              (local.set $i (i32.add (local.get $i) (i32.const 1)))
+              (br $row))))
+        (func (export "div_i32")
+          (param $a i32) (param $b i32) (param $n i32) (param $out i32)
+          (local $i i32)
+          (local $offset i32)
+          (block $done
+            (loop $row
+              (br_if $done (i32.ge_u (local.get $i) (local.get $n)))
+              (local.set $offset (i32.shl (local.get $i) (i32.const 2)))
+              (i32.store
+                (i32.add (local.get $out) (local.get $offset))
+                (i32.div_s
+                  (i32.load (i32.add (local.get $a) (local.get $offset)))
+                  (i32.load (i32.add (local.get $b) (local.get $offset)))))
+              (local.set $i (i32.add (local.get $i) (i32.const 1)))
+              (br $row))))
+        (func (export "row_index") (param $n i32) (param $out i32)
+          (local $i i32)
+          (block $done
+            (loop $row
+              (br_if $done (i32.ge_u (local.get $i) (local.get $n)))
+              (i32.store
+                (i32.add (local.get $out) (i32.shl (local.get $i) (i32.const 2)))
+                (local.get $i))
+              (local.set $i (i32.add (local.get $i) (i32.const 1)))
+              (br $row))))
+        (func (export "spin") (param i32 i32 i32)
+          (loop $forever (br $forever))))"#;
+
+    #[test]
+    fn call_wasm_function() -> Result<()> {
+        let module = WasmModule::try_new(MODULE)?;
+        let add = module.scalar_udf(
+            "add_f64",
+            vec![DataType::Float64, DataType::Float64],
+            DataType::Float64,
+        )?;
+
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0]));
+        let b: ArrayRef = Arc::new(Float64Array::from(vec![0.5, 1.5, 2.5, 3.5]));
+        // sliced arguments are copied from their offset
+        let args = [
+            ColumnarValue::Array(a.slice(1, 3)),
+            ColumnarValue::Array(b.slice(0, 3)),
+        ];
+        let ColumnarValue::Array(result) = add.invoke(&args)? else {
+            unreachable!()
+        };
+        let expected: ArrayRef = Arc::new(Float64Array::from(vec![2.5, 4.5, 6.5]));
+        assert_eq!(&result, &expected);
+
+        // the memory released by a call is reused by the next one
+        for _ in 0..1000 {
+            add.invoke(&args)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_wasm_function() -> Result<()> {
+        let module = WasmModule::try_new(MODULE)?;
+
+        let err = module
+            .scalar_udf("missing", vec![DataType::Int32], DataType::Int32)
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: WebAssembly module does not export function 'missing'"
+        );
+
+        let err = module
+            .scalar_udf("add_f64", vec![DataType::Float64], DataType::Float64)
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: WebAssembly function 'add_f64' must take 3 i32 \
+             parameters and return nothing"
+        );
+
+        let err = module
+            .scalar_udf("add_f64", vec![DataType::Utf8], DataType::Float64)
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: WebAssembly function 'add_f64' does not support \
+             type Utf8"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn wasm_function_out_of_fuel() -> Result<()> {
+        let limits = WasmLimits {
+            fuel: Some(10_000),
+            ..Default::default()
+        };
+        let module = WasmModule::try_new_with_limits(MODULE, limits)?;
+        let spin = module.scalar_udf("spin", vec![DataType::Int32], DataType::Int32)?;
+
+        let input: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        assert!(spin.invoke(&[ColumnarValue::Array(input)]).is_err());
+        Ok(())
+    }
+    #[test]
+    fn wasm_function_out_of_default_fuel() -> Result<()> {
+        let module = WasmModule::try_new(MODULE)?;
+        let spin = module.scalar_udf("spin", vec![DataType::Int32], DataType::Int32)?;
+
+        let input: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        assert!(spin.invoke(&[ColumnarValue::Array(input)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn wasm_instance_dropped_after_trap() -> Result<()> {
+        let module = WasmModule::try_new(MODULE)?;
+        let div = WasmScalarUDF::try_new(
+            &module,
+            "div_i32",
+            vec![DataType::Int32, DataType::Int32],
+            DataType::Int32,
+        )?;
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![6, 8]));
+        let zero: ArrayRef = Arc::new(Int32Array::from(vec![1, 0]));
+        // dividing by zero traps
+        let args = [
+            ColumnarValue::Array(Arc::clone(&a)),
+            ColumnarValue::Array(zero),
+        ];
+        assert!(div.invoke(&args).is_err());
+        // the instance that trapped is not reused
+        assert!(div.pool.lock().is_empty());
+
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![3, 2]));
+        let ColumnarValue::Array(result) =
+            div.invoke(&[ColumnarValue::Array(a), ColumnarValue::Array(b)])?
+        else {
+            unreachable!()
+        };
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![2, 4]));
+        assert_eq!(&result, &expected);
+        assert_eq!(div.pool.lock().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn wasm_function_without_arguments() -> Result<()> {
+        let module = WasmModule::try_new(MODULE)?;
+        let row_index = module.scalar_udf("row_index", vec![], DataType::Int32)?;
+
+        let ColumnarValue::Array(result) = row_index.invoke_batch(&[], 3)? else {
+            unreachable!()
+        };
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![0, 1, 2]));
+        assert_eq!(&result, &expected);
+        Ok(())
+    }
+}
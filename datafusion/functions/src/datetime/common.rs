@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use arrow::array::{
@@ -62,8 +63,92 @@ pub(crate) fn validate_data_types(args: &[ColumnarValue], name: &str) -> Result<
     Ok(())
 }
 
+/// Template patterns of PostgreSQL / Oracle style formats and the
+/// [`chrono::format::strftime`] specifier each of them translates to.
+///
+/// Longer patterns come first so that, for example, `DDD` is not read as `DD`.
+const TEMPLATE_PATTERNS: &[(&str, &str)] = &[
+    ("HH24", "%H"),
+    ("HH12", "%I"),
+    ("HH", "%I"),
+    ("MI", "%M"),
+    ("SS", "%S"),
+    ("MS", "%3f"),
+    ("US", "%6f"),
+    ("YYYY", "%Y"),
+    ("YY", "%y"),
+    ("Month", "%B"),
+    ("Mon", "%b"),
+    ("MM", "%m"),
+    ("DDD", "%j"),
+    ("DD", "%d"),
+    ("Day", "%A"),
+    ("Dy", "%a"),
+    ("AM", "%p"),
+    ("PM", "%p"),
+    ("am", "%P"),
+    ("pm", "%P"),
+    ("TZ", "%Z"),
+    ("OF", "%:z"),
+];
+
+/// Translates a PostgreSQL / Oracle style template such as
+/// `YYYY-MM-DD HH24:MI:SS` into the equivalent [`chrono::format::strftime`]
+/// format.
+///
+/// Formats containing a `%` are assumed to already be `strftime` formats and
+/// are returned unchanged. Text in double quotes is copied literally and the
+/// `FM` prefix suppresses zero padding of the numeric pattern it precedes.
+/// Patterns are case sensitive.
+///
+/// [`chrono::format::strftime`]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+pub(crate) fn to_strftime_format(format: &str) -> Cow<'_, str> {
+    if format.contains('%') {
+        return Cow::Borrowed(format);
+    }
+
+    let mut result = String::with_capacity(format.len() * 2);
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if c == '"' {
+            let literal = &rest[1..];
+            let end = literal.find('"').unwrap_or(literal.len());
+            result.push_str(&literal[..end]);
+            rest = literal.get(end + 1..).unwrap_or_default();
+            continue;
+        }
+
+        let (fill_mode, template) = match rest.strip_prefix("FM") {
+            Some(template) => (true, template),
+            None => (false, rest),
+        };
+        let matched = TEMPLATE_PATTERNS
+            .iter()
+            .find(|(pattern, _)| template.starts_with(pattern));
+        match matched {
+            Some((pattern, specifier)) => {
+                match specifier.strip_prefix('%') {
+                    // only single letter numeric specifiers can drop their padding
+                    Some(spec) if fill_mode && "HIMSmdj".contains(spec) => {
+                        result.push_str("%-");
+                        result.push_str(spec);
+                    }
+                    _ => result.push_str(specifier),
+                }
+                rest = &template[pattern.len()..];
+            }
+            None => {
+                result.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    Cow::Owned(result)
+}
+
 /// Accepts a string and parses it using the [`chrono::format::strftime`] specifiers
-/// relative to the provided `timezone`
+/// (or a template understood by [`to_strftime_format`]) relative to the provided
+/// `timezone`
 ///
 /// [IANA timezones] are only supported if the `arrow-array/chrono-tz` feature is enabled
 ///
@@ -86,7 +171,12 @@ pub(crate) fn string_to_datetime_formatted<T: TimeZone>(
     };
 
     let mut parsed = Parsed::new();
-    parse(&mut parsed, s, StrftimeItems::new(format)).map_err(|e| err(&e.to_string()))?;
+    parse(
+        &mut parsed,
+        s,
+        StrftimeItems::new(&to_strftime_format(format)),
+    )
+    .map_err(|e| err(&e.to_string()))?;
 
     // attempt to parse the string assuming it has a timezone
     let dt = parsed.to_datetime();
@@ -201,7 +201,7 @@ fn date_bin_nanos_interval(stride_nanos: i64, source: i64, origin: i64) -> i64 {
 fn compute_distance(time_diff: i64, stride: i64) -> i64 {
     let time_delta = time_diff - (time_diff % stride);
 
-    if time_diff < 0 && stride > 1 {
+    if time_diff < 0 && time_delta != time_diff {
         // The origin is later than the source timestamp, round down to the previous bin
         time_delta - stride
     } else {
@@ -323,6 +323,9 @@ fn date_bin_impl(
         }
     };
 
+    // Strides in months have a variable width, so only fixed strides can be
+    // applied directly to the timestamp values
+    let fixed_stride = matches!(stride, Interval::Nanoseconds(_));
     let (stride, stride_fn) = stride.bin_fn();
 
     // Return error if stride is 0
@@ -330,48 +333,87 @@ fn date_bin_impl(
         return exec_err!("DATE_BIN stride must be non-zero");
     }
 
+    fn unit_scale<T: ArrowTimestampType>() -> i64 {
+        match T::UNIT {
+            Nanosecond => 1,
+            Microsecond => NANOSECONDS / 1_000_000,
+            Millisecond => NANOSECONDS / 1_000,
+            Second => NANOSECONDS,
+        }
+    }
+
+    /// Returns the stride and origin in the unit of `T`, if a fixed stride and
+    /// the origin are whole multiples of that unit
+    fn unscaled_stride<T: ArrowTimestampType>(
+        origin: i64,
+        stride: i64,
+        fixed_stride: bool,
+    ) -> Option<(i64, i64)> {
+        let scale = unit_scale::<T>();
+        (fixed_stride && stride % scale == 0 && origin % scale == 0)
+            .then(|| (stride / scale, origin / scale))
+    }
+
     fn stride_map_fn<T: ArrowTimestampType>(
         origin: i64,
         stride: i64,
         stride_fn: fn(i64, i64, i64) -> i64,
+        fixed_stride: bool,
     ) -> impl Fn(i64) -> i64 {
-        let scale = match T::UNIT {
-            Nanosecond => 1,
-            Microsecond => NANOSECONDS / 1_000_000,
-            Millisecond => NANOSECONDS / 1_000,
-            Second => NANOSECONDS,
-        };
+        // Binning in the unit of `T` also avoids overflowing when values far from
+        // the epoch are converted to nanoseconds
+        let (stride, origin, scale) =
+            match unscaled_stride::<T>(origin, stride, fixed_stride) {
+                Some((stride, origin)) => (stride, origin, 1),
+                None => (stride, origin, unit_scale::<T>()),
+            };
         move |x: i64| stride_fn(stride, x * scale, origin) / scale
     }
 
     Ok(match array {
         ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(v, tz_opt)) => {
-            let apply_stride_fn =
-                stride_map_fn::<TimestampNanosecondType>(origin, stride, stride_fn);
+            let apply_stride_fn = stride_map_fn::<TimestampNanosecondType>(
+                origin,
+                stride,
+                stride_fn,
+                fixed_stride,
+            );
             ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
                 v.map(apply_stride_fn),
                 tz_opt.clone(),
             ))
         }
         ColumnarValue::Scalar(ScalarValue::TimestampMicrosecond(v, tz_opt)) => {
-            let apply_stride_fn =
-                stride_map_fn::<TimestampMicrosecondType>(origin, stride, stride_fn);
+            let apply_stride_fn = stride_map_fn::<TimestampMicrosecondType>(
+                origin,
+                stride,
+                stride_fn,
+                fixed_stride,
+            );
             ColumnarValue::Scalar(ScalarValue::TimestampMicrosecond(
                 v.map(apply_stride_fn),
                 tz_opt.clone(),
             ))
         }
         ColumnarValue::Scalar(ScalarValue::TimestampMillisecond(v, tz_opt)) => {
-            let apply_stride_fn =
-                stride_map_fn::<TimestampMillisecondType>(origin, stride, stride_fn);
+            let apply_stride_fn = stride_map_fn::<TimestampMillisecondType>(
+                origin,
+                stride,
+                stride_fn,
+                fixed_stride,
+            );
             ColumnarValue::Scalar(ScalarValue::TimestampMillisecond(
                 v.map(apply_stride_fn),
                 tz_opt.clone(),
             ))
         }
         ColumnarValue::Scalar(ScalarValue::TimestampSecond(v, tz_opt)) => {
-            let apply_stride_fn =
-                stride_map_fn::<TimestampSecondType>(origin, stride, stride_fn);
+            let apply_stride_fn = stride_map_fn::<TimestampSecondType>(
+                origin,
+                stride,
+                stride_fn,
+                fixed_stride,
+            );
             ColumnarValue::Scalar(ScalarValue::TimestampSecond(
                 v.map(apply_stride_fn),
                 tz_opt.clone(),
@@ -383,6 +425,7 @@ fn date_bin_impl(
                 origin: i64,
                 stride: i64,
                 stride_fn: fn(i64, i64, i64) -> i64,
+                fixed_stride: bool,
                 array: &ArrayRef,
                 tz_opt: &Option<Arc<str>>,
             ) -> Result<ColumnarValue>
@@ -390,9 +433,20 @@ fn date_bin_impl(
                 T: ArrowTimestampType,
             {
                 let array = as_primitive_array::<T>(array)?;
-                let apply_stride_fn = stride_map_fn::<T>(origin, stride, stride_fn);
-                let array: PrimitiveArray<T> = array
-                    .unary(apply_stride_fn)
+                let array: PrimitiveArray<T> =
+                    match unscaled_stride::<T>(origin, stride, fixed_stride) {
+                        // Fast path: bin the values in their own unit, which avoids
+                        // converting every value to nanoseconds and back
+                        Some((stride, origin)) => {
+                            array.unary(|x| date_bin_nanos_interval(stride, x, origin))
+                        }
+                        None => array.unary(stride_map_fn::<T>(
+                            origin,
+                            stride,
+                            stride_fn,
+                            fixed_stride,
+                        )),
+                    }
                     .with_timezone_opt(tz_opt.clone());
 
                 Ok(ColumnarValue::Array(Arc::new(array)))
@@ -401,22 +455,42 @@ fn date_bin_impl(
             match array.data_type() {
                 Timestamp(Nanosecond, tz_opt) => {
                     transform_array_with_stride::<TimestampNanosecondType>(
-                        origin, stride, stride_fn, array, tz_opt,
+                        origin,
+                        stride,
+                        stride_fn,
+                        fixed_stride,
+                        array,
+                        tz_opt,
                     )?
                 }
                 Timestamp(Microsecond, tz_opt) => {
                     transform_array_with_stride::<TimestampMicrosecondType>(
-                        origin, stride, stride_fn, array, tz_opt,
+                        origin,
+                        stride,
+                        stride_fn,
+                        fixed_stride,
+                        array,
+                        tz_opt,
                     )?
                 }
                 Timestamp(Millisecond, tz_opt) => {
                     transform_array_with_stride::<TimestampMillisecondType>(
-                        origin, stride, stride_fn, array, tz_opt,
+                        origin,
+                        stride,
+                        stride_fn,
+                        fixed_stride,
+                        array,
+                        tz_opt,
                     )?
                 }
                 Timestamp(Second, tz_opt) => {
                     transform_array_with_stride::<TimestampSecondType>(
-                        origin, stride, stride_fn, array, tz_opt,
+                        origin,
+                        stride,
+                        stride_fn,
+                        fixed_stride,
+                        array,
+                        tz_opt,
                     )?
                 }
                 _ => {
@@ -441,7 +515,9 @@ mod tests {
 
     use crate::datetime::date_bin::{date_bin_nanos_interval, DateBinFunc};
     use arrow::array::types::TimestampNanosecondType;
-    use arrow::array::{IntervalDayTimeArray, TimestampNanosecondArray};
+    use arrow::array::{
+        ArrayRef, IntervalDayTimeArray, TimestampNanosecondArray, TimestampSecondArray,
+    };
     use arrow::compute::kernels::cast_utils::string_to_timestamp_nanos;
     use arrow::datatypes::{DataType, TimeUnit};
 
@@ -767,6 +843,52 @@ mod tests {
             });
     }
 
+    #[test]
+    fn test_date_bin_seconds_far_from_epoch() {
+        // 3000-01-01T01:01:01, which overflows when converted to nanoseconds
+        let source = 32_503_683_661;
+        let timestamps = Arc::new(TimestampSecondArray::from(vec![Some(source), None]));
+        let res = DateBinFunc::new()
+            .invoke(&[
+                ColumnarValue::Scalar(ScalarValue::IntervalMonthDayNano(Some(
+                    IntervalMonthDayNano {
+                        months: 0,
+                        days: 0,
+                        nanoseconds: 3_600_000_000_000,
+                    },
+                ))),
+                ColumnarValue::Array(timestamps),
+                ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(0), None)),
+            ])
+            .unwrap();
+        let ColumnarValue::Array(result) = res else {
+            panic!("Expected an array");
+        };
+        let expected: ArrayRef =
+            Arc::new(TimestampSecondArray::from(vec![Some(source - 61), None]));
+        assert_eq!(&result, &expected);
+
+        let res = DateBinFunc::new()
+            .invoke(&[
+                ColumnarValue::Scalar(ScalarValue::IntervalMonthDayNano(Some(
+                    IntervalMonthDayNano {
+                        months: 0,
+                        days: 0,
+                        nanoseconds: 3_600_000_000_000,
+                    },
+                ))),
+                ColumnarValue::Scalar(ScalarValue::TimestampSecond(Some(source), None)),
+            ])
+            .unwrap();
+        let ColumnarValue::Scalar(result) = res else {
+            panic!("Expected a scalar");
+        };
+        assert_eq!(
+            result,
+            ScalarValue::TimestampSecond(Some(source - 61), None)
+        );
+    }
+
     #[test]
     fn test_date_bin_single() {
         let cases = vec![
@@ -810,6 +932,15 @@ mod tests {
                 ),
                 "2004-04-09T02:03:10Z",
             ),
+            // origin later than the source, on a bin boundary
+            (
+                (
+                    TimeDelta::try_minutes(15),
+                    "2004-04-09T02:00:00Z",
+                    "2005-01-01T00:00:00",
+                ),
+                "2004-04-09T02:00:00Z",
+            ),
         ];
 
         cases
@@ -0,0 +1,281 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::cast::AsArray;
+use arrow::array::types::{Float64Type, Int32Type};
+use arrow::array::{Array, TimestampNanosecondBuilder};
+use arrow::datatypes::DataType;
+use arrow::datatypes::DataType::{
+    Float64, Int32, Int64, Timestamp, UInt32, UInt64, Utf8,
+};
+use arrow::datatypes::TimeUnit::Nanosecond;
+use chrono::prelude::*;
+use chrono::TimeDelta;
+
+use datafusion_common::{exec_err, Result, ScalarValue};
+use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
+
+#[derive(Debug)]
+pub struct MakeTimestampFunc {
+    signature: Signature,
+}
+
+impl Default for MakeTimestampFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MakeTimestampFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(
+                6,
+                vec![Int32, Int64, UInt32, UInt64, Float64, Utf8],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MakeTimestampFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "make_timestamp"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Timestamp(Nanosecond, None))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        if args.len() != 6 {
+            return exec_err!(
+                "make_timestamp function requires 6 arguments, got {}",
+                args.len()
+            );
+        }
+
+        let is_scalar = args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        // the seconds may have a fractional part, all the other fields are integers
+        let args = args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| arg.cast_to(if i == 5 { &Float64 } else { &Int32 }, None))
+            .collect::<Result<Vec<_>>>()?;
+        let arrays = ColumnarValue::values_to_arrays(&args)?;
+        let fields = arrays[..5]
+            .iter()
+            .map(|array| array.as_primitive::<Int32Type>())
+            .collect::<Vec<_>>();
+        let seconds = arrays[5].as_primitive::<Float64Type>();
+
+        let mut builder = TimestampNanosecondBuilder::with_capacity(seconds.len());
+        for i in 0..seconds.len() {
+            if seconds.is_null(i) || fields.iter().any(|field| field.is_null(i)) {
+                builder.append_null();
+                continue;
+            }
+            builder.append_value(make_timestamp_inner(
+                fields[0].value(i),
+                fields[1].value(i),
+                fields[2].value(i),
+                fields[3].value(i),
+                fields[4].value(i),
+                seconds.value(i),
+            )?);
+        }
+        let array = builder.finish();
+
+        if is_scalar {
+            // keep the output as scalar since all inputs are scalar
+            ScalarValue::try_from_array(&array, 0).map(ColumnarValue::Scalar)
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(array)))
+        }
+    }
+}
+
+/// Converts the year/month/day/hour/minute/second fields to the nanoseconds
+/// from the unix epoch. `second` may have a fractional part, which is rounded
+/// to the nearest nanosecond.
+fn make_timestamp_inner(
+    year: i32,
+    month: i32,
+    day: i32,
+    hour: i32,
+    minute: i32,
+    second: f64,
+) -> Result<i64> {
+    let date = u32::try_from(month)
+        .ok()
+        .zip(u32::try_from(day).ok())
+        .and_then(|(month, day)| NaiveDate::from_ymd_opt(year, month, day));
+    let Some(date) = date else {
+        return exec_err!("Unable to parse date from {year}, {month}, {day}");
+    };
+
+    let datetime = u32::try_from(hour)
+        .ok()
+        .zip(u32::try_from(minute).ok())
+        .and_then(|(hour, minute)| date.and_hms_opt(hour, minute, 0));
+    let Some(datetime) = datetime else {
+        return exec_err!("Unable to parse time from {hour}, {minute}, {second}");
+    };
+    if !(0.0..60.0).contains(&second) {
+        return exec_err!("Seconds value '{second}' is out of range");
+    }
+    let nanos = (second * 1_000_000_000.0).round() as i64;
+
+    match datetime
+        .checked_add_signed(TimeDelta::nanoseconds(nanos))
+        .and_then(|datetime| datetime.and_utc().timestamp_nanos_opt())
+    {
+        Some(timestamp) => Ok(timestamp),
+        None => exec_err!(
+            "Timestamp from {year}, {month}, {day}, {hour}, {minute}, {second} \
+            is out of range for nanosecond precision"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datetime::make_timestamp::MakeTimestampFunc;
+    use arrow::array::{Array, Float64Array, Int32Array, TimestampNanosecondArray};
+    use datafusion_common::ScalarValue;
+    use datafusion_expr::{ColumnarValue, ScalarUDFImpl};
+    use std::sync::Arc;
+
+    fn scalar_args(
+        year: i64,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: f64,
+    ) -> Vec<ColumnarValue> {
+        vec![
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(year))),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(month))),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(day))),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(hour))),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(minute))),
+            ColumnarValue::Scalar(ScalarValue::Float64(Some(second))),
+        ]
+    }
+
+    #[test]
+    fn test_make_timestamp() {
+        let res = MakeTimestampFunc::new()
+            .invoke(&scalar_args(2024, 1, 14, 8, 15, 23.5))
+            .expect("that make_timestamp parsed values without error");
+
+        if let ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(ts, None)) = res {
+            assert_eq!(1_705_220_123_500_000_000, ts.unwrap());
+        } else {
+            panic!("Expected a scalar value")
+        }
+
+        let mut args = scalar_args(2024, 1, 14, 8, 15, 23.5);
+        args[1] = ColumnarValue::Scalar(ScalarValue::Int32(None));
+        let res = MakeTimestampFunc::new()
+            .invoke(&args)
+            .expect("that make_timestamp handled a null value without error");
+
+        if let ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(ts, None)) = res {
+            assert_eq!(None, ts);
+        } else {
+            panic!("Expected a scalar value")
+        }
+
+        let mut args = scalar_args(1970, 1, 1, 0, 0, 0.0);
+        args[3] = ColumnarValue::Array(Arc::new(Int32Array::from(vec![0, 1, 23])));
+        args[5] = ColumnarValue::Array(Arc::new(Float64Array::from(vec![
+            Some(0.000000001),
+            None,
+            Some(59.25),
+        ])));
+        let res = MakeTimestampFunc::new()
+            .invoke(&args)
+            .expect("that make_timestamp parsed values without error");
+
+        if let ColumnarValue::Array(array) = res {
+            let expected = TimestampNanosecondArray::from(vec![
+                Some(1),
+                None,
+                Some(82_859_250_000_000),
+            ]);
+            assert_eq!(&expected as &dyn Array, array.as_ref());
+        } else {
+            panic!("Expected a columnar array")
+        }
+
+        //
+        // Fallible test cases
+        //
+
+        // invalid number of arguments
+        let res = MakeTimestampFunc::new()
+            .invoke(&[ColumnarValue::Scalar(ScalarValue::Int32(Some(1)))]);
+        assert_eq!(
+            res.err().unwrap().strip_backtrace(),
+            "Execution error: make_timestamp function requires 6 arguments, got 1"
+        );
+
+        // invalid date
+        let res = MakeTimestampFunc::new().invoke(&scalar_args(2023, 2, 29, 0, 0, 0.0));
+        assert_eq!(
+            res.err().unwrap().strip_backtrace(),
+            "Execution error: Unable to parse date from 2023, 2, 29"
+        );
+
+        // invalid time
+        let res = MakeTimestampFunc::new().invoke(&scalar_args(2023, 2, 28, 24, 0, 0.0));
+        assert_eq!(
+            res.err().unwrap().strip_backtrace(),
+            "Execution error: Unable to parse time from 24, 0, 0"
+        );
+
+        // invalid seconds
+        let res = MakeTimestampFunc::new().invoke(&scalar_args(2023, 2, 28, 0, 0, 60.0));
+        assert_eq!(
+            res.err().unwrap().strip_backtrace(),
+            "Execution error: Seconds value '60' is out of range"
+        );
+
+        // out of the range of nanosecond timestamps
+        let res = MakeTimestampFunc::new().invoke(&scalar_args(3000, 1, 1, 0, 0, 0.0));
+        assert_eq!(
+            res.err().unwrap().strip_backtrace(),
+            "Execution error: Timestamp from 3000, 1, 1, 0, 0, 0 is out of range for nanosecond precision"
+        );
+    }
+}
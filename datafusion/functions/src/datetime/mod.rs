@@ -29,6 +29,7 @@ pub mod date_part;
 pub mod date_trunc;
 pub mod from_unixtime;
 pub mod make_date;
+pub mod make_timestamp;
 pub mod now;
 pub mod to_char;
 pub mod to_date;
@@ -42,6 +43,11 @@ make_udf_function!(date_bin::DateBinFunc, DATE_BIN, date_bin);
 make_udf_function!(date_part::DatePartFunc, DATE_PART, date_part);
 make_udf_function!(date_trunc::DateTruncFunc, DATE_TRUNC, date_trunc);
 make_udf_function!(make_date::MakeDateFunc, MAKE_DATE, make_date);
+make_udf_function!(
+    make_timestamp::MakeTimestampFunc,
+    MAKE_TIMESTAMP,
+    make_timestamp
+);
 make_udf_function!(
     from_unixtime::FromUnixtimeFunc,
     FROM_UNIXTIME,
@@ -105,6 +111,10 @@ pub mod expr_fn {
         make_date,
         "make a date from year, month and day component parts",
         year month day
+    ),(
+        make_timestamp,
+        "make a timestamp from year, month, day, hour, minute and second component parts",
+        year month day hour minute second
     ),(
         now,
         "returns the current timestamp in nanoseconds, using the same value for all instances of now() in same statement",
@@ -274,6 +284,7 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         date_trunc(),
         from_unixtime(),
         make_date(),
+        make_timestamp(),
         now(),
         to_char(),
         to_date(),
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::any::Any;
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use arrow::array::cast::AsArray;
@@ -34,6 +35,8 @@ use datafusion_expr::{
     ColumnarValue, ScalarUDFImpl, Signature, Volatility, TIMEZONE_WILDCARD,
 };
 
+use crate::datetime::common::to_strftime_format;
+
 #[derive(Debug)]
 pub struct ToCharFunc {
     signature: Signature,
@@ -139,6 +142,15 @@ impl ScalarUDFImpl for ToCharFunc {
     }
 }
 
+/// Translates `format` into a `strftime` format, unless it is the name of a
+/// duration format
+fn _strftime_format<'a>(data_type: &DataType, format: &'a str) -> Cow<'a, str> {
+    match data_type {
+        Duration(_) => Cow::Borrowed(format),
+        _ => to_strftime_format(format),
+    }
+}
+
 fn _build_format_options<'a>(
     data_type: &DataType,
     format: Option<&'a str>,
@@ -192,7 +204,8 @@ fn _to_char_scalar(
         }
     }
 
-    let format_options = match _build_format_options(data_type, format) {
+    let format = format.map(|format| _strftime_format(data_type, format));
+    let format_options = match _build_format_options(data_type, format.as_deref()) {
         Ok(value) => value,
         Err(value) => return value,
     };
@@ -229,11 +242,12 @@ fn _to_char_array(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         } else {
             Some(format_array.value(idx))
         };
-        if format.is_none() {
+        let Some(format) = format else {
             results.push(None);
             continue;
-        }
-        let format_options = match _build_format_options(data_type, format) {
+        };
+        let format = _strftime_format(data_type, format);
+        let format_options = match _build_format_options(data_type, Some(&format)) {
             Ok(value) => value,
             Err(value) => return value,
         };
@@ -700,6 +700,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn string_to_timestamp_template_formatted() {
+        let cases = [
+            ("YYYY-MM-DD HH24:MI:SS", "%Y-%m-%d %H:%M:%S"),
+            ("DD Mon YY HH12:MI:SS.MS AM", "%d %b %y %I:%M:%S.%3f %p"),
+            ("FMDD FMMonth YYYY \"at\" FMHH24", "%-d %B %Y at %-H"),
+            ("Day, DDD US OF", "%A, %j %6f %:z"),
+            ("Dy \"YYYY\" TZ", "%a YYYY %Z"),
+            // strftime formats are left unchanged
+            ("%Y-%m-%d MM", "%Y-%m-%d MM"),
+            ("unterminated \"quote", "unterminated quote"),
+        ];
+        for (template, expected) in cases {
+            assert_eq!(to_strftime_format(template), expected, "{template}");
+        }
+
+        assert_eq!(
+            1599572549190000000,
+            parse_timestamp_formatted(
+                "08.09.2020 01:42:29.190 PM",
+                "DD.MM.YYYY HH12:MI:SS.MS AM"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            1642896000000000000,
+            parse_timestamp_formatted("2022-01-23", "YYYY-MM-DD").unwrap()
+        );
+    }
+
     fn parse_timestamp_formatted(s: &str, format: &str) -> Result<i64, DataFusionError> {
         let result = string_to_timestamp_nanos_formatted(s, format);
         if let Err(e) = &result {
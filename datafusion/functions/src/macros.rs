@@ -171,7 +171,8 @@ macro_rules! make_math_unary_udf {
 
             use arrow::array::{ArrayRef, Float32Array, Float64Array};
             use arrow::datatypes::DataType;
-            use datafusion_common::{exec_err, DataFusionError, Result};
+            use datafusion_common::{exec_err, DataFusionError, Result, ScalarValue};
+            use datafusion_expr::interval_arithmetic::Interval;
             use datafusion_expr::sort_properties::{ExprProperties, SortProperties};
             use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 
@@ -222,7 +223,27 @@ macro_rules! make_math_unary_udf {
                     $OUTPUT_ORDERING(input)
                 }
 
+                fn evaluate_bounds(&self, input: &[&Interval]) -> Result<Interval> {
+                    let return_type = self.return_type(&[input[0].data_type()])?;
+                    super::monotonic_bounds(
+                        input[0],
+                        $OUTPUT_ORDERING,
+                        |value| match self
+                            .invoke(&[ColumnarValue::Scalar(value.clone())])?
+                        {
+                            ColumnarValue::Scalar(value) => Ok(value),
+                            ColumnarValue::Array(array) => {
+                                ScalarValue::try_from_array(&array, 0)
+                            }
+                        },
+                        &return_type,
+                    )
+                }
+
                 fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+                    let all_scalars = args
+                        .iter()
+                        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
                     let args = ColumnarValue::values_to_arrays(args)?;
 
                     let arr: ArrayRef = match args[0].data_type() {
@@ -251,6 +272,11 @@ macro_rules! make_math_unary_udf {
                             )
                         }
                     };
+                    if all_scalars {
+                        return Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                            &arr, 0,
+                        )?));
+                    }
                     Ok(ColumnarValue::Array(arr))
                 }
             }
@@ -278,7 +304,7 @@ macro_rules! make_math_binary_udf {
 
             use arrow::array::{ArrayRef, Float32Array, Float64Array};
             use arrow::datatypes::DataType;
-            use datafusion_common::{exec_err, DataFusionError, Result};
+            use datafusion_common::{exec_err, DataFusionError, Result, ScalarValue};
             use datafusion_expr::sort_properties::{ExprProperties, SortProperties};
             use datafusion_expr::TypeSignature::*;
             use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
@@ -333,6 +359,9 @@ macro_rules! make_math_binary_udf {
                 }
 
                 fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+                    let all_scalars = args
+                        .iter()
+                        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
                     let args = ColumnarValue::values_to_arrays(args)?;
 
                     let arr: ArrayRef = match args[0].data_type() {
@@ -360,6 +389,11 @@ macro_rules! make_math_binary_udf {
                             )
                         }
                     };
+                    if all_scalars {
+                        return Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                            &arr, 0,
+                        )?));
+                    }
                     Ok(ColumnarValue::Array(arr))
                 }
             }
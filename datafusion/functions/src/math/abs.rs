@@ -26,7 +26,7 @@ use arrow::array::{
 };
 use arrow::datatypes::DataType;
 use arrow::error::ArrowError;
-use datafusion_common::{exec_err, not_impl_err, DataFusionError, Result};
+use datafusion_common::{exec_err, not_impl_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::interval_arithmetic::Interval;
 use datafusion_expr::sort_properties::{ExprProperties, SortProperties};
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
@@ -184,4 +184,30 @@ impl ScalarUDFImpl for AbsFunc {
             Ok(SortProperties::Unordered)
         }
     }
+
+    fn evaluate_bounds(&self, input: &[&Interval]) -> Result<Interval> {
+        // The identity for x ≥ 0 and the negation for x ≤ 0. Ranges spanning
+        // zero map to [0, max(-lower, upper)].
+        let range = input[0];
+        let data_type = range.data_type();
+        let bounds = || -> Result<Interval> {
+            let zero_point = Interval::make_zero(&data_type)?;
+            if range.gt_eq(&zero_point)? == Interval::CERTAINLY_TRUE {
+                Ok(range.clone())
+            } else if range.lt_eq(&zero_point)? == Interval::CERTAINLY_TRUE {
+                range.clone().arithmetic_negate()
+            } else {
+                let lower = range.lower().arithmetic_negate()?;
+                let upper = if lower.is_null() || range.upper().is_null() {
+                    ScalarValue::try_from(&data_type)?
+                } else if lower > *range.upper() {
+                    lower
+                } else {
+                    range.upper().clone()
+                };
+                Interval::try_new(zero_point.lower().clone(), upper)
+            }
+        };
+        bounds().or_else(|_| Interval::make_unbounded(&data_type))
+    }
 }
@@ -15,16 +15,78 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use arrow::compute::SortOptions;
 use arrow::datatypes::DataType;
+use datafusion_common::rounding::{next_down, next_up, FloatBits};
 use datafusion_common::{exec_err, Result, ScalarValue};
 use datafusion_expr::interval_arithmetic::Interval;
 use datafusion_expr::sort_properties::{ExprProperties, SortProperties};
 
-fn symmetric_unit_interval(data_type: &DataType) -> Result<Interval> {
-    Interval::try_new(
-        ScalarValue::new_negative_one(data_type)?,
-        ScalarValue::new_one(data_type)?,
-    )
+/// Computes the bounds of a unary function over the `input` interval, using
+/// its ordering rule `order` to decide whether the function is monotonic over
+/// that interval. If it is, the result spans the function values `fun` at the
+/// endpoints of `input`, widened by one ULP to account for rounding errors.
+/// Unbounded floating-point endpoints are evaluated at the matching infinity.
+/// Otherwise, including when `input` leaves the domain of the function, the
+/// result is unbounded.
+pub fn monotonic_bounds(
+    input: &Interval,
+    order: impl Fn(&[ExprProperties]) -> Result<SortProperties>,
+    fun: impl Fn(&ScalarValue) -> Result<ScalarValue>,
+    return_type: &DataType,
+) -> Result<Interval> {
+    let unbounded = || Interval::make_unbounded(return_type);
+    let properties = ExprProperties::new_unknown()
+        .with_order(SortProperties::Ordered(SortOptions {
+            descending: false,
+            nulls_first: false,
+        }))
+        .with_range(input.clone());
+    let descending = match order(&[properties]) {
+        Ok(SortProperties::Ordered(options)) => options.descending,
+        Ok(SortProperties::Singleton) => false,
+        Ok(SortProperties::Unordered) | Err(_) => return unbounded(),
+    };
+    let lower = fun(&with_infinity(input.lower(), f64::NEG_INFINITY));
+    let upper = fun(&with_infinity(input.upper(), f64::INFINITY));
+    let (Ok(lower), Ok(upper)) = (lower, upper) else {
+        return unbounded();
+    };
+    let (lower, upper) = if descending {
+        (upper, lower)
+    } else {
+        (lower, upper)
+    };
+    match (lower, upper) {
+        (ScalarValue::Float64(lower), ScalarValue::Float64(upper)) => Interval::try_new(
+            ScalarValue::Float64(lower.map(|v| widen(v, next_down))),
+            ScalarValue::Float64(upper.map(|v| widen(v, next_up))),
+        ),
+        (ScalarValue::Float32(lower), ScalarValue::Float32(upper)) => Interval::try_new(
+            ScalarValue::Float32(lower.map(|v| widen(v, next_down))),
+            ScalarValue::Float32(upper.map(|v| widen(v, next_up))),
+        ),
+        _ => unbounded(),
+    }
+}
+
+/// Moves `value` to the adjacent float in the direction of `next`. Zeros are
+/// kept as they are, since math functions do not round across zero.
+fn widen<F: FloatBits + Copy + Default + PartialEq>(value: F, next: fn(F) -> F) -> F {
+    if value == F::default() {
+        value
+    } else {
+        next(value)
+    }
+}
+
+/// Replaces an unbounded (`NULL`) floating-point endpoint with `infinity`.
+fn with_infinity(value: &ScalarValue, infinity: f64) -> ScalarValue {
+    match value {
+        ScalarValue::Float64(None) => ScalarValue::Float64(Some(infinity)),
+        ScalarValue::Float32(None) => ScalarValue::Float32(Some(infinity as f32)),
+        _ => value.clone(),
+    }
 }
 
 /// Non-increasing on the interval \[−1, 1\], undefined otherwise.
@@ -239,3 +301,68 @@ pub fn tan_order(_input: &[ExprProperties]) -> Result<SortProperties> {
 pub fn tanh_order(input: &[ExprProperties]) -> Result<SortProperties> {
     Ok(input[0].sort_properties)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exp(value: &ScalarValue) -> Result<ScalarValue> {
+        match value {
+            ScalarValue::Float64(v) => Ok(ScalarValue::Float64(v.map(f64::exp))),
+            _ => unreachable!(),
+        }
+    }
+
+    fn ln(value: &ScalarValue) -> Result<ScalarValue> {
+        match value {
+            ScalarValue::Float64(v) => Ok(ScalarValue::Float64(v.map(f64::ln))),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_monotonic_bounds() -> Result<()> {
+        let input = Interval::make(Some(0.0_f64), Some(1.0_f64))?;
+        let bounds = monotonic_bounds(&input, exp_order, exp, &DataType::Float64)?;
+        assert!(bounds.contains_value(ScalarValue::from(1.0_f64))?);
+        assert!(bounds.contains_value(ScalarValue::from(1.0_f64.exp()))?);
+        assert!(!bounds.contains_value(ScalarValue::from(0.99_f64))?);
+        assert!(!bounds.contains_value(ScalarValue::from(2.72_f64))?);
+
+        // Unbounded endpoints are evaluated at infinity
+        let input = Interval::make_unbounded(&DataType::Float64)?;
+        let bounds = monotonic_bounds(&input, exp_order, exp, &DataType::Float64)?;
+        assert_eq!(bounds.lower(), &ScalarValue::from(0.0_f64));
+        assert!(bounds.upper().is_null());
+
+        let input = Interval::make(Some(1.0_f64), None)?;
+        let bounds = monotonic_bounds(&input, ln_order, ln, &DataType::Float64)?;
+        assert_eq!(bounds.lower(), &ScalarValue::from(0.0_f64));
+        assert!(bounds.upper().is_null());
+        Ok(())
+    }
+
+    #[test]
+    fn test_monotonic_bounds_decreasing() -> Result<()> {
+        let neg_exp = |value: &ScalarValue| exp(value)?.arithmetic_negate();
+        let input = Interval::make(Some(0.0_f64), Some(1.0_f64))?;
+        let order = |input: &[ExprProperties]| -> Result<SortProperties> {
+            Ok(-input[0].sort_properties)
+        };
+        let bounds = monotonic_bounds(&input, order, neg_exp, &DataType::Float64)?;
+        assert_eq!(
+            bounds.lower(),
+            &ScalarValue::from(next_down(-(1.0_f64.exp())))
+        );
+        assert_eq!(bounds.upper(), &ScalarValue::from(next_up(-1.0_f64)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_monotonic_bounds_out_of_domain() -> Result<()> {
+        let input = Interval::make(Some(-1.0_f64), Some(1.0_f64))?;
+        let bounds = monotonic_bounds(&input, ln_order, ln, &DataType::Float64)?;
+        assert_eq!(bounds, Interval::make_unbounded(&DataType::Float64)?);
+        Ok(())
+    }
+}
@@ -17,11 +17,17 @@
 
 //! "regx" DataFusion functions
 
+pub mod regexpextract;
 pub mod regexplike;
 pub mod regexpmatch;
 pub mod regexpreplace;
 // create UDFs
 make_udf_function!(regexpmatch::RegexpMatchFunc, REGEXP_MATCH, regexp_match);
+make_udf_function!(
+    regexpextract::RegexpExtractFunc,
+    REGEXP_EXTRACT,
+    regexp_extract
+);
 make_udf_function!(regexplike::RegexpLikeFunc, REGEXP_LIKE, regexp_like);
 make_udf_function!(
     regexpreplace::RegexpReplaceFunc,
@@ -41,6 +47,15 @@ pub mod expr_fn {
         super::regexp_match().call(args)
     }
 
+    /// Returns a capture group of the first regular expression match in a string.
+    pub fn regexp_extract(values: Expr, regex: Expr, group: Option<Expr>) -> Expr {
+        let mut args = vec![values, regex];
+        if let Some(group) = group {
+            args.push(group);
+        };
+        super::regexp_extract().call(args)
+    }
+
     /// Returns true if a has at least one match in a string, false otherwise.
     pub fn regexp_like(values: Expr, regex: Expr, flags: Option<Expr>) -> Expr {
         let mut args = vec![values, regex];
@@ -67,5 +82,10 @@ pub mod expr_fn {
 
 #[doc = r" Return a list of all functions in this package"]
 pub fn functions() -> Vec<std::sync::Arc<datafusion_expr::ScalarUDF>> {
-    vec![regexp_match(), regexp_like(), regexp_replace()]
+    vec![
+        regexp_extract(),
+        regexp_match(),
+        regexp_like(),
+        regexp_replace(),
+    ]
 }
@@ -0,0 +1,345 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Regx expressions
+use arrow::array::{
+    new_null_array, Array, ArrayRef, GenericStringArray, GenericStringBuilder,
+    Int64Array, OffsetSizeTrait,
+};
+use arrow::datatypes::DataType;
+use datafusion_common::cast::{as_generic_string_array, as_int64_array};
+use datafusion_common::{
+    exec_err, internal_err, plan_err, DataFusionError, Result, ScalarValue,
+};
+use datafusion_expr::ColumnarValue;
+use datafusion_expr::TypeSignature::*;
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use regex::Regex;
+use std::any::Any;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct RegexpExtractFunc {
+    signature: Signature,
+}
+impl Default for RegexpExtractFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpExtractFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8]),
+                    Exact(vec![Utf8, Utf8, Int64]),
+                    Exact(vec![LargeUtf8, Utf8, Int64]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpExtractFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_extract"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        use DataType::*;
+
+        Ok(match &arg_types[0] {
+            LargeUtf8 => LargeUtf8,
+            Utf8 => Utf8,
+            Null => Null,
+            other => {
+                return plan_err!(
+                    "The regexp_extract function can only accept strings. Got {other}"
+                );
+            }
+        })
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let len = args
+            .iter()
+            .fold(Option::<usize>::None, |acc, arg| match arg {
+                ColumnarValue::Scalar(_) => acc,
+                ColumnarValue::Array(a) => Some(a.len()),
+            });
+
+        let is_scalar = len.is_none();
+        let inferred_length = len.unwrap_or(1);
+        let result = match &args[1] {
+            // the pattern is compiled once for all the rows
+            ColumnarValue::Scalar(ScalarValue::Utf8(pattern)) if !is_scalar => {
+                let values = args[0].clone().into_array(inferred_length)?;
+                let groups = args
+                    .get(2)
+                    .map(|arg| arg.clone().into_array(inferred_length))
+                    .transpose()?;
+                match pattern {
+                    Some(pattern) => {
+                        let re = compile_regex(pattern)?;
+                        regexp_extract_static_pattern_func(&values, &re, groups.as_ref())
+                    }
+                    None => Ok(new_null_array(values.data_type(), values.len())),
+                }
+            }
+            _ => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.clone().into_array(inferred_length))
+                    .collect::<Result<Vec<_>>>()?;
+                regexp_extract_func(&args)
+            }
+        };
+
+        if is_scalar {
+            // If all inputs are scalar, keeps output as scalar
+            let result = result.and_then(|arr| ScalarValue::try_from_array(&arr, 0));
+            result.map(ColumnarValue::Scalar)
+        } else {
+            result.map(ColumnarValue::Array)
+        }
+    }
+}
+
+fn regexp_extract_func(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Utf8 => regexp_extract::<i32>(args),
+        DataType::LargeUtf8 => regexp_extract::<i64>(args),
+        other => {
+            internal_err!("Unsupported data type {other:?} for function regexp_extract")
+        }
+    }
+}
+
+fn regexp_extract_static_pattern_func(
+    values: &ArrayRef,
+    re: &Regex,
+    groups: Option<&ArrayRef>,
+) -> Result<ArrayRef> {
+    let groups = groups
+        .map(|groups| as_int64_array(groups.as_ref()))
+        .transpose()?;
+    match values.data_type() {
+        DataType::Utf8 => regexp_extract_static_pattern::<i32>(
+            as_generic_string_array(values)?,
+            re,
+            groups,
+        ),
+        DataType::LargeUtf8 => regexp_extract_static_pattern::<i64>(
+            as_generic_string_array(values)?,
+            re,
+            groups,
+        ),
+        other => {
+            internal_err!("Unsupported data type {other:?} for function regexp_extract")
+        }
+    }
+}
+
+/// Extracts the capture group of the first match of a regular expression in
+/// each string.
+///
+/// `args` are the strings, the patterns and optionally the indices of the
+/// groups to extract. Group 0, the default, is the whole match. The result is
+/// an empty string when the pattern does not match, or when the group did not
+/// take part in the match.
+pub fn regexp_extract<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let groups = match args.len() {
+        2 => None,
+        3 => Some(as_int64_array(&args[2])?),
+        other => {
+            return exec_err!(
+                "regexp_extract was called with {other} arguments. It requires at least 2 and at most 3."
+            )
+        }
+    };
+    let values = as_generic_string_array::<T>(&args[0])?;
+    let patterns = as_generic_string_array::<i32>(&args[1])?;
+
+    // creating Regex is expensive so create hashmap for memoization
+    let mut regexes: HashMap<&str, Regex> = HashMap::new();
+    let mut builder = GenericStringBuilder::<T>::with_capacity(values.len(), 0);
+    for i in 0..values.len() {
+        if patterns.is_null(i) {
+            builder.append_null();
+            continue;
+        }
+        let pattern = patterns.value(i);
+        let re = match regexes.entry(pattern) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(compile_regex(pattern)?),
+        };
+        match (value_at(values, i), group_at(groups, i)) {
+            (Some(value), Some(group)) => {
+                builder.append_value(extract_group(value, re, group)?)
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
+/// Special cased regexp_extract implementation for the scenario where the
+/// pattern is static, so that it is only compiled once
+fn regexp_extract_static_pattern<T: OffsetSizeTrait>(
+    values: &GenericStringArray<T>,
+    re: &Regex,
+    groups: Option<&Int64Array>,
+) -> Result<ArrayRef> {
+    let mut builder = GenericStringBuilder::<T>::with_capacity(values.len(), 0);
+    for i in 0..values.len() {
+        match (value_at(values, i), group_at(groups, i)) {
+            (Some(value), Some(group)) => {
+                builder.append_value(extract_group(value, re, group)?)
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
+fn value_at<T: OffsetSizeTrait>(
+    values: &GenericStringArray<T>,
+    i: usize,
+) -> Option<&str> {
+    values.is_valid(i).then(|| values.value(i))
+}
+
+fn group_at(groups: Option<&Int64Array>, i: usize) -> Option<i64> {
+    match groups {
+        Some(groups) => groups.is_valid(i).then(|| groups.value(i)),
+        None => Some(0),
+    }
+}
+
+fn extract_group<'a>(value: &'a str, re: &Regex, group: i64) -> Result<&'a str> {
+    // captures_len includes the implicit group 0 of the whole match
+    let groups = re.captures_len();
+    match usize::try_from(group) {
+        Ok(group) if group < groups => Ok(re
+            .captures(value)
+            .and_then(|captures| captures.get(group))
+            .map_or("", |m| m.as_str())),
+        _ => exec_err!(
+            "regexp_extract group index {group} is out of range, the pattern has {} groups",
+            groups - 1
+        ),
+    }
+}
+
+fn compile_regex(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|err| DataFusionError::External(Box::new(err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Array, StringArray};
+
+    use super::*;
+
+    #[test]
+    fn test_regexp_extract() {
+        let values = StringArray::from(vec![
+            Some("100-200"),
+            Some("foo"),
+            None,
+            Some("100-200"),
+            Some("ab"),
+        ]);
+        let patterns = StringArray::from(vec![
+            Some(r"(\d+)-(\d+)"),
+            Some(r"(\d+)"),
+            Some(r"(\d+)"),
+            None,
+            Some("a(x)?b"),
+        ]);
+        let groups = Int64Array::from(vec![Some(2), Some(1), Some(1), Some(1), Some(1)]);
+        let expected =
+            StringArray::from(vec![Some("200"), Some(""), None, None, Some("")]);
+
+        let re = regexp_extract::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(groups),
+        ])
+        .unwrap();
+
+        assert_eq!(re.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_extract_whole_match() {
+        let values = StringArray::from(vec!["foo 100 bar", "no digits"]);
+        let patterns = StringArray::from(vec![r"\d+"; 2]);
+        let expected = StringArray::from(vec!["100", ""]);
+
+        let re = regexp_extract::<i32>(&[Arc::new(values), Arc::new(patterns)]).unwrap();
+
+        assert_eq!(re.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_extract_static_pattern() {
+        let values = StringArray::from(vec![Some("a=1"), None, Some("b=22")]);
+        let re = Regex::new(r"(\w)=(\d+)").unwrap();
+        let groups = Int64Array::from(vec![Some(2), Some(2), None]);
+        let expected = StringArray::from(vec![Some("1"), None, None]);
+
+        let result =
+            regexp_extract_static_pattern::<i32>(&values, &re, Some(&groups)).unwrap();
+
+        assert_eq!(result.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_extract_group_out_of_range() {
+        let values = StringArray::from(vec!["abc"]);
+        let patterns = StringArray::from(vec!["(b)"]);
+        let groups = Int64Array::from(vec![2]);
+
+        let err = regexp_extract::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(groups),
+        ])
+        .expect_err("out of range group should have failed");
+
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: regexp_extract group index 2 is out of range, the pattern has 1 groups"
+        );
+    }
+}
@@ -32,7 +32,7 @@ use datafusion_expr::function::Hint;
 use datafusion_expr::ColumnarValue;
 use datafusion_expr::TypeSignature::*;
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
-use regex::Regex;
+use regex::{Captures, Regex};
 use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -127,18 +127,37 @@ fn regexp_replace_func(args: &[ColumnarValue]) -> Result<ArrayRef> {
         }
     }
 }
-/// replace POSIX capture groups (like \1) with Rust Regex group (like ${1})
-/// used by regexp_replace
+/// replace POSIX capture groups (like \1) with Rust Regex group (like ${1}) and
+/// `\&` with the whole match (`${0}`). The syntax of the regex crate, like `$1`,
+/// keeps working. used by regexp_replace
 fn regex_replace_posix_groups(replacement: &str) -> String {
     fn capture_groups_re() -> &'static Regex {
         static CAPTURE_GROUPS_RE_LOCK: OnceLock<Regex> = OnceLock::new();
-        CAPTURE_GROUPS_RE_LOCK.get_or_init(|| Regex::new(r"(\\)(\d*)").unwrap())
+        CAPTURE_GROUPS_RE_LOCK.get_or_init(|| Regex::new(r"\\(&|\d*)").unwrap())
     }
     capture_groups_re()
-        .replace_all(replacement, "$${$2}")
+        .replace_all(replacement, |caps: &Captures| match &caps[1] {
+            "&" => "${0}".to_string(),
+            group => format!("${{{group}}}"),
+        })
         .into_owned()
 }
 
+/// Embeds the flags other than `g` into the pattern. Returns the pattern and
+/// whether all the matches should be replaced, which is what `g` stands for.
+fn regex_pattern_with_flags(pattern: &str, flags: Option<&str>) -> (String, bool) {
+    let Some(flags) = flags else {
+        return (pattern.to_string(), false);
+    };
+    let global = flags.contains('g');
+    let flags = flags.replace('g', "");
+    if flags.is_empty() {
+        (pattern.to_string(), global)
+    } else {
+        (format!("(?{flags}){pattern}"), global)
+    }
+}
+
 /// Replaces substring(s) matching a PCRE-like regular expression.
 ///
 /// The full list of supported features and syntax can be found at
@@ -238,13 +257,7 @@ pub fn regexp_replace<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
                     let replacement = regex_replace_posix_groups(replacement);
 
                     // format flags into rust pattern
-                    let (pattern, replace_all) = if flags == "g" {
-                        (pattern.to_string(), true)
-                    } else if flags.contains('g') {
-                        (format!("(?{}){}", flags.to_string().replace('g', ""), pattern), true)
-                    } else {
-                        (format!("(?{flags}){pattern}"), false)
-                    };
+                    let (pattern, replace_all) = regex_pattern_with_flags(pattern, Some(flags));
 
                     // if patterns hashmap already has regexp then use else create and return
                     let re = match patterns.get(&pattern) {
@@ -329,14 +342,8 @@ fn _regexp_replace_static_pattern_replace<T: OffsetSizeTrait>(
     // Embed the flag (if it exists) into the pattern. Limit will determine
     // whether this is a global match (as in replace all) or just a single
     // replace operation.
-    let (pattern, limit) = match flags {
-        Some("g") => (pattern.to_string(), 0),
-        Some(flags) => (
-            format!("(?{}){}", flags.to_string().replace('g', ""), pattern),
-            !flags.contains('g') as usize,
-        ),
-        None => (pattern.to_string(), 1),
-    };
+    let (pattern, replace_all) = regex_pattern_with_flags(pattern, flags);
+    let limit = if replace_all { 0 } else { 1 };
 
     let re =
         Regex::new(&pattern).map_err(|err| DataFusionError::External(Box::new(err)))?;
@@ -493,6 +500,34 @@ mod tests {
         assert_eq!(re.as_ref(), &expected);
     }
 
+    #[test]
+    fn test_regex_replace_posix_groups() {
+        assert_eq!(regex_replace_posix_groups(r"X\1Y"), "X${1}Y");
+        assert_eq!(regex_replace_posix_groups(r"X\\1Y"), "X${}${1}Y");
+        assert_eq!(regex_replace_posix_groups(r"\10"), "${10}");
+        assert_eq!(regex_replace_posix_groups(r"<\&>"), "<${0}>");
+        assert_eq!(regex_replace_posix_groups("$1 and ${2}"), "$1 and ${2}");
+    }
+
+    #[test]
+    fn test_static_pattern_regexp_replace_empty_flags() {
+        let values = StringArray::from(vec!["abcb"]);
+        let patterns = StringArray::from(vec!["(b)"]);
+        let replacements = StringArray::from(vec![r"[\1$]"]);
+        let flags = StringArray::from(vec![""]);
+        let expected = StringArray::from(vec!["a[b$]cb"]);
+
+        let re = _regexp_replace_static_pattern_replace::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(replacements),
+            Arc::new(flags),
+        ])
+        .unwrap();
+
+        assert_eq!(re.as_ref(), &expected);
+    }
+
     #[test]
     fn test_static_pattern_regexp_replace_early_abort() {
         let values = StringArray::from(vec!["abc"; 5]);
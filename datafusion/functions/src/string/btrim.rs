@@ -27,7 +27,10 @@ use datafusion_expr::{ColumnarValue, Volatility};
 use datafusion_expr::{ScalarUDFImpl, Signature};
 
 use crate::string::common::*;
-use crate::utils::{make_scalar_function, utf8_to_str_type};
+use crate::utils::{
+    coerce_dictionary_string_args, invoke_on_dictionary_values, make_scalar_function,
+    utf8_to_dictionary_str_type,
+};
 
 /// Returns the longest string with leading and trailing characters removed. If the characters are not specified, whitespace is removed.
 /// btrim('xyxtrimyyx', 'xyz') = 'trim'
@@ -38,6 +41,8 @@ fn btrim<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 #[derive(Debug)]
 pub struct BTrimFunc {
     signature: Signature,
+    /// Signature of the function for arguments that are not dictionary encoded
+    string_signature: Signature,
     aliases: Vec<String>,
 }
 
@@ -51,7 +56,8 @@ impl BTrimFunc {
     pub fn new() -> Self {
         use DataType::*;
         Self {
-            signature: Signature::one_of(
+            signature: Signature::user_defined(Volatility::Immutable),
+            string_signature: Signature::one_of(
                 vec![Exact(vec![Utf8]), Exact(vec![Utf8, Utf8])],
                 Volatility::Immutable,
             ),
@@ -74,11 +80,11 @@ impl ScalarUDFImpl for BTrimFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "btrim")
+        utf8_to_dictionary_str_type(&arg_types[0], "btrim")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        match args[0].data_type() {
+        invoke_on_dictionary_values(args, |args| match args[0].data_type() {
             DataType::Utf8 => make_scalar_function(
                 btrim::<i32>,
                 vec![Hint::Pad, Hint::AcceptsSingular],
@@ -88,7 +94,11 @@ impl ScalarUDFImpl for BTrimFunc {
                 vec![Hint::Pad, Hint::AcceptsSingular],
             )(args),
             other => exec_err!("Unsupported data type {other:?} for function btrim"),
-        }
+        })
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_dictionary_string_args(arg_types, &self.string_signature)
     }
 
     fn aliases(&self) -> &[String] {
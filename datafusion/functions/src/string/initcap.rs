@@ -26,11 +26,16 @@ use datafusion_common::{exec_err, Result};
 use datafusion_expr::{ColumnarValue, Volatility};
 use datafusion_expr::{ScalarUDFImpl, Signature};
 
-use crate::utils::{make_scalar_function, utf8_to_str_type};
+use crate::utils::{
+    coerce_dictionary_string_args, invoke_on_dictionary_values, make_scalar_function,
+    utf8_to_dictionary_str_type,
+};
 
 #[derive(Debug)]
 pub struct InitcapFunc {
     signature: Signature,
+    /// Signature of the function for arguments that are not dictionary encoded
+    string_signature: Signature,
 }
 
 impl Default for InitcapFunc {
@@ -43,7 +48,8 @@ impl InitcapFunc {
     pub fn new() -> Self {
         use DataType::*;
         Self {
-            signature: Signature::uniform(
+            signature: Signature::user_defined(Volatility::Immutable),
+            string_signature: Signature::uniform(
                 1,
                 vec![Utf8, LargeUtf8],
                 Volatility::Immutable,
@@ -66,21 +72,26 @@ impl ScalarUDFImpl for InitcapFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "initcap")
+        utf8_to_dictionary_str_type(&arg_types[0], "initcap")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        match args[0].data_type() {
+        invoke_on_dictionary_values(args, |args| match args[0].data_type() {
             DataType::Utf8 => make_scalar_function(initcap::<i32>, vec![])(args),
             DataType::LargeUtf8 => make_scalar_function(initcap::<i64>, vec![])(args),
             other => {
                 exec_err!("Unsupported data type {other:?} for function initcap")
             }
-        }
+        })
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_dictionary_string_args(arg_types, &self.string_signature)
     }
 }
 
 /// Converts the first letter of each word to upper case and the rest to lower case. Words are sequences of alphanumeric characters separated by non-alphanumeric characters.
+/// Letters and digits of all scripts are considered, and the case mapping of a character may have several characters.
 /// initcap('hi THOMAS') = 'Hi Thomas'
 fn initcap<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
@@ -90,19 +101,17 @@ fn initcap<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
         .iter()
         .map(|string| {
             string.map(|string: &str| {
-                let mut char_vector = Vec::<char>::new();
+                let mut result = String::with_capacity(string.len());
                 let mut previous_character_letter_or_number = false;
                 for c in string.chars() {
                     if previous_character_letter_or_number {
-                        char_vector.push(c.to_ascii_lowercase());
+                        result.extend(c.to_lowercase());
                     } else {
-                        char_vector.push(c.to_ascii_uppercase());
+                        result.extend(c.to_uppercase());
                     }
-                    previous_character_letter_or_number = c.is_ascii_uppercase()
-                        || c.is_ascii_lowercase()
-                        || c.is_ascii_digit();
+                    previous_character_letter_or_number = c.is_alphanumeric();
                 }
-                char_vector.iter().collect::<String>()
+                result
             })
         })
         .collect::<GenericStringArray<T>>();
@@ -114,8 +123,8 @@ fn initcap<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 mod tests {
     use crate::string::initcap::InitcapFunc;
     use crate::utils::test::test_function;
-    use arrow::array::{Array, StringArray};
-    use arrow::datatypes::DataType::Utf8;
+    use arrow::array::{Array, LargeStringArray, StringArray};
+    use arrow::datatypes::DataType::{LargeUtf8, Utf8};
     use datafusion_common::{Result, ScalarValue};
     use datafusion_expr::{ColumnarValue, ScalarUDFImpl};
 
@@ -153,6 +162,26 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            InitcapFunc::new(),
+            &[ColumnarValue::Scalar(ScalarValue::from(
+                "élan ÉTÉ-ßtraße δέλτα"
+            ))],
+            Ok(Some("Élan Été-SStraße Δέλτα")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            InitcapFunc::new(),
+            &[ColumnarValue::Scalar(ScalarValue::LargeUtf8(Some(
+                "hi THOMAS".to_string()
+            )))],
+            Ok(Some("Hi Thomas")),
+            &str,
+            LargeUtf8,
+            LargeStringArray
+        );
 
         Ok(())
     }
@@ -24,11 +24,16 @@ use datafusion_expr::ColumnarValue;
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 
 use crate::string::common::to_lower;
-use crate::utils::utf8_to_str_type;
+use crate::utils::{
+    coerce_dictionary_string_args, invoke_on_dictionary_values,
+    utf8_to_dictionary_str_type,
+};
 
 #[derive(Debug)]
 pub struct LowerFunc {
     signature: Signature,
+    /// Signature of the function for arguments that are not dictionary encoded
+    string_signature: Signature,
 }
 
 impl Default for LowerFunc {
@@ -41,7 +46,8 @@ impl LowerFunc {
     pub fn new() -> Self {
         use DataType::*;
         Self {
-            signature: Signature::uniform(
+            signature: Signature::user_defined(Volatility::Immutable),
+            string_signature: Signature::uniform(
                 1,
                 vec![Utf8, LargeUtf8],
                 Volatility::Immutable,
@@ -64,11 +70,15 @@ impl ScalarUDFImpl for LowerFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "lower")
+        utf8_to_dictionary_str_type(&arg_types[0], "lower")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        to_lower(args, "lower")
+        invoke_on_dictionary_values(args, |args| to_lower(args, "lower"))
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_dictionary_string_args(arg_types, &self.string_signature)
     }
 }
 
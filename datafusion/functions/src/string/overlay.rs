@@ -27,11 +27,16 @@ use datafusion_expr::TypeSignature::*;
 use datafusion_expr::{ColumnarValue, Volatility};
 use datafusion_expr::{ScalarUDFImpl, Signature};
 
-use crate::utils::{make_scalar_function, utf8_to_str_type};
+use crate::utils::{
+    coerce_dictionary_string_args, invoke_on_dictionary_values, make_scalar_function,
+    utf8_to_dictionary_str_type,
+};
 
 #[derive(Debug)]
 pub struct OverlayFunc {
     signature: Signature,
+    /// Signature of the function for arguments that are not dictionary encoded
+    string_signature: Signature,
 }
 
 impl Default for OverlayFunc {
@@ -44,11 +49,16 @@ impl OverlayFunc {
     pub fn new() -> Self {
         use DataType::*;
         Self {
-            signature: Signature::one_of(
+            signature: Signature::user_defined(Volatility::Immutable),
+            string_signature: Signature::one_of(
                 vec![
                     Exact(vec![Utf8, Utf8, Int64, Int64]),
+                    Exact(vec![LargeUtf8, Utf8, Int64, Int64]),
+                    Exact(vec![Utf8, LargeUtf8, Int64, Int64]),
                     Exact(vec![LargeUtf8, LargeUtf8, Int64, Int64]),
                     Exact(vec![Utf8, Utf8, Int64]),
+                    Exact(vec![LargeUtf8, Utf8, Int64]),
+                    Exact(vec![Utf8, LargeUtf8, Int64]),
                     Exact(vec![LargeUtf8, LargeUtf8, Int64]),
                 ],
                 Volatility::Immutable,
@@ -71,15 +81,33 @@ impl ScalarUDFImpl for OverlayFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "overlay")
+        utf8_to_dictionary_str_type(&arg_types[0], "overlay")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        match args[0].data_type() {
-            DataType::Utf8 => make_scalar_function(overlay::<i32>, vec![])(args),
-            DataType::LargeUtf8 => make_scalar_function(overlay::<i64>, vec![])(args),
-            other => exec_err!("Unsupported data type {other:?} for function overlay"),
-        }
+        invoke_on_dictionary_values(args, |args| {
+            match (args[0].data_type(), args[1].data_type()) {
+                (DataType::Utf8, DataType::Utf8) => {
+                    make_scalar_function(overlay::<i32, i32>, vec![])(args)
+                }
+                (DataType::Utf8, DataType::LargeUtf8) => {
+                    make_scalar_function(overlay::<i32, i64>, vec![])(args)
+                }
+                (DataType::LargeUtf8, DataType::Utf8) => {
+                    make_scalar_function(overlay::<i64, i32>, vec![])(args)
+                }
+                (DataType::LargeUtf8, DataType::LargeUtf8) => {
+                    make_scalar_function(overlay::<i64, i64>, vec![])(args)
+                }
+                (string_type, characters_type) => exec_err!(
+                    "Unsupported data types {string_type:?} and {characters_type:?} for function overlay"
+                ),
+            }
+        })
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_dictionary_string_args(arg_types, &self.string_signature)
     }
 }
 
@@ -87,93 +115,69 @@ impl ScalarUDFImpl for OverlayFunc {
 /// Replaces a substring of string1 with string2 starting at the integer bit
 /// pgsql overlay('Txxxxas' placing 'hom' from 2 for 4) → Thomas
 /// overlay('Txxxxas' placing 'hom' from 2) -> Thomxas, without for option, str2's len is instead
-pub fn overlay<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
-    match args.len() {
-        3 => {
-            let string_array = as_generic_string_array::<T>(&args[0])?;
-            let characters_array = as_generic_string_array::<T>(&args[1])?;
-            let pos_num = as_int64_array(&args[2])?;
-
-            let result = string_array
-                .iter()
-                .zip(characters_array.iter())
-                .zip(pos_num.iter())
-                .map(|((string, characters), start_pos)| {
-                    match (string, characters, start_pos) {
-                        (Some(string), Some(characters), Some(start_pos)) => {
-                            let string_len = string.chars().count();
-                            let characters_len = characters.chars().count();
-                            let replace_len = characters_len as i64;
-                            let mut res =
-                                String::with_capacity(string_len.max(characters_len));
-
-                            //as sql replace index start from 1 while string index start from 0
-                            if start_pos > 1 && start_pos - 1 < string_len as i64 {
-                                let start = (start_pos - 1) as usize;
-                                res.push_str(&string[..start]);
-                            }
-                            res.push_str(characters);
-                            // if start + replace_len - 1 >= string_length, just to string end
-                            if start_pos + replace_len - 1 < string_len as i64 {
-                                let end = (start_pos + replace_len - 1) as usize;
-                                res.push_str(&string[end..]);
-                            }
-                            Ok(Some(res))
-                        }
-                        _ => Ok(None),
-                    }
-                })
-                .collect::<Result<GenericStringArray<T>>>()?;
-            Ok(Arc::new(result) as ArrayRef)
-        }
-        4 => {
-            let string_array = as_generic_string_array::<T>(&args[0])?;
-            let characters_array = as_generic_string_array::<T>(&args[1])?;
-            let pos_num = as_int64_array(&args[2])?;
-            let len_num = as_int64_array(&args[3])?;
-
-            let result = string_array
-                .iter()
-                .zip(characters_array.iter())
-                .zip(pos_num.iter())
-                .zip(len_num.iter())
-                .map(|(((string, characters), start_pos), len)| {
-                    match (string, characters, start_pos, len) {
-                        (Some(string), Some(characters), Some(start_pos), Some(len)) => {
-                            let string_len = string.chars().count();
-                            let characters_len = characters.chars().count();
-                            let replace_len = len.min(string_len as i64);
-                            let mut res =
-                                String::with_capacity(string_len.max(characters_len));
-
-                            //as sql replace index start from 1 while string index start from 0
-                            if start_pos > 1 && start_pos - 1 < string_len as i64 {
-                                let start = (start_pos - 1) as usize;
-                                res.push_str(&string[..start]);
-                            }
-                            res.push_str(characters);
-                            // if start + replace_len - 1 >= string_length, just to string end
-                            if start_pos + replace_len - 1 < string_len as i64 {
-                                let end = (start_pos + replace_len - 1) as usize;
-                                res.push_str(&string[end..]);
-                            }
-                            Ok(Some(res))
-                        }
-                        _ => Ok(None),
-                    }
-                })
-                .collect::<Result<GenericStringArray<T>>>()?;
-            Ok(Arc::new(result) as ArrayRef)
-        }
-        other => {
-            exec_err!("overlay was called with {other} arguments. It requires 3 or 4.")
-        }
+pub fn overlay<T: OffsetSizeTrait, U: OffsetSizeTrait>(
+    args: &[ArrayRef],
+) -> Result<ArrayRef> {
+    if !matches!(args.len(), 3 | 4) {
+        return exec_err!(
+            "overlay was called with {} arguments. It requires 3 or 4.",
+            args.len()
+        );
     }
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let characters_array = as_generic_string_array::<U>(&args[1])?;
+    let pos_array = as_int64_array(&args[2])?;
+    let len_array = args.get(3).map(|len| as_int64_array(len)).transpose()?;
+
+    let result = (0..string_array.len())
+        .map(|i| {
+            if string_array.is_null(i)
+                || characters_array.is_null(i)
+                || pos_array.is_null(i)
+                || len_array.is_some_and(|len| len.is_null(i))
+            {
+                return None;
+            }
+            let characters = characters_array.value(i);
+            let len = match len_array {
+                Some(len) => len.value(i),
+                None => characters.chars().count() as i64,
+            };
+            Some(overlay_str(
+                string_array.value(i),
+                characters,
+                pos_array.value(i),
+                len,
+            ))
+        })
+        .collect::<GenericStringArray<T>>();
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Returns `substr(string, 1, pos - 1) || characters || substr(string, pos + len)`,
+/// counting positions in characters rather than bytes
+fn overlay_str(string: &str, characters: &str, pos: i64, len: i64) -> String {
+    // byte offset of the character at the 0 based `index`, clamped to the string
+    let byte_offset = |index: i64| match usize::try_from(index) {
+        Ok(index) => string
+            .char_indices()
+            .nth(index)
+            .map_or(string.len(), |(offset, _)| offset),
+        Err(_) => 0,
+    };
+    let start = byte_offset(pos.saturating_sub(1));
+    let end = byte_offset(pos.saturating_add(len).saturating_sub(1));
+
+    let mut result = String::with_capacity(start + characters.len() + string.len() - end);
+    result.push_str(&string[..start]);
+    result.push_str(characters);
+    result.push_str(&string[end..]);
+    result
 }
 
 #[cfg(test)]
 mod tests {
-    use arrow::array::{Int64Array, StringArray};
+    use arrow::array::{Int64Array, LargeStringArray, StringArray};
 
     use super::*;
 
@@ -186,11 +190,45 @@ mod tests {
         let start = Arc::new(Int64Array::from(vec![4, 1, 1, 2])); // start
         let end = Arc::new(Int64Array::from(vec![5, 7, 2, 4])); // replace len
 
-        let res = overlay::<i32>(&[string, replace_string, start, end]).unwrap();
+        let res = overlay::<i32, i32>(&[string, replace_string, start, end]).unwrap();
         let result = as_generic_string_array::<i32>(&res).unwrap();
-        let expected = StringArray::from(vec!["abc", "qwertyasdfg", "ijkz", "Thomas"]);
+        let expected = StringArray::from(vec!["123abc", "qwertyasdfg", "ijkz", "Thomas"]);
         assert_eq!(&expected, result);
 
         Ok(())
     }
+
+    #[test]
+    fn to_overlay_unicode() -> Result<()> {
+        let string = Arc::new(LargeStringArray::from(vec![
+            Some("Tëëëëäs"),
+            Some("日本語"),
+            Some("ab"),
+            None,
+        ]));
+        let replace_string = Arc::new(StringArray::from(vec!["hóm", "xy", "😀", "x"]));
+        let start = Arc::new(Int64Array::from(vec![2, 2, 0, 1]));
+
+        let res = overlay::<i64, i32>(&[string, replace_string, start]).unwrap();
+        let result = as_generic_string_array::<i64>(&res).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some("Thómëäs"),
+            Some("日xy"),
+            Some("😀ab"),
+            None,
+        ]);
+        assert_eq!(&expected, result);
+        Ok(())
+    }
+
+    #[test]
+    fn to_overlay_str() {
+        assert_eq!(overlay_str("Txxxxas", "hom", 2, 4), "Thomas");
+        // past the end of the string
+        assert_eq!(overlay_str("123", "abc", 4, 5), "123abc");
+        assert_eq!(overlay_str("123", "abc", 10, 1), "123abc");
+        // a negative length keeps the characters before the end position
+        assert_eq!(overlay_str("abc", "x", 2, -1), "axabc");
+        assert_eq!(overlay_str("abc", "x", i64::MIN, i64::MAX), "xabc");
+    }
 }
@@ -27,11 +27,16 @@ use datafusion_expr::TypeSignature::*;
 use datafusion_expr::{ColumnarValue, Volatility};
 use datafusion_expr::{ScalarUDFImpl, Signature};
 
-use crate::utils::{make_scalar_function, utf8_to_str_type};
+use crate::utils::{
+    coerce_dictionary_string_args, invoke_on_dictionary_values, make_scalar_function,
+    utf8_to_dictionary_str_type,
+};
 
 #[derive(Debug)]
 pub struct SplitPartFunc {
     signature: Signature,
+    /// Signature of the function for arguments that are not dictionary encoded
+    string_signature: Signature,
 }
 
 impl Default for SplitPartFunc {
@@ -44,7 +49,8 @@ impl SplitPartFunc {
     pub fn new() -> Self {
         use DataType::*;
         Self {
-            signature: Signature::one_of(
+            signature: Signature::user_defined(Volatility::Immutable),
+            string_signature: Signature::one_of(
                 vec![
                     Exact(vec![Utf8, Utf8, Int64]),
                     Exact(vec![LargeUtf8, Utf8, Int64]),
@@ -71,25 +77,44 @@ impl ScalarUDFImpl for SplitPartFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "split_part")
+        utf8_to_dictionary_str_type(&arg_types[0], "split_part")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        match args[0].data_type() {
-            DataType::Utf8 => make_scalar_function(split_part::<i32>, vec![])(args),
-            DataType::LargeUtf8 => make_scalar_function(split_part::<i64>, vec![])(args),
-            other => {
-                exec_err!("Unsupported data type {other:?} for function split_part")
+        invoke_on_dictionary_values(args, |args| {
+            match (args[0].data_type(), args[1].data_type()) {
+                (DataType::Utf8, DataType::Utf8) => {
+                    make_scalar_function(split_part::<i32, i32>, vec![])(args)
+                }
+                (DataType::Utf8, DataType::LargeUtf8) => {
+                    make_scalar_function(split_part::<i32, i64>, vec![])(args)
+                }
+                (DataType::LargeUtf8, DataType::Utf8) => {
+                    make_scalar_function(split_part::<i64, i32>, vec![])(args)
+                }
+                (DataType::LargeUtf8, DataType::LargeUtf8) => {
+                    make_scalar_function(split_part::<i64, i64>, vec![])(args)
+                }
+                (string_type, delimiter_type) => exec_err!(
+                    "Unsupported data types {string_type:?} and {delimiter_type:?} for function split_part"
+                ),
             }
-        }
+        })
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_dictionary_string_args(arg_types, &self.string_signature)
     }
 }
 
 /// Splits string at occurrences of delimiter and returns the n'th field (counting from one).
+/// Negative positions count from the end, and an empty delimiter does not split the string.
 /// split_part('abc~@~def~@~ghi', '~@~', 2) = 'def'
-fn split_part<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+fn split_part<T: OffsetSizeTrait, U: OffsetSizeTrait>(
+    args: &[ArrayRef],
+) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
-    let delimiter_array = as_generic_string_array::<T>(&args[1])?;
+    let delimiter_array = as_generic_string_array::<U>(&args[1])?;
     let n_array = as_int64_array(&args[2])?;
     let result = string_array
         .iter()
@@ -97,7 +122,11 @@ fn split_part<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
         .zip(n_array.iter())
         .map(|((string, delimiter), n)| match (string, delimiter, n) {
             (Some(string), Some(delimiter), Some(n)) => {
-                let split_string: Vec<&str> = string.split(delimiter).collect();
+                let split_string: Vec<&str> = if delimiter.is_empty() {
+                    vec![string]
+                } else {
+                    string.split(delimiter).collect()
+                };
                 let len = split_string.len();
 
                 let index = match n.cmp(&0) {
@@ -123,8 +152,8 @@ fn split_part<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 
 #[cfg(test)]
 mod tests {
-    use arrow::array::{Array, StringArray};
-    use arrow::datatypes::DataType::Utf8;
+    use arrow::array::{Array, LargeStringArray, StringArray};
+    use arrow::datatypes::DataType::{LargeUtf8, Utf8};
 
     use datafusion_common::ScalarValue;
     use datafusion_common::{exec_err, Result};
@@ -191,6 +220,58 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            SplitPartFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("日本~語~ü")),
+                ColumnarValue::Scalar(ScalarValue::from("~")),
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(-2))),
+            ],
+            Ok(Some("語")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        // an empty delimiter does not split the string
+        test_function!(
+            SplitPartFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("abc")),
+                ColumnarValue::Scalar(ScalarValue::from("")),
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
+            ],
+            Ok(Some("abc")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPartFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("abc")),
+                ColumnarValue::Scalar(ScalarValue::from("")),
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(2))),
+            ],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        // the string and the delimiter may have different offset types
+        test_function!(
+            SplitPartFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::LargeUtf8(Some(
+                    "foo_bar".to_string()
+                ))),
+                ColumnarValue::Scalar(ScalarValue::from("_")),
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(2))),
+            ],
+            Ok(Some("bar")),
+            &str,
+            LargeUtf8,
+            LargeStringArray
+        );
 
         Ok(())
     }
@@ -16,7 +16,10 @@
 // under the License.
 
 use crate::string::common::to_upper;
-use crate::utils::utf8_to_str_type;
+use crate::utils::{
+    coerce_dictionary_string_args, invoke_on_dictionary_values,
+    utf8_to_dictionary_str_type,
+};
 use arrow::datatypes::DataType;
 use datafusion_common::Result;
 use datafusion_expr::ColumnarValue;
@@ -26,6 +29,8 @@ use std::any::Any;
 #[derive(Debug)]
 pub struct UpperFunc {
     signature: Signature,
+    /// Signature of the function for arguments that are not dictionary encoded
+    string_signature: Signature,
 }
 
 impl Default for UpperFunc {
@@ -38,7 +43,8 @@ impl UpperFunc {
     pub fn new() -> Self {
         use DataType::*;
         Self {
-            signature: Signature::uniform(
+            signature: Signature::user_defined(Volatility::Immutable),
+            string_signature: Signature::uniform(
                 1,
                 vec![Utf8, LargeUtf8],
                 Volatility::Immutable,
@@ -61,18 +67,23 @@ impl ScalarUDFImpl for UpperFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "upper")
+        utf8_to_dictionary_str_type(&arg_types[0], "upper")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        to_upper(args, "upper")
+        invoke_on_dictionary_values(args, |args| to_upper(args, "upper"))
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_dictionary_string_args(arg_types, &self.string_signature)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow::array::{ArrayRef, StringArray};
+    use arrow::array::{Array, ArrayRef, AsArray, DictionaryArray, StringArray};
+    use arrow::datatypes::Int32Type;
     use std::sync::Arc;
 
     fn to_upper(input: ArrayRef, expected: ArrayRef) -> Result<()> {
@@ -160,4 +171,41 @@ mod tests {
 
         to_upper(input, expected)
     }
+
+    #[test]
+    fn upper_dictionary() -> Result<()> {
+        let input: DictionaryArray<Int32Type> =
+            vec![Some("arrow"), None, Some("datafusion"), Some("arrow")]
+                .into_iter()
+                .collect();
+        let input = Arc::new(input) as ArrayRef;
+        let dictionary_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+
+        let func = UpperFunc::new();
+        assert_eq!(
+            func.coerce_types(&[dictionary_type.clone()])?,
+            vec![dictionary_type.clone()]
+        );
+        assert_eq!(
+            func.return_type(&[dictionary_type])?,
+            input.data_type().clone()
+        );
+
+        let result = func.invoke(&[ColumnarValue::Array(Arc::clone(&input))])?;
+        let ColumnarValue::Array(result) = result else {
+            unreachable!()
+        };
+        let result = result.as_dictionary::<Int32Type>();
+        // the keys are reused as is
+        assert_eq!(result.keys(), input.as_dictionary::<Int32Type>().keys());
+        let expected = Arc::new(StringArray::from(vec![
+            Some("ARROW"),
+            None,
+            Some("DATAFUSION"),
+            Some("ARROW"),
+        ])) as ArrayRef;
+        assert_eq!(&arrow::compute::cast(result, &DataType::Utf8)?, &expected);
+        Ok(())
+    }
 }
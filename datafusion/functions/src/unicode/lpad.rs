@@ -23,7 +23,10 @@ use arrow::datatypes::DataType;
 use datafusion_common::cast::{as_generic_string_array, as_int64_array};
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::utils::{make_scalar_function, utf8_to_str_type};
+use crate::utils::{
+    coerce_dictionary_string_args, invoke_on_dictionary_values, make_scalar_function,
+    utf8_to_dictionary_str_type,
+};
 use datafusion_common::{exec_err, Result};
 use datafusion_expr::TypeSignature::Exact;
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
@@ -31,6 +34,8 @@ use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 #[derive(Debug)]
 pub struct LPadFunc {
     signature: Signature,
+    /// Signature of the function for arguments that are not dictionary encoded
+    string_signature: Signature,
 }
 
 impl Default for LPadFunc {
@@ -43,7 +48,8 @@ impl LPadFunc {
     pub fn new() -> Self {
         use DataType::*;
         Self {
-            signature: Signature::one_of(
+            signature: Signature::user_defined(Volatility::Immutable),
+            string_signature: Signature::one_of(
                 vec![
                     Exact(vec![Utf8, Int64]),
                     Exact(vec![LargeUtf8, Int64]),
@@ -72,15 +78,19 @@ impl ScalarUDFImpl for LPadFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "lpad")
+        utf8_to_dictionary_str_type(&arg_types[0], "lpad")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        match args[0].data_type() {
+        invoke_on_dictionary_values(args, |args| match args[0].data_type() {
             DataType::Utf8 => make_scalar_function(lpad::<i32>, vec![])(args),
             DataType::LargeUtf8 => make_scalar_function(lpad::<i64>, vec![])(args),
             other => exec_err!("Unsupported data type {other:?} for function lpad"),
-        }
+        })
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_dictionary_string_args(arg_types, &self.string_signature)
     }
 }
 
@@ -182,8 +192,12 @@ pub fn lpad<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 
 #[cfg(test)]
 mod tests {
-    use arrow::array::{Array, StringArray};
-    use arrow::datatypes::DataType::Utf8;
+    use std::sync::Arc;
+
+    use arrow::array::{Array, AsArray, DictionaryArray, Int64Array, StringArray};
+    use arrow::compute::cast;
+    use arrow::datatypes::DataType::{Dictionary, Int32, Utf8};
+    use arrow::datatypes::Int32Type;
 
     use datafusion_common::{Result, ScalarValue};
     use datafusion_expr::{ColumnarValue, ScalarUDFImpl};
@@ -372,4 +386,29 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_dictionary_with_array_lengths() -> Result<()> {
+        let input: DictionaryArray<Int32Type> =
+            vec![Some("hi"), Some("hi"), None].into_iter().collect();
+        let lengths = Int64Array::from(vec![3, 5, 5]);
+        let result = LPadFunc::new().invoke(&[
+            ColumnarValue::Array(Arc::new(input)),
+            ColumnarValue::Array(Arc::new(lengths)),
+        ])?;
+        let ColumnarValue::Array(result) = result else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            result.data_type(),
+            &Dictionary(Box::new(Int32), Box::new(Utf8))
+        );
+        let result = cast(&result, &Utf8)?;
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec![Some(" hi"), Some("   hi"), None])
+        );
+        Ok(())
+    }
 }
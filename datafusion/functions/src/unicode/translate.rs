@@ -28,11 +28,16 @@ use datafusion_common::{exec_err, Result};
 use datafusion_expr::TypeSignature::Exact;
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 
-use crate::utils::{make_scalar_function, utf8_to_str_type};
+use crate::utils::{
+    coerce_dictionary_string_args, invoke_on_dictionary_values, make_scalar_function,
+    utf8_to_dictionary_str_type,
+};
 
 #[derive(Debug)]
 pub struct TranslateFunc {
     signature: Signature,
+    /// Signature of the function for arguments that are not dictionary encoded
+    string_signature: Signature,
 }
 
 impl Default for TranslateFunc {
@@ -45,8 +50,12 @@ impl TranslateFunc {
     pub fn new() -> Self {
         use DataType::*;
         Self {
-            signature: Signature::one_of(
-                vec![Exact(vec![Utf8, Utf8, Utf8])],
+            signature: Signature::user_defined(Volatility::Immutable),
+            string_signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8, Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8, Utf8]),
+                ],
                 Volatility::Immutable,
             ),
         }
@@ -67,17 +76,21 @@ impl ScalarUDFImpl for TranslateFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "translate")
+        utf8_to_dictionary_str_type(&arg_types[0], "translate")
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        match args[0].data_type() {
+        invoke_on_dictionary_values(args, |args| match args[0].data_type() {
             DataType::Utf8 => make_scalar_function(translate::<i32>, vec![])(args),
             DataType::LargeUtf8 => make_scalar_function(translate::<i64>, vec![])(args),
             other => {
                 exec_err!("Unsupported data type {other:?} for function translate")
             }
-        }
+        })
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_dictionary_string_args(arg_types, &self.string_signature)
     }
 }
 
@@ -85,8 +98,8 @@ impl ScalarUDFImpl for TranslateFunc {
 /// translate('12345', '143', 'ax') = 'a2x5'
 fn translate<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
-    let from_array = as_generic_string_array::<T>(&args[1])?;
-    let to_array = as_generic_string_array::<T>(&args[2])?;
+    let from_array = as_generic_string_array::<i32>(&args[1])?;
+    let to_array = as_generic_string_array::<i32>(&args[2])?;
 
     let result = string_array
         .iter()
@@ -127,8 +140,8 @@ fn translate<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 
 #[cfg(test)]
 mod tests {
-    use arrow::array::{Array, StringArray};
-    use arrow::datatypes::DataType::Utf8;
+    use arrow::array::{Array, LargeStringArray, StringArray};
+    use arrow::datatypes::DataType::{LargeUtf8, Utf8};
 
     use datafusion_common::{Result, ScalarValue};
     use datafusion_expr::{ColumnarValue, ScalarUDFImpl};
@@ -198,6 +211,31 @@ mod tests {
             Utf8,
             StringArray
         );
+        // grapheme clusters are translated as a whole
+        test_function!(
+            TranslateFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::from("e\u{301}e")),
+                ColumnarValue::Scalar(ScalarValue::from("e\u{301}")),
+                ColumnarValue::Scalar(ScalarValue::from("x")),
+            ],
+            Ok(Some("xe")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            TranslateFunc::new(),
+            &[
+                ColumnarValue::Scalar(ScalarValue::LargeUtf8(Some("12345".to_string()))),
+                ColumnarValue::Scalar(ScalarValue::from("143")),
+                ColumnarValue::Scalar(ScalarValue::from("ax"))
+            ],
+            Ok(Some("a2x5")),
+            &str,
+            LargeUtf8,
+            LargeStringArray
+        );
         #[cfg(not(feature = "unicode_expressions"))]
         test_function!(
             TranslateFunc::new(),
@@ -15,11 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::array::ArrayRef;
+use arrow::array::{ArrayRef, AsArray};
 use arrow::datatypes::DataType;
 use datafusion_common::{Result, ScalarValue};
 use datafusion_expr::function::Hint;
-use datafusion_expr::{ColumnarValue, ScalarFunctionImplementation};
+use datafusion_expr::type_coercion::functions::data_types;
+use datafusion_expr::{ColumnarValue, ScalarFunctionImplementation, Signature};
 use std::sync::Arc;
 
 /// Creates a function to identify the optimal return type of a string function given
@@ -68,6 +69,109 @@ get_optimal_return_type!(utf8_to_str_type, DataType::LargeUtf8, DataType::Utf8);
 // `utf8_to_int_type`: returns either a Int32 or Int64 based on the input type size.
 get_optimal_return_type!(utf8_to_int_type, DataType::Int64, DataType::Int32);
 
+/// Returns the value type of a dictionary encoded string type, or `None` if
+/// `data_type` is not one
+fn dictionary_string_value_type(data_type: &DataType) -> Option<&DataType> {
+    match data_type {
+        DataType::Dictionary(_, value_type)
+            if matches!(**value_type, DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            Some(value_type)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the return type of a string function whose result is dictionary
+/// encoded in the same way as its first argument (see
+/// [`invoke_on_dictionary_values`])
+pub(crate) fn utf8_to_dictionary_str_type(
+    arg_type: &DataType,
+    name: &str,
+) -> Result<DataType> {
+    match (arg_type, dictionary_string_value_type(arg_type)) {
+        (DataType::Dictionary(key_type, _), Some(value_type)) => {
+            Ok(DataType::Dictionary(
+                key_type.clone(),
+                Box::new(utf8_to_str_type(value_type, name)?),
+            ))
+        }
+        _ => utf8_to_str_type(arg_type, name),
+    }
+}
+
+/// Coerces `arg_types` to `signature`, except for a dictionary encoded string
+/// first argument which stays dictionary encoded (only its values are
+/// coerced) so that the function can run on the dictionary values only (see
+/// [`invoke_on_dictionary_values`]).
+pub(crate) fn coerce_dictionary_string_args(
+    arg_types: &[DataType],
+    signature: &Signature,
+) -> Result<Vec<DataType>> {
+    let Some(DataType::Dictionary(key_type, value_type)) = arg_types
+        .first()
+        .filter(|arg_type| dictionary_string_value_type(arg_type).is_some())
+    else {
+        return data_types(arg_types, signature);
+    };
+
+    let mut value_types = arg_types.to_vec();
+    value_types[0] = value_type.as_ref().clone();
+    let mut coerced_types = data_types(&value_types, signature)?;
+    if let Some(coerced_type) = coerced_types.first_mut() {
+        if matches!(coerced_type, DataType::Utf8 | DataType::LargeUtf8) {
+            *coerced_type =
+                DataType::Dictionary(key_type.clone(), Box::new(coerced_type.clone()));
+        }
+    }
+    Ok(coerced_types)
+}
+
+/// Evaluates a string function on a dictionary encoded first argument.
+///
+/// When all other arguments are scalars, `inner` only runs on the dictionary
+/// values and the keys of the argument are reused for the result, which is a
+/// lot cheaper than evaluating `inner` on every row of low cardinality
+/// columns. Otherwise the argument is unpacked and the result of `inner`
+/// dictionary encoded again.
+///
+/// Arguments that are not dictionary encoded are passed to `inner` directly.
+pub(crate) fn invoke_on_dictionary_values<F>(
+    args: &[ColumnarValue],
+    inner: F,
+) -> Result<ColumnarValue>
+where
+    F: Fn(&[ColumnarValue]) -> Result<ColumnarValue>,
+{
+    let (key_type, value_type) = match args.first().map(|arg| arg.data_type()) {
+        Some(DataType::Dictionary(key_type, value_type))
+            if matches!(*value_type, DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            (key_type, value_type)
+        }
+        _ => return inner(args),
+    };
+
+    let mut new_args = args.to_vec();
+    if let ColumnarValue::Array(array) = &args[0] {
+        if args[1..]
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)))
+        {
+            let dictionary = array.as_any_dictionary();
+            let values = dictionary.values();
+            new_args[0] = ColumnarValue::Array(Arc::clone(values));
+            let new_values = inner(&new_args)?.into_array(values.len())?;
+            return Ok(ColumnarValue::Array(dictionary.with_values(new_values)));
+        }
+    }
+
+    new_args[0] = args[0].cast_to(&value_type, None)?;
+    let result = inner(&new_args)?;
+    let result_type = DataType::Dictionary(key_type, Box::new(result.data_type()));
+    result.cast_to(&result_type, None)
+}
+
 /// Creates a scalar function implementation for the given function.
 /// * `inner` - the function to be executed
 /// * `hints` - hints to be used when expanding scalars to arrays
@@ -28,14 +28,14 @@ use datafusion_common::{
     DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::expr::{
-    self, AggregateFunctionDefinition, Between, BinaryExpr, Case, Exists, InList,
-    InSubquery, Like, ScalarFunction, WindowFunction,
+    self, AggregateFunctionDefinition, Alias, Between, BinaryExpr, Case, Cast, Exists,
+    InList, InSubquery, Like, ScalarFunction, WindowFunction,
 };
 use datafusion_expr::expr_schema::cast_subquery;
 use datafusion_expr::logical_plan::tree_node::unwrap_arc;
-use datafusion_expr::logical_plan::Subquery;
+use datafusion_expr::logical_plan::{Projection, Subquery, Union};
 use datafusion_expr::type_coercion::binary::{
-    comparison_coercion, get_input_types, like_coercion,
+    comparison_coercion, get_input_types, like_coercion, like_dictionary_type,
 };
 use datafusion_expr::type_coercion::functions::{
     data_types_with_aggregate_udf, data_types_with_scalar_udf,
@@ -68,11 +68,19 @@ impl AnalyzerRule for TypeCoercion {
         "type_coercion"
     }
 
-    fn analyze(&self, plan: LogicalPlan, _: &ConfigOptions) -> Result<LogicalPlan> {
+    fn analyze(&self, plan: LogicalPlan, config: &ConfigOptions) -> Result<LogicalPlan> {
         let empty_schema = DFSchema::empty();
+        let time_zone = config.execution.time_zone.as_deref().map(Arc::from);
 
         let transformed_plan = plan
-            .transform_up_with_subqueries(|plan| analyze_internal(&empty_schema, plan))?
+            .transform_up_with_subqueries(|plan| {
+                analyze_internal(
+                    &empty_schema,
+                    plan,
+                    config.execution.widen_decimal_aggregates,
+                    &time_zone,
+                )
+            })?
             .data;
 
         Ok(transformed_plan)
@@ -85,6 +93,8 @@ impl AnalyzerRule for TypeCoercion {
 fn analyze_internal(
     external_schema: &DFSchema,
     plan: LogicalPlan,
+    widen_decimal_aggregates: bool,
+    time_zone: &Option<Arc<str>>,
 ) -> Result<Transformed<LogicalPlan>> {
     // get schema representing all available input fields. This is used for data type
     // resolution only, so order does not matter here
@@ -103,7 +113,9 @@ fn analyze_internal(
     // select t2.c2 from t1 where t1.c1 in (select t2.c1 from t2 where t2.c2=t1.c3)
     schema.merge(external_schema);
 
-    let mut expr_rewrite = TypeCoercionRewriter::new(&schema);
+    let mut expr_rewrite = TypeCoercionRewriter::new(&schema)
+        .with_widen_decimal_aggregates(widen_decimal_aggregates)
+        .with_time_zone(time_zone.clone());
 
     let name_preserver = NamePreserver::new(&plan);
     // apply coercion rewrite all expressions in the plan individually
@@ -114,17 +126,61 @@ fn analyze_internal(
     })?
     // coerce join expressions specially
     .map_data(|plan| expr_rewrite.coerce_joins(plan))?
+    .transform_data(|plan| coerce_union_inputs(plan, time_zone))?
     // recompute the schema after the expressions have been rewritten as the types may have changed
     .map_data(|plan| plan.recompute_schema())
 }
 
 pub(crate) struct TypeCoercionRewriter<'a> {
     pub(crate) schema: &'a DFSchema,
+    /// Whether the aggregate functions widen their decimal arguments,
+    /// see [`AggregateUDF::widen_decimal_types`]
+    widen_decimal_aggregates: bool,
+    /// The session time zone, used to interpret timestamps without a time zone
+    /// when they are compared with timestamps with a time zone
+    time_zone: Option<Arc<str>>,
 }
 
 impl<'a> TypeCoercionRewriter<'a> {
-    fn new(schema: &'a DFSchema) -> Self {
-        Self { schema }
+    pub(crate) fn new(schema: &'a DFSchema) -> Self {
+        Self {
+            schema,
+            widen_decimal_aggregates: false,
+            time_zone: None,
+        }
+    }
+
+    fn with_widen_decimal_aggregates(mut self, widen_decimal_aggregates: bool) -> Self {
+        self.widen_decimal_aggregates = widen_decimal_aggregates;
+        self
+    }
+
+    fn with_time_zone(mut self, time_zone: Option<Arc<str>>) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// Casts the arguments of the aggregate function `fun` to the types they
+    /// are widened to, if enabled
+    fn widen_decimal_arguments(
+        &self,
+        args: Vec<Expr>,
+        fun: &AggregateUDF,
+    ) -> Result<Vec<Expr>> {
+        if !self.widen_decimal_aggregates {
+            return Ok(args);
+        }
+        let arg_types = args
+            .iter()
+            .map(|arg| arg.get_type(self.schema))
+            .collect::<Result<Vec<_>>>()?;
+        let Some(widened_types) = fun.widen_decimal_types(&arg_types) else {
+            return Ok(args);
+        };
+        args.into_iter()
+            .zip(widened_types)
+            .map(|(arg, data_type)| arg.cast_to(&data_type, self.schema))
+            .collect()
     }
 
     /// Coerce join equality expressions
@@ -166,8 +222,8 @@ impl<'a> TypeCoercionRewriter<'a> {
             &right.get_type(self.schema)?,
         )?;
         Ok((
-            left.cast_to(&left_type, self.schema)?,
-            right.cast_to(&right_type, self.schema)?,
+            cast_to_coerced_type(left, &left_type, self.schema, &self.time_zone)?,
+            cast_to_coerced_type(right, &right_type, self.schema, &self.time_zone)?,
         ))
     }
 }
@@ -184,15 +240,26 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                 subquery,
                 outer_ref_columns,
             }) => {
-                let new_plan = analyze_internal(self.schema, unwrap_arc(subquery))?.data;
+                let new_plan = analyze_internal(
+                    self.schema,
+                    unwrap_arc(subquery),
+                    self.widen_decimal_aggregates,
+                    &self.time_zone,
+                )?
+                .data;
                 Ok(Transformed::yes(Expr::ScalarSubquery(Subquery {
                     subquery: Arc::new(new_plan),
                     outer_ref_columns,
                 })))
             }
             Expr::Exists(Exists { subquery, negated }) => {
-                let new_plan =
-                    analyze_internal(self.schema, unwrap_arc(subquery.subquery))?.data;
+                let new_plan = analyze_internal(
+                    self.schema,
+                    unwrap_arc(subquery.subquery),
+                    self.widen_decimal_aggregates,
+                    &self.time_zone,
+                )?
+                .data;
                 Ok(Transformed::yes(Expr::Exists(Exists {
                     subquery: Subquery {
                         subquery: Arc::new(new_plan),
@@ -206,8 +273,13 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                 subquery,
                 negated,
             }) => {
-                let new_plan =
-                    analyze_internal(self.schema, unwrap_arc(subquery.subquery))?.data;
+                let new_plan = analyze_internal(
+                    self.schema,
+                    unwrap_arc(subquery.subquery),
+                    self.widen_decimal_aggregates,
+                    &self.time_zone,
+                )?
+                .data;
                 let expr_type = expr.get_type(self.schema)?;
                 let subquery_type = new_plan.schema().field(0).data_type();
                 let common_type = comparison_coercion(&expr_type, subquery_type).ok_or(plan_datafusion_err!(
@@ -265,9 +337,9 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                         "There isn't a common type to coerce {left_type} and {right_type} in {op_name} expression"
                     )
                 })?;
-                let expr = match left_type {
-                    DataType::Dictionary(_, inner) if *inner == DataType::Utf8 => expr,
-                    _ => Box::new(expr.cast_to(&coerced_type, self.schema)?),
+                let expr = match like_dictionary_type(&left_type, &coerced_type) {
+                    Some(_) => expr,
+                    None => Box::new(expr.cast_to(&coerced_type, self.schema)?),
                 };
                 let pattern = Box::new(pattern.cast_to(&coerced_type, self.schema)?);
                 Ok(Transformed::yes(Expr::Like(Like::new(
@@ -278,6 +350,32 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                     case_insensitive,
                 ))))
             }
+            Expr::SimilarTo(Like {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+                case_insensitive,
+            }) => {
+                let left_type = expr.get_type(self.schema)?;
+                let right_type = pattern.get_type(self.schema)?;
+                let coerced_type = like_coercion(&left_type,  &right_type).ok_or_else(|| {
+                    plan_datafusion_err!(
+                        "There isn't a common type to coerce {left_type} and {right_type} in SIMILAR TO expression"
+                    )
+                })?;
+                // SIMILAR TO is evaluated by the regex kernels, which do not
+                // support dictionaries
+                let expr = Box::new(expr.cast_to(&coerced_type, self.schema)?);
+                let pattern = Box::new(pattern.cast_to(&coerced_type, self.schema)?);
+                Ok(Transformed::yes(Expr::SimilarTo(Like::new(
+                    negated,
+                    expr,
+                    pattern,
+                    escape_char,
+                    case_insensitive,
+                ))))
+            }
             Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
                 let (left, right) = self.coerce_binary_op(*left, &op, *right)?;
                 Ok(Transformed::yes(Expr::BinaryExpr(BinaryExpr::new(
@@ -296,29 +394,38 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                 let low_type = low.get_type(self.schema)?;
                 let low_coerced_type = comparison_coercion(&expr_type, &low_type)
                     .ok_or_else(|| {
-                        DataFusionError::Internal(format!(
+                        plan_datafusion_err!(
                             "Failed to coerce types {expr_type} and {low_type} in BETWEEN expression"
-                        ))
+                        )
                     })?;
                 let high_type = high.get_type(self.schema)?;
-                let high_coerced_type = comparison_coercion(&expr_type, &low_type)
+                let high_coerced_type = comparison_coercion(&expr_type, &high_type)
                     .ok_or_else(|| {
-                        DataFusionError::Internal(format!(
+                        plan_datafusion_err!(
                             "Failed to coerce types {expr_type} and {high_type} in BETWEEN expression"
-                        ))
+                        )
                     })?;
                 let coercion_type =
                     comparison_coercion(&low_coerced_type, &high_coerced_type)
                         .ok_or_else(|| {
-                            DataFusionError::Internal(format!(
-                                "Failed to coerce types {expr_type} and {high_type} in BETWEEN expression"
-                            ))
+                            plan_datafusion_err!(
+                                "Failed to coerce types {low_type} and {high_type} in BETWEEN expression"
+                            )
                         })?;
+                let cast = |expr: Box<Expr>| {
+                    cast_to_coerced_type(
+                        *expr,
+                        &coercion_type,
+                        self.schema,
+                        &self.time_zone,
+                    )
+                    .map(Box::new)
+                };
                 Ok(Transformed::yes(Expr::Between(Between::new(
-                    Box::new(expr.cast_to(&coercion_type, self.schema)?),
+                    cast(expr)?,
                     negated,
-                    Box::new(low.cast_to(&coercion_type, self.schema)?),
-                    Box::new(high.cast_to(&coercion_type, self.schema)?),
+                    cast(low)?,
+                    cast(high)?,
                 ))))
             }
             Expr::InList(InList {
@@ -339,13 +446,17 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                     ),
                     Some(coerced_type) => {
                         // find the coerced type
-                        let cast_expr = expr.cast_to(&coerced_type, self.schema)?;
-                        let cast_list_expr = list
-                            .into_iter()
-                            .map(|list_expr| {
-                                list_expr.cast_to(&coerced_type, self.schema)
-                            })
-                            .collect::<Result<Vec<_>>>()?;
+                        let cast = |expr: Expr| {
+                            cast_to_coerced_type(
+                                expr,
+                                &coerced_type,
+                                self.schema,
+                                &self.time_zone,
+                            )
+                        };
+                        let cast_expr = cast(*expr)?;
+                        let cast_list_expr =
+                            list.into_iter().map(cast).collect::<Result<Vec<_>>>()?;
                         Ok(Transformed::yes(Expr::InList(InList ::new(
                              Box::new(cast_expr),
                              cast_list_expr,
@@ -355,7 +466,7 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                 }
             }
             Expr::Case(case) => {
-                let case = coerce_case_expression(case, self.schema)?;
+                let case = coerce_case_expression(case, self.schema, &self.time_zone)?;
                 Ok(Transformed::yes(Expr::Case(case)))
             }
             Expr::ScalarFunction(ScalarFunction { func, args }) => {
@@ -363,6 +474,7 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                     args,
                     self.schema,
                     &func,
+                    &self.time_zone,
                 )?;
                 let new_expr = coerce_arguments_for_fun(new_expr, self.schema, &func)?;
                 Ok(Transformed::yes(Expr::ScalarFunction(
@@ -401,6 +513,7 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                         self.schema,
                         &fun,
                     )?;
+                    let new_expr = self.widen_decimal_arguments(new_expr, &fun)?;
                     Ok(Transformed::yes(Expr::AggregateFunction(
                         expr::AggregateFunction::new_udf(
                             fun,
@@ -420,6 +533,7 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                 order_by,
                 window_frame,
                 null_treatment,
+                distinct,
             }) => {
                 let window_frame =
                     coerce_window_frame(window_frame, self.schema, &order_by)?;
@@ -434,29 +548,32 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
                         )?
                     }
                     expr::WindowFunctionDefinition::AggregateUDF(udf) => {
-                        coerce_arguments_for_signature_with_aggregate_udf(
+                        let args = coerce_arguments_for_signature_with_aggregate_udf(
                             args,
                             self.schema,
                             udf,
-                        )?
+                        )?;
+                        self.widen_decimal_arguments(args, udf)?
                     }
                     _ => args,
                 };
 
-                Ok(Transformed::yes(Expr::WindowFunction(WindowFunction::new(
-                    fun,
-                    args,
-                    partition_by,
-                    order_by,
-                    window_frame,
-                    null_treatment,
-                ))))
+                Ok(Transformed::yes(Expr::WindowFunction(
+                    WindowFunction::new(
+                        fun,
+                        args,
+                        partition_by,
+                        order_by,
+                        window_frame,
+                        null_treatment,
+                    )
+                    .with_distinct(distinct),
+                )))
             }
             Expr::Alias(_)
             | Expr::Column(_)
             | Expr::ScalarVariable(_, _)
             | Expr::Literal(_)
-            | Expr::SimilarTo(_)
             | Expr::IsNotNull(_)
             | Expr::IsNull(_)
             | Expr::Negative(_)
@@ -471,6 +588,131 @@ impl<'a> TreeNodeRewriter for TypeCoercionRewriter<'a> {
     }
 }
 
+/// Casts `expr` to `coerced_type`
+///
+/// A timestamp without a time zone that is coerced to a timestamp with a
+/// time zone is interpreted in the session `time_zone` (as PostgreSQL does),
+/// rather than in the time zone it is coerced to
+fn cast_to_coerced_type(
+    expr: Expr,
+    coerced_type: &DataType,
+    schema: &DFSchema,
+    time_zone: &Option<Arc<str>>,
+) -> Result<Expr> {
+    let expr_type = expr.get_type(schema)?;
+    match local_timestamp_type(&expr_type, coerced_type, time_zone) {
+        Some(local_type) => expr
+            .cast_to(&local_type, schema)?
+            .cast_to(coerced_type, schema),
+        None => expr.cast_to(coerced_type, schema),
+    }
+}
+
+/// Returns the type a timestamp without a time zone of `expr_type` is first
+/// cast to, if it is coerced to a timestamp with a time zone other than the
+/// session `time_zone`
+fn local_timestamp_type(
+    expr_type: &DataType,
+    coerced_type: &DataType,
+    time_zone: &Option<Arc<str>>,
+) -> Option<DataType> {
+    match (expr_type, coerced_type, time_zone) {
+        (
+            DataType::Timestamp(unit, None),
+            DataType::Timestamp(_, Some(coerced_tz)),
+            Some(time_zone),
+        ) if coerced_tz != time_zone => {
+            Some(DataType::Timestamp(*unit, Some(Arc::clone(time_zone))))
+        }
+        _ => None,
+    }
+}
+
+/// The inputs of a union are coerced to its schema when the plan is built,
+/// which casts a timestamp without a time zone to the time zone of the union
+/// column. Interpret such timestamps in the session `time_zone` instead, like
+/// the other coercions do
+fn coerce_union_inputs(
+    plan: LogicalPlan,
+    time_zone: &Option<Arc<str>>,
+) -> Result<Transformed<LogicalPlan>> {
+    let LogicalPlan::Union(Union { inputs, schema }) = plan else {
+        return Ok(Transformed::no(plan));
+    };
+    let mut transformed = false;
+    let inputs = inputs
+        .into_iter()
+        .map(|input| {
+            let LogicalPlan::Projection(projection) = input.as_ref() else {
+                return Ok(input);
+            };
+            let localized = projection
+                .expr
+                .iter()
+                .map(|expr| {
+                    localize_union_expr(expr, projection.input.schema(), time_zone)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if localized.iter().all(Option::is_none) {
+                return Ok(input);
+            }
+            transformed = true;
+            let expr = localized
+                .into_iter()
+                .zip(&projection.expr)
+                .map(|(localized, expr)| localized.unwrap_or_else(|| expr.clone()))
+                .collect();
+            Ok(Arc::new(LogicalPlan::Projection(
+                Projection::try_new_with_schema(
+                    expr,
+                    Arc::clone(&projection.input),
+                    Arc::clone(&projection.schema),
+                )?,
+            )))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let plan = LogicalPlan::Union(Union { inputs, schema });
+    Ok(if transformed {
+        Transformed::yes(plan)
+    } else {
+        Transformed::no(plan)
+    })
+}
+
+/// Returns `expr`, an aliased cast added by the union coercion, with the
+/// timestamp it casts interpreted in the session `time_zone`, if needed
+fn localize_union_expr(
+    expr: &Expr,
+    schema: &DFSchema,
+    time_zone: &Option<Arc<str>>,
+) -> Result<Option<Expr>> {
+    let Expr::Alias(Alias {
+        expr: aliased,
+        relation,
+        name,
+    }) = expr
+    else {
+        return Ok(None);
+    };
+    let Expr::Cast(Cast { expr, data_type }) = aliased.as_ref() else {
+        return Ok(None);
+    };
+    let expr_type = expr.get_type(schema)?;
+    let Some(local_type) = local_timestamp_type(&expr_type, data_type, time_zone) else {
+        return Ok(None);
+    };
+    let localized = expr
+        .as_ref()
+        .clone()
+        .cast_to(&local_type, schema)?
+        .cast_to(data_type, schema)?;
+    Ok(Some(Expr::Alias(Alias::new(
+        localized,
+        relation.clone(),
+        name.clone(),
+    ))))
+}
+
 /// Casts the given `value` to `target_type`. Note that this function
 /// only considers `Null` or `Utf8` values.
 fn coerce_scalar(target_type: &DataType, value: &ScalarValue) -> Result<ScalarValue> {
@@ -598,6 +840,7 @@ fn coerce_arguments_for_signature_with_scalar_udf(
     expressions: Vec<Expr>,
     schema: &DFSchema,
     func: &ScalarUDF,
+    time_zone: &Option<Arc<str>>,
 ) -> Result<Vec<Expr>> {
     if expressions.is_empty() {
         return Ok(expressions);
@@ -613,7 +856,7 @@ fn coerce_arguments_for_signature_with_scalar_udf(
     expressions
         .into_iter()
         .enumerate()
-        .map(|(i, expr)| expr.cast_to(&new_types[i], schema))
+        .map(|(i, expr)| cast_to_coerced_type(expr, &new_types[i], schema, time_zone))
         .collect()
 }
 
@@ -695,7 +938,11 @@ fn coerce_agg_exprs_for_signature(
         .collect()
 }
 
-fn coerce_case_expression(case: Case, schema: &DFSchema) -> Result<Case> {
+fn coerce_case_expression(
+    case: Case,
+    schema: &DFSchema,
+    time_zone: &Option<Arc<str>>,
+) -> Result<Case> {
     // Given expressions like:
     //
     // CASE a1
@@ -776,7 +1023,9 @@ fn coerce_case_expression(case: Case, schema: &DFSchema) -> Result<Case> {
     let case_expr = case
         .expr
         .zip(case_when_coerce_type.as_ref())
-        .map(|(case_expr, coercible_type)| case_expr.cast_to(coercible_type, schema))
+        .map(|(case_expr, coercible_type)| {
+            cast_to_coerced_type(case_expr, coercible_type, schema, time_zone)
+        })
         .transpose()?
         .map(Box::new);
     let when_then = case
@@ -784,22 +1033,25 @@ fn coerce_case_expression(case: Case, schema: &DFSchema) -> Result<Case> {
         .into_iter()
         .map(|(when, then)| {
             let when_type = case_when_coerce_type.as_ref().unwrap_or(&DataType::Boolean);
-            let when = when.cast_to(when_type, schema).map_err(|e| {
-                DataFusionError::Context(
-                    format!(
-                        "WHEN expressions in CASE couldn't be \
+            let when = cast_to_coerced_type(when, when_type, schema, time_zone).map_err(
+                |e| {
+                    DataFusionError::Context(
+                        format!(
+                            "WHEN expressions in CASE couldn't be \
                          converted to common type ({when_type})"
-                    ),
-                    Box::new(e),
-                )
-            })?;
-            let then = then.cast_to(&then_else_coerce_type, schema)?;
+                        ),
+                        Box::new(e),
+                    )
+                },
+            )?;
+            let then =
+                cast_to_coerced_type(then, &then_else_coerce_type, schema, time_zone)?;
             Ok((Box::new(when), Box::new(then)))
         })
         .collect::<Result<Vec<_>>>()?;
     let else_expr = case
         .else_expr
-        .map(|expr| expr.cast_to(&then_else_coerce_type, schema))
+        .map(|expr| cast_to_coerced_type(expr, &then_else_coerce_type, schema, time_zone))
         .transpose()?
         .map(Box::new);
 
@@ -822,8 +1074,8 @@ mod test {
     use datafusion_expr::{
         cast, col, create_udaf, is_true, lit, AccumulatorFactoryFunction, AggregateUDF,
         BinaryExpr, Case, ColumnarValue, Expr, ExprSchemable, Filter, LogicalPlan,
-        Operator, ScalarUDF, ScalarUDFImpl, Signature, SimpleAggregateUDF, Subquery,
-        Volatility,
+        LogicalPlanBuilder, Operator, ScalarUDF, ScalarUDFImpl, Signature,
+        SimpleAggregateUDF, Subquery, Volatility,
     };
     use datafusion_functions_aggregate::average::AvgAccumulator;
 
@@ -1104,7 +1356,7 @@ mod test {
         let empty = empty_with_type(DataType::Utf8);
         let plan = LogicalPlan::Filter(Filter::try_new(expr, empty)?);
         let expected =
-            "Filter: a BETWEEN Utf8(\"2002-05-08\") AND CAST(CAST(Utf8(\"2002-05-08\") AS Date32) + IntervalYearMonth(\"1\") AS Utf8)\
+            "Filter: CAST(a AS Date32) BETWEEN CAST(Utf8(\"2002-05-08\") AS Date32) AND CAST(Utf8(\"2002-05-08\") AS Date32) + IntervalYearMonth(\"1\")\
             \n  EmptyRelation";
         assert_analyzed_plan_eq(Arc::new(TypeCoercion::new()), plan, expected)
     }
@@ -1225,6 +1477,31 @@ mod test {
         assert!(err.unwrap_err().to_string().contains(
             "There isn't a common type to coerce Int64 and Utf8 in ILIKE expression"
         ));
+
+        // dictionaries of strings are matched without casting them
+        let dict = |value_type: DataType| {
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(value_type))
+        };
+        let expr = Box::new(col("a"));
+        let pattern = Box::new(lit(ScalarValue::new_utf8("abc")));
+        let like_expr = Expr::Like(Like::new(false, expr, pattern, None, false));
+        let empty = empty_with_type(dict(DataType::LargeUtf8));
+        let plan = LogicalPlan::Projection(Projection::try_new(vec![like_expr], empty)?);
+        let expected =
+            "Projection: a LIKE CAST(Utf8(\"abc\") AS LargeUtf8) AS a LIKE Utf8(\"abc\")\
+             \n  EmptyRelation";
+        assert_analyzed_plan_eq(Arc::new(TypeCoercion::new()), plan, expected)?;
+
+        // other dictionaries are cast to strings
+        let expr = Box::new(col("a"));
+        let pattern = Box::new(lit(ScalarValue::new_utf8("abc")));
+        let ilike_expr = Expr::Like(Like::new(false, expr, pattern, None, true));
+        let empty = empty_with_type(dict(DataType::Binary));
+        let plan = LogicalPlan::Projection(Projection::try_new(vec![ilike_expr], empty)?);
+        let expected =
+            "Projection: CAST(a AS Utf8) ILIKE Utf8(\"abc\") AS a ILIKE Utf8(\"abc\")\
+             \n  EmptyRelation";
+        assert_analyzed_plan_eq(Arc::new(TypeCoercion::new()), plan, expected)?;
         Ok(())
     }
 
@@ -1282,7 +1559,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema: &schema };
+        let mut rewriter = TypeCoercionRewriter::new(&schema);
         let expr = is_true(lit(12i32).gt(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).gt(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
@@ -1293,7 +1570,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema: &schema };
+        let mut rewriter = TypeCoercionRewriter::new(&schema);
         let expr = is_true(lit(12i32).eq(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).eq(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
@@ -1304,7 +1581,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema: &schema };
+        let mut rewriter = TypeCoercionRewriter::new(&schema);
         let expr = is_true(lit(12i32).lt(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).lt(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
@@ -1313,6 +1590,95 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_type_coercion_rewrite_timestamp_time_zones() -> Result<()> {
+        let naive = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let utc = DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()));
+        let berlin =
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("Europe/Berlin".into()));
+        let schema = Arc::new(DFSchema::from_unqualified_fields(
+            vec![
+                Field::new("naive", naive, true),
+                Field::new("utc", utc.clone(), true),
+                Field::new("berlin", berlin.clone(), true),
+            ]
+            .into(),
+            std::collections::HashMap::new(),
+        )?);
+
+        // timestamps with different time zones are compared as instants
+        let mut rewriter = TypeCoercionRewriter::new(&schema);
+        let expr = col("utc").lt(col("berlin"));
+        let expected = col("utc").lt(cast(col("berlin"), utc));
+        let result = expr.rewrite(&mut rewriter).data()?;
+        assert_eq!(expected, result);
+
+        // timestamps without a time zone are interpreted in the session time zone
+        let mut rewriter =
+            TypeCoercionRewriter::new(&schema).with_time_zone(Some("+05:00".into()));
+        let expr = col("naive").eq(col("berlin"));
+        let expected = cast(
+            cast(
+                col("naive"),
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("+05:00".into())),
+            ),
+            berlin.clone(),
+        )
+        .eq(col("berlin"));
+        let result = expr.rewrite(&mut rewriter).data()?;
+        assert_eq!(expected, result);
+
+        // the other coercions use the session time zone as well
+        let local_naive = cast(
+            cast(
+                col("naive"),
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("+05:00".into())),
+            ),
+            berlin.clone(),
+        );
+        let expr = col("naive").between(col("berlin"), col("berlin"));
+        let expected = local_naive.clone().between(col("berlin"), col("berlin"));
+        let result = expr.rewrite(&mut rewriter).data()?;
+        assert_eq!(expected, result);
+
+        let expr = col("naive").in_list(vec![col("berlin")], false);
+        let expected = local_naive.in_list(vec![col("berlin")], false);
+        let result = expr.rewrite(&mut rewriter).data()?;
+        assert_eq!(expected, result);
+
+        // without a session time zone, the time zone of the other side is used
+        let mut rewriter = TypeCoercionRewriter::new(&schema);
+        let expr = col("naive").eq(col("berlin"));
+        let expected = cast(col("naive"), berlin).eq(col("berlin"));
+        let result = expr.rewrite(&mut rewriter).data()?;
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_timestamp_time_zones() -> Result<()> {
+        let naive = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let berlin =
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("Europe/Berlin".into()));
+        let plan = LogicalPlanBuilder::from(empty_with_type(naive).as_ref().clone())
+            .project(vec![col("a")])?
+            .union(
+                LogicalPlanBuilder::from(empty_with_type(berlin).as_ref().clone())
+                    .project(vec![col("a")])?
+                    .build()?,
+            )?
+            .build()?;
+        // the timestamp without a time zone is read in the session time zone,
+        // which defaults to +00:00
+        let expected = "Union\
+            \n  Projection: CAST(CAST(a AS Timestamp(Nanosecond, Some(\"+00:00\"))) AS Timestamp(Nanosecond, Some(\"Europe/Berlin\"))) AS a\
+            \n    EmptyRelation\
+            \n  Projection: a\
+            \n    EmptyRelation";
+        assert_analyzed_plan_eq(Arc::new(TypeCoercion::new()), plan, expected)
+    }
+
     #[test]
     fn binary_op_date32_eq_ts() -> Result<()> {
         let expr = cast(
@@ -1414,7 +1780,7 @@ mod test {
             then_else_common_type,
             &schema,
         );
-        let actual = coerce_case_expression(case, &schema)?;
+        let actual = coerce_case_expression(case, &schema, &None)?;
         assert_eq!(expected, actual);
 
         let case = Case {
@@ -1434,7 +1800,7 @@ mod test {
             then_else_common_type,
             &schema,
         );
-        let actual = coerce_case_expression(case, &schema)?;
+        let actual = coerce_case_expression(case, &schema, &None)?;
         assert_eq!(expected, actual);
 
         let case = Case {
@@ -1446,7 +1812,7 @@ mod test {
             ],
             else_expr: Some(Box::new(col("string"))),
         };
-        let err = coerce_case_expression(case, &schema).unwrap_err();
+        let err = coerce_case_expression(case, &schema, &None).unwrap_err();
         assert_eq!(
             err.strip_backtrace(),
             "Error during planning: \
@@ -1464,7 +1830,7 @@ mod test {
             ],
             else_expr: Some(Box::new(col("timestamp"))),
         };
-        let err = coerce_case_expression(case, &schema).unwrap_err();
+        let err = coerce_case_expression(case, &schema, &None).unwrap_err();
         assert_eq!(
             err.strip_backtrace(),
             "Error during planning: \
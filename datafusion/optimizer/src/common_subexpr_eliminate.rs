@@ -120,7 +120,9 @@ type CommonExprs<'n> = IndexMap<Identifier<'n>, (Expr, String)>;
 /// same value
 ///
 /// Currently only common sub-expressions within a single `LogicalPlan` are
-/// eliminated.
+/// eliminated. The sub-expressions shared by a filter and the projection on top
+/// of it are eliminated by the `FilterProjectionCommonSubexprs` physical
+/// optimizer rule instead.
 ///
 /// # Example
 ///
@@ -26,15 +26,17 @@ use crate::utils::replace_qualified_name;
 use crate::{OptimizerConfig, OptimizerRule};
 
 use datafusion_common::alias::AliasGenerator;
-use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
-use datafusion_common::{internal_err, plan_err, Result};
+use datafusion_common::tree_node::{
+    Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
+};
+use datafusion_common::{internal_err, plan_err, Column, Result};
 use datafusion_expr::expr::{Exists, InSubquery};
 use datafusion_expr::expr_rewriter::create_col_from_scalar_expr;
 use datafusion_expr::logical_plan::{JoinType, Subquery};
 use datafusion_expr::utils::{conjunction, split_conjunction, split_conjunction_owned};
 use datafusion_expr::{
-    exists, in_subquery, not, not_exists, not_in_subquery, BinaryExpr, Expr, Filter,
-    LogicalPlan, LogicalPlanBuilder, Operator,
+    exists, in_subquery, max, not, not_exists, not_in_subquery, BinaryExpr, Expr,
+    ExprSchemable, Filter, LogicalPlan, LogicalPlanBuilder, Operator,
 };
 
 use datafusion_expr::logical_plan::tree_node::unwrap_arc;
@@ -292,50 +294,152 @@ fn build_join(
                 .map(Option::Some)
         })?;
 
-    if let Some(join_filter) = match (join_filter_opt, in_predicate_opt) {
-        (
-            Some(join_filter),
-            Some(Expr::BinaryExpr(BinaryExpr {
-                left,
-                op: Operator::Eq,
-                right,
-            })),
-        ) => {
-            let right_col = create_col_from_scalar_expr(right.deref(), subquery_alias)?;
-            let in_predicate = Expr::eq(left.deref().clone(), Expr::Column(right_col));
-            Some(in_predicate.and(join_filter))
-        }
-        (Some(join_filter), _) => Some(join_filter),
-        (
-            _,
-            Some(Expr::BinaryExpr(BinaryExpr {
-                left,
-                op: Operator::Eq,
-                right,
-            })),
-        ) => {
-            let right_col = create_col_from_scalar_expr(right.deref(), subquery_alias)?;
-            let in_predicate = Expr::eq(left.deref().clone(), Expr::Column(right_col));
-            Some(in_predicate)
+    // the operands of the IN predicate, with the output of the subquery
+    // referenced through its alias
+    let in_operands = match in_predicate_opt {
+        Some(Expr::BinaryExpr(BinaryExpr {
+            left: in_expr,
+            op: Operator::Eq,
+            right,
+        })) => {
+            let subquery_col =
+                create_col_from_scalar_expr(right.deref(), subquery_alias.clone())?;
+            Some((*in_expr, Expr::Column(subquery_col)))
         }
         _ => None,
-    } {
-        // join our sub query into the main plan
-        let join_type = match query_info.negated {
-            true => JoinType::LeftAnti,
-            false => JoinType::LeftSemi,
-        };
-        let new_plan = LogicalPlanBuilder::from(left.clone())
-            .join_on(sub_query_alias, join_type, Some(join_filter))?
-            .build()?;
-        debug!(
-            "predicate subquery optimized:\n{}",
-            new_plan.display_indent()
-        );
-        Ok(Some(new_plan))
-    } else {
-        Ok(None)
-    }
+    };
+
+    let join_type = match query_info.negated {
+        true => JoinType::LeftAnti,
+        false => JoinType::LeftSemi,
+    };
+    let join_filter = match (join_filter_opt, in_operands) {
+        (join_filter, Some((in_expr, subquery_col))) if query_info.negated => {
+            // `x NOT IN (subquery)` is NULL, which filters the row out, when
+            // `x` or any value of the subquery is NULL, unless the subquery
+            // is empty
+            let in_expr_nullable = in_expr.nullable(left.schema())?;
+            let subquery_nullable = subquery_col.nullable(sub_query_alias.schema())?;
+            if join_filter.is_none()
+                && (in_expr_nullable || subquery_nullable)
+                // the subquery is evaluated twice by the null-aware anti
+                // join, which could see different results if it is volatile
+                && !is_volatile_plan(&sub_query_alias)?
+            {
+                return build_null_aware_anti_join(
+                    left,
+                    sub_query_alias,
+                    in_expr,
+                    subquery_col,
+                    alias,
+                )
+                .map(Some);
+            }
+            let mut in_predicate = in_expr.clone().eq(subquery_col.clone());
+            if in_expr_nullable {
+                in_predicate = in_predicate.or(in_expr.is_null());
+            }
+            if subquery_nullable {
+                in_predicate = in_predicate.or(subquery_col.is_null());
+            }
+            match join_filter {
+                Some(join_filter) => in_predicate.and(join_filter),
+                None => in_predicate,
+            }
+        }
+        (Some(join_filter), Some((in_expr, subquery_col))) => {
+            in_expr.eq(subquery_col).and(join_filter)
+        }
+        (None, Some((in_expr, subquery_col))) => in_expr.eq(subquery_col),
+        (Some(join_filter), None) => join_filter,
+        (None, None) => return Ok(None),
+    };
+
+    // join our sub query into the main plan
+    let new_plan = LogicalPlanBuilder::from(left.clone())
+        .join_on(sub_query_alias, join_type, Some(join_filter))?
+        .build()?;
+    debug!(
+        "predicate subquery optimized:\n{}",
+        new_plan.display_indent()
+    );
+    Ok(Some(new_plan))
+}
+
+/// Rewrites an uncorrelated `in_expr NOT IN (subquery)` where `in_expr` or
+/// the output of the subquery is nullable, and the subquery is not volatile.
+///
+/// Checking for NULLs in the join filter would prevent using an equijoin, so
+/// the NULLs are instead checked with an aggregate over the subquery, which
+/// is NULL when the subquery is empty and true when it returns a NULL:
+///
+/// ```text
+/// Projection: t1.a
+///   Filter: __not_in_sq_2.has_null IS NULL OR t1.a IS NOT NULL AND NOT __not_in_sq_2.has_null
+///     CrossJoin:
+///       SubqueryAlias: __not_in_sq_2
+///         Aggregate: groupBy=[[]], aggr=[[MAX(__correlated_sq_1.a IS NULL) AS has_null]]
+///           SubqueryAlias: __correlated_sq_1
+///             TableScan: t2
+///       LeftAnti Join:  Filter: t1.a = __correlated_sq_1.a
+///         TableScan: t1
+///         SubqueryAlias: __correlated_sq_1
+///           TableScan: t2
+/// ```
+fn build_null_aware_anti_join(
+    left: &LogicalPlan,
+    subquery: LogicalPlan,
+    in_expr: Expr,
+    subquery_col: Expr,
+    alias: Arc<AliasGenerator>,
+) -> Result<LogicalPlan> {
+    let stats_alias = alias.next("__not_in_sq");
+    let has_null = Expr::Column(Column::new(Some(stats_alias.as_str()), "has_null"));
+    let stats = LogicalPlanBuilder::from(subquery.clone())
+        .aggregate(
+            Vec::<Expr>::new(),
+            vec![max(subquery_col.clone().is_null()).alias("has_null")],
+        )?
+        .alias(stats_alias)?
+        .build()?;
+    let anti_join = LogicalPlanBuilder::from(left.clone())
+        .join_on(
+            subquery,
+            JoinType::LeftAnti,
+            Some(in_expr.clone().eq(subquery_col)),
+        )?
+        .build()?;
+
+    let predicate = has_null
+        .clone()
+        .is_null()
+        .or(in_expr.is_not_null().and(not(has_null)));
+    let new_plan = LogicalPlanBuilder::from(stats)
+        .cross_join(anti_join)?
+        .filter(predicate)?
+        .project(left.schema().columns().into_iter().map(Expr::Column))?
+        .build()?;
+    debug!(
+        "null-aware NOT IN subquery optimized:\n{}",
+        new_plan.display_indent()
+    );
+    Ok(new_plan)
+}
+
+/// Returns true if any expression of `plan` is volatile
+fn is_volatile_plan(plan: &LogicalPlan) -> Result<bool> {
+    plan.exists(|node| {
+        let mut is_volatile = false;
+        node.apply_expressions(|expr| {
+            is_volatile = expr.is_volatile()?;
+            Ok(if is_volatile {
+                TreeNodeRecursion::Stop
+            } else {
+                TreeNodeRecursion::Continue
+            })
+        })?;
+        Ok(is_volatile)
+    })
 }
 
 struct SubqueryInfo {
@@ -369,8 +473,14 @@ mod tests {
     use super::*;
     use crate::test::*;
 
-    use arrow::datatypes::DataType;
-    use datafusion_expr::{and, binary_expr, col, lit, not, or, out_ref_col};
+    use std::any::Any;
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_expr::expr::ScalarFunction;
+    use datafusion_expr::{
+        and, binary_expr, col, lit, not, or, out_ref_col, ColumnarValue, ScalarUDF,
+        ScalarUDFImpl, Signature, Volatility,
+    };
 
     fn assert_optimized_plan_equal(plan: LogicalPlan, expected: &str) -> Result<()> {
         assert_optimized_plan_eq_display_indent(
@@ -1167,6 +1277,123 @@ mod tests {
         Ok(())
     }
 
+    /// Scans a table with the nullable columns `a` and `b`
+    fn nullable_table_scan(name: &str) -> Result<LogicalPlan> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::UInt32, true),
+            Field::new("b", DataType::UInt32, true),
+        ]);
+        scan_empty(Some(name), &schema, None)?.build()
+    }
+
+    /// Test for NOT IN subquery filter over nullable columns
+    #[test]
+    fn not_in_subquery_nullable() -> Result<()> {
+        let subquery = LogicalPlanBuilder::from(nullable_table_scan("t2")?)
+            .project(vec![col("t2.a")])?
+            .build()?;
+        let plan = LogicalPlanBuilder::from(nullable_table_scan("t1")?)
+            .filter(not_in_subquery(col("t1.a"), Arc::new(subquery)))?
+            .project(vec![col("t1.b")])?
+            .build()?;
+
+        let expected = "Projection: t1.b [b:UInt32;N]\
+        \n  Projection: t1.a, t1.b [a:UInt32;N, b:UInt32;N]\
+        \n    Filter: __not_in_sq_2.has_null IS NULL OR t1.a IS NOT NULL AND NOT __not_in_sq_2.has_null [has_null:Boolean;N, a:UInt32;N, b:UInt32;N]\
+        \n      CrossJoin: [has_null:Boolean;N, a:UInt32;N, b:UInt32;N]\
+        \n        SubqueryAlias: __not_in_sq_2 [has_null:Boolean;N]\
+        \n          Aggregate: groupBy=[[]], aggr=[[MAX(__correlated_sq_1.a IS NULL) AS has_null]] [has_null:Boolean;N]\
+        \n            SubqueryAlias: __correlated_sq_1 [a:UInt32;N]\
+        \n              Projection: t2.a [a:UInt32;N]\
+        \n                TableScan: t2 [a:UInt32;N, b:UInt32;N]\
+        \n        LeftAnti Join:  Filter: t1.a = __correlated_sq_1.a [a:UInt32;N, b:UInt32;N]\
+        \n          TableScan: t1 [a:UInt32;N, b:UInt32;N]\
+        \n          SubqueryAlias: __correlated_sq_1 [a:UInt32;N]\
+        \n            Projection: t2.a [a:UInt32;N]\
+        \n              TableScan: t2 [a:UInt32;N, b:UInt32;N]";
+
+        assert_optimized_plan_equal(plan, expected)
+    }
+
+    /// A volatile function without arguments
+    #[derive(Debug)]
+    struct VolatileUDF {
+        signature: Signature,
+    }
+
+    impl ScalarUDFImpl for VolatileUDF {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn name(&self) -> &str {
+            "volatile"
+        }
+
+        fn signature(&self) -> &Signature {
+            &self.signature
+        }
+
+        fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+            Ok(DataType::UInt32)
+        }
+
+        fn invoke(&self, _args: &[ColumnarValue]) -> Result<ColumnarValue> {
+            unimplemented!()
+        }
+    }
+
+    /// Test for NOT IN volatile subquery filter over nullable columns
+    #[test]
+    fn not_in_volatile_subquery_nullable() -> Result<()> {
+        let volatile = ScalarUDF::new_from_impl(VolatileUDF {
+            signature: Signature::exact(vec![], Volatility::Volatile),
+        });
+        let volatile =
+            Expr::ScalarFunction(ScalarFunction::new_udf(Arc::new(volatile), vec![]));
+        let subquery = LogicalPlanBuilder::from(nullable_table_scan("t2")?)
+            .filter(volatile.gt(lit(0u32)))?
+            .project(vec![col("t2.a")])?
+            .build()?;
+        let plan = LogicalPlanBuilder::from(nullable_table_scan("t1")?)
+            .filter(not_in_subquery(col("t1.a"), Arc::new(subquery)))?
+            .project(vec![col("t1.b")])?
+            .build()?;
+
+        // the subquery is only evaluated once
+        let expected = "Projection: t1.b [b:UInt32;N]\
+        \n  LeftAnti Join:  Filter: t1.a = __correlated_sq_1.a OR t1.a IS NULL OR __correlated_sq_1.a IS NULL [a:UInt32;N, b:UInt32;N]\
+        \n    TableScan: t1 [a:UInt32;N, b:UInt32;N]\
+        \n    SubqueryAlias: __correlated_sq_1 [a:UInt32;N]\
+        \n      Projection: t2.a [a:UInt32;N]\
+        \n        Filter: volatile() > UInt32(0) [a:UInt32;N, b:UInt32;N]\
+        \n          TableScan: t2 [a:UInt32;N, b:UInt32;N]";
+
+        assert_optimized_plan_equal(plan, expected)
+    }
+
+    /// Test for correlated NOT IN subquery filter over nullable columns
+    #[test]
+    fn not_in_subquery_nullable_correlated() -> Result<()> {
+        let subquery = LogicalPlanBuilder::from(nullable_table_scan("t2")?)
+            .filter(out_ref_col(DataType::UInt32, "t1.b").eq(col("t2.b")))?
+            .project(vec![col("t2.a")])?
+            .build()?;
+        let plan = LogicalPlanBuilder::from(nullable_table_scan("t1")?)
+            .filter(not_in_subquery(col("t1.a"), Arc::new(subquery)))?
+            .project(vec![col("t1.b")])?
+            .build()?;
+
+        let expected = "Projection: t1.b [b:UInt32;N]\
+        \n  LeftAnti Join:  Filter: (t1.a = __correlated_sq_1.a OR t1.a IS NULL OR __correlated_sq_1.a IS NULL) AND t1.b = __correlated_sq_1.b [a:UInt32;N, b:UInt32;N]\
+        \n    TableScan: t1 [a:UInt32;N, b:UInt32;N]\
+        \n    SubqueryAlias: __correlated_sq_1 [a:UInt32;N, b:UInt32;N]\
+        \n      Projection: t2.a, t2.b [a:UInt32;N, b:UInt32;N]\
+        \n        TableScan: t2 [a:UInt32;N, b:UInt32;N]";
+
+        assert_optimized_plan_equal(plan, expected)
+    }
+
     #[test]
     fn in_subquery_both_side_expr() -> Result<()> {
         let table_scan = test_table_scan()?;
@@ -764,11 +764,17 @@ impl OptimizerRule for PushDownFilter {
             }
             LogicalPlan::Aggregate(agg) => {
                 // We can push down Predicate which in groupby_expr.
-                let group_expr_columns = agg
-                    .group_expr
-                    .iter()
-                    .map(|e| Ok(Column::from_qualified_name(e.display_name()?)))
-                    .collect::<Result<HashSet<_>>>()?;
+                //
+                // Volatile group expressions are evaluated once per input row,
+                // so a predicate on their output can not be pushed below the
+                // aggregate, where they would be evaluated again.
+                let mut group_expr_columns = HashSet::new();
+                for expr in &agg.group_expr {
+                    if !expr.is_volatile()? {
+                        group_expr_columns
+                            .insert(Column::from_qualified_name(expr.display_name()?));
+                    }
+                }
 
                 let predicates = split_conjunction_owned(filter.predicate.clone());
 
@@ -816,6 +822,68 @@ impl OptimizerRule for PushDownFilter {
                         }
                     })
             }
+            LogicalPlan::Window(window) => {
+                // We can push down predicates that only refer to columns every
+                // window expression is partitioned by: such a predicate removes
+                // whole partitions, so it does not change the results of the
+                // window functions in the remaining ones.
+                let mut partition_columns: Option<HashSet<Column>> = None;
+                for expr in &window.window_expr {
+                    let columns = match expr.clone().unalias() {
+                        Expr::WindowFunction(window_function) => window_function
+                            .partition_by
+                            .iter()
+                            .filter_map(|expr| match expr {
+                                Expr::Column(column) => Some(column.clone()),
+                                _ => None,
+                            })
+                            .collect::<HashSet<_>>(),
+                        _ => HashSet::new(),
+                    };
+                    partition_columns = Some(match partition_columns {
+                        Some(common) => common.intersection(&columns).cloned().collect(),
+                        None => columns,
+                    });
+                }
+                let partition_columns = partition_columns.unwrap_or_default();
+
+                let predicates = split_conjunction_owned(filter.predicate.clone());
+
+                let mut keep_predicates = vec![];
+                let mut push_predicates = vec![];
+                for expr in predicates {
+                    let cols = expr.column_refs();
+                    if !cols.is_empty()
+                        && cols.iter().all(|c| partition_columns.contains(c))
+                        && !expr.is_volatile()?
+                    {
+                        push_predicates.push(expr);
+                    } else {
+                        keep_predicates.push(expr);
+                    }
+                }
+
+                let window_input = Arc::clone(&window.input);
+                Transformed::yes(LogicalPlan::Window(window))
+                    .transform_data(|new_plan| {
+                        // If we have a filter to push, we push it down to the input of the window
+                        if let Some(predicate) = conjunction(push_predicates) {
+                            let new_filter = make_filter(predicate, window_input)?;
+                            insert_below(new_plan, new_filter)
+                        } else {
+                            Ok(Transformed::no(new_plan))
+                        }
+                    })?
+                    .map_data(|child_plan| {
+                        // if there are any remaining predicates we can't push, add them
+                        // back as a filter
+                        if let Some(predicate) = conjunction(keep_predicates) {
+                            make_filter(predicate, Arc::new(child_plan))
+                        } else {
+                            Ok(child_plan)
+                        }
+                    })
+            }
             LogicalPlan::Join(join) => push_down_join(join, Some(&filter.predicate)),
             LogicalPlan::CrossJoin(cross_join) => {
                 let predicates = split_conjunction_owned(filter.predicate);
@@ -1151,12 +1219,12 @@ mod tests {
     use async_trait::async_trait;
 
     use datafusion_common::ScalarValue;
-    use datafusion_expr::expr::ScalarFunction;
+    use datafusion_expr::expr::{ScalarFunction, WindowFunction};
     use datafusion_expr::logical_plan::table_scan;
     use datafusion_expr::{
-        col, in_list, in_subquery, lit, ColumnarValue, Extension, ScalarUDF,
-        ScalarUDFImpl, Signature, TableSource, TableType, UserDefinedLogicalNodeCore,
-        Volatility,
+        col, in_list, in_subquery, lit, AggregateFunction, ColumnarValue, Extension,
+        ScalarUDF, ScalarUDFImpl, Signature, TableSource, TableType,
+        UserDefinedLogicalNodeCore, Volatility, WindowFrame, WindowFunctionDefinition,
     };
 
     use crate::optimizer::Optimizer;
@@ -1304,6 +1372,30 @@ mod tests {
         assert_optimized_plan_eq(plan, expected)
     }
 
+    #[test]
+    fn push_window_partition_by() -> Result<()> {
+        let table_scan = test_table_scan()?;
+        let max = Expr::WindowFunction(WindowFunction::new(
+            WindowFunctionDefinition::AggregateFunction(AggregateFunction::Max),
+            vec![col("c")],
+            vec![col("a")],
+            vec![col("b").sort(true, true)],
+            WindowFrame::new(None),
+            None,
+        ));
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .window(vec![max])?
+            .filter(col("a").gt(lit(10i64)).and(col("b").gt(lit(10i64))))?
+            .build()?;
+        // only the filter on the partition column can be applied before the
+        // window, since it removes whole partitions
+        let expected = "\
+            Filter: test.b > Int64(10)\
+            \n  WindowAggr: windowExpr=[[MAX(test.c) PARTITION BY [test.a] ORDER BY [test.b ASC NULLS FIRST] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]\
+            \n    TableScan: test, full_filters=[test.a > Int64(10)]";
+        assert_optimized_plan_eq(plan, expected)
+    }
+
     /// verifies that a filter is pushed to before a projection, the filter expression is correctly re-written
     #[test]
     fn alias() -> Result<()> {
@@ -3033,6 +3125,30 @@ Projection: a, b
         assert_optimized_plan_eq(plan, expected_after)
     }
 
+    #[test]
+    fn test_push_down_volatile_group_by() -> Result<()> {
+        // SELECT TestScalarUDF() + 1 FROM test1 GROUP BY TestScalarUDF() + 1, a HAVING TestScalarUDF() + 1 > 0.5 AND a > 5;
+        let table_scan = test_table_scan_with_name("test1")?;
+        let fun = ScalarUDF::new_from_impl(TestScalarUDF {
+            signature: Signature::exact(vec![], Volatility::Volatile),
+        });
+        let expr = Expr::ScalarFunction(ScalarFunction::new_udf(Arc::new(fun), vec![]));
+
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .aggregate(vec![add(expr, lit(1)), col("a")], vec![sum(col("b"))])?
+            .filter(
+                col("TestScalarUDF() + Int32(1)")
+                    .gt(lit(0.5))
+                    .and(col("a").gt(lit(5))),
+            )?
+            .build()?;
+
+        // the volatile group expression is evaluated once per row by the
+        // aggregate, and the filter on it must not evaluate it again
+        let expected = "Filter: TestScalarUDF() + Int32(1) > Float64(0.5)        \n  Aggregate: groupBy=[[TestScalarUDF() + Int32(1), test1.a]], aggr=[[sum(test1.b)]]        \n    TableScan: test1, full_filters=[test1.a > Int32(5)]";
+        assert_optimized_plan_eq(plan, expected)
+    }
+
     #[test]
     fn test_push_down_volatile_function_in_join() -> Result<()> {
         // SELECT t.a, t.r FROM (SELECT test1.a AS a, TestScalarUDF() AS r FROM test1 join test2 ON test1.a = test2.a) AS t WHERE t.r > 0.5;
@@ -73,10 +73,17 @@ pub fn expr_contains(expr: &Expr, needle: &Expr, search_op: Operator) -> bool {
             expr_contains(left, needle, search_op.clone())
                 || expr_contains(right, needle, search_op)
         }
-        _ => expr == needle,
+        _ => is_same_value(expr, needle),
     }
 }
 
+/// returns true if `left` and `right` are the same expression that evaluates
+/// to the same value twice. Two calls of a volatile function such as
+/// `random()` do not.
+fn is_same_value(left: &Expr, right: &Expr) -> bool {
+    left == right && !left.is_volatile().unwrap_or(true)
+}
+
 /// Deletes all 'needles' or remains one 'needle' that are found in a chain of xor
 /// expressions. Such as: A ^ (A ^ (B ^ A))
 pub fn delete_xor_in_complex_expr(expr: &Expr, needle: &Expr, is_left: bool) -> Expr {
@@ -206,17 +213,17 @@ pub fn is_false(expr: &Expr) -> bool {
 
 /// returns true if `haystack` looks like (needle OP X) or (X OP needle)
 pub fn is_op_with(target_op: Operator, haystack: &Expr, needle: &Expr) -> bool {
-    matches!(haystack, Expr::BinaryExpr(BinaryExpr { left, op, right }) if op == &target_op && (needle == left.as_ref() || needle == right.as_ref()))
+    matches!(haystack, Expr::BinaryExpr(BinaryExpr { left, op, right }) if op == &target_op && (is_same_value(needle, left) || is_same_value(needle, right)))
 }
 
 /// returns true if `not_expr` is !`expr` (not)
 pub fn is_not_of(not_expr: &Expr, expr: &Expr) -> bool {
-    matches!(not_expr, Expr::Not(inner) if expr == inner.as_ref())
+    matches!(not_expr, Expr::Not(inner) if is_same_value(expr, inner))
 }
 
 /// returns true if `not_expr` is !`expr` (bitwise not)
 pub fn is_negative_of(not_expr: &Expr, expr: &Expr) -> bool {
-    matches!(not_expr, Expr::Negative(inner) if expr == inner.as_ref())
+    matches!(not_expr, Expr::Negative(inner) if is_same_value(expr, inner))
 }
 
 /// returns the contained boolean value in `expr` as
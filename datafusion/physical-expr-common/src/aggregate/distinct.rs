@@ -0,0 +1,256 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`DistinctAccumulator`] to compute any aggregate over distinct values
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use ahash::RandomState;
+use arrow::array::{Array, ArrayRef, BooleanArray};
+use arrow::compute::{cast, filter};
+use arrow::datatypes::{DataType, Field};
+use arrow::row::{OwnedRow, RowConverter, SortField};
+use datafusion_common::cast::as_list_array;
+use datafusion_common::utils::array_into_list_array_nullable;
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::Accumulator;
+use hashbrown::HashSet;
+
+/// Computes `AGG(DISTINCT x)` with the [`Accumulator`] of `AGG`, for any type
+/// of `x` supported by the [row format].
+///
+/// The distinct values are kept in the row format, and only the values that
+/// were not seen before are passed to the wrapped accumulator. NULL values are
+/// ignored. Dictionary values are unpacked, so the wrapped accumulator must
+/// accept the value type of the dictionary.
+///
+/// The state of the accumulator is the list of the distinct values, see
+/// [`Self::state_field`].
+///
+/// [row format]: arrow::row
+pub struct DistinctAccumulator {
+    /// The type of the values, after unpacking dictionaries
+    data_type: DataType,
+    converter: RowConverter,
+    seen: HashSet<OwnedRow, RandomState>,
+    accumulator: Box<dyn Accumulator>,
+}
+
+impl Debug for DistinctAccumulator {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DistinctAccumulator")
+            .field("data_type", &self.data_type)
+            .field("distinct_values", &self.seen.len())
+            .field("accumulator", &self.accumulator)
+            .finish()
+    }
+}
+
+impl DistinctAccumulator {
+    /// Creates an accumulator of the distinct values of type `data_type`,
+    /// which are passed to `accumulator`
+    pub fn try_new(
+        data_type: &DataType,
+        accumulator: Box<dyn Accumulator>,
+    ) -> Result<Self> {
+        let data_type = value_type(data_type).clone();
+        let converter = RowConverter::new(vec![SortField::new(data_type.clone())])?;
+        Ok(Self {
+            data_type,
+            converter,
+            seen: HashSet::default(),
+            accumulator,
+        })
+    }
+
+    /// Returns the field of the state of the accumulator for the aggregate
+    /// expression `name` of the values of type `data_type`
+    pub fn state_field(name: &str, data_type: &DataType) -> Field {
+        Field::new_list(
+            format_state_name(name, "distinct"),
+            Field::new("item", value_type(data_type).clone(), true),
+            true,
+        )
+    }
+}
+
+impl Accumulator for DistinctAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        let values = if values[0].data_type() == &self.data_type {
+            Arc::clone(&values[0])
+        } else {
+            cast(&values[0], &self.data_type)?
+        };
+
+        let nulls = values.logical_nulls();
+        let rows = self.converter.convert_columns(&[Arc::clone(&values)])?;
+        let is_new = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let is_valid = nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i));
+                Some(is_valid && self.seen.insert(row.owned()))
+            })
+            .collect::<BooleanArray>();
+        if is_new.true_count() == 0 {
+            return Ok(());
+        }
+        let new_values = filter(&values, &is_new)?;
+        self.accumulator.update_batch(&[new_values])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        if states.is_empty() {
+            return Ok(());
+        }
+        for values in as_list_array(&states[0])?.iter().flatten() {
+            self.update_batch(&[values])?;
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let values = self
+            .converter
+            .convert_rows(self.seen.iter().map(|row| row.row()))?;
+        let list = array_into_list_array_nullable(Arc::clone(&values[0]));
+        Ok(vec![ScalarValue::List(Arc::new(list))])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        self.accumulator.evaluate()
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self.converter.size()
+            + self.seen.capacity() * std::mem::size_of::<OwnedRow>()
+            + self
+                .seen
+                .iter()
+                .map(|row| row.as_ref().len())
+                .sum::<usize>()
+            + self.accumulator.size()
+    }
+}
+
+/// Returns the type of the values of `data_type`, unpacking dictionaries
+fn value_type(data_type: &DataType) -> &DataType {
+    match data_type {
+        DataType::Dictionary(_, values) => value_type(values),
+        _ => data_type,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{DictionaryArray, Int32Array, Int64Array, StringArray};
+    use arrow::datatypes::Int32Type;
+
+    /// Collects the values it receives, to check which values are passed on
+    #[derive(Debug, Default)]
+    struct CollectAccumulator {
+        values: Vec<ScalarValue>,
+    }
+
+    impl Accumulator for CollectAccumulator {
+        fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+            for i in 0..values[0].len() {
+                self.values
+                    .push(ScalarValue::try_from_array(&values[0], i)?);
+            }
+            Ok(())
+        }
+
+        fn merge_batch(&mut self, _states: &[ArrayRef]) -> Result<()> {
+            unreachable!()
+        }
+
+        fn state(&mut self) -> Result<Vec<ScalarValue>> {
+            unreachable!()
+        }
+
+        fn evaluate(&mut self) -> Result<ScalarValue> {
+            Ok(ScalarValue::from(self.values.len() as i64))
+        }
+
+        fn size(&self) -> usize {
+            std::mem::size_of_val(self)
+        }
+    }
+
+    fn distinct_accumulator(data_type: &DataType) -> Result<DistinctAccumulator> {
+        DistinctAccumulator::try_new(data_type, Box::<CollectAccumulator>::default())
+    }
+
+    #[test]
+    fn distinct_values() -> Result<()> {
+        let mut acc = distinct_accumulator(&DataType::Int64)?;
+        let values: ArrayRef =
+            Arc::new(Int64Array::from(vec![Some(1), Some(2), None, Some(1)]));
+        acc.update_batch(&[values])?;
+        let values: ArrayRef = Arc::new(Int64Array::from(vec![Some(2), Some(3)]));
+        acc.update_batch(&[values])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Int64(Some(3)));
+        Ok(())
+    }
+
+    #[test]
+    fn merge_distinct_values() -> Result<()> {
+        let mut acc1 = distinct_accumulator(&DataType::Utf8)?;
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a"]));
+        acc1.update_batch(&[values])?;
+
+        let mut acc2 = distinct_accumulator(&DataType::Utf8)?;
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["b", "c"]));
+        acc2.update_batch(&[values])?;
+
+        let state = acc2
+            .state()?
+            .iter()
+            .map(|value| value.to_array())
+            .collect::<Result<Vec<_>>>()?;
+        acc1.merge_batch(&state)?;
+        assert_eq!(acc1.evaluate()?, ScalarValue::Int64(Some(3)));
+        Ok(())
+    }
+
+    #[test]
+    fn distinct_dictionary_values() -> Result<()> {
+        let data_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Int32));
+        let mut acc = distinct_accumulator(&data_type)?;
+        let values: ArrayRef = Arc::new(DictionaryArray::<Int32Type>::new(
+            Int32Array::from(vec![0, 1, 2, 0]),
+            Arc::new(Int32Array::from(vec![5, 6, 5])),
+        ));
+        acc.update_batch(&[values])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Int64(Some(2)));
+        assert_eq!(
+            DistinctAccumulator::state_field("avg", &data_type).data_type(),
+            &DataType::new_list(DataType::Int32, true)
+        );
+        Ok(())
+    }
+}
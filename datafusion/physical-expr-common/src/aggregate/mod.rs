@@ -16,12 +16,14 @@
 // under the License.
 
 pub mod count_distinct;
+pub mod distinct;
 pub mod groups_accumulator;
 pub mod stats;
 pub mod tdigest;
 pub mod utils;
 
 use arrow::datatypes::{DataType, Field, Schema};
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{not_impl_err, Result};
 use datafusion_expr::function::StateFieldsArgs;
 use datafusion_expr::type_coercion::aggregates::check_arg_count;
@@ -54,6 +56,33 @@ pub fn create_aggregate_expr(
     ignore_nulls: bool,
     is_distinct: bool,
 ) -> Result<Arc<dyn AggregateExpr>> {
+    Ok(Arc::new(create_aggregate_function_expr(
+        fun,
+        input_phy_exprs,
+        input_exprs,
+        sort_exprs,
+        ordering_req,
+        schema,
+        name,
+        ignore_nulls,
+        is_distinct,
+    )?))
+}
+
+/// Same as [`create_aggregate_expr`], returning the [`AggregateFunctionExpr`]
+/// itself so that its options can be changed
+#[allow(clippy::too_many_arguments)]
+pub fn create_aggregate_function_expr(
+    fun: &AggregateUDF,
+    input_phy_exprs: &[Arc<dyn PhysicalExpr>],
+    input_exprs: &[Expr],
+    sort_exprs: &[Expr],
+    ordering_req: &[PhysicalSortExpr],
+    schema: &Schema,
+    name: impl Into<String>,
+    ignore_nulls: bool,
+    is_distinct: bool,
+) -> Result<AggregateFunctionExpr> {
     debug_assert_eq!(sort_exprs.len(), ordering_req.len());
 
     let input_exprs_types = input_phy_exprs
@@ -74,7 +103,7 @@ pub fn create_aggregate_expr(
 
     let ordering_fields = ordering_fields(ordering_req, &ordering_types);
 
-    Ok(Arc::new(AggregateFunctionExpr {
+    Ok(AggregateFunctionExpr {
         fun: fun.clone(),
         args: input_phy_exprs.to_vec(),
         logical_args: input_exprs.to_vec(),
@@ -87,7 +116,8 @@ pub fn create_aggregate_expr(
         ordering_fields,
         is_distinct,
         input_type: input_exprs_types[0].clone(),
-    }))
+        arithmetic_overflow_mode: ArithmeticOverflowMode::Wrap,
+    })
 }
 
 /// An aggregate expression that:
@@ -251,9 +281,23 @@ pub struct AggregateFunctionExpr {
     ordering_fields: Vec<Field>,
     is_distinct: bool,
     input_type: DataType,
+    // what integer arithmetic does on overflow
+    arithmetic_overflow_mode: ArithmeticOverflowMode,
 }
 
 impl AggregateFunctionExpr {
+    /// Sets what the integer arithmetic of the accumulators does on overflow,
+    /// see `datafusion.execution.arithmetic_overflow_mode`
+    pub fn with_arithmetic_overflow_mode(mut self, mode: ArithmeticOverflowMode) -> Self {
+        self.arithmetic_overflow_mode = mode;
+        self
+    }
+
+    /// Returns what the integer arithmetic of the accumulators does on overflow
+    pub fn arithmetic_overflow_mode(&self) -> ArithmeticOverflowMode {
+        self.arithmetic_overflow_mode
+    }
+
     /// Return the `AggregateUDF` used by this `AggregateFunctionExpr`
     pub fn fun(&self) -> &AggregateUDF {
         &self.fun
@@ -301,12 +345,18 @@ impl AggregateExpr for AggregateFunctionExpr {
             input_type: &self.input_type,
             input_exprs: &self.logical_args,
             name: &self.name,
+            arithmetic_overflow_mode: self.arithmetic_overflow_mode,
         };
 
         self.fun.accumulator(acc_args)
     }
 
     fn create_sliding_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        // the distinct values can not be retracted, so the aggregate of every
+        // window is computed from scratch
+        if self.is_distinct {
+            return self.create_accumulator();
+        }
         let args = AccumulatorArgs {
             data_type: &self.data_type,
             schema: &self.schema,
@@ -316,6 +366,7 @@ impl AggregateExpr for AggregateFunctionExpr {
             input_type: &self.input_type,
             input_exprs: &self.logical_args,
             name: &self.name,
+            arithmetic_overflow_mode: self.arithmetic_overflow_mode,
         };
 
         let accumulator = self.fun.create_sliding_accumulator(args)?;
@@ -362,7 +413,12 @@ impl AggregateExpr for AggregateFunctionExpr {
         // This approach is a a bit different than window function
         // approach. In window function (when they use a window frame)
         // they get all the desired range during evaluation.
-        if !accumulator.supports_retract_batch() {
+        //
+        // Aggregates that opt in with `recompute_sliding_windows` are
+        // instead computed again over every window by
+        // `SlidingAggregateWindowExpr`.
+        if !accumulator.supports_retract_batch() && !self.fun.recompute_sliding_windows()
+        {
             return not_impl_err!(
                 "Aggregate can not be used as a sliding accumulator because \
                      `retract_batch` is not implemented: {}",
@@ -386,6 +442,7 @@ impl AggregateExpr for AggregateFunctionExpr {
             input_type: &self.input_type,
             input_exprs: &self.logical_args,
             name: &self.name,
+            arithmetic_overflow_mode: self.arithmetic_overflow_mode,
         };
         self.fun.groups_accumulator_supported(args)
     }
@@ -400,6 +457,7 @@ impl AggregateExpr for AggregateFunctionExpr {
             input_type: &self.input_type,
             input_exprs: &self.logical_args,
             name: &self.name,
+            arithmetic_overflow_mode: self.arithmetic_overflow_mode,
         };
         self.fun.create_groups_accumulator(args)
     }
@@ -437,7 +495,7 @@ impl AggregateExpr for AggregateFunctionExpr {
         else {
             return Ok(None);
         };
-        create_aggregate_expr(
+        let updated = create_aggregate_function_expr(
             &updated_fn,
             &self.args,
             &self.logical_args,
@@ -447,8 +505,9 @@ impl AggregateExpr for AggregateFunctionExpr {
             self.name(),
             self.ignore_nulls,
             self.is_distinct,
-        )
-        .map(Some)
+        )?
+        .with_arithmetic_overflow_mode(self.arithmetic_overflow_mode);
+        Ok(Some(Arc::new(updated)))
     }
 
     fn reverse_expr(&self) -> Option<Arc<dyn AggregateExpr>> {
@@ -472,7 +531,7 @@ impl AggregateExpr for AggregateFunctionExpr {
                 let mut name = self.name().to_string();
                 replace_order_by_clause(&mut name);
                 replace_fn_name_clause(&mut name, self.fun.name(), reverse_udf.name());
-                let reverse_aggr = create_aggregate_expr(
+                let reverse_aggr = create_aggregate_function_expr(
                     &reverse_udf,
                     &self.args,
                     &self.logical_args,
@@ -483,9 +542,10 @@ impl AggregateExpr for AggregateFunctionExpr {
                     self.ignore_nulls,
                     self.is_distinct,
                 )
-                .unwrap();
+                .unwrap()
+                .with_arithmetic_overflow_mode(self.arithmetic_overflow_mode);
 
-                Some(reverse_aggr)
+                Some(Arc::new(reverse_aggr))
             }
         }
     }
@@ -20,6 +20,7 @@ mod kernels;
 use std::hash::{Hash, Hasher};
 use std::{any::Any, sync::Arc};
 
+use crate::expressions::CastExpr;
 use crate::intervals::cp_solver::{propagate_arithmetic, propagate_comparison};
 use crate::physical_expr::down_cast_any_ref;
 use crate::PhysicalExpr;
@@ -31,9 +32,11 @@ use arrow::compute::kernels::comparison::{
     regexp_is_match_utf8, regexp_is_match_utf8_scalar,
 };
 use arrow::compute::kernels::concat_elements::concat_elements_utf8;
-use arrow::compute::{cast, ilike, like, nilike, nlike};
+use arrow::compute::{cast, ilike, like, nilike, nlike, prep_null_mask_filter};
 use arrow::datatypes::*;
+use arrow::error::ArrowError;
 use datafusion_common::cast::as_boolean_array;
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{internal_err, Result, ScalarValue};
 use datafusion_expr::interval_arithmetic::{apply_operator, Interval};
 use datafusion_expr::sort_properties::ExprProperties;
@@ -45,6 +48,7 @@ use kernels::{
     bitwise_and_dyn, bitwise_and_dyn_scalar, bitwise_or_dyn, bitwise_or_dyn_scalar,
     bitwise_shift_left_dyn, bitwise_shift_left_dyn_scalar, bitwise_shift_right_dyn,
     bitwise_shift_right_dyn_scalar, bitwise_xor_dyn, bitwise_xor_dyn_scalar,
+    interval_scale, saturating_arithmetic,
 };
 
 /// Binary expression
@@ -53,6 +57,8 @@ pub struct BinaryExpr {
     left: Arc<dyn PhysicalExpr>,
     op: Operator,
     right: Arc<dyn PhysicalExpr>,
+    /// What `+`, `-` and `*` do when an integer result overflows
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl BinaryExpr {
@@ -62,7 +68,23 @@ impl BinaryExpr {
         op: Operator,
         right: Arc<dyn PhysicalExpr>,
     ) -> Self {
-        Self { left, op, right }
+        Self {
+            left,
+            op,
+            right,
+            overflow_mode: ArithmeticOverflowMode::Wrap,
+        }
+    }
+
+    /// Create new binary expression whose `+`, `-` and `*` handle integer
+    /// results that do not fit in their type according to `overflow_mode`:
+    /// they wrap around, return an [`ArrowError::ArithmeticOverflow`] error or
+    /// saturate
+    pub fn with_overflow_mode(self, overflow_mode: ArithmeticOverflowMode) -> Self {
+        Self {
+            overflow_mode,
+            ..self
+        }
     }
 
     /// Get the left side of the binary expression
@@ -79,6 +101,184 @@ impl BinaryExpr {
     pub fn op(&self) -> &Operator {
         &self.op
     }
+
+    /// Returns what the arithmetic of this expression does on overflow
+    pub fn overflow_mode(&self) -> ArithmeticOverflowMode {
+        self.overflow_mode
+    }
+
+    /// Applies the checked arithmetic kernel `f`, reporting an overflow with
+    /// the operator and the types of its operands
+    fn apply_checked(
+        &self,
+        lhs: &ColumnarValue,
+        rhs: &ColumnarValue,
+        f: fn(&dyn Datum, &dyn Datum) -> Result<ArrayRef, ArrowError>,
+    ) -> Result<ColumnarValue> {
+        apply(lhs, rhs, |l, r| {
+            f(l, r).map_err(|e| match e {
+                ArrowError::ArithmeticOverflow(msg) => {
+                    ArrowError::ArithmeticOverflow(format!(
+                        "{} {} {}: {msg}",
+                        l.get().0.data_type(),
+                        self.op,
+                        r.get().0.data_type()
+                    ))
+                }
+                e => e,
+            })
+        })
+    }
+
+    /// Returns true if the value `lhs` of the left side of `AND` or `OR`
+    /// determines the result of every row, i.e. it is false for all the rows
+    /// of `AND` or true for all the rows of `OR`
+    fn left_determines_result(&self, lhs: &ColumnarValue) -> bool {
+        let ColumnarValue::Array(lhs) = lhs else {
+            return false;
+        };
+        let Some(lhs) = lhs.as_boolean_opt() else {
+            return false;
+        };
+        if lhs.null_count() > 0 {
+            return false;
+        }
+        match self.op {
+            Operator::And => lhs.true_count() == 0,
+            Operator::Or => lhs.true_count() == lhs.len(),
+            _ => false,
+        }
+    }
+
+    /// Returns the rows on which the right side of `AND` or `OR` has to be
+    /// evaluated, given the value `lhs` of the left side, or `None` if it has
+    /// to be evaluated on all of them.
+    ///
+    /// The result of the rows where the left side of `AND` is false, or the
+    /// left side of `OR` is true, does not depend on the right side. Skipping
+    /// them keeps a guard on the left side from being bypassed, as in
+    /// `x <> 0 AND 10 / x > 1`, which `CASE` expressions returning booleans
+    /// are simplified to.
+    ///
+    /// Evaluating a selection filters the whole batch, so this is only done
+    /// if [`may_fail`] holds for the right side.
+    fn short_circuit_selection(
+        &self,
+        lhs: &ColumnarValue,
+        input_schema: &Schema,
+    ) -> Result<Option<BooleanArray>> {
+        if !matches!(self.op, Operator::And | Operator::Or)
+            || !may_fail(&self.right, input_schema)
+        {
+            return Ok(None);
+        }
+        let ColumnarValue::Array(lhs) = lhs else {
+            return Ok(None);
+        };
+        let Some(lhs) = lhs.as_boolean_opt() else {
+            return Ok(None);
+        };
+        let determined = match self.op {
+            Operator::And => prep_null_mask_filter(&not(lhs)?),
+            Operator::Or => prep_null_mask_filter(lhs),
+            _ => return Ok(None),
+        };
+        if determined.true_count() == 0 {
+            return Ok(None);
+        }
+        Ok(Some(not(&determined)?))
+    }
+}
+
+/// Returns true if evaluating `expr` may return an error for some rows but
+/// not for others, i.e. if it contains a division, a remainder, an integer
+/// arithmetic that fails on overflow or a cast that may fail, see
+/// [`cast_may_fail`].
+///
+/// Other expressions, such as scalar functions, are assumed not to fail.
+fn may_fail(expr: &Arc<dyn PhysicalExpr>, input_schema: &Schema) -> bool {
+    let fails = if let Some(binary) = expr.as_any().downcast_ref::<BinaryExpr>() {
+        match binary.op {
+            Operator::Divide | Operator::Modulo => true,
+            Operator::Plus | Operator::Minus | Operator::Multiply => {
+                binary.overflow_mode == ArithmeticOverflowMode::Error
+            }
+            _ => false,
+        }
+    } else if let Some(cast) = expr.as_any().downcast_ref::<CastExpr>() {
+        !cast.cast_options().safe
+            && cast
+                .expr()
+                .data_type(input_schema)
+                .map_or(true, |from| cast_may_fail(&from, cast.cast_type()))
+    } else {
+        false
+    };
+    fails
+        || expr
+            .children()
+            .into_iter()
+            .any(|child| may_fail(child, input_schema))
+}
+
+/// Returns false if casting any value of type `from` to `to` succeeds, e.g.
+/// for the casts to a wider numeric type that type coercion adds to most
+/// comparisons
+fn cast_may_fail(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+
+    /// Returns the number of bits, and whether the type is signed, of an
+    /// integer type
+    fn integer_bits(data_type: &DataType) -> Option<(u8, bool)> {
+        match data_type {
+            Int8 => Some((8, true)),
+            Int16 => Some((16, true)),
+            Int32 => Some((32, true)),
+            Int64 => Some((64, true)),
+            UInt8 => Some((8, false)),
+            UInt16 => Some((16, false)),
+            UInt32 => Some((32, false)),
+            UInt64 => Some((64, false)),
+            _ => None,
+        }
+    }
+
+    if from == to {
+        return false;
+    }
+    match (from, to) {
+        (Dictionary(_, value_type), _) => cast_may_fail(value_type, to),
+        (_, Utf8 | LargeUtf8) => !(from.is_numeric() || *from == Boolean),
+        (Float32, Float64) => false,
+        (_, Float32 | Float64) => {
+            // integers of at most 16 bits are exact in a Float32
+            !matches!(integer_bits(from), Some((bits, _)) if bits <= 16 || *to == Float64)
+        }
+        (_, Decimal128(precision, scale)) => match integer_bits(from) {
+            // the number of decimal digits of the largest value
+            Some((bits, signed)) => {
+                let digits = match (bits, signed) {
+                    (8, _) => 3,
+                    (16, _) => 5,
+                    (32, _) => 10,
+                    (64, true) => 19,
+                    _ => 20,
+                };
+                (*precision as i16 - *scale as i16) < digits
+            }
+            None => true,
+        },
+        _ => match (integer_bits(from), integer_bits(to)) {
+            (Some((from_bits, from_signed)), Some((to_bits, to_signed))) => {
+                match (from_signed, to_signed) {
+                    (true, false) => true,
+                    (false, true) => from_bits >= to_bits,
+                    _ => from_bits > to_bits,
+                }
+            }
+            _ => true,
+        },
+    }
 }
 
 impl std::fmt::Display for BinaryExpr {
@@ -258,7 +458,15 @@ impl PhysicalExpr for BinaryExpr {
         use arrow::compute::kernels::numeric::*;
 
         let lhs = self.left.evaluate(batch)?;
-        let rhs = self.right.evaluate(batch)?;
+        if self.left_determines_result(&lhs) {
+            return Ok(lhs);
+        }
+        let rhs = match self.short_circuit_selection(&lhs, &batch.schema())? {
+            // the left side determines the result of all the rows
+            Some(selection) if selection.true_count() == 0 => return Ok(lhs),
+            Some(selection) => self.right.evaluate_selection(batch, &selection)?,
+            None => self.right.evaluate(batch)?,
+        };
         let left_data_type = lhs.data_type();
         let right_data_type = rhs.data_type();
 
@@ -272,7 +480,30 @@ impl PhysicalExpr for BinaryExpr {
             return apply_cmp_for_nested(&self.op, &lhs, &rhs);
         }
 
+        if matches!(self.op, Operator::Multiply | Operator::Divide)
+            && (matches!(left_data_type, DataType::Interval(_))
+                || matches!(right_data_type, DataType::Interval(_)))
+        {
+            return apply(&lhs, &rhs, |l, r| interval_scale(l, r, &self.op));
+        }
+
+        let fail_on_overflow = self.overflow_mode == ArithmeticOverflowMode::Error;
+        let saturate = self.overflow_mode == ArithmeticOverflowMode::Saturate
+            && left_data_type.is_integer()
+            && right_data_type.is_integer();
         match self.op {
+            Operator::Plus if fail_on_overflow => {
+                return self.apply_checked(&lhs, &rhs, add)
+            }
+            Operator::Minus if fail_on_overflow => {
+                return self.apply_checked(&lhs, &rhs, sub)
+            }
+            Operator::Multiply if fail_on_overflow => {
+                return self.apply_checked(&lhs, &rhs, mul)
+            }
+            Operator::Plus | Operator::Minus | Operator::Multiply if saturate => {
+                return apply(&lhs, &rhs, |l, r| saturating_arithmetic(l, r, &self.op))
+            }
             Operator::Plus => return apply(&lhs, &rhs, add_wrapping),
             Operator::Minus => return apply(&lhs, &rhs, sub_wrapping),
             Operator::Multiply => return apply(&lhs, &rhs, mul_wrapping),
@@ -327,11 +558,10 @@ impl PhysicalExpr for BinaryExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(BinaryExpr::new(
-            children[0].clone(),
-            self.op.clone(),
-            children[1].clone(),
-        )))
+        Ok(Arc::new(
+            BinaryExpr::new(children[0].clone(), self.op.clone(), children[1].clone())
+                .with_overflow_mode(self.overflow_mode),
+        ))
     }
 
     fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
@@ -496,7 +726,12 @@ impl PartialEq<dyn Any> for BinaryExpr {
     fn eq(&self, other: &dyn Any) -> bool {
         down_cast_any_ref(other)
             .downcast_ref::<Self>()
-            .map(|x| self.left.eq(&x.left) && self.op == x.op && self.right.eq(&x.right))
+            .map(|x| {
+                self.left.eq(&x.left)
+                    && self.op == x.op
+                    && self.right.eq(&x.right)
+                    && self.overflow_mode == x.overflow_mode
+            })
             .unwrap_or(false)
     }
 }
@@ -1275,6 +1510,74 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn arithmetic_overflow_mode() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let a = Int32Array::from(vec![1, i32::MAX]);
+        let b = Int32Array::from(vec![1, 1]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])?;
+
+        let expr =
+            BinaryExpr::new(col("a", &schema)?, Operator::Plus, col("b", &schema)?);
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = Int32Array::from(vec![2, i32::MIN]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let expr = expr.with_overflow_mode(ArithmeticOverflowMode::Error);
+        let err = expr.evaluate(&batch).unwrap_err().strip_backtrace();
+        assert_eq!(
+            err,
+            "Arrow error: Arithmetic overflow: Int32 + Int32: \
+             Overflow happened on: 2147483647 + 1"
+        );
+
+        // the mode is kept when the children are replaced
+        let swapped = Arc::new(expr.clone())
+            .with_new_children(vec![col("b", &schema)?, col("a", &schema)?])?;
+        assert!(swapped.evaluate(&batch).is_err());
+
+        let expr = expr.with_overflow_mode(ArithmeticOverflowMode::Saturate);
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = Int32Array::from(vec![2, i32::MAX]);
+        assert_eq!(result.as_ref(), &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn saturating_arithmetic_with_scalars() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt8, true)]));
+        let a = UInt8Array::from(vec![Some(1), None, Some(200)]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a)])?;
+
+        let cases = [
+            (Operator::Plus, lit(100u8), vec![Some(101), None, Some(255)]),
+            (Operator::Minus, lit(100u8), vec![Some(0), None, Some(100)]),
+            (Operator::Multiply, lit(2u8), vec![Some(2), None, Some(255)]),
+            (
+                Operator::Plus,
+                lit(ScalarValue::UInt8(None)),
+                vec![None, None, None],
+            ),
+        ];
+        for (op, scalar, expected) in cases {
+            let expr = BinaryExpr::new(col("a", &schema)?, op.clone(), scalar)
+                .with_overflow_mode(ArithmeticOverflowMode::Saturate);
+            let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+            assert_eq!(result.as_ref(), &UInt8Array::from(expected), "{op}");
+        }
+
+        // scalar on the left
+        let expr = BinaryExpr::new(lit(0u8), Operator::Minus, col("a", &schema)?)
+            .with_overflow_mode(ArithmeticOverflowMode::Saturate);
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = UInt8Array::from(vec![Some(0), None, Some(0)]);
+        assert_eq!(result.as_ref(), &expected);
+        Ok(())
+    }
+
     #[test]
     fn plus_op() -> Result<()> {
         let schema = Schema::new(vec![
@@ -2517,6 +2820,110 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn and_or_short_circuit() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let a = Int32Array::from(vec![Some(0), Some(5), Some(20), None]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(a)])?;
+
+        // `10 / a > 1` fails on the rows where `a` is 0
+        let guarded = || -> Result<Arc<dyn PhysicalExpr>> {
+            let divide =
+                binary(lit(10i32), Operator::Divide, col("a", &schema)?, &schema)?;
+            binary(divide, Operator::Gt, lit(1i32), &schema)
+        };
+        let zero = || lit(0i32);
+
+        let expr = binary(
+            binary(col("a", &schema)?, Operator::NotEq, zero(), &schema)?,
+            Operator::And,
+            guarded()?,
+            &schema,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected =
+            BooleanArray::from(vec![Some(false), Some(true), Some(false), None]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let expr = binary(
+            binary(col("a", &schema)?, Operator::Eq, zero(), &schema)?,
+            Operator::Or,
+            guarded()?,
+            &schema,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(true), Some(false), None]);
+        assert_eq!(result.as_ref(), &expected);
+
+        // the right side is not evaluated at all if the left side determines
+        // the result of every row
+        let zeros = Int32Array::from(vec![0, 0]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(zeros)])?;
+        let expr = binary(
+            binary(col("a", &schema)?, Operator::NotEq, zero(), &schema)?,
+            Operator::And,
+            guarded()?,
+            &schema,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        assert_eq!(result.as_ref(), &BooleanArray::from(vec![false, false]));
+
+        // without a guard the division fails
+        let err = guarded()?.evaluate(&batch).unwrap_err().to_string();
+        assert!(err.contains("Divide by zero"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn right_side_may_fail() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let a = || col("a", &schema);
+
+        // comparisons and wrapping arithmetic never fail
+        let expr = binary(a()?, Operator::Gt, lit(1i32), &schema)?;
+        assert!(!may_fail(&expr, &schema));
+        let plus = binary(a()?, Operator::Plus, lit(1i32), &schema)?;
+        assert!(!may_fail(&plus, &schema));
+
+        // neither do widening casts
+        let cast = crate::expressions::cast(a()?, &schema, DataType::Int64)?;
+        let expr = binary(cast, Operator::Gt, lit(1i64), &schema)?;
+        assert!(!may_fail(&expr, &schema));
+        let cast = crate::expressions::cast(a()?, &schema, DataType::Float64)?;
+        assert!(!may_fail(&cast, &schema));
+        let cast = crate::expressions::cast(a()?, &schema, DataType::Utf8)?;
+        assert!(!may_fail(&cast, &schema));
+
+        // divisions and narrowing or parsing casts may fail, also when they
+        // are nested
+        let divide = binary(lit(10i32), Operator::Divide, a()?, &schema)?;
+        let expr = binary(divide, Operator::Gt, lit(1i32), &schema)?;
+        assert!(may_fail(&expr, &schema));
+        let cast =
+            crate::expressions::cast(col("b", &schema)?, &schema, DataType::Int32)?;
+        let expr = binary(cast, Operator::Gt, lit(1i32), &schema)?;
+        assert!(may_fail(&expr, &schema));
+        let cast = crate::expressions::cast(a()?, &schema, DataType::Int16)?;
+        assert!(may_fail(&cast, &schema));
+        let cast = crate::expressions::cast(a()?, &schema, DataType::UInt64)?;
+        assert!(may_fail(&cast, &schema));
+        let cast = crate::expressions::cast(a()?, &schema, DataType::Decimal128(5, 0))?;
+        assert!(may_fail(&cast, &schema));
+
+        // so does arithmetic that fails on overflow
+        let plus: Arc<dyn PhysicalExpr> = Arc::new(
+            BinaryExpr::new(a()?, Operator::Plus, lit(1i32))
+                .with_overflow_mode(ArithmeticOverflowMode::Error),
+        );
+        assert!(may_fail(&plus, &schema));
+        Ok(())
+    }
+
     /// Returns (schema, a: BooleanArray, b: BooleanArray) with all possible inputs
     ///
     /// a: [true, true, true,  NULL, NULL, NULL,  false, false, false]
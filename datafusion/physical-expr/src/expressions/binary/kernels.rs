@@ -23,9 +23,14 @@ use arrow::compute::kernels::bitwise::{
     bitwise_shift_left_scalar, bitwise_shift_right, bitwise_shift_right_scalar,
     bitwise_xor, bitwise_xor_scalar,
 };
-use arrow::datatypes::DataType;
+use arrow::datatypes::{
+    ArrowPrimitiveType, DataType, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    IntervalMonthDayNanoType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow::error::ArrowError;
 use datafusion_common::internal_err;
 use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::Operator;
 
 use std::sync::Arc;
 
@@ -131,3 +136,177 @@ create_dyn_scalar_kernel!(bitwise_or_dyn_scalar, bitwise_or_scalar);
 create_dyn_scalar_kernel!(bitwise_xor_dyn_scalar, bitwise_xor_scalar);
 create_dyn_scalar_kernel!(bitwise_shift_right_dyn_scalar, bitwise_shift_right_scalar);
 create_dyn_scalar_kernel!(bitwise_shift_left_dyn_scalar, bitwise_shift_left_scalar);
+
+/// The number of days in a month when scaling an interval
+const DAYS_PER_MONTH: f64 = 30.0;
+/// The number of nanoseconds in a day when scaling an interval
+const NANOS_PER_DAY: f64 = 86_400_000_000_000.0;
+
+/// Multiplies the `Interval(MonthDayNano)` values of one of `lhs` and `rhs`
+/// by the `Float64` values of the other one, or divides the intervals of
+/// `lhs` by the values of `rhs` if `op` is [`Operator::Divide`].
+///
+/// As in PostgreSQL, the fractional months of the result are carried into
+/// days, counting 30 days per month, and the fractional days into
+/// nanoseconds, counting 24 hours per day.
+pub(crate) fn interval_scale(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    op: &Operator,
+) -> Result<ArrayRef, ArrowError> {
+    let (left, left_is_scalar) = lhs.get();
+    let (right, right_is_scalar) = rhs.get();
+    let ((intervals, intervals_is_scalar), (factors, factors_is_scalar)) =
+        if matches!(left.data_type(), DataType::Interval(_)) {
+            ((left, left_is_scalar), (right, right_is_scalar))
+        } else {
+            ((right, right_is_scalar), (left, left_is_scalar))
+        };
+    let (Some(intervals), Some(factors)) = (
+        intervals.as_primitive_opt::<IntervalMonthDayNanoType>(),
+        factors.as_primitive_opt::<Float64Type>(),
+    ) else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Unsupported interval operation {} {op} {}",
+            left.data_type(),
+            right.data_type()
+        )));
+    };
+
+    let divide = matches!(op, Operator::Divide);
+    let len = if intervals_is_scalar && !factors_is_scalar {
+        factors.len()
+    } else {
+        intervals.len()
+    };
+    let mut builder = IntervalMonthDayNanoBuilder::with_capacity(len);
+    for i in 0..len {
+        let interval_idx = if intervals_is_scalar { 0 } else { i };
+        let factor_idx = if factors_is_scalar { 0 } else { i };
+        if intervals.is_null(interval_idx) || factors.is_null(factor_idx) {
+            builder.append_null();
+            continue;
+        }
+        let interval = intervals.value(interval_idx);
+        let factor = factors.value(factor_idx);
+        if divide && factor == 0.0 {
+            return Err(ArrowError::DivideByZero);
+        }
+        let scaled = scale_interval(interval, factor, divide).ok_or_else(|| {
+            let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(interval);
+            ArrowError::ArithmeticOverflow(format!(
+                "Overflow happened on: {months} months {days} days {nanos} nanoseconds {op} {factor}"
+            ))
+        })?;
+        builder.append_value(scaled);
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Multiplies (or divides) `interval` by `factor`, returning `None` if the
+/// result does not fit in an `Interval(MonthDayNano)`
+fn scale_interval(
+    interval: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+    factor: f64,
+    divide: bool,
+) -> Option<<IntervalMonthDayNanoType as ArrowPrimitiveType>::Native> {
+    let scale = |value: f64| {
+        if divide {
+            value / factor
+        } else {
+            value * factor
+        }
+    };
+    let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(interval);
+
+    let months = scale(months as f64);
+    let month_remainder_days = months.fract() * DAYS_PER_MONTH;
+    let days = scale(days as f64);
+    let nanos = (scale(nanos as f64)
+        + (days.fract() + month_remainder_days.fract()) * NANOS_PER_DAY)
+        .round();
+    let months = months.trunc();
+    let days = days.trunc() + month_remainder_days.trunc();
+
+    // the upper bounds are exclusive, as `i64::MAX as f64` rounds up to 2^63.
+    // NaN and infinite values never fit
+    let fits = |value: f64, min: f64, max: f64| value >= min && value < max;
+    if !fits(months, i32::MIN as f64, i32::MAX as f64 + 1.0)
+        || !fits(days, i32::MIN as f64, i32::MAX as f64 + 1.0)
+        || !fits(nanos, i64::MIN as f64, i64::MAX as f64)
+    {
+        return None;
+    }
+    Some(IntervalMonthDayNanoType::make_value(
+        months as i32,
+        days as i32,
+        nanos as i64,
+    ))
+}
+
+/// Applies the integer arithmetic operator `op` (`+`, `-` or `*`), clamping
+/// the results that do not fit in their type to its minimum or maximum value
+pub(crate) fn saturating_arithmetic(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    op: &Operator,
+) -> Result<ArrayRef, ArrowError> {
+    macro_rules! saturating {
+        ($T:ty) => {
+            match op {
+                Operator::Plus => {
+                    saturating_op::<$T>(lhs, rhs, |l, r| l.saturating_add(r))
+                }
+                Operator::Minus => {
+                    saturating_op::<$T>(lhs, rhs, |l, r| l.saturating_sub(r))
+                }
+                Operator::Multiply => {
+                    saturating_op::<$T>(lhs, rhs, |l, r| l.saturating_mul(r))
+                }
+                _ => Err(ArrowError::InvalidArgumentError(format!(
+                    "Operator {op} has no saturating variant"
+                ))),
+            }
+        };
+    }
+
+    match (lhs.get().0.data_type(), rhs.get().0.data_type()) {
+        (DataType::Int8, DataType::Int8) => saturating!(Int8Type),
+        (DataType::Int16, DataType::Int16) => saturating!(Int16Type),
+        (DataType::Int32, DataType::Int32) => saturating!(Int32Type),
+        (DataType::Int64, DataType::Int64) => saturating!(Int64Type),
+        (DataType::UInt8, DataType::UInt8) => saturating!(UInt8Type),
+        (DataType::UInt16, DataType::UInt16) => saturating!(UInt16Type),
+        (DataType::UInt32, DataType::UInt32) => saturating!(UInt32Type),
+        (DataType::UInt64, DataType::UInt64) => saturating!(UInt64Type),
+        (l, r) => Err(ArrowError::InvalidArgumentError(format!(
+            "Saturating arithmetic is not supported for {l} {op} {r}"
+        ))),
+    }
+}
+
+fn saturating_op<T: ArrowPrimitiveType>(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    op: impl Fn(T::Native, T::Native) -> T::Native,
+) -> Result<ArrayRef, ArrowError> {
+    let (l, l_scalar) = lhs.get();
+    let (r, r_scalar) = rhs.get();
+    let l = l.as_primitive::<T>();
+    let r = r.as_primitive::<T>();
+
+    let result: PrimitiveArray<T> = match (l_scalar, r_scalar) {
+        (true, false) if l.is_null(0) => PrimitiveArray::new_null(r.len()),
+        (true, false) => {
+            let l = l.value(0);
+            r.unary(|r| op(l, r))
+        }
+        (false, true) if r.is_null(0) => PrimitiveArray::new_null(l.len()),
+        (false, true) => {
+            let r = r.value(0);
+            l.unary(|l| op(l, r))
+        }
+        _ => arrow::compute::binary(l, r, op)?,
+    };
+    Ok(Arc::new(result))
+}
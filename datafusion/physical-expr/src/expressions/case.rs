@@ -133,6 +133,11 @@ impl CaseExpr {
         // We only consider non-null values while comparing with whens
         let mut remainder = not(&base_nulls)?;
         for i in 0..self.when_then_expr.len() {
+            // All rows have been matched: the remaining WHEN and THEN
+            // expressions must not be evaluated
+            if remainder.true_count() == 0 {
+                break;
+            }
             let when_value = self.when_then_expr[i]
                 .0
                 .evaluate_selection(batch, &remainder)?;
@@ -177,6 +182,9 @@ impl CaseExpr {
                 .unwrap_or_else(|_| e.clone());
             // null and unmatched tuples should be assigned else value
             remainder = or(&base_nulls, &remainder)?;
+            if remainder.true_count() == 0 {
+                return Ok(ColumnarValue::Array(current_value));
+            }
             let else_ = expr
                 .evaluate_selection(batch, &remainder)?
                 .into_array(batch.num_rows())?;
@@ -200,6 +208,11 @@ impl CaseExpr {
         let mut current_value = new_null_array(&return_type, batch.num_rows());
         let mut remainder = BooleanArray::from(vec![true; batch.num_rows()]);
         for i in 0..self.when_then_expr.len() {
+            // All rows have been matched: the remaining WHEN and THEN
+            // expressions must not be evaluated
+            if remainder.true_count() == 0 {
+                break;
+            }
             let when_value = self.when_then_expr[i]
                 .0
                 .evaluate_selection(batch, &remainder)?;
@@ -244,7 +257,12 @@ impl CaseExpr {
             remainder = and_not(&remainder, &when_value)?;
         }
 
-        if let Some(e) = &self.else_expr {
+        // only evaluate the else expression if some rows did not match
+        if let Some(e) = self
+            .else_expr
+            .as_ref()
+            .filter(|_| remainder.true_count() > 0)
+        {
             // keep `else_expr`'s data type and return type consistent
             let expr = try_cast(e.clone(), &batch.schema(), return_type.clone())
                 .unwrap_or_else(|_| e.clone());
@@ -409,7 +427,7 @@ pub fn case(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::expressions::{binary, cast, col, lit};
+    use crate::expressions::{binary, cast, col, lit, IsNullExpr};
 
     use arrow::buffer::Buffer;
     use arrow::datatypes::DataType::Float64;
@@ -624,6 +642,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn case_does_not_evaluate_unreachable_branches() -> Result<()> {
+        let batch = case_test_batch1()?;
+        let schema = batch.schema();
+
+        // CASE WHEN a IS NULL OR a >= 0 THEN 1 WHEN a < 0 THEN 1 / 0 ELSE 1 / 0 END
+        let when1 = binary(
+            Arc::new(IsNullExpr::new(col("a", &schema)?)),
+            Operator::Or,
+            binary(col("a", &schema)?, Operator::GtEq, lit(0i32), &schema)?,
+            &schema,
+        )?;
+        let then1 = lit(1i32);
+        let when2 = binary(col("a", &schema)?, Operator::Lt, lit(0i32), &schema)?;
+        let divide_by_zero = || binary(lit(1i32), Operator::Divide, lit(0i32), &schema);
+
+        let expr = generate_case_when_with_type_coercion(
+            None,
+            vec![(when1, then1), (when2, divide_by_zero()?)],
+            Some(divide_by_zero()?),
+            schema.as_ref(),
+        )?;
+        let result = expr
+            .evaluate(&batch)?
+            .into_array(batch.num_rows())
+            .expect("Failed to convert to array");
+        let result = as_int32_array(&result)?;
+
+        let expected = &Int32Array::from(vec![Some(1), Some(1), Some(1), Some(1)]);
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
     fn case_test_batch1() -> Result<RecordBatch> {
         let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
         let a = Int32Array::from(vec![Some(1), Some(0), None, Some(5)]);
@@ -20,9 +20,16 @@ use std::{any::Any, sync::Arc};
 
 use crate::{physical_expr::down_cast_any_ref, PhysicalExpr};
 
+use arrow::array::{BooleanArray, StringArray};
+use arrow::compute::cast;
+use arrow::compute::kernels::boolean::not;
+use arrow::compute::kernels::comparison::{
+    regexp_is_match_utf8, regexp_is_match_utf8_scalar,
+};
 use arrow::record_batch::RecordBatch;
 use arrow_schema::{DataType, Schema};
-use datafusion_common::{internal_err, Result};
+use datafusion_common::cast::as_string_array;
+use datafusion_common::{exec_err, internal_err, Result, ScalarValue};
 use datafusion_expr::ColumnarValue;
 use datafusion_physical_expr_common::datum::apply_cmp;
 
@@ -33,6 +40,8 @@ pub struct LikeExpr {
     case_insensitive: bool,
     expr: Arc<dyn PhysicalExpr>,
     pattern: Arc<dyn PhysicalExpr>,
+    /// The escape character of the pattern, if it is not the default `\`
+    escape_char: Option<char>,
 }
 
 impl LikeExpr {
@@ -47,9 +56,16 @@ impl LikeExpr {
             case_insensitive,
             expr,
             pattern,
+            escape_char: None,
         }
     }
 
+    /// Set the escape character of the pattern
+    pub fn with_escape_char(mut self, escape_char: Option<char>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Is negated
     pub fn negated(&self) -> bool {
         self.negated
@@ -70,6 +86,11 @@ impl LikeExpr {
         &self.pattern
     }
 
+    /// Escape character of the pattern, if it is not the default `\`
+    pub fn escape_char(&self) -> Option<char> {
+        self.escape_char
+    }
+
     /// Operator name
     fn op_name(&self) -> &str {
         match (self.negated, self.case_insensitive) {
@@ -79,11 +100,76 @@ impl LikeExpr {
             (true, true) => "NOT ILIKE",
         }
     }
+
+    /// Evaluates the expression with a custom escape character.
+    ///
+    /// The `like` kernels only support `\` as escape character, so the
+    /// patterns are translated to regular expressions instead.
+    fn evaluate_with_escape_char(
+        &self,
+        lhs: ColumnarValue,
+        rhs: ColumnarValue,
+        escape_char: char,
+        num_rows: usize,
+    ) -> Result<ColumnarValue> {
+        let is_scalar = matches!(
+            (&lhs, &rhs),
+            (ColumnarValue::Scalar(_), ColumnarValue::Scalar(_))
+        );
+        let num_rows = if is_scalar { 1 } else { num_rows };
+        let values = cast(&lhs.into_array(num_rows)?, &DataType::Utf8)?;
+        let values = as_string_array(&values)?;
+        let flag = self.case_insensitive.then_some("i");
+
+        let result = match rhs {
+            ColumnarValue::Scalar(
+                ScalarValue::Utf8(pattern) | ScalarValue::LargeUtf8(pattern),
+            ) => match pattern {
+                Some(pattern) => {
+                    let regex = like_pattern_to_regex(&pattern, escape_char)?;
+                    regexp_is_match_utf8_scalar(values, &regex, flag)?
+                }
+                None => BooleanArray::new_null(num_rows),
+            },
+            ColumnarValue::Scalar(other) => {
+                return internal_err!(
+                    "Unexpected pattern {other:?} in {} expression",
+                    self.op_name()
+                );
+            }
+            ColumnarValue::Array(patterns) => {
+                let patterns = cast(&patterns, &DataType::Utf8)?;
+                let regexes = as_string_array(&patterns)?
+                    .iter()
+                    .map(|pattern| {
+                        pattern
+                            .map(|pattern| like_pattern_to_regex(pattern, escape_char))
+                            .transpose()
+                    })
+                    .collect::<Result<StringArray>>()?;
+                let flags = flag.map(|flag| StringArray::from(vec![flag; num_rows]));
+                regexp_is_match_utf8(values, &regexes, flags.as_ref())?
+            }
+        };
+        let result = if self.negated { not(&result)? } else { result };
+
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
 }
 
 impl std::fmt::Display for LikeExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{} {} {}", self.expr, self.op_name(), self.pattern)
+        write!(f, "{} {} {}", self.expr, self.op_name(), self.pattern)?;
+        if let Some(escape_char) = self.escape_char {
+            write!(f, " ESCAPE '{escape_char}'")?;
+        }
+        Ok(())
     }
 }
 
@@ -104,6 +190,14 @@ impl PhysicalExpr for LikeExpr {
         use arrow::compute::*;
         let lhs = self.expr.evaluate(batch)?;
         let rhs = self.pattern.evaluate(batch)?;
+        if let Some(escape_char) = self.escape_char {
+            return self.evaluate_with_escape_char(
+                lhs,
+                rhs,
+                escape_char,
+                batch.num_rows(),
+            );
+        }
         match (self.negated, self.case_insensitive) {
             (false, false) => apply_cmp(&lhs, &rhs, like),
             (false, true) => apply_cmp(&lhs, &rhs, ilike),
@@ -120,12 +214,15 @@ impl PhysicalExpr for LikeExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(LikeExpr::new(
-            self.negated,
-            self.case_insensitive,
-            children[0].clone(),
-            children[1].clone(),
-        )))
+        Ok(Arc::new(
+            LikeExpr::new(
+                self.negated,
+                self.case_insensitive,
+                children[0].clone(),
+                children[1].clone(),
+            )
+            .with_escape_char(self.escape_char),
+        ))
     }
 
     fn dyn_hash(&self, state: &mut dyn Hasher) {
@@ -143,15 +240,20 @@ impl PartialEq<dyn Any> for LikeExpr {
                     && self.case_insensitive == x.case_insensitive
                     && self.expr.eq(&x.expr)
                     && self.pattern.eq(&x.pattern)
+                    && self.escape_char == x.escape_char
             })
             .unwrap_or(false)
     }
 }
 
-/// used for optimize Dictionary like
-fn can_like_type(from_type: &DataType) -> bool {
-    match from_type {
-        DataType::Dictionary(_, inner_type_from) => **inner_type_from == DataType::Utf8,
+/// Returns true if `expr_type` is a dictionary of strings of `pattern_type`,
+/// whose values the like kernels match directly
+fn can_like_type(expr_type: &DataType, pattern_type: &DataType) -> bool {
+    match expr_type {
+        DataType::Dictionary(_, value_type) => {
+            value_type.as_ref() == pattern_type
+                && matches!(pattern_type, DataType::Utf8 | DataType::LargeUtf8)
+        }
         _ => false,
     }
 }
@@ -163,26 +265,147 @@ pub fn like(
     expr: Arc<dyn PhysicalExpr>,
     pattern: Arc<dyn PhysicalExpr>,
     input_schema: &Schema,
+) -> Result<Arc<dyn PhysicalExpr>> {
+    like_with_escape_char(negated, case_insensitive, expr, pattern, None, input_schema)
+}
+
+/// Create a like expression whose pattern uses `escape_char` as escape
+/// character, erroring if the argument types are not compatible.
+pub fn like_with_escape_char(
+    negated: bool,
+    case_insensitive: bool,
+    expr: Arc<dyn PhysicalExpr>,
+    pattern: Arc<dyn PhysicalExpr>,
+    escape_char: Option<char>,
+    input_schema: &Schema,
 ) -> Result<Arc<dyn PhysicalExpr>> {
     let expr_type = &expr.data_type(input_schema)?;
     let pattern_type = &pattern.data_type(input_schema)?;
-    if !expr_type.eq(pattern_type) && !can_like_type(expr_type) {
+    if !expr_type.eq(pattern_type) && !can_like_type(expr_type, pattern_type) {
         return internal_err!(
             "The type of {expr_type} AND {pattern_type} of like physical should be same"
         );
     }
-    Ok(Arc::new(LikeExpr::new(
-        negated,
-        case_insensitive,
-        expr,
-        pattern,
-    )))
+    Ok(Arc::new(
+        LikeExpr::new(negated, case_insensitive, expr, pattern)
+            .with_escape_char(escape_char),
+    ))
+}
+
+/// Translates a LIKE `pattern` whose escape character is `escape_char` to an
+/// equivalent regular expression, matching the whole string.
+///
+/// `%` matches any sequence of characters, `_` matches any single character
+/// and the escape character makes the next character match itself.
+pub(crate) fn like_pattern_to_regex(pattern: &str, escape_char: char) -> Result<String> {
+    let mut regex = String::with_capacity(pattern.len() * 2 + 8);
+    regex.push_str("^(?s:");
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == escape_char {
+            match chars.next() {
+                Some(c) => push_regex_literal(&mut regex, c),
+                None => {
+                    return exec_err!(
+                    "LIKE pattern must not end with the escape character '{escape_char}'"
+                )
+                }
+            }
+        } else if c == '%' {
+            regex.push_str(".*");
+        } else if c == '_' {
+            regex.push('.');
+        } else {
+            push_regex_literal(&mut regex, c);
+        }
+    }
+    regex.push_str(")$");
+    Ok(regex)
+}
+
+/// Translates a SIMILAR TO `pattern` to an equivalent regular expression,
+/// matching the whole string.
+///
+/// `%` and `_` are the LIKE wildcards, `.`, `^` and `$` match themselves and
+/// the other regular expression operators (`|`, `*`, `+`, `?`, `{m,n}`,
+/// parentheses and bracket expressions) keep their meaning. The escape
+/// character, `\` by default, makes the next character match itself.
+pub(crate) fn similar_to_pattern_to_regex(
+    pattern: &str,
+    escape_char: Option<char>,
+) -> Result<String> {
+    let escape_char = escape_char.unwrap_or('\\');
+    let mut regex = String::with_capacity(pattern.len() * 2 + 8);
+    regex.push_str("^(?s:");
+    let mut in_bracket = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == escape_char {
+            match chars.next() {
+                Some(c) => push_regex_literal(&mut regex, c),
+                None => {
+                    return exec_err!(
+                        "SIMILAR TO pattern must not end with the escape character '{escape_char}'"
+                    )
+                }
+            }
+        } else if in_bracket {
+            // bracket expressions are copied as is, `%` and `_` included
+            if c == ']' {
+                in_bracket = false;
+            }
+            if c == '\\' {
+                regex.push('\\');
+            }
+            regex.push(c);
+        } else {
+            match c {
+                '%' => regex.push_str(".*"),
+                '_' => regex.push('.'),
+                '[' => {
+                    in_bracket = true;
+                    regex.push(c);
+                }
+                '|' | '*' | '+' | '?' | '{' | '}' | '(' | ')' => regex.push(c),
+                c => push_regex_literal(&mut regex, c),
+            }
+        }
+    }
+    regex.push_str(")$");
+    Ok(regex)
+}
+
+/// Appends `c` to `regex` so that it only matches itself
+fn push_regex_literal(regex: &mut String, c: char) {
+    if matches!(
+        c,
+        '\\' | '.'
+            | '+'
+            | '*'
+            | '?'
+            | '('
+            | ')'
+            | '|'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '^'
+            | '$'
+            | '#'
+            | '&'
+            | '-'
+            | '~'
+    ) {
+        regex.push('\\');
+    }
+    regex.push(c);
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::expressions::col;
+    use crate::expressions::{col, lit};
     use arrow::array::*;
     use arrow_schema::Field;
     use datafusion_common::cast::as_boolean_array;
@@ -257,4 +480,130 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn like_dictionary() -> Result<()> {
+        let values = LargeStringArray::from(vec!["Hello world", "world"]);
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(0)]);
+        let dict = DictionaryArray::<arrow::datatypes::Int32Type>::try_new(
+            keys,
+            Arc::new(values),
+        )?;
+        let schema = Schema::new(vec![Field::new("a", dict.data_type().clone(), true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(dict)])?;
+        let pattern = lit(ScalarValue::LargeUtf8(Some("hello%".to_string())));
+
+        let expression =
+            like(false, false, col("a", &schema)?, pattern.clone(), &schema)?;
+        let result = expression.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected =
+            BooleanArray::from(vec![Some(false), Some(false), None, Some(false)]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+
+        let expression = like(true, true, col("a", &schema)?, pattern, &schema)?;
+        let result = expression.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected =
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(false)]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+
+        // the pattern must have the type of the values
+        let err = like(false, false, col("a", &schema)?, lit("hello%"), &schema)
+            .unwrap_err()
+            .strip_backtrace();
+        assert!(err.contains("of like physical should be same"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn like_escape_char() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let a = StringArray::from(vec![
+            Some("100%"),
+            Some("1000"),
+            Some("a_b"),
+            Some("A\\B"),
+            None,
+        ]);
+        let b = StringArray::from(vec![
+            Some("%#%"),
+            Some("%#%"),
+            Some("a#_b"),
+            Some("a\\b"),
+            Some("x"),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(a), Arc::new(b)],
+        )?;
+
+        let expression = like_with_escape_char(
+            false,
+            true,
+            col("a", &schema)?,
+            col("b", &schema)?,
+            Some('#'),
+            &schema,
+        )?;
+        assert_eq!(expression.to_string(), "a@0 ILIKE b@1 ESCAPE '#'");
+        let result = expression.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+        ]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+
+        let expression = like_with_escape_char(
+            true,
+            false,
+            col("a", &schema)?,
+            lit("%#%"),
+            Some('#'),
+            &schema,
+        )?;
+        let result = expression.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+            None,
+        ]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn pattern_to_regex() -> Result<()> {
+        assert_eq!(like_pattern_to_regex("a%b_c", '#')?, "^(?s:a.*b.c)$");
+        assert_eq!(
+            like_pattern_to_regex("#%#_##.\\", '#')?,
+            "^(?s:%_\\#\\.\\\\)$"
+        );
+        assert_eq!(
+            like_pattern_to_regex("a#", '#')
+                .unwrap_err()
+                .strip_backtrace(),
+            "Execution error: LIKE pattern must not end with the escape character '#'"
+        );
+
+        assert_eq!(
+            similar_to_pattern_to_regex("(a|b)%.[_%]", None)?,
+            "^(?s:(a|b).*\\.[_%])$"
+        );
+        assert_eq!(
+            similar_to_pattern_to_regex("a\\%^$", None)?,
+            "^(?s:a%\\^\\$)$"
+        );
+        assert_eq!(
+            similar_to_pattern_to_regex("a!_\\{2}", Some('!'))?,
+            "^(?s:a_\\\\{2})$"
+        );
+        Ok(())
+    }
 }
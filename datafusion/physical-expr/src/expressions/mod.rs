@@ -59,7 +59,8 @@ pub use datafusion_physical_expr_common::expressions::{cast, CastExpr};
 pub use in_list::{in_list, InListExpr};
 pub use is_not_null::{is_not_null, IsNotNullExpr};
 pub use is_null::{is_null, IsNullExpr};
-pub use like::{like, LikeExpr};
+pub(crate) use like::similar_to_pattern_to_regex;
+pub use like::{like, like_with_escape_char, LikeExpr};
 pub use negative::{negative, NegativeExpr};
 pub use no_op::NoOp;
 pub use not::{not, NotExpr};
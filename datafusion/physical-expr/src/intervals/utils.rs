@@ -21,7 +21,7 @@ use std::sync::Arc;
 
 use crate::{
     expressions::{BinaryExpr, CastExpr, Column, Literal, NegativeExpr},
-    PhysicalExpr,
+    PhysicalExpr, ScalarFunctionExpr,
 };
 
 use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
@@ -34,7 +34,8 @@ use datafusion_expr::Operator;
 /// Currently, we do not support all [`PhysicalExpr`]s for interval calculations.
 /// We do not support every type of [`Operator`]s either. Over time, this check
 /// will relax as more types of `PhysicalExpr`s and `Operator`s are supported.
-/// Currently, [`CastExpr`], [`NegativeExpr`], [`BinaryExpr`], [`Column`] and [`Literal`] are supported.
+/// Currently, [`CastExpr`], [`NegativeExpr`], [`BinaryExpr`], [`Column`] and [`Literal`] are supported,
+/// as well as [`ScalarFunctionExpr`]s whose functions implement `evaluate_bounds`.
 pub fn check_support(expr: &Arc<dyn PhysicalExpr>, schema: &SchemaRef) -> bool {
     let expr_any = expr.as_any();
     if let Some(binary_expr) = expr_any.downcast_ref::<BinaryExpr>() {
@@ -57,11 +58,36 @@ pub fn check_support(expr: &Arc<dyn PhysicalExpr>, schema: &SchemaRef) -> bool {
         check_support(cast.expr(), schema)
     } else if let Some(negative) = expr_any.downcast_ref::<NegativeExpr>() {
         check_support(negative.arg(), schema)
+    } else if let Some(function) = expr_any.downcast_ref::<ScalarFunctionExpr>() {
+        function.args().iter().all(|arg| check_support(arg, schema))
+            && is_datatype_supported(function.return_type())
+            && implements_bounds(function, schema)
     } else {
         false
     }
 }
 
+/// Indicates whether the function of the given [`ScalarFunctionExpr`] computes
+/// bounds for its result. Functions that do not implement `evaluate_bounds`
+/// return an unbounded interval of type `Null` by default.
+fn implements_bounds(function: &ScalarFunctionExpr, schema: &SchemaRef) -> bool {
+    let Ok(inputs) = function
+        .args()
+        .iter()
+        .map(|arg| Interval::make_unbounded(&arg.data_type(schema)?))
+        .collect::<Result<Vec<_>>>()
+    else {
+        return false;
+    };
+    let inputs = inputs.iter().collect::<Vec<_>>();
+    function
+        .fun()
+        .evaluate_bounds(&inputs)
+        .map_or(false, |bounds| {
+            bounds.data_type() == *function.return_type()
+        })
+}
+
 // This function returns the inverse operator of the given operator.
 pub fn get_inverse_op(op: &Operator) -> Result<Operator> {
     match op {
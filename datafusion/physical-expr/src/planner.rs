@@ -19,13 +19,15 @@ use std::sync::Arc;
 
 use crate::scalar_function;
 use crate::{
-    expressions::{self, binary, like, Column, Literal},
+    expressions::{
+        self, binary, like_with_escape_char, similar_to_pattern_to_regex, Column, Literal,
+    },
     PhysicalExpr,
 };
 
 use arrow::datatypes::Schema;
 use datafusion_common::{
-    exec_err, not_impl_err, plan_err, DFSchema, Result, ScalarValue, ToDFSchema,
+    not_impl_err, plan_err, DFSchema, Result, ScalarValue, ToDFSchema,
 };
 use datafusion_expr::execution_props::ExecutionProps;
 use datafusion_expr::expr::{Alias, Cast, InList, ScalarFunction};
@@ -195,7 +197,10 @@ pub fn create_physical_expr(
             //
             // There should be no coercion during physical
             // planning.
-            binary(lhs, op.clone(), rhs, input_schema)
+            Ok(Arc::new(
+                expressions::BinaryExpr::new(lhs, op.clone(), rhs)
+                    .with_overflow_mode(execution_props.arithmetic_overflow_mode),
+            ))
         }
         Expr::Like(Like {
             negated,
@@ -204,21 +209,55 @@ pub fn create_physical_expr(
             escape_char,
             case_insensitive,
         }) => {
-            if escape_char.is_some() {
-                return exec_err!("LIKE does not support escape_char");
-            }
             let physical_expr =
                 create_physical_expr(expr, input_dfschema, execution_props)?;
             let physical_pattern =
                 create_physical_expr(pattern, input_dfschema, execution_props)?;
-            like(
+            like_with_escape_char(
                 *negated,
                 *case_insensitive,
                 physical_expr,
                 physical_pattern,
+                *escape_char,
                 input_schema,
             )
         }
+        Expr::SimilarTo(Like {
+            negated,
+            expr,
+            pattern,
+            escape_char,
+            case_insensitive,
+        }) => {
+            // SIMILAR TO is evaluated as a match of the equivalent regular expression
+            let regex = match pattern.as_ref() {
+                Expr::Literal(ScalarValue::Utf8(pattern)) => ScalarValue::Utf8(
+                    pattern
+                        .as_deref()
+                        .map(|p| similar_to_pattern_to_regex(p, *escape_char))
+                        .transpose()?,
+                ),
+                Expr::Literal(ScalarValue::LargeUtf8(pattern)) => ScalarValue::LargeUtf8(
+                    pattern
+                        .as_deref()
+                        .map(|p| similar_to_pattern_to_regex(p, *escape_char))
+                        .transpose()?,
+                ),
+                _ => {
+                    return not_impl_err!(
+                    "SIMILAR TO is only supported with a literal pattern, got {pattern}"
+                )
+                }
+            };
+            let op = match (negated, case_insensitive) {
+                (false, false) => Operator::RegexMatch,
+                (false, true) => Operator::RegexIMatch,
+                (true, false) => Operator::RegexNotMatch,
+                (true, true) => Operator::RegexNotIMatch,
+            };
+            let binary_op = binary_expr(expr.as_ref().clone(), op, Expr::Literal(regex));
+            create_physical_expr(&binary_op, input_dfschema, execution_props)
+        }
         Expr::Case(case) => {
             let expr: Option<Arc<dyn PhysicalExpr>> = if let Some(e) = &case.expr {
                 Some(create_physical_expr(
@@ -305,19 +344,21 @@ pub fn create_physical_expr(
             let low_expr = create_physical_expr(low, input_dfschema, execution_props)?;
             let high_expr = create_physical_expr(high, input_dfschema, execution_props)?;
 
-            // rewrite the between into the two binary operators
-            let binary_expr = binary(
-                binary(value_expr.clone(), Operator::GtEq, low_expr, input_schema)?,
-                Operator::And,
-                binary(value_expr.clone(), Operator::LtEq, high_expr, input_schema)?,
-                input_schema,
-            );
-
-            if *negated {
-                expressions::not(binary_expr?)
+            // rewrite the between into a range predicate, which can be used for
+            // pruning:
+            // `a BETWEEN l AND h` => `a >= l AND a <= h`
+            // `a NOT BETWEEN l AND h` => `a < l OR a > h`
+            let (low_op, join_op, high_op) = if *negated {
+                (Operator::Lt, Operator::Or, Operator::Gt)
             } else {
-                binary_expr
-            }
+                (Operator::GtEq, Operator::And, Operator::LtEq)
+            };
+            binary(
+                binary(value_expr.clone(), low_op, low_expr, input_schema)?,
+                join_op,
+                binary(value_expr, high_op, high_expr, input_schema)?,
+                input_schema,
+            )
         }
         Expr::InList(InList {
             expr,
@@ -185,6 +185,20 @@ impl AggregateWindowExpr for SlidingAggregateWindowExpr {
         if cur_range.start == cur_range.end {
             // We produce None if the window is empty.
             ScalarValue::try_from(self.aggregate.field()?.data_type())
+        } else if cur_range.start > last_range.start
+            && !accumulator.supports_retract_batch()
+        {
+            // Rows have left the window, but can not be removed from the
+            // state of the accumulator: aggregate the whole window again.
+            // Only distinct aggregates and aggregates that opt in with
+            // `recompute_sliding_windows` get here
+            *accumulator = self.get_accumulator()?;
+            let window: Vec<ArrayRef> = value_slice
+                .iter()
+                .map(|v| v.slice(cur_range.start, cur_range.end - cur_range.start))
+                .collect();
+            accumulator.update_batch(&window)?;
+            accumulator.evaluate()
         } else {
             // Accumulate any new rows that have entered the window:
             let update_bound = cur_range.end - last_range.end;
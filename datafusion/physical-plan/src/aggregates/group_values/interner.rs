@@ -0,0 +1,298 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use ahash::RandomState;
+use arrow::buffer::{Buffer, OffsetBuffer, ScalarBuffer};
+use arrow::datatypes::ArrowNativeType;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    ByteArrayType, GenericBinaryType, GenericStringType, UInt32Type,
+};
+use arrow_array::{Array, ArrayRef, GenericByteArray, UInt32Array};
+use arrow_schema::DataType;
+use datafusion_common::hash_utils::create_hashes;
+use datafusion_common::{exec_err, internal_err, Result};
+use datafusion_execution::memory_pool::proxy::{RawTableAllocExt, VecAllocExt};
+use hashbrown::raw::RawTable;
+
+/// Assigns dense `u32` ids to the distinct values of a Utf8/LargeUtf8/
+/// Binary/LargeBinary group key, so that [`GroupValuesRows`] can store a
+/// fixed size id for each group in place of a copy of the value.
+///
+/// Ids are assigned in insertion order and stay valid until [`Self::clear`]
+/// is called.
+///
+/// [`GroupValuesRows`]: super::row::GroupValuesRows
+pub struct BytesInterner {
+    /// The type of the interned values
+    data_type: DataType,
+
+    /// Logically maps each distinct value to its id
+    ///
+    /// keys: u64 hashes of the value
+    /// values: (hash, id)
+    map: RawTable<(u64, u32)>,
+
+    /// The size of `map` in bytes
+    map_size: usize,
+
+    /// The bytes of the distinct values. The value with id `i` is stored in
+    /// the range `offsets[i]..offsets[i + 1]`
+    buffer: Vec<u8>,
+
+    /// Offsets into `buffer` for each distinct value
+    offsets: Vec<usize>,
+
+    /// reused buffer to store hashes
+    hashes_buffer: Vec<u64>,
+
+    /// Random state for creating hashes
+    random_state: RandomState,
+}
+
+impl BytesInterner {
+    pub fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            map: RawTable::with_capacity(0),
+            map_size: 0,
+            buffer: vec![],
+            offsets: vec![0],
+            hashes_buffer: vec![],
+            random_state: Default::default(),
+        }
+    }
+
+    /// Returns true if the values of `data_type` can be interned
+    pub fn supports(data_type: &DataType) -> bool {
+        matches!(
+            data_type,
+            DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Binary
+                | DataType::LargeBinary
+        )
+    }
+
+    /// Returns the ids of `values` as a `UInt32Array`, interning the values
+    /// seen for the first time. Null values have a null id.
+    pub fn intern(&mut self, values: &ArrayRef) -> Result<ArrayRef> {
+        let mut hashes = std::mem::take(&mut self.hashes_buffer);
+        hashes.clear();
+        hashes.resize(values.len(), 0);
+        create_hashes(
+            std::slice::from_ref(values),
+            &self.random_state,
+            &mut hashes,
+        )?;
+
+        let ids = match self.data_type {
+            DataType::Utf8 => self.intern_bytes(values.as_string::<i32>(), &hashes),
+            DataType::LargeUtf8 => self.intern_bytes(values.as_string::<i64>(), &hashes),
+            DataType::Binary => self.intern_bytes(values.as_binary::<i32>(), &hashes),
+            DataType::LargeBinary => {
+                self.intern_bytes(values.as_binary::<i64>(), &hashes)
+            }
+            _ => internal_err!("Can not intern values of type {}", self.data_type),
+        };
+        self.hashes_buffer = hashes;
+        Ok(Arc::new(ids?))
+    }
+
+    fn intern_bytes<T: ByteArrayType>(
+        &mut self,
+        values: &GenericByteArray<T>,
+        hashes: &[u64],
+    ) -> Result<UInt32Array> {
+        values
+            .iter()
+            .zip(hashes)
+            .map(|(value, &hash)| {
+                value
+                    .map(|value| self.intern_value(AsRef::<[u8]>::as_ref(value), hash))
+                    .transpose()
+            })
+            .collect()
+    }
+
+    fn intern_value(&mut self, value: &[u8], hash: u64) -> Result<u32> {
+        let Self {
+            map,
+            map_size,
+            buffer,
+            offsets,
+            ..
+        } = self;
+
+        let entry = map.get(hash, |(_hash, id)| {
+            let id = *id as usize;
+            &buffer[offsets[id]..offsets[id + 1]] == value
+        });
+        if let Some((_hash, id)) = entry {
+            return Ok(*id);
+        }
+
+        let Ok(id) = u32::try_from(offsets.len() - 1) else {
+            return exec_err!("Can not intern more than {} distinct values", u32::MAX);
+        };
+        buffer.extend_from_slice(value);
+        offsets.push(buffer.len());
+        // for hasher function, use precomputed hash value
+        map.insert_accounted((hash, id), |(hash, _id)| *hash, map_size);
+        Ok(id)
+    }
+
+    /// Returns the values of the `UInt32Array` of `ids` returned by
+    /// [`Self::intern`]
+    pub fn lookup(&self, ids: &ArrayRef) -> Result<ArrayRef> {
+        let ids = ids.as_primitive::<UInt32Type>();
+        Ok(match self.data_type {
+            DataType::Utf8 => Arc::new(self.lookup_bytes::<GenericStringType<i32>>(ids)?),
+            DataType::LargeUtf8 => {
+                Arc::new(self.lookup_bytes::<GenericStringType<i64>>(ids)?)
+            }
+            DataType::Binary => {
+                Arc::new(self.lookup_bytes::<GenericBinaryType<i32>>(ids)?)
+            }
+            DataType::LargeBinary => {
+                Arc::new(self.lookup_bytes::<GenericBinaryType<i64>>(ids)?)
+            }
+            _ => {
+                return internal_err!("Can not intern values of type {}", self.data_type)
+            }
+        })
+    }
+
+    fn lookup_bytes<T: ByteArrayType>(
+        &self,
+        ids: &UInt32Array,
+    ) -> Result<GenericByteArray<T>> {
+        let mut offsets = Vec::with_capacity(ids.len() + 1);
+        offsets.push(T::Offset::usize_as(0));
+        let mut values = vec![];
+        for id in ids.iter() {
+            if let Some(id) = id {
+                let id = id as usize;
+                values.extend_from_slice(
+                    &self.buffer[self.offsets[id]..self.offsets[id + 1]],
+                );
+            }
+            let Some(offset) = T::Offset::from_usize(values.len()) else {
+                return exec_err!(
+                    "Group key values of type {} are too large",
+                    self.data_type
+                );
+            };
+            offsets.push(offset);
+        }
+        Ok(GenericByteArray::try_new(
+            OffsetBuffer::new(ScalarBuffer::from(offsets)),
+            Buffer::from_vec(values),
+            ids.nulls().cloned(),
+        )?)
+    }
+
+    /// Returns the number of bytes used by this [`BytesInterner`]
+    pub fn size(&self) -> usize {
+        self.map_size
+            + self.buffer.allocated_size()
+            + self.offsets.allocated_size()
+            + self.hashes_buffer.allocated_size()
+    }
+
+    /// Forgets all the interned values, invalidating their ids
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.map.shrink_to(0, |_| 0); // hasher does not matter since the map is cleared
+        self.map_size = self.map.capacity() * std::mem::size_of::<(u64, u32)>();
+        self.buffer.clear();
+        self.buffer.shrink_to_fit();
+        self.offsets.truncate(1);
+        self.offsets.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_array::{BinaryArray, StringArray};
+
+    #[test]
+    fn intern_strings() -> Result<()> {
+        let mut interner = BytesInterner::new(DataType::Utf8);
+
+        let values: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("foo"),
+            None,
+            Some("bar"),
+            Some("foo"),
+        ]));
+        let ids = interner.intern(&values)?;
+        assert_eq!(
+            ids.as_primitive::<UInt32Type>(),
+            &UInt32Array::from(vec![Some(0), None, Some(1), Some(0)])
+        );
+
+        let values: ArrayRef =
+            Arc::new(StringArray::from(vec!["TheQuickBrownFox", "bar"]));
+        let ids = interner.intern(&values)?;
+        assert_eq!(
+            ids.as_primitive::<UInt32Type>(),
+            &UInt32Array::from(vec![2, 1])
+        );
+
+        let ids: ArrayRef = Arc::new(UInt32Array::from(vec![Some(2), None, Some(0)]));
+        assert_eq!(
+            interner.lookup(&ids)?.as_string::<i32>(),
+            &StringArray::from(vec![Some("TheQuickBrownFox"), None, Some("foo")])
+        );
+
+        interner.clear();
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["bar"]));
+        let ids = interner.intern(&values)?;
+        assert_eq!(
+            ids.as_primitive::<UInt32Type>(),
+            &UInt32Array::from(vec![0])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn intern_binary() -> Result<()> {
+        let mut interner = BytesInterner::new(DataType::Binary);
+
+        let values: ArrayRef = Arc::new(BinaryArray::from_opt_vec(vec![
+            Some(&b"\x00\x01"[..]),
+            Some(&b""[..]),
+            None,
+            Some(&b"\x00\x01"[..]),
+        ]));
+        let ids = interner.intern(&values)?;
+        assert_eq!(
+            ids.as_primitive::<UInt32Type>(),
+            &UInt32Array::from(vec![Some(0), Some(1), None, Some(0)])
+        );
+        assert_eq!(
+            interner.lookup(&ids)?.as_binary::<i32>(),
+            values.as_binary::<i32>()
+        );
+        Ok(())
+    }
+}
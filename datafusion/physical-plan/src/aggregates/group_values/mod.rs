@@ -29,6 +29,8 @@ use row::GroupValuesRows;
 
 mod bytes;
 use bytes::GroupValuesByes;
+
+mod interner;
 use datafusion_physical_expr::binary_map::OutputType;
 
 /// An interning store for group keys
@@ -52,7 +54,14 @@ pub trait GroupValues: Send {
     fn clear_shrink(&mut self, batch: &RecordBatch);
 }
 
-pub fn new_group_values(schema: SchemaRef) -> Result<Box<dyn GroupValues>> {
+/// Creates the [`GroupValues`] for the group keys of `schema`
+///
+/// If `intern_string_keys` is true, the string and binary keys of multi
+/// column group keys are interned (see [`GroupValuesRows`])
+pub fn new_group_values(
+    schema: SchemaRef,
+    intern_string_keys: bool,
+) -> Result<Box<dyn GroupValues>> {
     if schema.fields.len() == 1 {
         let d = schema.fields[0].data_type();
 
@@ -81,5 +90,8 @@ pub fn new_group_values(schema: SchemaRef) -> Result<Box<dyn GroupValues>> {
         }
     }
 
-    Ok(Box::new(GroupValuesRows::try_new(schema)?))
+    Ok(Box::new(GroupValuesRows::try_new(
+        schema,
+        intern_string_keys,
+    )?))
 }
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::aggregates::group_values::interner::BytesInterner;
 use crate::aggregates::group_values::GroupValues;
 use ahash::RandomState;
 use arrow::compute::cast;
@@ -27,8 +28,13 @@ use datafusion_common::{DataFusionError, Result};
 use datafusion_execution::memory_pool::proxy::{RawTableAllocExt, VecAllocExt};
 use datafusion_expr::EmitTo;
 use hashbrown::raw::RawTable;
+use std::sync::Arc;
 
 /// A [`GroupValues`] making use of [`Rows`]
+///
+/// String and binary group keys can optionally be interned: each distinct
+/// value is then stored once by a [`BytesInterner`], and the rows hold its
+/// `u32` id instead of the value, making them smaller and faster to compare.
 pub struct GroupValuesRows {
     /// The output schema
     schema: SchemaRef,
@@ -36,6 +42,10 @@ pub struct GroupValuesRows {
     /// Converter for the group values
     row_converter: RowConverter,
 
+    /// The interner of each group column whose values are stored in the
+    /// rows as ids, `None` for the columns stored as is
+    interners: Vec<Option<BytesInterner>>,
+
     /// Logically maps group values to a group_index in
     /// [`Self::group_values`] and in each accumulator
     ///
@@ -70,12 +80,25 @@ pub struct GroupValuesRows {
 }
 
 impl GroupValuesRows {
-    pub fn try_new(schema: SchemaRef) -> Result<Self> {
+    pub fn try_new(schema: SchemaRef, intern_string_keys: bool) -> Result<Self> {
+        let interners: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|f| {
+                let data_type = f.data_type();
+                (intern_string_keys && BytesInterner::supports(data_type))
+                    .then(|| BytesInterner::new(data_type.clone()))
+            })
+            .collect();
         let row_converter = RowConverter::new(
             schema
                 .fields()
                 .iter()
-                .map(|f| SortField::new(f.data_type().clone()))
+                .zip(&interners)
+                .map(|(f, interner)| match interner {
+                    Some(_) => SortField::new(DataType::UInt32),
+                    None => SortField::new(f.data_type().clone()),
+                })
                 .collect(),
         )?;
 
@@ -88,6 +111,7 @@ impl GroupValuesRows {
         Ok(Self {
             schema,
             row_converter,
+            interners,
             map,
             map_size: 0,
             group_values: None,
@@ -100,10 +124,20 @@ impl GroupValuesRows {
 
 impl GroupValues for GroupValuesRows {
     fn intern(&mut self, cols: &[ArrayRef], groups: &mut Vec<usize>) -> Result<()> {
+        // Replace the interned group keys by their ids
+        let cols = cols
+            .iter()
+            .zip(self.interners.iter_mut())
+            .map(|(col, interner)| match interner {
+                Some(interner) => interner.intern(col),
+                None => Ok(Arc::clone(col)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         // Convert the group keys into the row format
         let group_rows = &mut self.rows_buffer;
         group_rows.clear();
-        self.row_converter.append(group_rows, cols)?;
+        self.row_converter.append(group_rows, &cols)?;
         let n_rows = group_rows.num_rows();
 
         let mut group_values = match self.group_values.take() {
@@ -118,7 +152,7 @@ impl GroupValues for GroupValuesRows {
         let batch_hashes = &mut self.hashes_buffer;
         batch_hashes.clear();
         batch_hashes.resize(n_rows, 0);
-        create_hashes(cols, &self.random_state, batch_hashes)?;
+        create_hashes(&cols, &self.random_state, batch_hashes)?;
 
         for (row, &hash) in batch_hashes.iter().enumerate() {
             let entry = self.map.get_mut(hash, |(_hash, group_idx)| {
@@ -156,8 +190,11 @@ impl GroupValues for GroupValuesRows {
 
     fn size(&self) -> usize {
         let group_values_size = self.group_values.as_ref().map(|v| v.size()).unwrap_or(0);
+        let interners_size: usize =
+            self.interners.iter().flatten().map(|i| i.size()).sum();
         self.row_converter.size()
             + group_values_size
+            + interners_size
             + self.map_size
             + self.rows_buffer.size()
             + self.hashes_buffer.allocated_size()
@@ -213,6 +250,16 @@ impl GroupValues for GroupValuesRows {
             }
         };
 
+        for (array, interner) in output.iter_mut().zip(&self.interners) {
+            if let Some(interner) = interner {
+                *array = interner.lookup(array)?;
+            }
+        }
+        if matches!(emit_to, EmitTo::All) {
+            // no group references the interned values any more
+            self.interners.iter_mut().flatten().for_each(|i| i.clear());
+        }
+
         // TODO: Materialize dictionaries in group keys (#7647)
         for (field, array) in self.schema.fields.iter().zip(&mut output) {
             let expected = field.data_type();
@@ -242,5 +289,6 @@ impl GroupValues for GroupValuesRows {
         self.map_size = self.map.capacity() * std::mem::size_of::<(u64, usize)>();
         self.hashes_buffer.clear();
         self.hashes_buffer.shrink_to(count);
+        self.interners.iter_mut().flatten().for_each(|i| i.clear());
     }
 }
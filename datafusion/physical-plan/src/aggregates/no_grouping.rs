@@ -74,7 +74,8 @@ impl AggregateStream {
         let agg_schema = Arc::clone(&agg.schema);
         let agg_filter_expr = agg.filter_expr.clone();
 
-        let baseline_metrics = BaselineMetrics::new(&agg.metrics, partition);
+        let baseline_metrics = BaselineMetrics::new(&agg.metrics, partition)
+            .with_sampling_interval(context.session_config().metrics_sampling_interval());
         let input = agg.input.execute(partition, Arc::clone(&context))?;
 
         let aggregate_expressions = aggregate_expressions(&agg.aggr_expr, &agg.mode, 0)?;
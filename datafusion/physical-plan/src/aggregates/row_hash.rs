@@ -27,18 +27,21 @@ use crate::aggregates::{
     evaluate_group_by, evaluate_many, evaluate_optional, group_schema, AggregateMode,
     PhysicalGroupBy,
 };
-use crate::common::IPCWriter;
 use crate::metrics::{BaselineMetrics, RecordOutput};
 use crate::sorts::sort::sort_batch;
 use crate::sorts::streaming_merge;
 use crate::stream::RecordBatchStreamAdapter;
-use crate::{aggregates, read_spill_as_stream, ExecutionPlan, PhysicalExpr};
+use crate::{
+    aggregates, read_spill_as_stream, spill_record_batches_to_file, ExecutionPlan,
+    PhysicalExpr,
+};
 use crate::{RecordBatchStream, SendableRecordBatchStream};
 
 use arrow::array::*;
 use arrow::datatypes::SchemaRef;
 use arrow_schema::SortOptions;
 use datafusion_common::{DataFusionError, Result};
+use datafusion_execution::cancellation::CancellationToken;
 use datafusion_execution::disk_manager::RefCountedTempFile;
 use datafusion_execution::memory_pool::proxy::VecAllocExt;
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
@@ -274,6 +277,10 @@ pub(crate) struct GroupedHashAggregateStream {
     /// the `GroupedHashAggregateStream` operation immediately switches to
     /// output mode and emits all groups.
     group_values_soft_limit: Option<usize>,
+
+    /// Checked before each input batch is aggregated, to stop early when the
+    /// query is cancelled
+    cancellation_token: CancellationToken,
 }
 
 impl GroupedHashAggregateStream {
@@ -290,7 +297,8 @@ impl GroupedHashAggregateStream {
 
         let batch_size = context.session_config().batch_size();
         let input = agg.input.execute(partition, Arc::clone(&context))?;
-        let baseline_metrics = BaselineMetrics::new(&agg.metrics, partition);
+        let baseline_metrics = BaselineMetrics::new(&agg.metrics, partition)
+            .with_sampling_interval(context.session_config().metrics_sampling_interval());
 
         let timer = baseline_metrics.elapsed_compute().timer();
 
@@ -350,7 +358,12 @@ impl GroupedHashAggregateStream {
             ordering.as_slice(),
         )?;
 
-        let group_values = new_group_values(group_schema)?;
+        let intern_string_keys = context
+            .session_config()
+            .options()
+            .execution
+            .intern_string_group_keys;
+        let group_values = new_group_values(group_schema, intern_string_keys)?;
         timer.done();
 
         let exec_state = ExecutionState::ReadingInput;
@@ -383,6 +396,7 @@ impl GroupedHashAggregateStream {
             runtime: context.runtime_env(),
             spill_state,
             group_values_soft_limit: agg.limit,
+            cancellation_token: context.cancellation_token().clone(),
         })
     }
 }
@@ -432,6 +446,7 @@ impl Stream for GroupedHashAggregateStream {
                     match ready!(self.input.poll_next_unpin(cx)) {
                         // new batch to aggregate
                         Some(Ok(batch)) => {
+                            extract_ok!(self.cancellation_token.check());
                             let timer = elapsed_compute.timer();
                             // Make sure we have enough capacity for `batch`, otherwise spill
                             extract_ok!(self.spill_previous_if_necessary(&batch));
@@ -687,8 +702,8 @@ impl GroupedHashAggregateStream {
         let emit = self.emit(EmitTo::All, true)?;
         let sorted = sort_batch(&emit, &self.spill_state.spill_expr, None)?;
         let spillfile = self.runtime.disk_manager.create_tmp_file("HashAggSpill")?;
-        let mut writer = IPCWriter::new(spillfile.path(), &emit.schema())?;
         // TODO: slice large `sorted` and write to multiple files in parallel
+        let mut batches = vec![];
         let mut offset = 0;
         let total_rows = sorted.num_rows();
 
@@ -696,10 +711,10 @@ impl GroupedHashAggregateStream {
             let length = std::cmp::min(total_rows - offset, self.batch_size);
             let batch = sorted.slice(offset, length);
             offset += batch.num_rows();
-            writer.write(&batch)?;
+            batches.push(batch);
         }
 
-        writer.finish()?;
+        spill_record_batches_to_file(batches, &spillfile, emit.schema())?;
         self.spill_state.spills.push(spillfile);
         Ok(())
     }
@@ -144,6 +144,8 @@ impl ExecutionPlan for CoalesceBatchesExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition)
+            .with_sampling_interval(context.session_config().metrics_sampling_interval());
         Ok(Box::pin(CoalesceBatchesStream {
             input: self.input.execute(partition, context)?,
             schema: self.input.schema(),
@@ -151,7 +153,7 @@ impl ExecutionPlan for CoalesceBatchesExec {
             buffer: Vec::new(),
             buffered_rows: 0,
             is_closed: false,
-            baseline_metrics: BaselineMetrics::new(&self.metrics, partition),
+            baseline_metrics,
         }))
     }
 
@@ -48,6 +48,9 @@ pub struct DisplayableExecutionPlan<'a> {
     show_statistics: bool,
     /// If schema should be displayed. See [`Self::set_show_schema`]
     show_schema: bool,
+    /// If output partitioning and ordering should be displayed. See
+    /// [`Self::set_show_properties`]
+    show_properties: bool,
 }
 
 impl<'a> DisplayableExecutionPlan<'a> {
@@ -59,6 +62,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             show_metrics: ShowMetrics::None,
             show_statistics: false,
             show_schema: false,
+            show_properties: false,
         }
     }
 
@@ -71,6 +75,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             show_metrics: ShowMetrics::Aggregated,
             show_statistics: false,
             show_schema: false,
+            show_properties: false,
         }
     }
 
@@ -83,6 +88,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             show_metrics: ShowMetrics::Full,
             show_statistics: false,
             show_schema: false,
+            show_properties: false,
         }
     }
 
@@ -101,6 +107,16 @@ impl<'a> DisplayableExecutionPlan<'a> {
         self
     }
 
+    /// Enable display of the output partitioning and ordering
+    ///
+    /// If true, plans will be displayed with the partitioning of their output
+    /// at the end of each line, and their ordering if they have one. The
+    /// format is `partitioning=Hash([a@0], 4), ordering=[a@0 ASC]`
+    pub fn set_show_properties(mut self, show_properties: bool) -> Self {
+        self.show_properties = show_properties;
+        self
+    }
+
     /// Return a `format`able structure that produces a single line
     /// per node.
     ///
@@ -123,6 +139,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             show_metrics: ShowMetrics,
             show_statistics: bool,
             show_schema: bool,
+            show_properties: bool,
         }
         impl<'a> fmt::Display for Wrapper<'a> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -133,6 +150,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
                     show_metrics: self.show_metrics,
                     show_statistics: self.show_statistics,
                     show_schema: self.show_schema,
+                    show_properties: self.show_properties,
                 };
                 accept(self.plan, &mut visitor)
             }
@@ -143,17 +161,23 @@ impl<'a> DisplayableExecutionPlan<'a> {
             show_metrics: self.show_metrics,
             show_statistics: self.show_statistics,
             show_schema: self.show_schema,
+            show_properties: self.show_properties,
         }
     }
 
     /// Returns a `format`able structure that produces graphviz format for execution plan, which can
     /// be directly visualized [here](https://dreampuf.github.io/GraphvizOnline).
     ///
+    /// Each node is labelled with its operator and its number of output
+    /// partitions. Its metrics (see [`Self::with_metrics`], e.g. after the
+    /// plan has been executed), statistics and output partitioning and
+    /// ordering are shown in its tooltip if enabled.
+    ///
     /// An example is
     /// ```dot
     /// strict digraph dot_plan {
-    //     0[label="ProjectionExec: expr=[id@0 + 2 as employee.id + Int32(2)]",tooltip=""]
-    //     1[label="EmptyExec",tooltip=""]
+    //     0[label="ProjectionExec: expr=[id@0 + 2 as employee.id + Int32(2)], partitions=1",tooltip=""]
+    //     1[label="EmptyExec, partitions=1",tooltip=""]
     //     0 -> 1
     // }
     /// ```
@@ -162,6 +186,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             plan: &'a dyn ExecutionPlan,
             show_metrics: ShowMetrics,
             show_statistics: bool,
+            show_properties: bool,
         }
         impl<'a> fmt::Display for Wrapper<'a> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -172,6 +197,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
                     t,
                     show_metrics: self.show_metrics,
                     show_statistics: self.show_statistics,
+                    show_properties: self.show_properties,
                     graphviz_builder: GraphvizBuilder::default(),
                     parents: Vec::new(),
                 };
@@ -189,6 +215,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             plan: self.inner,
             show_metrics: self.show_metrics,
             show_statistics: self.show_statistics,
+            show_properties: self.show_properties,
         }
     }
 
@@ -200,6 +227,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             show_metrics: ShowMetrics,
             show_statistics: bool,
             show_schema: bool,
+            show_properties: bool,
         }
 
         impl<'a> fmt::Display for Wrapper<'a> {
@@ -211,6 +239,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
                     show_metrics: self.show_metrics,
                     show_statistics: self.show_statistics,
                     show_schema: self.show_schema,
+                    show_properties: self.show_properties,
                 };
                 visitor.pre_visit(self.plan)?;
                 Ok(())
@@ -222,6 +251,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             show_metrics: self.show_metrics,
             show_statistics: self.show_statistics,
             show_schema: self.show_schema,
+            show_properties: self.show_properties,
         }
     }
 
@@ -265,6 +295,8 @@ struct IndentVisitor<'a, 'b> {
     show_statistics: bool,
     /// If schema should be displayed
     show_schema: bool,
+    /// If output partitioning and ordering should be displayed
+    show_properties: bool,
 }
 
 impl<'a, 'b> ExecutionPlanVisitor for IndentVisitor<'a, 'b> {
@@ -298,6 +330,17 @@ impl<'a, 'b> ExecutionPlanVisitor for IndentVisitor<'a, 'b> {
             let stats = plan.statistics().map_err(|_e| fmt::Error)?;
             write!(self.f, ", statistics=[{}]", stats)?;
         }
+        if self.show_properties {
+            let properties = plan.properties();
+            write!(
+                self.f,
+                ", partitioning={}",
+                properties.output_partitioning()
+            )?;
+            if let Some(ordering) = properties.output_ordering() {
+                write!(self.f, ", ordering={}", OutputOrderingDisplay(ordering))?;
+            }
+        }
         if self.show_schema {
             write!(
                 self.f,
@@ -324,6 +367,8 @@ struct GraphvizVisitor<'a, 'b> {
     show_metrics: ShowMetrics,
     /// If statistics should be displayed
     show_statistics: bool,
+    /// If output partitioning and ordering should be displayed
+    show_properties: bool,
 
     graphviz_builder: GraphvizBuilder,
     /// Used to record parent node ids when visiting a plan.
@@ -354,7 +399,12 @@ impl ExecutionPlanVisitor for GraphvizVisitor<'_, '_> {
             }
         }
 
-        let label = { format!("{}", Wrapper(plan, self.t)) };
+        let properties = plan.properties();
+        let label = format!(
+            "{}, partitions={}",
+            Wrapper(plan, self.t),
+            properties.output_partitioning().partition_count()
+        );
 
         let metrics = match self.show_metrics {
             ShowMetrics::None => "".to_string(),
@@ -386,18 +436,17 @@ impl ExecutionPlanVisitor for GraphvizVisitor<'_, '_> {
             "".to_string()
         };
 
-        let delimiter = if !metrics.is_empty() && !statistics.is_empty() {
-            ", "
-        } else {
-            ""
-        };
+        let mut tooltip = vec![metrics, statistics];
+        if self.show_properties {
+            tooltip.push(format!("partitioning={}", properties.output_partitioning()));
+            if let Some(ordering) = properties.output_ordering() {
+                tooltip.push(format!("ordering={}", OutputOrderingDisplay(ordering)));
+            }
+        }
+        tooltip.retain(|s| !s.is_empty());
 
-        self.graphviz_builder.add_node(
-            self.f,
-            id,
-            &label,
-            Some(&format!("{}{}{}", metrics, delimiter, statistics)),
-        )?;
+        self.graphviz_builder
+            .add_node(self.f, id, &label, Some(&tooltip.join(", ")))?;
 
         if let Some(parent_node_id) = self.parents.last() {
             self.graphviz_builder
@@ -508,8 +557,14 @@ mod tests {
     use datafusion_common::{DataFusionError, Result, Statistics};
     use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 
+    use crate::expressions::col;
+    use crate::memory::MemoryExec;
     use crate::{DisplayAs, ExecutionPlan, PlanProperties};
 
+    use arrow::compute::SortOptions;
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion_physical_expr::PhysicalSortExpr;
+
     use super::DisplayableExecutionPlan;
 
     #[derive(Debug, Clone, Copy)]
@@ -613,4 +668,24 @@ mod tests {
     fn test_display_when_stats_ok_with_show_stats() {
         test_stats_display(TestStatsExecPlan::Ok, false);
     }
+
+    #[test]
+    fn test_display_properties() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let sort_expr = PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        };
+        let exec = MemoryExec::try_new(&[vec![], vec![]], schema, None)?
+            .with_sort_information(vec![vec![sort_expr]]);
+
+        let display = DisplayableExecutionPlan::new(&exec).set_show_properties(true);
+        let line = display.one_line().to_string();
+        assert!(
+            line.trim()
+                .ends_with(", partitioning=UnknownPartitioning(2), ordering=[a@0 ASC]"),
+            "{line}"
+        );
+        Ok(())
+    }
 }
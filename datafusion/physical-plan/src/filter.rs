@@ -269,7 +269,8 @@ impl ExecutionPlan for FilterExec {
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
         trace!("Start FilterExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
-        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition)
+            .with_sampling_interval(context.session_config().metrics_sampling_interval());
         Ok(Box::pin(FilterExecStream {
             schema: self.input.schema(),
             predicate: self.predicate.clone(),
@@ -0,0 +1,338 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the [`InstrumentedExec`] operator, which traces the execution of
+//! another operator with the [`tracing`] crate. Requires the `tracing` feature.
+
+use std::any::Any;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+use std::task::{Context, Poll};
+
+use crate::metrics::{Label, Metric, MetricsSet};
+use crate::work_table::WorkTableExec;
+use crate::{
+    DisplayAs, DisplayFormatType, Distribution, ExecutionPlan, PlanProperties,
+    RecordBatchStream, SendableRecordBatchStream,
+};
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_common::{Result, Statistics};
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::PhysicalSortRequirement;
+use futures::{Stream, StreamExt};
+use tracing::{info_span, Span};
+
+/// Wraps an [`ExecutionPlan`] so that each of its partitions executes within
+/// a [`tracing`] span.
+///
+/// The span is named `execute` and records the name of the operator, the
+/// partition and the [`TaskContext::task_id`]. It is entered whenever the
+/// output stream of the partition is polled, and closes when the stream is
+/// dropped, after an event with the metrics of the partition is emitted.
+/// Subscribers such as `tracing-opentelemetry` can then export the execution
+/// of a query as a trace. Once executed, the metrics of the wrapped operator
+/// also carry a `task_id` label.
+///
+/// The wrapper is transparent: it has the properties, children and display of
+/// the wrapped operator. Use [`instrument_plan`] to wrap every operator of a
+/// plan, or set `datafusion.execution.enable_tracing` to instrument the plans
+/// executed with [`execute_stream`] and [`execute_stream_partitioned`].
+///
+/// [`execute_stream`]: crate::execute_stream
+/// [`execute_stream_partitioned`]: crate::execute_stream_partitioned
+#[derive(Debug)]
+pub struct InstrumentedExec {
+    inner: Arc<dyn ExecutionPlan>,
+    /// The task id of the context the operator was first executed with
+    task_id: OnceLock<String>,
+}
+
+impl InstrumentedExec {
+    /// Create a new [`InstrumentedExec`] that traces the execution of `inner`
+    pub fn new(inner: Arc<dyn ExecutionPlan>) -> Self {
+        Self {
+            inner,
+            task_id: OnceLock::new(),
+        }
+    }
+
+    /// The wrapped operator
+    pub fn inner(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.inner
+    }
+}
+
+impl DisplayAs for InstrumentedExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        self.inner.fmt_as(t, f)
+    }
+}
+
+impl ExecutionPlan for InstrumentedExec {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        self.inner.properties()
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        self.inner.required_input_distribution()
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<Vec<PhysicalSortRequirement>>> {
+        self.inner.required_input_ordering()
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        self.inner.maintains_input_order()
+    }
+
+    fn benefits_from_input_partitioning(&self) -> Vec<bool> {
+        self.inner.benefits_from_input_partitioning()
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        self.inner.children()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let inner = Arc::clone(&self.inner).with_new_children(children)?;
+        Ok(Arc::new(Self::new(inner)))
+    }
+
+    fn repartitioned(
+        &self,
+        target_partitions: usize,
+        config: &ConfigOptions,
+    ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        Ok(self
+            .inner
+            .repartitioned(target_partitions, config)?
+            .map(|inner| Arc::new(Self::new(inner)) as _))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let task_id = context.task_id();
+        if let Some(task_id) = &task_id {
+            self.task_id.get_or_init(|| task_id.clone());
+        }
+        let span = info_span!(
+            "execute",
+            operator = self.inner.name(),
+            partition,
+            task_id = task_id.as_deref()
+        );
+        let inner = span.in_scope(|| self.inner.execute(partition, context))?;
+        Ok(Box::pin(InstrumentedStream {
+            inner,
+            span,
+            plan: Arc::clone(&self.inner),
+            partition,
+        }))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        let metrics = self.inner.metrics()?;
+        let Some(task_id) = self.task_id.get() else {
+            return Some(metrics);
+        };
+        let mut labelled = MetricsSet::new();
+        for metric in metrics.iter() {
+            let mut labels = metric.labels().to_vec();
+            // the spill metrics are already labelled
+            if !labels.iter().any(|label| label.name() == "task_id") {
+                labels.push(Label::new("task_id", task_id.clone()));
+            }
+            labelled.push(Arc::new(Metric::new_with_labels(
+                metric.value().clone(),
+                metric.partition(),
+                labels,
+            )));
+        }
+        Some(labelled)
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        self.inner.statistics()
+    }
+}
+
+/// Wraps every operator of `plan` in an [`InstrumentedExec`]
+pub fn instrument_plan(plan: Arc<dyn ExecutionPlan>) -> Result<Arc<dyn ExecutionPlan>> {
+    plan.transform_up(|plan| {
+        // `RecursiveQueryExec` finds its work table by downcasting
+        if plan.as_any().is::<InstrumentedExec>() || plan.as_any().is::<WorkTableExec>() {
+            return Ok(Transformed::no(plan));
+        }
+        Ok(Transformed::yes(Arc::new(InstrumentedExec::new(plan)) as _))
+    })
+    .data()
+}
+
+/// The output stream of a partition of an [`InstrumentedExec`]
+struct InstrumentedStream {
+    inner: SendableRecordBatchStream,
+    span: Span,
+    plan: Arc<dyn ExecutionPlan>,
+    partition: usize,
+}
+
+impl Stream for InstrumentedStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let _entered = this.span.enter();
+        this.inner.poll_next_unpin(cx)
+    }
+}
+
+impl RecordBatchStream for InstrumentedStream {
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+impl Drop for InstrumentedStream {
+    fn drop(&mut self) {
+        let Some(metrics) = self.plan.metrics() else {
+            return;
+        };
+        let mut partition_metrics = MetricsSet::new();
+        metrics
+            .iter()
+            .filter(|metric| metric.partition() == Some(self.partition))
+            .for_each(|metric| partition_metrics.push(Arc::clone(metric)));
+        let partition_metrics = partition_metrics
+            .aggregate_by_name()
+            .sorted_for_display()
+            .timestamps_removed();
+        self.span.in_scope(
+            || tracing::info!(metrics = %partition_metrics, "partition completed"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::coalesce_partitions::CoalescePartitionsExec;
+    use crate::memory::MemoryExec;
+    use crate::{collect, get_plan_string};
+
+    use std::collections::HashMap;
+
+    use arrow::array::Int32Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_execution::config::SessionConfig;
+    use datafusion_execution::runtime_env::RuntimeEnv;
+
+    #[tokio::test]
+    async fn instrumented_plan_is_transparent() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let memory = Arc::new(MemoryExec::try_new(
+            &[vec![batch.clone()], vec![batch]],
+            schema,
+            None,
+        )?);
+        let plan: Arc<dyn ExecutionPlan> = Arc::new(CoalescePartitionsExec::new(memory));
+
+        let instrumented = instrument_plan(Arc::clone(&plan))?;
+        assert!(instrumented.as_any().is::<InstrumentedExec>());
+        assert!(instrumented.children()[0].as_any().is::<InstrumentedExec>());
+        assert_eq!(get_plan_string(&instrumented), get_plan_string(&plan));
+
+        // instrumenting a plan twice does not wrap its operators again
+        let twice = instrument_plan(Arc::clone(&instrumented))?;
+        let inner = twice
+            .as_any()
+            .downcast_ref::<InstrumentedExec>()
+            .unwrap()
+            .inner();
+        assert!(!inner.as_any().is::<InstrumentedExec>());
+
+        let batches = collect(instrumented, Arc::new(TaskContext::default())).await?;
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn metrics_are_labelled_with_task_id() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let memory = Arc::new(MemoryExec::try_new(&[vec![batch]], schema, None)?);
+        let plan = instrument_plan(Arc::new(CoalescePartitionsExec::new(memory)))?;
+
+        // no label before the plan is executed
+        let metrics = plan.metrics().unwrap();
+        assert!(metrics.iter().all(|m| m.labels().is_empty()));
+
+        let context = TaskContext::new(
+            Some("task-1".to_string()),
+            "session".to_string(),
+            SessionConfig::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            Arc::new(RuntimeEnv::default()),
+        );
+        collect(Arc::clone(&plan), Arc::new(context)).await?;
+
+        let metrics = plan.metrics().unwrap();
+        assert!(metrics.output_rows().is_some());
+        for metric in metrics.iter() {
+            assert_eq!(
+                metric.labels(),
+                &[Label::new("task_id", "task-1")],
+                "{metric}"
+            );
+        }
+        Ok(())
+    }
+}
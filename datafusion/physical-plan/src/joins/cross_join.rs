@@ -152,6 +152,7 @@ async fn load_left_input(
     } else {
         left
     };
+    let cancellation_token = context.cancellation_token().clone();
     let stream = merge.execute(0, context)?;
 
     // Load all batches and count the rows
@@ -159,6 +160,7 @@ async fn load_left_input(
         .try_fold(
             (Vec::new(), 0usize, metrics, reservation),
             |mut acc, batch| async {
+                cancellation_token.check()?;
                 let batch_size = batch.get_array_memory_size();
                 // Reserve memory for incoming batch
                 acc.3.try_grow(batch_size)?;
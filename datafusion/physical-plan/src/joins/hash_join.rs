@@ -37,7 +37,7 @@ use crate::{
     joins::utils::{
         adjust_indices_by_join_type, apply_join_filter_to_indices,
         build_batch_from_indices, build_join_schema, check_join_is_valid,
-        estimate_join_statistics, get_final_indices_from_bit_map,
+        estimate_join_statistics, get_final_indices_from_bit_map, join_equal_conditions,
         need_produce_result_in_final, symmetric_join_output_partitioning,
         BuildProbeJoinMetrics, ColumnIndex, JoinFilter, JoinHashMap, JoinHashMapOffset,
         JoinHashMapType, JoinOn, JoinOnRef, StatefulStreamResult,
@@ -50,8 +50,9 @@ use crate::{
 
 use arrow::array::{
     Array, ArrayRef, BooleanArray, BooleanBufferBuilder, PrimitiveArray, UInt32Array,
-    UInt64Array,
+    UInt32BufferBuilder, UInt64Array, UInt64BufferBuilder,
 };
+use arrow::buffer::NullBuffer;
 use arrow::compute::kernels::cmp::{eq, not_distinct};
 use arrow::compute::{and, concat_batches, take, FilterBuilder};
 use arrow::datatypes::{Schema, SchemaRef};
@@ -60,6 +61,7 @@ use arrow::util::bit_util;
 use arrow_array::cast::downcast_array;
 use arrow_schema::ArrowError;
 use datafusion_common::utils::memory::estimate_memory_size;
+use datafusion_common::utils::proxy::VecAllocExt;
 use datafusion_common::{
     internal_datafusion_err, internal_err, plan_err, project_schema, DataFusionError,
     JoinSide, JoinType, Result,
@@ -76,6 +78,7 @@ use ahash::RandomState;
 use datafusion_expr::Operator;
 use datafusion_physical_expr_common::datum::compare_op_for_nested;
 use futures::{ready, Stream, StreamExt, TryStreamExt};
+use hashbrown::raw::RawTable;
 use parking_lot::Mutex;
 
 type SharedBitmapBuilder = Mutex<BooleanBufferBuilder>;
@@ -86,6 +89,9 @@ struct JoinLeftData {
     hash_map: JoinHashMap,
     /// The input rows for the build side
     batch: RecordBatch,
+    /// The build side join key values of `batch`, evaluated once so that
+    /// probing does not evaluate them again for every output batch
+    values: Vec<ArrayRef>,
     /// Shared bitmap builder for visited left indices
     visited_indices_bitmap: Mutex<BooleanBufferBuilder>,
     /// Counter of running probe-threads, potentially
@@ -102,6 +108,7 @@ impl JoinLeftData {
     fn new(
         hash_map: JoinHashMap,
         batch: RecordBatch,
+        values: Vec<ArrayRef>,
         visited_indices_bitmap: SharedBitmapBuilder,
         probe_threads_counter: AtomicUsize,
         reservation: MemoryReservation,
@@ -109,6 +116,7 @@ impl JoinLeftData {
         Self {
             hash_map,
             batch,
+            values,
             visited_indices_bitmap,
             probe_threads_counter,
             reservation,
@@ -125,6 +133,11 @@ impl JoinLeftData {
         &self.batch
     }
 
+    /// returns a reference to the build side join key values
+    fn values(&self) -> &[ArrayRef] {
+        &self.values
+    }
+
     /// returns a reference to the visited indices bitmap
     fn visited_indices_bitmap(&self) -> &SharedBitmapBuilder {
         &self.visited_indices_bitmap
@@ -370,6 +383,7 @@ impl HashJoinExec {
             join_schema.clone(),
             *join_type,
             &on,
+            filter.as_ref(),
             partition_mode,
             projection.as_ref(),
         )?;
@@ -479,10 +493,13 @@ impl HashJoinExec {
         schema: SchemaRef,
         join_type: JoinType,
         on: JoinOnRef,
+        filter: Option<&JoinFilter>,
         mode: PartitionMode,
         projection: Option<&Vec<usize>>,
     ) -> Result<PlanProperties> {
         // Calculate equivalence properties:
+        let equal_conditions =
+            join_equal_conditions(on, filter, &left.schema(), &right.schema());
         let mut eq_properties = join_equivalence_properties(
             left.equivalence_properties().clone(),
             right.equivalence_properties().clone(),
@@ -490,7 +507,7 @@ impl HashJoinExec {
             schema.clone(),
             &Self::maintains_input_order(join_type),
             Some(Self::probe_side()),
-            on,
+            &equal_conditions,
         );
 
         let mut output_partitioning = match mode {
@@ -733,7 +750,7 @@ impl ExecutionPlan for HashJoinExec {
                     Some(partition),
                     self.random_state.clone(),
                     self.left.clone(),
-                    on_left.clone(),
+                    on_left,
                     context.clone(),
                     join_metrics.clone(),
                     reservation,
@@ -750,6 +767,15 @@ impl ExecutionPlan for HashJoinExec {
         };
 
         let batch_size = context.session_config().batch_size();
+        let key_groups_reservation = context
+            .session_config()
+            .options()
+            .execution
+            .hash_join_group_duplicate_keys
+            .then(|| {
+                MemoryConsumer::new(format!("HashJoinKeyGroups[{partition}]"))
+                    .register(context.memory_pool())
+            });
 
         // we have the batches and the hash map with their keys. We can how create a stream
         // over the right that uses this information to issue new batches.
@@ -766,7 +792,6 @@ impl ExecutionPlan for HashJoinExec {
 
         Ok(Box::pin(HashJoinStream {
             schema: self.schema(),
-            on_left,
             on_right,
             filter: self.filter.clone(),
             join_type: self.join_type,
@@ -779,6 +804,7 @@ impl ExecutionPlan for HashJoinExec {
             build_side: BuildSide::Initial(BuildSideInitialState { left_fut }),
             batch_size,
             hashes_buffer: vec![],
+            key_groups_reservation,
         }))
     }
 
@@ -841,9 +867,11 @@ async fn collect_left_input(
     // This operation performs 2 steps at once:
     // 1. creates a [JoinHashMap] of all batches from the stream
     // 2. stores the batches in a vector.
+    let cancellation_token = context.cancellation_token();
     let initial = (Vec::new(), 0, metrics, reservation);
     let (batches, num_rows, metrics, mut reservation) = stream
         .try_fold(initial, |mut acc, batch| async {
+            cancellation_token.check()?;
             let batch_size = batch.get_array_memory_size();
             // Reserve memory for incoming batch
             acc.3.try_grow(batch_size)?;
@@ -892,6 +920,23 @@ async fn collect_left_input(
     // Merge all batches into a single batch, so we can directly index into the arrays
     let single_batch = concat_batches(&schema, batches_iter)?;
 
+    // Evaluate the join keys once. Keys that are plain columns share the
+    // buffers of `single_batch` and need no additional memory
+    let left_values = on_left
+        .iter()
+        .map(|c| {
+            c.evaluate(&single_batch)?
+                .into_array(single_batch.num_rows())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let values_size = left_values
+        .iter()
+        .filter(|v| !single_batch.columns().iter().any(|c| Arc::ptr_eq(c, v)))
+        .map(|v| v.get_array_memory_size())
+        .sum::<usize>();
+    reservation.try_grow(values_size)?;
+    metrics.build_mem_used.add(values_size);
+
     // Reserve additional memory for visited indices bitmap and create shared builder
     let visited_indices_bitmap = if with_visited_indices_bitmap {
         let bitmap_size = bit_util::ceil(single_batch.num_rows(), 8);
@@ -908,6 +953,7 @@ async fn collect_left_input(
     let data = JoinLeftData::new(
         hashmap,
         single_batch,
+        left_values,
         Mutex::new(visited_indices_bitmap),
         AtomicUsize::new(probe_threads_count),
         reservation,
@@ -1055,10 +1101,15 @@ impl HashJoinStreamState {
 struct ProcessProbeBatchState {
     /// Current probe-side batch
     batch: RecordBatch,
+    /// Probe-side join key values of `batch`
+    values: Vec<ArrayRef>,
     /// Starting offset for JoinHashMap lookups
     offset: JoinHashMapOffset,
     /// Max joined probe-side index from current batch
     joined_probe_idx: Option<usize>,
+    /// Build-side matches of the distinct join keys of `batch`, if duplicate
+    /// keys are grouped
+    key_groups: Option<ProbeKeyGroups>,
 }
 
 impl ProcessProbeBatchState {
@@ -1081,8 +1132,6 @@ impl ProcessProbeBatchState {
 struct HashJoinStream {
     /// Input schema
     schema: Arc<Schema>,
-    /// equijoin columns from the left (build side)
-    on_left: Vec<PhysicalExprRef>,
     /// equijoin columns from the right (probe side)
     on_right: Vec<PhysicalExprRef>,
     /// optional join filter
@@ -1107,6 +1156,9 @@ struct HashJoinStream {
     batch_size: usize,
     /// Scratch space for computing hashes
     hashes_buffer: Vec<u64>,
+    /// Memory reservation for the probe key groups, set if duplicate keys are
+    /// grouped (see [`ProbeKeyGroups`])
+    key_groups_reservation: Option<MemoryReservation>,
 }
 
 impl RecordBatchStream for HashJoinStream {
@@ -1163,30 +1215,15 @@ impl RecordBatchStream for HashJoinStream {
 /// Build indices: 4, 5, 6, 6
 /// Probe indices: 3, 3, 4, 5
 /// ```
-#[allow(clippy::too_many_arguments)]
 fn lookup_join_hashmap(
     build_hashmap: &JoinHashMap,
-    build_input_buffer: &RecordBatch,
-    probe_batch: &RecordBatch,
-    build_on: &[PhysicalExprRef],
-    probe_on: &[PhysicalExprRef],
+    build_join_values: &[ArrayRef],
+    probe_join_values: &[ArrayRef],
     null_equals_null: bool,
     hashes_buffer: &[u64],
     limit: usize,
     offset: JoinHashMapOffset,
 ) -> Result<(UInt64Array, UInt32Array, Option<JoinHashMapOffset>)> {
-    let keys_values = probe_on
-        .iter()
-        .map(|c| c.evaluate(probe_batch)?.into_array(probe_batch.num_rows()))
-        .collect::<Result<Vec<_>>>()?;
-    let build_join_values = build_on
-        .iter()
-        .map(|c| {
-            c.evaluate(build_input_buffer)?
-                .into_array(build_input_buffer.num_rows())
-        })
-        .collect::<Result<Vec<_>>>()?;
-
     let (mut probe_builder, mut build_builder, next_offset) = build_hashmap
         .get_matched_indices_with_limit_offset(hashes_buffer, None, limit, offset);
 
@@ -1198,14 +1235,210 @@ fn lookup_join_hashmap(
     let (build_indices, probe_indices) = equal_rows_arr(
         &build_indices,
         &probe_indices,
-        &build_join_values,
-        &keys_values,
+        build_join_values,
+        probe_join_values,
         null_equals_null,
     )?;
 
     Ok((build_indices, probe_indices, next_offset))
 }
 
+/// The build-side matches of the distinct join keys of a probe batch, used
+/// when `datafusion.execution.hash_join_group_duplicate_keys` is enabled.
+///
+/// The probe rows sharing a join key form a group, whose build-side matches
+/// are looked up and compared once, instead of once per probe row. The
+/// combinations of the rows of a group and its matches are then streamed in
+/// batches by [`Self::matched_indices`], so a key with many duplicates on both
+/// sides only holds its matching build-side indices in memory, which are at
+/// most one per build-side row.
+struct ProbeKeyGroups {
+    /// The group of each probe row, `None` for rows which can not match
+    row_groups: Vec<Option<u32>>,
+    /// The matching build-side indices of all groups, in group order
+    build_indices: Vec<u64>,
+    /// The start of the matches of each group in `build_indices`, followed by
+    /// the end of the matches of the last group
+    offsets: Vec<usize>,
+}
+
+impl ProbeKeyGroups {
+    fn try_new(
+        build_hashmap: &JoinHashMap,
+        build_join_values: &[ArrayRef],
+        probe_join_values: &[ArrayRef],
+        hashes_buffer: &[u64],
+        null_equals_null: bool,
+    ) -> Result<Self> {
+        let num_rows = hashes_buffer.len();
+
+        // Rows with a null key never match unless nulls are equal
+        let nulls = if null_equals_null {
+            None
+        } else {
+            probe_join_values.iter().fold(None, |nulls, values| {
+                NullBuffer::union(nulls.as_ref(), values.logical_nulls().as_ref())
+            })
+        };
+        let can_match = |row: usize| nulls.as_ref().map_or(true, |n| n.is_valid(row));
+
+        // The first row of each hash represents its group, the other rows
+        // with the same hash are candidates to join that group
+        let mut first_rows: RawTable<(u64, u32)> = RawTable::with_capacity(num_rows);
+        let mut candidate_reps = UInt64BufferBuilder::new(0);
+        let mut candidate_rows = UInt32BufferBuilder::new(0);
+        for (row, hash) in hashes_buffer.iter().enumerate() {
+            if !can_match(row) {
+                continue;
+            }
+            match first_rows
+                .get(*hash, |(h, _)| *h == *hash)
+                .map(|(_, rep)| *rep)
+            {
+                Some(rep) => {
+                    candidate_reps.append(rep as u64);
+                    candidate_rows.append(row as u32);
+                }
+                None => {
+                    first_rows.insert(*hash, (*hash, row as u32), |(h, _)| *h);
+                }
+            }
+        }
+        drop(first_rows);
+
+        // Candidates join the group of their representative if their keys are
+        // equal, and otherwise (on hash collisions) start groups of their own
+        let (reps, rows) = equal_rows_arr(
+            &PrimitiveArray::new(candidate_reps.finish().into(), None),
+            &PrimitiveArray::new(candidate_rows.finish().into(), None),
+            probe_join_values,
+            probe_join_values,
+            null_equals_null,
+        )?;
+        let mut row_reps = vec![None; num_rows];
+        rows.values()
+            .iter()
+            .zip(reps.values().iter())
+            .for_each(|(row, rep)| row_reps[*row as usize] = Some(*rep as usize));
+
+        let mut row_groups = vec![None; num_rows];
+        let mut group_reps = UInt32BufferBuilder::new(0);
+        let mut num_groups = 0;
+        for row in (0..num_rows).filter(|row| can_match(*row)) {
+            row_groups[row] = match row_reps[row] {
+                Some(rep) => row_groups[rep],
+                None => {
+                    group_reps.append(row as u32);
+                    num_groups += 1;
+                    Some(num_groups - 1)
+                }
+            };
+        }
+        drop(row_reps);
+
+        // Look up the build-side matches of each group's representative
+        let hash_map = build_hashmap.get_map();
+        let next_chain = build_hashmap.get_list();
+        let group_reps = group_reps.finish();
+        let mut probe_indices = UInt32BufferBuilder::new(0);
+        let mut build_indices = UInt64BufferBuilder::new(0);
+        for rep in group_reps.typed_data::<u32>() {
+            let hash = hashes_buffer[*rep as usize];
+            if let Some((_, index)) = hash_map.get(hash, |(h, _)| *h == hash) {
+                let mut i = *index;
+                while i != 0 {
+                    probe_indices.append(*rep);
+                    build_indices.append(i - 1);
+                    i = next_chain[i as usize - 1];
+                }
+            }
+        }
+        let (build_indices, probe_indices) = equal_rows_arr(
+            &PrimitiveArray::new(build_indices.finish().into(), None),
+            &PrimitiveArray::new(probe_indices.finish().into(), None),
+            build_join_values,
+            probe_join_values,
+            null_equals_null,
+        )?;
+
+        // The matches are in group order, so each group's matches are
+        // contiguous
+        let mut offsets = vec![0; num_groups as usize + 1];
+        for rep in probe_indices.values() {
+            if let Some(group) = row_groups[*rep as usize] {
+                offsets[group as usize + 1] += 1;
+            }
+        }
+        for group in 0..num_groups as usize {
+            offsets[group + 1] += offsets[group];
+        }
+
+        Ok(Self {
+            row_groups,
+            build_indices: build_indices.values().to_vec(),
+            offsets,
+        })
+    }
+
+    /// Memory used by the groups
+    fn size(&self) -> usize {
+        self.row_groups.allocated_size()
+            + self.build_indices.allocated_size()
+            + self.offsets.allocated_size()
+    }
+
+    /// Returns up to `limit` pairs of matching build and probe indices, in
+    /// probe row order, starting at `offset`, along with the (optional)
+    /// starting point for the next iteration. The offset holds the probe row
+    /// and the position in the matches of its group to continue from.
+    fn matched_indices(
+        &self,
+        limit: usize,
+        offset: JoinHashMapOffset,
+    ) -> (UInt64Array, UInt32Array, Option<JoinHashMapOffset>) {
+        let mut build_indices = UInt64BufferBuilder::new(limit);
+        let mut probe_indices = UInt32BufferBuilder::new(limit);
+        let mut remaining = limit;
+        let mut next_offset = None;
+
+        let (initial_row, initial_position) = offset;
+        let num_rows = self.row_groups.len();
+        for row in initial_row..num_rows {
+            let Some(group) = self.row_groups[row] else {
+                continue;
+            };
+            let mut start = self.offsets[group as usize];
+            if row == initial_row {
+                start += initial_position.unwrap_or(0) as usize;
+            }
+            let end = self.offsets[group as usize + 1];
+            let len = (end - start).min(remaining);
+
+            build_indices.append_slice(&self.build_indices[start..start + len]);
+            probe_indices.append_n(len, row as u32);
+            remaining -= len;
+
+            if remaining == 0 {
+                let position = start + len - self.offsets[group as usize];
+                next_offset = if start + len < end {
+                    Some((row, Some(position as u64)))
+                } else if row + 1 < num_rows {
+                    Some((row + 1, None))
+                } else {
+                    None
+                };
+                break;
+            }
+        }
+
+        (
+            PrimitiveArray::new(build_indices.finish().into(), None),
+            PrimitiveArray::new(probe_indices.finish().into(), None),
+            next_offset,
+        )
+    }
+}
+
 // version of eq_dyn supporting equality on null arrays
 fn eq_dyn_null(
     left: &dyn Array,
@@ -1332,6 +1565,9 @@ impl HashJoinStream {
     ) -> Poll<Result<StatefulStreamResult<Option<RecordBatch>>>> {
         match ready!(self.right.poll_next_unpin(cx)) {
             None => {
+                if let Some(reservation) = &mut self.key_groups_reservation {
+                    reservation.free();
+                }
                 self.state = HashJoinStreamState::ExhaustedProbeSide;
             }
             Some(Ok(batch)) => {
@@ -1346,14 +1582,32 @@ impl HashJoinStream {
                 self.hashes_buffer.resize(batch.num_rows(), 0);
                 create_hashes(&keys_values, &self.random_state, &mut self.hashes_buffer)?;
 
+                let key_groups = match &mut self.key_groups_reservation {
+                    Some(reservation) => {
+                        let left_data = &self.build_side.try_as_ready()?.left_data;
+                        let key_groups = ProbeKeyGroups::try_new(
+                            left_data.hash_map(),
+                            left_data.values(),
+                            &keys_values,
+                            &self.hashes_buffer,
+                            self.null_equals_null,
+                        )?;
+                        reservation.try_resize(key_groups.size())?;
+                        Some(key_groups)
+                    }
+                    None => None,
+                };
+
                 self.join_metrics.input_batches.add(1);
                 self.join_metrics.input_rows.add(batch.num_rows());
 
                 self.state =
                     HashJoinStreamState::ProcessProbeBatch(ProcessProbeBatchState {
                         batch,
+                        values: keys_values,
                         offset: (0, None),
                         joined_probe_idx: None,
+                        key_groups,
                     });
             }
             Some(Err(err)) => return Poll::Ready(Err(err)),
@@ -1374,17 +1628,18 @@ impl HashJoinStream {
         let timer = self.join_metrics.join_time.timer();
 
         // get the matched by join keys indices
-        let (left_indices, right_indices, next_offset) = lookup_join_hashmap(
-            build_side.left_data.hash_map(),
-            build_side.left_data.batch(),
-            &state.batch,
-            &self.on_left,
-            &self.on_right,
-            self.null_equals_null,
-            &self.hashes_buffer,
-            self.batch_size,
-            state.offset,
-        )?;
+        let (left_indices, right_indices, next_offset) = match &state.key_groups {
+            Some(key_groups) => key_groups.matched_indices(self.batch_size, state.offset),
+            None => lookup_join_hashmap(
+                build_side.left_data.hash_map(),
+                build_side.left_data.values(),
+                &state.values,
+                self.null_equals_null,
+                &self.hashes_buffer,
+                self.batch_size,
+                state.offset,
+            )?,
+        };
 
         // apply join filter if exists
         let (left_indices, right_indices) = if let Some(filter) = &self.filter {
@@ -1555,7 +1810,7 @@ mod tests {
     use datafusion_expr::Operator;
     use datafusion_physical_expr::expressions::{BinaryExpr, Literal};
 
-    use hashbrown::raw::RawTable;
+    use arrow::util::pretty::pretty_format_batches;
     use rstest::*;
     use rstest_reuse::*;
 
@@ -3844,6 +4099,153 @@ mod tests {
         Ok(())
     }
 
+    fn group_duplicate_keys_task_ctx(
+        batch_size: usize,
+        group_duplicate_keys: bool,
+    ) -> TaskContext {
+        let mut session_config = SessionConfig::default().with_batch_size(batch_size);
+        session_config
+            .options_mut()
+            .execution
+            .hash_join_group_duplicate_keys = group_duplicate_keys;
+        TaskContext::default().with_session_config(session_config)
+    }
+
+    #[tokio::test]
+    async fn join_grouped_duplicate_keys_under_memory_limit() -> Result<()> {
+        // every row of both sides has the same key, so the join produces the
+        // cartesian product of 1000 x 1000 rows
+        let left = build_table(
+            ("a1", &vec![1; 1000]),
+            ("b1", &(0..1000).collect()),
+            ("c1", &vec![0; 1000]),
+        );
+        let right = build_table(
+            ("a2", &vec![1; 1000]),
+            ("b2", &(0..1000).collect()),
+            ("c2", &vec![0; 1000]),
+        );
+        let on = vec![(
+            Arc::new(Column::new_with_schema("a1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("a2", &right.schema())?) as _,
+        )];
+
+        let runtime_config = RuntimeConfig::new().with_memory_limit(256 * 1024, 1.0);
+        let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
+        let task_ctx =
+            group_duplicate_keys_task_ctx(4096, true).with_runtime(runtime.clone());
+
+        let join = join(left, right, on, &JoinType::Inner, false)?;
+        let mut stream = join.execute(0, Arc::new(task_ctx))?;
+
+        let mut num_rows = 0;
+        while let Some(batch) = stream.next().await {
+            let batch = batch?;
+            assert!(batch.num_rows() <= 4096);
+            num_rows += batch.num_rows();
+        }
+        assert_eq!(num_rows, 1_000_000);
+
+        drop(stream);
+        drop(join);
+        assert_eq!(runtime.memory_pool.reserved(), 0);
+        Ok(())
+    }
+
+    /// Returns a table whose join key has many duplicates and nulls, with
+    /// the non-null keys ranging from 0 to `num_keys - 1`
+    fn build_duplicate_keys_table(
+        a: &str,
+        b: &str,
+        c: &str,
+        num_keys: i32,
+    ) -> Arc<dyn ExecutionPlan> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(a, DataType::Int32, true),
+            Field::new(b, DataType::Int32, false),
+            Field::new(c, DataType::Int32, false),
+        ]));
+        let keys: Int32Array = (0..200)
+            .map(|i| (i % 7 != 0).then_some(i % num_keys))
+            .collect();
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(keys),
+                Arc::new(Int32Array::from_iter_values(0..200)),
+                Arc::new(Int32Array::from_iter_values((0..200).map(|i| i * 13 % 17))),
+            ],
+        )
+        .unwrap();
+        Arc::new(MemoryExec::try_new(&[vec![batch]], schema, None).unwrap())
+    }
+
+    #[tokio::test]
+    async fn join_grouped_duplicate_keys_same_results() -> Result<()> {
+        let join_types = [
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftSemi,
+            JoinType::LeftAnti,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
+        ];
+        for join_type in join_types {
+            for null_equals_null in [false, true] {
+                for filter in [None, Some(prepare_join_filter())] {
+                    for batch_size in [1, 7, 8192] {
+                        let mut results = vec![];
+                        for group_duplicate_keys in [false, true] {
+                            let left = build_duplicate_keys_table("a1", "b1", "c1", 5);
+                            let right = build_duplicate_keys_table("a2", "b2", "c2", 8);
+                            let on = vec![(
+                                Arc::new(Column::new_with_schema("a1", &left.schema())?)
+                                    as _,
+                                Arc::new(Column::new_with_schema("a2", &right.schema())?)
+                                    as _,
+                            )];
+                            let join = HashJoinExec::try_new(
+                                left,
+                                right,
+                                on,
+                                filter.clone(),
+                                &join_type,
+                                None,
+                                PartitionMode::CollectLeft,
+                                null_equals_null,
+                            )?;
+                            let task_ctx = group_duplicate_keys_task_ctx(
+                                batch_size,
+                                group_duplicate_keys,
+                            );
+                            let stream = join.execute(0, Arc::new(task_ctx))?;
+                            let batches = common::collect(stream).await?;
+
+                            let mut lines = pretty_format_batches(&batches)?
+                                .to_string()
+                                .lines()
+                                .map(String::from)
+                                .collect::<Vec<_>>();
+                            lines.sort_unstable();
+                            results.push(lines);
+                        }
+                        assert_eq!(
+                            results[0],
+                            results[1],
+                            "{join_type} join, null_equals_null: {null_equals_null}, \
+                             filter: {}, batch_size: {batch_size}",
+                            filter.is_some()
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the column names on the schema
     fn columns(schema: &Schema) -> Vec<String> {
         schema.fields().iter().map(|f| f.name().clone()).collect()
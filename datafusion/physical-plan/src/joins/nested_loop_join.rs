@@ -21,6 +21,8 @@
 
 use std::any::Any;
 use std::fmt::Formatter;
+use std::iter::repeat;
+use std::ops::Range;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::task::Poll;
@@ -31,8 +33,8 @@ use crate::coalesce_partitions::CoalescePartitionsExec;
 use crate::joins::utils::{
     adjust_indices_by_join_type, apply_join_filter_to_indices, build_batch_from_indices,
     build_join_schema, check_join_is_valid, estimate_join_statistics,
-    get_final_indices_from_bit_map, BuildProbeJoinMetrics, ColumnIndex, JoinFilter,
-    OnceAsync, OnceFut,
+    get_final_indices_from_bit_map, join_equal_conditions, BuildProbeJoinMetrics,
+    ColumnIndex, JoinFilter, OnceAsync, OnceFut,
 };
 use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use crate::{
@@ -119,7 +121,8 @@ impl JoinLeftData {
 /// Sequentially polling batches from the probe-side input and processing them according to the
 /// following logic:
 /// - apply join filter (`ON` clause) to Cartesian product of probe batch and build side data
-///   -- filter evaluation is executed once per build-side data row
+///   -- filter evaluation is executed once per chunk of build-side data rows, where chunks
+///   are sized so that their product with the probe batch holds about `batch_size` rows
 /// - update shared bitmap of joined ("visited") build-side row indices, if required -- allows
 ///   to produce unmatched build-side data in case of e.g. LEFT/FULL JOIN after probing phase
 ///   completed
@@ -173,7 +176,13 @@ impl NestedLoopJoinExec {
         let (schema, column_indices) =
             build_join_schema(&left_schema, &right_schema, join_type);
         let schema = Arc::new(schema);
-        let cache = Self::compute_properties(&left, &right, schema.clone(), *join_type);
+        let cache = Self::compute_properties(
+            &left,
+            &right,
+            schema.clone(),
+            *join_type,
+            filter.as_ref(),
+        );
 
         Ok(NestedLoopJoinExec {
             left,
@@ -214,8 +223,12 @@ impl NestedLoopJoinExec {
         right: &Arc<dyn ExecutionPlan>,
         schema: SchemaRef,
         join_type: JoinType,
+        filter: Option<&JoinFilter>,
     ) -> PlanProperties {
-        // Calculate equivalence properties:
+        // Calculate equivalence properties. There are no on columns in nested
+        // loop join, but the filter may still require columns to be equal
+        let equal_conditions =
+            join_equal_conditions(&[], filter, &left.schema(), &right.schema());
         let eq_properties = join_equivalence_properties(
             left.equivalence_properties().clone(),
             right.equivalence_properties().clone(),
@@ -223,8 +236,7 @@ impl NestedLoopJoinExec {
             schema,
             &[false, false],
             None,
-            // No on columns in nested loop join
-            &[],
+            &equal_conditions,
         );
 
         let output_partitioning =
@@ -316,6 +328,7 @@ impl ExecutionPlan for NestedLoopJoinExec {
                 self.right().output_partitioning().partition_count(),
             )
         });
+        let batch_size = context.session_config().batch_size();
         let outer_table = self.right.execute(partition, context)?;
 
         Ok(Box::pin(NestedLoopJoinStream {
@@ -327,6 +340,7 @@ impl ExecutionPlan for NestedLoopJoinExec {
             is_exhausted: false,
             column_indices: self.column_indices.clone(),
             join_metrics,
+            batch_size,
         }))
     }
 
@@ -360,6 +374,7 @@ async fn collect_left_input(
     } else {
         input
     };
+    let cancellation_token = context.cancellation_token().clone();
     let stream = merge.execute(0, context)?;
 
     // Load all batches and count the rows
@@ -367,6 +382,7 @@ async fn collect_left_input(
         .try_fold(
             (Vec::new(), 0usize, join_metrics, reservation),
             |mut acc, batch| async {
+                cancellation_token.check()?;
                 let batch_size = batch.get_array_memory_size();
                 // Reserve memory for incoming batch
                 acc.3.try_grow(batch_size)?;
@@ -428,20 +444,27 @@ struct NestedLoopJoinStream {
     // null_equals_null: bool
     /// Join execution metrics
     join_metrics: BuildProbeJoinMetrics,
+    /// Target number of rows of the Cartesian product chunks the filter is evaluated on
+    batch_size: usize,
 }
 
 fn build_join_indices(
-    left_row_index: usize,
+    left_rows: Range<usize>,
     right_batch: &RecordBatch,
     left_batch: &RecordBatch,
     filter: Option<&JoinFilter>,
 ) -> Result<(UInt64Array, UInt32Array)> {
-    // left indices: [left_index, left_index, ...., left_index]
-    // right indices: [0, 1, 2, 3, 4,....,right_row_count]
+    // left indices: [left_start, ..., left_start, ..., left_end - 1, ..., left_end - 1]
+    // right indices: [0, 1, ..., right_row_count - 1, 0, 1, ...] for each left row
 
     let right_row_count = right_batch.num_rows();
-    let left_indices = UInt64Array::from(vec![left_row_index as u64; right_row_count]);
-    let right_indices = UInt32Array::from_iter_values(0..(right_row_count as u32));
+    let left_indices =
+        UInt64Array::from_iter_values(left_rows.clone().flat_map(|left_row_index| {
+            repeat(left_row_index as u64).take(right_row_count)
+        }));
+    let right_indices = UInt32Array::from_iter_values(
+        left_rows.flat_map(|_| 0..(right_row_count as u32)),
+    );
     // in the nested loop join, the filter can contain non-equal and equal condition.
     if let Some(filter) = filter {
         apply_join_filter_to_indices(
@@ -490,6 +513,7 @@ impl NestedLoopJoinStream {
                         &self.column_indices,
                         &self.schema,
                         visited_left_side,
+                        self.batch_size,
                     );
 
                     // Recording time & updating output metrics
@@ -562,10 +586,17 @@ fn join_left_and_right_batch(
     column_indices: &[ColumnIndex],
     schema: &Schema,
     visited_left_side: &SharedBitmapBuilder,
+    batch_size: usize,
 ) -> Result<RecordBatch> {
-    let indices_result = (0..left_batch.num_rows())
-        .map(|left_row_index| {
-            build_join_indices(left_row_index, right_batch, left_batch, filter)
+    // Evaluate the filter on chunks of the Cartesian product holding about
+    // `batch_size` rows, but on at least one build-side row at a time
+    let left_row_count = left_batch.num_rows();
+    let left_chunk_size = (batch_size / right_batch.num_rows().max(1)).max(1);
+    let indices_result = (0..left_row_count)
+        .step_by(left_chunk_size)
+        .map(|chunk_start| {
+            let chunk_end = (chunk_start + left_chunk_size).min(left_row_count);
+            build_join_indices(chunk_start..chunk_end, right_batch, left_batch, filter)
         })
         .collect::<Result<Vec<(UInt64Array, UInt32Array)>>>();
 
@@ -656,6 +687,7 @@ mod tests {
 
     use arrow::datatypes::{DataType, Field};
     use datafusion_common::{assert_batches_sorted_eq, assert_contains, ScalarValue};
+    use datafusion_execution::config::SessionConfig;
     use datafusion_execution::runtime_env::{RuntimeConfig, RuntimeEnv};
     use datafusion_expr::Operator;
     use datafusion_physical_expr::expressions::{BinaryExpr, Literal};
@@ -887,6 +919,43 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn join_full_with_filter_small_batch_size() -> Result<()> {
+        // The filter is evaluated on one, two and all build-side rows at a time
+        for batch_size in [1, 6, 8192] {
+            let session_config = SessionConfig::default().with_batch_size(batch_size);
+            let task_ctx =
+                Arc::new(TaskContext::default().with_session_config(session_config));
+            let left = build_left_table();
+            let right = build_right_table();
+
+            let filter = prepare_join_filter();
+            let (_, batches) = multi_partitioned_join_collect(
+                left,
+                right,
+                &JoinType::Full,
+                Some(filter),
+                task_ctx,
+            )
+            .await?;
+            let expected = [
+                "+----+----+-----+----+----+-----+",
+                "| a1 | b1 | c1  | a2 | b2 | c2  |",
+                "+----+----+-----+----+----+-----+",
+                "|    |    |     | 10 | 10 | 100 |",
+                "|    |    |     | 12 | 10 | 40  |",
+                "| 11 | 8  | 110 |    |    |     |",
+                "| 5  | 5  | 50  | 2  | 2  | 80  |",
+                "| 9  | 8  | 90  |    |    |     |",
+                "+----+----+-----+----+----+-----+",
+            ];
+
+            assert_batches_sorted_eq!(expected, &batches);
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn join_left_semi_with_filter() -> Result<()> {
         let task_ctx = Arc::new(TaskContext::default());
@@ -1005,6 +1074,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn join_filter_equivalences() -> Result<()> {
+        let column_indices = vec![
+            ColumnIndex {
+                index: 1,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 1,
+                side: JoinSide::Right,
+            },
+        ];
+        let intermediate_schema = Schema::new(vec![
+            Field::new("x", DataType::Int32, true),
+            Field::new("x", DataType::Int32, true),
+        ]);
+        // left.b1 = right.b2
+        let filter_expression = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("x", 0)),
+            Operator::Eq,
+            Arc::new(Column::new("x", 1)),
+        )) as Arc<dyn PhysicalExpr>;
+        let filter =
+            JoinFilter::new(filter_expression, column_indices, intermediate_schema);
+
+        // Output partitioned by b1 is partitioned by b2 only if they are equal
+        let b1 = Arc::new(Column::new("b1", 1)) as Arc<dyn PhysicalExpr>;
+        let b2 = Arc::new(Column::new("b2", 4)) as Arc<dyn PhysicalExpr>;
+        let partitioning = Partitioning::Hash(vec![b1], 4);
+        let required = Distribution::HashPartitioned(vec![b2]);
+        for (join_type, expected) in [(JoinType::Inner, true), (JoinType::Left, false)] {
+            let join = NestedLoopJoinExec::try_new(
+                build_left_table(),
+                build_right_table(),
+                Some(filter.clone()),
+                &join_type,
+            )?;
+            assert_eq!(
+                partitioning.satisfy(&required, join.equivalence_properties()),
+                expected,
+                "{join_type}"
+            );
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_overallocation() -> Result<()> {
         let left = build_table(
@@ -33,7 +33,8 @@ use std::task::{Context, Poll};
 use crate::expressions::PhysicalSortExpr;
 use crate::joins::utils::{
     build_join_schema, check_join_is_valid, estimate_join_statistics,
-    symmetric_join_output_partitioning, JoinFilter, JoinOn, JoinOnRef,
+    join_equal_conditions, symmetric_join_output_partitioning, JoinFilter, JoinOn,
+    JoinOnRef,
 };
 use crate::metrics::{ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
 use crate::{
@@ -139,8 +140,14 @@ impl SortMergeJoinExec {
 
         let schema =
             Arc::new(build_join_schema(&left_schema, &right_schema, &join_type).0);
-        let cache =
-            Self::compute_properties(&left, &right, schema.clone(), join_type, &on);
+        let cache = Self::compute_properties(
+            &left,
+            &right,
+            schema.clone(),
+            join_type,
+            &on,
+            filter.as_ref(),
+        );
         Ok(Self {
             left,
             right,
@@ -210,8 +217,11 @@ impl SortMergeJoinExec {
         schema: SchemaRef,
         join_type: JoinType,
         join_on: JoinOnRef,
+        filter: Option<&JoinFilter>,
     ) -> PlanProperties {
         // Calculate equivalence properties:
+        let equal_conditions =
+            join_equal_conditions(join_on, filter, &left.schema(), &right.schema());
         let eq_properties = join_equivalence_properties(
             left.equivalence_properties().clone(),
             right.equivalence_properties().clone(),
@@ -219,7 +229,7 @@ impl SortMergeJoinExec {
             schema,
             &Self::maintains_input_order(join_type),
             Some(Self::probe_side(&join_type)),
-            join_on,
+            &equal_conditions,
         );
 
         let output_partitioning =
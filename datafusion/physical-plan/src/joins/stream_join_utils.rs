@@ -645,6 +645,8 @@ pub struct StreamJoinMetrics {
     pub(crate) output_batches: metrics::Count,
     /// Number of rows produced by this operator
     pub(crate) output_rows: metrics::Count,
+    /// Number of buffered rows pruned from both sides
+    pub(crate) pruned_rows: metrics::Count,
 }
 
 impl StreamJoinMetrics {
@@ -673,12 +675,15 @@ impl StreamJoinMetrics {
 
         let output_rows = MetricBuilder::new(metrics).output_rows(partition);
 
+        let pruned_rows = MetricBuilder::new(metrics).counter("pruned_rows", partition);
+
         Self {
             left,
             right,
             output_batches,
             stream_memory_usage,
             output_rows,
+            pruned_rows,
         }
     }
 }
@@ -42,8 +42,8 @@ use crate::joins::stream_join_utils::{
 };
 use crate::joins::utils::{
     apply_join_filter_to_indices, build_batch_from_indices, build_join_schema,
-    check_join_is_valid, symmetric_join_output_partitioning, ColumnIndex, JoinFilter,
-    JoinHashMapType, JoinOn, JoinOnRef, StatefulStreamResult,
+    check_join_is_valid, join_equal_conditions, symmetric_join_output_partitioning,
+    ColumnIndex, JoinFilter, JoinHashMapType, JoinOn, JoinOnRef, StatefulStreamResult,
 };
 use crate::{
     execution_mode_from_children,
@@ -232,8 +232,14 @@ impl SymmetricHashJoinExec {
         // Initialize the random state for the join operation:
         let random_state = RandomState::with_seeds(0, 0, 0, 0);
         let schema = Arc::new(schema);
-        let cache =
-            Self::compute_properties(&left, &right, schema.clone(), *join_type, &on);
+        let cache = Self::compute_properties(
+            &left,
+            &right,
+            schema.clone(),
+            *join_type,
+            &on,
+            filter.as_ref(),
+        );
         Ok(SymmetricHashJoinExec {
             left,
             right,
@@ -258,8 +264,11 @@ impl SymmetricHashJoinExec {
         schema: SchemaRef,
         join_type: JoinType,
         join_on: JoinOnRef,
+        filter: Option<&JoinFilter>,
     ) -> PlanProperties {
         // Calculate equivalence properties:
+        let equal_conditions =
+            join_equal_conditions(join_on, filter, &left.schema(), &right.schema());
         let eq_properties = join_equivalence_properties(
             left.equivalence_properties().clone(),
             right.equivalence_properties().clone(),
@@ -268,7 +277,7 @@ impl SymmetricHashJoinExec {
             &[false, false],
             // Has alternating probe side
             None,
-            join_on,
+            &equal_conditions,
         );
 
         let output_partitioning =
@@ -1514,6 +1523,7 @@ impl SymmetricHashJoinStream {
                 &self.column_indices,
             )?;
             build_hash_joiner.prune_internal_state(prune_length)?;
+            self.metrics.pruned_rows.add(prune_length);
             result
         } else {
             None
@@ -2503,4 +2513,74 @@ mod tests {
         experiment(left, right, Some(filter), join_type, on, task_ctx).await?;
         Ok(())
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn join_reports_pruned_rows() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let (left_partition, right_partition) = get_or_create_table((4, 5), 8)?;
+
+        let left_schema = &left_partition[0].schema();
+        let right_schema = &right_partition[0].schema();
+        let left_sorted = vec![PhysicalSortExpr {
+            expr: col("la1", left_schema)?,
+            options: SortOptions::default(),
+        }];
+        let right_sorted = vec![PhysicalSortExpr {
+            expr: col("ra1", right_schema)?,
+            options: SortOptions::default(),
+        }];
+        let (left, right) = create_memory_table(
+            left_partition,
+            right_partition,
+            vec![left_sorted.clone()],
+            vec![right_sorted.clone()],
+        )?;
+
+        let on = vec![(
+            Arc::new(Column::new_with_schema("lc1", left_schema)?) as _,
+            Arc::new(Column::new_with_schema("rc1", right_schema)?) as _,
+        )];
+
+        // left_col - 1 > right_col + 5 AND left_col - 3 < right_col + 10
+        let intermediate_schema = Schema::new(vec![
+            Field::new("left", DataType::Int32, true),
+            Field::new("right", DataType::Int32, true),
+        ]);
+        let filter_expr = join_expr_tests_fixture_i32(
+            2,
+            col("left", &intermediate_schema)?,
+            col("right", &intermediate_schema)?,
+        );
+        let column_indices = vec![
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Right,
+            },
+        ];
+        let filter = JoinFilter::new(filter_expr, column_indices, intermediate_schema);
+
+        let join = SymmetricHashJoinExec::try_new(
+            left,
+            right,
+            on,
+            Some(filter),
+            &JoinType::Inner,
+            false,
+            Some(left_sorted),
+            Some(right_sorted),
+            StreamJoinPartitionMode::SinglePartition,
+        )?;
+        let stream = join.execute(0, task_ctx)?;
+        crate::common::collect(stream).await?;
+
+        // Both inputs are ascending, so the range filter lets the join evict
+        // buffered rows that can no longer match
+        let metrics = join.metrics().unwrap();
+        assert!(metrics.sum_by_name("pruned_rows").unwrap().as_usize() > 0);
+        Ok(())
+    }
 }
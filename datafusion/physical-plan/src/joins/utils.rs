@@ -45,11 +45,13 @@ use datafusion_common::{
     plan_err, DataFusionError, JoinSide, JoinType, Result, SharedResult,
 };
 use datafusion_expr::interval_arithmetic::Interval;
+use datafusion_expr::Operator;
 use datafusion_physical_expr::equivalence::add_offset_to_expr;
-use datafusion_physical_expr::expressions::Column;
+use datafusion_physical_expr::expressions::{BinaryExpr, Column};
 use datafusion_physical_expr::utils::{collect_columns, merge_vectors};
 use datafusion_physical_expr::{
-    LexOrdering, LexOrderingRef, PhysicalExpr, PhysicalExprRef, PhysicalSortExpr,
+    split_conjunction, LexOrdering, LexOrderingRef, PhysicalExpr, PhysicalExprRef,
+    PhysicalSortExpr,
 };
 
 use futures::future::{BoxFuture, Shared};
@@ -208,23 +210,35 @@ pub trait JoinHashMapType {
     ) {
         let (mut_map, mut_list) = self.get_mut();
         for (row, hash_value) in iter {
-            let item = mut_map.get_mut(*hash_value, |(hash, _)| *hash_value == *hash);
-            if let Some((_, index)) = item {
-                // Already exists: add index to next array
-                let prev_index = *index;
-                // Store new value inside hashmap
-                *index = (row + 1) as u64;
-                // Update chained Vec at `row` with previous value
-                mut_list[row - deleted_offset] = prev_index;
-            } else {
-                mut_map.insert(
-                    *hash_value,
-                    // store the value + 1 as 0 value reserved for end of list
-                    (*hash_value, (row + 1) as u64),
-                    |(hash, _)| *hash,
-                );
-                // chained list at `row` is already initialized with 0
-                // meaning end of list
+            // Probe the map only once, whether the hash exists or not
+            let item = mut_map.find_or_find_insert_slot(
+                *hash_value,
+                |(hash, _)| *hash_value == *hash,
+                |(hash, _)| *hash,
+            );
+            // SAFETY: No mutation occurred since find_or_find_insert_slot
+            unsafe {
+                match item {
+                    Ok(bucket) => {
+                        // Already exists: add index to next array
+                        let (_, index) = bucket.as_mut();
+                        let prev_index = *index;
+                        // Store new value inside hashmap
+                        *index = (row + 1) as u64;
+                        // Update chained Vec at `row` with previous value
+                        mut_list[row - deleted_offset] = prev_index;
+                    }
+                    Err(slot) => {
+                        mut_map.insert_in_slot(
+                            *hash_value,
+                            slot,
+                            // store the value + 1 as 0 value reserved for end of list
+                            (*hash_value, (row + 1) as u64),
+                        );
+                        // chained list at `row` is already initialized with 0
+                        // meaning end of list
+                    }
+                }
             }
         }
     }
@@ -293,8 +307,11 @@ pub trait JoinHashMapType {
         UInt64BufferBuilder,
         Option<JoinHashMapOffset>,
     ) {
-        let mut input_indices = UInt32BufferBuilder::new(0);
-        let mut match_indices = UInt64BufferBuilder::new(0);
+        // Most probe rows match at most one build row, so the output usually
+        // fits in one index per probe row, up to the limit
+        let capacity = limit.min(hash_values.len());
+        let mut input_indices = UInt32BufferBuilder::new(capacity);
+        let mut match_indices = UInt64BufferBuilder::new(capacity);
 
         let mut remaining_output = limit;
 
@@ -608,6 +625,57 @@ impl JoinFilter {
     pub fn schema(&self) -> &Schema {
         &self.schema
     }
+
+    /// Returns the pairs of left and right side columns that the conjuncts of
+    /// the filter require to be equal, in terms of the `left` and `right`
+    /// input schemas. Like the equijoin keys, these pairs are equal in the
+    /// output of inner joins.
+    pub fn equal_columns(&self, left: &Schema, right: &Schema) -> JoinOn {
+        let input_column = |expr: &PhysicalExprRef| {
+            let column = expr.as_any().downcast_ref::<Column>()?;
+            let ColumnIndex { index, side } = self.column_indices.get(column.index())?;
+            let schema = match side {
+                JoinSide::Left => left,
+                JoinSide::Right => right,
+            };
+            let name = schema.fields().get(*index)?.name();
+            Some((
+                *side,
+                Arc::new(Column::new(name, *index)) as PhysicalExprRef,
+            ))
+        };
+        split_conjunction(&self.expression)
+            .into_iter()
+            .filter_map(|conjunct| {
+                let binary = conjunct.as_any().downcast_ref::<BinaryExpr>()?;
+                if binary.op() != &Operator::Eq {
+                    return None;
+                }
+                match (input_column(binary.left())?, input_column(binary.right())?) {
+                    ((JoinSide::Left, left), (JoinSide::Right, right))
+                    | ((JoinSide::Right, right), (JoinSide::Left, left)) => {
+                        Some((left, right))
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the equijoin keys `on`, followed by the pairs of columns that the
+/// join `filter` requires to be equal. See [`JoinFilter::equal_columns`].
+pub(crate) fn join_equal_conditions(
+    on: JoinOnRef,
+    filter: Option<&JoinFilter>,
+    left: &Schema,
+    right: &Schema,
+) -> JoinOn {
+    let mut conditions = on.to_vec();
+    if let Some(filter) = filter {
+        conditions.extend(filter.equal_columns(left, right));
+    }
+    conditions
 }
 
 /// Returns the output field given the input field. Outer joins may
@@ -1591,6 +1659,40 @@ mod tests {
         check_join_set_is_valid(&left, &right, on)
     }
 
+    #[test]
+    fn join_hash_map_chains() {
+        // rows 0, 2 and 3 share the same hash
+        let hashes = [10, 20, 10, 10, 30];
+        let mut hash_map = JoinHashMap::with_capacity(hashes.len());
+        // insert in reverse order, so that the chains are in row order
+        hash_map.update_from_iter(hashes.iter().enumerate().rev(), 0);
+
+        let probe_hashes = [10, 40, 20];
+        let (input_indices, match_indices, next_offset) = hash_map
+            .get_matched_indices_with_limit_offset(&probe_hashes, None, 10, (0, None));
+        assert_eq!(input_indices.as_slice(), &[0, 0, 0, 2]);
+        assert_eq!(match_indices.as_slice(), &[0, 2, 3, 1]);
+        assert_eq!(next_offset, None);
+
+        // the same matches are returned in two steps with a limit of 2
+        let (input_indices, match_indices, next_offset) = hash_map
+            .get_matched_indices_with_limit_offset(&probe_hashes, None, 2, (0, None));
+        assert_eq!(input_indices.as_slice(), &[0, 0]);
+        assert_eq!(match_indices.as_slice(), &[0, 2]);
+        assert_eq!(next_offset, Some((0, Some(4))));
+
+        let (input_indices, match_indices, next_offset) = hash_map
+            .get_matched_indices_with_limit_offset(
+                &probe_hashes,
+                None,
+                2,
+                next_offset.unwrap(),
+            );
+        assert_eq!(input_indices.as_slice(), &[0, 2]);
+        assert_eq!(match_indices.as_slice(), &[3, 1]);
+        assert_eq!(next_offset, None);
+    }
+
     #[test]
     fn check_valid() -> Result<()> {
         let left = vec![Column::new("a", 0), Column::new("b1", 1)];
@@ -19,10 +19,10 @@
 
 use std::any::Any;
 use std::fmt::Debug;
-use std::fs::File;
-use std::io::BufReader;
-use std::path::{Path, PathBuf};
+use std::io::{Cursor, Read, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::coalesce_partitions::CoalescePartitionsExec;
 use crate::display::DisplayableExecutionPlan;
@@ -31,10 +31,15 @@ use crate::repartition::RepartitionExec;
 use crate::sorts::sort_preserving_merge::SortPreservingMergeExec;
 
 use arrow::datatypes::SchemaRef;
-use arrow::ipc::reader::FileReader;
+use arrow::ipc::reader::{FileReader, StreamReader};
+use arrow::ipc::writer::{FileWriter, StreamWriter};
 use arrow::record_batch::RecordBatch;
+use arrow_schema::ArrowError;
 use datafusion_common::config::ConfigOptions;
-use datafusion_common::{exec_datafusion_err, Result};
+use datafusion_common::{
+    exec_datafusion_err, internal_datafusion_err, DataFusionError, Result,
+};
+use datafusion_execution::cancellation::CancellableStream;
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::{
     EquivalenceProperties, LexOrdering, PhysicalSortExpr, PhysicalSortRequirement,
@@ -44,6 +49,7 @@ use futures::stream::TryStreamExt;
 use log::debug;
 use tokio::sync::mpsc::Sender;
 use tokio::task::JoinSet;
+use tokio::time::Instant;
 
 mod ordering;
 mod topk;
@@ -59,6 +65,8 @@ pub mod empty;
 pub mod explain;
 pub mod filter;
 pub mod insert;
+#[cfg(feature = "tracing")]
+pub mod instrumented;
 pub mod joins;
 pub mod limit;
 pub mod memory;
@@ -95,14 +103,14 @@ pub use datafusion_physical_expr::{
 // Backwards compatibility
 use crate::common::IPCWriter;
 pub use crate::stream::EmptyRecordBatchStream;
-use crate::stream::RecordBatchReceiverStream;
+use crate::stream::{RecordBatchReceiverStream, TimeoutStream};
 use datafusion_execution::disk_manager::RefCountedTempFile;
 use datafusion_execution::memory_pool::human_readable_size;
 pub use datafusion_execution::{RecordBatchStream, SendableRecordBatchStream};
 
 pub mod udaf {
     pub use datafusion_physical_expr_common::aggregate::{
-        create_aggregate_expr, AggregateFunctionExpr,
+        create_aggregate_expr, create_aggregate_function_expr, AggregateFunctionExpr,
     };
 }
 
@@ -726,21 +734,101 @@ pub async fn collect(
 /// # Aborting Execution
 ///
 /// Dropping the stream will abort the execution of the query, and free up
-/// any allocated resources
+/// any allocated resources. The execution is also aborted when the
+/// [`CancellationToken`] of `context` is cancelled, or when it runs for
+/// longer than the configured [`query_timeout`].
+///
+/// [`query_timeout`]: datafusion_execution::config::SessionConfig::query_timeout
 pub fn execute_stream(
     plan: Arc<dyn ExecutionPlan>,
     context: Arc<TaskContext>,
 ) -> Result<SendableRecordBatchStream> {
-    match plan.output_partitioning().partition_count() {
-        0 => Ok(Box::pin(EmptyRecordBatchStream::new(plan.schema()))),
-        1 => plan.execute(0, context),
-        _ => {
-            // merge into a single partition
-            let plan = CoalescePartitionsExec::new(plan.clone());
-            // CoalescePartitionsExec must produce a single partition
-            assert_eq!(1, plan.properties().output_partitioning().partition_count());
-            plan.execute(0, context)
+    let (context, deadline) = with_query_deadline(context);
+    let plan = with_tracing(plan, &context)?;
+    let stream: SendableRecordBatchStream =
+        match plan.output_partitioning().partition_count() {
+            0 => Box::pin(EmptyRecordBatchStream::new(plan.schema())),
+            1 => plan.execute(0, Arc::clone(&context))?,
+            _ => {
+                // merge into a single partition
+                let plan = CoalescePartitionsExec::new(plan.clone());
+                // CoalescePartitionsExec must produce a single partition
+                assert_eq!(1, plan.properties().output_partitioning().partition_count());
+                plan.execute(0, Arc::clone(&context))?
+            }
+        };
+    Ok(cancellable_stream(stream, &plan, &context, deadline))
+}
+
+/// Wraps every operator of `plan` in an [`InstrumentedExec`] if tracing is
+/// enabled in the configuration of `context`
+///
+/// [`InstrumentedExec`]: instrumented::InstrumentedExec
+#[cfg(feature = "tracing")]
+fn with_tracing(
+    plan: Arc<dyn ExecutionPlan>,
+    context: &TaskContext,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    if context.session_config().options().execution.enable_tracing {
+        instrumented::instrument_plan(plan)
+    } else {
+        Ok(plan)
+    }
+}
+
+/// Returns an error if tracing is enabled in the configuration of `context`,
+/// as it requires the `tracing` feature
+#[cfg(not(feature = "tracing"))]
+fn with_tracing(
+    plan: Arc<dyn ExecutionPlan>,
+    context: &TaskContext,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    if context.session_config().options().execution.enable_tracing {
+        datafusion_common::config_err!(
+            "datafusion.execution.enable_tracing requires the tracing feature"
+        )
+    } else {
+        Ok(plan)
+    }
+}
+
+/// If a query timeout is configured, returns the deadline of the query and
+/// a [`TaskContext`] with its own [`CancellationToken`], so that the query
+/// can be aborted without cancelling the other queries using `context`.
+///
+/// [`CancellationToken`]: datafusion_execution::CancellationToken
+fn with_query_deadline(
+    context: Arc<TaskContext>,
+) -> (Arc<TaskContext>, Option<(Duration, Instant)>) {
+    match context.session_config().query_timeout() {
+        Some(timeout) => {
+            let token = context.cancellation_token().child_token();
+            let context = context.as_ref().clone().with_cancellation_token(token);
+            (Arc::new(context), Some((timeout, Instant::now() + timeout)))
         }
+        None => (context, None),
+    }
+}
+
+/// Wrap the output `stream` of `plan` so that it stops when the query is
+/// cancelled or when `deadline` is reached
+fn cancellable_stream(
+    stream: SendableRecordBatchStream,
+    plan: &Arc<dyn ExecutionPlan>,
+    context: &TaskContext,
+    deadline: Option<(Duration, Instant)>,
+) -> SendableRecordBatchStream {
+    let token = context.cancellation_token().clone();
+    let stream = Box::pin(CancellableStream::new(stream, token.clone()));
+    match deadline {
+        Some((timeout, deadline)) => Box::pin(TimeoutStream::new(
+            stream,
+            Arc::clone(plan),
+            token,
+            timeout,
+            deadline,
+        )),
+        None => stream,
     }
 }
 
@@ -790,15 +878,19 @@ pub async fn collect_partitioned(
 /// # Aborting Execution
 ///
 /// Dropping the stream will abort the execution of the query, and free up
-/// any allocated resources
+/// any allocated resources. See [`execute_stream`] for the other ways the
+/// execution is aborted.
 pub fn execute_stream_partitioned(
     plan: Arc<dyn ExecutionPlan>,
     context: Arc<TaskContext>,
 ) -> Result<Vec<SendableRecordBatchStream>> {
+    let (context, deadline) = with_query_deadline(context);
+    let plan = with_tracing(plan, &context)?;
     let num_partitions = plan.output_partitioning().partition_count();
     let mut streams = Vec::with_capacity(num_partitions);
     for i in 0..num_partitions {
-        streams.push(plan.execute(i, context.clone())?);
+        let stream = plan.execute(i, Arc::clone(&context))?;
+        streams.push(cancellable_stream(stream, &plan, &context, deadline));
     }
     Ok(streams)
 }
@@ -823,14 +915,21 @@ pub fn read_spill_as_stream(
     let mut builder = RecordBatchReceiverStream::builder(schema, buffer);
     let sender = builder.tx();
 
-    builder.spawn_blocking(move || read_spill(sender, path.path()));
+    builder.spawn_blocking(move || read_spill(sender, &path));
 
     Ok(builder.build())
 }
 
 /// Spills in-memory `batches` to disk.
 ///
+/// The batches are written to `path` in the Arrow IPC file format, without
+/// the encryption, checksums and disk space limits of a [`DiskManager`]. See
+/// [`spill_record_batches_to_file`] to write a temporary file of a
+/// [`DiskManager`].
+///
 /// Returns total number of the rows spilled to disk.
+///
+/// [`DiskManager`]: datafusion_execution::disk_manager::DiskManager
 pub fn spill_record_batches(
     batches: Vec<RecordBatch>,
     path: PathBuf,
@@ -850,9 +949,88 @@ pub fn spill_record_batches(
     Ok(writer.num_rows)
 }
 
-fn read_spill(sender: Sender<Result<RecordBatch>>, path: &Path) -> Result<()> {
-    let file = BufReader::new(File::open(path)?);
-    let reader = FileReader::try_new(file, None)?;
+/// Spills in-memory `batches` to `spill_file`, to be read back with
+/// [`read_spill_as_stream`].
+///
+/// The batches are written in the Arrow IPC file format, unless the file
+/// should include [checksums], in which case each batch is written as an IPC
+/// stream preceded by its CRC32 checksum and length.
+///
+/// Returns total number of the rows spilled to disk, or a
+/// [`DataFusionError::ResourcesExhausted`] error as soon as the disk space
+/// limit of the file is exceeded.
+///
+/// [checksums]: RefCountedTempFile::checksums
+pub fn spill_record_batches_to_file(
+    batches: Vec<RecordBatch>,
+    spill_file: &RefCountedTempFile,
+    schema: SchemaRef,
+) -> Result<usize> {
+    let num_rows = batches.iter().map(|batch| batch.num_rows()).sum();
+    let num_bytes = batches
+        .iter()
+        .map(|batch| batch.get_array_memory_size())
+        .sum();
+    write_spill(&batches, spill_file, &schema).map_err(unwrap_temp_file_error)?;
+    debug!(
+        "Spilled {} batches of total {} rows to disk, memory released {}",
+        batches.len(),
+        num_rows,
+        human_readable_size(num_bytes),
+    );
+    Ok(num_rows)
+}
+
+fn write_spill(
+    batches: &[RecordBatch],
+    spill_file: &RefCountedTempFile,
+    schema: &SchemaRef,
+) -> Result<()> {
+    let mut writer = spill_file.writer()?;
+    if spill_file.checksums() {
+        for batch in batches {
+            write_checksummed_batch(&mut writer, batch, schema)?;
+        }
+    } else {
+        let mut ipc_writer = FileWriter::try_new(writer, schema.as_ref())?;
+        for batch in batches {
+            ipc_writer.write(batch)?;
+        }
+        writer = ipc_writer.into_inner()?;
+    }
+    writer.finish()
+}
+
+/// Returns the error a [`TempFileWriter`] failed with, such as an exceeded
+/// disk space limit, if `e` is the I/O error that wraps it
+///
+/// [`TempFileWriter`]: datafusion_execution::disk_manager::TempFileWriter
+fn unwrap_temp_file_error(e: DataFusionError) -> DataFusionError {
+    match e {
+        DataFusionError::IoError(e)
+        | DataFusionError::ArrowError(ArrowError::IoError(_, e), _)
+            if e.get_ref()
+                .is_some_and(|inner| inner.is::<DataFusionError>()) =>
+        {
+            e.into_inner()
+                .and_then(|inner| inner.downcast::<DataFusionError>().ok())
+                .map(|e| *e)
+                .unwrap_or_else(|| {
+                    internal_datafusion_err!("expected a wrapped DataFusionError")
+                })
+        }
+        e => e,
+    }
+}
+
+fn read_spill(
+    sender: Sender<Result<RecordBatch>>,
+    spill_file: &RefCountedTempFile,
+) -> Result<()> {
+    if spill_file.checksums() {
+        return read_checksummed_spill(sender, spill_file);
+    }
+    let reader = FileReader::try_new(spill_file.reader()?, None)?;
     for batch in reader {
         sender
             .blocking_send(batch.map_err(Into::into))
@@ -861,16 +1039,94 @@ fn read_spill(sender: Sender<Result<RecordBatch>>, path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// The length of the CRC32 checksum and of the length that precede each
+/// batch of a spill file with checksums
+const CHECKSUM_HEADER_LEN: usize = 4 + 8;
+
+/// Writes `batch` as an IPC stream, preceded by the CRC32 checksum and the
+/// length of the stream
+fn write_checksummed_batch(
+    writer: &mut impl Write,
+    batch: &RecordBatch,
+    schema: &SchemaRef,
+) -> Result<()> {
+    let mut stream = StreamWriter::try_new(vec![], schema.as_ref())?;
+    stream.write(batch)?;
+    let bytes = stream.into_inner()?;
+    writer.write_all(&crc32fast::hash(&bytes).to_le_bytes())?;
+    writer.write_all(&(bytes.len() as u64).to_le_bytes())?;
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Reads the batches written by [`write_checksummed_batch`], returning a
+/// [`DataFusionError::SpillFileCorrupted`] error before decoding a batch
+/// that does not match its checksum
+fn read_checksummed_spill(
+    sender: Sender<Result<RecordBatch>>,
+    spill_file: &RefCountedTempFile,
+) -> Result<()> {
+    let corrupted = |description: String| {
+        DataFusionError::SpillFileCorrupted(spill_file.path().to_path_buf(), description)
+    };
+    let mut reader = spill_file.reader()?;
+    for index in 0.. {
+        let mut header = Vec::with_capacity(CHECKSUM_HEADER_LEN);
+        reader
+            .by_ref()
+            .take(CHECKSUM_HEADER_LEN as u64)
+            .read_to_end(&mut header)?;
+        if header.is_empty() {
+            break;
+        }
+        let (Some(Ok(checksum)), Some(Ok(len))) = (
+            header.get(..4).map(<[u8; 4]>::try_from),
+            header.get(4..).map(<[u8; 8]>::try_from),
+        ) else {
+            return Err(corrupted(format!("truncated header of batch {index}")));
+        };
+        let len = u64::from_le_bytes(len);
+
+        let mut bytes = vec![];
+        reader.by_ref().take(len).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 != len {
+            return Err(corrupted(format!(
+                "batch {index} has {} bytes, expected {len}",
+                bytes.len()
+            )));
+        }
+        if crc32fast::hash(&bytes) != u32::from_le_bytes(checksum) {
+            return Err(corrupted(format!("checksum mismatch in batch {index}")));
+        }
+
+        for batch in StreamReader::try_new(Cursor::new(bytes), None)? {
+            sender
+                .blocking_send(batch.map_err(Into::into))
+                .map_err(|e| exec_datafusion_err!("{e}"))?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::any::Any;
     use std::sync::Arc;
 
-    use arrow_schema::{Schema, SchemaRef};
-    use datafusion_common::{Result, Statistics};
+    use arrow::array::Int32Array;
+    use arrow::record_batch::RecordBatch;
+    use arrow_schema::{DataType, Field, Schema, SchemaRef};
+    use datafusion_common::{DataFusionError, Result, Statistics};
+    use datafusion_execution::disk_manager::{
+        DiskManager, DiskManagerConfig, SpillEncryptionKey,
+    };
     use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 
-    use crate::{DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties};
+    use crate::common::collect;
+    use crate::{
+        read_spill_as_stream, spill_record_batches_to_file, DisplayAs, DisplayFormatType,
+        ExecutionPlan, PlanProperties,
+    };
 
     #[derive(Debug)]
     pub struct EmptyExec;
@@ -1003,6 +1259,77 @@ mod tests {
         assert_eq!(RenamedEmptyExec::static_name(), "MyRenamedEmptyExec");
     }
 
+    #[tokio::test]
+    async fn spill_with_checksums() -> Result<()> {
+        let disk_manager = DiskManager::try_new(DiskManagerConfig::NewOs)?;
+        let disk_manager = disk_manager.with_checksums();
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batches = (0..3)
+            .map(|i| {
+                let array = Int32Array::from(vec![i, i + 1]);
+                RecordBatch::try_new(Arc::clone(&schema), vec![Arc::new(array)])
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let spill_file = disk_manager.create_tmp_file("Testing")?;
+        let num_rows = spill_record_batches_to_file(
+            batches.clone(),
+            &spill_file,
+            Arc::clone(&schema),
+        )?;
+        assert_eq!(num_rows, 6);
+        let stream = read_spill_as_stream(spill_file, Arc::clone(&schema), 2)?;
+        assert_eq!(collect(stream).await?, batches);
+
+        // flip a bit of the last batch
+        let spill_file = disk_manager.create_tmp_file("Testing")?;
+        spill_record_batches_to_file(batches, &spill_file, Arc::clone(&schema))?;
+        let path = spill_file.path().to_path_buf();
+        let mut bytes = std::fs::read(&path)?;
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        std::fs::write(&path, bytes)?;
+
+        let stream = read_spill_as_stream(spill_file, schema, 2)?;
+        let err = collect(stream).await.unwrap_err();
+        match err {
+            DataFusionError::SpillFileCorrupted(err_path, description) => {
+                assert_eq!(err_path, path);
+                assert_eq!(description, "checksum mismatch in batch 2");
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn spill_encrypted_with_disk_limit() -> Result<()> {
+        let disk_manager = DiskManager::try_new(DiskManagerConfig::NewOs)?
+            .with_encryption_key(SpillEncryptionKey::generate());
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let array = Int32Array::from((0..10_000).collect::<Vec<_>>());
+        let batches = vec![RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(array)],
+        )?];
+
+        let spill_file = disk_manager.create_tmp_file("Testing")?;
+        spill_record_batches_to_file(batches.clone(), &spill_file, Arc::clone(&schema))?;
+        let stream = read_spill_as_stream(spill_file, Arc::clone(&schema), 2)?;
+        assert_eq!(collect(stream).await?, batches);
+
+        // the limit stops the spill while the file is written
+        let limited = disk_manager.with_max_temp_directory_size(1_000);
+        let spill_file = limited.create_tmp_file("Testing")?;
+        let err = spill_record_batches_to_file(batches, &spill_file, schema).unwrap_err();
+        assert!(
+            matches!(err, DataFusionError::ResourcesExhausted(_)),
+            "unexpected error: {err}"
+        );
+        assert!(limited.used_disk_space() <= 1_000);
+        Ok(())
+    }
+
     /// A compilation test to ensure that the `ExecutionPlan::name()` method can
     /// be called from a trait object.
     /// Related ticket: https://github.com/apache/datafusion/pull/11047
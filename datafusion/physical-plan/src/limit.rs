@@ -170,7 +170,8 @@ impl ExecutionPlan for GlobalLimitExec {
             return internal_err!("GlobalLimitExec requires a single input partition");
         }
 
-        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition)
+            .with_sampling_interval(context.session_config().metrics_sampling_interval());
         let stream = self.input.execute(0, context)?;
         Ok(Box::pin(LimitStream::new(
             stream,
@@ -365,7 +366,8 @@ impl ExecutionPlan for LocalLimitExec {
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
         trace!("Start LocalLimitExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
-        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition)
+            .with_sampling_interval(context.session_config().metrics_sampling_interval());
         let stream = self.input.execute(partition, context)?;
         Ok(Box::pin(LimitStream::new(
             stream,
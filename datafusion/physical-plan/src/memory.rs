@@ -23,6 +23,7 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use super::expressions::PhysicalSortExpr;
+use super::metrics::{ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
 use super::{
     common, DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan, Partitioning,
     PlanProperties, RecordBatchStream, SendableRecordBatchStream, Statistics,
@@ -52,6 +53,8 @@ pub struct MemoryExec {
     cache: PlanProperties,
     /// if partition sizes should be displayed
     show_sizes: bool,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
 }
 
 impl fmt::Debug for MemoryExec {
@@ -145,6 +148,11 @@ impl ExecutionPlan for MemoryExec {
         )?))
     }
 
+    fn metrics(&self) -> Option<MetricsSet> {
+        let metrics = self.metrics.clone_inner();
+        metrics.iter().next().is_some().then_some(metrics)
+    }
+
     /// We recompute the statistics dynamically from the arrow metadata as it is pretty cheap to do so
     fn statistics(&self) -> Result<Statistics> {
         Ok(common::compute_record_batch_statistics(
@@ -173,6 +181,7 @@ impl MemoryExec {
             sort_information: vec![],
             cache,
             show_sizes: true,
+            metrics: ExecutionPlanMetricsSet::new(),
         })
     }
 
@@ -182,6 +191,18 @@ impl MemoryExec {
         self
     }
 
+    /// Record the number of batches of each partition that were skipped
+    /// before creating this plan, for example using the statistics of the
+    /// batches, as the `batches_pruned` metric
+    pub fn with_pruned_batches(self, pruned_batches: &[usize]) -> Self {
+        for (partition, pruned) in pruned_batches.iter().enumerate() {
+            MetricBuilder::new(&self.metrics)
+                .counter("batches_pruned", partition)
+                .add(*pruned);
+        }
+        self
+    }
+
     pub fn partitions(&self) -> &[Vec<RecordBatch>] {
         &self.partitions
     }
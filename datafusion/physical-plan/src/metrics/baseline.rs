@@ -68,6 +68,13 @@ impl BaselineMetrics {
         }
     }
 
+    /// Samples the `elapsed_compute` metric, timing only one in `interval` of
+    /// the timers started by the operator. See [`Time::with_sampling_interval`]
+    pub fn with_sampling_interval(mut self, interval: usize) -> Self {
+        self.elapsed_compute = self.elapsed_compute.with_sampling_interval(interval);
+        self
+    }
+
     /// Returns a [`BaselineMetrics`] that updates the same `elapsed_compute` ignoring
     /// all other metrics
     ///
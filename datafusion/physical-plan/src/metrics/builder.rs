@@ -20,7 +20,8 @@
 use std::{borrow::Cow, sync::Arc};
 
 use super::{
-    Count, ExecutionPlanMetricsSet, Gauge, Label, Metric, MetricValue, Time, Timestamp,
+    Count, CustomMetricValue, ExecutionPlanMetricsSet, Gauge, Label, Metric, MetricValue,
+    Time, Timestamp,
 };
 
 /// Structure for constructing metrics, counters, timers, etc.
@@ -182,6 +183,20 @@ impl<'a> MetricBuilder<'a> {
         gauge
     }
 
+    /// Consumes self and registers `value` as a [`CustomMetricValue`] for
+    /// reporting some arbitrary metric of an operator.
+    pub fn custom(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        partition: usize,
+        value: Arc<dyn CustomMetricValue>,
+    ) {
+        self.with_partition(partition).build(MetricValue::Custom {
+            name: name.into(),
+            value,
+        });
+    }
+
     /// Consume self and create a new Timer for recording the elapsed
     /// CPU time spent by an operator
     pub fn elapsed_compute(self, partition: usize) -> Time {
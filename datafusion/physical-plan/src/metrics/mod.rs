@@ -19,6 +19,7 @@
 
 mod baseline;
 mod builder;
+mod registry;
 mod value;
 
 use parking_lot::Mutex;
@@ -33,7 +34,10 @@ use hashbrown::HashMap;
 // public exports
 pub use baseline::{BaselineMetrics, RecordOutput};
 pub use builder::MetricBuilder;
-pub use value::{Count, Gauge, MetricValue, ScopedTimerGuard, Time, Timestamp};
+pub use registry::{MetricsRegistry, QueryMetrics};
+pub use value::{
+    Count, CustomMetricValue, Gauge, MetricValue, ScopedTimerGuard, Time, Timestamp,
+};
 
 /// Something that tracks a value of interest (metric) of a DataFusion
 /// [`ExecutionPlan`] execution.
@@ -261,6 +265,7 @@ impl MetricsSet {
             MetricValue::SpilledRows(_) => false,
             MetricValue::CurrentMemoryUsage(_) => false,
             MetricValue::Gauge { name, .. } => name == metric_name,
+            MetricValue::Custom { name, .. } => name == metric_name,
             MetricValue::StartTimestamp(_) => false,
             MetricValue::EndTimestamp(_) => false,
         })
@@ -421,6 +426,8 @@ impl Display for Label {
 
 #[cfg(test)]
 mod tests {
+    use std::any::Any;
+    use std::fmt::{self, Formatter};
     use std::time::Duration;
 
     use chrono::{TimeZone, Utc};
@@ -582,6 +589,85 @@ mod tests {
         assert!(output_rows[0].partition.is_none())
     }
 
+    /// Distinct values seen by an operator, aggregated by union
+    #[derive(Debug, Default)]
+    struct DistinctValues(Mutex<std::collections::BTreeSet<i32>>);
+
+    impl DistinctValues {
+        fn new(values: impl IntoIterator<Item = i32>) -> Arc<Self> {
+            Arc::new(Self(Mutex::new(values.into_iter().collect())))
+        }
+    }
+
+    impl Display for DistinctValues {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "{:?}", self.0.lock())
+        }
+    }
+
+    impl CustomMetricValue for DistinctValues {
+        fn new_empty(&self) -> Arc<dyn CustomMetricValue> {
+            Arc::new(Self::default())
+        }
+
+        fn aggregate(&self, other: Arc<dyn CustomMetricValue>) {
+            let other = other.as_any().downcast_ref::<Self>().unwrap();
+            let other = other.0.lock().clone();
+            self.0.lock().extend(other);
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_usize(&self) -> usize {
+            self.0.lock().len()
+        }
+
+        fn is_eq(&self, other: &dyn CustomMetricValue) -> bool {
+            other
+                .as_any()
+                .downcast_ref::<Self>()
+                .is_some_and(|other| *self.0.lock() == *other.0.lock())
+        }
+    }
+
+    #[test]
+    fn test_custom_metric() {
+        let metrics = ExecutionPlanMetricsSet::new();
+        let values = DistinctValues::new([1, 2]);
+        MetricBuilder::new(&metrics).custom("distinct_values", 0, values.clone());
+        MetricBuilder::new(&metrics).custom(
+            "distinct_values",
+            1,
+            DistinctValues::new([2, 3]),
+        );
+
+        // updates through the registered handle are visible in the metrics
+        values.0.lock().insert(4);
+
+        let metrics = metrics.clone_inner();
+        assert_eq!(
+            metrics.to_string(),
+            "distinct_values{partition=0}=[1, 2, 4], \
+            distinct_values{partition=1}=[2, 3]"
+        );
+
+        let sum = metrics.sum_by_name("distinct_values").unwrap();
+        assert_eq!(sum.to_string(), "[1, 2, 3, 4]");
+        assert_eq!(sum.as_usize(), 4);
+        assert_eq!(
+            sum,
+            MetricValue::Custom {
+                name: "distinct_values".into(),
+                value: DistinctValues::new([1, 2, 3, 4]),
+            }
+        );
+
+        let aggregated = metrics.aggregate_by_name();
+        assert_eq!(aggregated.to_string(), "distinct_values=[1, 2, 3, 4]");
+    }
+
     #[test]
     #[should_panic(expected = "Mismatched metric types. Can not aggregate Count")]
     fn test_aggregate_partition_bad_sum() {
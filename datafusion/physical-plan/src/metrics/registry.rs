@@ -0,0 +1,354 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`MetricsRegistry`] for inspecting and exporting the metrics of queries
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{Display, Write};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use super::{MetricValue, MetricsSet};
+use crate::ExecutionPlan;
+
+/// Retains the [`ExecutionPlan`]s of the last queries to inspect their
+/// metrics, and renders the metrics of all the queries in the [Prometheus
+/// text exposition format].
+///
+/// Plans are registered with [`Self::register`] before they are executed.
+/// Their metrics are read when they are inspected, so the metrics of running
+/// queries are up to date. Only the last `capacity` plans are retained: the
+/// counters of older plans are added to totals so that the exported counters
+/// never decrease, but they no longer change if the plan is still running.
+///
+/// A registry is usually attached to a `SessionState`, which registers every
+/// physical plan it creates.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion_physical_plan::metrics::MetricsRegistry;
+/// # use datafusion_physical_plan::empty::EmptyExec;
+/// # use arrow::datatypes::Schema;
+/// let registry = MetricsRegistry::new(10);
+/// registry.register(Arc::new(EmptyExec::new(Arc::new(Schema::empty()))));
+/// assert_eq!(registry.recent_queries().len(), 1);
+/// let text = registry.render_prometheus();
+/// ```
+///
+/// [Prometheus text exposition format]: https://prometheus.io/docs/instrumenting/exposition_formats/
+#[derive(Debug)]
+pub struct MetricsRegistry {
+    capacity: usize,
+    state: Mutex<RegistryState>,
+}
+
+#[derive(Debug, Default)]
+struct RegistryState {
+    next_query_id: u64,
+    queries: VecDeque<QueryMetrics>,
+    /// The counters of the plans that are no longer retained
+    evicted_counters: BTreeMap<MetricKey, f64>,
+}
+
+impl MetricsRegistry {
+    /// Create a registry that retains the plans of the last `capacity` queries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(RegistryState::default()),
+        }
+    }
+
+    /// Register the plan of a query, returning the id of the query
+    pub fn register(&self, plan: Arc<dyn ExecutionPlan>) -> u64 {
+        let mut state = self.state.lock();
+        let query_id = state.next_query_id;
+        state.next_query_id += 1;
+        state.queries.push_back(QueryMetrics { query_id, plan });
+
+        while state.queries.len() > self.capacity {
+            let Some(evicted) = state.queries.pop_front() else {
+                break;
+            };
+            for (key, value) in samples(evicted.plan.as_ref()) {
+                if key.kind == MetricKind::Counter {
+                    *state.evicted_counters.entry(key).or_default() += value;
+                }
+            }
+        }
+        query_id
+    }
+
+    /// Returns the retained queries, from the oldest to the most recent
+    pub fn recent_queries(&self) -> Vec<QueryMetrics> {
+        self.state.lock().queries.iter().cloned().collect()
+    }
+
+    /// Renders the metrics of all the registered queries in the Prometheus
+    /// text exposition format.
+    ///
+    /// Each metric is summed over the queries and partitions, and labelled
+    /// with the name of the operator that produced it. Counts are exported as
+    /// `datafusion_<name>_total` counters, times as
+    /// `datafusion_<name>_seconds_total` counters, and gauges, which only
+    /// include the retained queries, as `datafusion_<name>` gauges.
+    pub fn render_prometheus(&self) -> String {
+        let state = self.state.lock();
+        let mut values = state.evicted_counters.clone();
+        for query in &state.queries {
+            for (key, value) in samples(query.plan.as_ref()) {
+                *values.entry(key).or_default() += value;
+            }
+        }
+
+        let mut output = String::new();
+        let mut previous_name = None;
+        for (key, value) in &values {
+            if previous_name != Some(&key.name) {
+                writeln!(output, "# TYPE {} {}", key.name, key.kind).unwrap();
+                previous_name = Some(&key.name);
+            }
+            let labels = key
+                .labels
+                .iter()
+                .map(|(name, value)| format!("{name}=\"{}\"", escape_label_value(value)))
+                .collect::<Vec<_>>()
+                .join(",");
+            writeln!(output, "{}{{{labels}}} {value}", key.name).unwrap();
+        }
+        output
+    }
+}
+
+/// A query registered with a [`MetricsRegistry`]
+#[derive(Debug, Clone)]
+pub struct QueryMetrics {
+    query_id: u64,
+    plan: Arc<dyn ExecutionPlan>,
+}
+
+impl QueryMetrics {
+    /// The id assigned to the query by the registry
+    pub fn query_id(&self) -> u64 {
+        self.query_id
+    }
+
+    /// The plan of the query
+    pub fn plan(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.plan
+    }
+
+    /// Returns the name and the current metrics of each operator of the plan
+    /// that has metrics, in pre-order
+    pub fn operator_metrics(&self) -> Vec<(String, MetricsSet)> {
+        let mut operators = vec![];
+        let mut stack = vec![self.plan.as_ref()];
+        while let Some(plan) = stack.pop() {
+            if let Some(metrics) = plan.metrics() {
+                operators.push((plan.name().to_string(), metrics));
+            }
+            stack.extend(plan.children().into_iter().rev().map(|c| c.as_ref()));
+        }
+        operators
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MetricKind {
+    Counter,
+    Gauge,
+}
+
+impl Display for MetricKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Counter => write!(f, "counter"),
+            Self::Gauge => write!(f, "gauge"),
+        }
+    }
+}
+
+/// Identifies an exported metric
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct MetricKey {
+    /// The Prometheus name of the metric
+    name: String,
+    kind: MetricKind,
+    labels: Vec<(String, String)>,
+}
+
+/// Returns the exported values of the metrics of `plan` and its children,
+/// summed by [`MetricKey`]
+fn samples(plan: &dyn ExecutionPlan) -> BTreeMap<MetricKey, f64> {
+    let mut samples = BTreeMap::new();
+    let mut stack = vec![plan];
+    while let Some(plan) = stack.pop() {
+        stack.extend(plan.children().into_iter().map(|c| c.as_ref()));
+        let Some(metrics) = plan.metrics() else {
+            continue;
+        };
+        for metric in metrics.iter() {
+            let value = metric.value();
+            let (suffix, kind, value) = match value {
+                MetricValue::OutputRows(_)
+                | MetricValue::SpillCount(_)
+                | MetricValue::SpilledBytes(_)
+                | MetricValue::SpilledRows(_)
+                | MetricValue::Count { .. } => {
+                    ("_total", MetricKind::Counter, value.as_usize() as f64)
+                }
+                MetricValue::ElapsedCompute(_) | MetricValue::Time { .. } => (
+                    "_seconds_total",
+                    MetricKind::Counter,
+                    value.as_usize() as f64 / 1e9,
+                ),
+                MetricValue::CurrentMemoryUsage(_) | MetricValue::Gauge { .. } => {
+                    ("", MetricKind::Gauge, value.as_usize() as f64)
+                }
+                // custom values have no known exported semantics
+                MetricValue::Custom { .. }
+                | MetricValue::StartTimestamp(_)
+                | MetricValue::EndTimestamp(_) => continue,
+            };
+            let mut labels = vec![("operator".to_string(), plan.name().to_string())];
+            // the task id is unique to each query, so exporting it would create
+            // a new time series for every query
+            labels.extend(
+                metric
+                    .labels()
+                    .iter()
+                    .filter(|l| l.name() != "task_id")
+                    .map(|l| (sanitize_name(l.name()), l.value().to_string())),
+            );
+            let key = MetricKey {
+                name: format!("datafusion_{}{suffix}", sanitize_name(value.name())),
+                kind,
+                labels,
+            };
+            *samples.entry(key).or_default() += value;
+        }
+    }
+    samples
+}
+
+/// Replaces the characters that are not allowed in Prometheus names
+fn sanitize_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::coalesce_partitions::CoalescePartitionsExec;
+    use crate::collect;
+    use crate::empty::EmptyExec;
+    use crate::memory::MemoryExec;
+
+    use arrow::array::{Int32Array, RecordBatch};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_common::Result;
+    use datafusion_execution::TaskContext;
+
+    /// Executes a plan producing `num_rows` rows from two partitions and
+    /// returns it
+    async fn executed_plan(num_rows: i32) -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(Int32Array::from_iter_values(0..num_rows))],
+        )?;
+        let memory = Arc::new(MemoryExec::try_new(
+            &[vec![batch.clone()], vec![batch]],
+            schema,
+            None,
+        )?);
+        let plan: Arc<dyn ExecutionPlan> = Arc::new(CoalescePartitionsExec::new(memory));
+        collect(Arc::clone(&plan), Arc::new(TaskContext::default())).await?;
+        Ok(plan)
+    }
+
+    /// Returns the lines of the rendered metrics that start with `prefix`
+    fn rendered_lines(registry: &MetricsRegistry, prefix: &str) -> Vec<String> {
+        registry
+            .render_prometheus()
+            .lines()
+            .filter(|line| line.starts_with(prefix))
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn render_prometheus() -> Result<()> {
+        let registry = MetricsRegistry::new(2);
+        assert_eq!(registry.render_prometheus(), "");
+
+        assert_eq!(registry.register(executed_plan(5).await?), 0);
+        assert_eq!(registry.register(executed_plan(10).await?), 1);
+        assert_eq!(
+            rendered_lines(&registry, "datafusion_output_rows"),
+            vec!["datafusion_output_rows_total{operator=\"CoalescePartitionsExec\"} 30"]
+        );
+        assert_eq!(
+            rendered_lines(&registry, "# TYPE datafusion_elapsed_compute"),
+            vec!["# TYPE datafusion_elapsed_compute_seconds_total counter"]
+        );
+
+        // the counters of the evicted queries are kept
+        assert_eq!(registry.register(executed_plan(15).await?), 2);
+        assert_eq!(
+            rendered_lines(&registry, "datafusion_output_rows"),
+            vec!["datafusion_output_rows_total{operator=\"CoalescePartitionsExec\"} 60"]
+        );
+
+        let queries = registry.recent_queries();
+        assert_eq!(
+            queries.iter().map(|q| q.query_id()).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        let operators = queries[0].operator_metrics();
+        assert_eq!(operators.len(), 1);
+        assert_eq!(operators[0].0, "CoalescePartitionsExec");
+        assert_eq!(operators[0].1.output_rows(), Some(20));
+        Ok(())
+    }
+
+    #[test]
+    fn plan_without_metrics() {
+        let registry = MetricsRegistry::new(1);
+        registry.register(Arc::new(EmptyExec::new(Arc::new(Schema::empty()))));
+        assert!(registry.recent_queries()[0].operator_metrics().is_empty());
+        assert_eq!(registry.render_prometheus(), "");
+    }
+
+    #[test]
+    fn sanitize_and_escape() {
+        assert_eq!(sanitize_name("bytes scanned"), "bytes_scanned");
+        assert_eq!(escape_label_value("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+}
@@ -18,8 +18,9 @@
 //! Value representation of metrics
 
 use std::{
+    any::Any,
     borrow::{Borrow, Cow},
-    fmt::Display,
+    fmt::{Debug, Display},
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
@@ -152,6 +153,17 @@ impl Gauge {
 pub struct Time {
     /// elapsed time, in nanoseconds
     nanos: Arc<AtomicUsize>,
+    /// which timers measure the elapsed time, if not all of them
+    sampling: Option<Arc<TimeSampling>>,
+}
+
+/// Samples the timers of a [`Time`], see [`Time::with_sampling_interval`]
+#[derive(Debug)]
+struct TimeSampling {
+    /// only one in `interval` timers measures the elapsed time
+    interval: u32,
+    /// the number of timers started so far
+    timers: AtomicUsize,
 }
 
 impl Default for Time {
@@ -179,6 +191,27 @@ impl Time {
     pub fn new() -> Self {
         Self {
             nanos: Arc::new(AtomicUsize::new(0)),
+            sampling: None,
+        }
+    }
+
+    /// Returns a [`Time`] that updates the same elapsed time as self, but
+    /// whose [`Self::timer`]s only measure one in `interval` of the timed
+    /// operations, and count each measured time `interval` times.
+    ///
+    /// This lowers the overhead of reading the clock when many short
+    /// operations are timed, at the cost of an approximate elapsed time.
+    /// An `interval` of 1 measures every operation.
+    pub fn with_sampling_interval(&self, interval: usize) -> Self {
+        let sampling = (interval > 1).then(|| {
+            Arc::new(TimeSampling {
+                interval: u32::try_from(interval).unwrap_or(u32::MAX),
+                timers: AtomicUsize::new(0),
+            })
+        });
+        Self {
+            nanos: Arc::clone(&self.nanos),
+            sampling,
         }
     }
 
@@ -211,9 +244,21 @@ impl Time {
     /// between its creation and its drop or call to `stop` to the
     /// underlying metric.
     pub fn timer(&self) -> ScopedTimerGuard<'_> {
+        let scale = match &self.sampling {
+            None => 1,
+            Some(sampling) => {
+                let timer = sampling.timers.fetch_add(1, Ordering::Relaxed);
+                if timer % sampling.interval as usize == 0 {
+                    sampling.interval
+                } else {
+                    0
+                }
+            }
+        };
         ScopedTimerGuard {
             inner: self,
-            start: Some(Instant::now()),
+            start: (scale > 0).then(Instant::now),
+            scale,
         }
     }
 
@@ -311,19 +356,24 @@ impl Display for Timestamp {
 pub struct ScopedTimerGuard<'a> {
     inner: &'a Time,
     start: Option<Instant>,
+    /// the number of operations the measured time accounts for, 0 if the
+    /// timer is not sampled
+    scale: u32,
 }
 
 impl<'a> ScopedTimerGuard<'a> {
     /// Stop the timer timing and record the time taken
     pub fn stop(&mut self) {
         if let Some(start) = self.start.take() {
-            self.inner.add_elapsed(start)
+            self.inner.add_duration(start.elapsed() * self.scale)
         }
     }
 
     /// Restarts the timer recording from the current time
     pub fn restart(&mut self) {
-        self.start = Some(Instant::now())
+        if self.scale > 0 {
+            self.start = Some(Instant::now())
+        }
     }
 
     /// Stop the timer, record the time taken and consume self
@@ -338,6 +388,85 @@ impl<'a> Drop for ScopedTimerGuard<'a> {
     }
 }
 
+/// A user defined metric value, for metrics that do not fit any of the
+/// built-in [`MetricValue`] variants.
+///
+/// Like [`Count`] and [`Time`], implementations are expected to use
+/// interior mutability so that the operator can update the value it
+/// registered through a shared handle. [`Display`] is used to show the value
+/// in `EXPLAIN ANALYZE`.
+///
+/// ```
+/// use std::any::Any;
+/// use std::fmt::{self, Display};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use datafusion_physical_plan::metrics::CustomMetricValue;
+///
+/// /// Records the largest batch seen, aggregated across partitions by max
+/// #[derive(Debug, Default)]
+/// struct MaxBatchRows(AtomicUsize);
+///
+/// impl Display for MaxBatchRows {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "{}", self.0.load(Ordering::Relaxed))
+///     }
+/// }
+///
+/// impl CustomMetricValue for MaxBatchRows {
+///     fn new_empty(&self) -> Arc<dyn CustomMetricValue> {
+///         Arc::new(Self::default())
+///     }
+///
+///     fn aggregate(&self, other: Arc<dyn CustomMetricValue>) {
+///         let other = other.as_any().downcast_ref::<Self>().unwrap();
+///         self.0.fetch_max(other.0.load(Ordering::Relaxed), Ordering::Relaxed);
+///     }
+///
+///     fn as_any(&self) -> &dyn Any {
+///         self
+///     }
+///
+///     fn as_usize(&self) -> usize {
+///         self.0.load(Ordering::Relaxed)
+///     }
+///
+///     fn is_eq(&self, other: &dyn CustomMetricValue) -> bool {
+///         other
+///             .as_any()
+///             .downcast_ref::<Self>()
+///             .is_some_and(|other| self.as_usize() == other.as_usize())
+///     }
+/// }
+/// ```
+pub trait CustomMetricValue: Display + Debug + Send + Sync {
+    /// Returns a new value of the same type that holds no data, used to
+    /// accumulate values of this metric from several partitions
+    fn new_empty(&self) -> Arc<dyn CustomMetricValue>;
+
+    /// Aggregates the value of `other` into `self`. `other` is a value of the
+    /// metric with the same name, and is expected to have the same type.
+    fn aggregate(&self, other: Arc<dyn CustomMetricValue>);
+
+    /// Returns the value as [`Any`] so that it can be downcast
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the value as a usize, see [`MetricValue::as_usize`]. Defaults
+    /// to 0 for values that have no numeric representation.
+    fn as_usize(&self) -> usize {
+        0
+    }
+
+    /// Returns true if `other` is of the same type and holds the same value
+    fn is_eq(&self, other: &dyn CustomMetricValue) -> bool;
+}
+
+impl PartialEq for dyn CustomMetricValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_eq(other)
+    }
+}
+
 /// Possible values for a [super::Metric].
 ///
 /// Among other differences, the metric types have different ways to
@@ -396,6 +525,13 @@ pub enum MetricValue {
         /// The value of the metric
         time: Time,
     },
+    /// Operator defined value, see [`CustomMetricValue`]
+    Custom {
+        /// The provided name of this metric
+        name: Cow<'static, str>,
+        /// The value of the metric
+        value: Arc<dyn CustomMetricValue>,
+    },
     /// The time at which execution started
     StartTimestamp(Timestamp),
     /// The time at which execution ended
@@ -415,6 +551,7 @@ impl MetricValue {
             Self::Count { name, .. } => name.borrow(),
             Self::Gauge { name, .. } => name.borrow(),
             Self::Time { name, .. } => name.borrow(),
+            Self::Custom { name, .. } => name.borrow(),
             Self::StartTimestamp(_) => "start_timestamp",
             Self::EndTimestamp(_) => "end_timestamp",
         }
@@ -432,6 +569,7 @@ impl MetricValue {
             Self::Count { count, .. } => count.value(),
             Self::Gauge { gauge, .. } => gauge.value(),
             Self::Time { time, .. } => time.value(),
+            Self::Custom { value, .. } => value.as_usize(),
             Self::StartTimestamp(timestamp) => timestamp
                 .value()
                 .and_then(|ts| ts.timestamp_nanos_opt())
@@ -467,6 +605,10 @@ impl MetricValue {
                 name: name.clone(),
                 time: Time::new(),
             },
+            Self::Custom { name, value } => Self::Custom {
+                name: name.clone(),
+                value: value.new_empty(),
+            },
             Self::StartTimestamp(_) => Self::StartTimestamp(Timestamp::new()),
             Self::EndTimestamp(_) => Self::EndTimestamp(Timestamp::new()),
         }
@@ -507,6 +649,12 @@ impl MetricValue {
                     time: other_time, ..
                 },
             ) => time.add(other_time),
+            (
+                Self::Custom { value, .. },
+                Self::Custom {
+                    value: other_value, ..
+                },
+            ) => value.aggregate(Arc::clone(other_value)),
             // timestamps are aggregated by min/max
             (Self::StartTimestamp(timestamp), Self::StartTimestamp(other_timestamp)) => {
                 timestamp.update_to_min(other_timestamp);
@@ -537,8 +685,9 @@ impl MetricValue {
             Self::Count { .. } => 6,
             Self::Gauge { .. } => 7,
             Self::Time { .. } => 8,
-            Self::StartTimestamp(_) => 9, // show timestamps last
-            Self::EndTimestamp(_) => 10,
+            Self::Custom { .. } => 9,
+            Self::StartTimestamp(_) => 10, // show timestamps last
+            Self::EndTimestamp(_) => 11,
         }
     }
 
@@ -571,6 +720,9 @@ impl Display for MetricValue {
                     write!(f, "NOT RECORDED")
                 }
             }
+            Self::Custom { value, .. } => {
+                write!(f, "{value}")
+            }
             Self::StartTimestamp(timestamp) | Self::EndTimestamp(timestamp) => {
                 write!(f, "{timestamp}")
             }
@@ -627,6 +779,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sampled_time() {
+        let time = Time::new();
+        let sampled = time.with_sampling_interval(3);
+        for _ in 0..3 {
+            let mut timer = sampled.timer();
+            std::thread::sleep(Duration::from_millis(1));
+            timer.stop();
+        }
+        // only the first timer measured its time, counted 3 times
+        let nanos = time.value();
+        assert!(nanos >= 3_000_000, "{nanos}");
+        assert_eq!(nanos % 3, 0);
+
+        time.with_sampling_interval(1).timer().done();
+        assert!(time.value() > nanos);
+    }
+
     #[test]
     fn test_display_timestamp() {
         let timestamp = Timestamp::new();
@@ -168,7 +168,8 @@ impl ExecutionPlan for RecursiveQueryExec {
         }
 
         let static_stream = self.static_term.execute(partition, context.clone())?;
-        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition)
+            .with_sampling_interval(context.session_config().metrics_sampling_interval());
         Ok(Box::pin(RecursiveQueryStream::new(
             context,
             self.work_table.clone(),
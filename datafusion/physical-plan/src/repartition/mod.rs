@@ -632,7 +632,9 @@ impl ExecutionPlan for RepartitionExec {
                     input_streams,
                     schema_captured,
                     &sort_exprs,
-                    BaselineMetrics::new(&metrics, partition),
+                    BaselineMetrics::new(&metrics, partition).with_sampling_interval(
+                        context.session_config().metrics_sampling_interval(),
+                    ),
                     context.session_config().batch_size(),
                     fetch,
                     merge_reservation,
@@ -168,6 +168,11 @@ impl PartialSortExec {
         self.fetch
     }
 
+    /// The number of leading sort expressions the input is already sorted on
+    pub fn common_prefix_length(&self) -> usize {
+        self.common_prefix_length
+    }
+
     fn output_partitioning_helper(
         input: &Arc<dyn ExecutionPlan>,
         preserve_partitioning: bool,
@@ -294,7 +299,10 @@ impl ExecutionPlan for PartialSortExec {
             in_mem_batches: vec![],
             fetch: self.fetch,
             is_closed: false,
-            baseline_metrics: BaselineMetrics::new(&self.metrics_set, partition),
+            baseline_metrics: BaselineMetrics::new(&self.metrics_set, partition)
+                .with_sampling_interval(
+                    context.session_config().metrics_sampling_interval(),
+                ),
         }))
     }
 
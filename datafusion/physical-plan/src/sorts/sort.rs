@@ -33,7 +33,7 @@ use crate::sorts::streaming_merge::streaming_merge;
 use crate::stream::RecordBatchStreamAdapter;
 use crate::topk::TopK;
 use crate::{
-    read_spill_as_stream, spill_record_batches, DisplayAs, DisplayFormatType,
+    read_spill_as_stream, spill_record_batches_to_file, DisplayAs, DisplayFormatType,
     Distribution, EmptyRecordBatchStream, ExecutionMode, ExecutionPlan,
     ExecutionPlanProperties, Partitioning, PlanProperties, SendableRecordBatchStream,
     Statistics,
@@ -70,12 +70,25 @@ struct ExternalSorterMetrics {
 }
 
 impl ExternalSorterMetrics {
-    fn new(metrics: &ExecutionPlanMetricsSet, partition: usize) -> Self {
+    /// The spill metrics are labelled with `task_id`, if any, so that they
+    /// can be correlated with the spill files of the query
+    fn new(
+        metrics: &ExecutionPlanMetricsSet,
+        partition: usize,
+        task_id: Option<String>,
+    ) -> Self {
+        let spill_metric = || {
+            let builder = MetricBuilder::new(metrics);
+            match &task_id {
+                Some(task_id) => builder.with_new_label("task_id", task_id.clone()),
+                None => builder,
+            }
+        };
         Self {
             baseline: BaselineMetrics::new(metrics, partition),
-            spill_count: MetricBuilder::new(metrics).spill_count(partition),
-            spilled_bytes: MetricBuilder::new(metrics).spilled_bytes(partition),
-            spilled_rows: MetricBuilder::new(metrics).spilled_rows(partition),
+            spill_count: spill_metric().spill_count(partition),
+            spilled_bytes: spill_metric().spilled_bytes(partition),
+            spilled_rows: spill_metric().spilled_rows(partition),
         }
     }
 }
@@ -247,8 +260,9 @@ impl ExternalSorter {
         sort_in_place_threshold_bytes: usize,
         metrics: &ExecutionPlanMetricsSet,
         runtime: Arc<RuntimeEnv>,
+        task_id: Option<String>,
     ) -> Self {
-        let metrics = ExternalSorterMetrics::new(metrics, partition_id);
+        let metrics = ExternalSorterMetrics::new(metrics, partition_id, task_id);
         let reservation = MemoryConsumer::new(format!("ExternalSorter[{partition_id}]"))
             .with_can_spill(true)
             .register(&runtime.memory_pool);
@@ -395,7 +409,7 @@ impl ExternalSorter {
         let spill_file = self.runtime.disk_manager.create_tmp_file("Sorting")?;
         let batches = std::mem::take(&mut self.in_mem_batches);
         let spilled_rows =
-            spill_record_batches(batches, spill_file.path().into(), self.schema.clone())?;
+            spill_record_batches_to_file(batches, &spill_file, self.schema.clone())?;
         let used = self.reservation.free();
         self.metrics.spill_count.add(1);
         self.metrics.spilled_bytes.add(used);
@@ -859,6 +873,7 @@ impl ExecutionPlan for SortExec {
         trace!("Start SortExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
 
         let mut input = self.input.execute(partition, context.clone())?;
+        let cancellation_token = context.cancellation_token().clone();
 
         let execution_options = &context.session_config().options().execution;
 
@@ -881,6 +896,7 @@ impl ExecutionPlan for SortExec {
                 futures::stream::once(async move {
                     while let Some(batch) = input.next().await {
                         let batch = batch?;
+                        cancellation_token.check()?;
                         topk.insert_batch(batch)?;
                     }
                     topk.emit()
@@ -898,6 +914,7 @@ impl ExecutionPlan for SortExec {
                 execution_options.sort_in_place_threshold_bytes,
                 &self.metrics_set,
                 context.runtime_env(),
+                context.task_id(),
             );
 
             Ok(Box::pin(RecordBatchStreamAdapter::new(
@@ -905,6 +922,7 @@ impl ExecutionPlan for SortExec {
                 futures::stream::once(async move {
                     while let Some(batch) = input.next().await {
                         let batch = batch?;
+                        cancellation_token.check()?;
                         sorter.insert_batch(batch).await?;
                     }
                     sorter.sort()
@@ -943,6 +961,7 @@ mod tests {
     use datafusion_execution::config::SessionConfig;
     use datafusion_execution::runtime_env::RuntimeConfig;
 
+    use crate::metrics::Label;
     use datafusion_common::ScalarValue;
     use datafusion_physical_expr::expressions::Literal;
     use futures::FutureExt;
@@ -987,11 +1006,15 @@ mod tests {
         let rt_config = RuntimeConfig::new()
             .with_memory_limit(sort_spill_reservation_bytes + 12288, 1.0);
         let runtime = Arc::new(RuntimeEnv::new(rt_config)?);
-        let task_ctx = Arc::new(
-            TaskContext::default()
-                .with_session_config(session_config)
-                .with_runtime(runtime),
-        );
+        let task_ctx = Arc::new(TaskContext::new(
+            Some("query-1".to_string()),
+            "session".to_string(),
+            session_config,
+            HashMap::default(),
+            HashMap::default(),
+            HashMap::default(),
+            runtime,
+        ));
 
         let partitions = 100;
         let input = test::scan_partitioned(partitions);
@@ -1017,6 +1040,15 @@ mod tests {
         assert_eq!(metrics.spill_count().unwrap(), 4);
         assert_eq!(metrics.spilled_bytes().unwrap(), 38784);
         assert_eq!(metrics.spilled_rows().unwrap(), 9600);
+        // the spill metrics are labelled with the task id
+        let spill_metrics = metrics
+            .iter()
+            .filter(|m| m.value().name().starts_with("spill"))
+            .collect::<Vec<_>>();
+        assert_eq!(spill_metrics.len(), 3);
+        for metric in spill_metrics {
+            assert_eq!(metric.labels(), &[Label::new("task_id", "query-1")]);
+        }
 
         let columns = result[0].columns();
 
@@ -237,7 +237,10 @@ impl ExecutionPlan for SortPreservingMergeExec {
                     receivers,
                     schema,
                     &self.expr,
-                    BaselineMetrics::new(&self.metrics, partition),
+                    BaselineMetrics::new(&self.metrics, partition)
+                        .with_sampling_interval(
+                            context.session_config().metrics_sampling_interval(),
+                        ),
                     context.session_config().batch_size(),
                     self.fetch,
                     reservation,
@@ -21,13 +21,16 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 
 use super::metrics::BaselineMetrics;
 use super::{ExecutionPlan, RecordBatchStream, SendableRecordBatchStream};
+use crate::display::DisplayableExecutionPlan;
 use crate::displayable;
 
 use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
-use datafusion_common::{internal_err, Result};
+use datafusion_common::{internal_err, DataFusionError, Result};
+use datafusion_execution::cancellation::CancellationToken;
 use datafusion_execution::TaskContext;
 
 use futures::stream::BoxStream;
@@ -36,6 +39,7 @@ use log::debug;
 use pin_project_lite::pin_project;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::task::JoinSet;
+use tokio::time::{Instant, Sleep};
 
 /// Creates a stream from a collection of producing tasks, routing panics to the stream.
 ///
@@ -454,6 +458,81 @@ impl futures::Stream for ObservedStream {
     }
 }
 
+/// Stream wrapper that aborts the execution of a plan with a
+/// [`DataFusionError::ExecutionTimeout`] once its deadline is reached.
+///
+/// On timeout, `token` is cancelled so that the operators of the plan that
+/// are running in other tasks stop as well.
+pub(crate) struct TimeoutStream {
+    schema: SchemaRef,
+    /// The inner stream, or `None` once the timeout has been reported
+    inner: Option<SendableRecordBatchStream>,
+    /// The plan, used to report its metrics on timeout
+    plan: Arc<dyn ExecutionPlan>,
+    token: CancellationToken,
+    timeout: Duration,
+    deadline: Instant,
+    /// Created on the first poll, so that the stream can be created outside
+    /// of a tokio runtime
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl TimeoutStream {
+    pub fn new(
+        inner: SendableRecordBatchStream,
+        plan: Arc<dyn ExecutionPlan>,
+        token: CancellationToken,
+        timeout: Duration,
+        deadline: Instant,
+    ) -> Self {
+        Self {
+            schema: inner.schema(),
+            inner: Some(inner),
+            plan,
+            token,
+            timeout,
+            deadline,
+            sleep: None,
+        }
+    }
+}
+
+impl RecordBatchStream for TimeoutStream {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+impl Stream for TimeoutStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let Some(inner) = this.inner.as_mut() else {
+            return Poll::Ready(None);
+        };
+        let deadline = this.deadline;
+        let sleep = this
+            .sleep
+            .get_or_insert_with(|| Box::pin(tokio::time::sleep_until(deadline)));
+        if sleep.as_mut().poll(cx).is_ready() {
+            this.token.cancel();
+            this.inner = None;
+            let metrics = DisplayableExecutionPlan::with_metrics(this.plan.as_ref())
+                .indent(true)
+                .to_string();
+            return Poll::Ready(Some(Err(DataFusionError::ExecutionTimeout(
+                this.timeout,
+                metrics,
+            ))));
+        }
+        inner.poll_next_unpin(cx)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -463,6 +542,7 @@ mod test {
 
     use arrow_schema::{DataType, Field, Schema};
     use datafusion_common::exec_err;
+    use datafusion_execution::config::SessionConfig;
 
     fn schema() -> SchemaRef {
         Arc::new(Schema::new(vec![Field::new("a", DataType::Float32, true)]))
@@ -546,6 +626,28 @@ mod test {
         assert!(stream.next().await.is_none());
     }
 
+    #[tokio::test]
+    async fn execute_stream_timeout() {
+        let timeout = Duration::from_millis(10);
+        let config = SessionConfig::new().with_query_timeout(timeout);
+        let task_ctx = Arc::new(TaskContext::default().with_session_config(config));
+
+        // Make an input that never proceeds
+        let input = Arc::new(BlockingExec::new(schema(), 2));
+        let refs = input.refs();
+
+        match crate::collect(input, task_ctx).await.unwrap_err() {
+            DataFusionError::ExecutionTimeout(t, metrics) => {
+                assert_eq!(t, timeout);
+                assert!(metrics.contains("BlockingExec"), "{metrics}");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+
+        // the execution is aborted
+        assert_strong_count_converges_to_zero(refs).await;
+    }
+
     /// Consumes all the input's partitions into a
     /// RecordBatchReceiverStream and runs it to completion
     ///
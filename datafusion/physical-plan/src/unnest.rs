@@ -96,6 +96,26 @@ impl UnnestExec {
         }
     }
 
+    /// Input execution plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// Indices of the list-typed columns in the input schema
+    pub fn list_column_indices(&self) -> &[usize] {
+        &self.list_column_indices
+    }
+
+    /// Indices of the struct-typed columns in the input schema
+    pub fn struct_column_indices(&self) -> &[usize] {
+        &self.struct_column_indices
+    }
+
+    /// Options of the unnest
+    pub fn options(&self) -> &UnnestOptions {
+        &self.options
+    }
+
     /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
     fn compute_properties(
         input: &Arc<dyn ExecutionPlan>,
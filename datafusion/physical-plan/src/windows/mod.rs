@@ -31,6 +31,7 @@ use crate::{
 
 use arrow::datatypes::Schema;
 use arrow_schema::{DataType, Field, SchemaRef};
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{exec_err, Column, DataFusionError, Result, ScalarValue};
 use datafusion_expr::Expr;
 use datafusion_expr::{
@@ -101,11 +102,56 @@ pub fn create_window_expr(
     input_schema: &Schema,
     ignore_nulls: bool,
 ) -> Result<Arc<dyn WindowExpr>> {
+    create_window_expr_with_options(
+        fun,
+        name,
+        args,
+        logical_args,
+        partition_by,
+        order_by,
+        window_frame,
+        input_schema,
+        ignore_nulls,
+        &WindowExprOptions::default(),
+    )
+}
+
+/// Options of the window expressions created by
+/// [`create_window_expr_with_options`]
+#[derive(Debug, Clone, Default)]
+pub struct WindowExprOptions {
+    /// Whether aggregate functions only aggregate distinct values, as in
+    /// `COUNT(DISTINCT x) OVER (...)`
+    pub distinct: bool,
+    /// How aggregate UDFs handle integer overflow, see
+    /// `datafusion.execution.arithmetic_overflow_mode`
+    pub overflow_mode: ArithmeticOverflowMode,
+}
+
+/// Create a physical expression for window function, with the given
+/// [`WindowExprOptions`]
+#[allow(clippy::too_many_arguments)]
+pub fn create_window_expr_with_options(
+    fun: &WindowFunctionDefinition,
+    name: String,
+    args: &[Arc<dyn PhysicalExpr>],
+    logical_args: &[Expr],
+    partition_by: &[Arc<dyn PhysicalExpr>],
+    order_by: &[PhysicalSortExpr],
+    window_frame: Arc<WindowFrame>,
+    input_schema: &Schema,
+    ignore_nulls: bool,
+    options: &WindowExprOptions,
+) -> Result<Arc<dyn WindowExpr>> {
+    let WindowExprOptions {
+        distinct,
+        overflow_mode,
+    } = *options;
     Ok(match fun {
         WindowFunctionDefinition::AggregateFunction(fun) => {
             let aggregate = aggregates::create_aggregate_expr(
                 fun,
-                false,
+                distinct,
                 args,
                 &[],
                 input_schema,
@@ -146,7 +192,7 @@ pub fn create_window_expr(
                 })
                 .collect::<Vec<_>>();
 
-            let aggregate = udaf::create_aggregate_expr(
+            let aggregate = udaf::create_aggregate_function_expr(
                 fun.as_ref(),
                 args,
                 logical_args,
@@ -155,8 +201,10 @@ pub fn create_window_expr(
                 input_schema,
                 name,
                 ignore_nulls,
-                false,
-            )?;
+                distinct,
+            )?
+            .with_arithmetic_overflow_mode(overflow_mode);
+            let aggregate = Arc::new(aggregate) as Arc<dyn AggregateExpr>;
             window_expr_from_aggregate_expr(
                 partition_by,
                 order_by,
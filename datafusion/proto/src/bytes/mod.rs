@@ -244,6 +244,88 @@ pub fn logical_plan_from_bytes_with_extension_codec(
     protobuf.try_into_logical_plan(ctx, extension_codec)
 }
 
+/// Serialize the [`FunctionManifest`] of the user defined functions called
+/// by a LogicalPlan as bytes
+///
+/// [`FunctionManifest`]: protobuf::FunctionManifest
+pub fn function_manifest_to_bytes(plan: &LogicalPlan) -> Result<Bytes> {
+    let manifest = logical_plan::functions::function_manifest(plan)?;
+    let mut buffer = BytesMut::new();
+    manifest
+        .encode(&mut buffer)
+        .map_err(|e| plan_datafusion_err!("Error encoding protobuf as bytes: {e}"))?;
+    Ok(buffer.into())
+}
+
+/// Deserialize a LogicalPlan from bytes, after checking that `ctx` provides
+/// the functions listed in `manifest` (the output of
+/// [`function_manifest_to_bytes`]).
+///
+/// Returns an error listing all the missing functions if any are not
+/// registered with `ctx`, instead of failing on the first one.
+pub fn logical_plan_from_bytes_with_function_manifest(
+    bytes: &[u8],
+    manifest: &[u8],
+    ctx: &SessionContext,
+    extension_codec: &dyn LogicalExtensionCodec,
+) -> Result<LogicalPlan> {
+    let manifest = protobuf::FunctionManifest::decode(manifest).map_err(|e| {
+        plan_datafusion_err!("Error decoding function manifest as protobuf: {e}")
+    })?;
+    logical_plan::functions::check_function_manifest(&manifest, ctx)?;
+    logical_plan_from_bytes_with_extension_codec(bytes, ctx, extension_codec)
+}
+
+/// Serialize the [`SessionSnapshot`] of `ctx` as bytes, see
+/// [`session_snapshot`] for its contents
+///
+/// [`SessionSnapshot`]: protobuf::SessionSnapshot
+/// [`session_snapshot`]: logical_plan::session::session_snapshot
+pub async fn session_snapshot_to_bytes(ctx: &SessionContext) -> Result<Bytes> {
+    let snapshot = logical_plan::session::session_snapshot(ctx).await?;
+    let mut buffer = BytesMut::new();
+    snapshot
+        .encode(&mut buffer)
+        .map_err(|e| plan_datafusion_err!("Error encoding protobuf as bytes: {e}"))?;
+    Ok(buffer.into())
+}
+
+/// Serialize the [`SessionSnapshot`] of `ctx` as JSON
+///
+/// [`SessionSnapshot`]: protobuf::SessionSnapshot
+#[cfg(feature = "json")]
+pub async fn session_snapshot_to_json(ctx: &SessionContext) -> Result<String> {
+    let snapshot = logical_plan::session::session_snapshot(ctx).await?;
+    serde_json::to_string(&snapshot)
+        .map_err(|e| plan_datafusion_err!("Error serializing session snapshot: {e}"))
+}
+
+/// Restore a session snapshot serialized by [`session_snapshot_to_bytes`]
+/// into `ctx`, see [`restore_session_snapshot`] for details
+///
+/// [`restore_session_snapshot`]: logical_plan::session::restore_session_snapshot
+pub async fn restore_session_snapshot_from_bytes(
+    bytes: &[u8],
+    ctx: &SessionContext,
+) -> Result<()> {
+    let snapshot = protobuf::SessionSnapshot::decode(bytes).map_err(|e| {
+        plan_datafusion_err!("Error decoding session snapshot as protobuf: {e}")
+    })?;
+    logical_plan::session::restore_session_snapshot(&snapshot, ctx).await
+}
+
+/// Restore a session snapshot serialized by [`session_snapshot_to_json`]
+/// into `ctx`
+#[cfg(feature = "json")]
+pub async fn restore_session_snapshot_from_json(
+    json: &str,
+    ctx: &SessionContext,
+) -> Result<()> {
+    let snapshot: protobuf::SessionSnapshot = serde_json::from_str(json)
+        .map_err(|e| plan_datafusion_err!("Error deserializing session snapshot: {e}"))?;
+    logical_plan::session::restore_session_snapshot(&snapshot, ctx).await
+}
+
 /// Serialize a PhysicalPlan as bytes
 pub fn physical_plan_to_bytes(plan: Arc<dyn ExecutionPlan>) -> Result<Bytes> {
     let extension_codec = DefaultPhysicalExtensionCodec {};
@@ -321,7 +321,7 @@ pub fn parse_expr(
                         order_by,
                         window_frame,
                         None
-                    )))
+                    ).with_distinct(expr.distinct)))
                 }
                 window_expr_node::WindowFunction::BuiltInFunction(i) => {
                     let built_in_function = protobuf::BuiltInWindowFunction::try_from(*i)
@@ -350,16 +350,19 @@ pub fn parse_expr(
                         parse_optional_expr(expr.expr.as_deref(), registry, codec)?
                             .map(|e| vec![e])
                             .unwrap_or_else(Vec::new);
-                    Ok(Expr::WindowFunction(WindowFunction::new(
-                        datafusion_expr::expr::WindowFunctionDefinition::AggregateUDF(
-                            udaf_function,
-                        ),
-                        args,
-                        partition_by,
-                        order_by,
-                        window_frame,
-                        None,
-                    )))
+                    Ok(Expr::WindowFunction(
+                        WindowFunction::new(
+                            datafusion_expr::expr::WindowFunctionDefinition::AggregateUDF(
+                                udaf_function,
+                            ),
+                            args,
+                            partition_by,
+                            order_by,
+                            window_frame,
+                            None,
+                        )
+                        .with_distinct(expr.distinct),
+                    ))
                 }
                 window_expr_node::WindowFunction::Udwf(udwf_name) => {
                     let udwf_function = registry.udwf(udwf_name)?;
@@ -672,7 +675,7 @@ where
 }
 
 /// Parse an optional escape_char for Like, ILike, SimilarTo
-fn parse_escape_char(s: &str) -> Result<Option<char>> {
+pub(crate) fn parse_escape_char(s: &str) -> Result<Option<char>> {
     match s.len() {
         0 => Ok(None),
         1 => Ok(s.chars().next()),
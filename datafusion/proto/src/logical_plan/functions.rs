@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`FunctionManifest`] of the user defined functions called by a plan.
+//!
+//! Functions are serialized by name only, so a plan can only be deserialized
+//! by a consumer that registered the same functions. Sending the manifest of
+//! a plan along with the plan lets the consumer check, before decoding the
+//! plan, that it provides every function the plan calls with the expected
+//! return types, and report all the missing functions at once.
+//!
+//! [`FunctionManifest`]: protobuf::FunctionManifest
+
+use arrow::datatypes::DataType;
+use datafusion::execution::registry::FunctionRegistry;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_common::{plan_err, DFSchema, Result};
+use datafusion_expr::expr::{
+    AggregateFunction, AggregateFunctionDefinition, ScalarFunction, WindowFunction,
+};
+use datafusion_expr::{Expr, ExprSchemable, LogicalPlan, WindowFunctionDefinition};
+
+use crate::protobuf;
+
+/// Returns the [`protobuf::FunctionManifest`] of the user defined scalar,
+/// aggregate and window functions called by `plan`, including its subqueries.
+///
+/// Each function is listed once per distinct list of argument types. The
+/// argument and return types are omitted when they can not be determined, for
+/// example for arguments that reference an outer query.
+pub fn function_manifest(plan: &LogicalPlan) -> Result<protobuf::FunctionManifest> {
+    let mut manifest = protobuf::FunctionManifest::default();
+    plan.apply_with_subqueries(|plan| {
+        let schemas = plan
+            .inputs()
+            .into_iter()
+            .map(|input| input.schema().as_ref())
+            .chain(std::iter::once(plan.schema().as_ref()))
+            .collect::<Vec<_>>();
+        plan.apply_expressions(|expr| {
+            expr.apply(|expr| {
+                let (list, name, args) = match expr {
+                    Expr::ScalarFunction(ScalarFunction { func, args }) => {
+                        (&mut manifest.scalar_functions, func.name(), args)
+                    }
+                    Expr::AggregateFunction(AggregateFunction {
+                        func_def: AggregateFunctionDefinition::UDF(fun),
+                        args,
+                        ..
+                    }) => (&mut manifest.aggregate_functions, fun.name(), args),
+                    Expr::WindowFunction(WindowFunction {
+                        fun: WindowFunctionDefinition::AggregateUDF(fun),
+                        args,
+                        ..
+                    }) => (&mut manifest.aggregate_functions, fun.name(), args),
+                    Expr::WindowFunction(WindowFunction {
+                        fun: WindowFunctionDefinition::WindowUDF(fun),
+                        args,
+                        ..
+                    }) => (&mut manifest.window_functions, fun.name(), args),
+                    _ => return Ok(TreeNodeRecursion::Continue),
+                };
+                let signature = function_signature(name, expr, args, &schemas)?;
+                if !list.contains(&signature) {
+                    list.push(signature);
+                }
+                Ok(TreeNodeRecursion::Continue)
+            })
+        })
+    })?;
+    Ok(manifest)
+}
+
+/// Returns the signature of the call `expr` to the function `name`, typing
+/// its arguments with the first of `schemas` they can be resolved against
+fn function_signature(
+    name: &str,
+    expr: &Expr,
+    args: &[Expr],
+    schemas: &[&DFSchema],
+) -> Result<protobuf::FunctionSignature> {
+    let types = schemas.iter().find_map(|schema| {
+        let arg_types = args
+            .iter()
+            .map(|arg| arg.get_type(*schema))
+            .collect::<Result<Vec<_>>>()
+            .ok()?;
+        let return_type = expr.get_type(*schema).ok()?;
+        Some((arg_types, return_type))
+    });
+
+    let mut signature = protobuf::FunctionSignature {
+        name: name.to_string(),
+        ..Default::default()
+    };
+    if let Some((arg_types, return_type)) = types {
+        signature.arg_types = arg_types
+            .iter()
+            .map(protobuf::ArrowType::try_from)
+            .collect::<Result<_, _>>()?;
+        signature.return_type = Some(protobuf::ArrowType::try_from(&return_type)?);
+    }
+    Ok(signature)
+}
+
+/// Checks that `registry` provides every function listed in `manifest`,
+/// returning an error listing all the missing functions otherwise.
+///
+/// When the manifest records the types of a call, the return type of the
+/// registered function for the same argument types must match the recorded
+/// return type.
+pub fn check_function_manifest(
+    manifest: &protobuf::FunctionManifest,
+    registry: &dyn FunctionRegistry,
+) -> Result<()> {
+    let mut missing = vec![];
+    let mut mismatched = vec![];
+    let mut check = |kind: &str,
+                     signature: &protobuf::FunctionSignature,
+                     return_type: Option<Result<DataType>>|
+     -> Result<()> {
+        let Some(return_type) = return_type else {
+            let name = format!("{kind} function '{}'", signature.name);
+            if !missing.contains(&name) {
+                missing.push(name);
+            }
+            return Ok(());
+        };
+        let (Some(expected), Ok(actual)) = (&signature.return_type, return_type) else {
+            return Ok(());
+        };
+        let expected = DataType::try_from(expected)?;
+        if expected != actual {
+            let arg_types = arg_types(signature)?;
+            mismatched.push(format!(
+                "{kind} function '{}' returns {actual} instead of {expected} \
+                 for arguments {arg_types:?}",
+                signature.name
+            ));
+        }
+        Ok(())
+    };
+
+    for signature in &manifest.scalar_functions {
+        let return_type = registry.udf(&signature.name).ok().map(|udf| {
+            let arg_types = arg_types(signature)?;
+            udf.inner().return_type(&arg_types)
+        });
+        check("scalar", signature, return_type)?;
+    }
+    for signature in &manifest.aggregate_functions {
+        let return_type = registry
+            .udaf(&signature.name)
+            .ok()
+            .map(|udaf| udaf.return_type(&arg_types(signature)?));
+        check("aggregate", signature, return_type)?;
+    }
+    for signature in &manifest.window_functions {
+        let return_type = registry
+            .udwf(&signature.name)
+            .ok()
+            .map(|udwf| udwf.return_type(&arg_types(signature)?));
+        check("window", signature, return_type)?;
+    }
+
+    if !missing.is_empty() {
+        return plan_err!(
+            "The plan calls functions that are not registered: {}",
+            missing.join(", ")
+        );
+    }
+    if !mismatched.is_empty() {
+        return plan_err!(
+            "The plan calls functions with different return types: {}",
+            mismatched.join(", ")
+        );
+    }
+    Ok(())
+}
+
+fn arg_types(signature: &protobuf::FunctionSignature) -> Result<Vec<DataType>> {
+    Ok(signature
+        .arg_types
+        .iter()
+        .map(DataType::try_from)
+        .collect::<Result<_, _>>()?)
+}
@@ -70,6 +70,8 @@ use self::to_proto::serialize_expr;
 
 pub mod file_formats;
 pub mod from_proto;
+pub mod functions;
+pub mod session;
 pub mod to_proto;
 
 pub trait AsLogicalPlan: Debug + Send + Sync + Clone {
@@ -183,6 +185,213 @@ impl LogicalExtensionCodec for DefaultLogicalExtensionCodec {
     }
 }
 
+/// A [`LogicalExtensionCodec`] that combines several codecs, each registered
+/// under a name, so that independent libraries can each provide the codec of
+/// their own extensions, table providers and functions.
+///
+/// An extension node or table provider is encoded by the first registered
+/// codec that does not return an error, and the encoding is tagged with the
+/// name of that codec, which is used to decode it. The registered codecs must
+/// therefore return an error for the nodes they do not support. Functions are
+/// encoded by the first codec that writes a definition for them.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion_proto::logical_plan::{ComposedLogicalExtensionCodec, DefaultLogicalExtensionCodec};
+/// let codec = ComposedLogicalExtensionCodec::new()
+///     .with_codec("my_tables", Arc::new(DefaultLogicalExtensionCodec {}))
+///     .with_codec("my_udfs", Arc::new(DefaultLogicalExtensionCodec {}));
+/// assert!(codec.codec("my_udfs").is_some());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ComposedLogicalExtensionCodec {
+    codecs: Vec<(String, Arc<dyn LogicalExtensionCodec>)>,
+}
+
+impl ComposedLogicalExtensionCodec {
+    /// Create a codec without any registered codec
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `codec` under `name`, see [`Self::register_codec`]
+    pub fn with_codec(
+        mut self,
+        name: impl Into<String>,
+        codec: Arc<dyn LogicalExtensionCodec>,
+    ) -> Self {
+        self.register_codec(name, codec);
+        self
+    }
+
+    /// Register `codec` under `name`, returning the codec previously
+    /// registered under this name, if any.
+    ///
+    /// The codecs are tried in the order in which they were first registered.
+    pub fn register_codec(
+        &mut self,
+        name: impl Into<String>,
+        codec: Arc<dyn LogicalExtensionCodec>,
+    ) -> Option<Arc<dyn LogicalExtensionCodec>> {
+        let name = name.into();
+        match self.codecs.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, codec)),
+            None => {
+                self.codecs.push((name, codec));
+                None
+            }
+        }
+    }
+
+    /// Returns the codec registered under `name`
+    pub fn codec(&self, name: &str) -> Option<&Arc<dyn LogicalExtensionCodec>> {
+        self.codecs.iter().find(|(n, _)| n == name).map(|(_, c)| c)
+    }
+
+    /// Decodes a [`protobuf::ComposedExtensionBlob`] and calls `decode` with
+    /// the codec it was encoded by
+    fn decode<T>(
+        &self,
+        buf: &[u8],
+        decode: impl FnOnce(&dyn LogicalExtensionCodec, &[u8]) -> Result<T>,
+    ) -> Result<T> {
+        let blob = protobuf::ComposedExtensionBlob::decode(buf).map_err(|e| {
+            internal_datafusion_err!("Failed to decode composed extension: {e}")
+        })?;
+        match self.codec(&blob.codec) {
+            Some(codec) => decode(codec.as_ref(), &blob.blob),
+            None => not_impl_err!("No extension codec registered as '{}'", blob.codec),
+        }
+    }
+
+    /// Encodes with the first codec for which `encode` succeeds
+    fn encode(
+        &self,
+        buf: &mut Vec<u8>,
+        mut encode: impl FnMut(&dyn LogicalExtensionCodec, &mut Vec<u8>) -> Result<()>,
+    ) -> Result<()> {
+        let mut errors = vec![];
+        for (name, codec) in &self.codecs {
+            let mut blob = vec![];
+            match encode(codec.as_ref(), &mut blob) {
+                Ok(()) => return encode_composed_blob(name, blob, buf),
+                Err(e) => errors.push(format!("{name}: {e}")),
+            }
+        }
+        not_impl_err!(
+            "No registered extension codec can encode the node: [{}]",
+            errors.join(", ")
+        )
+    }
+}
+
+impl LogicalExtensionCodec for ComposedLogicalExtensionCodec {
+    fn try_decode(
+        &self,
+        buf: &[u8],
+        inputs: &[LogicalPlan],
+        ctx: &SessionContext,
+    ) -> Result<Extension> {
+        self.decode(buf, |codec, buf| codec.try_decode(buf, inputs, ctx))
+    }
+
+    fn try_encode(&self, node: &Extension, buf: &mut Vec<u8>) -> Result<()> {
+        self.encode(buf, |codec, buf| codec.try_encode(node, buf))
+    }
+
+    fn try_decode_table_provider(
+        &self,
+        buf: &[u8],
+        table_ref: &TableReference,
+        schema: SchemaRef,
+        ctx: &SessionContext,
+    ) -> Result<Arc<dyn TableProvider>> {
+        self.decode(buf, |codec, buf| {
+            codec.try_decode_table_provider(buf, table_ref, schema, ctx)
+        })
+    }
+
+    fn try_encode_table_provider(
+        &self,
+        table_ref: &TableReference,
+        node: Arc<dyn TableProvider>,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        self.encode(buf, |codec, buf| {
+            codec.try_encode_table_provider(table_ref, Arc::clone(&node), buf)
+        })
+    }
+
+    fn try_decode_file_format(
+        &self,
+        buf: &[u8],
+        ctx: &SessionContext,
+    ) -> Result<Arc<dyn FileFormatFactory>> {
+        // file formats are not tagged, as their encoding is not written to
+        // `buf`: use the first codec that can decode it
+        let mut errors = vec![];
+        for (name, codec) in &self.codecs {
+            match codec.try_decode_file_format(buf, ctx) {
+                Ok(format) => return Ok(format),
+                Err(e) => errors.push(format!("{name}: {e}")),
+            }
+        }
+        not_impl_err!(
+            "No registered extension codec can decode the file format: [{}]",
+            errors.join(", ")
+        )
+    }
+
+    fn try_encode_file_format(
+        &self,
+        buf: &[u8],
+        node: Arc<dyn FileFormatFactory>,
+    ) -> Result<()> {
+        let mut errors = vec![];
+        for (name, codec) in &self.codecs {
+            match codec.try_encode_file_format(buf, Arc::clone(&node)) {
+                Ok(()) => return Ok(()),
+                Err(e) => errors.push(format!("{name}: {e}")),
+            }
+        }
+        not_impl_err!(
+            "No registered extension codec can encode the file format: [{}]",
+            errors.join(", ")
+        )
+    }
+
+    fn try_decode_udf(&self, name: &str, buf: &[u8]) -> Result<Arc<ScalarUDF>> {
+        self.decode(buf, |codec, buf| codec.try_decode_udf(name, buf))
+    }
+
+    fn try_encode_udf(&self, node: &ScalarUDF, buf: &mut Vec<u8>) -> Result<()> {
+        for (name, codec) in &self.codecs {
+            let mut blob = vec![];
+            codec.try_encode_udf(node, &mut blob)?;
+            // codecs that do not know the function do not write a definition
+            if !blob.is_empty() {
+                return encode_composed_blob(name, blob, buf);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes `blob`, tagged with the name of the `codec` that encoded it, to `buf`
+pub(crate) fn encode_composed_blob(
+    codec: &str,
+    blob: Vec<u8>,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    protobuf::ComposedExtensionBlob {
+        codec: codec.to_string(),
+        blob,
+    }
+    .encode(buf)
+    .map_err(|e| internal_datafusion_err!("Failed to encode composed extension: {e}"))
+}
+
 #[macro_export]
 macro_rules! into_logical_plan {
     ($PB:expr, $CTX:expr, $CODEC:expr) => {{
@@ -592,7 +801,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                     name: from_table_reference(create_view.name.as_ref(), "CreateView")?,
                     input: Arc::new(plan),
                     or_replace: create_view.or_replace,
+                    if_not_exists: create_view.if_not_exists,
                     definition,
+                    temporary: create_view.temporary,
                 })))
             }
             LogicalPlanType::CreateCatalogSchema(create_catalog_schema) => {
@@ -1418,7 +1629,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                 name,
                 input,
                 or_replace,
+                if_not_exists,
                 definition,
+                temporary,
             })) => Ok(protobuf::LogicalPlanNode {
                 logical_plan_type: Some(LogicalPlanType::CreateView(Box::new(
                     protobuf::CreateViewNode {
@@ -1429,6 +1642,8 @@ impl AsLogicalPlan for LogicalPlanNode {
                         )?)),
                         or_replace: *or_replace,
                         definition: definition.clone().unwrap_or_default(),
+                        if_not_exists: *if_not_exists,
+                        temporary: *temporary,
                     },
                 ))),
             }),
@@ -0,0 +1,275 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`SessionSnapshot`] of the state of a [`SessionContext`].
+//!
+//! User code such as table providers or functions can not be serialized, so a
+//! snapshot only contains what a new context can recreate on its own: the
+//! configuration options that were changed and the tables that were created
+//! with DDL statements, such as `CREATE EXTERNAL TABLE` or `CREATE VIEW`. The
+//! names of the functions registered by the user are also saved, to check
+//! that they are registered again before the snapshot is restored. The
+//! built-in functions are not saved, so that a snapshot can be restored by a
+//! version of DataFusion whose built-in functions differ.
+//!
+//! [`SessionSnapshot`]: protobuf::SessionSnapshot
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use datafusion::datasource::TableType;
+use datafusion::prelude::SessionContext;
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::{plan_err, DFSchema, Result, TableReference};
+use datafusion_expr::logical_plan::{
+    CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateView, DdlStatement,
+    SetVariable, Statement,
+};
+use datafusion_expr::LogicalPlan;
+
+use crate::protobuf;
+
+/// Returns the [`protobuf::SessionSnapshot`] of `ctx`.
+///
+/// Only the tables that return their definition with
+/// [`TableProvider::get_table_definition`] are saved, views after the other
+/// tables.
+///
+/// [`TableProvider::get_table_definition`]: datafusion::datasource::TableProvider::get_table_definition
+pub async fn session_snapshot(ctx: &SessionContext) -> Result<protobuf::SessionSnapshot> {
+    let state = ctx.state();
+
+    let defaults = ConfigOptions::new()
+        .entries()
+        .into_iter()
+        .map(|entry| (entry.key, entry.value))
+        .collect::<HashMap<_, _>>();
+    let settings = state
+        .config_options()
+        .entries()
+        .into_iter()
+        .filter_map(|entry| {
+            let value = entry.value?;
+            let changed = defaults.get(&entry.key) != Some(&Some(value.clone()));
+            changed.then_some((entry.key, value))
+        })
+        .collect();
+
+    let mut tables = vec![];
+    let mut views = vec![];
+    for catalog_name in sorted(ctx.catalog_names()) {
+        let Some(catalog) = ctx.catalog(&catalog_name) else {
+            continue;
+        };
+        for schema_name in sorted(catalog.schema_names()) {
+            let Some(schema) = catalog.schema(&schema_name) else {
+                continue;
+            };
+            for table_name in sorted(schema.table_names()) {
+                let Some(table) = schema.table(&table_name).await? else {
+                    continue;
+                };
+                let Some(definition) = table.get_table_definition() else {
+                    continue;
+                };
+                let definition = protobuf::TableDefinition {
+                    catalog: catalog_name.clone(),
+                    schema: schema_name.clone(),
+                    table: table_name,
+                    definition: definition.to_string(),
+                };
+                match table.table_type() {
+                    TableType::View => views.push(definition),
+                    _ => tables.push(definition),
+                }
+            }
+        }
+    }
+    tables.extend(views);
+
+    let builtins = SessionContext::new().state();
+    Ok(protobuf::SessionSnapshot {
+        settings,
+        tables,
+        scalar_functions: user_function_names(
+            state.scalar_functions(),
+            builtins.scalar_functions(),
+        ),
+        aggregate_functions: user_function_names(
+            state.aggregate_functions(),
+            builtins.aggregate_functions(),
+        ),
+        window_functions: user_function_names(
+            state.window_functions(),
+            builtins.window_functions(),
+        ),
+    })
+}
+
+/// Restores `snapshot` (the output of [`session_snapshot`]) into `ctx`,
+/// which should not contain the tables of the snapshot yet.
+///
+/// Returns an error listing all the missing functions if any of the user
+/// functions of the snapshot are not registered with `ctx`, before changing
+/// `ctx`. The configuration options are then set, and the tables created in
+/// their original catalog and schema, creating them if needed.
+pub async fn restore_session_snapshot(
+    snapshot: &protobuf::SessionSnapshot,
+    ctx: &SessionContext,
+) -> Result<()> {
+    let state = ctx.state();
+    let missing = [
+        (
+            "scalar",
+            &snapshot.scalar_functions,
+            names(state.scalar_functions()),
+        ),
+        (
+            "aggregate",
+            &snapshot.aggregate_functions,
+            names(state.aggregate_functions()),
+        ),
+        (
+            "window",
+            &snapshot.window_functions,
+            names(state.window_functions()),
+        ),
+    ]
+    .into_iter()
+    .flat_map(|(kind, functions, registered)| {
+        functions
+            .iter()
+            .filter(move |name| !registered.contains(name.as_str()))
+            .map(move |name| format!("{kind} function '{name}'"))
+    })
+    .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return plan_err!(
+            "The snapshot uses functions that are not registered: {}",
+            missing.join(", ")
+        );
+    }
+
+    for (variable, value) in sorted(snapshot.settings.iter().collect()) {
+        let plan = LogicalPlan::Statement(Statement::SetVariable(SetVariable {
+            variable: variable.clone(),
+            value: value.clone(),
+            schema: Arc::new(DFSchema::empty()),
+        }));
+        ctx.execute_logical_plan(plan).await?;
+    }
+
+    // views may be defined over other views saved after them, so retry the
+    // tables that could not be created until no more progress is made
+    let mut pending = snapshot.tables.iter().collect::<Vec<_>>();
+    while !pending.is_empty() {
+        let mut failed = vec![];
+        let mut last_error = None;
+        for table in &pending {
+            if let Err(e) = restore_table(table, ctx).await {
+                failed.push(*table);
+                last_error = Some(e);
+            }
+        }
+        if let Some(e) = last_error.filter(|_| failed.len() == pending.len()) {
+            return Err(e);
+        }
+        pending = failed;
+    }
+    Ok(())
+}
+
+/// Runs the definition of `table`, resolving the names it references
+/// against the catalog and schema of the table
+async fn restore_table(
+    table: &protobuf::TableDefinition,
+    ctx: &SessionContext,
+) -> Result<()> {
+    // the catalogs and schemas created with DDL statements are not saved
+    let empty_schema = Arc::new(DFSchema::empty());
+    let ddl = [
+        DdlStatement::CreateCatalog(CreateCatalog {
+            catalog_name: table.catalog.clone(),
+            if_not_exists: true,
+            schema: Arc::clone(&empty_schema),
+        }),
+        DdlStatement::CreateCatalogSchema(CreateCatalogSchema {
+            schema_name: format!("{}.{}", table.catalog, table.schema),
+            if_not_exists: true,
+            schema: empty_schema,
+        }),
+    ];
+    for ddl in ddl {
+        ctx.execute_logical_plan(LogicalPlan::Ddl(ddl)).await?;
+    }
+
+    let mut state = ctx.state();
+    let catalog = &mut state.config_mut().options_mut().catalog;
+    catalog.default_catalog.clone_from(&table.catalog);
+    catalog.default_schema.clone_from(&table.schema);
+
+    // the definition was already rewritten by the statement interceptors
+    let dialect = state.config().options().sql_parser.dialect.clone();
+    let statement = state.sql_to_statement(&table.definition, &dialect)?;
+    let mut plan = state.statement_to_plan(statement).await?;
+
+    let name = TableReference::full(
+        table.catalog.as_str(),
+        table.schema.as_str(),
+        table.table.as_str(),
+    );
+    match &mut plan {
+        LogicalPlan::Ddl(DdlStatement::CreateExternalTable(CreateExternalTable {
+            name: table_name,
+            ..
+        }))
+        | LogicalPlan::Ddl(DdlStatement::CreateView(CreateView {
+            name: table_name,
+            ..
+        })) => *table_name = name,
+        _ => {}
+    }
+    ctx.execute_logical_plan(plan).await?;
+    Ok(())
+}
+
+fn names<T>(functions: &HashMap<String, T>) -> HashSet<&str> {
+    functions.keys().map(|name| name.as_str()).collect()
+}
+
+/// Returns the sorted names of `functions`, except for the built-in
+/// functions, which are registered with the same instance in `builtins`
+fn user_function_names<T>(
+    functions: &HashMap<String, Arc<T>>,
+    builtins: &HashMap<String, Arc<T>>,
+) -> Vec<String> {
+    let names = functions
+        .iter()
+        .filter(|(name, function)| {
+            !builtins
+                .get(*name)
+                .is_some_and(|builtin| Arc::ptr_eq(function, builtin))
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+    sorted(names)
+}
+
+fn sorted<T: Ord>(mut values: Vec<T>) -> Vec<T> {
+    values.sort();
+    values
+}
@@ -319,6 +319,7 @@ pub fn serialize_expr(
             ref window_frame,
             // TODO: support null treatment in proto
             null_treatment: _,
+            distinct,
         }) => {
             let window_function = match fun {
                 WindowFunctionDefinition::AggregateFunction(fun) => {
@@ -359,6 +360,7 @@ pub fn serialize_expr(
                 partition_by,
                 order_by,
                 window_frame,
+                distinct,
             });
             protobuf::LogicalExprNode {
                 expr_type: Some(ExprType::WindowExpr(window_expr)),
@@ -39,8 +39,10 @@ use datafusion::physical_plan::expressions::{
     in_list, BinaryExpr, CaseExpr, CastExpr, Column, IsNotNullExpr, IsNullExpr, LikeExpr,
     Literal, NegativeExpr, NotExpr, TryCastExpr,
 };
+use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
 use datafusion::physical_plan::windows::{create_window_expr, schema_add_window_field};
 use datafusion::physical_plan::{Partitioning, PhysicalExpr, WindowExpr};
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{not_impl_err, DataFusionError, Result};
 use datafusion_proto_common::common::proto_error;
 
@@ -51,6 +53,18 @@ use crate::protobuf::physical_expr_node::ExprType;
 
 use super::PhysicalExtensionCodec;
 
+impl From<protobuf::ArithmeticOverflowMode> for ArithmeticOverflowMode {
+    fn from(mode: protobuf::ArithmeticOverflowMode) -> Self {
+        match mode {
+            protobuf::ArithmeticOverflowMode::Wrap => ArithmeticOverflowMode::Wrap,
+            protobuf::ArithmeticOverflowMode::Error => ArithmeticOverflowMode::Error,
+            protobuf::ArithmeticOverflowMode::Saturate => {
+                ArithmeticOverflowMode::Saturate
+            }
+        }
+    }
+}
+
 impl From<&protobuf::PhysicalColumn> for Column {
     fn from(c: &protobuf::PhysicalColumn) -> Column {
         Column::new(&c.name, c.index as usize)
@@ -232,23 +246,36 @@ pub fn parse_physical_expr(
             Arc::new(pcol)
         }
         ExprType::Literal(scalar) => Arc::new(Literal::new(scalar.try_into()?)),
-        ExprType::BinaryExpr(binary_expr) => Arc::new(BinaryExpr::new(
-            parse_required_physical_expr(
-                binary_expr.l.as_deref(),
-                registry,
-                "left",
-                input_schema,
-                codec,
-            )?,
-            logical_plan::from_proto::from_proto_binary_op(&binary_expr.op)?,
-            parse_required_physical_expr(
-                binary_expr.r.as_deref(),
-                registry,
-                "right",
-                input_schema,
-                codec,
-            )?,
-        )),
+        ExprType::BinaryExpr(binary_expr) => {
+            let overflow_mode =
+                protobuf::ArithmeticOverflowMode::try_from(binary_expr.overflow_mode)
+                    .map_err(|_| {
+                        proto_error(format!(
+                            "Received a PhysicalBinaryExprNode message with unknown ArithmeticOverflowMode {}",
+                            binary_expr.overflow_mode
+                        ))
+                    })?;
+            Arc::new(
+                BinaryExpr::new(
+                    parse_required_physical_expr(
+                        binary_expr.l.as_deref(),
+                        registry,
+                        "left",
+                        input_schema,
+                        codec,
+                    )?,
+                    logical_plan::from_proto::from_proto_binary_op(&binary_expr.op)?,
+                    parse_required_physical_expr(
+                        binary_expr.r.as_deref(),
+                        registry,
+                        "right",
+                        input_schema,
+                        codec,
+                    )?,
+                )
+                .with_overflow_mode(overflow_mode.into()),
+            )
+        }
         ExprType::AggregateExpr(_) => {
             return not_impl_err!(
                 "Cannot convert aggregate expr node to physical expression"
@@ -376,30 +403,35 @@ pub fn parse_physical_expr(
                 convert_required!(e.return_type)?,
             ))
         }
-        ExprType::LikeExpr(like_expr) => Arc::new(LikeExpr::new(
-            like_expr.negated,
-            like_expr.case_insensitive,
-            parse_required_physical_expr(
-                like_expr.expr.as_deref(),
-                registry,
-                "expr",
-                input_schema,
-                codec,
-            )?,
-            parse_required_physical_expr(
-                like_expr.pattern.as_deref(),
-                registry,
-                "pattern",
-                input_schema,
-                codec,
-            )?,
-        )),
+        ExprType::LikeExpr(like_expr) => Arc::new(
+            LikeExpr::new(
+                like_expr.negated,
+                like_expr.case_insensitive,
+                parse_required_physical_expr(
+                    like_expr.expr.as_deref(),
+                    registry,
+                    "expr",
+                    input_schema,
+                    codec,
+                )?,
+                parse_required_physical_expr(
+                    like_expr.pattern.as_deref(),
+                    registry,
+                    "pattern",
+                    input_schema,
+                    codec,
+                )?,
+            )
+            .with_escape_char(logical_plan::from_proto::parse_escape_char(
+                &like_expr.escape_char,
+            )?),
+        ),
     };
 
     Ok(pexpr)
 }
 
-fn parse_required_physical_expr(
+pub(crate) fn parse_required_physical_expr(
     expr: Option<&protobuf::PhysicalExprNode>,
     registry: &dyn FunctionRegistry,
     field: &str,
@@ -540,6 +572,43 @@ pub fn parse_protobuf_file_scan_config(
     })
 }
 
+/// Parses the filter of a join from a protobuf.
+pub fn parse_join_filter(
+    proto: &protobuf::JoinFilter,
+    registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<JoinFilter> {
+    let schema: Schema = proto
+        .schema
+        .as_ref()
+        .ok_or_else(|| proto_error("Missing JoinFilter schema"))?
+        .try_into()?;
+    let expression = parse_required_physical_expr(
+        proto.expression.as_ref(),
+        registry,
+        "expression",
+        &schema,
+        codec,
+    )?;
+    let column_indices = proto
+        .column_indices
+        .iter()
+        .map(|i| {
+            let side = protobuf::JoinSide::try_from(i.side).map_err(|_| {
+                proto_error(format!(
+                    "Received a JoinFilter with unknown JoinSide {}",
+                    i.side
+                ))
+            })?;
+            Ok(ColumnIndex {
+                index: i.index as usize,
+                side: side.into(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(JoinFilter::new(expression, column_indices, schema))
+}
+
 impl TryFrom<&protobuf::PartitionedFile> for PartitionedFile {
     type Error = DataFusionError;
 
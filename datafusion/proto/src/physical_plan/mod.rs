@@ -30,7 +30,7 @@ use datafusion::datasource::file_format::json::JsonSink;
 use datafusion::datasource::file_format::parquet::ParquetSink;
 #[cfg(feature = "parquet")]
 use datafusion::datasource::physical_plan::ParquetExec;
-use datafusion::datasource::physical_plan::{AvroExec, CsvExec};
+use datafusion::datasource::physical_plan::{ArrowExec, AvroExec, CsvExec, NdJsonExec};
 use datafusion::execution::runtime_env::RuntimeEnv;
 use datafusion::execution::FunctionRegistry;
 use datafusion::physical_expr::{PhysicalExprRef, PhysicalSortRequirement};
@@ -46,31 +46,40 @@ use datafusion::physical_plan::filter::FilterExec;
 use datafusion::physical_plan::insert::DataSinkExec;
 use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
 use datafusion::physical_plan::joins::{
-    CrossJoinExec, NestedLoopJoinExec, StreamJoinPartitionMode, SymmetricHashJoinExec,
+    CrossJoinExec, NestedLoopJoinExec, SortMergeJoinExec, StreamJoinPartitionMode,
+    SymmetricHashJoinExec,
 };
 use datafusion::physical_plan::joins::{HashJoinExec, PartitionMode};
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use datafusion::physical_plan::placeholder_row::PlaceholderRowExec;
 use datafusion::physical_plan::projection::ProjectionExec;
 use datafusion::physical_plan::repartition::RepartitionExec;
+use datafusion::physical_plan::sorts::partial_sort::PartialSortExec;
 use datafusion::physical_plan::sorts::sort::SortExec;
 use datafusion::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
 use datafusion::physical_plan::union::{InterleaveExec, UnionExec};
+use datafusion::physical_plan::unnest::UnnestExec;
 use datafusion::physical_plan::windows::{BoundedWindowAggExec, WindowAggExec};
 use datafusion::physical_plan::{
     udaf, AggregateExpr, ExecutionPlan, InputOrderMode, PhysicalExpr, WindowExpr,
 };
-use datafusion_common::{internal_err, not_impl_err, DataFusionError, Result};
+use datafusion_common::parsers::CompressionTypeVariant;
+use datafusion_common::{
+    internal_datafusion_err, internal_err, not_impl_err, DataFusionError, Result,
+};
 use datafusion_expr::ScalarUDF;
 
 use crate::common::{byte_to_string, str_to_byte};
 use crate::convert_required;
+use crate::logical_plan::encode_composed_blob;
 use crate::physical_plan::from_proto::{
-    parse_physical_expr, parse_physical_sort_expr, parse_physical_sort_exprs,
-    parse_physical_window_expr, parse_protobuf_file_scan_config,
+    parse_join_filter, parse_physical_expr, parse_physical_sort_expr,
+    parse_physical_sort_exprs, parse_physical_window_expr,
+    parse_protobuf_file_scan_config, parse_required_physical_expr,
 };
 use crate::physical_plan::to_proto::{
-    serialize_file_scan_config, serialize_maybe_filter, serialize_physical_aggr_expr,
+    serialize_file_scan_config, serialize_join_filter, serialize_maybe_filter,
+    serialize_physical_aggr_expr, serialize_physical_sort_exprs,
     serialize_physical_window_expr,
 };
 use crate::protobuf::physical_aggregate_expr_node::AggregateFunction;
@@ -1091,6 +1100,137 @@ impl AsExecutionPlan for protobuf::PhysicalPlanNode {
                     sort_order,
                 )))
             }
+            PhysicalPlanType::SortMergeJoin(join) => {
+                let left =
+                    into_physical_plan(&join.left, registry, runtime, extension_codec)?;
+                let right =
+                    into_physical_plan(&join.right, registry, runtime, extension_codec)?;
+                let left_schema = left.schema();
+                let right_schema = right.schema();
+                let on = join
+                    .on
+                    .iter()
+                    .map(|on| {
+                        let left = parse_required_physical_expr(
+                            on.left.as_ref(),
+                            registry,
+                            "left",
+                            &left_schema,
+                            extension_codec,
+                        )?;
+                        let right = parse_required_physical_expr(
+                            on.right.as_ref(),
+                            registry,
+                            "right",
+                            &right_schema,
+                            extension_codec,
+                        )?;
+                        Ok((left, right))
+                    })
+                    .collect::<Result<_>>()?;
+                let join_type =
+                    protobuf::JoinType::try_from(join.join_type).map_err(|_| {
+                        proto_error(format!(
+                            "Received a SortMergeJoinExecNode message with unknown JoinType {}",
+                            join.join_type
+                        ))
+                    })?;
+                let filter = join
+                    .filter
+                    .as_ref()
+                    .map(|f| parse_join_filter(f, registry, extension_codec))
+                    .transpose()?;
+                let sort_options = join
+                    .sort_options
+                    .iter()
+                    .map(|options| SortOptions {
+                        descending: !options.asc,
+                        nulls_first: options.nulls_first,
+                    })
+                    .collect();
+                Ok(Arc::new(SortMergeJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    filter,
+                    join_type.into(),
+                    sort_options,
+                    join.null_equals_null,
+                )?))
+            }
+            PhysicalPlanType::Unnest(unnest) => {
+                let input = into_physical_plan(
+                    &unnest.input,
+                    registry,
+                    runtime,
+                    extension_codec,
+                )?;
+                Ok(Arc::new(UnnestExec::new(
+                    input,
+                    unnest
+                        .list_type_columns
+                        .iter()
+                        .map(|c| *c as usize)
+                        .collect(),
+                    unnest
+                        .struct_type_columns
+                        .iter()
+                        .map(|c| *c as usize)
+                        .collect(),
+                    Arc::new(convert_required!(unnest.schema)?),
+                    unnest.options.as_ref().map(Into::into).unwrap_or_default(),
+                )))
+            }
+            PhysicalPlanType::JsonScan(scan) => {
+                let compression =
+                    protobuf::CompressionTypeVariant::try_from(scan.file_compression_type)
+                        .map_err(|_| {
+                            proto_error(format!(
+                                "Received a JsonScanExecNode message with unknown CompressionTypeVariant {}",
+                                scan.file_compression_type
+                            ))
+                        })?;
+                Ok(Arc::new(NdJsonExec::new(
+                    parse_protobuf_file_scan_config(
+                        scan.base_conf.as_ref().unwrap(),
+                        registry,
+                        extension_codec,
+                    )?,
+                    CompressionTypeVariant::from(compression).into(),
+                )))
+            }
+            PhysicalPlanType::ArrowScan(scan) => {
+                Ok(Arc::new(ArrowExec::new(parse_protobuf_file_scan_config(
+                    scan.base_conf.as_ref().unwrap(),
+                    registry,
+                    extension_codec,
+                )?)))
+            }
+            PhysicalPlanType::PartialSort(sort) => {
+                let input =
+                    into_physical_plan(&sort.input, registry, runtime, extension_codec)?;
+                let expr = parse_physical_sort_exprs(
+                    &sort.expr,
+                    registry,
+                    &input.schema(),
+                    extension_codec,
+                )?;
+                if sort.common_prefix_length == 0 {
+                    return Err(proto_error(
+                        "Received a PartialSortExecNode message with a common_prefix_length of 0",
+                    ));
+                }
+                let fetch = if sort.fetch < 0 {
+                    None
+                } else {
+                    Some(sort.fetch as usize)
+                };
+                Ok(Arc::new(
+                    PartialSortExec::new(expr, input, sort.common_prefix_length as usize)
+                        .with_fetch(fetch)
+                        .with_preserve_partitioning(sort.preserve_partitioning),
+                ))
+            }
         }
     }
 
@@ -1877,6 +2017,139 @@ impl AsExecutionPlan for protobuf::PhysicalPlanNode {
             });
         }
 
+        if let Some(exec) = plan.downcast_ref::<SortMergeJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left().to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right().to_owned(),
+                extension_codec,
+            )?;
+            let on = exec
+                .on()
+                .iter()
+                .map(|(left, right)| {
+                    Ok::<_, DataFusionError>(protobuf::JoinOn {
+                        left: Some(serialize_physical_expr(
+                            left.to_owned(),
+                            extension_codec,
+                        )?),
+                        right: Some(serialize_physical_expr(
+                            right.to_owned(),
+                            extension_codec,
+                        )?),
+                    })
+                })
+                .collect::<Result<_>>()?;
+            let join_type: protobuf::JoinType = exec.join_type().into();
+            let filter = exec
+                .filter
+                .as_ref()
+                .map(|f| serialize_join_filter(f, extension_codec))
+                .transpose()?;
+            let sort_options = exec
+                .sort_options
+                .iter()
+                .map(|options| protobuf::JoinSortOptions {
+                    asc: !options.descending,
+                    nulls_first: options.nulls_first,
+                })
+                .collect();
+
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::SortMergeJoin(Box::new(
+                    protobuf::SortMergeJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        on,
+                        join_type: join_type.into(),
+                        filter,
+                        sort_options,
+                        null_equals_null: exec.null_equals_null,
+                    },
+                ))),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<UnnestExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Unnest(Box::new(
+                    protobuf::UnnestExecNode {
+                        input: Some(Box::new(input)),
+                        schema: Some(exec.schema().as_ref().try_into()?),
+                        list_type_columns: exec
+                            .list_column_indices()
+                            .iter()
+                            .map(|c| *c as u64)
+                            .collect(),
+                        struct_type_columns: exec
+                            .struct_column_indices()
+                            .iter()
+                            .map(|c| *c as u64)
+                            .collect(),
+                        options: Some(exec.options().into()),
+                    },
+                ))),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<NdJsonExec>() {
+            let compression: protobuf::CompressionTypeVariant =
+                (*exec.file_compression_type().get_variant()).into();
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::JsonScan(
+                    protobuf::JsonScanExecNode {
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
+                        file_compression_type: compression.into(),
+                    },
+                )),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<ArrowExec>() {
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::ArrowScan(
+                    protobuf::ArrowScanExecNode {
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
+                    },
+                )),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<PartialSortExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            let expr =
+                serialize_physical_sort_exprs(exec.expr().to_vec(), extension_codec)?;
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::PartialSort(Box::new(
+                    protobuf::PartialSortExecNode {
+                        input: Some(Box::new(input)),
+                        expr,
+                        fetch: match exec.fetch() {
+                            Some(n) => n as i64,
+                            _ => -1,
+                        },
+                        preserve_partitioning: exec.preserve_partitioning(),
+                        common_prefix_length: exec.common_prefix_length() as u64,
+                    },
+                ))),
+            });
+        }
+
         if let Some(exec) = plan.downcast_ref::<DataSinkExec>() {
             let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
                 exec.input().to_owned(),
@@ -2042,6 +2315,123 @@ impl PhysicalExtensionCodec for DefaultPhysicalExtensionCodec {
     }
 }
 
+/// A [`PhysicalExtensionCodec`] that combines several codecs, each registered
+/// under a name, so that independent libraries can each provide the codec of
+/// their own execution plans and functions.
+///
+/// An execution plan is encoded by the first registered codec that does not
+/// return an error, and the encoding is tagged with the name of that codec,
+/// which is used to decode it. Functions are encoded by the first codec that
+/// writes a definition for them.
+///
+/// See [`ComposedLogicalExtensionCodec`] for the logical plan equivalent.
+///
+/// [`ComposedLogicalExtensionCodec`]: crate::logical_plan::ComposedLogicalExtensionCodec
+#[derive(Debug, Clone, Default)]
+pub struct ComposedPhysicalExtensionCodec {
+    codecs: Vec<(String, Arc<dyn PhysicalExtensionCodec>)>,
+}
+
+impl ComposedPhysicalExtensionCodec {
+    /// Create a codec without any registered codec
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `codec` under `name`, see [`Self::register_codec`]
+    pub fn with_codec(
+        mut self,
+        name: impl Into<String>,
+        codec: Arc<dyn PhysicalExtensionCodec>,
+    ) -> Self {
+        self.register_codec(name, codec);
+        self
+    }
+
+    /// Register `codec` under `name`, returning the codec previously
+    /// registered under this name, if any.
+    ///
+    /// The codecs are tried in the order in which they were first registered.
+    pub fn register_codec(
+        &mut self,
+        name: impl Into<String>,
+        codec: Arc<dyn PhysicalExtensionCodec>,
+    ) -> Option<Arc<dyn PhysicalExtensionCodec>> {
+        let name = name.into();
+        match self.codecs.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, codec)),
+            None => {
+                self.codecs.push((name, codec));
+                None
+            }
+        }
+    }
+
+    /// Returns the codec registered under `name`
+    pub fn codec(&self, name: &str) -> Option<&Arc<dyn PhysicalExtensionCodec>> {
+        self.codecs.iter().find(|(n, _)| n == name).map(|(_, c)| c)
+    }
+
+    /// Decodes a [`protobuf::ComposedExtensionBlob`] and calls `decode` with
+    /// the codec it was encoded by
+    fn decode<T>(
+        &self,
+        buf: &[u8],
+        decode: impl FnOnce(&dyn PhysicalExtensionCodec, &[u8]) -> Result<T>,
+    ) -> Result<T> {
+        let blob = protobuf::ComposedExtensionBlob::decode(buf).map_err(|e| {
+            internal_datafusion_err!("Failed to decode composed extension: {e}")
+        })?;
+        match self.codec(&blob.codec) {
+            Some(codec) => decode(codec.as_ref(), &blob.blob),
+            None => not_impl_err!("No extension codec registered as '{}'", blob.codec),
+        }
+    }
+}
+
+impl PhysicalExtensionCodec for ComposedPhysicalExtensionCodec {
+    fn try_decode(
+        &self,
+        buf: &[u8],
+        inputs: &[Arc<dyn ExecutionPlan>],
+        registry: &dyn FunctionRegistry,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.decode(buf, |codec, buf| codec.try_decode(buf, inputs, registry))
+    }
+
+    fn try_encode(&self, node: Arc<dyn ExecutionPlan>, buf: &mut Vec<u8>) -> Result<()> {
+        let mut errors = vec![];
+        for (name, codec) in &self.codecs {
+            let mut blob = vec![];
+            match codec.try_encode(Arc::clone(&node), &mut blob) {
+                Ok(()) => return encode_composed_blob(name, blob, buf),
+                Err(e) => errors.push(format!("{name}: {e}")),
+            }
+        }
+        not_impl_err!(
+            "No registered extension codec can encode {}: [{}]",
+            node.name(),
+            errors.join(", ")
+        )
+    }
+
+    fn try_decode_udf(&self, name: &str, buf: &[u8]) -> Result<Arc<ScalarUDF>> {
+        self.decode(buf, |codec, buf| codec.try_decode_udf(name, buf))
+    }
+
+    fn try_encode_udf(&self, node: &ScalarUDF, buf: &mut Vec<u8>) -> Result<()> {
+        for (name, codec) in &self.codecs {
+            let mut blob = vec![];
+            codec.try_encode_udf(node, &mut blob)?;
+            // codecs that do not know the function do not write a definition
+            if !blob.is_empty() {
+                return encode_composed_blob(name, blob, buf);
+            }
+        }
+        Ok(())
+    }
+}
+
 fn into_physical_plan(
     node: &Option<Box<protobuf::PhysicalPlanNode>>,
     registry: &dyn FunctionRegistry,
@@ -28,6 +28,7 @@ use datafusion::physical_plan::expressions::{
     NthValue, NthValueAgg, Ntile, OrderSensitiveArrayAgg, Rank, RankType, RowNumber,
     TryCastExpr, WindowShift,
 };
+use datafusion::physical_plan::joins::utils::JoinFilter;
 use datafusion::physical_plan::udaf::AggregateFunctionExpr;
 use datafusion::physical_plan::windows::{BuiltInWindowExpr, PlainAggregateWindowExpr};
 use datafusion::physical_plan::{AggregateExpr, Partitioning, PhysicalExpr, WindowExpr};
@@ -39,6 +40,7 @@ use datafusion::{
     },
     physical_plan::expressions::LikeExpr,
 };
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{internal_err, not_impl_err, DataFusionError, Result};
 
 use crate::protobuf::{
@@ -333,6 +335,8 @@ pub fn serialize_physical_expr(
                 codec,
             )?)),
             op: format!("{:?}", expr.op()),
+            overflow_mode: protobuf::ArithmeticOverflowMode::from(expr.overflow_mode())
+                .into(),
         });
 
         Ok(protobuf::PhysicalExprNode {
@@ -493,6 +497,10 @@ pub fn serialize_physical_expr(
                         expr.pattern().to_owned(),
                         codec,
                     )?)),
+                    escape_char: expr
+                        .escape_char()
+                        .map(|ch| ch.to_string())
+                        .unwrap_or_default(),
                 },
             ))),
         })
@@ -542,6 +550,18 @@ fn serialize_when_then_expr(
     })
 }
 
+impl From<ArithmeticOverflowMode> for protobuf::ArithmeticOverflowMode {
+    fn from(mode: ArithmeticOverflowMode) -> Self {
+        match mode {
+            ArithmeticOverflowMode::Wrap => protobuf::ArithmeticOverflowMode::Wrap,
+            ArithmeticOverflowMode::Error => protobuf::ArithmeticOverflowMode::Error,
+            ArithmeticOverflowMode::Saturate => {
+                protobuf::ArithmeticOverflowMode::Saturate
+            }
+        }
+    }
+}
+
 impl TryFrom<&PartitionedFile> for protobuf::PartitionedFile {
     type Error = DataFusionError;
 
@@ -657,6 +677,29 @@ pub fn serialize_maybe_filter(
     }
 }
 
+pub fn serialize_join_filter(
+    filter: &JoinFilter,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::JoinFilter> {
+    let expression = serialize_physical_expr(filter.expression().to_owned(), codec)?;
+    let column_indices = filter
+        .column_indices()
+        .iter()
+        .map(|i| {
+            let side: protobuf::JoinSide = i.side.to_owned().into();
+            protobuf::ColumnIndex {
+                index: i.index as u32,
+                side: side.into(),
+            }
+        })
+        .collect();
+    Ok(protobuf::JoinFilter {
+        expression: Some(expression),
+        column_indices,
+        schema: Some(filter.schema().try_into()?),
+    })
+}
+
 impl TryFrom<&JsonSink> for protobuf::JsonSink {
     type Error = DataFusionError;
 
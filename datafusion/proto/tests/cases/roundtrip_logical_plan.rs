@@ -26,6 +26,7 @@ use arrow::datatypes::{
     DataType, Field, Fields, Int32Type, IntervalDayTimeType, IntervalMonthDayNanoType,
     IntervalUnit, Schema, SchemaRef, TimeUnit, UnionFields, UnionMode,
 };
+use datafusion::assert_batches_eq;
 use datafusion::datasource::file_format::arrow::ArrowFormatFactory;
 use datafusion::datasource::file_format::csv::CsvFormatFactory;
 use datafusion::datasource::file_format::format_as_file_type;
@@ -43,7 +44,7 @@ use datafusion::execution::FunctionRegistry;
 use datafusion::functions_aggregate::count::count_udaf;
 use datafusion::functions_aggregate::expr_fn::{
     approx_median, approx_percentile_cont, approx_percentile_cont_with_weight, count,
-    count_distinct, covar_pop, covar_samp, first_value, grouping, median, stddev,
+    count_distinct, covar_pop, covar_samp, first_value, grouping, median, mode, stddev,
     stddev_pop, sum, var_pop, var_sample,
 };
 use datafusion::prelude::*;
@@ -51,8 +52,8 @@ use datafusion::test_util::{TestTableFactory, TestTableProvider};
 use datafusion_common::config::TableOptions;
 use datafusion_common::scalar::ScalarStructBuilder;
 use datafusion_common::{
-    internal_datafusion_err, internal_err, not_impl_err, plan_err, DFSchema, DFSchemaRef,
-    DataFusionError, Result, ScalarValue, TableReference,
+    assert_contains, internal_datafusion_err, internal_err, not_impl_err, plan_err,
+    DFSchema, DFSchemaRef, DataFusionError, Result, ScalarValue, TableReference,
 };
 use datafusion_expr::dml::CopyTo;
 use datafusion_expr::expr::{
@@ -72,12 +73,16 @@ use datafusion_functions_aggregate::expr_fn::{
 };
 use datafusion_functions_aggregate::string_agg::string_agg;
 use datafusion_proto::bytes::{
-    logical_plan_from_bytes, logical_plan_from_bytes_with_extension_codec,
-    logical_plan_to_bytes, logical_plan_to_bytes_with_extension_codec,
+    function_manifest_to_bytes, logical_plan_from_bytes,
+    logical_plan_from_bytes_with_extension_codec,
+    logical_plan_from_bytes_with_function_manifest, logical_plan_to_bytes,
+    logical_plan_to_bytes_with_extension_codec, restore_session_snapshot_from_bytes,
+    session_snapshot_to_bytes,
 };
 use datafusion_proto::logical_plan::to_proto::serialize_expr;
 use datafusion_proto::logical_plan::{
-    from_proto, DefaultLogicalExtensionCodec, LogicalExtensionCodec,
+    from_proto, ComposedLogicalExtensionCodec, DefaultLogicalExtensionCodec,
+    LogicalExtensionCodec,
 };
 use datafusion_proto::protobuf;
 
@@ -220,6 +225,191 @@ async fn roundtrip_custom_tables() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn roundtrip_composed_extension_codec() -> Result<()> {
+    let mut table_factories: HashMap<String, Arc<dyn TableProviderFactory>> =
+        HashMap::new();
+    table_factories.insert("TESTTABLE".to_string(), Arc::new(TestTableFactory {}));
+    let mut state = SessionState::new_with_config_rt(
+        SessionConfig::new(),
+        Arc::new(RuntimeEnv::new(RuntimeConfig::new())?),
+    );
+    *state.table_factories_mut() = table_factories;
+    let ctx = SessionContext::new_with_state(state);
+
+    let sql = "CREATE EXTERNAL TABLE t STORED AS testtable LOCATION 's3://bucket/schema/table';";
+    ctx.sql(sql).await?;
+
+    // the extension node and the table are encoded by different codecs
+    let scan = ctx.table("t").await?.into_optimized_plan()?;
+    let topk_plan = LogicalPlan::Extension(Extension {
+        node: Arc::new(TopKPlanNode::new(3, scan, lit(1))),
+    });
+    let codec = ComposedLogicalExtensionCodec::new()
+        .with_codec("topk", Arc::new(TopKExtensionCodec {}))
+        .with_codec("tables", Arc::new(TestTableProviderCodec {}));
+    let bytes = logical_plan_to_bytes_with_extension_codec(&topk_plan, &codec)?;
+    let logical_round_trip =
+        logical_plan_from_bytes_with_extension_codec(&bytes, &ctx, &codec)?;
+    assert_eq!(format!("{topk_plan:?}"), format!("{logical_round_trip:?}"));
+
+    // the codecs are found by name, whatever the order of registration
+    let reordered = ComposedLogicalExtensionCodec::new()
+        .with_codec("tables", Arc::new(TestTableProviderCodec {}))
+        .with_codec("topk", Arc::new(TopKExtensionCodec {}));
+    let logical_round_trip =
+        logical_plan_from_bytes_with_extension_codec(&bytes, &ctx, &reordered)?;
+    assert_eq!(format!("{topk_plan:?}"), format!("{logical_round_trip:?}"));
+
+    let missing = ComposedLogicalExtensionCodec::new()
+        .with_codec("topk", Arc::new(TopKExtensionCodec {}));
+    let err =
+        logical_plan_from_bytes_with_extension_codec(&bytes, &ctx, &missing).unwrap_err();
+    assert_contains!(err.to_string(), "No extension codec registered as 'tables'");
+    Ok(())
+}
+
+#[tokio::test]
+async fn roundtrip_with_function_manifest() -> Result<()> {
+    let identity = |name: &str, return_type: DataType| {
+        create_udf(
+            name,
+            vec![DataType::Int64],
+            Arc::new(return_type),
+            Volatility::Immutable,
+            Arc::new(|args: &[ColumnarValue]| Ok(args[0].clone())),
+        )
+    };
+
+    let ctx = SessionContext::new();
+    ctx.register_csv("t1", "tests/testdata/test.csv", CsvReadOptions::default())
+        .await?;
+    ctx.register_udf(identity("double", DataType::Int64));
+    ctx.register_udf(identity("to_text", DataType::Utf8));
+    let plan = ctx
+        .sql("SELECT double(a), to_text(b), to_text(b + 1) FROM t1")
+        .await?
+        .into_optimized_plan()?;
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let manifest = function_manifest_to_bytes(&plan)?;
+
+    let decoded = protobuf::FunctionManifest::decode(manifest.as_ref()).unwrap();
+    let names = decoded
+        .scalar_functions
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["double", "to_text"]);
+    assert_eq!(decoded.scalar_functions[0].arg_types.len(), 1);
+
+    let codec = DefaultLogicalExtensionCodec {};
+    let logical_round_trip =
+        logical_plan_from_bytes_with_function_manifest(&bytes, &manifest, &ctx, &codec)?;
+    assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+
+    // all the missing functions are reported at once
+    let consumer = SessionContext::new();
+    consumer
+        .register_csv("t1", "tests/testdata/test.csv", CsvReadOptions::default())
+        .await?;
+    let err = logical_plan_from_bytes_with_function_manifest(
+        &bytes, &manifest, &consumer, &codec,
+    )
+    .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "The plan calls functions that are not registered: \
+         scalar function 'double', scalar function 'to_text'"
+    );
+
+    // a function registered with a different return type is rejected
+    consumer.register_udf(identity("double", DataType::Int64));
+    consumer.register_udf(identity("to_text", DataType::LargeUtf8));
+    let err = logical_plan_from_bytes_with_function_manifest(
+        &bytes, &manifest, &consumer, &codec,
+    )
+    .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "scalar function 'to_text' returns LargeUtf8 instead of Utf8"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn roundtrip_session_snapshot() -> Result<()> {
+    let double = create_udf(
+        "double",
+        vec![DataType::Int64],
+        Arc::new(DataType::Int64),
+        Volatility::Immutable,
+        Arc::new(|args: &[ColumnarValue]| Ok(args[0].clone())),
+    );
+
+    let ctx = SessionContext::new();
+    ctx.register_udf(double.clone());
+    for sql in [
+        "SET datafusion.execution.batch_size = 1234",
+        "CREATE SCHEMA s",
+        "CREATE EXTERNAL TABLE s.t1 STORED AS CSV \
+         LOCATION 'tests/testdata/test.csv' OPTIONS ('format.has_header' 'true')",
+        // v1 is saved before v2, but can only be restored after it
+        "CREATE VIEW s.v2 AS SELECT a + b AS c FROM s.t1",
+        "CREATE VIEW s.v1 AS SELECT c + 1 AS d FROM s.v2",
+        // tables without a definition are not saved
+        "CREATE TABLE t2 AS VALUES (1)",
+    ] {
+        ctx.sql(sql).await?;
+    }
+    let bytes = session_snapshot_to_bytes(&ctx).await?;
+
+    let decoded = protobuf::SessionSnapshot::decode(bytes.as_ref()).unwrap();
+    assert_eq!(
+        decoded.settings,
+        HashMap::from([(
+            "datafusion.execution.batch_size".to_string(),
+            "1234".to_string()
+        )])
+    );
+    let tables = decoded
+        .tables
+        .iter()
+        .map(|t| format!("{}.{}.{}", t.catalog, t.schema, t.table))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tables,
+        ["datafusion.s.t1", "datafusion.s.v1", "datafusion.s.v2"]
+    );
+    // only the functions registered by the user are saved
+    assert_eq!(decoded.scalar_functions, ["double"]);
+    assert!(decoded.aggregate_functions.is_empty());
+    assert!(decoded.window_functions.is_empty());
+
+    // the functions must be registered before restoring the snapshot
+    let restored = SessionContext::new();
+    let err = restore_session_snapshot_from_bytes(&bytes, &restored)
+        .await
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "The snapshot uses functions that are not registered: scalar function 'double'"
+    );
+
+    restored.register_udf(double);
+    restore_session_snapshot_from_bytes(&bytes, &restored).await?;
+    assert_eq!(restored.copied_config().batch_size(), 1234);
+    let results = restored
+        .sql("SELECT d FROM s.v1 ORDER BY d")
+        .await?
+        .collect()
+        .await?;
+    assert_batches_eq!(
+        ["+---+", "| d |", "+---+", "| 4 |", "| 8 |", "+---+"],
+        &results
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_custom_memory_tables() -> Result<()> {
     let ctx = SessionContext::new();
@@ -688,6 +878,7 @@ async fn roundtrip_expr_api() -> Result<()> {
         corr(lit(1.5), lit(2.2)),
         sum(lit(1)),
         median(lit(2)),
+        mode(lit(2)),
         var_sample(lit(2.2)),
         var_pop(lit(2.2)),
         stddev(lit(2.2)),
@@ -2210,6 +2401,18 @@ fn roundtrip_window() {
         None,
     ));
 
+    let test_expr8 = Expr::WindowFunction(
+        expr::WindowFunction::new(
+            WindowFunctionDefinition::AggregateUDF(avg_udaf()),
+            vec![col("col1")],
+            vec![col("col2")],
+            vec![],
+            row_number_frame.clone(),
+            None,
+        )
+        .with_distinct(true),
+    );
+
     ctx.register_udwf(dummy_window_udf);
 
     roundtrip_expr_test(test_expr1, ctx.clone());
@@ -2218,5 +2421,6 @@ fn roundtrip_window() {
     roundtrip_expr_test(test_expr4, ctx.clone());
     roundtrip_expr_test(test_expr5, ctx.clone());
     roundtrip_expr_test(test_expr6, ctx.clone());
-    roundtrip_expr_test(text_expr7, ctx);
+    roundtrip_expr_test(text_expr7, ctx.clone());
+    roundtrip_expr_test(test_expr8, ctx);
 }
@@ -26,15 +26,16 @@ use prost::Message;
 
 use datafusion::arrow::array::ArrayRef;
 use datafusion::arrow::compute::kernels::sort::SortOptions;
-use datafusion::arrow::datatypes::{DataType, Field, IntervalUnit, Schema};
+use datafusion::arrow::datatypes::{DataType, Field, IntervalUnit, Schema, SchemaRef};
 use datafusion::datasource::file_format::csv::CsvSink;
+use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
 use datafusion::datasource::file_format::json::JsonSink;
 use datafusion::datasource::file_format::parquet::ParquetSink;
 use datafusion::datasource::listing::{ListingTableUrl, PartitionedFile};
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::datasource::physical_plan::{
-    wrap_partition_type_in_dict, wrap_partition_value_in_dict, FileScanConfig,
-    FileSinkConfig, ParquetExec,
+    wrap_partition_type_in_dict, wrap_partition_value_in_dict, ArrowExec, FileScanConfig,
+    FileSinkConfig, NdJsonExec, ParquetExec,
 };
 use datafusion::execution::FunctionRegistry;
 use datafusion::logical_expr::{create_udf, JoinType, Operator, Volatility};
@@ -47,35 +48,42 @@ use datafusion::physical_plan::aggregates::{
 use datafusion::physical_plan::analyze::AnalyzeExec;
 use datafusion::physical_plan::empty::EmptyExec;
 use datafusion::physical_plan::expressions::{
-    binary, cast, col, in_list, like, lit, BinaryExpr, Column, NotExpr, NthValue,
-    PhysicalSortExpr,
+    binary, cast, col, in_list, like, like_with_escape_char, lit, BinaryExpr, Column,
+    NotExpr, NthValue, PhysicalSortExpr,
 };
 use datafusion::physical_plan::filter::FilterExec;
 use datafusion::physical_plan::insert::DataSinkExec;
+use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
 use datafusion::physical_plan::joins::{
-    HashJoinExec, NestedLoopJoinExec, PartitionMode, StreamJoinPartitionMode,
+    HashJoinExec, NestedLoopJoinExec, PartitionMode, SortMergeJoinExec,
+    StreamJoinPartitionMode,
 };
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use datafusion::physical_plan::placeholder_row::PlaceholderRowExec;
 use datafusion::physical_plan::projection::ProjectionExec;
 use datafusion::physical_plan::repartition::RepartitionExec;
+use datafusion::physical_plan::sorts::partial_sort::PartialSortExec;
 use datafusion::physical_plan::sorts::sort::SortExec;
 use datafusion::physical_plan::udaf::create_aggregate_expr;
 use datafusion::physical_plan::union::{InterleaveExec, UnionExec};
+use datafusion::physical_plan::unnest::UnnestExec;
 use datafusion::physical_plan::windows::{
-    BuiltInWindowExpr, PlainAggregateWindowExpr, WindowAggExec,
+    BoundedWindowAggExec, BuiltInWindowExpr, PlainAggregateWindowExpr, WindowAggExec,
 };
 use datafusion::physical_plan::{
-    udaf, AggregateExpr, ExecutionPlan, Partitioning, PhysicalExpr, Statistics,
+    udaf, AggregateExpr, ExecutionPlan, InputOrderMode, Partitioning, PhysicalExpr,
+    Statistics,
 };
 use datafusion::prelude::SessionContext;
 use datafusion::scalar::ScalarValue;
-use datafusion_common::config::TableParquetOptions;
+use datafusion_common::config::{ArithmeticOverflowMode, TableParquetOptions};
 use datafusion_common::file_options::csv_writer::CsvWriterOptions;
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::stats::Precision;
-use datafusion_common::{not_impl_err, plan_err, DataFusionError, Result};
+use datafusion_common::{
+    not_impl_err, plan_err, DataFusionError, JoinSide, Result, UnnestOptions,
+};
 use datafusion_expr::{
     Accumulator, AccumulatorFactoryFunction, AggregateUDF, ColumnarValue, ScalarUDF,
     ScalarUDFImpl, Signature, SimpleAggregateUDF, WindowFrame, WindowFrameBound,
@@ -83,7 +91,8 @@ use datafusion_expr::{
 use datafusion_functions_aggregate::average::avg_udaf;
 use datafusion_functions_aggregate::string_agg::StringAgg;
 use datafusion_proto::physical_plan::{
-    AsExecutionPlan, DefaultPhysicalExtensionCodec, PhysicalExtensionCodec,
+    AsExecutionPlan, ComposedPhysicalExtensionCodec, DefaultPhysicalExtensionCodec,
+    PhysicalExtensionCodec,
 };
 use datafusion_proto::protobuf;
 
@@ -531,6 +540,38 @@ fn roundtrip_filter_with_not_and_in_list() -> Result<()> {
     )?))
 }
 
+#[test]
+fn roundtrip_binary_overflow_mode() -> Result<()> {
+    let field_a = Field::new("a", DataType::Int64, false);
+    let field_b = Field::new("b", DataType::Int64, false);
+    let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+    for mode in [
+        ArithmeticOverflowMode::Wrap,
+        ArithmeticOverflowMode::Error,
+        ArithmeticOverflowMode::Saturate,
+    ] {
+        let plus =
+            BinaryExpr::new(col("a", &schema)?, Operator::Plus, col("b", &schema)?)
+                .with_overflow_mode(mode);
+        let plan = roundtrip_test_and_return(
+            Arc::new(ProjectionExec::try_new(
+                vec![(Arc::new(plus) as _, "a + b".to_string())],
+                Arc::new(EmptyExec::new(schema.clone())),
+            )?),
+            &SessionContext::new(),
+            &DefaultPhysicalExtensionCodec {},
+        )?;
+        let projection = plan.as_any().downcast_ref::<ProjectionExec>().unwrap();
+        let plus = projection.expr()[0]
+            .0
+            .as_any()
+            .downcast_ref::<BinaryExpr>()
+            .unwrap();
+        assert_eq!(plus.overflow_mode(), mode);
+    }
+    Ok(())
+}
+
 #[test]
 fn roundtrip_sort() -> Result<()> {
     let field_a = Field::new("a", DataType::Boolean, false);
@@ -845,7 +886,13 @@ fn roundtrip_scalar_udf_extension_codec() -> Result<()> {
 
     let ctx = SessionContext::new();
     let codec = ScalarUDFExtensionCodec {};
-    roundtrip_test_and_return(aggregate, &ctx, &codec)?;
+    roundtrip_test_and_return(aggregate.clone(), &ctx, &codec)?;
+
+    // the function is encoded by the codec that writes a definition for it
+    let composed = ComposedPhysicalExtensionCodec::new()
+        .with_codec("default", Arc::new(DefaultPhysicalExtensionCodec {}))
+        .with_codec("regex", Arc::new(codec));
+    roundtrip_test_and_return(aggregate, &ctx, &composed)?;
     Ok(())
 }
 
@@ -863,8 +910,19 @@ fn roundtrip_like() -> Result<()> {
         col("b", &schema)?,
         &schema,
     )?;
+    let like_escape_expr = like_with_escape_char(
+        true,
+        true,
+        col("a", &schema)?,
+        col("b", &schema)?,
+        Some('#'),
+        &schema,
+    )?;
     let plan = Arc::new(ProjectionExec::try_new(
-        vec![(like_expr, "result".to_string())],
+        vec![
+            (like_expr, "result".to_string()),
+            (like_escape_expr, "escaped".to_string()),
+        ],
         input,
     )?);
     roundtrip_test(plan)
@@ -1106,3 +1164,196 @@ fn roundtrip_interleave() -> Result<()> {
     let interleave = InterleaveExec::try_new(inputs)?;
     roundtrip_test(Arc::new(interleave))
 }
+
+#[test]
+fn roundtrip_union_with_different_nullability() -> Result<()> {
+    let schema_left = Schema::new(vec![Field::new("col", DataType::Int64, false)]);
+    let schema_right = Schema::new(vec![Field::new("col", DataType::Int64, true)]);
+    let inputs: Vec<Arc<dyn ExecutionPlan>> = vec![
+        Arc::new(EmptyExec::new(Arc::new(schema_left))),
+        Arc::new(EmptyExec::new(Arc::new(schema_right))),
+    ];
+    roundtrip_test(Arc::new(UnionExec::new(inputs)))
+}
+
+#[test]
+fn roundtrip_sort_merge_join() -> Result<()> {
+    let field_a = Field::new("col", DataType::Int64, false);
+    let schema_left = Arc::new(Schema::new(vec![field_a.clone()]));
+    let schema_right = Arc::new(Schema::new(vec![field_a]));
+    let on = vec![(
+        Arc::new(Column::new("col", schema_left.index_of("col")?)) as _,
+        Arc::new(Column::new("col", schema_right.index_of("col")?)) as _,
+    )];
+    let filter = JoinFilter::new(
+        Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("l", 0)),
+            Operator::Gt,
+            Arc::new(Column::new("r", 1)),
+        )),
+        vec![
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Right,
+            },
+        ],
+        Schema::new(vec![
+            Field::new("l", DataType::Int64, false),
+            Field::new("r", DataType::Int64, false),
+        ]),
+    );
+
+    for join_type in &[
+        JoinType::Inner,
+        JoinType::Left,
+        JoinType::Right,
+        JoinType::Full,
+        JoinType::LeftAnti,
+        JoinType::RightAnti,
+        JoinType::LeftSemi,
+    ] {
+        for filter in [None, Some(filter.clone())] {
+            roundtrip_test(Arc::new(SortMergeJoinExec::try_new(
+                Arc::new(EmptyExec::new(schema_left.clone())),
+                Arc::new(EmptyExec::new(schema_right.clone())),
+                on.clone(),
+                filter,
+                *join_type,
+                vec![SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }],
+                true,
+            )?))?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn roundtrip_unnest() -> Result<()> {
+    let input_schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new_list("list", Field::new("item", DataType::Int64, true), true),
+    ]));
+    let output_schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("list", DataType::Int64, true),
+    ]));
+    roundtrip_test(Arc::new(UnnestExec::new(
+        Arc::new(EmptyExec::new(input_schema)),
+        vec![1],
+        vec![],
+        output_schema,
+        UnnestOptions::new().with_preserve_nulls(false),
+    )))
+}
+
+#[test]
+fn roundtrip_partial_sort() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Int64, false),
+    ]));
+    let sort_exprs = vec![
+        PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        },
+        PhysicalSortExpr {
+            expr: col("b", &schema)?,
+            options: SortOptions {
+                descending: true,
+                nulls_first: true,
+            },
+        },
+    ];
+    roundtrip_test(Arc::new(
+        PartialSortExec::new(sort_exprs, Arc::new(EmptyExec::new(schema)), 1)
+            .with_fetch(Some(10))
+            .with_preserve_partitioning(true),
+    ))
+}
+
+/// A scan of a single file with the given schema
+fn file_scan_config(path: &str, schema: SchemaRef) -> FileScanConfig {
+    FileScanConfig {
+        object_store_url: ObjectStoreUrl::local_filesystem(),
+        file_groups: vec![vec![PartitionedFile::new(path.to_string(), 1024)]],
+        statistics: Statistics::new_unknown(&schema),
+        file_schema: schema,
+        projection: Some(vec![0]),
+        limit: Some(5),
+        table_partition_cols: vec![],
+        output_ordering: vec![],
+    }
+}
+
+#[test]
+fn roundtrip_json_exec() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, true),
+    ]));
+    for compression in [FileCompressionType::UNCOMPRESSED, FileCompressionType::GZIP] {
+        roundtrip_test(Arc::new(NdJsonExec::new(
+            file_scan_config("/path/to/file.json", schema.clone()),
+            compression,
+        )))?;
+    }
+    Ok(())
+}
+
+#[test]
+fn roundtrip_arrow_exec() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    roundtrip_test(Arc::new(ArrowExec::new(file_scan_config(
+        "/path/to/file.arrow",
+        schema,
+    ))))
+}
+
+#[test]
+fn roundtrip_bounded_window_with_rows_frame() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Int64, false),
+    ]));
+    let window_frame = WindowFrame::new_bounds(
+        datafusion_expr::WindowFrameUnits::Rows,
+        WindowFrameBound::Preceding(ScalarValue::UInt64(Some(2))),
+        WindowFrameBound::Following(ScalarValue::UInt64(Some(1))),
+    );
+    let sum_expr = udaf::create_aggregate_expr(
+        &sum_udaf(),
+        &[col("a", &schema)?],
+        &[],
+        &[],
+        &[],
+        &schema,
+        "SUM(a) ROWS BETWEEN 2 PRECEDING AND 1 FOLLOWING",
+        false,
+        false,
+    )?;
+    let order_by = vec![PhysicalSortExpr {
+        expr: col("a", &schema)?,
+        options: SortOptions::default(),
+    }];
+    let window_expr = Arc::new(SlidingAggregateWindowExpr::new(
+        sum_expr,
+        &[col("b", &schema)?],
+        &order_by,
+        Arc::new(window_frame),
+    ));
+
+    roundtrip_test(Arc::new(BoundedWindowAggExec::try_new(
+        vec![window_expr],
+        Arc::new(EmptyExec::new(schema.clone())),
+        vec![col("b", &schema)?],
+        InputOrderMode::Sorted,
+    )?))
+}
@@ -308,30 +308,28 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             };
 
             if let Ok(fun) = self.find_window_func(&name) {
-                let expr = match fun {
-                    WindowFunctionDefinition::AggregateFunction(aggregate_fun) => {
-                        let args =
-                            self.function_args_to_expr(args, schema, planner_context)?;
-
-                        Expr::WindowFunction(expr::WindowFunction::new(
-                            WindowFunctionDefinition::AggregateFunction(aggregate_fun),
-                            args,
-                            partition_by,
-                            order_by,
-                            window_frame,
-                            null_treatment,
-                        ))
-                    }
-                    _ => Expr::WindowFunction(expr::WindowFunction::new(
-                        fun,
-                        self.function_args_to_expr(args, schema, planner_context)?,
-                        partition_by,
-                        order_by,
-                        window_frame,
-                        null_treatment,
-                    )),
-                };
-                return Ok(expr);
+                let is_aggregate = matches!(
+                    fun,
+                    WindowFunctionDefinition::AggregateFunction(_)
+                        | WindowFunctionDefinition::AggregateUDF(_)
+                );
+                if distinct && !is_aggregate {
+                    return not_impl_err!(
+                        "DISTINCT is only supported for aggregate window functions, \
+                         not for {name}"
+                    );
+                }
+                let args = self.function_args_to_expr(args, schema, planner_context)?;
+                let expr = expr::WindowFunction::new(
+                    fun,
+                    args,
+                    partition_by,
+                    order_by,
+                    window_frame,
+                    null_treatment,
+                )
+                .with_distinct(distinct);
+                return Ok(Expr::WindowFunction(expr));
             }
         } else {
             // User defined aggregate functions (UDAF) have precedence in case it has the same name as a scalar built-in function
@@ -19,7 +19,9 @@ use arrow_schema::DataType;
 use arrow_schema::TimeUnit;
 use datafusion_expr::planner::PlannerResult;
 use datafusion_expr::planner::RawFieldAccessExpr;
-use sqlparser::ast::{CastKind, Expr as SQLExpr, Subscript, TrimWhereField, Value};
+use sqlparser::ast::{
+    CastKind, DictionaryField, Expr as SQLExpr, Subscript, TrimWhereField, Value,
+};
 
 use datafusion_common::{
     internal_datafusion_err, internal_err, not_impl_err, plan_err, DFSchema, Result,
@@ -596,6 +598,9 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             SQLExpr::Struct { values, fields } => {
                 self.parse_struct(values, fields, schema, planner_context)
             }
+            SQLExpr::Dictionary(fields) => {
+                self.parse_dictionary(fields, schema, planner_context)
+            }
             SQLExpr::Position { expr, r#in } => {
                 self.sql_position_to_expr(*expr, *r#in, schema, planner_context)
             }
@@ -645,6 +650,27 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }
     }
 
+    /// Parses a struct literal such as `{'a': 1, 'b': [2, 3]}` into a call to
+    /// the `named_struct` function, keeping the order of the fields
+    fn parse_dictionary(
+        &self,
+        fields: Vec<DictionaryField>,
+        input_schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Expr> {
+        if fields.is_empty() {
+            return plan_err!("Struct literals must have at least one field");
+        }
+        let values = fields
+            .into_iter()
+            .map(|field| SQLExpr::Named {
+                expr: field.value,
+                name: field.key,
+            })
+            .collect();
+        self.create_named_struct(values, input_schema, planner_context)
+    }
+
     // Handles a call to struct(...) where the arguments are named. For example
     // `struct (v as foo, v2 as bar)` by creating a call to the `named_struct` function
     fn create_named_struct(
@@ -47,6 +47,8 @@ fn parse_file_type(s: &str) -> Result<String, ParserError> {
 pub struct ExplainStatement {
     pub analyze: bool,
     pub verbose: bool,
+    /// Show the estimated statistics of the plans, `EXPLAIN (ESTIMATES)`
+    pub estimates: bool,
     pub statement: Box<Statement>,
 }
 
@@ -55,10 +57,14 @@ impl fmt::Display for ExplainStatement {
         let Self {
             analyze,
             verbose,
+            estimates,
             statement,
         } = self;
 
         write!(f, "EXPLAIN ")?;
+        if *estimates {
+            write!(f, "(ESTIMATES) ")?;
+        }
         if *analyze {
             write!(f, "ANALYZE ")?;
         }
@@ -533,6 +539,7 @@ impl<'a> DFParser<'a> {
 
     /// Parse a SQL `EXPLAIN`
     pub fn parse_explain(&mut self) -> Result<Statement, ParserError> {
+        let estimates = self.parse_explain_estimates()?;
         let analyze = self.parser.parse_keyword(Keyword::ANALYZE);
         let verbose = self.parser.parse_keyword(Keyword::VERBOSE);
         let statement = self.parse_statement()?;
@@ -541,9 +548,26 @@ impl<'a> DFParser<'a> {
             statement: Box::new(statement),
             analyze,
             verbose,
+            estimates,
         }))
     }
 
+    /// Parse the optional `(ESTIMATES)` option of `EXPLAIN`, telling it apart
+    /// from a parenthesized query such as `EXPLAIN (SELECT 1)`
+    fn parse_explain_estimates(&mut self) -> Result<bool, ParserError> {
+        let is_estimates = self.parser.peek_token().token == Token::LParen
+            && matches!(
+                self.parser.peek_nth_token(1).token,
+                Token::Word(w) if w.value.eq_ignore_ascii_case("ESTIMATES")
+            );
+        if is_estimates {
+            self.parser.next_token(); // (
+            self.parser.next_token(); // ESTIMATES
+            self.parser.expect_token(&Token::RParen)?;
+        }
+        Ok(is_estimates)
+    }
+
     /// Parse a SQL `CREATE` statement handling `CREATE EXTERNAL TABLE`
     pub fn parse_create(&mut self) -> Result<Statement, ParserError> {
         if self.parser.parse_keyword(Keyword::EXTERNAL) {
@@ -1397,6 +1421,7 @@ mod tests {
             let expected = Statement::Explain(ExplainStatement {
                 analyze,
                 verbose,
+                estimates: false,
                 statement: Box::new(expected_copy),
             });
             assert_eq!(verified_stmt(sql), expected);
@@ -1404,6 +1429,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn explain_estimates() -> Result<(), ParserError> {
+        let cases = vec![
+            ("EXPLAIN (ESTIMATES) SELECT 1", false),
+            ("EXPLAIN (ESTIMATES) VERBOSE SELECT 1", true),
+        ];
+        for (sql, verbose) in cases {
+            let expected = Statement::Explain(ExplainStatement {
+                analyze: false,
+                verbose,
+                estimates: true,
+                statement: Box::new(verified_stmt("SELECT 1")),
+            });
+            assert_eq!(verified_stmt(sql), expected);
+        }
+
+        // the option is case insensitive
+        let statement = DFParser::parse_sql("EXPLAIN (estimates) SELECT 1")?;
+        assert!(matches!(
+            &statement[0],
+            Statement::Explain(ExplainStatement {
+                estimates: true,
+                ..
+            })
+        ));
+
+        // a parenthesized query is not an option
+        let statement = DFParser::parse_sql("EXPLAIN (SELECT 1)")?;
+        assert!(matches!(
+            &statement[0],
+            Statement::Explain(ExplainStatement {
+                estimates: false,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
     #[test]
     fn copy_to_query_to_table() -> Result<(), ParserError> {
         let statement = verified_stmt("SELECT 1");
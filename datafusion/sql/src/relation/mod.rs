@@ -34,10 +34,10 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 name, alias, args, ..
             } => {
                 if let Some(func_args) = args {
-                    let tbl_func_name = name.0.first().unwrap().value.to_string();
+                    let tbl_func_ref = self.object_name_to_table_reference(name)?;
                     let args = func_args
                         .into_iter()
-                        .flat_map(|arg| {
+                        .map(|arg| {
                             if let FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) = arg
                             {
                                 self.sql_expr_to_logical_expr(
@@ -49,14 +49,12 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                                 plan_err!("Unsupported function argument type: {:?}", arg)
                             }
                         })
-                        .collect::<Vec<_>>();
+                        .collect::<Result<Vec<_>>>()?;
                     let provider = self
                         .context_provider
-                        .get_table_function_source(&tbl_func_name, args)?;
+                        .get_table_function_source(tbl_func_ref.table(), args)?;
                     let plan = LogicalPlanBuilder::scan(
-                        TableReference::Bare {
-                            table: "tmp_table".into(),
-                        },
+                        TableReference::bare(tbl_func_ref.table()),
                         provider,
                         None,
                     )?
@@ -22,8 +22,9 @@ use crate::planner::{
     idents_to_table_reference, ContextProvider, PlannerContext, SqlToRel,
 };
 use crate::utils::{
-    check_columns_satisfy_exprs, extract_aliases, rebase_expr, resolve_aliases_to_exprs,
-    resolve_columns, resolve_positions_to_exprs, transform_bottom_unnest,
+    check_columns_satisfy_exprs, extract_aliases, find_outer_aggregate_exprs,
+    rebase_expr, rebase_subquery_outer_refs, resolve_aliases_to_exprs, resolve_columns,
+    resolve_positions_to_exprs, transform_bottom_unnest,
 };
 
 use datafusion_common::{not_impl_err, plan_err, DataFusionError, Result};
@@ -145,8 +146,15 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             aggr_expr_haystack.push(having_expr.clone());
         }
 
-        // All of the aggregate expressions (deduplicated).
-        let aggr_exprs = find_aggregate_exprs(&aggr_expr_haystack);
+        // All of the aggregate expressions (deduplicated), including the
+        // aggregates of outer columns used inside subqueries, which are
+        // computed by this query block.
+        let mut aggr_exprs = find_aggregate_exprs(&aggr_expr_haystack);
+        for aggr_expr in find_outer_aggregate_exprs(&aggr_expr_haystack)? {
+            if !aggr_exprs.contains(&aggr_expr) {
+                aggr_exprs.push(aggr_expr);
+            }
+        }
 
         // All of the group by expressions
         let group_by_exprs = if let GroupByExpr::Expressions(exprs) = select.group_by {
@@ -661,10 +669,14 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             .map(|expr| expr_as_column_expr(expr, input))
             .collect::<Result<Vec<Expr>>>()?;
 
-        // next we re-write the projection
+        // next we re-write the projection, including the outer references of
+        // any subqueries
         let select_exprs_post_aggr = select_exprs
             .iter()
-            .map(|expr| rebase_expr(expr, &aggr_projection_exprs, input))
+            .map(|expr| {
+                let expr = rebase_expr(expr, &aggr_projection_exprs, input)?;
+                rebase_subquery_outer_refs(expr, &aggr_projection_exprs, input, &plan)
+            })
             .collect::<Result<Vec<Expr>>>()?;
 
         // finally, we have some validation that the re-written projection can be resolved
@@ -680,6 +692,12 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         let having_expr_post_aggr = if let Some(having_expr) = having_expr_opt {
             let having_expr_post_aggr =
                 rebase_expr(having_expr, &aggr_projection_exprs, input)?;
+            let having_expr_post_aggr = rebase_subquery_outer_refs(
+                having_expr_post_aggr,
+                &aggr_projection_exprs,
+                input,
+                &plan,
+            )?;
 
             check_columns_satisfy_exprs(
                 &column_exprs_post_aggr,
@@ -47,10 +47,10 @@ use datafusion_expr::{
     CreateExternalTable as PlanCreateExternalTable, CreateFunction, CreateFunctionBody,
     CreateMemoryTable, CreateView, DescribeTable, DmlStatement, DropCatalogSchema,
     DropFunction, DropTable, DropView, EmptyRelation, Explain, ExprSchemable, Filter,
-    LogicalPlan, LogicalPlanBuilder, OperateFunctionArg, PlanType, Prepare, SetVariable,
-    Statement as PlanStatement, ToStringifiedPlan, TransactionAccessMode,
-    TransactionConclusion, TransactionEnd, TransactionIsolationLevel, TransactionStart,
-    Volatility, WriteOp,
+    LogicalPlan, LogicalPlanBuilder, OperateFunctionArg, PlanType, Prepare,
+    SetTransaction, SetVariable, Statement as PlanStatement, TableSource,
+    ToStringifiedPlan, TransactionAccessMode, TransactionConclusion, TransactionEnd,
+    TransactionIsolationLevel, TransactionStart, Volatility, WriteOp,
 };
 use sqlparser::ast;
 use sqlparser::ast::{
@@ -61,6 +61,42 @@ use sqlparser::ast::{
 };
 use sqlparser::parser::ParserError::ParserError;
 
+/// Returns the last access mode among the given transaction modes, if any
+fn transaction_access_mode(modes: &[TransactionMode]) -> Option<TransactionAccessMode> {
+    modes.iter().rev().find_map(|mode| match mode {
+        TransactionMode::AccessMode(ast::TransactionAccessMode::ReadOnly) => {
+            Some(TransactionAccessMode::ReadOnly)
+        }
+        TransactionMode::AccessMode(ast::TransactionAccessMode::ReadWrite) => {
+            Some(TransactionAccessMode::ReadWrite)
+        }
+        TransactionMode::IsolationLevel(_) => None,
+    })
+}
+
+/// Returns the last isolation level among the given transaction modes, if any
+fn transaction_isolation_level(
+    modes: &[TransactionMode],
+) -> Option<TransactionIsolationLevel> {
+    modes.iter().rev().find_map(|mode| match mode {
+        TransactionMode::AccessMode(_) => None,
+        TransactionMode::IsolationLevel(level) => Some(match level {
+            ast::TransactionIsolationLevel::ReadUncommitted => {
+                TransactionIsolationLevel::ReadUncommitted
+            }
+            ast::TransactionIsolationLevel::ReadCommitted => {
+                TransactionIsolationLevel::ReadCommitted
+            }
+            ast::TransactionIsolationLevel::RepeatableRead => {
+                TransactionIsolationLevel::RepeatableRead
+            }
+            ast::TransactionIsolationLevel::Serializable => {
+                TransactionIsolationLevel::Serializable
+            }
+        }),
+    })
+}
+
 fn ident_to_string(ident: &Ident) -> String {
     normalize_ident(ident.to_owned())
 }
@@ -187,8 +223,9 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             DFStatement::Explain(ExplainStatement {
                 verbose,
                 analyze,
+                estimates,
                 statement,
-            }) => self.explain_to_plan(verbose, analyze, *statement),
+            }) => self.explain_to_plan(verbose, analyze, estimates, *statement),
         }
     }
 
@@ -228,9 +265,12 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 format: _,
                 describe_alias: _,
                 ..
-            } => {
-                self.explain_to_plan(verbose, analyze, DFStatement::Statement(statement))
-            }
+            } => self.explain_to_plan(
+                verbose,
+                analyze,
+                false,
+                DFStatement::Statement(statement),
+            ),
             Statement::Query(query) => self.query_to_plan(*query, planner_context),
             Statement::ShowVariable { variable } => self.show_variable_to_plan(&variable),
             Statement::SetVariable {
@@ -340,6 +380,8 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 columns,
                 query,
                 options: CreateTableOptions::None,
+                if_not_exists,
+                temporary,
                 ..
             } => {
                 let columns = columns
@@ -362,7 +404,9 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                     name: self.object_name_to_table_reference(name)?,
                     input: Arc::new(plan),
                     or_replace,
+                    if_not_exists,
                     definition: sql,
+                    temporary,
                 })))
             }
             Statement::ShowCreate { obj_type, obj_name } => match obj_type {
@@ -527,9 +571,6 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 if ignore {
                     plan_err!("Insert-ignore clause not supported")?;
                 }
-                let Some(source) = source else {
-                    plan_err!("Inserts without a source not supported")?
-                };
                 if let Some(table_alias) = table_alias {
                     plan_err!(
                         "Inserts with a table alias not supported: {table_alias:?}"
@@ -597,7 +638,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
 
             Statement::StartTransaction {
                 modes,
-                begin: false,
+                begin: _,
                 modifier,
             } => {
                 if let Some(modifier) = modifier {
@@ -605,49 +646,31 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                         "Transaction modifier not supported: {modifier}"
                     );
                 }
-                let isolation_level: ast::TransactionIsolationLevel = modes
-                    .iter()
-                    .filter_map(|m: &ast::TransactionMode| match m {
-                        TransactionMode::AccessMode(_) => None,
-                        TransactionMode::IsolationLevel(level) => Some(level),
-                    })
-                    .last()
-                    .copied()
-                    .unwrap_or(ast::TransactionIsolationLevel::Serializable);
-                let access_mode: ast::TransactionAccessMode = modes
-                    .iter()
-                    .filter_map(|m: &ast::TransactionMode| match m {
-                        TransactionMode::AccessMode(mode) => Some(mode),
-                        TransactionMode::IsolationLevel(_) => None,
-                    })
-                    .last()
-                    .copied()
-                    .unwrap_or(ast::TransactionAccessMode::ReadWrite);
-                let isolation_level = match isolation_level {
-                    ast::TransactionIsolationLevel::ReadUncommitted => {
-                        TransactionIsolationLevel::ReadUncommitted
-                    }
-                    ast::TransactionIsolationLevel::ReadCommitted => {
-                        TransactionIsolationLevel::ReadCommitted
-                    }
-                    ast::TransactionIsolationLevel::RepeatableRead => {
-                        TransactionIsolationLevel::RepeatableRead
-                    }
-                    ast::TransactionIsolationLevel::Serializable => {
-                        TransactionIsolationLevel::Serializable
-                    }
-                };
-                let access_mode = match access_mode {
-                    ast::TransactionAccessMode::ReadOnly => {
-                        TransactionAccessMode::ReadOnly
-                    }
-                    ast::TransactionAccessMode::ReadWrite => {
-                        TransactionAccessMode::ReadWrite
-                    }
-                };
                 let statement = PlanStatement::TransactionStart(TransactionStart {
-                    access_mode,
-                    isolation_level,
+                    access_mode: transaction_access_mode(&modes)
+                        .unwrap_or(TransactionAccessMode::ReadWrite),
+                    isolation_level: transaction_isolation_level(&modes)
+                        .unwrap_or(TransactionIsolationLevel::Serializable),
+                    schema: DFSchemaRef::new(DFSchema::empty()),
+                });
+                Ok(LogicalPlan::Statement(statement))
+            }
+            Statement::SetTransaction {
+                modes,
+                snapshot,
+                session,
+            } => {
+                if snapshot.is_some() {
+                    return not_impl_err!("Transaction snapshots not supported");
+                }
+                if session {
+                    return not_impl_err!(
+                        "Session characteristics of transactions not supported"
+                    );
+                }
+                let statement = PlanStatement::SetTransaction(SetTransaction {
+                    access_mode: transaction_access_mode(&modes),
+                    isolation_level: transaction_isolation_level(&modes),
                     schema: DFSchemaRef::new(DFSchema::empty()),
                 });
                 Ok(LogicalPlan::Statement(statement))
@@ -850,13 +873,11 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         let table_source = self.context_provider.get_table_source(table_ref)?;
 
         let schema = table_source.schema();
+        let partition_cols = table_source.partition_columns();
 
-        let output_schema = DFSchema::try_from(LogicalPlan::describe_schema()).unwrap();
-
-        Ok(LogicalPlan::DescribeTable(DescribeTable {
-            schema,
-            output_schema: Arc::new(output_schema),
-        }))
+        Ok(LogicalPlan::DescribeTable(
+            DescribeTable::new(schema).with_partition_cols(partition_cols),
+        ))
     }
 
     fn copy_to_plan(&self, statement: CopyToStatement) -> Result<LogicalPlan> {
@@ -1088,8 +1109,12 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         &self,
         verbose: bool,
         analyze: bool,
+        estimates: bool,
         statement: DFStatement,
     ) -> Result<LogicalPlan> {
+        if analyze && estimates {
+            return not_impl_err!("EXPLAIN (ESTIMATES) ANALYZE is not supported");
+        }
         let plan = self.statement_to_plan(statement)?;
         if matches!(plan, LogicalPlan::Explain(_)) {
             return plan_err!("Nested EXPLAINs are not supported");
@@ -1109,6 +1134,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 vec![plan.to_stringified(PlanType::InitialLogicalPlan)];
             Ok(LogicalPlan::Explain(Explain {
                 verbose,
+                estimates,
                 plan,
                 stringified_plans,
                 schema,
@@ -1375,7 +1401,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         &self,
         table_name: ObjectName,
         columns: Vec<Ident>,
-        source: Box<Query>,
+        source: Option<Box<Query>>,
         overwrite: bool,
     ) -> Result<LogicalPlan> {
         // Do a table lookup to verify the table exists
@@ -1422,6 +1448,24 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             (Fields::from(fields), value_indices)
         };
 
+        // `INSERT INTO t DEFAULT VALUES` has no source: insert a single row in
+        // which every column is filled with its default value
+        let Some(source) = source else {
+            if !fields.is_empty() {
+                plan_err!("Column count doesn't match insert query!")?;
+            }
+            let value_indices = vec![None; table_schema.fields().len()];
+            let source = LogicalPlanBuilder::empty(true).build()?;
+            return self.insert_source_to_plan(
+                table_name,
+                table_schema,
+                table_source,
+                source,
+                value_indices,
+                overwrite,
+            );
+        };
+
         // infer types for Values clause... other types should be resolvable the regular way
         let mut prepare_param_data_types = BTreeMap::new();
         if let SetExpr::Values(ast::Values { rows, .. }) = (*source.body).clone() {
@@ -1454,6 +1498,28 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             plan_err!("Column count doesn't match insert query!")?;
         }
 
+        self.insert_source_to_plan(
+            table_name,
+            table_schema,
+            table_source,
+            source,
+            value_indices,
+            overwrite,
+        )
+    }
+
+    /// Projects `source` onto the columns of the target table and wraps it in
+    /// a DML insert. Columns without a value index are filled with their
+    /// default value, or NULL if the column has no default.
+    fn insert_source_to_plan(
+        &self,
+        table_name: TableReference,
+        table_schema: DFSchema,
+        table_source: Arc<dyn TableSource>,
+        source: LogicalPlan,
+        value_indices: Vec<Option<usize>>,
+        overwrite: bool,
+    ) -> Result<LogicalPlan> {
         let exprs = value_indices
             .into_iter()
             .enumerate()
@@ -1524,7 +1590,8 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         let select_list = if full || extended {
             "*"
         } else {
-            "table_catalog, table_schema, table_name, column_name, data_type, is_nullable"
+            "table_catalog, table_schema, table_name, column_name, data_type, is_nullable, \
+             is_partition"
         };
 
         let query = format!(
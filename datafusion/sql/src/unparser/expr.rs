@@ -228,6 +228,7 @@ impl Unparser<'_> {
                 order_by,
                 window_frame,
                 null_treatment: _,
+                distinct,
             }) => {
                 let func_name = fun.name();
 
@@ -271,7 +272,8 @@ impl Unparser<'_> {
                         quote_style: None,
                     }]),
                     args: ast::FunctionArguments::List(ast::FunctionArgumentList {
-                        duplicate_treatment: None,
+                        duplicate_treatment: distinct
+                            .then_some(ast::DuplicateTreatment::Distinct),
                         args,
                         clauses: vec![],
                     }),
@@ -1319,6 +1321,7 @@ mod tests {
                     order_by: vec![],
                     window_frame: WindowFrame::new(None),
                     null_treatment: None,
+                    distinct: false,
                 }),
                 r#"ROW_NUMBER(col) OVER (ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING)"#,
             ),
@@ -1342,6 +1345,7 @@ mod tests {
                         ),
                     ),
                     null_treatment: None,
+                    distinct: false,
                 }),
                 r#"count(*) OVER (ORDER BY a DESC NULLS FIRST RANGE BETWEEN 6 PRECEDING AND 2 FOLLOWING)"#,
             ),
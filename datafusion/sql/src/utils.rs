@@ -18,6 +18,7 @@
 //! SQL Utility Functions
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use arrow_schema::{
     DataType, DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION, DECIMAL_DEFAULT_SCALE,
@@ -29,9 +30,15 @@ use datafusion_common::{
     exec_err, internal_err, plan_err, Column, DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::builder::get_unnested_columns;
-use datafusion_expr::expr::{Alias, GroupingSet, Unnest, WindowFunction};
-use datafusion_expr::utils::{expr_as_column_expr, find_column_exprs};
-use datafusion_expr::{expr_vec_fmt, Expr, ExprSchemable, LogicalPlan};
+use datafusion_expr::expr::{
+    Alias, Exists, GroupingSet, InSubquery, Unnest, WindowFunction,
+};
+use datafusion_expr::expr_rewriter::strip_outer_reference;
+use datafusion_expr::logical_plan::tree_node::unwrap_arc;
+use datafusion_expr::utils::{
+    expr_as_column_expr, find_aggregate_exprs, find_column_exprs,
+};
+use datafusion_expr::{expr_vec_fmt, Expr, ExprSchemable, LogicalPlan, Subquery};
 use sqlparser::ast::Ident;
 
 /// Make a best-effort attempt at resolving all columns in the expression tree
@@ -85,6 +92,201 @@ pub(crate) fn rebase_expr(
         .data()
 }
 
+/// Rebuilds the outer references of the subqueries in `expr` as references
+/// to the output of `aggregate`, the aggregation of `input` whose grouping
+/// and aggregate expressions are `base_exprs`.
+///
+/// While a subquery is planned, its outer references are resolved against
+/// the input of the enclosing query block. Once that query block is
+/// aggregated, a reference to a grouping expression, or an aggregate of
+/// outer columns such as `max(t.b)` in
+///
+/// SELECT t.a, (SELECT count(*) FROM u WHERE u.b = max(t.b)) FROM t GROUP BY t.a
+///
+/// must instead refer to the corresponding output column of the aggregate.
+pub(crate) fn rebase_subquery_outer_refs(
+    expr: Expr,
+    base_exprs: &[Expr],
+    input: &LogicalPlan,
+    aggregate: &LogicalPlan,
+) -> Result<Expr> {
+    expr.transform_down(|nested_expr| match nested_expr {
+        Expr::ScalarSubquery(subquery) => Ok(Transformed::yes(Expr::ScalarSubquery(
+            rebase_subquery(subquery, base_exprs, input, aggregate)?,
+        ))),
+        Expr::Exists(Exists { subquery, negated }) => {
+            Ok(Transformed::yes(Expr::Exists(Exists {
+                subquery: rebase_subquery(subquery, base_exprs, input, aggregate)?,
+                negated,
+            })))
+        }
+        Expr::InSubquery(InSubquery {
+            expr,
+            subquery,
+            negated,
+        }) => Ok(Transformed::yes(Expr::InSubquery(InSubquery::new(
+            expr,
+            rebase_subquery(subquery, base_exprs, input, aggregate)?,
+            negated,
+        )))),
+        _ => Ok(Transformed::no(nested_expr)),
+    })
+    .data()
+}
+
+fn rebase_subquery(
+    subquery: Subquery,
+    base_exprs: &[Expr],
+    input: &LogicalPlan,
+    aggregate: &LogicalPlan,
+) -> Result<Subquery> {
+    if subquery.outer_ref_columns.is_empty() {
+        return Ok(subquery);
+    }
+    let plan = unwrap_arc(subquery.subquery)
+        .transform_up(|plan| {
+            // keep the names of the projected expressions, they may be
+            // referenced by the plan above
+            let is_projection = matches!(plan, LogicalPlan::Projection(_));
+            let plan = plan.map_expressions(|expr| {
+                let original_name =
+                    is_projection.then(|| expr.display_name()).transpose()?;
+                let expr = rebase_outer_refs(expr, base_exprs, input, aggregate)?;
+                match original_name {
+                    Some(name) if expr.transformed => {
+                        expr.map_data(|expr| expr.alias_if_changed(name))
+                    }
+                    _ => Ok(expr),
+                }
+            })?;
+            if plan.transformed {
+                plan.map_data(|plan| plan.recompute_schema())
+            } else {
+                Ok(plan)
+            }
+        })
+        .data()?;
+    let outer_ref_columns = plan.all_out_ref_exprs();
+    Ok(Subquery {
+        subquery: Arc::new(plan),
+        outer_ref_columns,
+    })
+}
+
+/// Replaces the largest sub-expressions of `expr` that only reference outer
+/// columns and match one of `base_exprs` with an outer reference to the
+/// corresponding output column of `aggregate`.
+fn rebase_outer_refs(
+    expr: Expr,
+    base_exprs: &[Expr],
+    input: &LogicalPlan,
+    aggregate: &LogicalPlan,
+) -> Result<Transformed<Expr>> {
+    expr.transform_down(|nested_expr| {
+        if !references_only_outer_columns(&nested_expr) {
+            return Ok(Transformed::no(nested_expr));
+        }
+        let base_expr = strip_outer_reference(nested_expr.clone());
+        if !base_exprs.contains(&base_expr) {
+            return Ok(Transformed::no(nested_expr));
+        }
+        let Expr::Column(column) = expr_as_column_expr(&base_expr, input)? else {
+            return internal_err!("Expr::Column is required");
+        };
+        let data_type = aggregate
+            .schema()
+            .field_from_column(&column)?
+            .data_type()
+            .clone();
+        Ok(Transformed::new(
+            Expr::OuterReferenceColumn(data_type, column),
+            true,
+            TreeNodeRecursion::Jump,
+        ))
+    })
+}
+
+/// Collect the aggregate expressions of the enclosing query block that are
+/// used inside the subqueries of `exprs`, such as `max(t.b)` in
+///
+/// SELECT (SELECT count(*) FROM u WHERE u.b = max(t.b)) FROM t
+///
+/// These aggregates only reference outer columns and are computed by the
+/// enclosing query block, so they are returned with the outer references
+/// stripped. Aggregates of the subquery's own aggregation are left alone.
+pub(crate) fn find_outer_aggregate_exprs(exprs: &[Expr]) -> Result<Vec<Expr>> {
+    let mut aggr_exprs = vec![];
+    for expr in exprs {
+        expr.apply(|expr| {
+            let subquery = match expr {
+                Expr::ScalarSubquery(subquery)
+                | Expr::Exists(Exists { subquery, .. })
+                | Expr::InSubquery(InSubquery { subquery, .. }) => subquery,
+                _ => return Ok(TreeNodeRecursion::Continue),
+            };
+            subquery.subquery.apply(|plan| {
+                if matches!(plan, LogicalPlan::Aggregate(_)) {
+                    return Ok(TreeNodeRecursion::Continue);
+                }
+                plan.apply_expressions(|expr| {
+                    for aggr_expr in find_aggregate_exprs(std::slice::from_ref(expr)) {
+                        if references_only_outer_columns(&aggr_expr) {
+                            let aggr_expr = strip_outer_reference(aggr_expr);
+                            if !aggr_exprs.contains(&aggr_expr) {
+                                aggr_exprs.push(aggr_expr);
+                            }
+                        }
+                    }
+                    Ok(TreeNodeRecursion::Continue)
+                })
+            })
+        })?;
+    }
+    Ok(aggr_exprs)
+}
+
+/// Returns true if `expr` references at least one outer column and no
+/// columns of its own query block
+fn references_only_outer_columns(expr: &Expr) -> bool {
+    let mut has_outer_ref = false;
+    let mut has_column = false;
+    expr.apply(|expr| {
+        match expr {
+            Expr::OuterReferenceColumn(_, _) => has_outer_ref = true,
+            Expr::Column(_) => has_column = true,
+            _ => {}
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })
+    // As the closure always returns Ok, this "can't" error
+    .expect("Unexpected error");
+    has_outer_ref && !has_column
+}
+
+/// Returns the columns of the enclosing query block that are referenced by
+/// the subqueries in `exprs`
+fn find_subquery_outer_ref_columns(exprs: &[Expr]) -> Vec<Expr> {
+    let mut columns = vec![];
+    for expr in exprs {
+        expr.apply(|expr| {
+            if let Expr::ScalarSubquery(subquery)
+            | Expr::Exists(Exists { subquery, .. })
+            | Expr::InSubquery(InSubquery { subquery, .. }) = expr
+            {
+                for outer_ref in &subquery.outer_ref_columns {
+                    if let Expr::OuterReferenceColumn(_, column) = outer_ref {
+                        columns.push(Expr::Column(column.clone()));
+                    }
+                }
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })
+        // As the closure always returns Ok, this "can't" error
+        .expect("Unexpected error");
+    }
+    columns
+}
+
 /// Determines if the set of `Expr`'s are a valid projection on the input
 /// `Expr::Column`'s.
 pub(crate) fn check_columns_satisfy_exprs(
@@ -96,7 +298,8 @@ pub(crate) fn check_columns_satisfy_exprs(
         Expr::Column(_) => Ok(()),
         _ => internal_err!("Expr::Column are required"),
     })?;
-    let column_exprs = find_column_exprs(exprs);
+    let mut column_exprs = find_column_exprs(exprs);
+    column_exprs.extend(find_subquery_outer_ref_columns(exprs));
     for e in &column_exprs {
         match e {
             Expr::GroupingSet(GroupingSet::Rollup(exprs)) => {
@@ -374,6 +374,27 @@ isolation level repeatable read
     quick_test(sql, plan);
 }
 
+#[test]
+fn plan_begin_transaction() {
+    let sql = "begin transaction read only";
+    let plan = "TransactionStart: ReadOnly Serializable";
+    quick_test(sql, plan);
+}
+
+#[test]
+fn plan_set_transaction() {
+    let sql = "set transaction read only";
+    let plan = "SetTransaction: ReadOnly";
+    quick_test(sql, plan);
+}
+
+#[test]
+fn plan_set_transaction_isolation() {
+    let sql = "set transaction isolation level read committed, read write";
+    let plan = "SetTransaction: ReadWrite ReadCommitted";
+    quick_test(sql, plan);
+}
+
 #[test]
 fn plan_commit_transaction() {
     let sql = "commit transaction";
@@ -474,6 +495,18 @@ Dml: op=[Insert Into] table=[test_decimal]
     quick_test(sql, plan);
 }
 
+#[test]
+fn plan_insert_default_values() {
+    let sql = "INSERT INTO test_decimal DEFAULT VALUES";
+    let plan = r#"
+Dml: op=[Insert Into] table=[test_decimal]
+  Projection: CAST(NULL AS Int32) AS id, CAST(NULL AS Decimal128(10, 2)) AS price
+    EmptyRelation
+    "#
+    .trim();
+    quick_test(sql, plan);
+}
+
 #[rstest]
 #[case::duplicate_columns(
     "INSERT INTO test_decimal (id, price, price) VALUES (1, 2, 3), (4, 5, 6)",
@@ -2129,6 +2162,8 @@ fn union_with_incompatible_data_type() {
     let err = logical_plan(sql)
         .expect_err("query should have failed")
         .strip_backtrace();
+    // a single column has no position, and Int64 cannot be cast to an
+    // interval
     assert_eq!(
        "Error during planning: UNION Column Int64(1) (type: Int64) is not compatible with column IntervalMonthDayNano(\"IntervalMonthDayNano { months: 12, days: 1, nanoseconds: 0 }\") (type: Interval(MonthDayNano))",
        err
@@ -2238,7 +2273,20 @@ fn union_with_incompatible_data_types() {
         .expect_err("query should have failed")
         .strip_backtrace();
     assert_eq!(
-        "Error during planning: UNION Column a (type: Boolean) is not compatible with column a (type: Utf8)",
+        "Error during planning: UNION Column a (type: Boolean) is not compatible with column a (type: Utf8), consider casting it to Utf8",
+        err
+    );
+}
+
+#[test]
+fn union_with_multiple_incompatible_data_types() {
+    let sql = "SELECT 1 a, 'x' b, 'y' c UNION ALL SELECT 2 a, true b, false c";
+    let err = logical_plan(sql)
+        .expect_err("query should have failed")
+        .strip_backtrace();
+    assert_eq!(
+        "Error during planning: UNION Column b (type: Boolean) is not compatible with column b (type: Utf8) at position 2, consider casting it to Utf8\n\
+         UNION Column c (type: Boolean) is not compatible with column c (type: Utf8) at position 3, consider casting it to Utf8",
         err
     );
 }
@@ -44,7 +44,7 @@ use datafusion::logical_expr::expr::{
     AggregateFunctionDefinition, Alias, BinaryExpr, Case, Cast, GroupingSet, InList,
     InSubquery, Sort, WindowFunction,
 };
-use datafusion::logical_expr::{expr, Between, JoinConstraint, LogicalPlan, Operator};
+use datafusion::logical_expr::{expr, Between, LogicalPlan, Operator};
 use datafusion::prelude::Expr;
 use pbjson_types::Any as ProtoAny;
 use substrait::proto::exchange_rel::{ExchangeKind, RoundRobin, ScatterFields};
@@ -352,13 +352,10 @@ pub fn to_substrait_rel(
             let left = to_substrait_rel(join.left.as_ref(), ctx, extension_info)?;
             let right = to_substrait_rel(join.right.as_ref(), ctx, extension_info)?;
             let join_type = to_substrait_jointype(join.join_type);
-            // we only support basic joins so return an error for anything not yet supported
-            match join.join_constraint {
-                JoinConstraint::On => {}
-                JoinConstraint::Using => {
-                    return not_impl_err!("join constraint: `using`")
-                }
-            }
+            // Substrait has no notion of `USING`, but the columns of a `USING`
+            // constraint are also present as equality pairs in `join.on`, so
+            // both constraints are encoded as an `ON` condition. The consumer
+            // produces an equivalent `ON` join.
             // parse filter if exists
             let in_join_schema = join.left.schema().join(join.right.schema())?;
             let join_filter = match &join.filter {
@@ -1250,6 +1247,7 @@ pub fn to_substrait_rex(
             order_by,
             window_frame,
             null_treatment: _,
+            distinct,
         }) => {
             // function reference
             let function_anchor = _register_function(fun.to_string(), extension_info);
@@ -1279,6 +1277,10 @@ pub fn to_substrait_rex(
             // window frame
             let bounds = to_substrait_bounds(window_frame)?;
             let bound_type = to_substrait_bound_type(window_frame)?;
+            let invocation = match distinct {
+                true => AggregationInvocation::Distinct,
+                false => AggregationInvocation::All,
+            };
             Ok(make_substrait_window_function(
                 function_anchor,
                 arguments,
@@ -1286,6 +1288,7 @@ pub fn to_substrait_rex(
                 order_by,
                 bounds,
                 bound_type,
+                invocation,
             ))
         }
         Expr::Like(Like {
@@ -1691,6 +1694,7 @@ fn make_substrait_window_function(
     sorts: Vec<SortField>,
     bounds: (Bound, Bound),
     bounds_type: BoundsType,
+    invocation: AggregationInvocation,
 ) -> Expression {
     Expression {
         rex_type: Some(RexType::WindowFunction(SubstraitWindowFunction {
@@ -1700,8 +1704,8 @@ fn make_substrait_window_function(
             sorts,
             options: vec![],
             output_type: None,
-            phase: 0,      // default to AGGREGATION_PHASE_UNSPECIFIED
-            invocation: 0, // TODO: fix
+            phase: 0, // default to AGGREGATION_PHASE_UNSPECIFIED
+            invocation: invocation as i32,
             lower_bound: Some(bounds.0),
             upper_bound: Some(bounds.1),
             args: vec![],
@@ -476,6 +476,19 @@ async fn inner_join() -> Result<()> {
     .await
 }
 
+#[tokio::test]
+async fn join_using() -> Result<()> {
+    assert_expected_plan(
+        "SELECT data.a FROM data JOIN data2 USING (a)",
+        "Projection: data.a\
+         \n  Inner Join: data.a = data2.a\
+         \n    TableScan: data projection=[a]\
+         \n    TableScan: data2 projection=[a]",
+        true,
+    )
+    .await
+}
+
 #[tokio::test]
 async fn roundtrip_left_join() -> Result<()> {
     roundtrip("SELECT data.a FROM data LEFT JOIN data2 ON data.a = data2.a").await
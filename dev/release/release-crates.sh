@@ -38,6 +38,7 @@ if ! [ git rev-parse --is-inside-work-tree ]; then
   cd datafusion/proto && cargo publish
   cd datafusion/execution && cargo publish
   cd datafusion/substrait && cargo publish
+  cd datafusion/flight-sql && cargo publish
   cd datafusion-cli && cargo publish --no-verify
 else
     echo "Crates must be released from the source tarball that was voted on, not from the repo"
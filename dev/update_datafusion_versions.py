@@ -44,6 +44,7 @@ crates = {
     'datafusion-sql': 'datafusion/sql/Cargo.toml',
     'datafusion-sqllogictest': 'datafusion/sqllogictest/Cargo.toml',
     'datafusion-substrait': 'datafusion/substrait/Cargo.toml',
+    'datafusion-flight-sql': 'datafusion/flight-sql/Cargo.toml',
     'datafusion-wasmtest': 'datafusion/wasmtest/Cargo.toml',
     'datafusion-benchmarks': 'benchmarks/Cargo.toml',
     'datafusion-cli': 'datafusion-cli/Cargo.toml',